@@ -204,6 +204,51 @@ pub trait BalanceOps<AccountId> {
 pub mod time {
     use super::*;
 
+    /// Factor by which the `fast-runtime` feature compresses time for development. The block time
+    /// and the tempos, rate limits and intervals counted in blocks are divided by it, so with the
+    /// default of 48 blocks take 250ms and a 360 blocks tempo takes 7 blocks.
+    ///
+    /// Set `SUBTENSOR_TIME_COMPRESSION` when building to use another factor. Without the feature
+    /// time is not compressed, and the `fast-blocks` feature keeps its own fixed values.
+    pub const TIME_COMPRESSION: u64 = if cfg!(feature = "fast-runtime") {
+        match option_env!("SUBTENSOR_TIME_COMPRESSION") {
+            Some(factor) => match u64::from_str_radix(factor, 10) {
+                Ok(factor) if factor > 0 && factor <= 12000 => factor,
+                _ => panic!("SUBTENSOR_TIME_COMPRESSION must be an integer from 1 to 12000"),
+            },
+            None => 48,
+        }
+    } else {
+        1
+    };
+
+    /// A duration of `duration` blocks compressed by [`TIME_COMPRESSION`], at least one block
+    /// unless it is zero.
+    pub const fn scale_blocks(duration: u64) -> u64 {
+        match duration.checked_div(TIME_COMPRESSION) {
+            Some(0) if duration > 0 => 1,
+            Some(scaled) => scaled,
+            None => duration,
+        }
+    }
+
+    /// [`scale_blocks`] of a tempo.
+    pub const fn scale_tempo(tempo: u16) -> u16 {
+        let scaled = scale_blocks(tempo as u64);
+        assert!(scaled <= u16::MAX as u64, "scaled tempo overflows u16");
+        scaled as u16
+    }
+
+    /// [`scale_blocks`] of a duration in [`BlockNumber`].
+    pub const fn scale_block_number(duration: BlockNumber) -> BlockNumber {
+        let scaled = scale_blocks(duration as u64);
+        assert!(
+            scaled <= BlockNumber::MAX as u64,
+            "scaled duration overflows BlockNumber"
+        );
+        scaled as BlockNumber
+    }
+
     /// This determines the average expected block time that we are targeting. Blocks will be
     /// produced at a minimum duration defined by `SLOT_DURATION`. `SLOT_DURATION` is picked up by
     /// `pallet_timestamp` which is in turn picked up by `pallet_aura` to implement `fn
@@ -211,7 +256,7 @@ pub mod time {
     ///
     /// Change this to adjust the block time.
     #[cfg(not(feature = "fast-blocks"))]
-    pub const MILLISECS_PER_BLOCK: u64 = 12000;
+    pub const MILLISECS_PER_BLOCK: u64 = scale_blocks(12000);
 
     /// Fast blocks for development
     #[cfg(feature = "fast-blocks")]
@@ -36,6 +36,21 @@ export const ISr25519VerifyABI = [
     },
 ];
 
+export const IBLS12381VERIFY_ADDRESS = "0x0000000000000000000000000000000000000404";
+export const IBls12381VerifyABI = [
+    {
+        inputs: [
+            { internalType: "bytes32", name: "message", type: "bytes32" },
+            { internalType: "bytes32[3]", name: "publicKey", type: "bytes32[3]" },
+            { internalType: "bytes32[2]", name: "signature", type: "bytes32[2]" },
+        ],
+        name: "verify",
+        outputs: [{ internalType: "bool", name: "", type: "bool" }],
+        stateMutability: "pure",
+        type: "function",
+    },
+];
+
 export const IBALANCETRANSFER_ADDRESS = "0x0000000000000000000000000000000000000800";
 export const IBalanceTransferABI = [
     {
@@ -51,6 +66,61 @@ export const IBalanceTransferABI = [
         stateMutability: "payable",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "coldkey",
+                type: "bytes32",
+            },
+            {
+                internalType: "uint256",
+                name: "amount",
+                type: "uint256",
+            },
+        ],
+        name: "withdraw",
+        outputs: [],
+        stateMutability: "nonpayable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "bytes32",
+                name: "coldkey",
+                type: "bytes32",
+            },
+        ],
+        name: "getPendingWithdrawal",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "total",
+                type: "uint64",
+            },
+            {
+                internalType: "uint64",
+                name: "released",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [],
+        name: "getTotalEvmLocked",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
 ];
 
 export const IDISPATCH_ADDRESS = "0x0000000000000000000000000000000000000006";
@@ -1,6 +1,30 @@
 export const IMETAGRAPH_ADDRESS = "0x0000000000000000000000000000000000000802";
 
 export const IMetagraphABI = [
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+        ],
+        name: "getAlphaStake",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
     {
         inputs: [
             {
@@ -249,6 +273,119 @@ export const IMetagraphABI = [
         stateMutability: "view",
         type: "function",
     },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+        ],
+        name: "getNeuron",
+        outputs: [
+            {
+                components: [
+                    {
+                        internalType: "bytes32",
+                        name: "hotkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "bytes32",
+                        name: "coldkey",
+                        type: "bytes32",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "alpha_stake",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "incentive",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "dividends",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "emission",
+                        type: "uint64",
+                    },
+                    {
+                        internalType: "uint16",
+                        name: "vtrust",
+                        type: "uint16",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "validator_permit",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "bool",
+                        name: "active",
+                        type: "bool",
+                    },
+                    {
+                        internalType: "uint64",
+                        name: "last_update",
+                        type: "uint64",
+                    },
+                    {
+                        components: [
+                            {
+                                internalType: "uint64",
+                                name: "block",
+                                type: "uint64",
+                            },
+                            {
+                                internalType: "uint32",
+                                name: "version",
+                                type: "uint32",
+                            },
+                            {
+                                internalType: "uint128",
+                                name: "ip",
+                                type: "uint128",
+                            },
+                            {
+                                internalType: "uint16",
+                                name: "port",
+                                type: "uint16",
+                            },
+                            {
+                                internalType: "uint8",
+                                name: "ip_type",
+                                type: "uint8",
+                            },
+                            {
+                                internalType: "uint8",
+                                name: "protocol",
+                                type: "uint8",
+                            },
+                        ],
+                        internalType: "struct AxonInfo",
+                        name: "axon",
+                        type: "tuple",
+                    },
+                ],
+                internalType: "struct NeuronInfo",
+                name: "",
+                type: "tuple",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
     {
         inputs: [
             {
@@ -252,6 +252,25 @@ export const IStakingV2ABI = [
         "stateMutability": "view",
         "type": "function"
     },
+    {
+        "inputs": [
+            {
+                "internalType": "address",
+                "name": "evmAddress",
+                "type": "address"
+            }
+        ],
+        "name": "addressToColdkey",
+        "outputs": [
+            {
+                "internalType": "bytes32",
+                "name": "",
+                "type": "bytes32"
+            }
+        ],
+        "stateMutability": "view",
+        "type": "function"
+    },
     {
         "inputs": [],
         "name": "getNominatorMinRequiredStake",
@@ -976,4 +976,258 @@ export const ISubnetABI = [
         stateMutability: "payable",
         type: "function"
     },
+    {
+        inputs: [],
+        name: "getNetworkRegistrationCost",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getSubnetOwner",
+        outputs: [
+            {
+                internalType: "bytes32",
+                name: "",
+                type: "bytes32",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getBondsPenalty",
+        outputs: [
+            {
+                internalType: "uint16",
+                name: "",
+                type: "uint16",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getMaxWeightAge",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getMinerEmissionVestingPeriod",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getSubnetTransactionFee",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getSubnetTreasuryShare",
+        outputs: [
+            {
+                internalType: "uint16",
+                name: "",
+                type: "uint16",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+        ],
+        name: "getWeightsVersionKeyGracePeriod",
+        outputs: [
+            {
+                internalType: "uint64",
+                name: "",
+                type: "uint64",
+            },
+        ],
+        stateMutability: "view",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "bondsPenalty",
+                type: "uint16",
+            },
+        ],
+        name: "setBondsPenalty",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint64",
+                name: "maxWeightAge",
+                type: "uint64",
+            },
+        ],
+        name: "setMaxWeightAge",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint64",
+                name: "vestingPeriod",
+                type: "uint64",
+            },
+        ],
+        name: "setMinerEmissionVestingPeriod",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint64",
+                name: "fee",
+                type: "uint64",
+            },
+        ],
+        name: "setSubnetTransactionFee",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint16",
+                name: "share",
+                type: "uint16",
+            },
+        ],
+        name: "setSubnetTreasuryShare",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
+    {
+        inputs: [
+            {
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                internalType: "uint64",
+                name: "gracePeriod",
+                type: "uint64",
+            },
+        ],
+        name: "setWeightsVersionKeyGracePeriod",
+        outputs: [],
+        stateMutability: "payable",
+        type: "function",
+    },
 ];
@@ -0,0 +1,109 @@
+export const ISUBTENSOR_EVENTS_ADDRESS = "0x000000000000000000000000000000000000080b";
+
+export const ISubtensorEventsABI = [
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "ownerCut",
+                type: "uint256",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "incentives",
+                type: "uint256",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "alphaDividends",
+                type: "uint256",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "taoDividends",
+                type: "uint256",
+            },
+        ],
+        name: "EmissionDistributed",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "uid",
+                type: "uint16",
+            },
+        ],
+        name: "NeuronRegistered",
+        type: "event",
+    },
+    {
+        anonymous: false,
+        inputs: [
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "coldkey",
+                type: "bytes32",
+            },
+            {
+                indexed: true,
+                internalType: "bytes32",
+                name: "hotkey",
+                type: "bytes32",
+            },
+            {
+                indexed: false,
+                internalType: "uint16",
+                name: "netuid",
+                type: "uint16",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "tao",
+                type: "uint256",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "alpha",
+                type: "uint256",
+            },
+            {
+                indexed: false,
+                internalType: "uint256",
+                name: "fee",
+                type: "uint256",
+            },
+        ],
+        name: "StakeAdded",
+        type: "event",
+    },
+];
@@ -130,7 +130,7 @@ describe("Test the Metagraph precompile", () => {
         }
 
         const methodList = ["getEmission", "getVtrust", "getValidatorStatus", "getLastUpdate", "getIsActive",
-            "getHotkey", "getColdkey"
+            "getHotkey", "getColdkey", "getAlphaStake"
         ]
         for (const method of methodList) {
             const value = await publicClient.readContract({
@@ -143,4 +143,32 @@ describe("Test the Metagraph precompile", () => {
             assert.ok(value != undefined);
         }
     });
+
+    it("Neuron snapshot via precompile contract matches the single getters", async () => {
+        const uid = 0
+        const neuron = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getNeuron",
+            args: [subnetId, uid]
+        })
+
+        const hotkeyValue = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getHotkey",
+            args: [subnetId, uid]
+        })
+
+        const alphaStake = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getAlphaStake",
+            args: [subnetId, uid]
+        })
+
+        assert.equal(neuron.hotkey, hotkeyValue)
+        assert.equal(neuron.alpha_stake, alphaStake)
+        assert.ok(neuron.axon != undefined)
+    });
 });
\ No newline at end of file
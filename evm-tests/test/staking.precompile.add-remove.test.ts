@@ -86,6 +86,13 @@ describe("Test neuron precompile add remove stake", () => {
         assert.ok(stakeAfter > stakeBefore)
     })
 
+    it("V2 maps the EVM address to its coldkey", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet2);
+        const coldkey = await contract.addressToColdkey(wallet2.address)
+
+        assert.equal(coldkey, ethers.hexlify(convertH160ToPublicKey(wallet2.address)))
+    })
+
     it("Can not add stake if subnet doesn't exist", async () => {
         // wrong netuid
         let netuid = 12345;
@@ -550,4 +550,36 @@ describe("Test the Subnet precompile contract", () => {
         assert.equal(valueFromContract, newValue)
         assert.equal(valueFromContract, onchainValue);
     })
+
+    it("Can set bondsPenalty parameter", async () => {
+        const totalNetwork = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        const netuid = totalNetwork - 1;
+
+        const newValue = 105;
+        const tx = await contract.setBondsPenalty(netuid, newValue);
+        await tx.wait();
+
+        let onchainValue = await api.query.SubtensorModule.BondsPenalty.getValue(netuid)
+
+        let valueFromContract = Number(
+            await contract.getBondsPenalty(netuid)
+        );
+
+        assert.equal(valueFromContract, newValue)
+        assert.equal(valueFromContract, onchainValue);
+    })
+
+    it("Can read subnet owner and network registration cost", async () => {
+        const totalNetwork = await api.query.SubtensorModule.TotalNetworks.getValue()
+        const contract = new ethers.Contract(ISUBNET_ADDRESS, ISubnetABI, wallet);
+        const netuid = totalNetwork - 1;
+
+        const onchainOwner = await api.query.SubtensorModule.SubnetOwner.getValue(netuid)
+        const ownerFromContract = await contract.getSubnetOwner(netuid)
+        assert.equal(convertPublicKeyToSs58(ethers.getBytes(ownerFromContract)), onchainOwner)
+
+        const costFromContract = BigInt(await contract.getNetworkRegistrationCost())
+        assert.ok(costFromContract > BigInt(0))
+    })
 })
@@ -0,0 +1,26 @@
+//! Decrypts an arbitrary commit-reveal v3 commit with an arbitrary drand signature, which must
+//! fail or reveal weights without panicking.
+//!
+//! Input layout: the committing hotkey (32 bytes), the length of the signature (1 byte), the
+//! signature, then the serialized commit.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use node_subtensor_runtime::SubtensorModule;
+use sp_core::crypto::AccountId32;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((who, data)) = data.split_first_chunk::<32>() else {
+        return;
+    };
+    let Some((&signature_len, data)) = data.split_first() else {
+        return;
+    };
+    let Some((signature, commit)) = data.split_at_checked(usize::from(signature_len)) else {
+        return;
+    };
+
+    // The revealed weights are set through `do_set_weights`, which checks them.
+    let _ = SubtensorModule::decrypt_crv3_commit(&AccountId32::from(*who), commit, signature);
+});
@@ -0,0 +1,66 @@
+//! Registers a hotkey with an arbitrary proof of work, which must be rejected or accepted without
+//! panicking whatever the block number, nonce and work.
+//!
+//! Input layout, little-endian: the difficulty (u64), the current block (u32), the block number
+//! (u64) and nonce (u64) of the work, the hotkey and coldkey (32 bytes each), then the work.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use node_subtensor_runtime::{Runtime, RuntimeOrigin, SubtensorModule, System};
+use sp_core::crypto::AccountId32;
+use sp_runtime::BuildStorage;
+use subtensor_runtime_common::NetUid;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((difficulty, data)) = data.split_first_chunk::<8>() else {
+        return;
+    };
+    let Some((current_block, data)) = data.split_first_chunk::<4>() else {
+        return;
+    };
+    let Some((block_number, data)) = data.split_first_chunk::<8>() else {
+        return;
+    };
+    let Some((nonce, data)) = data.split_first_chunk::<8>() else {
+        return;
+    };
+    let Some((hotkey, data)) = data.split_first_chunk::<32>() else {
+        return;
+    };
+    let Some((coldkey, work)) = data.split_first_chunk::<32>() else {
+        return;
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let netuid = NetUid::from(1);
+        System::set_block_number(u32::from_le_bytes(*current_block));
+        SubtensorModule::init_new_network(netuid, 13);
+        SubtensorModule::set_network_registration_allowed(netuid, true);
+        SubtensorModule::set_network_pow_registration_allowed(netuid, true);
+        SubtensorModule::set_difficulty(netuid, u64::from_le_bytes(*difficulty));
+
+        let difficulty = SubtensorModule::get_difficulty(netuid);
+        let meets_difficulty = SubtensorModule::vec_to_hash(work.to_vec())
+            .is_some_and(|hash| SubtensorModule::hash_meets_difficulty(&hash, difficulty));
+
+        let hotkey = AccountId32::from(*hotkey);
+        let result = SubtensorModule::register(
+            RuntimeOrigin::signed(hotkey.clone()),
+            netuid,
+            u64::from_le_bytes(*block_number),
+            u64::from_le_bytes(*nonce),
+            work.to_vec(),
+            hotkey,
+            AccountId32::from(*coldkey),
+        );
+
+        // Work that is not a hash meeting the difficulty is never accepted.
+        if result.is_ok() {
+            assert!(meets_difficulty);
+        }
+    });
+});
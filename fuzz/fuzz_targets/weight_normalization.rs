@@ -0,0 +1,61 @@
+//! Normalizes arbitrary weights the way `set_weights` and the epoch do, which must not panic and
+//! must keep the weights within their bounds.
+//!
+//! Input layout: the max weight limit as a little-endian u16, then the weights as little-endian
+//! u16s. The epoch rows are the weights split on zeros, each weight keyed by its position.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use node_subtensor_runtime::SubtensorModule;
+use pallet_subtensor::epoch::math::{
+    check_vec_max_limited, inplace_row_normalize_sparse, u16_to_fixed, vec_u16_max_upscale_to_u16,
+};
+use substrate_fixed::types::I32F32;
+
+/// `set_weights` accepts at most one weight per uid of a subnet.
+const MAX_WEIGHTS: usize = 4096;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((max_limit, data)) = data.split_first_chunk::<2>() else {
+        return;
+    };
+    let max_limit = u16::from_le_bytes(*max_limit);
+    let weights: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|w| u16::from_le_bytes([w[0], w[1]]))
+        .collect();
+    if weights.len() > MAX_WEIGHTS {
+        return;
+    }
+
+    // The weights set by a validator are max-upscaled so that the largest is u16::MAX.
+    let upscaled = vec_u16_max_upscale_to_u16(&weights);
+    assert_eq!(upscaled.len(), weights.len());
+    if weights.iter().any(|&w| w > 0) {
+        assert_eq!(upscaled.iter().max(), Some(&u16::MAX));
+    }
+    for (w, u) in weights.iter().zip(&upscaled) {
+        assert!(*w > 0 || *u == 0);
+    }
+    let _ = check_vec_max_limited(&upscaled, max_limit);
+
+    let normalized = SubtensorModule::normalize_weights(weights.clone());
+    assert_eq!(normalized.len(), weights.len());
+    assert!(normalized.iter().map(|&w| u64::from(w)).sum::<u64>() <= u64::from(u16::MAX));
+
+    // The epoch normalizes every weight row to sum to one.
+    let mut rows: Vec<Vec<(u16, I32F32)>> = weights
+        .iter()
+        .enumerate()
+        .map(|(j, &w)| (j as u16, w))
+        .collect::<Vec<_>>()
+        .split(|(_, w)| *w == 0)
+        .map(|row| row.iter().map(|&(j, w)| (j, u16_to_fixed(w))).collect())
+        .collect();
+    inplace_row_normalize_sparse(&mut rows);
+    for row in rows {
+        let sum: I32F32 = row.iter().map(|(_, w)| *w).sum();
+        assert!(sum <= I32F32::from_num(1));
+    }
+});
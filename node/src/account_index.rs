@@ -0,0 +1,169 @@
+//! Optional node-side index mapping accounts to the blocks in which they appear in subtensor
+//! events, enabled with `--index-accounts`.
+//!
+//! The index is kept in memory and rebuilt from the finalized chain on startup, so it needs
+//! state for historical blocks (i.e. an archive node) to cover the full history. Blocks whose
+//! events can no longer be decoded with the current runtime types are skipped.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, RwLock},
+};
+
+use codec::Decode;
+use futures::StreamExt;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorObjectOwned, error::ErrorObject},
+};
+use node_subtensor_runtime::RuntimeEvent;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_telemetry::log;
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use sp_runtime::{AccountId32, traits::Header};
+use subtensor_runtime_common::{BlockNumber, Hash};
+
+use crate::client::FullClient;
+
+/// Maximum number of blocks a single `subtensor_getAccountHistory` query may span.
+pub const MAX_ACCOUNT_HISTORY_RANGE: BlockNumber = 1_000_000;
+
+type EventRecords = Vec<frame_system::EventRecord<RuntimeEvent, Hash>>;
+
+/// In-memory account → blocks index.
+#[derive(Default)]
+pub struct AccountIndex {
+    blocks: RwLock<BTreeMap<AccountId32, BTreeSet<BlockNumber>>>,
+    last_indexed: RwLock<Option<BlockNumber>>,
+}
+
+impl AccountIndex {
+    /// Blocks in `from..=to` in which `account` appeared in a subtensor event.
+    pub fn history(
+        &self,
+        account: &AccountId32,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> Vec<BlockNumber> {
+        let blocks = self.blocks.read().unwrap_or_else(|e| e.into_inner());
+        blocks
+            .get(account)
+            .map(|set| set.range(from..=to).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Highest block number indexed so far.
+    pub fn last_indexed(&self) -> Option<BlockNumber> {
+        *self.last_indexed.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn insert(&self, number: BlockNumber, records: EventRecords) {
+        let mut blocks = self.blocks.write().unwrap_or_else(|e| e.into_inner());
+        for record in records {
+            if let RuntimeEvent::SubtensorModule(event) = record.event {
+                for account in event.accounts() {
+                    blocks.entry(account.clone()).or_default().insert(number);
+                }
+            }
+        }
+    }
+
+    fn index_block(&self, client: &FullClient, number: BlockNumber) {
+        let Ok(Some(hash)) = client.hash(number) else {
+            return;
+        };
+
+        let key = StorageKey([sp_core::twox_128(b"System"), sp_core::twox_128(b"Events")].concat());
+        match client.storage(hash, &key) {
+            Ok(Some(data)) => match EventRecords::decode(&mut &data.0[..]) {
+                Ok(records) => self.insert(number, records),
+                Err(e) => log::debug!("Skipping events of block #{number}: {e:?}"),
+            },
+            Ok(None) => {}
+            Err(e) => log::debug!("Unable to read events of block #{number}: {e:?}"),
+        }
+
+        *self.last_indexed.write().unwrap_or_else(|e| e.into_inner()) = Some(number);
+    }
+
+    /// Indexes every block up to and including `target` that has not been indexed yet.
+    fn catch_up(&self, client: &FullClient, target: BlockNumber) {
+        let start = self.last_indexed().map_or(0, |n| n.saturating_add(1));
+        for number in start..=target {
+            self.index_block(client, number);
+        }
+    }
+}
+
+/// Background task that backfills the index from genesis and then follows finality.
+pub async fn run_account_indexer(index: Arc<AccountIndex>, client: Arc<FullClient>) {
+    let mut finality = client.finality_notification_stream();
+
+    index.catch_up(&client, client.info().finalized_number);
+    log::info!(
+        "Account index built up to block #{}",
+        index.last_indexed().unwrap_or_default()
+    );
+
+    while let Some(notification) = finality.next().await {
+        index.catch_up(&client, *notification.header.number());
+    }
+}
+
+#[rpc(server)]
+pub trait AccountHistoryApi {
+    /// Returns the finalized block numbers in `from..=to` in which the SCALE-encoded `account`
+    /// appeared in a subtensor event.
+    #[method(name = "subtensor_getAccountHistory")]
+    fn get_account_history(
+        &self,
+        account_vec: Vec<u8>,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<BlockNumber>>;
+}
+
+/// RPC handler serving queries from the [`AccountIndex`].
+pub struct AccountHistory {
+    index: Arc<AccountIndex>,
+}
+
+impl AccountHistory {
+    pub fn new(index: Arc<AccountIndex>) -> Self {
+        Self { index }
+    }
+}
+
+fn invalid_params(message: String) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        jsonrpsee::types::error::INVALID_PARAMS_CODE,
+        message,
+        None::<()>,
+    )
+}
+
+impl AccountHistoryApiServer for AccountHistory {
+    fn get_account_history(
+        &self,
+        account_vec: Vec<u8>,
+        from: BlockNumber,
+        to: BlockNumber,
+    ) -> RpcResult<Vec<BlockNumber>> {
+        let account = AccountId32::decode(&mut &account_vec[..])
+            .map_err(|e| invalid_params(format!("Unable to decode account: {e:?}")))?;
+
+        if from > to {
+            return Err(invalid_params(format!("Invalid range: {from} > {to}")));
+        }
+        if to.saturating_sub(from) > MAX_ACCOUNT_HISTORY_RANGE {
+            return Err(invalid_params(format!(
+                "Range too large: {} > {MAX_ACCOUNT_HISTORY_RANGE}",
+                to.saturating_sub(from)
+            )));
+        }
+
+        Ok(self.index.history(&account, from, to))
+    }
+}
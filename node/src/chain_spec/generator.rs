@@ -0,0 +1,99 @@
+// Allowed since it's actually better to panic during chain setup when there is an error
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+/// Balance of every generated account, 1_000_000 TAO.
+pub const DEFAULT_GENERATED_BALANCE: u64 = 1_000_000_000_000_000;
+
+/// The accounts, subnets and neurons of a generated development chain spec.
+///
+/// Every key is derived from a fixed seed, so the same parameters always give the same chain:
+/// - funded accounts: `//Account{i}`;
+/// - subnet owners: `//Subnet{m}` coldkey and `//Subnet{m}//Owner` hotkey;
+/// - neurons: `//Subnet{m}//Neuron{k}` coldkey and `//Subnet{m}//Neuron{k}//Hotkey` hotkey.
+///
+/// The subnets get netuids 2, 3, ..., netuid 1 being the subnet every genesis starts with. Owners
+/// and neuron coldkeys are funded too.
+#[derive(Debug, Clone, Copy)]
+pub struct GeneratedSpecParams {
+    /// Number of funded accounts.
+    pub accounts: u32,
+    /// Number of subnets.
+    pub subnets: u32,
+    /// Number of neurons per subnet, on top of the hotkey of the owner at uid 0.
+    pub neurons: u32,
+    /// Balance of every funded account, in RAO.
+    pub balance: u64,
+}
+
+fn account(seed: &str) -> AccountId {
+    get_account_id_from_seed::<sr25519::Public>(seed)
+}
+
+pub fn generated_config(params: GeneratedSpecParams) -> Result<ChainSpec, String> {
+    let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+    // Give front-ends necessary data to present to users
+    let mut properties = sc_service::Properties::new();
+    properties.insert("tokenSymbol".into(), "TAO".into());
+    properties.insert("tokenDecimals".into(), 9.into());
+    properties.insert("ss58Format".into(), 42.into());
+
+    Ok(ChainSpec::builder(wasm_binary, Extensions::default())
+        .with_name("Bittensor")
+        .with_protocol_id("bittensor")
+        .with_id("bittensor")
+        .with_chain_type(ChainType::Development)
+        .with_genesis_config_patch(generated_genesis(params))
+        .with_properties(properties)
+        .build())
+}
+
+pub fn generated_genesis(params: GeneratedSpecParams) -> serde_json::Value {
+    let mut funded = vec![account("Alice")];
+    funded.extend((0..params.accounts).map(|i| account(&format!("Account{i}"))));
+
+    let subnets = (0..params.subnets)
+        .map(|m| {
+            let neurons = (0..params.neurons)
+                .map(|k| {
+                    (
+                        account(&format!("Subnet{m}//Neuron{k}")),
+                        account(&format!("Subnet{m}//Neuron{k}//Hotkey")),
+                    )
+                })
+                .collect::<Vec<_>>();
+            (
+                account(&format!("Subnet{m}")),
+                account(&format!("Subnet{m}//Owner")),
+                neurons,
+            )
+        })
+        .collect::<Vec<_>>();
+    for (owner, _, neurons) in &subnets {
+        funded.push(owner.clone());
+        funded.extend(neurons.iter().map(|(coldkey, _)| coldkey.clone()));
+    }
+
+    let balances = funded
+        .into_iter()
+        .map(|account| (account, params.balance))
+        .collect::<Vec<_>>();
+    let balances_issuance = params.balance.saturating_mul(balances.len() as u64);
+    let (aura, grandpa) = authority_keys_from_seed("Alice");
+
+    serde_json::json!({
+        "balances": { "balances": balances },
+        "aura": { "authorities": vec![aura] },
+        "grandpa": { "authorities": vec![(grandpa, 1)] },
+        "sudo": { "key": Some(account("Alice")) },
+        "triumvirateMembers": { "members": vec![account("Alice")] },
+        "senateMembers": { "members": Vec::<AccountId>::new() },
+        "evmChainId": { "chainId": 42 },
+        "subtensorModule": {
+            "balancesIssuance": balances_issuance,
+            "subnets": subnets,
+        },
+    })
+}
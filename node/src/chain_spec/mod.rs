@@ -3,7 +3,9 @@
 
 pub mod devnet;
 pub mod finney;
+pub mod generator;
 pub mod localnet;
+pub mod snapshot;
 pub mod testnet;
 
 use node_subtensor_runtime::{Block, WASM_BINARY};
@@ -31,6 +33,23 @@ pub struct Extensions {
     pub fork_blocks: sc_client_api::ForkBlocks<Block>,
     /// Known bad block hashes.
     pub bad_blocks: sc_client_api::BadBlocks<Block>,
+    /// GRANDPA set id warp sync proofs start from, overriding the default of the chain type.
+    /// The justifications of the authority set changes before it are not kept.
+    pub grandpa_initial_set_id: Option<u64>,
+}
+
+/// The GRANDPA set id warp sync proofs of `chain_spec` start from.
+///
+/// Specs without [`Extensions::grandpa_initial_set_id`] fall back to the ids of mainnet and
+/// testnet, whose history was not kept from their genesis.
+pub fn grandpa_initial_set_id(chain_spec: &dyn sc_service::ChainSpec) -> u64 {
+    sc_chain_spec::get_extension::<Extensions>(chain_spec.extensions())
+        .and_then(|extensions| extensions.grandpa_initial_set_id)
+        .unwrap_or(if chain_spec.chain_type() == ChainType::Live {
+            3 // mainnet patch
+        } else {
+            2 // testnet patch
+        })
 }
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
@@ -0,0 +1,56 @@
+use super::*;
+use crate::{cli::ExportSubtensorGenesisCmd, client::FullClient};
+use sc_client_api::UsageProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, storage::Storage};
+use sp_runtime::BuildStorage;
+use std::{io::Write, sync::Arc};
+
+/// Builds the raw `dev` chain spec with the storage of `pallets` replaced by the one in
+/// `snapshot`.
+///
+/// Pallets are given by their name in the runtime, the prefix of their storage keys. Only the
+/// top trie is copied, the subtensor pallets keep no child tries.
+pub fn localnet_from_snapshot(snapshot: Storage, pallets: &[String]) -> Result<ChainSpec, String> {
+    let mut spec = localnet::localnet_config(true)?;
+    let mut storage = spec.build_storage()?;
+
+    let prefixes = pallets
+        .iter()
+        .map(|pallet| twox_128(pallet.as_bytes()))
+        .collect::<Vec<_>>();
+    let copied = |key: &Vec<u8>| prefixes.iter().any(|prefix| key.starts_with(prefix));
+
+    storage.top.retain(|key, _| !copied(key));
+    storage
+        .top
+        .extend(snapshot.top.into_iter().filter(|(key, _)| copied(key)));
+
+    sc_service::ChainSpec::set_storage(&mut spec, storage);
+    Ok(spec)
+}
+
+impl ExportSubtensorGenesisCmd {
+    /// Exports the state of the subtensor pallets at the requested block as a localnet spec.
+    pub async fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+        let hash = match self
+            .input
+            .as_ref()
+            .map(|block| block.parse::<Block>())
+            .transpose()?
+        {
+            Some(id) => client.expect_block_hash_from_id(&id)?,
+            None => client.usage_info().chain.best_hash,
+        };
+        log::info!("Exporting the subtensor state at {hash:?}...");
+
+        let snapshot = sc_service::chain_ops::export_raw_state(client, hash)?;
+        let spec = localnet_from_snapshot(snapshot, &self.pallets)?;
+        let json = sc_service::ChainSpec::as_json(&spec, true)?;
+        if std::io::stdout().write_all(json.as_bytes()).is_err() {
+            let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
+        }
+
+        Ok(())
+    }
+}
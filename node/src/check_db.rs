@@ -0,0 +1,81 @@
+use crate::{cli::CheckDbCmd, client::FullClient};
+use node_subtensor_runtime::{Block, SubtensorModule};
+use sc_client_api::UsageProvider;
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::Storage;
+use std::{io::Write, sync::Arc};
+
+/// The outcome of one of the subtensor storage checks.
+#[derive(Debug, Serialize)]
+pub struct InvariantCheck {
+    pub name: &'static str,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<&'static str>,
+}
+
+/// The report printed by `check-db`.
+#[derive(Debug, Serialize)]
+pub struct CheckDbReport {
+    pub block: String,
+    pub number: Option<u32>,
+    pub ok: bool,
+    pub checks: Vec<InvariantCheck>,
+}
+
+/// Runs the subtensor storage checks against `storage`.
+///
+/// The checks are executed natively with the node's runtime, so the storage must match its
+/// storage layout.
+pub fn check_storage(storage: Storage) -> Vec<InvariantCheck> {
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        SubtensorModule::check_invariants()
+            .into_iter()
+            .map(|(name, result)| InvariantCheck {
+                name,
+                ok: result.is_ok(),
+                error: result.err().map(Into::into),
+            })
+            .collect()
+    })
+}
+
+impl CheckDbCmd {
+    /// Checks the subtensor storage at the requested block and prints the report as JSON.
+    ///
+    /// Fails if any check fails, so the exit code can be used in scripts.
+    pub async fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+        let hash = match self
+            .input
+            .as_ref()
+            .map(|block| block.parse::<Block>())
+            .transpose()?
+        {
+            Some(id) => client.expect_block_hash_from_id(&id)?,
+            None => client.usage_info().chain.best_hash,
+        };
+        log::info!("Checking the subtensor state at {hash:?}...");
+
+        let storage = sc_service::chain_ops::export_raw_state(client.clone(), hash)?;
+        let checks = check_storage(storage);
+        let report = CheckDbReport {
+            block: format!("{hash:?}"),
+            number: client.number(hash)?,
+            ok: checks.iter().all(|check| check.ok),
+            checks,
+        };
+
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Error serializing the report: {e}"))?;
+        if std::io::stdout().write_all(json.as_bytes()).is_err() {
+            let _ = std::io::stderr().write_all(b"Error writing to stdout\n");
+        }
+
+        if report.ok {
+            Ok(())
+        } else {
+            Err("Subtensor storage checks failed".into())
+        }
+    }
+}
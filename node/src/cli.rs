@@ -1,4 +1,7 @@
+use crate::chain_spec::generator::{DEFAULT_GENERATED_BALANCE, GeneratedSpecParams};
 use crate::ethereum::EthConfiguration;
+use crate::snapshot::SnapshotParams;
+use crate::tx_pool_policy::TxPoolPolicy;
 use sc_cli::RunCmd;
 
 #[derive(Debug, clap::Parser)]
@@ -13,6 +16,28 @@ pub struct Cli {
     #[arg(long, value_enum, ignore_case = true)]
     pub sealing: Option<Sealing>,
 
+    /// Maintain an index of the blocks in which each account had subtensor events, served by
+    /// the `subtensor_getAccountHistory` RPC.
+    #[arg(long)]
+    pub index_accounts: bool,
+
+    /// Keep the weights and bonds matrices of the last EPOCHS epochs of each subnet, and the
+    /// summary of every epoch, in the offchain database, served by the `subtensor_getEpochHistory`
+    /// RPC.
+    #[arg(long, value_name = "EPOCHS")]
+    pub epoch_history: Option<u32>,
+
+    /// JSON file with the rate limit and the methods served by the RPC server. See the
+    /// `rpc_policy` module for its format.
+    #[arg(long, value_name = "PATH")]
+    pub rpc_policy: Option<std::path::PathBuf>,
+
+    #[command(flatten)]
+    pub tx_pool_policy: TxPoolPolicy,
+
+    #[command(flatten)]
+    pub snapshot: SnapshotParams,
+
     #[command(flatten)]
     pub eth: EthConfiguration,
 }
@@ -27,18 +52,30 @@ pub enum Subcommand {
     // Build a chain specification.
     BuildSpec(sc_cli::BuildSpecCmd),
 
+    // Build a development chain specification with generated accounts, subnets and neurons.
+    BuildSpecDev(BuildSpecDevCmd),
+
     // Validate blocks.
     CheckBlock(sc_cli::CheckBlockCmd),
 
+    // Check the subtensor storage of a given block for accounting inconsistencies.
+    CheckDb(CheckDbCmd),
+
     // Export blocks.
     ExportBlocks(sc_cli::ExportBlocksCmd),
 
     // Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    // Export the subtensor state of a given block into a localnet chain spec.
+    ExportSubtensorGenesis(ExportSubtensorGenesisCmd),
+
     // Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
+    // Import a state snapshot into an empty database.
+    ImportSnapshot(ImportSnapshotCmd),
+
     // Remove the whole chain.
     PurgeChain(sc_cli::PurgeChainCmd),
 
@@ -54,6 +91,156 @@ pub enum Subcommand {
     ChainInfo(sc_cli::ChainInfoCmd),
 }
 
+/// Generates a development chain spec with funded accounts, subnets and neurons derived from
+/// fixed seeds, and prints it to stdout.
+#[derive(Debug, clap::Parser)]
+pub struct BuildSpecDevCmd {
+    /// Number of funded accounts, on top of Alice.
+    #[arg(long, default_value_t = 10)]
+    pub accounts: u32,
+
+    /// Number of subnets registered in genesis.
+    #[arg(long, default_value_t = 1)]
+    pub subnets: u32,
+
+    /// Number of neurons registered on every subnet, on top of the owner hotkey.
+    #[arg(long, default_value_t = 4)]
+    pub neurons: u32,
+
+    /// Balance of every funded account, in RAO.
+    #[arg(long, default_value_t = DEFAULT_GENERATED_BALANCE)]
+    pub balance: u64,
+
+    /// Print the raw genesis storage instead of the genesis config.
+    #[arg(long)]
+    pub raw: bool,
+}
+
+impl BuildSpecDevCmd {
+    pub fn params(&self) -> GeneratedSpecParams {
+        GeneratedSpecParams {
+            accounts: self.accounts,
+            subnets: self.subnets,
+            neurons: self.neurons,
+            balance: self.balance,
+        }
+    }
+}
+
+/// Copies the storage of the subtensor pallets at a block of the chain in the database into a
+/// localnet chain spec, and prints the raw spec to stdout.
+///
+/// The rest of the genesis is the one of the `dev` chain, so Alice is the sudo key and the only
+/// authority.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportSubtensorGenesisCmd {
+    /// Block hash or number to export the state at, the best block by default.
+    #[arg(value_name = "HASH or NUMBER")]
+    pub input: Option<sc_cli::BlockNumberOrHash>,
+
+    /// Pallets whose storage is copied, by their name in the runtime.
+    #[arg(long, value_delimiter = ',', default_value = "SubtensorModule,Swap")]
+    pub pallets: Vec<String>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: sc_cli::PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ExportSubtensorGenesisCmd {
+    fn shared_params(&self) -> &sc_cli::SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&sc_cli::PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// Checks the accounting invariants of the subtensor storage at a block of the chain in the
+/// database, and prints a JSON report of every check to stdout.
+///
+/// Exits with an error if any check fails.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct CheckDbCmd {
+    /// Block hash or number to check the state at, the best block by default.
+    #[arg(value_name = "HASH or NUMBER")]
+    pub input: Option<sc_cli::BlockNumberOrHash>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: sc_cli::PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for CheckDbCmd {
+    fn shared_params(&self) -> &sc_cli::SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&sc_cli::PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// Imports a snapshot taken with `--snapshot-interval` into an empty database, making its block
+/// the finalized head the node syncs from.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ImportSnapshotCmd {
+    /// Manifest of the snapshot, such as `latest.json`.
+    #[arg(value_name = "MANIFEST")]
+    pub manifest: std::path::PathBuf,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: sc_cli::SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: sc_cli::PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for ImportSnapshotCmd {
+    fn shared_params(&self) -> &sc_cli::SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&sc_cli::PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
 /// Available Sealing methods.
 #[derive(Copy, Clone, Debug, Default, clap::ValueEnum)]
 pub enum Sealing {
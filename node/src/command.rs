@@ -2,6 +2,7 @@ use crate::{
     chain_spec,
     cli::{Cli, Subcommand},
     ethereum::db_config_dir,
+    rpc_policy::RpcPolicy,
     service,
 };
 use fc_db::{DatabaseSource, kv::frontier_database_dir};
@@ -66,6 +67,11 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
         }
+        Some(Subcommand::BuildSpecDev(cmd)) => {
+            let spec = chain_spec::generator::generated_config(cmd.params())?;
+            println!("{}", sc_service::ChainSpec::as_json(&spec, cmd.raw)?);
+            Ok(())
+        }
         Some(Subcommand::CheckBlock(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|mut config| {
@@ -74,6 +80,14 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, import_queue), task_manager))
             })
         }
+        Some(Subcommand::CheckDb(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.async_run(|mut config| {
+                let (client, _, _, task_manager, _) =
+                    service::new_chain_ops(&mut config, &cli.eth)?;
+                Ok((cmd.run(client), task_manager))
+            })
+        }
         Some(Subcommand::ExportBlocks(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|mut config| {
@@ -90,6 +104,14 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, config.chain_spec), task_manager))
             })
         }
+        Some(Subcommand::ExportSubtensorGenesis(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.async_run(|mut config| {
+                let (client, _, _, task_manager, _) =
+                    service::new_chain_ops(&mut config, &cli.eth)?;
+                Ok((cmd.run(client), task_manager))
+            })
+        }
         Some(Subcommand::ImportBlocks(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|mut config| {
@@ -98,6 +120,14 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, import_queue), task_manager))
             })
         }
+        Some(Subcommand::ImportSnapshot(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.async_run(|mut config| {
+                let (client, block_import, task_manager) =
+                    service::new_snapshot_import(&mut config, &cli.eth)?;
+                Ok((cmd.run(client, block_import), task_manager))
+            })
+        }
         Some(Subcommand::PurgeChain(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| {
@@ -234,9 +264,23 @@ pub fn run() -> sc_cli::Result<()> {
             runner.run_node_until_exit(|config| async move {
                 let mut config = override_default_heap_pages(config, 60_000);
 
-                // If the operator did **not** supply `--rpc-rate-limit`, disable the limiter.
+                let rpc_policy = match &cli.rpc_policy {
+                    Some(path) => RpcPolicy::from_file(path).map_err(sc_cli::Error::Input)?,
+                    None => RpcPolicy::default(),
+                };
+                // If the operator did **not** supply `--rpc-rate-limit`, use the one of the RPC
+                // policy, which disables the limiter by default.
                 if cli.run.rpc_params.rpc_rate_limit.is_none() {
-                    config.rpc.rate_limit = None;
+                    config.rpc.rate_limit = rpc_policy.rate_limit;
+                    for endpoint in config.rpc.addr.iter_mut().flatten() {
+                        endpoint.rate_limit = rpc_policy.rate_limit;
+                    }
+                }
+                if rpc_policy.rate_limit_trust_proxy_headers {
+                    config.rpc.rate_limit_trust_proxy_headers = true;
+                    for endpoint in config.rpc.addr.iter_mut().flatten() {
+                        endpoint.rate_limit_trust_proxy_headers = true;
+                    }
                 }
                 // If the operator did **not** supply `--rpc-max-subscriptions-per-connection` set to high value.
                 config.rpc.max_subs_per_conn =
@@ -251,9 +295,22 @@ pub fn run() -> sc_cli::Result<()> {
                     Some(ValueSource::CommandLine) => cli.run.rpc_params.rpc_max_connections,
                     _ => 10000,
                 };
-                service::build_full(config, cli.eth, cli.sealing)
-                    .map_err(Into::into)
-                    .await
+                // The epoch history is written by the runtime to the offchain index.
+                if cli.epoch_history.is_some() {
+                    config.offchain_worker.indexing_enabled = true;
+                }
+                service::build_full(
+                    config,
+                    cli.eth,
+                    cli.sealing,
+                    cli.index_accounts,
+                    cli.epoch_history,
+                    rpc_policy,
+                    cli.tx_pool_policy,
+                    cli.snapshot,
+                )
+                .map_err(Into::into)
+                .await
             })
         }
     }
@@ -0,0 +1,223 @@
+//! Optional node-side history of the weights and bonds matrices of every epoch, enabled with
+//! `--epoch-history <EPOCHS>`.
+//!
+//! Keeping the matrices in the state history makes them dominate the storage of archive nodes.
+//! With this mode the runtime writes the matrices each epoch starts from, and their summary, to
+//! the offchain index instead, so the node can run with a pruned state (`--state-pruning`) and
+//! keep only the matrices of the last `EPOCHS` epochs of each subnet. Summaries are never pruned.
+//!
+//! The index is written when blocks are imported and pruned as they are finalized. Only epochs
+//! imported while the mode is enabled are recorded.
+
+use std::sync::{Arc, Mutex};
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_subtensor_runtime::{opaque::Block, pallet_subtensor::epoch::history::EpochHistoryKey};
+use sc_client_api::BlockchainEvents;
+use sc_telemetry::log;
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::Header;
+use subtensor_runtime_common::NetUid;
+
+use crate::client::{FullBackend, FullClient};
+
+/// Prefix of the entries the node keeps about the epoch history.
+const NODE_PREFIX: &[u8] = b"subtensor::epoch_history::node";
+
+/// The epoch history kept in the offchain database of a full node.
+pub type FullEpochHistory =
+    EpochHistory<<FullBackend as sc_client_api::Backend<Block>>::OffchainStorage>;
+
+#[derive(Encode, Decode)]
+enum NodeKey {
+    /// Last finalized block whose epochs were recorded.
+    LastProcessed,
+    /// [`SubnetHistory`] of a subnet.
+    Subnet(NetUid),
+}
+
+impl NodeKey {
+    fn key(&self) -> Vec<u8> {
+        (NODE_PREFIX, self).encode()
+    }
+}
+
+/// Blocks at which the epoch of a subnet ran, of which the first `pruned` lost their matrices.
+#[derive(Encode, Decode, Default)]
+struct SubnetHistory {
+    epochs: Vec<u64>,
+    pruned: u32,
+}
+
+/// Blocks whose epoch history is available for a subnet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochHistoryRange {
+    /// Number of epochs with a summary.
+    pub epochs: u32,
+    /// Block of the first epoch with a summary.
+    pub first_summary: u64,
+    /// Block of the first epoch whose matrices are retained, if any.
+    pub first_matrices: Option<u64>,
+    /// Block of the last epoch.
+    pub last: u64,
+}
+
+/// Epoch history kept in an offchain database.
+pub struct EpochHistory<S> {
+    storage: Mutex<S>,
+    retain: u32,
+}
+
+impl<S: OffchainStorage> EpochHistory<S> {
+    /// Keeps the matrices of the last `retain` epochs of each subnet in `storage`.
+    pub fn new(storage: S, retain: u32) -> Self {
+        Self {
+            storage: Mutex::new(storage),
+            retain,
+        }
+    }
+
+    fn storage(&self) -> std::sync::MutexGuard<'_, S> {
+        self.storage.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+        let data = self.storage().get(STORAGE_PREFIX, key)?;
+        T::decode(&mut &data[..]).ok()
+    }
+
+    /// Last finalized block whose epochs were recorded.
+    pub fn last_processed(&self) -> Option<u64> {
+        self.get(&NodeKey::LastProcessed.key())
+    }
+
+    /// Records the epochs that ran at finalized block `number`, pruning the matrices of the
+    /// epochs that are no longer retained.
+    pub fn process_block(&self, number: u64) {
+        let netuids: Vec<NetUid> = self
+            .get(&EpochHistoryKey::Epochs(number).key())
+            .unwrap_or_default();
+        for netuid in netuids {
+            let key = NodeKey::Subnet(netuid).key();
+            let mut history: SubnetHistory = self.get(&key).unwrap_or_default();
+            if history.epochs.last().is_some_and(|last| *last >= number) {
+                continue;
+            }
+            history.epochs.push(number);
+
+            let mut storage = self.storage();
+            while (history.epochs.len() as u64).saturating_sub(history.pruned.into())
+                > self.retain.into()
+            {
+                if let Some(block) = history.epochs.get(history.pruned as usize) {
+                    let matrices = EpochHistoryKey::Matrices(netuid, *block).key();
+                    storage.remove(STORAGE_PREFIX, &matrices);
+                }
+                history.pruned = history.pruned.saturating_add(1);
+            }
+            storage.set(STORAGE_PREFIX, &key, &history.encode());
+        }
+
+        self.storage().set(
+            STORAGE_PREFIX,
+            &NodeKey::LastProcessed.key(),
+            &number.encode(),
+        );
+    }
+
+    /// Records every block up to and including `target` that has not been recorded yet.
+    pub fn catch_up(&self, target: u64) {
+        let start = self.last_processed().map_or(0, |n| n.saturating_add(1));
+        for number in start..=target {
+            self.process_block(number);
+        }
+    }
+
+    /// Blocks whose epoch history is available for `netuid`.
+    pub fn range(&self, netuid: NetUid) -> Option<EpochHistoryRange> {
+        let history: SubnetHistory = self.get(&NodeKey::Subnet(netuid).key())?;
+        Some(EpochHistoryRange {
+            epochs: history.epochs.len() as u32,
+            first_summary: *history.epochs.first()?,
+            first_matrices: history.epochs.get(history.pruned as usize).copied(),
+            last: *history.epochs.last()?,
+        })
+    }
+
+    /// The SCALE-encoded `(EpochMatricesSummary, Option<EpochMatrices>)` of the epoch of
+    /// `netuid` that ran at `block`.
+    pub fn epoch(&self, netuid: NetUid, block: u64) -> Option<Vec<u8>> {
+        let storage = self.storage();
+        let mut epoch = storage.get(
+            STORAGE_PREFIX,
+            &EpochHistoryKey::Summary(netuid, block).key(),
+        )?;
+        match storage.get(
+            STORAGE_PREFIX,
+            &EpochHistoryKey::Matrices(netuid, block).key(),
+        ) {
+            Some(matrices) => {
+                epoch.push(1);
+                epoch.extend(matrices);
+            }
+            None => epoch.push(0),
+        }
+        Some(epoch)
+    }
+}
+
+/// Background task that records the epochs of the finalized blocks.
+pub async fn run_epoch_history<S: OffchainStorage>(
+    history: Arc<EpochHistory<S>>,
+    client: Arc<FullClient>,
+) {
+    let mut finality = client.finality_notification_stream();
+
+    history.catch_up(client.info().finalized_number.into());
+    log::info!(
+        "Epoch history recorded up to block #{}",
+        history.last_processed().unwrap_or_default()
+    );
+
+    while let Some(notification) = finality.next().await {
+        history.catch_up((*notification.header.number()).into());
+    }
+}
+
+#[rpc(server)]
+pub trait EpochHistoryApi {
+    /// Returns the blocks whose epoch history is available for `netuid`.
+    #[method(name = "subtensor_getEpochHistoryRange")]
+    fn get_epoch_history_range(&self, netuid: u16) -> RpcResult<Option<EpochHistoryRange>>;
+
+    /// Returns the SCALE-encoded `(EpochMatricesSummary, Option<EpochMatrices>)` of the epoch of
+    /// `netuid` that ran at `block`, the matrices being `None` once pruned.
+    #[method(name = "subtensor_getEpochHistory")]
+    fn get_epoch_history(&self, netuid: u16, block: u64) -> RpcResult<Option<Vec<u8>>>;
+}
+
+/// RPC handler serving queries from the [`EpochHistory`].
+pub struct EpochHistoryRpc<S> {
+    history: Arc<EpochHistory<S>>,
+}
+
+impl<S> EpochHistoryRpc<S> {
+    pub fn new(history: Arc<EpochHistory<S>>) -> Self {
+        Self { history }
+    }
+}
+
+impl<S: OffchainStorage + 'static> EpochHistoryApiServer for EpochHistoryRpc<S> {
+    fn get_epoch_history_range(&self, netuid: u16) -> RpcResult<Option<EpochHistoryRange>> {
+        Ok(self.history.range(netuid.into()))
+    }
+
+    fn get_epoch_history(&self, netuid: u16, block: u64) -> RpcResult<Option<Vec<u8>>> {
+        Ok(self.history.epoch(netuid.into(), block))
+    }
+}
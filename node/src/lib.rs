@@ -1,6 +1,14 @@
+pub mod account_index;
 pub mod chain_spec;
+pub mod check_db;
 pub mod cli;
 pub mod client;
+pub mod epoch_history;
 pub mod ethereum;
+pub mod metrics;
 pub mod rpc;
+pub mod rpc_policy;
 pub mod service;
+pub mod slot_tracker;
+pub mod snapshot;
+pub mod tx_pool_policy;
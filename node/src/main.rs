@@ -1,7 +1,9 @@
 //! Substrate Node Subtensor CLI library.
 #![warn(missing_docs)]
 
+mod account_index;
 mod chain_spec;
+mod check_db;
 #[macro_use]
 mod service;
 #[cfg(feature = "runtime-benchmarks")]
@@ -9,8 +11,14 @@ mod benchmarking;
 mod cli;
 mod client;
 mod command;
+mod epoch_history;
 mod ethereum;
+mod metrics;
 mod rpc;
+mod rpc_policy;
+mod slot_tracker;
+mod snapshot;
+mod tx_pool_policy;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
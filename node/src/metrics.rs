@@ -0,0 +1,149 @@
+//! Prometheus metrics derived from the subtensor state and events of every new best block, so
+//! operators can alert on chain-level anomalies.
+//!
+//! The registry prefixes the metrics with `substrate_`. Only blocks that become the best block
+//! when imported are counted, so the counters may count blocks that are later reorganized away.
+
+use std::sync::Arc;
+
+use codec::Decode;
+use frame_support::dispatch::DispatchClass;
+use futures::StreamExt;
+use node_subtensor_runtime::{RuntimeEvent, pallet_subtensor::Event};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sc_telemetry::log;
+use sp_core::{storage::StorageKey, twox_128};
+use substrate_prometheus_endpoint::{Counter, Gauge, PrometheusError, Registry, U64, register};
+use subtensor_runtime_common::Hash;
+
+use crate::client::FullClient;
+
+type EventRecords = Vec<frame_system::EventRecord<RuntimeEvent, Hash>>;
+
+/// Subtensor metrics registered on the node's Prometheus registry.
+#[derive(Clone)]
+pub struct SubtensorMetrics {
+    neuron_registrations: Counter<U64>,
+    block_neuron_registrations: Gauge<U64>,
+    weights_set: Counter<U64>,
+    total_stake: Gauge<U64>,
+    subnets: Gauge<U64>,
+    block_mandatory_weight: Gauge<U64>,
+}
+
+impl SubtensorMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            neuron_registrations: register(
+                Counter::new(
+                    "subtensor_neuron_registrations_total",
+                    "Number of neurons registered",
+                )?,
+                registry,
+            )?,
+            block_neuron_registrations: register(
+                Gauge::new(
+                    "subtensor_block_neuron_registrations",
+                    "Number of neurons registered in the best block",
+                )?,
+                registry,
+            )?,
+            weights_set: register(
+                Counter::new(
+                    "subtensor_weights_set_total",
+                    "Number of weights set by neurons",
+                )?,
+                registry,
+            )?,
+            total_stake: register(
+                Gauge::new(
+                    "subtensor_total_stake",
+                    "Total TAO staked at the best block, in RAO",
+                )?,
+                registry,
+            )?,
+            subnets: register(
+                Gauge::new("subtensor_subnets", "Number of subnets at the best block")?,
+                registry,
+            )?,
+            block_mandatory_weight: register(
+                Gauge::new(
+                    "subtensor_block_mandatory_weight",
+                    "Ref time of the mandatory weight of the best block, mostly epochs and \
+                     emission run on initialization, in picoseconds",
+                )?,
+                registry,
+            )?,
+        })
+    }
+
+    /// Records the events of a block.
+    pub fn record_events(&self, records: &[frame_system::EventRecord<RuntimeEvent, Hash>]) {
+        let mut registrations = 0u64;
+        for record in records {
+            match &record.event {
+                RuntimeEvent::SubtensorModule(Event::NeuronRegistered(..)) => {
+                    registrations = registrations.saturating_add(1);
+                }
+                RuntimeEvent::SubtensorModule(Event::BulkNeuronsRegistered(_, count)) => {
+                    registrations = registrations.saturating_add(u64::from(*count));
+                }
+                RuntimeEvent::SubtensorModule(Event::WeightsSet(..)) => {
+                    self.weights_set.inc();
+                }
+                _ => {}
+            }
+        }
+        self.neuron_registrations.inc_by(registrations);
+        self.block_neuron_registrations.set(registrations);
+    }
+
+    fn record_block(&self, client: &FullClient, hash: Hash) {
+        let read = |pallet: &[u8], item: &[u8]| {
+            let key = StorageKey([twox_128(pallet), twox_128(item)].concat());
+            match client.storage(hash, &key) {
+                Ok(data) => data,
+                Err(e) => {
+                    log::debug!(
+                        "Unable to read {} at {hash:?}: {e:?}",
+                        String::from_utf8_lossy(item)
+                    );
+                    None
+                }
+            }
+        };
+
+        if let Some(data) = read(b"System", b"Events") {
+            match EventRecords::decode(&mut &data.0[..]) {
+                Ok(records) => self.record_events(&records),
+                Err(e) => log::debug!("Skipping events of block {hash:?}: {e:?}"),
+            }
+        }
+        if let Some(Ok(total_stake)) =
+            read(b"SubtensorModule", b"TotalStake").map(|data| u64::decode(&mut &data.0[..]))
+        {
+            self.total_stake.set(total_stake);
+        }
+        if let Some(Ok(subnets)) =
+            read(b"SubtensorModule", b"TotalNetworks").map(|data| u16::decode(&mut &data.0[..]))
+        {
+            self.subnets.set(subnets.into());
+        }
+        if let Some(Ok(weight)) = read(b"System", b"BlockWeight")
+            .map(|data| frame_system::ConsumedWeight::decode(&mut &data.0[..]))
+        {
+            self.block_mandatory_weight
+                .set(weight.get(DispatchClass::Mandatory).ref_time());
+        }
+    }
+}
+
+/// Background task recording the metrics of every new best block.
+pub async fn run_subtensor_metrics(metrics: SubtensorMetrics, client: Arc<FullClient>) {
+    let mut imports = client.import_notification_stream();
+    while let Some(notification) = imports.next().await {
+        if notification.is_new_best {
+            metrics.record_block(&client, notification.hash);
+        }
+    }
+}
@@ -25,8 +25,12 @@ use sp_runtime::{OpaqueExtrinsic, traits::BlakeTwo256, traits::Block as BlockT};
 use subtensor_runtime_common::Hash;
 
 use crate::{
+    account_index::AccountIndex,
     client::{FullBackend, FullClient},
+    epoch_history::FullEpochHistory,
     ethereum::create_eth,
+    rpc_policy::RpcPolicy,
+    slot_tracker::SlotTracker,
 };
 
 /// Extra dependencies for Ethereum compatibility.
@@ -90,6 +94,14 @@ pub struct FullDeps<P, CT, CIDP> {
     pub pool: Arc<P>,
     /// Manual seal command sink
     pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+    /// Account history index, when enabled with `--index-accounts`.
+    pub account_index: Option<Arc<AccountIndex>>,
+    /// Epoch history, when enabled with `--epoch-history`.
+    pub epoch_history: Option<Arc<FullEpochHistory>>,
+    /// Slots of the local validator keys, on authority nodes.
+    pub slot_tracker: Option<Arc<SlotTracker>>,
+    /// Methods served, from `--rpc-policy`.
+    pub rpc_policy: RpcPolicy,
     /// Ethereum-compatibility specific dependencies.
     pub eth: EthDeps<P, CT, CIDP>,
 }
@@ -115,22 +127,45 @@ where
     CIDP: CreateInherentDataProviders<Block, ()> + Send + Clone + 'static,
     CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + Clone + 'static,
 {
+    use crate::account_index::{AccountHistory, AccountHistoryApiServer};
+    use crate::epoch_history::{EpochHistoryApiServer, EpochHistoryRpc};
+    use crate::slot_tracker::{SlotStatsApiServer, SlotStatsRpc};
     use pallet_subtensor_swap_rpc::{Swap, SwapRpcApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
-    use subtensor_custom_rpc::{SubtensorCustom, SubtensorCustomApiServer};
+    use subtensor_custom_rpc::{
+        SubtensorCustom, SubtensorCustomApiServer,
+        epoch::{SubtensorEpoch, SubtensorEpochApiServer},
+    };
 
     let mut module = RpcModule::new(());
     let FullDeps {
         client,
         pool,
         command_sink,
+        account_index,
+        epoch_history,
+        slot_tracker,
+        rpc_policy,
         eth,
     } = deps;
 
     // Custom RPC methods for Paratensor
     module.merge(SubtensorCustom::new(client.clone()).into_rpc())?;
+    module.merge(
+        SubtensorEpoch::new(client.clone(), subscription_task_executor.clone()).into_rpc(),
+    )?;
+
+    if let Some(account_index) = account_index {
+        module.merge(AccountHistory::new(account_index).into_rpc())?;
+    }
+    if let Some(epoch_history) = epoch_history {
+        module.merge(EpochHistoryRpc::new(epoch_history).into_rpc())?;
+    }
+    if let Some(slot_tracker) = slot_tracker {
+        module.merge(SlotStatsRpc::new(slot_tracker).into_rpc())?;
+    }
 
     // Swap RPC
     module.merge(Swap::new(client.clone()).into_rpc())?;
@@ -152,12 +187,14 @@ where
     }
 
     // Ethereum compatibility RPCs
-    let module = create_eth::<_, _, _, DefaultEthConfig>(
+    let mut module = create_eth::<_, _, _, DefaultEthConfig>(
         module,
         eth,
         subscription_task_executor,
         pubsub_notification_sinks,
     )?;
 
+    rpc_policy.apply(&mut module);
+
     Ok(module)
 }
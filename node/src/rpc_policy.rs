@@ -0,0 +1,75 @@
+//! RPC access policy loaded from the file given with `--rpc-policy`, so public RPC nodes can
+//! limit what they serve without a proxy in front of them.
+//!
+//! The policy file is JSON:
+//!
+//! ```json
+//! {
+//!     "rateLimit": 600,
+//!     "rateLimitTrustProxyHeaders": false,
+//!     "allow": [],
+//!     "deny": ["subtensor_getAllMetagraphs", "debug_*"]
+//! }
+//! ```
+//!
+//! `rateLimit` is the number of calls per minute allowed on each connection, used when
+//! `--rpc-rate-limit` is not passed. Methods are matched by name, or by prefix with a trailing
+//! `*`. When `allow` is not empty only the methods it matches are served, and `deny` always
+//! wins. The lists apply to the methods the node adds on top of Substrate's, unsafe Substrate
+//! methods such as `author_insertKey` are covered by `--rpc-methods`.
+
+use std::{num::NonZeroU32, path::Path};
+
+use jsonrpsee::RpcModule;
+use sc_telemetry::log;
+use serde::Deserialize;
+
+/// Access policy for the RPC server.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RpcPolicy {
+    /// Calls per minute allowed on each connection.
+    #[serde(default)]
+    pub rate_limit: Option<NonZeroU32>,
+    /// Whether to use the `X-Real-IP` or `X-Forwarded-For` headers to identify clients.
+    #[serde(default)]
+    pub rate_limit_trust_proxy_headers: bool,
+    /// Methods served, all when empty.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Methods never served.
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl RpcPolicy {
+    /// Reads the policy from a JSON file.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let file = std::fs::read(path)
+            .map_err(|e| format!("Error reading RPC policy {}: {e}", path.display()))?;
+        serde_json::from_slice(&file)
+            .map_err(|e| format!("Error parsing RPC policy {}: {e}", path.display()))
+    }
+
+    /// Whether `method` is served under this policy.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == pattern,
+        };
+
+        (self.allow.is_empty() || self.allow.iter().any(matches)) && !self.deny.iter().any(matches)
+    }
+
+    /// Removes the methods this policy does not serve from `module`.
+    pub fn apply<Context>(&self, module: &mut RpcModule<Context>) {
+        let denied = module
+            .method_names()
+            .filter(|method| !self.is_allowed(method))
+            .collect::<Vec<_>>();
+        for method in denied {
+            log::debug!("RPC method {method} denied by the RPC policy");
+            module.remove_method(method);
+        }
+    }
+}
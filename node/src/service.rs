@@ -25,13 +25,20 @@ use std::{cell::RefCell, path::Path};
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
+use crate::account_index::{AccountIndex, run_account_indexer};
 use crate::cli::Sealing;
 use crate::client::{FullBackend, FullClient, HostFunctions, RuntimeExecutor};
+use crate::epoch_history::{EpochHistory, run_epoch_history};
 use crate::ethereum::{
     BackendType, EthConfiguration, FrontierBackend, FrontierBlockImport, FrontierPartialComponents,
     StorageOverride, StorageOverrideHandler, db_config_dir, new_frontier_partial,
     spawn_frontier_tasks,
 };
+use crate::metrics::{SubtensorMetrics, run_subtensor_metrics};
+use crate::rpc_policy::RpcPolicy;
+use crate::slot_tracker::{SlotMetrics, SlotTracker, run_slot_tracker};
+use crate::snapshot::{SnapshotParams, run_snapshots};
+use crate::tx_pool_policy::{TxPoolPolicy, run_tx_pool_policy};
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -340,10 +347,16 @@ pub fn build_manual_seal_import_queue(
 }
 
 /// Builds a new service for a full client.
+#[allow(clippy::too_many_arguments)]
 pub async fn new_full<NB>(
     mut config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    index_accounts: bool,
+    epoch_history: Option<u32>,
+    rpc_policy: RpcPolicy,
+    tx_pool_policy: TxPoolPolicy,
+    snapshot: SnapshotParams,
 ) -> Result<TaskManager, ServiceError>
 where
     NumberFor<Block>: BlockNumberOps,
@@ -404,11 +417,7 @@ where
     let warp_sync_config = if sealing.is_some() {
         None
     } else {
-        let set_id: u64 = if config.chain_spec.chain_type() == ChainType::Live {
-            3 // mainnet patch
-        } else {
-            2 // testnet patch
-        };
+        let set_id = crate::chain_spec::grandpa_initial_set_id(&*config.chain_spec);
         log::warn!(
             "Grandpa warp sync patch enabled. Chain type = {:?}. Set ID = {set_id}",
             config.chain_spec.chain_type()
@@ -507,6 +516,83 @@ where
     // for ethereum-compatibility rpc.
     config.rpc.id_provider = Some(Box::new(fc_rpc::EthereumSubIdProvider));
 
+    let account_index = if index_accounts {
+        let index = Arc::new(AccountIndex::default());
+        task_manager.spawn_handle().spawn_blocking(
+            "account-indexer",
+            None,
+            run_account_indexer(index.clone(), client.clone()),
+        );
+        Some(index)
+    } else {
+        None
+    };
+
+    let epoch_history = match (epoch_history, backend.offchain_storage()) {
+        (Some(retain), Some(storage)) => {
+            let history = Arc::new(EpochHistory::new(storage, retain));
+            task_manager.spawn_handle().spawn_blocking(
+                "epoch-history",
+                None,
+                run_epoch_history(history.clone(), client.clone()),
+            );
+            Some(history)
+        }
+        _ => None,
+    };
+
+    if tx_pool_policy.is_enabled() {
+        task_manager.spawn_handle().spawn(
+            "tx-pool-policy",
+            None,
+            run_tx_pool_policy(tx_pool_policy, transaction_pool.clone()),
+        );
+    }
+
+    if let Some(interval) = snapshot.snapshot_interval {
+        let dir = snapshot
+            .snapshot_path
+            .clone()
+            .unwrap_or_else(|| config.data_path.join("snapshots"));
+        task_manager.spawn_handle().spawn_blocking(
+            "snapshots",
+            None,
+            run_snapshots(snapshot, interval, dir, client.clone()),
+        );
+    }
+
+    if let Some(registry) = prometheus_registry.as_ref() {
+        let metrics = SubtensorMetrics::register(registry)?;
+        task_manager.spawn_handle().spawn(
+            "subtensor-metrics",
+            None,
+            run_subtensor_metrics(metrics, client.clone()),
+        );
+    }
+
+    let slot_tracker = if role.is_authority() {
+        let metrics = prometheus_registry
+            .as_ref()
+            .map(SlotMetrics::register)
+            .transpose()?;
+        let tracker = Arc::new(SlotTracker::new(
+            metrics,
+            telemetry.as_ref().map(|telemetry| telemetry.handle()),
+        ));
+        task_manager.spawn_handle().spawn(
+            "slot-tracker",
+            None,
+            run_slot_tracker(
+                tracker.clone(),
+                client.clone(),
+                keystore_container.keystore(),
+            ),
+        );
+        Some(tracker)
+    } else {
+        None
+    };
+
     let rpc_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
@@ -577,6 +663,10 @@ where
                 } else {
                     None
                 },
+                account_index: account_index.clone(),
+                slot_tracker: slot_tracker.clone(),
+                epoch_history: epoch_history.clone(),
+                rpc_policy: rpc_policy.clone(),
                 eth: eth_deps,
             };
             crate::rpc::create_full(
@@ -731,17 +821,43 @@ where
     Ok(task_manager)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn build_full(
     config: Configuration,
     eth_config: EthConfiguration,
     sealing: Option<Sealing>,
+    index_accounts: bool,
+    epoch_history: Option<u32>,
+    rpc_policy: RpcPolicy,
+    tx_pool_policy: TxPoolPolicy,
+    snapshot: SnapshotParams,
 ) -> Result<TaskManager, ServiceError> {
     match config.network.network_backend {
         sc_network::config::NetworkBackendType::Libp2p => {
-            new_full::<sc_network::NetworkWorker<_, _>>(config, eth_config, sealing).await
+            new_full::<sc_network::NetworkWorker<_, _>>(
+                config,
+                eth_config,
+                sealing,
+                index_accounts,
+                epoch_history,
+                rpc_policy,
+                tx_pool_policy,
+                snapshot,
+            )
+            .await
         }
         sc_network::config::NetworkBackendType::Litep2p => {
-            new_full::<sc_network::Litep2pNetworkBackend>(config, eth_config, sealing).await
+            new_full::<sc_network::Litep2pNetworkBackend>(
+                config,
+                eth_config,
+                sealing,
+                index_accounts,
+                epoch_history,
+                rpc_policy,
+                tx_pool_policy,
+                snapshot,
+            )
+            .await
         }
     }
 }
@@ -771,6 +887,21 @@ pub fn new_chain_ops(
     Ok((client, backend, import_queue, task_manager, other.3))
 }
 
+/// Builds the client and block import used to import a state snapshot.
+pub fn new_snapshot_import(
+    config: &mut Configuration,
+    eth_config: &EthConfiguration,
+) -> Result<(Arc<FullClient>, BoxBlockImport<Block>, TaskManager), ServiceError> {
+    config.keystore = sc_service::config::KeystoreConfig::InMemory;
+    let PartialComponents {
+        client,
+        task_manager,
+        other: (_, block_import, ..),
+        ..
+    } = new_partial(config, eth_config, build_aura_grandpa_import_queue)?;
+    Ok((client, block_import, task_manager))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn run_manual_seal_authorship(
     sealing: Sealing,
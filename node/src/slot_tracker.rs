@@ -0,0 +1,280 @@
+//! Tracking of the Aura slots of the local validator keys, so operators get early warning of
+//! keystore or clock issues.
+//!
+//! Every new best block is checked against its parent: the slots in between had no block, and
+//! those belonging to a local key are counted as missed, while the block's own slot is counted as
+//! authored when it belongs to a local key. The counts are exported to Prometheus, reported to
+//! telemetry and served by the `subtensor_getSlotStats` RPC. They are kept in memory from the
+//! node start, and blocks later reorganized away are still counted.
+
+use std::sync::{Arc, RwLock};
+
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_subtensor_runtime::opaque::Block;
+use sc_client_api::BlockchainEvents;
+use sc_telemetry::{CONSENSUS_INFO, CONSENSUS_WARN, TelemetryHandle, log, telemetry};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::{
+    AuraApi, Slot,
+    sr25519::{AuthorityId as AuraId, AuthoritySignature as AuraSignature},
+};
+use sp_core::crypto::key_types::AURA;
+use sp_keystore::{Keystore, KeystorePtr};
+use sp_runtime::traits::Header;
+use substrate_prometheus_endpoint::{Counter, Gauge, PrometheusError, Registry, U64, register};
+use subtensor_runtime_common::{BlockNumber, Hash};
+
+use crate::client::FullClient;
+
+/// Slots of the local validator keys since the node started.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotStats {
+    /// Whether a key of the keystore is in the authority set of the best block.
+    pub is_authority: bool,
+    /// Slots of the local keys with a block.
+    pub authored: u64,
+    /// Slots of the local keys without a block.
+    pub missed: u64,
+    /// Last slot of the local keys with a block, and the block number.
+    pub last_authored: Option<(u64, BlockNumber)>,
+    /// Last slot of the local keys without a block.
+    pub last_missed: Option<u64>,
+    /// Slots between the local clock and the best block when it was imported.
+    pub slot_lag: u64,
+}
+
+/// Number of slots `slot` such that `slot % authorities == index` in `0..end`.
+fn count_slots(end: u64, authorities: u64, index: u64) -> u64 {
+    end / authorities + u64::from(end % authorities > index)
+}
+
+impl SlotStats {
+    /// Records a block at `slot` whose parent is at `parent_slot`, with `local` the indexes of
+    /// the local keys in the `authorities` of the block.
+    pub fn record_block(
+        &mut self,
+        number: BlockNumber,
+        slot: u64,
+        parent_slot: Option<u64>,
+        authorities: u64,
+        local: &[u64],
+    ) -> (bool, u64) {
+        self.is_authority = !local.is_empty();
+        if authorities == 0 {
+            return (false, 0);
+        }
+
+        // Slots without a block are those strictly between the parent and the block.
+        let first_empty = parent_slot.map_or(slot, |parent| parent.saturating_add(1).min(slot));
+        let mut missed = 0u64;
+        for index in local {
+            let count = count_slots(slot, authorities, *index).saturating_sub(count_slots(
+                first_empty,
+                authorities,
+                *index,
+            ));
+            if count > 0 {
+                missed = missed.saturating_add(count);
+                let last = slot
+                    .saturating_sub(1)
+                    .saturating_sub(slot.saturating_sub(1).saturating_sub(*index) % authorities);
+                self.last_missed = self.last_missed.max(Some(last));
+            }
+        }
+        self.missed = self.missed.saturating_add(missed);
+
+        let authored = local.contains(&(slot % authorities));
+        if authored {
+            self.authored = self.authored.saturating_add(1);
+            self.last_authored = Some((slot, number));
+        }
+        (authored, missed)
+    }
+}
+
+/// Prometheus metrics of the [`SlotTracker`].
+#[derive(Clone)]
+pub struct SlotMetrics {
+    authored: Counter<U64>,
+    missed: Counter<U64>,
+    slot_lag: Gauge<U64>,
+}
+
+impl SlotMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            authored: register(
+                Counter::new(
+                    "subtensor_aura_slots_authored_total",
+                    "Slots of the local validator keys with a block",
+                )?,
+                registry,
+            )?,
+            missed: register(
+                Counter::new(
+                    "subtensor_aura_slots_missed_total",
+                    "Slots of the local validator keys without a block",
+                )?,
+                registry,
+            )?,
+            slot_lag: register(
+                Gauge::new(
+                    "subtensor_aura_slot_lag",
+                    "Slots between the local clock and the best block when it was imported",
+                )?,
+                registry,
+            )?,
+        })
+    }
+}
+
+/// Tracker of the slots of the local validator keys.
+pub struct SlotTracker {
+    stats: RwLock<SlotStats>,
+    metrics: Option<SlotMetrics>,
+    telemetry: Option<TelemetryHandle>,
+}
+
+impl SlotTracker {
+    pub fn new(metrics: Option<SlotMetrics>, telemetry: Option<TelemetryHandle>) -> Self {
+        Self {
+            stats: RwLock::default(),
+            metrics,
+            telemetry,
+        }
+    }
+
+    /// Slots of the local validator keys since the node started.
+    pub fn stats(&self) -> SlotStats {
+        self.stats.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn slot(client: &FullClient, hash: Hash) -> Option<Slot> {
+        let header = client.header(hash).ok()??;
+        sc_consensus_aura::find_pre_digest::<Block, AuraSignature>(&header).ok()
+    }
+
+    fn track_block(
+        &self,
+        client: &FullClient,
+        keystore: &KeystorePtr,
+        hash: Hash,
+        slot_duration: sp_consensus_aura::SlotDuration,
+    ) {
+        let Ok(Some(header)) = client.header(hash) else {
+            return;
+        };
+        let Ok(slot) = sc_consensus_aura::find_pre_digest::<Block, AuraSignature>(&header) else {
+            return;
+        };
+        let parent = *header.parent_hash();
+        let authorities = match client.runtime_api().authorities(parent) {
+            Ok(authorities) => authorities,
+            Err(e) => {
+                log::debug!("Unable to read the Aura authorities at {parent:?}: {e:?}");
+                return;
+            }
+        };
+        let keys = keystore.sr25519_public_keys(AURA);
+        let local = authorities
+            .iter()
+            .enumerate()
+            .filter(|(_, authority)| keys.iter().any(|key| AuraId::from(*key) == **authority))
+            .map(|(index, _)| index as u64)
+            .collect::<Vec<_>>();
+
+        let now = Slot::from_timestamp(sp_timestamp::Timestamp::current(), slot_duration);
+        let slot_lag = u64::from(now).saturating_sub(slot.into());
+        let (authored, missed) = {
+            let mut stats = self.stats.write().unwrap_or_else(|e| e.into_inner());
+            stats.slot_lag = slot_lag;
+            stats.record_block(
+                *header.number(),
+                slot.into(),
+                Self::slot(client, parent).map(Into::into),
+                authorities.len() as u64,
+                &local,
+            )
+        };
+
+        if let Some(metrics) = &self.metrics {
+            metrics.slot_lag.set(slot_lag);
+            metrics.missed.inc_by(missed);
+            if authored {
+                metrics.authored.inc();
+            }
+        }
+        if authored {
+            telemetry!(
+                self.telemetry;
+                CONSENSUS_INFO;
+                "subtensor.slot_authored";
+                "slot" => u64::from(slot),
+                "number" => *header.number()
+            );
+        }
+        if missed > 0 {
+            log::warn!(
+                "⚠️ The local validator keys missed {missed} slot(s) before slot {}",
+                u64::from(slot)
+            );
+            telemetry!(
+                self.telemetry;
+                CONSENSUS_WARN;
+                "subtensor.slots_missed";
+                "slot" => u64::from(slot),
+                "missed" => missed
+            );
+        }
+    }
+}
+
+/// Background task tracking the slots of the new best blocks.
+pub async fn run_slot_tracker(
+    tracker: Arc<SlotTracker>,
+    client: Arc<FullClient>,
+    keystore: KeystorePtr,
+) {
+    let slot_duration = match sc_consensus_aura::slot_duration(&*client) {
+        Ok(slot_duration) => slot_duration,
+        Err(e) => {
+            log::warn!("Slot tracking disabled, unable to read the slot duration: {e:?}");
+            return;
+        }
+    };
+
+    let mut imports = client.import_notification_stream();
+    while let Some(notification) = imports.next().await {
+        if notification.is_new_best {
+            tracker.track_block(&client, &keystore, notification.hash, slot_duration);
+        }
+    }
+}
+
+#[rpc(server)]
+pub trait SlotStatsApi {
+    /// Returns the authored and missed slots of the local validator keys since the node started.
+    #[method(name = "subtensor_getSlotStats")]
+    fn get_slot_stats(&self) -> RpcResult<SlotStats>;
+}
+
+/// RPC handler serving the [`SlotStats`] of a [`SlotTracker`].
+pub struct SlotStatsRpc {
+    tracker: Arc<SlotTracker>,
+}
+
+impl SlotStatsRpc {
+    pub fn new(tracker: Arc<SlotTracker>) -> Self {
+        Self { tracker }
+    }
+}
+
+impl SlotStatsApiServer for SlotStatsRpc {
+    fn get_slot_stats(&self) -> RpcResult<SlotStats> {
+        Ok(self.tracker.stats())
+    }
+}
@@ -0,0 +1,320 @@
+//! Periodic state snapshots, enabled with `--snapshot-interval`, and their import into a new
+//! node with `import-snapshot`.
+//!
+//! A snapshot is the SCALE-encoded header and raw state of a finalized block, written next to a
+//! JSON manifest with the block hash, the runtime version and the hash of the snapshot file. The
+//! state is read from the finalized block, so it is consistent whatever the node imports while
+//! it is written. Importing a snapshot sets the finalized head of an empty database to that
+//! block, like warp sync does, and the node syncs the rest of the chain from the network.
+
+use std::{
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Arc,
+};
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use node_subtensor_runtime::opaque::Block;
+use sc_client_api::{BlockchainEvents, KeyValueStates, KeyValueStorageLevel};
+use sc_consensus::{
+    BlockImport, BlockImportParams, BoxBlockImport, ForkChoiceStrategy, ImportResult,
+    ImportedState, StateAction, StorageChanges,
+};
+use sc_telemetry::log;
+use serde::{Deserialize, Serialize};
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_consensus::BlockOrigin;
+use sp_core::{hashing::blake2_256, storage::Storage};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use subtensor_runtime_common::{BlockNumber, Hash};
+
+use crate::{cli::ImportSnapshotCmd, client::FullClient};
+
+type Header = <Block as BlockT>::Header;
+type KeyValues = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Name of the manifest of the last snapshot taken.
+pub const LATEST_MANIFEST: &str = "latest.json";
+
+/// Periodic snapshots of the finalized state, none by default.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct SnapshotParams {
+    /// Take a snapshot of the finalized state every BLOCKS blocks.
+    #[arg(long, value_name = "BLOCKS")]
+    pub snapshot_interval: Option<NonZeroU32>,
+
+    /// Directory the snapshots are written to, `snapshots` in the chain's data directory by
+    /// default.
+    #[arg(long, value_name = "PATH")]
+    pub snapshot_path: Option<PathBuf>,
+
+    /// S3 location, such as `s3://bucket/prefix`, the snapshots are uploaded to with the `aws`
+    /// CLI.
+    #[arg(long, value_name = "URL")]
+    pub snapshot_s3: Option<String>,
+
+    /// Number of snapshots kept in the snapshot directory.
+    #[arg(long, value_name = "COUNT", default_value_t = 2)]
+    pub snapshot_keep: usize,
+}
+
+/// Header and raw state of a finalized block.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Snapshot {
+    pub header: Header,
+    pub top: KeyValues,
+    /// Default child tries, by prefixed storage key.
+    pub children: Vec<(Vec<u8>, KeyValues)>,
+}
+
+/// Description of a snapshot file, written next to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    /// Name of the snapshot file, relative to the manifest.
+    pub file: String,
+    pub number: BlockNumber,
+    pub hash: Hash,
+    pub state_root: Hash,
+    pub spec_name: String,
+    pub spec_version: u32,
+    /// Blake2-256 hash of the snapshot file, hex encoded.
+    pub file_hash: String,
+}
+
+impl Snapshot {
+    pub fn new(header: Header, storage: Storage) -> Self {
+        Self {
+            header,
+            top: storage.top.into_iter().collect(),
+            children: storage
+                .children_default
+                .into_values()
+                .map(|child| {
+                    (
+                        child.child_info.prefixed_storage_key().into_inner(),
+                        child.data.into_iter().collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Import of the snapshot block with its state, as the finalized head.
+    pub fn into_import_params(self) -> BlockImportParams<Block> {
+        let hash = self.header.hash();
+        let mut levels = vec![KeyValueStorageLevel {
+            state_root: Vec::new(),
+            parent_storage_keys: Vec::new(),
+            key_values: self.top,
+        }];
+        levels.extend(
+            self.children
+                .into_iter()
+                .map(|(key, key_values)| KeyValueStorageLevel {
+                    state_root: Vec::new(),
+                    parent_storage_keys: vec![key],
+                    key_values,
+                }),
+        );
+
+        let mut params = BlockImportParams::new(BlockOrigin::NetworkInitialSync, self.header);
+        params.state_action = StateAction::ApplyChanges(StorageChanges::Import(ImportedState {
+            block: hash,
+            state: KeyValueStates(levels),
+        }));
+        params.finalized = true;
+        params.fork_choice = Some(ForkChoiceStrategy::Custom(true));
+        params
+    }
+}
+
+impl SnapshotManifest {
+    /// Reads a manifest and the snapshot it describes, checking the snapshot matches it.
+    pub fn read(path: &Path) -> Result<(Self, Snapshot), String> {
+        let manifest = std::fs::read(path)
+            .map_err(|e| format!("Error reading manifest {}: {e}", path.display()))?;
+        let manifest: Self = serde_json::from_slice(&manifest)
+            .map_err(|e| format!("Error parsing manifest {}: {e}", path.display()))?;
+
+        let file = path.with_file_name(&manifest.file);
+        let data = std::fs::read(&file)
+            .map_err(|e| format!("Error reading snapshot {}: {e}", file.display()))?;
+        if hex::encode(blake2_256(&data)) != manifest.file_hash {
+            return Err(format!(
+                "Snapshot {} does not match its manifest",
+                file.display()
+            ));
+        }
+        let snapshot = Snapshot::decode(&mut &data[..])
+            .map_err(|e| format!("Error decoding snapshot {}: {e}", file.display()))?;
+        if snapshot.header.hash() != manifest.hash {
+            return Err(format!(
+                "Snapshot {} is not of block {:?}",
+                file.display(),
+                manifest.hash
+            ));
+        }
+
+        Ok((manifest, snapshot))
+    }
+}
+
+fn write_atomically(path: &Path, data: &[u8]) -> Result<(), String> {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, data)
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .map_err(|e| format!("Error writing {}: {e}", path.display()))
+}
+
+/// Takes a snapshot of the finalized block `hash` into `dir`, returning its manifest.
+pub fn take_snapshot(
+    client: &Arc<FullClient>,
+    hash: Hash,
+    dir: &Path,
+) -> Result<SnapshotManifest, String> {
+    let header = client
+        .header(hash)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Unknown block {hash:?}"))?;
+    let version = client
+        .runtime_api()
+        .version(hash)
+        .map_err(|e| e.to_string())?;
+    let storage =
+        sc_service::chain_ops::export_raw_state(client.clone(), hash).map_err(|e| e.to_string())?;
+
+    let number = *header.number();
+    let state_root = *header.state_root();
+    let data = Snapshot::new(header, storage).encode();
+    let manifest = SnapshotManifest {
+        file: format!("snapshot-{number}.scale"),
+        number,
+        hash,
+        state_root,
+        spec_name: version.spec_name.to_string(),
+        spec_version: version.spec_version,
+        file_hash: hex::encode(blake2_256(&data)),
+    };
+    let json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("Error creating {}: {e}", dir.display()))?;
+    write_atomically(&dir.join(&manifest.file), &data)?;
+    write_atomically(&dir.join(format!("snapshot-{number}.json")), &json)?;
+    write_atomically(&dir.join(LATEST_MANIFEST), &json)?;
+    Ok(manifest)
+}
+
+/// Uploads the snapshot of `manifest` to `url`, the latest manifest last so it never points to
+/// a partial upload.
+fn upload_snapshot(dir: &Path, manifest: &SnapshotManifest, url: &str) -> Result<(), String> {
+    let url = url.trim_end_matches('/');
+    let files = [
+        manifest.file.clone(),
+        format!("snapshot-{}.json", manifest.number),
+        LATEST_MANIFEST.to_string(),
+    ];
+    for file in files {
+        let status = Command::new("aws")
+            .args(["s3", "cp", "--only-show-errors"])
+            .arg(dir.join(&file))
+            .arg(format!("{url}/{file}"))
+            .status()
+            .map_err(|e| format!("Error running the aws CLI: {e}"))?;
+        if !status.success() {
+            return Err(format!("Uploading {file} to {url} failed with {status}"));
+        }
+    }
+    Ok(())
+}
+
+/// Removes all but the last `keep` snapshots from `dir`.
+fn remove_old_snapshots(dir: &Path, keep: usize) -> Result<(), String> {
+    let mut numbers = std::fs::read_dir(dir)
+        .map_err(|e| format!("Error reading {}: {e}", dir.display()))?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix("snapshot-")?
+                .strip_suffix(".json")?
+                .parse::<BlockNumber>()
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    numbers.sort_unstable();
+
+    for number in numbers.iter().rev().skip(keep) {
+        for file in [
+            format!("snapshot-{number}.scale"),
+            format!("snapshot-{number}.json"),
+        ] {
+            let _ = std::fs::remove_file(dir.join(file));
+        }
+    }
+    Ok(())
+}
+
+/// Background task taking a snapshot of the finalized state every `interval` blocks.
+pub async fn run_snapshots(
+    params: SnapshotParams,
+    interval: NonZeroU32,
+    dir: PathBuf,
+    client: Arc<FullClient>,
+) {
+    let interval = interval.get();
+    let mut finality = client.finality_notification_stream();
+    let mut last = client.info().finalized_number / interval;
+
+    while let Some(notification) = finality.next().await {
+        let number = *notification.header.number();
+        if number / interval <= last {
+            continue;
+        }
+        last = number / interval;
+
+        log::info!("Taking a snapshot of block #{number}...");
+        let result = take_snapshot(&client, notification.hash, &dir).and_then(|manifest| {
+            if let Some(url) = &params.snapshot_s3 {
+                upload_snapshot(&dir, &manifest, url)?;
+            }
+            remove_old_snapshots(&dir, params.snapshot_keep.max(1))
+        });
+        match result {
+            Ok(()) => log::info!("Snapshot of block #{number} written to {}", dir.display()),
+            Err(e) => log::warn!("Snapshot of block #{number} failed: {e}"),
+        }
+    }
+}
+
+impl ImportSnapshotCmd {
+    /// Imports the snapshot described by the manifest into an empty database.
+    pub async fn run(
+        &self,
+        client: Arc<FullClient>,
+        block_import: BoxBlockImport<Block>,
+    ) -> sc_cli::Result<()> {
+        if client.info().best_number != 0 {
+            return Err("import-snapshot requires an empty database".into());
+        }
+
+        let (manifest, snapshot) = SnapshotManifest::read(&self.manifest)?;
+        log::info!(
+            "Importing the snapshot of block #{} ({:?}), {} {}",
+            manifest.number,
+            manifest.hash,
+            manifest.spec_name,
+            manifest.spec_version
+        );
+
+        match block_import
+            .import_block(snapshot.into_import_params())
+            .await
+            .map_err(|e| format!("Error importing the snapshot: {e}"))?
+        {
+            ImportResult::Imported(_) => Ok(()),
+            result => Err(format!("Snapshot not imported: {result:?}").into()),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Node-local transaction pool policy limiting the registration and weights transactions pending
+//! in this node's pool, so registration storms cannot exhaust the pool of a block author.
+//!
+//! The runtime validates every transaction on its own and cannot see the pool, so the limits are
+//! enforced by a task following the pool imports. A ready transaction that takes a hotkey or a
+//! subnet over its limit is reported invalid, which removes it from the pool and bans it for
+//! `--tx-ban-seconds`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use node_subtensor_runtime::{RuntimeCall, UncheckedExtrinsic, opaque::Block, pallet_subtensor};
+use sc_telemetry::log;
+use sc_transaction_pool::TransactionPoolHandle;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TxInvalidityReportMap};
+use sp_runtime::{MultiAddress, generic::Preamble, traits::Block as BlockT};
+use subtensor_runtime_common::{AccountId, NetUid};
+
+use crate::client::FullClient;
+
+/// Limits on the transactions pending in the pool, none by default.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct TxPoolPolicy {
+    /// Maximum number of registrations pending in the transaction pool on each subnet.
+    #[arg(long)]
+    pub pool_max_registrations_per_netuid: Option<usize>,
+
+    /// Maximum number of registrations of each hotkey pending in the transaction pool.
+    #[arg(long)]
+    pub pool_max_registrations_per_hotkey: Option<usize>,
+
+    /// Maximum number of weights transactions of each signer pending in the transaction pool on
+    /// each subnet.
+    #[arg(long)]
+    pub pool_max_weights_per_hotkey: Option<usize>,
+}
+
+/// What a limit of the [`TxPoolPolicy`] is counted by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PolicyKey {
+    SubnetRegistrations(NetUid),
+    HotkeyRegistrations(AccountId),
+    HotkeyWeights(AccountId, NetUid),
+}
+
+impl TxPoolPolicy {
+    /// Whether any limit is set.
+    pub fn is_enabled(&self) -> bool {
+        self.pool_max_registrations_per_netuid.is_some()
+            || self.pool_max_registrations_per_hotkey.is_some()
+            || self.pool_max_weights_per_hotkey.is_some()
+    }
+
+    /// The limit on the transactions counted by `key`.
+    pub fn limit(&self, key: &PolicyKey) -> Option<usize> {
+        match key {
+            PolicyKey::SubnetRegistrations(_) => self.pool_max_registrations_per_netuid,
+            PolicyKey::HotkeyRegistrations(_) => self.pool_max_registrations_per_hotkey,
+            PolicyKey::HotkeyWeights(..) => self.pool_max_weights_per_hotkey,
+        }
+    }
+
+    /// The keys of the limits `call`, signed by `signer`, counts towards.
+    pub fn limited_keys(&self, signer: Option<&AccountId>, call: &RuntimeCall) -> Vec<PolicyKey> {
+        use pallet_subtensor::Call;
+
+        let RuntimeCall::SubtensorModule(call) = call else {
+            return Vec::new();
+        };
+        let keys = match call {
+            Call::register { netuid, hotkey, .. }
+            | Call::burned_register { netuid, hotkey }
+            | Call::sponsored_burned_register { netuid, hotkey, .. } => vec![
+                PolicyKey::SubnetRegistrations(*netuid),
+                PolicyKey::HotkeyRegistrations(hotkey.clone()),
+            ],
+            Call::set_weights { netuid, .. }
+            | Call::set_weights_delta { netuid, .. }
+            | Call::commit_weights { netuid, .. }
+            | Call::reveal_weights { netuid, .. }
+            | Call::batch_reveal_weights { netuid, .. }
+            | Call::commit_crv3_weights { netuid, .. }
+            | Call::commit_timelocked_weights { netuid, .. } => signer
+                .map(|signer| PolicyKey::HotkeyWeights(signer.clone(), *netuid))
+                .into_iter()
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        keys.into_iter()
+            .filter(|key| self.limit(key).is_some())
+            .collect()
+    }
+
+    fn extrinsic_keys(&self, extrinsic: &<Block as BlockT>::Extrinsic) -> Vec<PolicyKey> {
+        let Ok(extrinsic) = UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..]) else {
+            return Vec::new();
+        };
+        let signer = match &extrinsic.0.preamble {
+            Preamble::Signed(MultiAddress::Id(signer), ..) => Some(signer),
+            _ => None,
+        };
+        self.limited_keys(signer, &extrinsic.0.function)
+    }
+}
+
+/// Background task enforcing `policy` on the transactions imported into `pool`.
+pub async fn run_tx_pool_policy(
+    policy: TxPoolPolicy,
+    pool: Arc<TransactionPoolHandle<Block, FullClient>>,
+) {
+    let mut imports = pool.import_notification_stream();
+    // Keys of the ready transactions, kept across imports to decode each transaction once.
+    let mut ready_keys: HashMap<_, Vec<PolicyKey>> = HashMap::new();
+
+    while let Some(hash) = imports.next().await {
+        let Some(transaction) = pool.ready_transaction(&hash) else {
+            continue;
+        };
+        let keys = policy.extrinsic_keys(transaction.data());
+        if keys.is_empty() {
+            continue;
+        }
+
+        let ready = pool.ready().collect::<Vec<_>>();
+        let hashes = ready
+            .iter()
+            .map(|transaction| *transaction.hash())
+            .collect::<HashSet<_>>();
+        ready_keys.retain(|hash, _| hashes.contains(hash));
+        for transaction in ready {
+            ready_keys
+                .entry(*transaction.hash())
+                .or_insert_with(|| policy.extrinsic_keys(transaction.data()));
+        }
+
+        let exceeded = keys.iter().find(|key| {
+            let pending = ready_keys
+                .values()
+                .filter(|pending| pending.contains(key))
+                .count();
+            policy.limit(key).is_some_and(|limit| pending > limit)
+        });
+        if let Some(key) = exceeded {
+            log::debug!("Removing transaction {hash:?} from the pool, over the limit of {key:?}");
+            ready_keys.remove(&hash);
+            pool.report_invalid(None, TxInvalidityReportMap::from_iter([(hash, None)]))
+                .await;
+        }
+    }
+}
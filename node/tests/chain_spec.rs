@@ -1,3 +1,5 @@
+#![allow(clippy::arithmetic_side_effects, clippy::unwrap_used)]
+
 use sp_core::sr25519;
 // use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 // use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -52,3 +54,78 @@ fn test_authority_keys_from_seed_panics() {
     let bad_seed = "";
     authority_keys_from_seed(bad_seed);
 }
+
+#[test]
+fn test_generated_genesis() {
+    let params = generator::GeneratedSpecParams {
+        accounts: 3,
+        subnets: 2,
+        neurons: 4,
+        balance: 1_000,
+    };
+    let genesis = generator::generated_genesis(params);
+
+    // Alice, the accounts, and the owner and neuron coldkeys of every subnet.
+    let balances = genesis["balances"]["balances"].as_array().unwrap();
+    assert_eq!(balances.len(), 1 + 3 + 2 * (1 + 4));
+    assert_eq!(
+        genesis["subtensorModule"]["balancesIssuance"],
+        serde_json::json!(1_000 * balances.len() as u64)
+    );
+
+    let subnets = genesis["subtensorModule"]["subnets"].as_array().unwrap();
+    assert_eq!(subnets.len(), 2);
+    assert!(
+        subnets
+            .iter()
+            .all(|subnet| subnet[2].as_array().unwrap().len() == 4)
+    );
+
+    // The keys only depend on the parameters.
+    assert_eq!(genesis, generator::generated_genesis(params));
+}
+
+#[test]
+fn test_localnet_from_snapshot_copies_only_subtensor_storage() {
+    use sp_core::{hashing::twox_128, storage::Storage};
+    use sp_runtime::BuildStorage;
+
+    let subtensor_key = [twox_128(b"SubtensorModule"), twox_128(b"Tempo")].concat();
+    let system_key = [twox_128(b"System"), twox_128(b"Number")].concat();
+    let mut snapshot = Storage::default();
+    snapshot.top.insert(subtensor_key.clone(), vec![1]);
+    snapshot.top.insert(system_key.clone(), vec![2]);
+
+    let spec =
+        snapshot::localnet_from_snapshot(snapshot, &["SubtensorModule".to_string()]).unwrap();
+    let storage = spec.build_storage().unwrap();
+
+    assert_eq!(storage.top.get(&subtensor_key), Some(&vec![1]));
+    assert_ne!(storage.top.get(&system_key), Some(&vec![2]));
+    // The rest of the subtensor genesis is replaced by the snapshot.
+    let prefix = twox_128(b"SubtensorModule");
+    assert_eq!(
+        storage
+            .top
+            .keys()
+            .filter(|key| key.starts_with(&prefix))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_grandpa_initial_set_id() {
+    // Specs without the extension keep the testnet patch.
+    let localnet = localnet::localnet_config(true).unwrap();
+    assert_eq!(grandpa_initial_set_id(&localnet), 2);
+
+    // An exported spec can override it.
+    let mut json: serde_json::Value =
+        serde_json::from_str(&sc_service::ChainSpec::as_json(&localnet, false).unwrap()).unwrap();
+    json.as_object_mut()
+        .unwrap()
+        .insert("grandpaInitialSetId".into(), 0.into());
+    let spec = ChainSpec::from_json_bytes(json.to_string().into_bytes()).unwrap();
+    assert_eq!(grandpa_initial_set_id(&spec), 0);
+}
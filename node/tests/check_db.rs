@@ -0,0 +1,32 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Encode;
+use node_subtensor::{chain_spec::localnet, check_db};
+use sp_core::hashing::twox_128;
+use sp_runtime::BuildStorage;
+
+#[test]
+fn test_check_storage_reports_subnetwork_n_mismatch() {
+    let mut storage = localnet::localnet_config(true)
+        .unwrap()
+        .build_storage()
+        .unwrap();
+    let subnetwork_n_ok = |storage| {
+        check_db::check_storage(storage)
+            .into_iter()
+            .find(|check| check.name == "subnetwork_n")
+            .unwrap()
+            .ok
+    };
+    assert!(subnetwork_n_ok(storage.clone()));
+
+    // Root has no neurons registered in genesis.
+    let key = [
+        twox_128(b"SubtensorModule").as_slice(),
+        twox_128(b"SubnetworkN").as_slice(),
+        &0u16.encode(),
+    ]
+    .concat();
+    storage.top.insert(key, 3u16.encode());
+    assert!(!subnetwork_n_ok(storage));
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::{Decode, Encode};
+use node_subtensor::epoch_history::{EpochHistory, EpochHistoryRange};
+use node_subtensor_runtime::pallet_subtensor::epoch::history::{
+    EpochHistoryKey, EpochMatrices, EpochMatricesSummary,
+};
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX, storage::InMemOffchainStorage};
+use subtensor_runtime_common::NetUid;
+
+fn index_epoch(storage: &mut InMemOffchainStorage, netuid: NetUid, block: u64) {
+    let matrices = EpochMatrices {
+        weights: vec![vec![(0, u16::MAX)]],
+        bonds: vec![vec![]],
+    };
+    let summary = EpochMatricesSummary {
+        num_uids: 1,
+        weight_rows: 1,
+        weight_entries: 1,
+        bond_entries: 0,
+    };
+    let mut set = |key: EpochHistoryKey, value: Vec<u8>| {
+        storage.set(STORAGE_PREFIX, &key.key(), &value);
+    };
+    set(EpochHistoryKey::Matrices(netuid, block), matrices.encode());
+    set(EpochHistoryKey::Summary(netuid, block), summary.encode());
+    set(EpochHistoryKey::Epochs(block), vec![netuid].encode());
+}
+
+#[test]
+fn test_epoch_history_pruning() {
+    let netuid = NetUid::from(1);
+    let mut storage = InMemOffchainStorage::default();
+    for block in [10, 20, 30, 40] {
+        index_epoch(&mut storage, netuid, block);
+    }
+
+    let history = EpochHistory::new(storage, 2);
+    assert_eq!(history.range(netuid), None);
+
+    history.catch_up(35);
+    assert_eq!(history.last_processed(), Some(35));
+    assert_eq!(
+        history.range(netuid),
+        Some(EpochHistoryRange {
+            epochs: 3,
+            first_summary: 10,
+            first_matrices: Some(20),
+            last: 30,
+        })
+    );
+
+    history.catch_up(40);
+    assert_eq!(
+        history.range(netuid),
+        Some(EpochHistoryRange {
+            epochs: 4,
+            first_summary: 10,
+            first_matrices: Some(30),
+            last: 40,
+        })
+    );
+    assert_eq!(history.range(NetUid::from(2)), None);
+
+    // Pruned epochs keep their summary.
+    let pruned = history.epoch(netuid, 20).unwrap();
+    let (summary, matrices) =
+        <(EpochMatricesSummary, Option<EpochMatrices>)>::decode(&mut &pruned[..]).unwrap();
+    assert_eq!(summary.num_uids, 1);
+    assert_eq!(matrices, None);
+
+    let retained = history.epoch(netuid, 30).unwrap();
+    let (_, matrices) =
+        <(EpochMatricesSummary, Option<EpochMatrices>)>::decode(&mut &retained[..]).unwrap();
+    assert_eq!(matrices.unwrap().weights, vec![vec![(0, u16::MAX)]]);
+
+    assert_eq!(history.epoch(netuid, 25), None);
+}
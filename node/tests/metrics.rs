@@ -0,0 +1,63 @@
+#![allow(clippy::unwrap_used)]
+
+use frame_system::{EventRecord, Phase};
+use node_subtensor::metrics::SubtensorMetrics;
+use node_subtensor_runtime::{RuntimeEvent, pallet_subtensor::Event};
+use sp_runtime::AccountId32;
+use substrate_prometheus_endpoint::Registry;
+use subtensor_runtime_common::NetUid;
+
+fn metric(registry: &Registry, name: &str) -> f64 {
+    let family = registry
+        .gather()
+        .into_iter()
+        .find(|family| family.get_name() == name)
+        .unwrap();
+    let metric = &family.get_metric()[0];
+    if metric.has_counter() {
+        metric.get_counter().get_value()
+    } else {
+        metric.get_gauge().get_value()
+    }
+}
+
+#[test]
+fn test_record_events() {
+    let registry = Registry::new();
+    let metrics = SubtensorMetrics::register(&registry).unwrap();
+    let record = |event| EventRecord {
+        phase: Phase::ApplyExtrinsic(0),
+        event: RuntimeEvent::SubtensorModule(event),
+        topics: vec![],
+    };
+
+    metrics.record_events(&[
+        record(Event::NeuronRegistered(
+            NetUid::from(1),
+            0,
+            AccountId32::new([1; 32]),
+        )),
+        record(Event::BulkNeuronsRegistered(1, 3)),
+        record(Event::WeightsSet(NetUid::from(1), 0)),
+    ]);
+    assert_eq!(
+        metric(&registry, "subtensor_neuron_registrations_total"),
+        4.0
+    );
+    assert_eq!(
+        metric(&registry, "subtensor_block_neuron_registrations"),
+        4.0
+    );
+    assert_eq!(metric(&registry, "subtensor_weights_set_total"), 1.0);
+
+    // The gauge only covers the last block.
+    metrics.record_events(&[]);
+    assert_eq!(
+        metric(&registry, "subtensor_neuron_registrations_total"),
+        4.0
+    );
+    assert_eq!(
+        metric(&registry, "subtensor_block_neuron_registrations"),
+        0.0
+    );
+}
@@ -0,0 +1,29 @@
+#![allow(clippy::unwrap_used)]
+
+use node_subtensor::rpc_policy::RpcPolicy;
+
+#[test]
+fn test_rpc_policy_matches_methods() {
+    let policy: RpcPolicy = serde_json::from_str(
+        r#"{ "rateLimit": 60, "deny": ["subtensor_getAllMetagraphs", "debug_*"] }"#,
+    )
+    .unwrap();
+    assert_eq!(policy.rate_limit.map(u32::from), Some(60));
+    assert!(policy.is_allowed("subtensor_getMetagraph"));
+    assert!(!policy.is_allowed("subtensor_getAllMetagraphs"));
+    assert!(!policy.is_allowed("debug_traceTransaction"));
+
+    // Denied methods are removed even when allowed.
+    let policy: RpcPolicy =
+        serde_json::from_str(r#"{ "allow": ["eth_*", "net_version"], "deny": ["eth_call"] }"#)
+            .unwrap();
+    assert!(policy.is_allowed("eth_chainId"));
+    assert!(policy.is_allowed("net_version"));
+    assert!(!policy.is_allowed("eth_call"));
+    assert!(!policy.is_allowed("subtensor_getDelegates"));
+}
+
+#[test]
+fn test_rpc_policy_rejects_unknown_fields() {
+    assert!(serde_json::from_str::<RpcPolicy>(r#"{ "denied": ["eth_call"] }"#).is_err());
+}
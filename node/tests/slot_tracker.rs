@@ -0,0 +1,26 @@
+use node_subtensor::slot_tracker::SlotStats;
+
+#[test]
+fn test_record_block_counts_authored_and_missed_slots() {
+    let mut stats = SlotStats::default();
+
+    // Not an authority: nothing is counted.
+    assert_eq!(stats.record_block(1, 10, Some(9), 4, &[]), (false, 0));
+    assert_eq!(stats, SlotStats::default());
+
+    // The local key is authority 2 of 4: slot 10 is ours.
+    assert_eq!(stats.record_block(2, 10, Some(9), 4, &[2]), (true, 0));
+    assert_eq!(stats.authored, 1);
+    assert_eq!(stats.last_authored, Some((10, 2)));
+
+    // Slots 11 to 19 had no block, slots 14 and 18 were ours.
+    assert_eq!(stats.record_block(3, 20, Some(10), 4, &[2]), (false, 2));
+    assert_eq!(stats.missed, 2);
+    assert_eq!(stats.last_missed, Some(18));
+
+    // Without a parent slot, only the block's slot is counted.
+    assert_eq!(stats.record_block(4, 22, None, 4, &[2]), (true, 0));
+    assert_eq!(stats.authored, 2);
+    assert_eq!(stats.missed, 2);
+    assert!(stats.is_authority);
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Encode;
+use node_subtensor::snapshot::{Snapshot, SnapshotManifest};
+use node_subtensor_runtime::opaque::Block;
+use sp_core::{
+    hashing::blake2_256,
+    storage::{ChildInfo, Storage, StorageChild},
+};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+fn snapshot() -> Snapshot {
+    let child_info = ChildInfo::new_default(b"child");
+    let mut storage = Storage::default();
+    storage.top.insert(b"key".to_vec(), b"value".to_vec());
+    storage.children_default.insert(
+        child_info.storage_key().to_vec(),
+        StorageChild {
+            data: [(b"child_key".to_vec(), b"child_value".to_vec())].into(),
+            child_info,
+        },
+    );
+    let header = <Block as BlockT>::Header::new(
+        100,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    );
+    Snapshot::new(header, storage)
+}
+
+#[test]
+fn test_snapshot_keeps_child_tries() {
+    let snapshot = snapshot();
+    assert_eq!(snapshot.top, vec![(b"key".to_vec(), b"value".to_vec())]);
+    assert_eq!(
+        snapshot.children,
+        vec![(
+            ChildInfo::new_default(b"child")
+                .prefixed_storage_key()
+                .into_inner(),
+            vec![(b"child_key".to_vec(), b"child_value".to_vec())]
+        )]
+    );
+}
+
+#[test]
+fn test_read_snapshot_manifest() {
+    let dir = std::env::temp_dir().join(format!("subtensor-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let snapshot = snapshot();
+    let data = snapshot.encode();
+    let manifest = SnapshotManifest {
+        file: "snapshot-100.scale".to_string(),
+        number: 100,
+        hash: snapshot.header.hash(),
+        state_root: *snapshot.header.state_root(),
+        spec_name: "node-subtensor".to_string(),
+        spec_version: 1,
+        file_hash: hex::encode(blake2_256(&data)),
+    };
+    let manifest_path = dir.join("latest.json");
+    std::fs::write(&manifest_path, serde_json::to_vec(&manifest).unwrap()).unwrap();
+    std::fs::write(dir.join(&manifest.file), &data).unwrap();
+
+    assert_eq!(
+        SnapshotManifest::read(&manifest_path).unwrap(),
+        (manifest.clone(), snapshot)
+    );
+
+    // A snapshot that does not match its manifest is refused.
+    std::fs::write(dir.join(&manifest.file), &data[1..]).unwrap();
+    assert!(SnapshotManifest::read(&manifest_path).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,41 @@
+use node_subtensor::tx_pool_policy::{PolicyKey, TxPoolPolicy};
+use node_subtensor_runtime::{RuntimeCall, pallet_subtensor};
+use subtensor_runtime_common::{AccountId, NetUid};
+
+#[test]
+fn test_limited_keys() {
+    let hotkey = AccountId::new([1; 32]);
+    let signer = AccountId::new([2; 32]);
+    let netuid = NetUid::from(1);
+    let register = RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register {
+        netuid,
+        hotkey: hotkey.clone(),
+    });
+    let set_weights = RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_weights {
+        netuid,
+        dests: vec![0],
+        weights: vec![1],
+        version_key: 0,
+    });
+
+    // Without limits nothing is counted.
+    let policy = TxPoolPolicy::default();
+    assert!(!policy.is_enabled());
+    assert!(policy.limited_keys(Some(&signer), &register).is_empty());
+
+    let policy = TxPoolPolicy {
+        pool_max_registrations_per_netuid: Some(10),
+        pool_max_weights_per_hotkey: Some(2),
+        ..Default::default()
+    };
+    assert!(policy.is_enabled());
+    assert_eq!(
+        policy.limited_keys(Some(&signer), &register),
+        vec![PolicyKey::SubnetRegistrations(netuid)]
+    );
+    assert_eq!(
+        policy.limited_keys(Some(&signer), &set_weights),
+        vec![PolicyKey::HotkeyWeights(signer, netuid)]
+    );
+    assert!(policy.limited_keys(None, &set_weights).is_empty());
+}
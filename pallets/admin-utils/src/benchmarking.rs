@@ -346,5 +346,286 @@ mod benchmarks {
 		_(RawOrigin::Root, 5u16/*version*/)/*sudo_set_commit_reveal_version()*/;
     }
 
+    #[benchmark]
+    fn sudo_set_unbonding_period() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 7200u64/*unbonding_period*/)/*sudo_set_unbonding_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_owner_cut_for_subnet() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 0u16/*subnet_owner_cut*/)/*sudo_set_subnet_owner_cut_for_subnet*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_registration_auction_enabled() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_registration_auction_enabled*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_delegate_take_notice_period() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 7200u64/*notice_period*/)/*sudo_set_delegate_take_notice_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_owner_cut_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 0u16/*min*/, 11_796u16/*max*/)/*sudo_set_subnet_owner_cut_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_stake_threshold() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 1_000_000_000u64/*stake_threshold*/)/*sudo_set_subnet_stake_threshold*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_stake_threshold_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 0u64/*min*/, 1_000_000_000_000u64/*max*/)/*sudo_set_subnet_stake_threshold_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_proposal_quorum() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 32_767u16/*quorum*/)/*sudo_set_subnet_proposal_quorum*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_proposal_duration() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 50_400u64/*duration*/)/*sudo_set_subnet_proposal_duration*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_unregister_refund_fraction() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 32_767u16/*fraction*/)/*sudo_set_unregister_refund_fraction*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_max_subnet_maintenance_duration() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 50_400u64/*duration*/)/*sudo_set_max_subnet_maintenance_duration*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_offense_slash_fraction() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 6_553u16/*fraction*/)/*sudo_set_offense_slash_fraction*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_staking_rate_limit_exemption() {
+        let coldkey: T::AccountId = account("Alice", 0, 1);
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, coldkey/*coldkey*/, true/*exempt*/)/*sudo_set_staking_rate_limit_exemption*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_stake_lock_boost() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, pallet_subtensor::StakeLockPeriod::Days90/*period*/, 25u16/*boost*/)/*sudo_set_stake_lock_boost*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_identity_fee() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1_000_000_000u64.into()/*fee*/)/*sudo_set_subnet_identity_fee*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_bonds_reset_on_deregistration() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_bonds_reset_on_deregistration*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_liquid_alpha_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1638u16/*min_alpha_low*/, 58982u16/*max_alpha_high*/)/*sudo_set_liquid_alpha_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_miner_emission_vesting_period() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 7200u64/*vesting_period*/)/*sudo_set_miner_emission_vesting_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_emission_circuit_breaker_threshold() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 300u32/*threshold*/)/*sudo_set_emission_circuit_breaker_threshold*/;
+    }
+
+    #[benchmark]
+    fn sudo_reset_emission_circuit_breaker() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, Some(1u16.into())/*netuid*/)/*sudo_reset_emission_circuit_breaker*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_burn_controller_gains() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 32767u16/*kp*/, 6553u16/*ki*/, 6553u16/*kd*/)/*sudo_set_burn_controller_gains*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_registration_pow_algorithm() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, pallet_subtensor::RegistrationPowAlgorithm::Argon2id/*algorithm*/)/*sudo_set_registration_pow_algorithm*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_incentive_matching() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 1_000_000_000_000u64.into()/*cap*/, 216_000u64/*end_block*/)/*sudo_set_incentive_matching*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_max_weight_age() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 7200u64/*max_weight_age*/)/*sudo_set_max_weight_age*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_transaction_fee() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 1_000_000u64.into()/*fee*/)/*sudo_set_subnet_transaction_fee*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_treasury_share() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 6553u16/*share*/)/*sudo_set_subnet_treasury_share*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_multi_block_epoch_chunk_size() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 64u16/*chunk_size*/)/*sudo_set_multi_block_epoch_chunk_size*/;
+    }
+
+    #[benchmark]
+    fn sudo_grow_max_allowed_uids() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+        pallet_subtensor::Pallet::<T>::set_max_allowed_uids(1u16.into(), 256u16);
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 512u16/*max_allowed_uids*/)/*sudo_grow_max_allowed_uids*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_call_disabled() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 71u8/*call_index*/, true/*disabled*/)/*sudo_set_call_disabled*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_weights_version_key_grace_period() {
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 7200u64/*grace_period*/)/*sudo_set_weights_version_key_grace_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_weights_priority_window() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 20u64/*window*/)/*sudo_set_weights_priority_window*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_target_registration_block_fullness() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, sp_runtime::Perbill::from_percent(5)/*fullness*/)/*sudo_set_target_registration_block_fullness*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_evm_withdrawal_delay() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 3600u64/*delay*/)/*sudo_set_evm_withdrawal_delay*/;
+    }
+
     //impl_benchmark_test_suite!(AdminUtils, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -109,6 +109,8 @@ pub mod pallet {
         NegativeSigmoidSteepness,
         /// Reveal Peroid is not within the valid range.
         RevealPeriodOutOfBounds,
+        /// The subnet transaction fee exceeds the maximum.
+        SubnetTransactionFeeTooHigh,
     }
     /// Enum for specifying the type of precompile operation.
     #[derive(
@@ -275,7 +277,8 @@ pub mod pallet {
 
         /// The extrinsic sets the weights version key for a subnet.
         /// It is only callable by the root account or subnet owner.
-        /// The extrinsic will call the Subtensor pallet to set the weights version key.
+        /// The extrinsic will call the Subtensor pallet to schedule the weights version key, which
+        /// applies once the grace period of the subnet has passed.
         #[pallet::call_index(6)]
         #[pallet::weight(Weight::from_parts(12_560_000, 0)
         .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
@@ -314,7 +317,10 @@ pub mod pallet {
                 );
             }
 
-            pallet_subtensor::Pallet::<T>::set_weights_version_key(netuid, weights_version_key);
+            pallet_subtensor::Pallet::<T>::schedule_weights_version_key(
+                netuid,
+                weights_version_key,
+            );
             log::debug!(
                 "WeightsVersionKeySet( netuid: {netuid:?} weights_version_key: {weights_version_key:?} ) "
             );
@@ -627,6 +633,35 @@ pub mod pallet {
             Ok(())
         }
 
+        /// The extrinsic switches a subnet between auctioned and PoW/burn registration.
+        /// It is only callable by the root account or subnet owner.
+        /// When enabled, UIDs are auctioned every adjustment interval through bid_registration,
+        /// when disabled all open bids are refunded.
+        #[pallet::call_index(76)]
+        #[pallet::weight((
+			Weight::from_parts(14_000_000, 0)
+				.saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+        pub fn sudo_set_registration_auction_enabled(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_auction_enabled(netuid, enabled);
+            log::debug!("RegistrationAuctionEnabled( netuid: {netuid:?} enabled: {enabled:?} ) ");
+            Ok(())
+        }
+
         /// The extrinsic sets the target registrations per interval for a subnet.
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the target registrations per interval.
@@ -1066,6 +1101,21 @@ pub mod pallet {
             Ok(())
         }
 
+        /// The extrinsic sets the notice period for delegate take increases.
+        /// It is only callable by the root account.
+        /// Take increases are queued for this many blocks before they apply, decreases are immediate.
+        #[pallet::call_index(75)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_delegate_take_notice_period(
+            origin: OriginFor<T>,
+            notice_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_delegate_take_notice_period(notice_period);
+            log::debug!("DelegateTakeNoticePeriodSet( notice_period: {notice_period:?} ) ");
+            Ok(())
+        }
+
         /// The extrinsic sets the minimum delegate take.
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the minimum delegate take.
@@ -1673,6 +1723,816 @@ pub mod pallet {
             pallet_subtensor::Pallet::<T>::set_commit_reveal_weights_version(version);
             Ok(())
         }
+
+        /// The extrinsic sets the unbonding period for a subnet.
+        /// It is only callable by the root account.
+        /// TAO unstaked from the subnet is locked in the coldkey's unbonding queue for this many
+        /// blocks before it can be withdrawn. Zero disables unbonding.
+        #[pallet::call_index(72)]
+        #[pallet::weight(Weight::from_parts(14_160_000, 0)
+        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
+        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        pub fn sudo_set_unbonding_period(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            unbonding_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                unbonding_period <= pallet_subtensor::MAX_UNBONDING_PERIOD,
+                pallet_subtensor::Error::<T>::UnbondingPeriodTooLong
+            );
+
+            pallet_subtensor::Pallet::<T>::set_unbonding_period(netuid, unbonding_period);
+            log::debug!(
+                "UnbondingPeriodSet( netuid: {netuid:?} unbonding_period: {unbonding_period:?} ) "
+            );
+            Ok(())
+        }
+
+        /// Sets the owner cut for a single subnet, overriding the global subnet owner cut.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or root.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `subnet_owner_cut` - The share of the subnet's emission paid to the owner, normalized to `u16::MAX`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor root.
+        /// * `SubnetNotExists` - If the subnet does not exist.
+        /// * `SubnetOwnerCutOutOfBounds` - If the cut is outside the bounds set by governance.
+        /// * `TxRateLimitExceeded` - If the subnet owner changed the cut too recently.
+        #[pallet::call_index(73)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_owner_cut_for_subnet(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            subnet_owner_cut: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::do_set_subnet_owner_cut_for_subnet(
+                origin,
+                netuid,
+                subnet_owner_cut,
+            )
+        }
+
+        /// Sets the bounds within which subnet owners may choose their subnet's owner cut.
+        /// It is only callable by the root account.
+        /// Owner cuts already set outside the new bounds are kept until the owner changes them.
+        #[pallet::call_index(74)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_owner_cut_bounds(
+            origin: OriginFor<T>,
+            min_subnet_owner_cut: u16,
+            max_subnet_owner_cut: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_subnet_owner_cut <= max_subnet_owner_cut,
+                pallet_subtensor::Error::<T>::InvalidSubnetOwnerCutBounds
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_owner_cut_bounds(
+                min_subnet_owner_cut,
+                max_subnet_owner_cut,
+            );
+            log::debug!(
+                "SubnetOwnerCutBoundsSet( min: {min_subnet_owner_cut:?} max: {max_subnet_owner_cut:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum stake for a validator permit on a subnet.
+        /// It is only callable by the root account or subnet owner, within the bounds set by root.
+        /// The global stake threshold still applies if it is higher.
+        #[pallet::call_index(77)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_stake_threshold(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            stake_threshold: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                stake_threshold >= pallet_subtensor::MinSubnetStakeThreshold::<T>::get()
+                    && stake_threshold <= pallet_subtensor::MaxSubnetStakeThreshold::<T>::get(),
+                pallet_subtensor::Error::<T>::SubnetStakeThresholdOutOfBounds
+            );
+
+            pallet_subtensor::Pallet::<T>::set_subnet_stake_threshold(netuid, stake_threshold);
+            log::debug!(
+                "SubnetStakeThresholdSet( netuid: {netuid:?} stake_threshold: {stake_threshold:?} ) "
+            );
+            Ok(())
+        }
+
+        /// Sets the bounds within which subnet owners may choose their subnet's validator stake threshold.
+        /// It is only callable by the root account.
+        /// Thresholds already set outside the new bounds are kept until the owner changes them.
+        #[pallet::call_index(78)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_stake_threshold_bounds(
+            origin: OriginFor<T>,
+            min_stake_threshold: u64,
+            max_stake_threshold: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_stake_threshold <= max_stake_threshold,
+                pallet_subtensor::Error::<T>::InvalidSubnetStakeThresholdBounds
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_stake_threshold_bounds(
+                min_stake_threshold,
+                max_stake_threshold,
+            );
+            log::debug!(
+                "SubnetStakeThresholdBoundsSet( min: {min_stake_threshold:?} max: {max_stake_threshold:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of a subnet's outstanding alpha, normalized to `u16::MAX`,
+        /// that must approve a subnet proposal for it to execute.
+        /// It is only callable by the root account.
+        #[pallet::call_index(79)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_proposal_quorum(
+            origin: OriginFor<T>,
+            quorum: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                quorum > 0,
+                pallet_subtensor::Error::<T>::InvalidSubnetProposalQuorum
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_proposal_quorum(quorum);
+            log::debug!("SubnetProposalQuorumSet( quorum: {quorum:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet proposal accepts votes.
+        /// It is only callable by the root account.
+        /// Open proposals keep the duration they were opened with.
+        #[pallet::call_index(80)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_proposal_duration(
+            origin: OriginFor<T>,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_proposal_duration(duration);
+            log::debug!("SubnetProposalDurationSet( duration: {duration:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the registration burn, normalized to `u16::MAX`,
+        /// refunded to a neuron unregistering during its immunity period.
+        /// It is only callable by the root account.
+        #[pallet::call_index(81)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_unregister_refund_fraction(
+            origin: OriginFor<T>,
+            fraction: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_unregister_refund_fraction(fraction);
+            log::debug!("UnregisterRefundFractionSet( fraction: {fraction:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks after which a subnet in maintenance mode
+        /// resumes automatically.
+        /// It is only callable by the root account.
+        #[pallet::call_index(82)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_max_subnet_maintenance_duration(
+            origin: OriginFor<T>,
+            duration: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_max_subnet_maintenance_duration(duration);
+            log::debug!("MaxSubnetMaintenanceDurationSet( duration: {duration:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the stake of an offending hotkey, normalized to
+        /// `u16::MAX`, slashed when an offense is reported.
+        /// It is only callable by the root account.
+        #[pallet::call_index(83)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_offense_slash_fraction(
+            origin: OriginFor<T>,
+            fraction: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_offense_slash_fraction(fraction);
+            log::debug!("OffenseSlashFractionSet( fraction: {fraction:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic exempts a coldkey from the staking operation rate limit, or revokes
+        /// its exemption.
+        /// It is only callable by the root account.
+        #[pallet::call_index(84)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_staking_rate_limit_exemption(
+            origin: OriginFor<T>,
+            coldkey: <T as frame_system::Config>::AccountId,
+            exempt: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_staking_rate_limit_exemption(&coldkey, exempt);
+            log::debug!(
+                "StakingRateLimitExemptionSet( coldkey: {coldkey:?}, exempt: {exempt:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the boost of stake locked for `period`, in percent of the locked
+        /// alpha. Existing locks keep the boost they were created with.
+        /// It is only callable by the root account.
+        #[pallet::call_index(85)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_stake_lock_boost(
+            origin: OriginFor<T>,
+            period: pallet_subtensor::StakeLockPeriod,
+            boost: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_stake_lock_boost(period, boost);
+            log::debug!("StakeLockBoostSet( period: {period:?}, boost: {boost:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the TAO burned from a subnet owner to set the subnet identity.
+        /// It is only callable by the root account.
+        #[pallet::call_index(86)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_identity_fee(
+            origin: OriginFor<T>,
+            fee: TaoCurrency,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_subnet_identity_fee(fee);
+            log::debug!("SubnetIdentityFeeSet( fee: {fee:?} ) ");
+            Ok(())
+        }
+
+        /// Enables or disables the reset of the bonds of dormant validators for a subnet.
+        ///
+        /// When enabled, deregistering or replacing a neuron also removes the bonds other
+        /// validators hold on it, and the epoch drops the bonds of validators that have not set
+        /// weights within the activity cutoff, so they earn no dividends from stale bonds and
+        /// start over when they return.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(87)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_bonds_reset_on_deregistration(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_bonds_reset_on_deregistration(netuid, enabled);
+            log::debug!(
+                "BondsResetOnDeregistrationSet( netuid: {netuid:?} enabled: {enabled:?} ) "
+            );
+            Ok(())
+        }
+
+        /// Sets the bounds within which subnet owners may choose their liquid alpha values.
+        /// It is only callable by the root account.
+        /// Alpha values already set outside the new bounds are kept until the owner changes them.
+        #[pallet::call_index(88)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_liquid_alpha_bounds(
+            origin: OriginFor<T>,
+            min_alpha_low: u16,
+            max_alpha_high: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                min_alpha_low > 0 && min_alpha_low <= max_alpha_high,
+                pallet_subtensor::Error::<T>::InvalidLiquidAlphaBounds
+            );
+            pallet_subtensor::Pallet::<T>::set_liquid_alpha_bounds(min_alpha_low, max_alpha_high);
+            log::debug!(
+                "LiquidAlphaBoundsSet( min_alpha_low: {min_alpha_low:?} max_alpha_high: {max_alpha_high:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the miner emission vesting period for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The incentive of each epoch is streamed to miners over this many blocks, and the part
+        /// not yet vested when a miner deregisters is recycled. Zero pays incentive at once.
+        #[pallet::call_index(89)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_miner_emission_vesting_period(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            vesting_period: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                vesting_period <= pallet_subtensor::MAX_MINER_EMISSION_VESTING_PERIOD,
+                pallet_subtensor::Error::<T>::MinerEmissionVestingPeriodTooLong
+            );
+
+            pallet_subtensor::Pallet::<T>::set_miner_emission_vesting_period(
+                netuid,
+                vesting_period,
+            );
+            log::debug!(
+                "MinerEmissionVestingPeriodSet( netuid: {netuid:?} vesting_period: {vesting_period:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the emission circuit breaker threshold.
+        /// It is only callable by the root account.
+        /// The breaker halts the distribution of emission when a block emits more than this
+        /// percentage of the expected emission. Zero disables the breaker.
+        #[pallet::call_index(90)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_emission_circuit_breaker_threshold(
+            origin: OriginFor<T>,
+            threshold: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_emission_circuit_breaker_threshold(threshold);
+            log::debug!("EmissionCircuitBreakerThresholdSet( threshold: {threshold:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic resets the emission circuit breaker of a subnet, or the global breaker
+        /// when `netuid` is `None`, resuming the distribution of the withheld emission.
+        /// It is only callable by the root account.
+        #[pallet::call_index(91)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_reset_emission_circuit_breaker(
+            origin: OriginFor<T>,
+            netuid: Option<NetUid>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::reset_emission_circuit_breaker(netuid);
+            log::debug!("EmissionCircuitBreakerReset( netuid: {netuid:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the gains of the PID controller of the burn for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The gains are normalized to u16::MAX. When any gain is set, the controller adjusts the
+        /// burn towards the target registrations per interval instead of the default adjustment.
+        #[pallet::call_index(92)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_burn_controller_gains(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            kp: u16,
+            ki: u16,
+            kd: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_burn_controller_gains(netuid, kp, ki, kd);
+            log::debug!(
+                "BurnControllerGainsSet( netuid: {netuid:?} kp: {kp:?} ki: {ki:?} kd: {kd:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the hash of the registration proof of work for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// Argon2id is memory-hard, resisting GPU registration farms. Switching the hash restarts
+        /// the difficulty from the minimum difficulty.
+        #[pallet::call_index(93)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(2)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_registration_pow_algorithm(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            algorithm: pallet_subtensor::RegistrationPowAlgorithm,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_registration_pow_algorithm(netuid, algorithm);
+            log::debug!(
+                "RegistrationPowAlgorithmSet( netuid: {netuid:?} algorithm: {algorithm:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the incentive matching schedule of a subnet.
+        /// It is only callable by the root account.
+        /// Until `end_block`, the TAO emission of the subnet is matched 1:1 with TAO from the
+        /// incentive matching fund, up to `cap` TAO in total. A zero cap removes the schedule.
+        #[pallet::call_index(94)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_incentive_matching(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            cap: TaoCurrency,
+            end_block: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_incentive_matching(netuid, cap, end_block);
+            log::debug!(
+                "IncentiveMatchingSet( netuid: {netuid:?} cap: {cap:?} end_block: {end_block:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum weight age for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// Validators whose weights were last updated more than this many blocks ago have zero
+        /// weight in the epoch and earn no dividends. Zero lets weights count indefinitely.
+        #[pallet::call_index(95)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_max_weight_age(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            max_weight_age: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_max_weight_age(netuid, max_weight_age);
+            log::debug!(
+                "MaxWeightAgeSet( netuid: {netuid:?} max_weight_age: {max_weight_age:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the transaction fee for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The fee is paid to the subnet treasury on setting weights, serving axons and burn
+        /// registering neurons on the subnet, on top of the regular transaction fee.
+        #[pallet::call_index(96)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_transaction_fee(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            fee: TaoCurrency,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            let max_fee: TaoCurrency =
+                pallet_subtensor::subnets::transaction_fee::MAX_SUBNET_TRANSACTION_FEE.into();
+            ensure!(fee <= max_fee, Error::<T>::SubnetTransactionFeeTooHigh);
+            pallet_subtensor::Pallet::<T>::set_subnet_transaction_fee(netuid, fee);
+            log::debug!("SubnetTransactionFeeSet( netuid: {netuid:?} fee: {fee:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the owner cut paid to the subnet treasury.
+        /// It is only callable by the root account or subnet owner.
+        /// The share is normalized to u16::MAX and unstaked to TAO as the owner cut is distributed.
+        #[pallet::call_index(97)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_subnet_treasury_share(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            share: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_subnet_treasury_share(netuid, share);
+            log::debug!("SubnetTreasuryShareSet( netuid: {netuid:?} share: {share:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the items processed per block by the epoch of a subnet.
+        /// It is only callable by the root account.
+        /// A non-zero chunk size splits the epoch of the subnet over consecutive blocks, zero runs
+        /// it in a single block.
+        #[pallet::call_index(98)]
+        #[pallet::weight((
+            Weight::from_parts(14_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_multi_block_epoch_chunk_size(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            chunk_size: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_multi_block_epoch_chunk_size(netuid, chunk_size);
+            log::debug!(
+                "MultiBlockEpochChunkSizeSet( netuid: {netuid:?} chunk_size: {chunk_size:?} ) "
+            );
+            Ok(())
+        }
+
+        /// Grows the max UIDs of a subnet after its creation.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or root.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `max_allowed_uids` - The new max UIDs, above the current one.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is neither the subnet owner nor root.
+        /// * `SubnetNotExists` - If the subnet does not exist.
+        /// * `CannotGrowRootSubnetUids` - If the subnet is the root subnet.
+        /// * `MaxAllowedUidsNotIncreased` - If the new max is not above the current max.
+        /// * `MaxAllowedUidsGrowthTooLarge` - If the new max is above the limit or grows the
+        ///   subnet by too much at once.
+        /// * `TxRateLimitExceeded` - If the subnet owner grew the subnet too recently.
+        #[pallet::call_index(99)]
+        #[pallet::weight((
+            Weight::from_parts(24_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(17))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(525)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_grow_max_allowed_uids(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            max_allowed_uids: u16,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::do_grow_max_allowed_uids(
+                origin,
+                netuid,
+                max_allowed_uids,
+            )
+        }
+
+        /// The extrinsic pauses or resumes a call of the subtensor pallet.
+        /// It is only callable by the root account.
+        /// Transactions of a paused call are rejected by the subtensor transaction extension, so
+        /// that a call can be disabled during an incident without a runtime upgrade.
+        #[pallet::call_index(100)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1))
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_call_disabled(
+            origin: OriginFor<T>,
+            call_index: u8,
+            disabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_call_disabled(call_index, disabled);
+            log::debug!("CallDisabledSet( call_index: {call_index:?} disabled: {disabled:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the grace period of new weights version keys for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// Until a new weights version key has waited out the grace period, weights meeting the
+        /// previous key are still accepted, so that miners and validators can upgrade in time.
+        #[pallet::call_index(101)]
+        #[pallet::weight(Weight::from_parts(10_000_000, 0)
+        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2_u64))
+        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        pub fn sudo_set_weights_version_key_grace_period(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            grace_period: u64,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_weights_version_key_grace_period(
+                netuid,
+                grace_period,
+            );
+            log::debug!(
+                "WeightsVersionKeyGracePeriodSet( netuid: {netuid:?} grace_period: {grace_period:?} )"
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks before an epoch in which validator weights
+        /// get priority.
+        /// It is only callable by the root account.
+        /// Weights transactions of permitted validators in this window rank above the other
+        /// weights transactions, reducing the epochs they miss under congestion.
+        #[pallet::call_index(102)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_weights_priority_window(
+            origin: OriginFor<T>,
+            window: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_weights_priority_window(window);
+            log::debug!("WeightsPriorityWindowSet( window: {window:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the block weight registration calls are priced to
+        /// fill.
+        /// It is only callable by the root account.
+        /// The fee of registration calls rises while they fill blocks past this share, so
+        /// registration bursts throttle themselves; zero disables the congestion pricing.
+        #[pallet::call_index(103)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_target_registration_block_fullness(
+            origin: OriginFor<T>,
+            fullness: sp_runtime::Perbill,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_target_registration_block_fullness(fullness);
+            log::debug!("TargetRegistrationBlockFullnessSet( fullness: {fullness:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks TAO withdrawn from the EVM waits before its
+        /// coldkey can claim it.
+        /// It is only callable by the root account.
+        #[pallet::call_index(104)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_evm_withdrawal_delay(origin: OriginFor<T>, delay: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_evm_withdrawal_delay(delay)?;
+            log::debug!("EvmWithdrawalDelaySet( delay: {delay:?} ) ");
+            Ok(())
+        }
     }
 }
 
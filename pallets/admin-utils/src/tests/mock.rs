@@ -11,11 +11,11 @@ use frame_system::{EnsureNever, EnsureRoot, limits};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityList as GrandpaAuthorityList;
 use sp_core::U256;
-use sp_core::{ConstU64, H256};
+use sp_core::{ConstU64, H160, H256};
 use sp_runtime::{
     BuildStorage, KeyTypeId, Perbill,
     testing::TestXt,
-    traits::{BlakeTwo256, ConstU32, IdentityLookup},
+    traits::{BlakeTwo256, ConstU32, Convert, IdentityLookup},
 };
 use sp_std::cmp::Ordering;
 use sp_weights::Weight;
@@ -151,6 +151,14 @@ parameter_types! {
     pub const LeaseDividendsDistributionInterval: u32 = 100; // 100 blocks
 }
 
+pub struct EvmAddressToAccount;
+
+impl Convert<H160, U256> for EvmAddressToAccount {
+    fn convert(evm_address: H160) -> U256 {
+        U256::from_big_endian(evm_address.as_bytes())
+    }
+}
+
 impl pallet_subtensor::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -222,6 +230,8 @@ impl pallet_subtensor::Config for Test {
     type KeySwapOnSubnetCost = InitialKeySwapOnSubnetCost;
     type HotkeySwapOnSubnetInterval = HotkeySwapOnSubnetInterval;
     type ProxyInterface = ();
+    type EventBridge = ();
+    type EvmAddressMapping = EvmAddressToAccount;
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
 }
 
@@ -10,6 +10,7 @@ use pallet_subtensor::{Error as SubtensorError, SubnetOwner, Tempo, WeightsVersi
 use pallet_subtensor::Event;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{Get, Pair, U256, ed25519};
+use sp_runtime::Perbill;
 use substrate_fixed::types::I96F32;
 use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
 
@@ -264,6 +265,65 @@ fn test_sudo_set_weights_version_key_rate_limit_root() {
     });
 }
 
+#[test]
+fn test_sudo_set_weights_version_key_grace_period() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        let grace_period: u64 = 100;
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+        assert_ok!(AdminUtils::sudo_set_weights_version_key(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+
+        assert_eq!(
+            AdminUtils::sudo_set_weights_version_key_grace_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                grace_period
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_weights_version_key_grace_period(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            grace_period
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_version_key_grace_period(netuid),
+            grace_period
+        );
+
+        // The new key waits out the grace period, both keys are accepted meanwhile.
+        let effective_block = System::block_number() + grace_period;
+        assert_ok!(AdminUtils::sudo_set_weights_version_key(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            10
+        ));
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 5);
+        assert_eq!(
+            SubtensorModule::get_pending_weights_version_key(netuid),
+            Some((10, effective_block))
+        );
+        assert!(!SubtensorModule::check_version_key(netuid, 4));
+        assert!(SubtensorModule::check_version_key(netuid, 5));
+        assert!(SubtensorModule::check_version_key(netuid, 10));
+
+        run_to_block(effective_block);
+        assert_eq!(SubtensorModule::get_weights_version_key(netuid), 10);
+        assert_eq!(
+            SubtensorModule::get_pending_weights_version_key(netuid),
+            None
+        );
+        assert!(!SubtensorModule::check_version_key(netuid, 5));
+        assert!(SubtensorModule::check_version_key(netuid, 10));
+    });
+}
+
 #[test]
 fn test_sudo_set_weights_set_rate_limit() {
     new_test_ext().execute_with(|| {
@@ -1951,3 +2011,1022 @@ fn test_sudo_set_commit_reveal_version() {
         );
     });
 }
+
+#[test]
+fn test_sudo_set_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let to_be_set: u64 = 7200;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_unbonding_period(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                pallet_subtensor::MAX_UNBONDING_PERIOD + 1
+            ),
+            Err(pallet_subtensor::Error::<Test>::UnbondingPeriodTooLong.into())
+        );
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_unbonding_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_owner_cut_for_subnet() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        let owner = U256::from(10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        run_to_block(1);
+
+        let global_cut = SubtensorModule::get_subnet_owner_cut();
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_cut_for_subnet(netuid),
+            global_cut
+        );
+
+        // Only root can set the bounds, and min must not exceed max
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                0,
+                global_cut
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                global_cut,
+                0
+            ),
+            Err(pallet_subtensor::Error::<Test>::InvalidSubnetOwnerCutBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100,
+            global_cut
+        ));
+
+        // The owner can only pick a cut within the bounds
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut_for_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(11)),
+                netuid,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut_for_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                global_cut + 1
+            ),
+            Err(pallet_subtensor::Error::<Test>::SubnetOwnerCutOutOfBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut_for_subnet(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_cut_for_subnet(netuid),
+            100
+        );
+
+        // Owner changes are rate limited, root changes are not
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_owner_cut_for_subnet(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                200
+            ),
+            Err(pallet_subtensor::Error::<Test>::TxRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_owner_cut_for_subnet(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            200
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_owner_cut_for_subnet(netuid),
+            200
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_delegate_take_notice_period() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = SubtensorModule::get_delegate_take_notice_period();
+        assert_eq!(
+            AdminUtils::sudo_set_delegate_take_notice_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_delegate_take_notice_period(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_delegate_take_notice_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_delegate_take_notice_period(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_weights_priority_window() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 20;
+        let init_value: u64 = SubtensorModule::get_weights_priority_window();
+        assert_eq!(
+            AdminUtils::sudo_set_weights_priority_window(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_weights_priority_window(), init_value);
+        assert_ok!(AdminUtils::sudo_set_weights_priority_window(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_weights_priority_window(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_target_registration_block_fullness() {
+    new_test_ext().execute_with(|| {
+        let to_be_set = Perbill::from_percent(5);
+        let init_value = SubtensorModule::get_target_registration_block_fullness();
+        assert_eq!(
+            AdminUtils::sudo_set_target_registration_block_fullness(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_target_registration_block_fullness(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_target_registration_block_fullness(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_target_registration_block_fullness(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_evm_withdrawal_delay() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 3600;
+        let init_value = SubtensorModule::get_evm_withdrawal_delay();
+        assert_eq!(
+            AdminUtils::sudo_set_evm_withdrawal_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_evm_withdrawal_delay(), init_value);
+        assert_noop!(
+            AdminUtils::sudo_set_evm_withdrawal_delay(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                pallet_subtensor::MAX_EVM_WITHDRAWAL_DELAY + 1
+            ),
+            SubtensorError::<Test>::EvmWithdrawalDelayTooLong
+        );
+        assert_ok!(AdminUtils::sudo_set_evm_withdrawal_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_evm_withdrawal_delay(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_auction_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_registration_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_registration_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_registration_auction_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_registration_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_registration_auction_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_stake_threshold() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        let owner = U256::from(10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_stake_threshold(1_000);
+
+        // Only root can set the bounds, and min must not exceed max
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                0,
+                1_000_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                1_000_000,
+                0
+            ),
+            Err(pallet_subtensor::Error::<Test>::InvalidSubnetStakeThresholdBounds.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_stake_threshold_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            0,
+            1_000_000
+        ));
+
+        // The owner can only pick a threshold within the bounds
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(11)),
+                netuid,
+                500
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_stake_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                1_000_001
+            ),
+            Err(pallet_subtensor::Error::<Test>::SubnetStakeThresholdOutOfBounds.into())
+        );
+
+        // A threshold below the global one has no effect
+        assert_ok!(AdminUtils::sudo_set_subnet_stake_threshold(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            500
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_threshold_for_subnet(netuid),
+            1_000
+        );
+
+        assert_ok!(AdminUtils::sudo_set_subnet_stake_threshold(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            1_000_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_threshold_for_subnet(netuid),
+            1_000_000
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_proposal_quorum_and_duration() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_proposal_quorum(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_proposal_quorum(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                0
+            ),
+            Err(pallet_subtensor::Error::<Test>::InvalidSubnetProposalQuorum.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_proposal_quorum(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            100
+        ));
+        assert_eq!(pallet_subtensor::SubnetProposalQuorum::<Test>::get(), 100);
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_proposal_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                10
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_proposal_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            10
+        ));
+        assert_eq!(pallet_subtensor::SubnetProposalDuration::<Test>::get(), 10);
+    });
+}
+
+#[test]
+fn test_sudo_set_unregister_refund_fraction() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = u16::MAX / 2;
+        assert_eq!(
+            AdminUtils::sudo_set_unregister_refund_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(pallet_subtensor::UnregisterRefundFraction::<Test>::get(), 0);
+        assert_ok!(AdminUtils::sudo_set_unregister_refund_fraction(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            pallet_subtensor::UnregisterRefundFraction::<Test>::get(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_max_subnet_maintenance_duration() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value = pallet_subtensor::MaxSubnetMaintenanceDuration::<Test>::get();
+        assert_eq!(
+            AdminUtils::sudo_set_max_subnet_maintenance_duration(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            pallet_subtensor::MaxSubnetMaintenanceDuration::<Test>::get(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_max_subnet_maintenance_duration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            pallet_subtensor::MaxSubnetMaintenanceDuration::<Test>::get(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_offense_slash_fraction() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = u16::MAX / 4;
+        let init_value = pallet_subtensor::OffenseSlashFraction::<Test>::get();
+        assert_eq!(
+            AdminUtils::sudo_set_offense_slash_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            pallet_subtensor::OffenseSlashFraction::<Test>::get(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_offense_slash_fraction(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            pallet_subtensor::OffenseSlashFraction::<Test>::get(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_staking_rate_limit_exemption() {
+    new_test_ext().execute_with(|| {
+        let coldkey = U256::from(7);
+        assert_eq!(
+            AdminUtils::sudo_set_staking_rate_limit_exemption(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                coldkey,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert!(!pallet_subtensor::StakingRateLimitExempt::<Test>::get(
+            coldkey
+        ));
+        assert_ok!(AdminUtils::sudo_set_staking_rate_limit_exemption(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey,
+            true
+        ));
+        assert!(pallet_subtensor::StakingRateLimitExempt::<Test>::get(
+            coldkey
+        ));
+        assert_ok!(AdminUtils::sudo_set_staking_rate_limit_exemption(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey,
+            false
+        ));
+        assert!(!pallet_subtensor::StakingRateLimitExempt::<Test>::get(
+            coldkey
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_stake_lock_boost() {
+    new_test_ext().execute_with(|| {
+        let period = pallet_subtensor::StakeLockPeriod::Days90;
+        assert_eq!(
+            AdminUtils::sudo_set_stake_lock_boost(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                period,
+                40
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(pallet_subtensor::StakeLockBoost::<Test>::get(period), None);
+        assert_ok!(AdminUtils::sudo_set_stake_lock_boost(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            period,
+            40
+        ));
+        assert_eq!(
+            pallet_subtensor::StakeLockBoost::<Test>::get(period),
+            Some(40)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_identity_fee() {
+    new_test_ext().execute_with(|| {
+        let to_be_set = TaoCurrency::from(1_000_000_000);
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_identity_fee(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            pallet_subtensor::SubnetIdentityFee::<Test>::get(),
+            TaoCurrency::ZERO
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_identity_fee(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            pallet_subtensor::SubnetIdentityFee::<Test>::get(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_bonds_reset_on_deregistration() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_bonds_reset_on_deregistration(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_bonds_reset_on_deregistration(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(AdminUtils::sudo_set_bonds_reset_on_deregistration(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_bonds_reset_on_deregistration(netuid));
+
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+        assert_ok!(AdminUtils::sudo_set_bonds_reset_on_deregistration(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            false
+        ));
+        assert!(!SubtensorModule::get_bonds_reset_on_deregistration(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_liquid_alpha_bounds() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            AdminUtils::sudo_set_liquid_alpha_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                2_000,
+                60_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_liquid_alpha_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                60_000,
+                2_000
+            ),
+            SubtensorError::<Test>::InvalidLiquidAlphaBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_liquid_alpha_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            2_000,
+            60_000
+        ));
+        assert_eq!(pallet_subtensor::MinLiquidAlphaLow::<Test>::get(), 2_000);
+        assert_eq!(pallet_subtensor::MaxLiquidAlphaHigh::<Test>::get(), 60_000);
+    });
+}
+
+#[test]
+fn test_sudo_set_miner_emission_vesting_period() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_miner_emission_vesting_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                7200
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_miner_emission_vesting_period(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                pallet_subtensor::MAX_MINER_EMISSION_VESTING_PERIOD + 1
+            ),
+            SubtensorError::<Test>::MinerEmissionVestingPeriodTooLong
+        );
+        assert_ok!(AdminUtils::sudo_set_miner_emission_vesting_period(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            7200
+        ));
+        assert_eq!(
+            SubtensorModule::get_miner_emission_vesting_period(netuid),
+            7200
+        );
+    });
+}
+
+#[test]
+fn test_sudo_emission_circuit_breaker() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+
+        assert_eq!(
+            AdminUtils::sudo_set_emission_circuit_breaker_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                300
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_emission_circuit_breaker_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            300
+        ));
+        assert_eq!(
+            pallet_subtensor::EmissionCircuitBreakerThreshold::<Test>::get(),
+            300
+        );
+
+        pallet_subtensor::SubnetEmissionHalted::<Test>::insert(netuid, true);
+        assert_eq!(
+            AdminUtils::sudo_reset_emission_circuit_breaker(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                Some(netuid)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_reset_emission_circuit_breaker(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Some(netuid)
+        ));
+        assert!(!SubtensorModule::is_emission_halted(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_burn_controller_gains() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_burn_controller_gains(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                32767,
+                6553,
+                6553
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_burn_controller_gains(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            32767,
+            6553,
+            6553
+        ));
+        assert_eq!(
+            SubtensorModule::get_burn_controller_gains(netuid),
+            (32767, 6553, 6553)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_registration_pow_algorithm() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_registration_pow_algorithm(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                pallet_subtensor::RegistrationPowAlgorithm::Argon2id
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_registration_pow_algorithm(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            pallet_subtensor::RegistrationPowAlgorithm::Argon2id
+        ));
+        assert_eq!(
+            SubtensorModule::get_registration_pow_algorithm(netuid),
+            pallet_subtensor::RegistrationPowAlgorithm::Argon2id
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_incentive_matching() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+        let cap = TaoCurrency::from(1_000_000_000);
+
+        assert_eq!(
+            AdminUtils::sudo_set_incentive_matching(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                cap,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_incentive_matching(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                cap,
+                100
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_ok!(AdminUtils::sudo_set_incentive_matching(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            cap,
+            100
+        ));
+        assert_eq!(
+            pallet_subtensor::IncentiveMatching::<Test>::get(netuid).map(|s| (s.cap, s.end_block)),
+            Some((cap, 100))
+        );
+
+        // A zero cap removes the schedule.
+        assert_ok!(AdminUtils::sudo_set_incentive_matching(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            TaoCurrency::ZERO,
+            100
+        ));
+        assert!(pallet_subtensor::IncentiveMatching::<Test>::get(netuid).is_none());
+    });
+}
+
+#[test]
+fn test_sudo_set_max_weight_age() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_max_weight_age(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                7200
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_max_weight_age(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            7200
+        ));
+        assert_eq!(SubtensorModule::get_max_weight_age(netuid), 7200);
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_transaction_fee() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_transaction_fee(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                1_000_000.into()
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_transaction_fee(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                1_000_000_000.into()
+            ),
+            Error::<Test>::SubnetTransactionFeeTooHigh
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_transaction_fee(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            1_000_000.into()
+        ));
+        assert_eq!(
+            SubtensorModule::get_subnet_transaction_fee(netuid),
+            1_000_000.into()
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_subnet_treasury_share() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_treasury_share(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                6553
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_subnet_treasury_share(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            6553
+        ));
+        assert_eq!(SubtensorModule::get_subnet_treasury_share(netuid), 6553);
+    });
+}
+
+#[test]
+fn test_sudo_set_multi_block_epoch_chunk_size() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_multi_block_epoch_chunk_size(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                64
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_multi_block_epoch_chunk_size(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            64
+        ));
+        assert_eq!(
+            SubtensorModule::get_multi_block_epoch_chunk_size(netuid),
+            64
+        );
+    });
+}
+
+#[test]
+fn test_sudo_grow_max_allowed_uids() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        let owner = U256::from(10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        run_to_block(1);
+
+        // Fill the subnet, and leave a stale weights row behind at a slot it grows into
+        let max_allowed_uids = SubtensorModule::get_max_allowed_uids(netuid);
+        for i in 0..max_allowed_uids {
+            let key = U256::from(100 + i);
+            register_ok_neuron(netuid, key, key, i as u64 * 100_000);
+        }
+        pallet_subtensor::Weights::<Test>::insert(netuid, max_allowed_uids, vec![(0, 1)]);
+
+        assert_eq!(
+            AdminUtils::sudo_grow_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(11)),
+                netuid,
+                max_allowed_uids + 2
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_grow_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                max_allowed_uids
+            ),
+            Err(SubtensorError::<Test>::MaxAllowedUidsNotIncreased.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_grow_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                max_allowed_uids + 257
+            ),
+            Err(SubtensorError::<Test>::MaxAllowedUidsGrowthTooLarge.into())
+        );
+        assert_ok!(AdminUtils::sudo_grow_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            max_allowed_uids + 2
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids(netuid),
+            max_allowed_uids + 2
+        );
+        assert!(!pallet_subtensor::Weights::<Test>::contains_key(
+            netuid,
+            max_allowed_uids
+        ));
+
+        // New registrations fill the new slots without pruning anyone
+        let key = U256::from(200);
+        register_ok_neuron(netuid, key, key, 1_000_000);
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &key),
+            Ok(max_allowed_uids)
+        );
+        for i in 0..max_allowed_uids {
+            assert_eq!(
+                SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(100 + i)),
+                Ok(i)
+            );
+        }
+        assert_eq!(
+            SubtensorModule::get_rank(netuid).len(),
+            max_allowed_uids as usize + 1
+        );
+
+        // Owner growth is rate limited, root growth is not
+        assert_eq!(
+            AdminUtils::sudo_grow_max_allowed_uids(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                max_allowed_uids + 4
+            ),
+            Err(SubtensorError::<Test>::TxRateLimitExceeded.into())
+        );
+        assert_ok!(AdminUtils::sudo_grow_max_allowed_uids(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            max_allowed_uids + 4
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_allowed_uids(netuid),
+            max_allowed_uids + 4
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_call_disabled() {
+    new_test_ext().execute_with(|| {
+        let call_index: u8 = 71;
+        assert!(!SubtensorModule::is_call_disabled(call_index));
+        assert_eq!(
+            AdminUtils::sudo_set_call_disabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                call_index,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_call_disabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            call_index,
+            true
+        ));
+        assert!(SubtensorModule::is_call_disabled(call_index));
+        assert!(!SubtensorModule::is_call_disabled(call_index + 1));
+        assert_ok!(AdminUtils::sudo_set_call_disabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            call_index,
+            false
+        ));
+        assert!(!SubtensorModule::is_call_disabled(call_index));
+    });
+}
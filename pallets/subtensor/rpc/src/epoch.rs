@@ -0,0 +1,70 @@
+//! Pubsub notifications for subnet epochs
+
+use codec::Encode;
+use futures::{FutureExt, StreamExt, future};
+use jsonrpsee::{PendingSubscriptionSink, proc_macros::rpc};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::{SubscriptionTaskExecutor, utils::pipe_from_stream};
+use sp_api::ProvideRuntimeApi;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+use crate::SubnetInfoRuntimeApi;
+
+#[rpc(client, server)]
+pub trait SubtensorEpochApi {
+    /// Notifies with the SCALE-encoded `Vec<EpochSummary>` of every imported best block in which
+    /// at least one subnet ran its epoch.
+    #[subscription(
+        name = "subtensor_subscribeEpoch" => "subtensor_epoch",
+        unsubscribe = "subtensor_unsubscribeEpoch",
+        item = Vec<u8>
+    )]
+    fn subscribe_epoch(&self);
+}
+
+pub struct SubtensorEpoch<C, Block> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    /// Executor used to drive the subscription streams.
+    executor: SubscriptionTaskExecutor,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> SubtensorEpoch<C, Block> {
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self {
+            client,
+            executor,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> SubtensorEpochApiServer for SubtensorEpoch<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+    C::Api: SubnetInfoRuntimeApi<Block>,
+{
+    fn subscribe_epoch(&self, pending: PendingSubscriptionSink) {
+        let client = self.client.clone();
+        let stream = self
+            .client
+            .import_notification_stream()
+            .filter(|notification| future::ready(notification.is_new_best))
+            .filter_map(move |notification| {
+                let summaries = client
+                    .runtime_api()
+                    .get_epoch_summaries(notification.hash)
+                    .ok()
+                    .filter(|summaries| !summaries.is_empty())
+                    .map(|summaries| summaries.encode());
+                future::ready(summaries)
+            });
+
+        let fut = pipe_from_stream(pending, stream);
+        self.executor
+            .spawn("subtensor-epoch-subscription", Some("rpc"), fut.boxed());
+    }
+}
@@ -6,16 +6,23 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::{ErrorObjectOwned, error::ErrorObject},
 };
+use pallet_subtensor::rpc_info::metagraph::SelectiveMetagraphIndex;
+use sc_client_api::ProofProvider;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{AccountId32, traits::Block as BlockT};
+use sp_runtime::{
+    AccountId32,
+    traits::{Block as BlockT, HashingFor, Header as HeaderT},
+};
 use std::sync::Arc;
 use subtensor_runtime_common::{NetUid, TaoCurrency};
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
+
+pub mod epoch;
 
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    AccountProfileRuntimeApi, DelegateInfoRuntimeApi, DryRunRuntimeApi, NeuronInfoRuntimeApi,
+    StakeInfoRuntimeApi, SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -34,6 +41,14 @@ pub trait SubtensorCustomApi<BlockHash> {
         delegatee_account_vec: Vec<u8>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegateReturnRates")]
+    fn get_delegate_return_rates(&self, epochs: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getHotkeyHierarchy")]
+    fn get_hotkey_hierarchy(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "neuronInfo_getNeuronsLite")]
     fn get_neurons_lite(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -48,6 +63,10 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: NetUid, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronConnectivity")]
+    fn get_neuron_connectivity(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronAxonsV2")]
+    fn get_neuron_axons_v2(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetsInfo")]
@@ -76,6 +95,12 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnet_state(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<TaoCurrency>;
+    #[method(name = "subnetInfo_getLockCostProjection")]
+    fn get_network_lock_cost_projection(
+        &self,
+        blocks: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<TaoCurrency>>;
     #[method(name = "subnetInfo_getSelectiveMetagraph")]
     fn get_selective_metagraph(
         &self,
@@ -83,8 +108,113 @@ pub trait SubtensorCustomApi<BlockHash> {
         metagraph_index: Vec<u16>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getMetagraphPaginated")]
+    fn get_metagraph_paginated(
+        &self,
+        netuid: NetUid,
+        start_uid: u16,
+        limit: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeInfoForColdkey")]
+    fn get_stake_info_for_coldkey(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeInfoForColdkeys")]
+    fn get_stake_info_for_coldkeys(
+        &self,
+        coldkey_account_vecs: Vec<Vec<u8>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeForColdkeyLite")]
+    fn get_stake_for_coldkey_lite(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getStakeProof")]
+    fn get_stake_proof(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "stakeInfo_getTotalHotkeyStake")]
+    fn get_total_hotkey_stake(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TaoCurrency>;
+    #[method(name = "subnetInfo_getSubnetScheduleInfo")]
+    fn get_subnet_schedule_info(&self, netuid: NetUid, at: Option<BlockHash>)
+    -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getChainSummary")]
+    fn get_chain_summary(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getConsensusArtifacts")]
+    fn get_consensus_artifacts(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getCommitRevealStatus")]
+    fn get_commit_reveal_status(
+        &self,
+        hotkey_account_vec: Option<Vec<u8>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_getAccountProfile")]
+    fn get_account_profile(
+        &self,
+        account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_dryRun")]
+    fn dry_run(&self, extrinsic: Vec<u8>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_estimateFee")]
+    fn estimate_fee(&self, extrinsic: Vec<u8>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subtensor_epochDryRun")]
+    fn epoch_dry_run(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getWeightsSparse")]
+    fn get_weights_sparse(
+        &self,
+        netuid: NetUid,
+        compress: Option<bool>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getPoolState")]
+    fn get_pool_state(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllPoolStates")]
+    fn get_all_pool_states(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getPruningInfo")]
+    fn get_pruning_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetSupplyInfo")]
+    fn get_subnet_supply_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllSubnetSupplyInfo")]
+    fn get_all_subnet_supply_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetIdentity")]
+    fn get_subnet_identity_info(&self, netuid: NetUid, at: Option<BlockHash>)
+    -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getAllSubnetIdentities")]
+    fn get_all_subnet_identity_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    /// `field_mask` is a hex string such as `0x1f`, bit `i` selecting metagraph field `i`, as
+    /// JSON numbers cannot hold all 128 bits.
+    #[method(name = "subnetInfo_getSelectiveMetagraphByMask")]
+    fn get_selective_metagraph_by_mask(
+        &self,
+        netuid: NetUid,
+        field_mask: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getStaleWeightsInfo")]
+    fn get_stale_weights_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getWeightsVersionInfo")]
+    fn get_weights_version_info(&self, netuid: NetUid, at: Option<BlockHash>)
+    -> RpcResult<Vec<u8>>;
 }
 
+/// zstd compression level used for compressed RPC responses.
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+/// Maximum number of coldkeys accepted by a single `stakeInfo_getStakeInfoForColdkeys` call.
+pub const MAX_STAKE_INFO_BATCH_SIZE: usize = 256;
+
 pub struct SubtensorCustom<C, P> {
     /// Shared reference to the client.
     client: Arc<C>,
@@ -123,14 +253,72 @@ impl From<Error> for i32 {
     }
 }
 
+/// Parses a metagraph field mask given as a hex string, with or without the `0x` prefix.
+fn parse_field_mask(mask: &str) -> Result<u128, Error> {
+    let digits = mask.strip_prefix("0x").unwrap_or(mask);
+    u128::from_str_radix(digits, 16)
+        .map_err(|e| Error::RuntimeError(format!("Invalid field mask {mask:?}: {e}")))
+}
+
+impl<C, P> SubtensorCustom<C, P> {
+    /// Returns the version of the runtime API `Api` implemented by the runtime at `at`, failing
+    /// if the runtime predates the API. `name` is only used in error messages.
+    fn runtime_api_version<Block, Api>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        name: &str,
+    ) -> Result<u32, Error>
+    where
+        Block: BlockT,
+        C: ProvideRuntimeApi<Block>,
+        Api: RuntimeApiInfo + ?Sized,
+    {
+        self.client
+            .runtime_api()
+            .api_version::<Api>(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get runtime API version: {e:?}")))?
+            .ok_or_else(|| Error::RuntimeError(format!("{name} is not available at block {at:?}")))
+    }
+
+    /// Like [`Self::runtime_api_version`], but also fails if the runtime does not implement at
+    /// least `min_version` of the API.
+    fn require_api_version<Block, Api>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        name: &str,
+        min_version: u32,
+    ) -> Result<u32, Error>
+    where
+        Block: BlockT,
+        C: ProvideRuntimeApi<Block>,
+        Api: RuntimeApiInfo + ?Sized,
+    {
+        let version = self.runtime_api_version::<Block, Api>(at, name)?;
+        if version < min_version {
+            return Err(Error::RuntimeError(format!(
+                "{name} v{min_version} is required but block {at:?} only supports v{version}"
+            )));
+        }
+        Ok(version)
+    }
+}
+
 impl<C, Block> SubtensorCustomApiServer<<Block as BlockT>::Hash> for SubtensorCustom<C, Block>
 where
     Block: BlockT,
-    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + ProofProvider<Block>
+        + Send
+        + Sync
+        + 'static,
     C::Api: DelegateInfoRuntimeApi<Block>,
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
+    C::Api: DryRunRuntimeApi<Block>,
+    C::Api: AccountProfileRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
@@ -411,6 +599,26 @@ where
         })
     }
 
+    fn get_network_lock_cost_projection(
+        &self,
+        blocks: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<TaoCurrency>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetRegistrationRuntimeApi<Block>>(
+            at,
+            "SubnetRegistrationRuntimeApi",
+            2,
+        )?;
+
+        api.get_network_registration_cost_projection(at, blocks)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnet lock cost projection: {e:?}"))
+                    .into()
+            })
+    }
+
     fn get_selective_metagraph(
         &self,
         netuid: NetUid,
@@ -427,4 +635,688 @@ where
             }
         }
     }
+
+    fn get_metagraph_paginated(
+        &self,
+        netuid: NetUid,
+        start_uid: u16,
+        limit: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        // Runtimes before v2 cannot paginate; fall back to the full metagraph.
+        let version = self.runtime_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+        )?;
+        let result = if version >= 2 {
+            api.get_metagraph_paginated(at, netuid, start_uid, limit)
+        } else {
+            api.get_metagraph(at, netuid)
+        };
+        match result {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get paginated metagraph: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_selective_metagraph_by_mask(
+        &self,
+        netuid: NetUid,
+        field_mask: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let field_mask = parse_field_mask(&field_mask)?;
+
+        // Runtimes before v2 only take explicit indexes, so expand the mask on the node side.
+        let version = self.runtime_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+        )?;
+        let result = if version >= 2 {
+            api.get_selective_metagraph_by_mask(at, netuid, field_mask)
+        } else {
+            api.get_selective_metagraph(
+                at,
+                netuid,
+                SelectiveMetagraphIndex::indexes_from_mask(field_mask),
+            )
+        };
+        match result {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get selective metagraph: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_stake_info_for_coldkey(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn StakeInfoRuntimeApi<Block>>(
+            at,
+            "StakeInfoRuntimeApi",
+            1,
+        )?;
+
+        let coldkey_account = match AccountId32::decode(&mut &coldkey_account_vec[..]) {
+            Ok(coldkey_account) => coldkey_account,
+            Err(e) => {
+                return Err(Error::RuntimeError(format!("Unable to get stake info: {e:?}")).into());
+            }
+        };
+        match api.get_stake_info_for_coldkey(at, coldkey_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get stake info: {e:?}")).into()),
+        }
+    }
+
+    fn get_stake_for_coldkey_lite(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn StakeInfoRuntimeApi<Block>>(
+            at,
+            "StakeInfoRuntimeApi",
+            3,
+        )?;
+
+        let coldkey_account = AccountId32::decode(&mut &coldkey_account_vec[..])
+            .map_err(|e| Error::RuntimeError(format!("Unable to get stake: {e:?}")))?;
+        match api.get_stake_for_coldkey_lite(at, coldkey_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get stake: {e:?}")).into()),
+        }
+    }
+
+    fn get_stake_proof(
+        &self,
+        coldkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn StakeInfoRuntimeApi<Block>>(
+            at,
+            "StakeInfoRuntimeApi",
+            3,
+        )?;
+
+        let error = |e: String| Error::RuntimeError(format!("Unable to get stake proof: {e}"));
+        let coldkey_account = AccountId32::decode(&mut &coldkey_account_vec[..])
+            .map_err(|e| error(format!("{e:?}")))?;
+        let keys = api
+            .get_stake_proof_keys(at, coldkey_account)
+            .map_err(|e| error(format!("{e:?}")))?;
+        let state_root = *self
+            .client
+            .header(at)
+            .map_err(|e| error(format!("{e:?}")))?
+            .ok_or_else(|| error(format!("unknown block {at:?}")))?
+            .state_root();
+        let proof = self
+            .client
+            .read_proof(at, &mut keys.iter().map(|key| key.as_slice()))
+            .map_err(|e| error(format!("{e:?}")))?
+            .into_compact_proof::<HashingFor<Block>>(state_root)
+            .map_err(|e| error(format!("{e:?}")))?;
+
+        Ok((at, keys, proof).encode())
+    }
+
+    fn get_total_hotkey_stake(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TaoCurrency> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn StakeInfoRuntimeApi<Block>>(
+            at,
+            "StakeInfoRuntimeApi",
+            2,
+        )?;
+
+        let hotkey_account = match AccountId32::decode(&mut &hotkey_account_vec[..]) {
+            Ok(hotkey_account) => hotkey_account,
+            Err(e) => {
+                return Err(Error::RuntimeError(format!(
+                    "Unable to get total hotkey stake: {e:?}"
+                ))
+                .into());
+            }
+        };
+        api.get_total_stake_for_hotkey(at, hotkey_account)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get total hotkey stake: {e:?}")).into()
+            })
+    }
+
+    fn get_stake_info_for_coldkeys(
+        &self,
+        coldkey_account_vecs: Vec<Vec<u8>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn StakeInfoRuntimeApi<Block>>(
+            at,
+            "StakeInfoRuntimeApi",
+            2,
+        )?;
+
+        if coldkey_account_vecs.len() > MAX_STAKE_INFO_BATCH_SIZE {
+            return Err(Error::RuntimeError(format!(
+                "Too many coldkeys: {} > {MAX_STAKE_INFO_BATCH_SIZE}",
+                coldkey_account_vecs.len()
+            ))
+            .into());
+        }
+
+        let mut coldkey_accounts = Vec::with_capacity(coldkey_account_vecs.len());
+        for coldkey_account_vec in coldkey_account_vecs {
+            match AccountId32::decode(&mut &coldkey_account_vec[..]) {
+                Ok(coldkey_account) => coldkey_accounts.push(coldkey_account),
+                Err(e) => {
+                    return Err(
+                        Error::RuntimeError(format!("Unable to get stake info: {e:?}")).into(),
+                    );
+                }
+            }
+        }
+        match api.get_stake_info_for_coldkeys(at, coldkey_accounts) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get stake info: {e:?}")).into()),
+        }
+    }
+
+    fn get_delegate_return_rates(
+        &self,
+        epochs: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn DelegateInfoRuntimeApi<Block>>(
+            at,
+            "DelegateInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_delegate_return_rates(at, epochs) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get delegate return rates: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_hotkey_hierarchy(
+        &self,
+        hotkey_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn DelegateInfoRuntimeApi<Block>>(
+            at,
+            "DelegateInfoRuntimeApi",
+            2,
+        )?;
+
+        let hotkey_account = match AccountId32::decode(&mut &hotkey_account_vec[..]) {
+            Ok(hotkey_account) => hotkey_account,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get hotkey hierarchy: {e:?}")).into(),
+                );
+            }
+        };
+        match api.get_hotkey_hierarchy(at, hotkey_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get hotkey hierarchy: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_subnet_schedule_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_subnet_schedule_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get subnet schedule info: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_chain_summary(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_chain_summary(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get chain summary: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_consensus_artifacts(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_consensus_artifacts(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get consensus artifacts: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_commit_reveal_status(
+        &self,
+        hotkey_account_vec: Option<Vec<u8>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        let hotkey_account = match hotkey_account_vec
+            .map(|vec| AccountId32::decode(&mut &vec[..]))
+            .transpose()
+        {
+            Ok(hotkey_account) => hotkey_account,
+            Err(e) => {
+                return Err(Error::RuntimeError(format!(
+                    "Unable to get commit reveal status: {e:?}"
+                ))
+                .into());
+            }
+        };
+        match api.get_commit_reveal_status(at, hotkey_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get commit reveal status: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_neuron_connectivity(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn NeuronInfoRuntimeApi<Block>>(
+            at,
+            "NeuronInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_neuron_connectivity(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get neuron connectivity: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_neuron_axons_v2(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn NeuronInfoRuntimeApi<Block>>(
+            at,
+            "NeuronInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_neuron_axons_v2(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get neuron axons: {e:?}")).into()),
+        }
+    }
+
+    fn dry_run(
+        &self,
+        extrinsic: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let extrinsic = match <Block as BlockT>::Extrinsic::decode(&mut &extrinsic[..]) {
+            Ok(extrinsic) => extrinsic,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to decode extrinsic: {e:?}")).into(),
+                );
+            }
+        };
+        match api.dry_run_extrinsic(at, extrinsic) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to dry run extrinsic: {e:?}")).into())
+            }
+        }
+    }
+
+    fn estimate_fee(
+        &self,
+        extrinsic: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let extrinsic = match <Block as BlockT>::Extrinsic::decode(&mut &extrinsic[..]) {
+            Ok(extrinsic) => extrinsic,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to decode extrinsic: {e:?}")).into(),
+                );
+            }
+        };
+        match api.estimate_fee(at, extrinsic) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to estimate fee: {e:?}")).into()),
+        }
+    }
+
+    fn epoch_dry_run(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.epoch_dry_run(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to dry run epoch: {e:?}")).into()),
+        }
+    }
+
+    fn get_weights_sparse(
+        &self,
+        netuid: NetUid,
+        compress: Option<bool>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        let encoded = match api.get_weights_sparse(at, netuid) {
+            Ok(result) => result.encode(),
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get sparse weights: {e:?}")).into(),
+                );
+            }
+        };
+        if !compress.unwrap_or(false) {
+            return Ok(encoded);
+        }
+        zstd::encode_all(&encoded[..], ZSTD_COMPRESSION_LEVEL).map_err(|e| {
+            Error::RuntimeError(format!("Unable to compress sparse weights: {e:?}")).into()
+        })
+    }
+
+    fn get_account_profile(
+        &self,
+        account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let account = match AccountId32::decode(&mut &account_vec[..]) {
+            Ok(account) => account,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get account profile: {e:?}")).into(),
+                );
+            }
+        };
+        match api.get_account_profile(at, account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get account profile: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_pool_state(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_pool_state(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get pool state: {e:?}")).into()),
+        }
+    }
+
+    fn get_all_pool_states(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_all_pool_states(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get pool states: {e:?}")).into()),
+        }
+    }
+
+    fn get_pruning_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_pruning_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get pruning info: {e:?}")).into()),
+        }
+    }
+
+    fn get_subnet_supply_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_subnet_supply_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet supply info: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_all_subnet_supply_info(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_all_subnet_supply_info(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet supply info: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_subnet_identity_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_subnet_identity_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet identity: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_all_subnet_identity_info(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_all_subnet_identity_info(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet identities: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_stale_weights_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_stale_weights_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get stale weights info: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_weights_version_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.require_api_version::<Block, dyn SubnetInfoRuntimeApi<Block>>(
+            at,
+            "SubnetInfoRuntimeApi",
+            2,
+        )?;
+
+        match api.get_weights_version_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get weights version info: {e:?}"
+            ))
+            .into()),
+        }
+    }
 }
@@ -3,33 +3,59 @@ extern crate alloc;
 use alloc::vec::Vec;
 use codec::Compact;
 use pallet_subtensor::rpc_info::{
-    delegate_info::DelegateInfo,
+    account_profile::AccountProfile,
+    chain_summary::ChainSummary,
+    child_info::HotkeyHierarchy,
+    commit_reveal_info::CommitRevealStatus,
+    consensus_info::ConsensusArtifacts,
+    delegate_info::{DelegateInfo, DelegateReturnRate},
+    dry_run::{DryRunInfo, EpochDryRun},
     dynamic_info::DynamicInfo,
+    epoch_summary::EpochSummary,
+    fee_estimate::FeeEstimate,
+    identity_info::SubnetIdentityInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
-    neuron_info::{NeuronInfo, NeuronInfoLite},
+    neuron_info::{NeuronAxonInfo, NeuronConnectivity, NeuronInfo, NeuronInfoLite},
+    pool_info::SubnetPoolState,
+    pruning_info::PruningInfo,
     show_subnet::SubnetState,
     stake_info::StakeInfo,
-    subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    subnet_info::{
+        SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2, SubnetScheduleInfo,
+    },
+    supply_info::SubnetSupplyInfo,
+    weights_info::{StaleWeightsInfo, SubnetWeightsSparse, WeightsVersionInfo},
 };
-use sp_runtime::AccountId32;
+use sp_runtime::{AccountId32, traits::Block as BlockT};
 use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // src/neuron_info.rs, src/subnet_info.rs, and src/delegate_info.rs
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait DelegateInfoRuntimeApi {
         fn get_delegates() -> Vec<DelegateInfo<AccountId32>>;
         fn get_delegate( delegate_account: AccountId32 ) -> Option<DelegateInfo<AccountId32>>;
         fn get_delegated( delegatee_account: AccountId32 ) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))>;
+        #[api_version(2)]
+        fn get_delegate_return_rates( epochs: u16 ) -> Vec<DelegateReturnRate<AccountId32>>;
+        #[api_version(2)]
+        fn get_hotkey_hierarchy( hotkey_account: AccountId32 ) -> Vec<HotkeyHierarchy<AccountId32>>;
     }
 
+    #[api_version(2)]
     pub trait NeuronInfoRuntimeApi {
         fn get_neurons(netuid: NetUid) -> Vec<NeuronInfo<AccountId32>>;
         fn get_neuron(netuid: NetUid, uid: u16) -> Option<NeuronInfo<AccountId32>>;
         fn get_neurons_lite(netuid: NetUid) -> Vec<NeuronInfoLite<AccountId32>>;
         fn get_neuron_lite(netuid: NetUid, uid: u16) -> Option<NeuronInfoLite<AccountId32>>;
+        #[api_version(2)]
+        fn get_neuron_connectivity(netuid: NetUid) -> Vec<NeuronConnectivity<AccountId32>>;
+        #[api_version(2)]
+        fn get_neuron_axons_v2(netuid: NetUid) -> Vec<NeuronAxonInfo<AccountId32>>;
     }
 
+    #[api_version(2)]
     pub trait SubnetInfoRuntimeApi {
         fn get_subnet_info(netuid: NetUid) -> Option<SubnetInfo<AccountId32>>;
         fn get_subnets_info() -> Vec<Option<SubnetInfo<AccountId32>>>;
@@ -43,16 +69,71 @@ sp_api::decl_runtime_apis! {
         fn get_dynamic_info(netuid: NetUid) -> Option<DynamicInfo<AccountId32>>;
         fn get_subnet_state(netuid: NetUid) -> Option<SubnetState<AccountId32>>;
         fn get_selective_metagraph(netuid: NetUid, metagraph_indexes: Vec<u16>) -> Option<SelectiveMetagraph<AccountId32>>;
+        #[api_version(2)]
+        fn get_metagraph_paginated(netuid: NetUid, start_uid: u16, limit: u16) -> Option<Metagraph<AccountId32>>;
+        #[api_version(2)]
+        fn get_selective_metagraph_by_mask(netuid: NetUid, field_mask: u128) -> Option<SelectiveMetagraph<AccountId32>>;
+        #[api_version(2)]
+        fn get_epoch_summaries() -> Vec<EpochSummary>;
+        #[api_version(2)]
+        fn get_subnet_schedule_info(netuid: NetUid) -> Option<SubnetScheduleInfo>;
+        #[api_version(2)]
+        fn get_chain_summary() -> ChainSummary;
+        #[api_version(2)]
+        fn get_consensus_artifacts(netuid: NetUid) -> Option<ConsensusArtifacts>;
+        #[api_version(2)]
+        fn get_commit_reveal_status( hotkey_account: Option<AccountId32> ) -> Vec<CommitRevealStatus>;
+        #[api_version(2)]
+        fn get_weights_sparse(netuid: NetUid) -> Option<SubnetWeightsSparse>;
+        #[api_version(2)]
+        fn get_pool_state(netuid: NetUid) -> Option<SubnetPoolState>;
+        #[api_version(2)]
+        fn get_all_pool_states() -> Vec<SubnetPoolState>;
+        #[api_version(2)]
+        fn get_pruning_info(netuid: NetUid) -> Option<PruningInfo>;
+        #[api_version(2)]
+        fn get_subnet_supply_info(netuid: NetUid) -> Option<SubnetSupplyInfo>;
+        #[api_version(2)]
+        fn get_all_subnet_supply_info() -> Vec<SubnetSupplyInfo>;
+        #[api_version(2)]
+        fn get_subnet_identity_info(netuid: NetUid) -> Option<SubnetIdentityInfo>;
+        #[api_version(2)]
+        fn get_all_subnet_identity_info() -> Vec<SubnetIdentityInfo>;
+        #[api_version(2)]
+        fn get_stale_weights_info(netuid: NetUid) -> Option<StaleWeightsInfo>;
+        #[api_version(2)]
+        fn get_weights_version_info(netuid: NetUid) -> Option<WeightsVersionInfo>;
     }
 
+    #[api_version(3)]
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account: AccountId32 ) -> Vec<StakeInfo<AccountId32>>;
+        #[api_version(2)]
         fn get_stake_info_for_coldkeys( coldkey_accounts: Vec<AccountId32> ) -> Vec<(AccountId32, Vec<StakeInfo<AccountId32>>)>;
         fn get_stake_info_for_hotkey_coldkey_netuid( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: NetUid ) -> Option<StakeInfo<AccountId32>>;
         fn get_stake_fee( origin: Option<(AccountId32, NetUid)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, NetUid)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64;
+        #[api_version(2)]
+        fn get_total_stake_for_hotkey( hotkey_account: AccountId32 ) -> TaoCurrency;
+        #[api_version(3)]
+        fn get_stake_for_coldkey_lite( coldkey_account: AccountId32 ) -> Vec<(AccountId32, Compact<NetUid>, Compact<AlphaCurrency>)>;
+        #[api_version(3)]
+        fn get_stake_proof_keys( coldkey_account: AccountId32 ) -> Vec<Vec<u8>>;
     }
 
+    #[api_version(2)]
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> TaoCurrency;
+        #[api_version(2)]
+        fn get_network_registration_cost_projection( blocks: u64 ) -> Vec<TaoCurrency>;
+    }
+
+    pub trait AccountProfileRuntimeApi {
+        fn get_account_profile( account: AccountId32 ) -> AccountProfile<AccountId32>;
+    }
+
+    pub trait DryRunRuntimeApi {
+        fn dry_run_extrinsic( extrinsic: <Block as BlockT>::Extrinsic ) -> DryRunInfo;
+        fn estimate_fee( extrinsic: <Block as BlockT>::Extrinsic ) -> FeeEstimate;
+        fn epoch_dry_run( netuid: NetUid ) -> Option<EpochDryRun<AccountId32>>;
     }
 }
@@ -17,6 +17,10 @@ use sp_runtime::{
 use sp_std::vec;
 use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
 
+use crate::swap::coldkey_recovery::{
+    ColdkeyRecovery, ColdkeyRecoveryConfig, MAX_COLDKEY_RECOVERY_FRIENDS,
+};
+
 #[frame_benchmarking::v2::benchmarks]
 mod pallet_benchmarks {
     use super::*;
@@ -31,6 +35,9 @@ mod pallet_benchmarks {
         Subtensor::<T>::init_new_network(netuid, tempo);
         Subtensor::<T>::set_network_registration_allowed(netuid, true);
         Subtensor::<T>::set_network_pow_registration_allowed(netuid, true);
+        // Verifying the memory-hard proof of work is the worst case.
+        Subtensor::<T>::set_registration_pow_algorithm(netuid, RegistrationPowAlgorithm::Argon2id);
+        Subtensor::<T>::set_difficulty(netuid, 1);
 
         let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
         let (nonce, work): (u64, Vec<u8>) =
@@ -198,6 +205,47 @@ mod pallet_benchmarks {
         );
     }
 
+    #[benchmark]
+    fn serve_axon_v2() {
+        let netuid = NetUid::from(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let version: u32 = 2;
+        let protocol_version: u32 = 1;
+        let endpoints: Vec<AxonEndpoint> = (0..MAX_AXON_ENDPOINTS)
+            .map(|i| AxonEndpoint {
+                ip: 1676056785_u128.saturating_add(i.into()),
+                port: 128,
+                ip_type: 4,
+                protocol: 0,
+            })
+            .collect();
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        SubtokenEnabled::<T>::insert(netuid, true);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+
+        let reg_fee = Subtensor::<T>::get_burn(netuid);
+        let deposit = reg_fee.saturating_mul(2.into());
+        Subtensor::<T>::add_balance_to_coldkey_account(&caller, deposit.into());
+
+        assert_ok!(Subtensor::<T>::do_burned_registration(
+            RawOrigin::Signed(caller.clone()).into(),
+            netuid,
+            caller.clone()
+        ));
+        Subtensor::<T>::set_serving_rate_limit(netuid, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            netuid,
+            version,
+            protocol_version,
+            endpoints,
+            Some([1u8; 32]),
+        );
+    }
+
     #[benchmark]
     fn serve_prometheus() {
         let netuid = NetUid::from(1);
@@ -1634,4 +1682,201 @@ mod pallet_benchmarks {
             Subtensor::<T>::get_commit_reveal_weights_version(),
         );
     }
+
+    #[benchmark]
+    fn set_children() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let hotkey: T::AccountId = account("Hot", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_network_registration_allowed(netuid, true);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+        Subtensor::<T>::set_max_registrations_per_block(netuid, 4096);
+        Subtensor::<T>::set_target_registrations_per_interval(netuid, 4096);
+        SubtokenEnabled::<T>::insert(netuid, true);
+
+        // Register the parent and the most children a hotkey can have.
+        let reg_fee = Subtensor::<T>::get_burn(netuid);
+        Subtensor::<T>::add_balance_to_coldkey_account(
+            &coldkey,
+            reg_fee.saturating_mul(10.into()).into(),
+        );
+        assert_ok!(Subtensor::<T>::do_burned_registration(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid,
+            hotkey.clone()
+        ));
+        let mut children: Vec<(u64, T::AccountId)> = Vec::new();
+        for i in 0..5 {
+            let child: T::AccountId = account("Child", i, 1);
+            assert_ok!(Subtensor::<T>::do_burned_registration(
+                RawOrigin::Signed(coldkey.clone()).into(),
+                netuid,
+                child.clone()
+            ));
+            children.push((u64::MAX / 5, child));
+        }
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            hotkey.clone(),
+            netuid,
+            children,
+        );
+    }
+
+    #[benchmark]
+    fn cancel_swap_coldkey() {
+        let old_coldkey: T::AccountId = account("old_cold", 0, 1);
+        let new_coldkey: T::AccountId = account("new_cold", 1, 2);
+        let amount: u64 = 100_000_000_000_000;
+        Subtensor::<T>::add_balance_to_coldkey_account(&old_coldkey, amount);
+
+        assert_ok!(Subtensor::<T>::schedule_swap_coldkey(
+            RawOrigin::Signed(old_coldkey.clone()).into(),
+            new_coldkey.clone()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(old_coldkey.clone()));
+    }
+
+    #[benchmark]
+    fn set_coldkey_recovery() {
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let friends: Vec<T::AccountId> = (0..MAX_COLDKEY_RECOVERY_FRIENDS as u32)
+            .map(|i| account("Friend", i, 1))
+            .collect();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey.clone()),
+            friends,
+            MAX_COLDKEY_RECOVERY_FRIENDS as u16,
+            100u64,
+        );
+    }
+
+    #[benchmark]
+    fn vouch_coldkey_recovery() {
+        let lost_coldkey: T::AccountId = account("Lost", 0, 1);
+        let new_coldkey: T::AccountId = account("New", 0, 1);
+        let friends: Vec<T::AccountId> = (0..MAX_COLDKEY_RECOVERY_FRIENDS as u32)
+            .map(|i| account("Friend", i, 1))
+            .collect();
+
+        // Every friend but the last one already vouched.
+        let (friend, vouches) = friends.split_last().unwrap();
+        ColdkeyRecoveryConfigs::<T>::insert(
+            &lost_coldkey,
+            ColdkeyRecoveryConfig {
+                friends: friends.clone(),
+                threshold: MAX_COLDKEY_RECOVERY_FRIENDS as u16,
+                delay: 100,
+            },
+        );
+        ActiveColdkeyRecoveries::<T>::insert(
+            &lost_coldkey,
+            &new_coldkey,
+            ColdkeyRecovery {
+                started: Subtensor::<T>::get_current_block_as_u64(),
+                vouches: vouches.to_vec(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(friend.clone()),
+            lost_coldkey.clone(),
+            new_coldkey.clone(),
+        );
+    }
+
+    #[benchmark]
+    fn claim_coldkey_recovery() {
+        let lost_coldkey: T::AccountId = account("Lost", 0, 1);
+        let new_coldkey: T::AccountId = account("New", 0, 1);
+        let friend: T::AccountId = account("Friend", 0, 1);
+        let hotkey: T::AccountId = account("Hot", 0, 1);
+        let netuid = NetUid::from(1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_network_registration_allowed(netuid, true);
+        SubtokenEnabled::<T>::insert(netuid, true);
+
+        let reg_fee = Subtensor::<T>::get_burn(netuid);
+        Subtensor::<T>::add_balance_to_coldkey_account(
+            &lost_coldkey,
+            reg_fee.saturating_mul(2.into()).into(),
+        );
+        assert_ok!(Subtensor::<T>::do_burned_registration(
+            RawOrigin::Signed(lost_coldkey.clone()).into(),
+            netuid,
+            hotkey.clone()
+        ));
+        Identities::<T>::insert(
+            &lost_coldkey,
+            ChainIdentity {
+                name: b"Lost Identity".to_vec(),
+                url: vec![],
+                image: vec![],
+                discord: vec![],
+                description: vec![],
+                additional: vec![],
+            },
+        );
+
+        ColdkeyRecoveryConfigs::<T>::insert(
+            &lost_coldkey,
+            ColdkeyRecoveryConfig {
+                friends: vec![friend.clone()],
+                threshold: 1,
+                delay: 0,
+            },
+        );
+        ActiveColdkeyRecoveries::<T>::insert(
+            &lost_coldkey,
+            &new_coldkey,
+            ColdkeyRecovery {
+                started: Subtensor::<T>::get_current_block_as_u64(),
+                vouches: vec![friend],
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(new_coldkey.clone()), lost_coldkey.clone());
+    }
+
+    #[benchmark]
+    fn cancel_coldkey_recovery() {
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let new_coldkey: T::AccountId = account("New", 0, 1);
+        let friend: T::AccountId = account("Friend", 0, 1);
+
+        ActiveColdkeyRecoveries::<T>::insert(
+            &coldkey,
+            &new_coldkey,
+            ColdkeyRecovery {
+                started: Subtensor::<T>::get_current_block_as_u64(),
+                vouches: vec![friend],
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()), new_coldkey.clone());
+    }
+
+    #[benchmark]
+    fn dissolve_subnet() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Test", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        SubnetOwner::<T>::set(netuid, coldkey.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey.clone()), netuid);
+    }
 }
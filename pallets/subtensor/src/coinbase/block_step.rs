@@ -21,6 +21,12 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         Self::run_coinbase(block_emission);
         // --- 4. Set pending children on the epoch; but only after the coinbase has been run.
         Self::try_set_pending_children(block_number);
+        // --- 5. Resume subnets that reached the maximum maintenance duration.
+        Self::resume_expired_subnet_maintenance(block_number);
+        // --- 6. Process the next chunk of the subnet dissolutions.
+        Self::step_network_dissolutions();
+        // --- 7. Apply weights version keys whose grace period has ended.
+        Self::apply_pending_weights_version_keys(block_number);
         // Return ok.
         Ok(())
     }
@@ -54,6 +60,12 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
             if current_block.saturating_sub(last_adjustment_block) >= adjustment_interval as u64 {
                 log::debug!("interval reached.");
 
+                // --- 3.1. Settle the registration auction of the interval, so its registrations
+                // count towards the burn adjustment below.
+                if Self::get_registration_auction_enabled(netuid) {
+                    Self::settle_registration_auction(netuid);
+                }
+
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
                 let current_burn = Self::get_burn(netuid);
                 let current_difficulty: u64 = Self::get_difficulty_as_u64(netuid);
@@ -174,6 +186,20 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                     }
                 }
 
+                // --- 5.1. Subnets with a burn controller adjust the burn every interval, in place
+                // of the adjustment above.
+                if Self::is_burn_controller_enabled(netuid) {
+                    Self::set_burn(
+                        netuid,
+                        Self::controlled_burn(
+                            netuid,
+                            current_burn,
+                            registrations_this_interval,
+                            target_registrations_this_interval,
+                        ),
+                    );
+                }
+
                 // --- 6. Drain all counters for this network for this interval.
                 Self::set_last_adjustment_block(netuid, current_block);
                 Self::set_registrations_this_interval(netuid, 0);
@@ -0,0 +1,95 @@
+//! This file defines the PID controller of the registration burn.
+//!
+//! By default the burn follows the heuristic in `upgraded_burn`, which scales it by the ratio of
+//! registrations to their target. A subnet can instead set the gains of a PID controller with
+//! `BurnControllerGains`. At every adjustment interval, the controller computes the relative error
+//! between the registrations of the interval and `TargetRegistrationsPerInterval`, and scales the
+//! burn by one plus the weighted sum of the error, its integral and its derivative. The integral
+//! lets the burn settle where registrations meet the target under a sustained load, instead of
+//! lagging behind it as a purely proportional adjustment does.
+//!
+//! The integral is bounded to limit windup after bursts, and the burn can at most halve or double
+//! in one interval. The burn stays within `MinBurn` and `MaxBurn`.
+
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::I96F32;
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+
+/// The bound of the integral of the error, in relative registration errors.
+const MAX_BURN_CONTROLLER_INTEGRAL: i32 = 16;
+
+impl<T: Config> Pallet<T> {
+    /// Whether the burn of `netuid` is adjusted by the PID controller.
+    pub fn is_burn_controller_enabled(netuid: NetUid) -> bool {
+        BurnControllerGains::<T>::get(netuid) != (0, 0, 0)
+    }
+
+    /// Calculates the burn of the next interval with the PID controller and records its state.
+    pub fn controlled_burn(
+        netuid: NetUid,
+        current_burn: TaoCurrency,
+        registrations_this_interval: u16,
+        target_registrations_per_interval: u16,
+    ) -> TaoCurrency {
+        let gain = |gain: u16| {
+            I96F32::saturating_from_num(gain).safe_div(I96F32::saturating_from_num(u16::MAX))
+        };
+        let (kp, ki, kd) = BurnControllerGains::<T>::get(netuid);
+
+        // The error relative to the target, positive when there are too many registrations.
+        let error: I96F32 = I96F32::saturating_from_num(registrations_this_interval)
+            .saturating_sub(I96F32::saturating_from_num(
+                target_registrations_per_interval,
+            ))
+            .safe_div(I96F32::saturating_from_num(
+                target_registrations_per_interval.max(1),
+            ));
+        let (integral, last_error) = BurnControllerState::<T>::get(netuid).unwrap_or((
+            I96F32::saturating_from_num(0),
+            I96F32::saturating_from_num(0),
+        ));
+        let integral: I96F32 = integral.saturating_add(error).clamp(
+            I96F32::saturating_from_num(MAX_BURN_CONTROLLER_INTEGRAL.saturating_neg()),
+            I96F32::saturating_from_num(MAX_BURN_CONTROLLER_INTEGRAL),
+        );
+        let derivative: I96F32 = error.saturating_sub(last_error);
+        BurnControllerState::<T>::insert(netuid, (integral, error));
+
+        let output: I96F32 = gain(kp)
+            .saturating_mul(error)
+            .saturating_add(gain(ki).saturating_mul(integral))
+            .saturating_add(gain(kd).saturating_mul(derivative));
+        let factor: I96F32 = I96F32::saturating_from_num(1).saturating_add(output).clamp(
+            I96F32::saturating_from_num(0.5),
+            I96F32::saturating_from_num(2),
+        );
+        log::debug!(
+            "netuid: {netuid:?} burn controller error: {error:?} integral: {integral:?} derivative: {derivative:?} factor: {factor:?}"
+        );
+
+        let next_burn: TaoCurrency = I96F32::saturating_from_num(current_burn.to_u64())
+            .saturating_mul(factor)
+            .saturating_to_num::<u64>()
+            .into();
+        if next_burn >= Self::get_max_burn(netuid) {
+            Self::get_max_burn(netuid)
+        } else if next_burn <= Self::get_min_burn(netuid) {
+            Self::get_min_burn(netuid)
+        } else {
+            next_burn
+        }
+    }
+
+    pub fn get_burn_controller_gains(netuid: NetUid) -> (u16, u16, u16) {
+        BurnControllerGains::<T>::get(netuid)
+    }
+
+    /// Sets the gains of the burn controller of `netuid`, normalized to u16::MAX, and restarts it.
+    /// Zero gains restore the default burn adjustment.
+    pub fn set_burn_controller_gains(netuid: NetUid, kp: u16, ki: u16, kd: u16) {
+        BurnControllerGains::<T>::insert(netuid, (kp, ki, kd));
+        BurnControllerState::<T>::remove(netuid);
+        Self::deposit_event(Event::BurnControllerGainsSet(netuid, kp, ki, kd));
+    }
+}
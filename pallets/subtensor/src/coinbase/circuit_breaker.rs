@@ -0,0 +1,77 @@
+//! This file defines the emission circuit breaker.
+//!
+//! Every block, the coinbase compares the emission it is about to inject with the emission expected
+//! from the schedule: the block emission for TAO across all subnets, and the alpha block emission for
+//! the alpha out of each subnet. When the emission exceeds the expected emission by more than
+//! `EmissionCircuitBreakerThreshold` percent, the breaker trips, globally or for the subnet alone.
+//!
+//! While tripped, the emission keeps accumulating in the pending pools but epochs stop draining it,
+//! so an inflation bug cannot reach stakers before it is investigated. Only root can reset the
+//! breaker, after which the pending emission is distributed at the next epoch.
+
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Trips the breaker of `netuid`, or the global breaker when `None`, if `emitted` exceeds
+    /// `expected` by more than the threshold.
+    pub fn check_emission_circuit_breaker(
+        netuid: Option<NetUid>,
+        expected: U96F32,
+        emitted: U96F32,
+    ) {
+        let threshold = EmissionCircuitBreakerThreshold::<T>::get();
+        if threshold == 0 || Self::is_emission_circuit_breaker_tripped(netuid) {
+            return;
+        }
+
+        let limit = expected
+            .saturating_mul(U96F32::saturating_from_num(threshold))
+            .safe_div(U96F32::saturating_from_num(100));
+        if emitted <= limit {
+            return;
+        }
+
+        match netuid {
+            Some(netuid) => SubnetEmissionHalted::<T>::insert(netuid, true),
+            None => EmissionHalted::<T>::put(true),
+        }
+        log::error!(
+            "Emission circuit breaker tripped for {netuid:?}: expected {expected:?}, emitted {emitted:?}"
+        );
+        Self::deposit_event(Event::EmissionCircuitBreakerTripped {
+            netuid,
+            expected: expected.saturating_to_num::<u64>(),
+            emitted: emitted.saturating_to_num::<u64>(),
+        });
+    }
+
+    /// Whether the breaker of `netuid`, or the global breaker when `None`, has tripped.
+    pub fn is_emission_circuit_breaker_tripped(netuid: Option<NetUid>) -> bool {
+        match netuid {
+            Some(netuid) => SubnetEmissionHalted::<T>::get(netuid),
+            None => EmissionHalted::<T>::get(),
+        }
+    }
+
+    /// Whether the pending emission of `netuid` is withheld by a tripped breaker.
+    pub fn is_emission_halted(netuid: NetUid) -> bool {
+        EmissionHalted::<T>::get() || SubnetEmissionHalted::<T>::get(netuid)
+    }
+
+    /// Resets the breaker of `netuid`, or the global breaker when `None`.
+    pub fn reset_emission_circuit_breaker(netuid: Option<NetUid>) {
+        match netuid {
+            Some(netuid) => SubnetEmissionHalted::<T>::remove(netuid),
+            None => EmissionHalted::<T>::kill(),
+        }
+        Self::deposit_event(Event::EmissionCircuitBreakerReset(netuid));
+    }
+
+    pub fn set_emission_circuit_breaker_threshold(threshold: u32) {
+        EmissionCircuitBreakerThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::EmissionCircuitBreakerThresholdSet(threshold));
+    }
+}
@@ -0,0 +1,143 @@
+//! This file defines the vesting of miner emission.
+//!
+//! By default the incentive a miner earns in an epoch is staked at once. When a subnet sets a
+//! `MinerEmissionVestingPeriod`, the incentive is instead streamed linearly over that many blocks,
+//! discouraging miners from registering, extracting a burst of emission and leaving.
+//!
+//! The unvested incentive of each hotkey is tracked in `VestingMinerEmission`. Its vested part is
+//! staked to the hotkey and its owner whenever the hotkey earns again, or when the owner claims it
+//! with `claim_vested_emission`. Incentive earned while a schedule is running is added to it and the
+//! whole unvested amount restarts the period. When the hotkey leaves the subnet, the vested part is
+//! staked and the rest is withheld and recycled.
+
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// The miner emission of a hotkey that has not vested yet.
+#[freeze_struct("637205d29dabc8f7")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingEmission {
+    /// The alpha left to vest.
+    pub remaining: AlphaCurrency,
+    /// The block up to which the emission has been released.
+    pub last_block: u64,
+    /// The block at which the remaining alpha is fully vested.
+    pub end_block: u64,
+}
+
+impl VestingEmission {
+    /// The part of the remaining alpha vested at `block`.
+    pub fn vested_at(&self, block: u64) -> AlphaCurrency {
+        if block >= self.end_block {
+            return self.remaining;
+        }
+        let elapsed = block.saturating_sub(self.last_block);
+        let duration = self.end_block.saturating_sub(self.last_block);
+        U96F32::saturating_from_num(self.remaining.to_u64())
+            .saturating_mul(U96F32::saturating_from_num(elapsed))
+            .safe_div(U96F32::saturating_from_num(duration))
+            .saturating_to_num::<u64>()
+            .into()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds `incentive` to the vesting emission of `hotkey` on `netuid`, after releasing what
+    /// already vested.
+    pub fn vest_miner_emission(netuid: NetUid, hotkey: &T::AccountId, incentive: AlphaCurrency) {
+        Self::release_vested_emission(netuid, hotkey);
+
+        let current_block = Self::get_current_block_as_u64();
+        let remaining = VestingMinerEmission::<T>::get(netuid, hotkey)
+            .map(|vesting| vesting.remaining)
+            .unwrap_or(AlphaCurrency::ZERO)
+            .saturating_add(incentive);
+        VestingMinerEmission::<T>::insert(
+            netuid,
+            hotkey,
+            VestingEmission {
+                remaining,
+                last_block: current_block,
+                end_block: current_block
+                    .saturating_add(MinerEmissionVestingPeriod::<T>::get(netuid)),
+            },
+        );
+    }
+
+    /// Stakes the vested emission of `hotkey` on `netuid` to the hotkey and its owner.
+    pub fn release_vested_emission(netuid: NetUid, hotkey: &T::AccountId) -> AlphaCurrency {
+        let Some(mut vesting) = VestingMinerEmission::<T>::get(netuid, hotkey) else {
+            return AlphaCurrency::ZERO;
+        };
+
+        let current_block = Self::get_current_block_as_u64();
+        let vested = vesting.vested_at(current_block);
+        vesting.remaining = vesting.remaining.saturating_sub(vested);
+        vesting.last_block = current_block;
+        if vesting.remaining.is_zero() {
+            VestingMinerEmission::<T>::remove(netuid, hotkey);
+        } else {
+            VestingMinerEmission::<T>::insert(netuid, hotkey, vesting);
+        }
+
+        if !vested.is_zero() {
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                hotkey,
+                &Owner::<T>::get(hotkey),
+                netuid,
+                vested,
+            );
+        }
+
+        vested
+    }
+
+    /// Stakes the vested emission of `hotkey` on `netuid` for the caller, who must own the hotkey.
+    pub fn do_claim_vested_emission(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            VestingMinerEmission::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::NoVestingEmission
+        );
+
+        let alpha = Self::release_vested_emission(netuid, &hotkey);
+
+        Self::deposit_event(Event::VestedEmissionClaimed {
+            netuid,
+            hotkey,
+            alpha,
+        });
+
+        Ok(())
+    }
+
+    /// Releases the vested emission of a hotkey leaving `netuid` and recycles the rest.
+    pub fn withhold_vesting_emission(netuid: NetUid, hotkey: &T::AccountId) {
+        Self::release_vested_emission(netuid, hotkey);
+
+        let Some(vesting) = VestingMinerEmission::<T>::take(netuid, hotkey) else {
+            return;
+        };
+        SubnetAlphaOut::<T>::mutate(netuid, |total| {
+            *total = total.saturating_sub(vesting.remaining);
+        });
+        Self::record_subnet_alpha_recycled(netuid, vesting.remaining);
+
+        Self::deposit_event(Event::VestingEmissionWithheld {
+            netuid,
+            hotkey: hotkey.clone(),
+            alpha: vesting.remaining,
+        });
+    }
+}
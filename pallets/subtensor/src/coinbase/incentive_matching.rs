@@ -0,0 +1,128 @@
+//! This file defines the incentive matching program.
+//!
+//! Governance can register a matching schedule for a strategic subnet to bootstrap it. While the
+//! schedule runs, every block the TAO emission of the subnet is matched 1:1 with TAO from the
+//! incentive matching fund, a keyless account anyone can fund. The matched TAO buys alpha from the
+//! subnet pool, which is added to the pending emission of the subnet and distributed to its miners
+//! and validators at the next epoch, like the rest of its emission.
+//!
+//! A schedule ends at its end block, or once it has matched its cap. Matching pauses while the fund
+//! is empty.
+
+use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use frame_support::traits::tokens::{Fortitude, Preservation, fungible::Inspect};
+use sp_core::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// A schedule matching the TAO emission of a subnet.
+#[freeze_struct("714aa04be0fc664c")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct IncentiveMatchingSchedule {
+    /// The most TAO the schedule matches.
+    pub cap: TaoCurrency,
+    /// The TAO matched so far.
+    pub matched: TaoCurrency,
+    /// The block after which the schedule no longer matches.
+    pub end_block: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The keyless account funding the incentive matching program.
+    pub fn get_incentive_matching_account() -> T::AccountId {
+        let entropy = b"subtensor/incentive_matching".using_encoded(blake2_256);
+        Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    /// Registers a schedule matching the TAO emission of `netuid` up to `cap` until `end_block`,
+    /// replacing its previous schedule, or removes the schedule when `cap` is zero.
+    pub fn set_incentive_matching(netuid: NetUid, cap: TaoCurrency, end_block: u64) {
+        if cap.is_zero() {
+            IncentiveMatching::<T>::remove(netuid);
+            Self::deposit_event(Event::IncentiveMatchingRemoved(netuid));
+            return;
+        }
+
+        IncentiveMatching::<T>::insert(
+            netuid,
+            IncentiveMatchingSchedule {
+                cap,
+                matched: TaoCurrency::ZERO,
+                end_block,
+            },
+        );
+        Self::deposit_event(Event::IncentiveMatchingSet {
+            netuid,
+            cap,
+            end_block,
+        });
+    }
+
+    /// Matches the TAO emission `tao_in` of `netuid` with TAO from the fund, buying alpha that is
+    /// added to the pending emission of the subnet.
+    pub fn match_subnet_emission(netuid: NetUid, tao_in: TaoCurrency) {
+        let Some(mut schedule) = IncentiveMatching::<T>::get(netuid) else {
+            return;
+        };
+
+        if Self::get_current_block_as_u64() > schedule.end_block || schedule.matched >= schedule.cap
+        {
+            IncentiveMatching::<T>::remove(netuid);
+            Self::deposit_event(Event::IncentiveMatchingEnded {
+                netuid,
+                matched: schedule.matched,
+            });
+            return;
+        }
+
+        let fund = Self::get_incentive_matching_account();
+        let available: TaoCurrency = <T as Config>::Currency::reducible_balance(
+            &fund,
+            Preservation::Preserve,
+            Fortitude::Polite,
+        )
+        .into();
+        let tao = tao_in
+            .min(schedule.cap.saturating_sub(schedule.matched))
+            .min(available);
+        if tao.is_zero() {
+            return;
+        }
+
+        let Ok(withdrawn) = Self::transfer_tao(
+            LedgerAccount::Coldkey(fund.clone()),
+            LedgerAccount::Stake,
+            tao,
+            LedgerReason::IncentiveMatching,
+        ) else {
+            return;
+        };
+        match Self::swap_tao_for_alpha(
+            netuid,
+            withdrawn,
+            T::SwapInterface::max_price().into(),
+            true,
+        ) {
+            Ok(swap_result) => {
+                let alpha = AlphaCurrency::from(swap_result.amount_paid_out);
+                PendingEmission::<T>::mutate(netuid, |total| {
+                    *total = total.saturating_add(alpha);
+                });
+                schedule.matched = schedule.matched.saturating_add(withdrawn);
+                IncentiveMatching::<T>::insert(netuid, schedule);
+            }
+            Err(e) => {
+                log::warn!("Failed to match the emission of subnet {netuid}: {e:?}");
+                let _ = Self::transfer_tao(
+                    LedgerAccount::Stake,
+                    LedgerAccount::Coldkey(fund.clone()),
+                    withdrawn,
+                    LedgerReason::IncentiveMatching,
+                );
+            }
+        }
+    }
+}
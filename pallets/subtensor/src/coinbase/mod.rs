@@ -1,6 +1,11 @@
 use super::*;
 pub mod block_emission;
 pub mod block_step;
+pub mod burn_controller;
+pub mod circuit_breaker;
+pub mod emission_vesting;
+pub mod incentive_matching;
+pub mod multi_block_epoch;
 pub mod reveal_commits;
 pub mod root;
 pub mod run_coinbase;
@@ -0,0 +1,1408 @@
+//! This file defines the multi-block epoch.
+//!
+//! By default the epoch of a subnet reads every weight and bond row, computes consensus, writes
+//! every bond row and pays every hotkey in the block it is due. When a subnet sets a
+//! `MultiBlockEpochChunkSize`, its epoch is instead split over consecutive blocks, each processing
+//! at most that many uids, columns or hotkeys, so that large subnets keep every block under the
+//! weight limit. The epoch computes the same result as in a single block, in passes:
+//!
+//! 1. `Snapshot`: the hotkey, stake, weight row and bond row of each uid are read.
+//! 2. `Weights`: the weight rows are masked and normalized, and transposed into columns.
+//! 3. `Consensus`: the stake-weighted median of each column is computed.
+//! 4. `Bonds`: the weights are clipped at consensus and the bonds are masked, then moved towards
+//!    the weights under Yuma3.
+//! 5. `Ema`: without Yuma3, the bonds are moved towards the weights.
+//! 6. `Dividends`: the dividends of each validator are computed from its bonds.
+//! 7. `Upscale`: without Yuma3, the bonds are upscaled for storage.
+//! 8. `Neurons`: the bond rows are written, and the emission of each uid is split between its
+//!    hotkey and its parents.
+//! 9. `Split`: the dividends of each hotkey are split into alpha and root dividends, and the
+//!    owner cut is paid.
+//! 10. `Incentives`, `ClearRecords`, `AlphaDividends` and `TaoDividends`: the incentives are paid,
+//!     the dividend records of the previous epoch are cleared and the dividends are paid. Once
+//!     done, `EmissionDistributed` is emitted.
+//!
+//! The work on vectors over every uid, such as normalizing the stake or the ranks, is done in the
+//! block that ends a pass. The rows are persisted in `MultiBlockEpochNeurons` and
+//! `MultiBlockEpochColumns`, and the vectors in `MultiBlockEpochVectors`, between blocks. The
+//! subnet's next epoch is deferred, accumulating its pending emission, until the running one is
+//! done.
+//!
+//! The epoch runs on the neurons registered in the block it was due, evaluated at that block. The
+//! uids registered since are recorded in `MultiBlockEpochRegistrations`: if the snapshot had not
+//! read a uid yet, its rows and the weights on it are left out, and once the epoch is done neither
+//! its bond row, the bonds on it nor its values are overwritten.
+
+use super::*;
+use crate::epoch::math::*;
+use frame_support::weights::Weight;
+use safe_math::*;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::ops::Range;
+use substrate_fixed::types::{I32F32, I64F64, U96F32};
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+
+/// The stage a multi-block epoch is in.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MultiBlockEpochPhase {
+    /// Reading the hotkey, stake, weights and bonds of each uid.
+    Snapshot,
+    /// Masking and normalizing the weight rows.
+    Weights,
+    /// Computing the consensus of each column.
+    Consensus,
+    /// Clipping the weights at consensus and masking the bonds.
+    Bonds,
+    /// Moving the bonds towards the weights, without Yuma3.
+    Ema,
+    /// Computing the dividends of each validator.
+    Dividends,
+    /// Upscaling the bonds for storage, without Yuma3.
+    Upscale,
+    /// Writing the bond rows and splitting the emission of each uid.
+    Neurons,
+    /// Splitting the dividends of each hotkey into alpha and root dividends.
+    Split,
+    /// Paying the incentives.
+    Incentives,
+    /// Clearing the dividend records of the previous epoch.
+    ClearRecords,
+    /// Paying the alpha dividends.
+    AlphaDividends,
+    /// Paying the root dividends.
+    TaoDividends,
+}
+
+/// A vector over the uids kept by a multi-block epoch between blocks.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MultiBlockEpochVector {
+    /// The normalized stake of the neurons meeting the stake threshold.
+    Stake,
+    /// The normalized stake of the active validators.
+    ActiveStake,
+    /// The ranks before the weights are clipped.
+    Preranks,
+    /// The consensus of each column.
+    Consensus,
+    /// The sum of the clipped weights of each validator.
+    ValidatorTrust,
+    /// The ranks after the weights are clipped, normalized once every row is clipped.
+    Ranks,
+    /// The ratio of the ranks after and before the weights are clipped.
+    Trust,
+    /// The dividends of each validator.
+    Dividends,
+    /// The column sums of the masked bonds, or of their moving average under Yuma3.
+    BondsColSum,
+    /// The column sums of the bonds deltas.
+    DeltaColSum,
+    /// The column sums of the moving average of the bonds.
+    EmaColSum,
+    /// The column maxima of the moving average of the bonds.
+    EmaColMax,
+    /// The normalized emission of each miner.
+    ServerEmission,
+    /// The normalized emission of each validator.
+    ValidatorEmission,
+}
+
+/// What a multi-block epoch read of a uid, for the work on vectors over every uid.
+#[freeze_struct("923c4098e8b1b273")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MultiBlockEpochUid {
+    /// Whether the epoch reads the neuron of the uid, false if it is vacant or registered since.
+    pub neuron: bool,
+    /// The block the neuron registered, `u64::MAX` if it registered since the epoch started.
+    pub registered: u64,
+    /// The stake of the neuron, its alpha and its TAO weighted by the TAO weight.
+    pub stake: I64F64,
+    /// Whether the neuron did not update its weights within the activity cutoff.
+    pub inactive: bool,
+    /// Whether the weights of the neuron are older than the maximum weight age.
+    pub stale: bool,
+    /// Whether the neuron held a validator permit.
+    pub validator_permit: bool,
+    /// The block of the earliest commit of the neuron that has not expired, `u64::MAX` if none.
+    pub commit_block: u64,
+}
+
+/// The rows of a uid a multi-block epoch works on.
+#[freeze_struct("441d205a314d5c87")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MultiBlockEpochNeuron<AccountId> {
+    /// The hotkey of the neuron.
+    pub hotkey: AccountId,
+    /// The block the neuron last updated its weights.
+    pub last_update: u64,
+    /// The weight row, masked and normalized, then the weights for bonds.
+    pub weights: Vec<(u16, I32F32)>,
+    /// The bond row, masked, then moved towards the weights.
+    pub bonds: Vec<(u16, I32F32)>,
+}
+
+pub type MultiBlockEpochNeuronOf<T> = MultiBlockEpochNeuron<<T as frame_system::Config>::AccountId>;
+
+/// The intermediate state of a multi-block epoch.
+#[freeze_struct("5a6b8df5e4caf0aa")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MultiBlockEpoch {
+    /// The stage the epoch is in.
+    pub phase: MultiBlockEpochPhase,
+    /// The block the epoch was due, at which the neurons are evaluated.
+    pub started: u64,
+    /// The number of uids when the epoch was due.
+    pub n: u16,
+    /// The next uid, or column, the current pass processes.
+    pub cursor: u16,
+    /// Whether the subnet ran Yuma3 when the epoch was due.
+    pub yuma3: bool,
+    /// The uid of the subnet owner hotkey when the epoch was due.
+    pub owner_uid: Option<u16>,
+    /// The alpha emission drained for the epoch.
+    pub pending_alpha: AlphaCurrency,
+    /// The root dividends drained for the epoch.
+    pub pending_tao: TaoCurrency,
+    /// The alpha swapped for the root dividends.
+    pub pending_swapped: AlphaCurrency,
+    /// The owner cut drained for the epoch.
+    pub owner_cut: AlphaCurrency,
+    /// The alpha emission paid as alpha dividends, once the emission of each uid is computed.
+    pub pending_validator_alpha: AlphaCurrency,
+    /// The sum of the alpha dividends of every hotkey, before they are scaled to the emission.
+    pub total_alpha_divs: U96F32,
+    /// The sum of the root dividends of every hotkey, before they are scaled to the emission.
+    pub total_root_divs: U96F32,
+    /// The incentives paid so far.
+    pub incentives: AlphaCurrency,
+    /// The alpha dividends paid so far.
+    pub alpha_dividends: AlphaCurrency,
+    /// The root dividends paid so far.
+    pub tao_dividends: TaoCurrency,
+}
+
+impl MultiBlockEpoch {
+    /// Takes the next `chunk_size` uids, or columns, of the current pass, and whether they are
+    /// the last ones.
+    fn next_uids(&mut self, chunk_size: u16) -> (Range<u16>, bool) {
+        let end = self.cursor.saturating_add(chunk_size).min(self.n);
+        let uids = self.cursor..end;
+        self.cursor = end;
+        (uids, end >= self.n)
+    }
+
+    /// Moves on to the pass `phase`.
+    fn advance(&mut self, phase: MultiBlockEpochPhase) {
+        self.phase = phase;
+        self.cursor = 0;
+    }
+}
+
+/// Divides `value` by the sum, or maximum, of its column, unless that is zero.
+fn scale_to_column(value: I32F32, column: Option<&I32F32>) -> I32F32 {
+    match column {
+        Some(column) if *column != I32F32::saturating_from_num(0) => value.safe_div(*column),
+        _ => value,
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_multi_block_epoch_chunk_size(netuid: NetUid) -> u16 {
+        MultiBlockEpochChunkSize::<T>::get(netuid)
+    }
+    pub fn set_multi_block_epoch_chunk_size(netuid: NetUid, chunk_size: u16) {
+        MultiBlockEpochChunkSize::<T>::insert(netuid, chunk_size);
+        Self::deposit_event(Event::MultiBlockEpochChunkSizeSet(netuid, chunk_size));
+    }
+
+    /// Whether the epoch of `netuid` is still being processed over several blocks.
+    pub fn is_multi_block_epoch_running(netuid: NetUid) -> bool {
+        MultiBlockEpochs::<T>::contains_key(netuid)
+    }
+
+    /// Starts the multi-block epoch of `netuid` with the emission drained for it.
+    pub fn start_multi_block_epoch(
+        netuid: NetUid,
+        pending_alpha: AlphaCurrency,
+        pending_tao: TaoCurrency,
+        pending_swapped: AlphaCurrency,
+        owner_cut: AlphaCurrency,
+    ) {
+        log::debug!(
+            "Starting multi-block epoch for netuid {netuid:?}, pending_alpha: {pending_alpha:?}, pending_tao: {pending_tao:?}, pending_swapped: {pending_swapped:?}, owner_cut: {owner_cut:?}"
+        );
+        MultiBlockEpochs::<T>::insert(
+            netuid,
+            MultiBlockEpoch {
+                phase: MultiBlockEpochPhase::Snapshot,
+                started: Self::get_current_block_as_u64(),
+                n: Self::get_subnetwork_n(netuid),
+                cursor: 0,
+                yuma3: Yuma3On::<T>::get(netuid),
+                owner_uid: Self::get_owner_uid(netuid),
+                pending_alpha,
+                pending_tao,
+                pending_swapped,
+                owner_cut,
+                pending_validator_alpha: AlphaCurrency::ZERO,
+                total_alpha_divs: U96F32::saturating_from_num(0),
+                total_root_divs: U96F32::saturating_from_num(0),
+                incentives: AlphaCurrency::ZERO,
+                alpha_dividends: AlphaCurrency::ZERO,
+                tao_dividends: TaoCurrency::ZERO,
+            },
+        );
+    }
+
+    /// Removes the multi-block epoch of `netuid` and its intermediate state.
+    pub fn clear_multi_block_epoch(netuid: NetUid) {
+        MultiBlockEpochs::<T>::remove(netuid);
+        MultiBlockEpochUids::<T>::remove(netuid);
+        let _ = MultiBlockEpochNeurons::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochColumns::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochVectors::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochRegistrations::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochIncentives::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochDividends::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochAlphaDividends::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = MultiBlockEpochRootDividends::<T>::clear_prefix(netuid, u32::MAX, None);
+    }
+
+    /// Records that a neuron registered on `uid` while the epoch of `netuid` runs over several
+    /// blocks, if it does.
+    pub fn note_multi_block_epoch_registration(netuid: NetUid, uid: u16) {
+        if Self::is_multi_block_epoch_running(netuid) {
+            MultiBlockEpochRegistrations::<T>::insert(netuid, uid, true);
+        }
+    }
+
+    /// Processes the next chunk of every multi-block epoch running, returning the weight used.
+    pub fn step_multi_block_epochs() -> Weight {
+        let netuids: Vec<NetUid> = MultiBlockEpochs::<T>::iter_keys().collect();
+        let mut weight = T::DbWeight::get().reads(netuids.len().saturating_add(1) as u64);
+        for netuid in netuids {
+            weight = weight.saturating_add(Self::step_multi_block_epoch(netuid));
+        }
+        weight
+    }
+
+    /// Processes the next chunk of the multi-block epoch of `netuid`, if one is running,
+    /// returning the weight used.
+    pub fn step_multi_block_epoch(netuid: NetUid) -> Weight {
+        let Some(mut epoch) = MultiBlockEpochs::<T>::get(netuid) else {
+            return T::DbWeight::get().reads(1);
+        };
+        let chunk_size = Self::get_multi_block_epoch_chunk_size(netuid).max(1);
+        let mut weight = T::DbWeight::get().reads_writes(2, 1);
+
+        match epoch.phase {
+            MultiBlockEpochPhase::Snapshot => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight =
+                    weight.saturating_add(Self::snapshot_multi_block_epoch(netuid, &epoch, uids));
+                if last {
+                    weight = weight.saturating_add(Self::stake_multi_block_epoch(netuid, &epoch));
+                    epoch.advance(MultiBlockEpochPhase::Weights);
+                }
+            }
+            MultiBlockEpochPhase::Weights => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight = weight
+                    .saturating_add(Self::mask_multi_block_epoch_weights(netuid, &epoch, uids));
+                if last {
+                    epoch.advance(MultiBlockEpochPhase::Consensus);
+                }
+            }
+            MultiBlockEpochPhase::Consensus => {
+                let (columns, last) = epoch.next_uids(chunk_size);
+                weight = weight
+                    .saturating_add(Self::compute_multi_block_epoch_consensus(netuid, columns));
+                if last {
+                    epoch.advance(MultiBlockEpochPhase::Bonds);
+                }
+            }
+            MultiBlockEpochPhase::Bonds => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight = weight
+                    .saturating_add(Self::clip_multi_block_epoch_weights(netuid, &epoch, uids));
+                if last {
+                    weight = weight.saturating_add(Self::rank_multi_block_epoch(netuid));
+                    epoch.advance(if epoch.yuma3 {
+                        MultiBlockEpochPhase::Dividends
+                    } else {
+                        MultiBlockEpochPhase::Ema
+                    });
+                }
+            }
+            MultiBlockEpochPhase::Ema => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight =
+                    weight.saturating_add(Self::move_multi_block_epoch_bonds(netuid, &epoch, uids));
+                if last {
+                    epoch.advance(MultiBlockEpochPhase::Dividends);
+                }
+            }
+            MultiBlockEpochPhase::Dividends => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight = weight.saturating_add(Self::compute_multi_block_epoch_dividends(
+                    netuid, &epoch, uids,
+                ));
+                if last && epoch.yuma3 {
+                    weight =
+                        weight.saturating_add(Self::emit_multi_block_epoch(netuid, &mut epoch));
+                    epoch.advance(MultiBlockEpochPhase::Neurons);
+                } else if last {
+                    epoch.advance(MultiBlockEpochPhase::Upscale);
+                }
+            }
+            MultiBlockEpochPhase::Upscale => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight = weight
+                    .saturating_add(Self::upscale_multi_block_epoch_bonds(netuid, &epoch, uids));
+                if last {
+                    weight =
+                        weight.saturating_add(Self::emit_multi_block_epoch(netuid, &mut epoch));
+                    epoch.advance(MultiBlockEpochPhase::Neurons);
+                }
+            }
+            MultiBlockEpochPhase::Neurons => {
+                let (uids, last) = epoch.next_uids(chunk_size);
+                weight = weight
+                    .saturating_add(Self::write_multi_block_epoch_neurons(netuid, &epoch, uids));
+                if last {
+                    epoch.advance(MultiBlockEpochPhase::Split);
+                }
+            }
+            MultiBlockEpochPhase::Split => {
+                let tao_weight = Self::get_tao_weight();
+                let dividends: Vec<(T::AccountId, U96F32)> =
+                    MultiBlockEpochDividends::<T>::drain_prefix(netuid)
+                        .take(usize::from(chunk_size))
+                        .collect();
+                let count = dividends.len();
+                for (hotkey, dividend) in dividends {
+                    let (alpha_divs, root_divs) = Self::split_dividend(
+                        dividend,
+                        Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid),
+                        Self::get_stake_for_hotkey_on_subnet(&hotkey, NetUid::ROOT),
+                        tao_weight,
+                    );
+                    epoch.total_alpha_divs = epoch.total_alpha_divs.saturating_add(alpha_divs);
+                    epoch.total_root_divs = epoch.total_root_divs.saturating_add(root_divs);
+                    MultiBlockEpochAlphaDividends::<T>::insert(netuid, &hotkey, alpha_divs);
+                    MultiBlockEpochRootDividends::<T>::insert(netuid, &hotkey, root_divs);
+                }
+                weight = weight.saturating_add(Self::weigh_multi_block_epoch_items(count, 6, 3));
+                if count < usize::from(chunk_size) {
+                    Self::distribute_owner_cut(netuid, epoch.owner_cut);
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(12, 8));
+                    epoch.advance(MultiBlockEpochPhase::Incentives);
+                }
+            }
+            MultiBlockEpochPhase::Incentives => {
+                let incentives: Vec<(T::AccountId, AlphaCurrency)> =
+                    MultiBlockEpochIncentives::<T>::drain_prefix(netuid)
+                        .take(usize::from(chunk_size))
+                        .collect();
+                let count = incentives.len();
+                for (hotkey, incentive) in incentives {
+                    epoch.incentives = epoch.incentives.saturating_add(incentive);
+                    Self::distribute_incentive(netuid, hotkey, incentive);
+                }
+                weight = weight.saturating_add(Self::weigh_multi_block_epoch_items(count, 10, 6));
+                if count < usize::from(chunk_size) {
+                    epoch.advance(MultiBlockEpochPhase::ClearRecords);
+                }
+            }
+            MultiBlockEpochPhase::ClearRecords => {
+                let alpha =
+                    AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::from(chunk_size), None);
+                let tao =
+                    TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::from(chunk_size), None);
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(
+                    u64::from(alpha.loops.saturating_add(tao.loops)),
+                    u64::from(alpha.unique.saturating_add(tao.unique)),
+                ));
+                if alpha.maybe_cursor.is_none() && tao.maybe_cursor.is_none() {
+                    epoch.advance(MultiBlockEpochPhase::AlphaDividends);
+                }
+            }
+            MultiBlockEpochPhase::AlphaDividends => {
+                let dividends: Vec<(T::AccountId, U96F32)> =
+                    MultiBlockEpochAlphaDividends::<T>::drain_prefix(netuid)
+                        .take(usize::from(chunk_size))
+                        .collect();
+                let count = dividends.len();
+                for (hotkey, alpha_divs) in dividends {
+                    let alpha_divs = Self::get_dividend_share(
+                        epoch.pending_validator_alpha.to_u64(),
+                        alpha_divs,
+                        epoch.total_alpha_divs,
+                    );
+                    epoch.alpha_dividends = epoch
+                        .alpha_dividends
+                        .saturating_add(alpha_divs.saturating_to_num::<u64>().into());
+                    Self::distribute_alpha_dividends(netuid, hotkey, alpha_divs);
+                }
+                weight = weight.saturating_add(Self::weigh_multi_block_epoch_items(count, 16, 10));
+                if count < usize::from(chunk_size) {
+                    epoch.advance(MultiBlockEpochPhase::TaoDividends);
+                }
+            }
+            MultiBlockEpochPhase::TaoDividends => {
+                let dividends: Vec<(T::AccountId, U96F32)> =
+                    MultiBlockEpochRootDividends::<T>::drain_prefix(netuid)
+                        .take(usize::from(chunk_size))
+                        .collect();
+                let count = dividends.len();
+                for (hotkey, root_divs) in dividends {
+                    let root_tao = Self::get_dividend_share(
+                        epoch.pending_tao.to_u64(),
+                        root_divs,
+                        epoch.total_root_divs,
+                    );
+                    epoch.tao_dividends = epoch
+                        .tao_dividends
+                        .saturating_add(root_tao.saturating_to_num::<u64>().into());
+                    Self::distribute_tao_dividends(netuid, hotkey, root_tao);
+                }
+                weight = weight.saturating_add(Self::weigh_multi_block_epoch_items(count, 12, 8));
+                if count < usize::from(chunk_size) {
+                    Self::deposit_event(Event::EmissionDistributed {
+                        netuid,
+                        owner_cut: epoch.owner_cut,
+                        incentives: epoch.incentives,
+                        alpha_dividends: epoch.alpha_dividends,
+                        tao_dividends: epoch.tao_dividends,
+                    });
+                    log::debug!("Finished multi-block epoch for netuid {netuid:?}");
+                    Self::clear_multi_block_epoch(netuid);
+                    return weight.saturating_add(T::DbWeight::get().writes(16));
+                }
+            }
+        }
+
+        MultiBlockEpochs::<T>::insert(netuid, epoch);
+        weight
+    }
+
+    /// The weight of `count` items of a pass, each reading `reads` and writing `writes` storage
+    /// items.
+    fn weigh_multi_block_epoch_items(count: usize, reads: u64, writes: u64) -> Weight {
+        let count = count as u64;
+        T::DbWeight::get().reads_writes(count.saturating_mul(reads), count.saturating_mul(writes))
+    }
+
+    /// The `vector` of the multi-block epoch of `netuid`, zero for each of the `n` uids before
+    /// it is first written.
+    fn get_multi_block_epoch_vector(
+        netuid: NetUid,
+        vector: MultiBlockEpochVector,
+        n: u16,
+    ) -> Vec<I32F32> {
+        let values = MultiBlockEpochVectors::<T>::get(netuid, vector);
+        if values.is_empty() {
+            vec![I32F32::saturating_from_num(0); usize::from(n)]
+        } else {
+            values
+        }
+    }
+
+    /// The per-uid `values` of the multi-block epoch written over the `current` ones, for the
+    /// `len` uids the subnet has now, keeping the current values of the uids in `registered`.
+    fn merge_multi_block_epoch_vector<V: Clone + Default>(
+        current: Vec<V>,
+        values: Vec<V>,
+        len: u16,
+        registered: &BTreeSet<u16>,
+    ) -> Vec<V> {
+        (0..len)
+            .map(|uid| match values.get(usize::from(uid)) {
+                Some(value) if !registered.contains(&uid) => value.clone(),
+                _ => current.get(usize::from(uid)).cloned().unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// Reads the hotkey, stake, weight row and bond row of `uids`, leaving out the uids
+    /// registered since the epoch started.
+    fn snapshot_multi_block_epoch(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        uids: Range<u16>,
+    ) -> Weight {
+        let activity_cutoff: u64 = Self::get_activity_cutoff(netuid) as u64;
+        let last_update: Vec<u64> = Self::get_last_update(netuid);
+        let stale: Vec<bool> = Self::get_stale_weights(netuid, &last_update, epoch.started);
+        let validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
+        let commit_reveal: bool = Self::get_commit_reveal_weights_enabled(netuid);
+        let count = uids.len();
+        let (total_stake, _, _) = Self::get_stake_weights_for_uids(netuid, uids.clone());
+
+        for (uid, stake) in uids.zip(total_stake) {
+            let validator_permit = validator_permits
+                .get(usize::from(uid))
+                .copied()
+                .unwrap_or(false);
+            let last_update = last_update.get(usize::from(uid)).copied().unwrap_or(0);
+            let inactive = last_update.saturating_add(activity_cutoff) < epoch.started;
+            let stale = stale.get(usize::from(uid)).copied().unwrap_or(false);
+
+            // The weights on a uid registered since the epoch started are masked as outdated.
+            if MultiBlockEpochRegistrations::<T>::get(netuid, uid) {
+                MultiBlockEpochUids::<T>::append(
+                    netuid,
+                    MultiBlockEpochUid {
+                        neuron: false,
+                        registered: u64::MAX,
+                        stake: I64F64::saturating_from_num(0),
+                        inactive,
+                        stale,
+                        validator_permit: false,
+                        commit_block: u64::MAX,
+                    },
+                );
+                continue;
+            }
+            let registered = Self::get_neuron_block_at_registration(netuid, uid);
+            let Ok(hotkey) = Keys::<T>::try_get(netuid, uid) else {
+                MultiBlockEpochUids::<T>::append(
+                    netuid,
+                    MultiBlockEpochUid {
+                        neuron: false,
+                        registered,
+                        stake,
+                        inactive,
+                        stale,
+                        validator_permit,
+                        commit_block: u64::MAX,
+                    },
+                );
+                continue;
+            };
+
+            // Only permitted validators with fresh weights keep their weights.
+            let weights: Vec<(u16, I32F32)> = if validator_permit && !stale {
+                Weights::<T>::get(netuid, uid)
+                    .into_iter()
+                    .filter(|(uid_j, _)| *uid_j < epoch.n)
+                    .map(|(uid_j, weight_ij)| (uid_j, u16_to_fixed(weight_ij)))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let bonds: Vec<(u16, I32F32)> = if validator_permit {
+                Bonds::<T>::get(netuid, uid)
+                    .into_iter()
+                    .map(|(uid_j, bonds_ij)| (uid_j, u16_to_fixed(bonds_ij)))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            // The earliest v2 commit that has not expired, the v3 ones are added once every uid
+            // is read.
+            let commit_block: u64 = if commit_reveal {
+                WeightCommits::<T>::get(netuid, &hotkey)
+                    .and_then(|commits| {
+                        commits
+                            .iter()
+                            .map(|(_, commit_block, _, _)| *commit_block)
+                            .find(|commit_block| {
+                                !Self::is_commit_expired_at(netuid, *commit_block, epoch.started)
+                            })
+                    })
+                    .unwrap_or(u64::MAX)
+            } else {
+                u64::MAX
+            };
+
+            MultiBlockEpochUids::<T>::append(
+                netuid,
+                MultiBlockEpochUid {
+                    neuron: true,
+                    registered,
+                    stake,
+                    inactive,
+                    stale,
+                    validator_permit,
+                    commit_block,
+                },
+            );
+            MultiBlockEpochNeurons::<T>::insert(
+                netuid,
+                uid,
+                MultiBlockEpochNeuron {
+                    hotkey,
+                    last_update,
+                    weights,
+                    bonds,
+                },
+            );
+        }
+
+        T::DbWeight::get()
+            .reads(5)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 16, 2))
+    }
+
+    /// Adds the v3 commits, and normalizes the stake and the active stake, once every uid is
+    /// read.
+    fn stake_multi_block_epoch(netuid: NetUid, epoch: &MultiBlockEpoch) -> Weight {
+        let mut uids: Vec<MultiBlockEpochUid> = MultiBlockEpochUids::<T>::get(netuid);
+        let mut reads: u64 = 4;
+
+        if Self::get_commit_reveal_weights_enabled(netuid) {
+            for (_, commits) in CRV3WeightCommitsV2::<T>::iter_prefix(netuid) {
+                reads = reads.saturating_add(1);
+                for (who, commit_block, ..) in commits.iter() {
+                    if Self::is_commit_expired_at(netuid, *commit_block, epoch.started) {
+                        continue;
+                    }
+                    reads = reads.saturating_add(1);
+                    if let Some(uid) = Uids::<T>::get(netuid, who)
+                        .and_then(|uid| uids.get_mut(usize::from(uid)))
+                        .filter(|uid| uid.neuron)
+                    {
+                        uid.commit_block = uid.commit_block.min(*commit_block);
+                    }
+                }
+            }
+            MultiBlockEpochUids::<T>::insert(netuid, uids.clone());
+        }
+
+        // Set stake of validators that doesn't meet the staking threshold to 0 as filter.
+        let min_stake = Self::get_stake_threshold_for_subnet(netuid);
+        let mut filtered_stake: Vec<I64F64> = uids
+            .iter()
+            .map(|uid| {
+                if fixed64_to_u64(uid.stake) < min_stake {
+                    return I64F64::from(0);
+                }
+                uid.stake
+            })
+            .collect();
+        inplace_normalize_64(&mut filtered_stake);
+        let stake: Vec<I32F32> = vec_fixed64_to_fixed32(filtered_stake);
+
+        // Remove inactive stake, the stake of validators with stale weights and non-validator
+        // stake.
+        let inactive: Vec<bool> = uids.iter().map(|uid| uid.inactive).collect();
+        let stale: Vec<bool> = uids.iter().map(|uid| uid.stale).collect();
+        let validator_forbids: Vec<bool> = uids.iter().map(|uid| !uid.validator_permit).collect();
+        let mut active_stake: Vec<I32F32> = stake.clone();
+        inplace_mask_vector(&inactive, &mut active_stake);
+        inplace_mask_vector(&stale, &mut active_stake);
+        inplace_mask_vector(&validator_forbids, &mut active_stake);
+        inplace_normalize(&mut active_stake);
+        log::trace!("Active Stake: {:?}", &active_stake);
+
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Stake, stake);
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::ActiveStake,
+            active_stake,
+        );
+        T::DbWeight::get().reads_writes(reads, 3)
+    }
+
+    /// Masks and normalizes the weight rows of `uids`, accumulating the preranks and adding the
+    /// weights of the validators with active stake to the columns.
+    fn mask_multi_block_epoch_weights(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let uids: Vec<MultiBlockEpochUid> = MultiBlockEpochUids::<T>::get(netuid);
+        let registered = |uid: u16| {
+            uids.get(usize::from(uid))
+                .map_or(u64::MAX, |uid| uid.registered)
+        };
+        let active_stake =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ActiveStake);
+        let mut preranks =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::Preranks, epoch.n);
+        let mut columns: BTreeMap<u16, Vec<(u16, I32F32)>> = BTreeMap::new();
+        let count = rows.len();
+
+        for uid_i in rows {
+            let Some(mut neuron) = MultiBlockEpochNeurons::<T>::get(netuid, uid_i) else {
+                continue;
+            };
+            let last_update = neuron.last_update;
+            let commit_block = uids
+                .get(usize::from(uid_i))
+                .map_or(u64::MAX, |uid| uid.commit_block);
+
+            // Remove self-weight, except the owner's, and weights referring to neurons
+            // registered since the weights were set or since the earliest pending commit.
+            neuron.weights.retain(|(uid_j, _)| {
+                (*uid_j != uid_i || epoch.owner_uid == Some(uid_i))
+                    && last_update > registered(*uid_j)
+                    && commit_block >= registered(*uid_j)
+            });
+
+            // Normalize remaining weights.
+            inplace_row_normalize_sparse(sp_std::slice::from_mut(&mut neuron.weights));
+
+            // Compute preranks: r_j = SUM(i) w_ij * s_i
+            let stake_i = active_stake
+                .get(usize::from(uid_i))
+                .copied()
+                .unwrap_or_default();
+            for (uid_j, weight_ij) in neuron.weights.iter() {
+                if let Some(prerank) = preranks.get_mut(usize::from(*uid_j)) {
+                    *prerank = prerank.saturating_add(stake_i.saturating_mul(*weight_ij));
+                }
+                // Only the validators with active stake take part in consensus.
+                if stake_i > I32F32::saturating_from_num(0) {
+                    columns.entry(*uid_j).or_default().push((uid_i, *weight_ij));
+                }
+            }
+
+            MultiBlockEpochNeurons::<T>::insert(netuid, uid_i, neuron);
+        }
+
+        let columns_count = columns.len();
+        for (uid_j, column) in columns {
+            MultiBlockEpochColumns::<T>::mutate(netuid, uid_j, |weights| weights.extend(column));
+        }
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Preranks, preranks);
+
+        T::DbWeight::get()
+            .reads_writes(3, 1)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 1, 1))
+            .saturating_add(Self::weigh_multi_block_epoch_items(columns_count, 1, 1))
+    }
+
+    /// Computes the consensus of `columns`, the stake-weighted median of the weights on each.
+    fn compute_multi_block_epoch_consensus(netuid: NetUid, columns: Range<u16>) -> Weight {
+        let active_stake =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ActiveStake);
+        let kappa: I32F32 = Self::get_float_kappa(netuid);
+        let count = columns.len();
+
+        // The weights on `columns` by row, the columns numbered from the first one.
+        let mut weights: Vec<Vec<(u16, I32F32)>> = vec![Vec::new(); active_stake.len()];
+        for uid_j in columns.clone() {
+            for (uid_i, weight_ij) in MultiBlockEpochColumns::<T>::take(netuid, uid_j) {
+                if let Some(row) = weights.get_mut(usize::from(uid_i)) {
+                    row.push((uid_j.saturating_sub(columns.start), weight_ij));
+                }
+            }
+        }
+        let consensus: Vec<I32F32> = weighted_median_col_sparse(
+            &active_stake,
+            &weights,
+            columns.end.saturating_sub(columns.start),
+            kappa,
+        );
+        log::trace!("Consensus of {columns:?}: {consensus:?}");
+        MultiBlockEpochVectors::<T>::mutate(netuid, MultiBlockEpochVector::Consensus, |values| {
+            values.extend(consensus)
+        });
+
+        T::DbWeight::get()
+            .reads_writes(3, 1)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 1, 1))
+    }
+
+    /// Clips the weight rows of `uids` at consensus, accumulating the validator trust and the
+    /// ranks, and masks their bond rows, moving them towards the weights under Yuma3.
+    fn clip_multi_block_epoch_weights(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let uids: Vec<MultiBlockEpochUid> = MultiBlockEpochUids::<T>::get(netuid);
+        let registered = |uid: u16| {
+            uids.get(usize::from(uid))
+                .map_or(u64::MAX, |uid| uid.registered)
+        };
+        let active_stake =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ActiveStake);
+        let consensus = MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::Consensus);
+        let mut validator_trust = Self::get_multi_block_epoch_vector(
+            netuid,
+            MultiBlockEpochVector::ValidatorTrust,
+            epoch.n,
+        );
+        let mut ranks =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::Ranks, epoch.n);
+        let mut bonds_col_sum =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::BondsColSum, epoch.n);
+        let mut delta_col_sum =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::DeltaColSum, epoch.n);
+        let bonds_penalty: I32F32 = Self::get_float_bonds_penalty(netuid);
+        let tempo: u64 = Self::get_tempo(netuid).into();
+        let last_tempo: u64 = epoch.started.saturating_sub(tempo);
+        let bonds_reset: bool = Self::get_bonds_reset_on_deregistration(netuid);
+        let count = rows.len();
+
+        for uid_i in rows {
+            let Some(mut neuron) = MultiBlockEpochNeurons::<T>::get(netuid, uid_i) else {
+                continue;
+            };
+            let stake_i = active_stake
+                .get(usize::from(uid_i))
+                .copied()
+                .unwrap_or_default();
+
+            // Clip weights at majority consensus.
+            let clipped_weights = col_clip_row_sparse(&neuron.weights, &consensus);
+
+            // Calculate validator trust as sum of clipped weights set by validator.
+            if let Some(trust) = validator_trust.get_mut(usize::from(uid_i)) {
+                *trust = clipped_weights.iter().map(|(_, value)| value).sum();
+            }
+
+            // Compute ranks: r_j = SUM(i) w_ij * s_i.
+            for (uid_j, weight_ij) in clipped_weights.iter() {
+                if let Some(rank) = ranks.get_mut(usize::from(*uid_j)) {
+                    *rank = rank.saturating_add(stake_i.saturating_mul(*weight_ij));
+                }
+            }
+
+            // Calculate weights for bonds, apply bonds penalty to weights.
+            let weights_for_bonds =
+                interpolate_row_sparse(&neuron.weights, &clipped_weights, epoch.n, bonds_penalty);
+
+            let mut bonds = sp_std::mem::take(&mut neuron.bonds);
+            if epoch.yuma3 {
+                bonds
+                    .iter_mut()
+                    .for_each(|(_, bond)| *bond = fixed_to_fixed_u16_proportion(*bond));
+            }
+            // Remove bonds referring to neurons that have registered since last tempo.
+            bonds.retain(|(uid_j, _)| last_tempo > registered(*uid_j));
+            // Reset the bonds of validators that went dormant, as if they had deregistered.
+            if bonds_reset && uids.get(usize::from(uid_i)).is_some_and(|uid| uid.inactive) {
+                bonds.clear();
+            }
+
+            if epoch.yuma3 {
+                // Compute the Exponential Moving Average (EMA) of bonds.
+                bonds =
+                    Self::compute_bonds_row_sparse(netuid, &weights_for_bonds, &bonds, &consensus);
+                neuron.weights = Vec::new();
+            } else {
+                // Compute bonds delta: ΔB = W◦S, normalized once every row is clipped.
+                for (uid_j, weight_ij) in weights_for_bonds.iter() {
+                    if let Some(col_sum) = delta_col_sum.get_mut(usize::from(*uid_j)) {
+                        *col_sum = col_sum.saturating_add(weight_ij.saturating_mul(stake_i));
+                    }
+                }
+                neuron.weights = weights_for_bonds;
+            }
+            for (uid_j, bond_ij) in bonds.iter() {
+                if let Some(col_sum) = bonds_col_sum.get_mut(usize::from(*uid_j)) {
+                    *col_sum = col_sum.saturating_add(*bond_ij);
+                }
+            }
+            neuron.bonds = bonds;
+
+            MultiBlockEpochNeurons::<T>::insert(netuid, uid_i, neuron);
+        }
+
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::ValidatorTrust,
+            validator_trust,
+        );
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Ranks, ranks);
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::BondsColSum,
+            bonds_col_sum,
+        );
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::DeltaColSum,
+            delta_col_sum,
+        );
+
+        T::DbWeight::get()
+            .reads_writes(12, 4)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 4, 1))
+    }
+
+    /// Computes the trust and normalizes the ranks into the incentive, once every weight row is
+    /// clipped.
+    fn rank_multi_block_epoch(netuid: NetUid) -> Weight {
+        let mut ranks = MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::Ranks);
+        let preranks = MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::Preranks);
+
+        // Compute server trust: ratio of rank after vs. rank before.
+        let trust: Vec<I32F32> = vecdiv(&ranks, &preranks); // range: I32F32(0, 1)
+        inplace_normalize(&mut ranks); // range: I32F32(0, 1)
+        log::trace!("Incentive (=Rank): {:?}", &ranks);
+
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Trust, trust);
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Ranks, ranks);
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    /// Moves the bond rows of `uids` towards the bonds deltas, without Yuma3.
+    fn move_multi_block_epoch_bonds(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let active_stake =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ActiveStake);
+        let bonds_col_sum =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::BondsColSum);
+        let delta_col_sum =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::DeltaColSum);
+        let mut ema_col_sum =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::EmaColSum, epoch.n);
+        let alpha: I32F32 = Self::get_float_bonds_ema_alpha(netuid);
+        let count = rows.len();
+
+        for uid_i in rows {
+            let Some(mut neuron) = MultiBlockEpochNeurons::<T>::get(netuid, uid_i) else {
+                continue;
+            };
+            let stake_i = active_stake
+                .get(usize::from(uid_i))
+                .copied()
+                .unwrap_or_default();
+
+            // Normalize the bonds and the bonds delta: sum_i b_ij = 1.
+            let bonds: Vec<(u16, I32F32)> = neuron
+                .bonds
+                .iter()
+                .map(|(uid_j, bond_ij)| {
+                    let col_sum = bonds_col_sum.get(usize::from(*uid_j));
+                    (*uid_j, scale_to_column(*bond_ij, col_sum))
+                })
+                .collect();
+            let bonds_delta: Vec<(u16, I32F32)> = neuron
+                .weights
+                .iter()
+                .map(|(uid_j, weight_ij)| {
+                    let col_sum = delta_col_sum.get(usize::from(*uid_j));
+                    (
+                        *uid_j,
+                        scale_to_column(weight_ij.saturating_mul(stake_i), col_sum),
+                    )
+                })
+                .collect();
+
+            // Compute the Exponential Moving Average (EMA) of bonds.
+            let ema_bonds = row_ema_sparse(&bonds_delta, &bonds, alpha, usize::from(epoch.n));
+            for (uid_j, bond_ij) in ema_bonds.iter() {
+                if let Some(col_sum) = ema_col_sum.get_mut(usize::from(*uid_j)) {
+                    *col_sum = col_sum.saturating_add(*bond_ij);
+                }
+            }
+            neuron.weights = Vec::new();
+            neuron.bonds = ema_bonds;
+
+            MultiBlockEpochNeurons::<T>::insert(netuid, uid_i, neuron);
+        }
+
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::EmaColSum, ema_col_sum);
+
+        T::DbWeight::get()
+            .reads_writes(6, 1)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 1, 1))
+    }
+
+    /// Computes the dividends of the validators of `uids` from their bonds, normalizing the
+    /// bonds without Yuma3.
+    fn compute_multi_block_epoch_dividends(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let active_stake =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ActiveStake);
+        let incentive = MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::Ranks);
+        let col_sum = MultiBlockEpochVectors::<T>::get(
+            netuid,
+            if epoch.yuma3 {
+                MultiBlockEpochVector::BondsColSum
+            } else {
+                MultiBlockEpochVector::EmaColSum
+            },
+        );
+        let mut dividends =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::Dividends, epoch.n);
+        let mut ema_col_max =
+            Self::get_multi_block_epoch_vector(netuid, MultiBlockEpochVector::EmaColMax, epoch.n);
+        let count = rows.len();
+
+        for uid_i in rows {
+            let Some(mut neuron) = MultiBlockEpochNeurons::<T>::get(netuid, uid_i) else {
+                continue;
+            };
+
+            // Normalize EMA bonds: sum_i b_ij = 1.
+            let ema_bonds_norm: Vec<(u16, I32F32)> = neuron
+                .bonds
+                .iter()
+                .map(|(uid_j, bond_ij)| {
+                    (
+                        *uid_j,
+                        scale_to_column(*bond_ij, col_sum.get(usize::from(*uid_j))),
+                    )
+                })
+                .collect();
+
+            let dividend: I32F32 = if epoch.yuma3 {
+                // d_i = SUM(j) b_ij * inc_j * s_i, the stored bonds are not normalized.
+                let total_bonds: I32F32 = row_sum_sparse(&mat_vec_mul_sparse(
+                    sp_std::slice::from_ref(&ema_bonds_norm),
+                    &incentive,
+                ))
+                .first()
+                .copied()
+                .unwrap_or_default();
+                let stake_i = active_stake
+                    .get(usize::from(uid_i))
+                    .copied()
+                    .unwrap_or_default();
+                total_bonds.checked_mul(stake_i).unwrap_or_default()
+            } else {
+                // d_i = SUM(j) b_ij * inc_j, the stored bonds are normalized.
+                let dividend: I32F32 =
+                    matmul_transpose_sparse(sp_std::slice::from_ref(&ema_bonds_norm), &incentive)
+                        .first()
+                        .copied()
+                        .unwrap_or_default();
+                for (uid_j, bond_ij) in ema_bonds_norm.iter() {
+                    if let Some(col_max) = ema_col_max.get_mut(usize::from(*uid_j)) {
+                        if *col_max < *bond_ij {
+                            *col_max = *bond_ij;
+                        }
+                    }
+                }
+                neuron.bonds = ema_bonds_norm;
+                MultiBlockEpochNeurons::<T>::insert(netuid, uid_i, neuron);
+                dividend
+            };
+            if let Some(dividend_i) = dividends.get_mut(usize::from(uid_i)) {
+                *dividend_i = dividend;
+            }
+        }
+
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::Dividends, dividends);
+        MultiBlockEpochVectors::<T>::insert(netuid, MultiBlockEpochVector::EmaColMax, ema_col_max);
+
+        T::DbWeight::get()
+            .reads_writes(5, 2)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 1, 1))
+    }
+
+    /// Column max-upscales the bond rows of `uids` for storage, without Yuma3.
+    fn upscale_multi_block_epoch_bonds(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let ema_col_max =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::EmaColMax);
+        let count = rows.len();
+
+        for uid_i in rows {
+            let Some(mut neuron) = MultiBlockEpochNeurons::<T>::get(netuid, uid_i) else {
+                continue;
+            };
+            // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
+            neuron.bonds.iter_mut().for_each(|(uid_j, bond_ij)| {
+                *bond_ij = scale_to_column(*bond_ij, ema_col_max.get(usize::from(*uid_j)));
+            });
+            MultiBlockEpochNeurons::<T>::insert(netuid, uid_i, neuron);
+        }
+
+        T::DbWeight::get()
+            .reads(1)
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 1, 1))
+    }
+
+    /// Computes the emission of each uid and writes the values of the epoch, once every
+    /// dividend is computed.
+    fn emit_multi_block_epoch(netuid: NetUid, epoch: &mut MultiBlockEpoch) -> Weight {
+        let uids: Vec<MultiBlockEpochUid> = MultiBlockEpochUids::<T>::get(netuid);
+        let vector =
+            |vector: MultiBlockEpochVector| MultiBlockEpochVectors::<T>::get(netuid, vector);
+        let stake = vector(MultiBlockEpochVector::Stake);
+        let active_stake = vector(MultiBlockEpochVector::ActiveStake);
+        let incentive = vector(MultiBlockEpochVector::Ranks);
+        let mut dividends = vector(MultiBlockEpochVector::Dividends);
+        inplace_normalize(&mut dividends);
+        log::trace!("Dividends: {:?}", &dividends);
+
+        // Compute normalized emission scores. range: I32F32(0, 1)
+        let combined_emission: Vec<I32F32> = incentive
+            .iter()
+            .zip(dividends.clone())
+            .map(|(ii, di)| ii.saturating_add(di))
+            .collect();
+        let emission_sum: I32F32 = combined_emission.iter().sum();
+
+        let mut normalized_server_emission: Vec<I32F32> = incentive.clone(); // Servers get incentive.
+        let mut normalized_validator_emission: Vec<I32F32> = dividends.clone(); // Validators get dividends.
+        let mut normalized_combined_emission: Vec<I32F32> = combined_emission;
+        // Normalize on the sum of incentive + dividends.
+        inplace_normalize_using_sum(&mut normalized_server_emission, emission_sum);
+        inplace_normalize_using_sum(&mut normalized_validator_emission, emission_sum);
+        inplace_normalize(&mut normalized_combined_emission);
+
+        // If emission is zero, replace emission with normalized stake.
+        if emission_sum == I32F32::from(0) {
+            // no weights set | outdated weights | self_weights
+            if is_zero(&active_stake) {
+                // no active stake
+                normalized_validator_emission.clone_from(&stake); // do not mask inactive, assumes stake is normalized
+                normalized_combined_emission.clone_from(&stake);
+            } else {
+                normalized_validator_emission.clone_from(&active_stake); // emission proportional to inactive-masked normalized stake
+                normalized_combined_emission.clone_from(&active_stake);
+            }
+        }
+
+        // Compute rao based emission scores. range: [0, rao_emission]
+        let rao_emission: u64 = epoch
+            .pending_alpha
+            .saturating_add(epoch.pending_swapped)
+            .into();
+        let combined_emission: Vec<AlphaCurrency> = normalized_combined_emission
+            .iter()
+            .map(|ce: &I32F32| ce.saturating_scale(rao_emission).into())
+            .collect();
+
+        // The validators get the emission the miners do not, see `calculate_epoch_distribution`.
+        let incentive_sum: AlphaCurrency = uids
+            .iter()
+            .zip(normalized_server_emission.iter())
+            .filter(|(uid, _)| uid.neuron)
+            .fold(AlphaCurrency::ZERO, |sum, (_, se)| {
+                sum.saturating_add(se.saturating_scale(rao_emission).into())
+            });
+        epoch.pending_validator_alpha = Self::get_pending_validator_alpha(
+            epoch.pending_alpha,
+            epoch.pending_swapped,
+            incentive_sum,
+        );
+
+        // Get new validator permits.
+        let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
+        let new_validator_permits: Vec<bool> =
+            is_topk_nonzero(&stake, max_allowed_validators as usize);
+
+        // ===================
+        // == Value storage ==
+        // ===================
+        let to_u16 = |values: &[I32F32]| -> Vec<u16> {
+            values
+                .iter()
+                .map(|xi| fixed_proportion_to_u16(*xi))
+                .collect()
+        };
+        let registered: BTreeSet<u16> =
+            MultiBlockEpochRegistrations::<T>::iter_key_prefix(netuid).collect();
+        let len: u16 = Self::get_subnetwork_n(netuid);
+        StakeWeight::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                StakeWeight::<T>::get(netuid),
+                to_u16(&stake),
+                len,
+                &registered,
+            ),
+        );
+        Active::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Active::<T>::get(netuid),
+                uids.iter().map(|uid| !uid.inactive).collect(),
+                len,
+                &registered,
+            ),
+        );
+        Emission::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Emission::<T>::get(netuid),
+                combined_emission,
+                len,
+                &registered,
+            ),
+        );
+        Rank::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Rank::<T>::get(netuid),
+                to_u16(&incentive),
+                len,
+                &registered,
+            ),
+        );
+        Trust::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Trust::<T>::get(netuid),
+                to_u16(&vector(MultiBlockEpochVector::Trust)),
+                len,
+                &registered,
+            ),
+        );
+        Consensus::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Consensus::<T>::get(netuid),
+                to_u16(&vector(MultiBlockEpochVector::Consensus)),
+                len,
+                &registered,
+            ),
+        );
+        Incentive::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Incentive::<T>::get(netuid),
+                to_u16(&incentive),
+                len,
+                &registered,
+            ),
+        );
+        Dividends::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                Dividends::<T>::get(netuid),
+                to_u16(&dividends),
+                len,
+                &registered,
+            ),
+        );
+        PruningScores::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                PruningScores::<T>::get(netuid),
+                vec_max_upscale_to_u16(&normalized_combined_emission),
+                len,
+                &registered,
+            ),
+        );
+        ValidatorTrust::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                ValidatorTrust::<T>::get(netuid),
+                to_u16(&vector(MultiBlockEpochVector::ValidatorTrust)),
+                len,
+                &registered,
+            ),
+        );
+        ValidatorPermit::<T>::insert(
+            netuid,
+            Self::merge_multi_block_epoch_vector(
+                ValidatorPermit::<T>::get(netuid),
+                new_validator_permits,
+                len,
+                &registered,
+            ),
+        );
+
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::ServerEmission,
+            normalized_server_emission,
+        );
+        MultiBlockEpochVectors::<T>::insert(
+            netuid,
+            MultiBlockEpochVector::ValidatorEmission,
+            normalized_validator_emission,
+        );
+
+        T::DbWeight::get()
+            .reads_writes(22, 13)
+            .saturating_add(T::DbWeight::get().reads(registered.len() as u64))
+    }
+
+    /// Writes the bond rows of `uids`, and splits the emission of each uid between its hotkey,
+    /// for the incentive, and its parents, for the dividends.
+    fn write_multi_block_epoch_neurons(
+        netuid: NetUid,
+        epoch: &MultiBlockEpoch,
+        rows: Range<u16>,
+    ) -> Weight {
+        let uids: Vec<MultiBlockEpochUid> = MultiBlockEpochUids::<T>::get(netuid);
+        let new_validator_permits: Vec<bool> = Self::get_validator_permit(netuid);
+        let server_emission =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ServerEmission);
+        let validator_emission =
+            MultiBlockEpochVectors::<T>::get(netuid, MultiBlockEpochVector::ValidatorEmission);
+        let registered: BTreeSet<u16> =
+            MultiBlockEpochRegistrations::<T>::iter_key_prefix(netuid).collect();
+        let rao_emission: u64 = epoch
+            .pending_alpha
+            .saturating_add(epoch.pending_swapped)
+            .into();
+        let count = rows.len();
+
+        for uid_i in rows {
+            let neuron = MultiBlockEpochNeurons::<T>::take(netuid, uid_i);
+
+            // Neither write the bonds of a uid registered since the epoch started, nor the bonds
+            // on it.
+            if !registered.contains(&uid_i) {
+                // Set bonds only if uid retains validator permit, otherwise clear bonds.
+                if new_validator_permits
+                    .get(usize::from(uid_i))
+                    .copied()
+                    .unwrap_or(false)
+                {
+                    let new_bonds_row: Vec<(u16, u16)> = neuron
+                        .as_ref()
+                        .map(|neuron| {
+                            neuron
+                                .bonds
+                                .iter()
+                                .filter(|(uid_j, _)| !registered.contains(uid_j))
+                                .map(|(uid_j, value)| (*uid_j, fixed_proportion_to_u16(*value)))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    Bonds::<T>::insert(netuid, uid_i, new_bonds_row);
+                } else if uids
+                    .get(usize::from(uid_i))
+                    .is_some_and(|uid| uid.validator_permit)
+                {
+                    // Only overwrite the intersection.
+                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
+                    Bonds::<T>::insert(netuid, uid_i, new_empty_bonds_row);
+                }
+            }
+
+            let Some(neuron) = neuron else {
+                continue;
+            };
+            let incentive: AlphaCurrency = server_emission
+                .get(usize::from(uid_i))
+                .map(|se| se.saturating_scale(rao_emission).into())
+                .unwrap_or_default();
+            let dividend: AlphaCurrency = validator_emission
+                .get(usize::from(uid_i))
+                .map(|ve| ve.saturating_scale(rao_emission).into())
+                .unwrap_or_default();
+
+            // Accumulate incentives to miners.
+            MultiBlockEpochIncentives::<T>::mutate(netuid, &neuron.hotkey, |total| {
+                *total = total.saturating_add(incentive)
+            });
+            // Accumulate dividends to parents.
+            for (parent, parent_div) in
+                Self::get_parent_child_dividends_distribution(&neuron.hotkey, netuid, dividend)
+            {
+                MultiBlockEpochDividends::<T>::mutate(netuid, &parent, |total| {
+                    *total = total.saturating_add(U96F32::saturating_from_num(parent_div.to_u64()))
+                });
+            }
+        }
+
+        T::DbWeight::get()
+            .reads(5)
+            .saturating_add(T::DbWeight::get().reads(registered.len() as u64))
+            .saturating_add(Self::weigh_multi_block_epoch_items(count, 12, 5))
+    }
+}
@@ -36,6 +36,9 @@ pub struct LegacyWeightsTlockPayload {
 
 impl<T: Config> Pallet<T> {
     /// The `reveal_crv3_commits` function is run at the very beginning of epoch `n`,
+    /// before the epoch itself, and decrypts the commits made during epoch `n - reveal_period`
+    /// with the drand pulse of their reveal round, setting the weights on behalf of the committing
+    /// hotkeys. Commits whose pulse is not on chain yet are retried every block of the reveal epoch.
     pub fn reveal_crv3_commits(netuid: NetUid) -> dispatch::DispatchResult {
         let reveal_period = Self::get_reveal_period(netuid);
         let cur_block = Self::get_current_block_as_u64();
@@ -82,93 +85,20 @@ impl<T: Config> Pallet<T> {
                 }
             };
 
-            let reader = &mut &serialized_compresssed_commit[..];
-            let commit = match TLECiphertext::<TinyBLS381>::deserialize_compressed(reader) {
-                Ok(c) => c,
-                Err(e) => {
-                    log::trace!(
-                        "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to error deserializing the commit: {e:?}"
-                    );
-                    continue;
-                }
-            };
-
-            let signature_bytes = pulse
-                .signature
-                .strip_prefix(b"0x")
-                .unwrap_or(&pulse.signature);
-
-            let sig_reader = &mut &signature_bytes[..];
-            let sig = match <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                sig_reader,
+            let (uids, values, version_key) = match Self::decrypt_crv3_commit(
+                &who,
+                &serialized_compresssed_commit,
+                &pulse.signature,
             ) {
-                Ok(s) => s,
+                Ok(revealed) => revealed,
                 Err(e) => {
                     log::trace!(
-                        "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to error deserializing signature from drand pallet: {e:?}"
+                        "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to {e}"
                     );
                     continue;
                 }
             };
 
-            let decrypted_bytes: Vec<u8> = match tld::<TinyBLS381, AESGCMStreamCipherProvider>(
-                commit, sig,
-            ) {
-                Ok(d) => d,
-                Err(e) => {
-                    log::trace!(
-                        "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to error decrypting the commit: {e:?}"
-                    );
-                    continue;
-                }
-            };
-
-            // ------------------------------------------------------------------
-            // Try to decode payload with the new and legacy formats.
-            // ------------------------------------------------------------------
-            let (uids, values, version_key) = {
-                let mut reader_new = &decrypted_bytes[..];
-                if let Ok(payload) = WeightsTlockPayload::decode(&mut reader_new) {
-                    // Verify hotkey matches committer
-                    let mut hk_reader = &payload.hotkey[..];
-                    match T::AccountId::decode(&mut hk_reader) {
-                        Ok(decoded_hotkey) if decoded_hotkey == who => {
-                            (payload.uids, payload.values, payload.version_key)
-                        }
-                        Ok(_) => {
-                            log::trace!(
-                                "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to hotkey mismatch in payload"
-                            );
-                            continue;
-                        }
-                        Err(e) => {
-                            let mut reader_legacy = &decrypted_bytes[..];
-                            match LegacyWeightsTlockPayload::decode(&mut reader_legacy) {
-                                Ok(legacy) => (legacy.uids, legacy.values, legacy.version_key),
-                                Err(_) => {
-                                    log::trace!(
-                                        "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to error deserializing hotkey: {e:?}"
-                                    );
-                                    continue;
-                                }
-                            }
-                        }
-                    }
-                } else {
-                    // Fallback to legacy payload
-                    let mut reader_legacy = &decrypted_bytes[..];
-                    match LegacyWeightsTlockPayload::decode(&mut reader_legacy) {
-                        Ok(legacy) => (legacy.uids, legacy.values, legacy.version_key),
-                        Err(e) => {
-                            log::trace!(
-                                "Failed to reveal commit for subnet {netuid} submitted by {who:?} due to error deserializing both payload formats: {e:?}"
-                            );
-                            continue;
-                        }
-                    }
-                }
-            };
-
             // ------------------------------------------------------------------
             //                          Apply weights
             // ------------------------------------------------------------------
@@ -194,4 +124,57 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Decrypts the serialized commit-reveal v3 commit of `who` with the drand pulse `signature`
+    /// of its reveal round, and decodes the revealed uids, values and version key from the new or
+    /// the legacy payload format.
+    ///
+    /// Both inputs come from outside the runtime and may be arbitrary bytes, so this never panics.
+    pub fn decrypt_crv3_commit(
+        who: &T::AccountId,
+        commit: &[u8],
+        signature: &[u8],
+    ) -> Result<(Vec<u16>, Vec<u16>, u64), &'static str> {
+        let reader = &mut &commit[..];
+        let commit = TLECiphertext::<TinyBLS381>::deserialize_compressed(reader).map_err(|e| {
+            log::trace!("Failed to deserialize the commit: {e:?}");
+            "error deserializing the commit"
+        })?;
+
+        let signature_bytes = signature.strip_prefix(b"0x").unwrap_or(signature);
+        let sig_reader = &mut &signature_bytes[..];
+        let sig = <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(sig_reader)
+            .map_err(|e| {
+                log::trace!("Failed to deserialize the drand signature: {e:?}");
+                "error deserializing signature from drand pallet"
+            })?;
+
+        let decrypted_bytes: Vec<u8> = tld::<TinyBLS381, AESGCMStreamCipherProvider>(commit, sig)
+            .map_err(|e| {
+            log::trace!("Failed to decrypt the commit: {e:?}");
+            "error decrypting the commit"
+        })?;
+
+        // Try to decode payload with the new and legacy formats.
+        let legacy = || {
+            let mut reader_legacy = &decrypted_bytes[..];
+            LegacyWeightsTlockPayload::decode(&mut reader_legacy)
+                .map(|legacy| (legacy.uids, legacy.values, legacy.version_key))
+        };
+        let mut reader_new = &decrypted_bytes[..];
+        let Ok(payload) = WeightsTlockPayload::decode(&mut reader_new) else {
+            // Fallback to legacy payload
+            return legacy().map_err(|_| "error deserializing both payload formats");
+        };
+
+        // Verify hotkey matches committer
+        let mut hk_reader = &payload.hotkey[..];
+        match T::AccountId::decode(&mut hk_reader) {
+            Ok(decoded_hotkey) if decoded_hotkey == *who => {
+                Ok((payload.uids, payload.values, payload.version_key))
+            }
+            Ok(_) => Err("hotkey mismatch in payload"),
+            Err(_) => legacy().map_err(|_| "error deserializing hotkey"),
+        }
+    }
 }
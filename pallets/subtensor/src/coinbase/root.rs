@@ -16,6 +16,7 @@
 // DEALINGS IN THE SOFTWARE.
 
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
 use frame_support::dispatch::Pays;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::weights::Weight;
@@ -465,6 +466,7 @@ impl<T: Config> Pallet<T> {
         LastUpdate::<T>::remove(netuid);
         ValidatorPermit::<T>::remove(netuid);
         ValidatorTrust::<T>::remove(netuid);
+        let _ = AlphaDividendsHistory::<T>::clear_prefix(netuid, u32::MAX, None);
 
         for (_uid, key) in keys {
             IsNetworkMember::<T>::remove(key, netuid);
@@ -477,14 +479,59 @@ impl<T: Config> Pallet<T> {
         MaxAllowedUids::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
+        UnbondingPeriod::<T>::remove(netuid);
+        let _ = UnbondingFloor::<T>::clear_prefix(netuid, u32::MAX, None);
+        SubnetOwnerCutForSubnet::<T>::remove(netuid);
+        SubnetStakeThreshold::<T>::remove(netuid);
+        BondsResetOnDeregistration::<T>::remove(netuid);
+        MinerEmissionVestingPeriod::<T>::remove(netuid);
+        MaxWeightAge::<T>::remove(netuid);
+        SubnetTransactionFee::<T>::remove(netuid);
+        Self::remove_evm_gas_sponsorship(netuid);
+        MultiBlockEpochChunkSize::<T>::remove(netuid);
+        Self::clear_multi_block_epoch(netuid);
+        Self::remove_emission_shares(netuid);
+        SubnetEmissionHalted::<T>::remove(netuid);
+        BurnControllerGains::<T>::remove(netuid);
+        BurnControllerState::<T>::remove(netuid);
+        RegistrationPowAlgorithms::<T>::remove(netuid);
+        SubnetOffers::<T>::remove(netuid);
+        IncentiveMatching::<T>::remove(netuid);
+        Self::refund_registration_bids(netuid);
+        RegistrationAuctionEnabled::<T>::remove(netuid);
+        Self::remove_subnet_proposal(netuid);
+        VacantUids::<T>::remove(netuid);
+        SubnetMaintenanceStart::<T>::remove(netuid);
+        let _ = RegistrationBurnPaid::<T>::clear_prefix(netuid, u32::MAX, None);
+        SubnetTaoBurned::<T>::remove(netuid);
+        SubnetAlphaRecycled::<T>::remove(netuid);
+        SubnetAlphaBurned::<T>::remove(netuid);
+        let _ = ReportedOffenses::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = LockBoost::<T>::clear_prefix((netuid,), u32::MAX, None);
+        let _ = VestingMinerEmission::<T>::clear_prefix(netuid, u32::MAX, None);
+        let locks: Vec<_> = StakeLocks::<T>::iter_keys()
+            .filter(|(_, _, lock_netuid)| *lock_netuid == netuid)
+            .collect();
+        for key in locks {
+            StakeLocks::<T>::remove(key);
+        }
         MaxWeightsLimit::<T>::remove(netuid);
+        PendingWeightsVersionKey::<T>::remove(netuid);
+        WeightsVersionKeyGracePeriod::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
 
-        // --- 12. Add the balance back to the owner.
-        Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount.into());
+        Self::remove_subnet_treasury(netuid, &owner_coldkey);
+
+        // --- 12. Add the balance back to the owner, reissuing the lock burned at registration.
+        let _ = Self::transfer_tao(
+            LedgerAccount::Issuance,
+            LedgerAccount::Coldkey(owner_coldkey.clone()),
+            reserved_amount,
+            LedgerReason::NetworkLockRefund,
+        );
         Self::set_subnet_locked_balance(netuid, TaoCurrency::ZERO);
         SubnetOwner::<T>::remove(netuid);
 
@@ -493,6 +540,7 @@ impl<T: Config> Pallet<T> {
             SubnetIdentitiesV3::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+        SubnetLogoHashes::<T>::remove(netuid);
     }
 
     #[allow(clippy::arithmetic_side_effects)]
@@ -515,10 +563,15 @@ impl<T: Config> Pallet<T> {
     ///     - The lock cost for the network.
     ///
     pub fn get_network_lock_cost() -> TaoCurrency {
+        Self::get_network_lock_cost_at(Self::get_current_block_as_u64())
+    }
+
+    /// Lock cost to register a network at `current_block`, assuming no other network is
+    /// registered in the meantime.
+    pub fn get_network_lock_cost_at(current_block: u64) -> TaoCurrency {
         let last_lock = Self::get_network_last_lock();
         let min_lock = Self::get_network_min_lock();
         let last_lock_block = Self::get_network_last_lock_block();
-        let current_block = Self::get_current_block_as_u64();
         let lock_reduction_interval = Self::get_lock_reduction_interval();
         let mult: TaoCurrency = if last_lock_block == 0 { 1 } else { 2 }.into();
 
@@ -541,6 +594,15 @@ impl<T: Config> Pallet<T> {
         lock_cost
     }
 
+    /// Projected lock cost for each of the next `blocks` blocks (capped at
+    /// `MAX_LOCK_COST_PROJECTION_BLOCKS`), following the lock reduction schedule.
+    pub fn get_network_lock_cost_projection(blocks: u64) -> Vec<TaoCurrency> {
+        let current_block = Self::get_current_block_as_u64();
+        (1..=blocks.min(MAX_LOCK_COST_PROJECTION_BLOCKS))
+            .map(|offset| Self::get_network_lock_cost_at(current_block.saturating_add(offset)))
+            .collect()
+    }
+
     pub fn get_network_registered_block(netuid: NetUid) -> u64 {
         NetworkRegisteredAt::<T>::get(netuid)
     }
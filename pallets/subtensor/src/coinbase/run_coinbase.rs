@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
 use alloc::collections::BTreeMap;
 use safe_math::*;
 use substrate_fixed::types::U96F32;
@@ -24,10 +25,10 @@ impl<T: Config> Pallet<T> {
         let current_block: u64 = Self::get_current_block_as_u64();
         log::debug!("Current block: {current_block:?}");
 
-        // --- 1. Get all netuids (filter out root)
+        // --- 1. Get all netuids (filter out root and the subnets being dissolved)
         let subnets: Vec<NetUid> = Self::get_all_subnet_netuids()
             .into_iter()
-            .filter(|netuid| *netuid != NetUid::ROOT)
+            .filter(|netuid| *netuid != NetUid::ROOT && !Self::is_network_dissolving(*netuid))
             .collect();
         log::debug!("All subnet netuids: {subnets:?}");
         // Filter out subnets with no first emission block number.
@@ -94,6 +95,8 @@ impl<T: Config> Pallet<T> {
                     true,
                 );
                 if let Ok(buy_swap_result_ok) = buy_swap_result {
+                    // The TAO buying the subsidy is added to the pools without being issued.
+                    Self::increase_total_stake(tou64!(difference_tao).into());
                     let bought_alpha = AlphaCurrency::from(buy_swap_result_ok.amount_paid_out);
                     SubnetAlphaOut::<T>::mutate(*netuid_i, |total| {
                         *total = total.saturating_sub(bought_alpha);
@@ -117,12 +120,19 @@ impl<T: Config> Pallet<T> {
                 alpha_in_i = asfloat!(0.0);
                 alpha_out_i = asfloat!(0.0);
             }
+            // Halt the subnet if its alpha emission exceeds the schedule.
+            Self::check_emission_circuit_breaker(Some(*netuid_i), alpha_emission_i, alpha_out_i);
             // Insert values into maps
             tao_in.insert(*netuid_i, tao_in_i);
             alpha_in.insert(*netuid_i, alpha_in_i);
             alpha_out.insert(*netuid_i, alpha_out_i);
         }
         log::debug!("tao_in: {tao_in:?}");
+        // Halt all subnets if the TAO emission exceeds the schedule.
+        let total_tao_in: U96F32 = tao_in.values().fold(asfloat!(0), |total, tao_in_i| {
+            total.saturating_add(*tao_in_i)
+        });
+        Self::check_emission_circuit_breaker(None, block_emission, total_tao_in);
         log::debug!("alpha_in: {alpha_in:?}");
         log::debug!("alpha_out: {alpha_out:?}");
 
@@ -151,25 +161,28 @@ impl<T: Config> Pallet<T> {
             SubnetTAO::<T>::mutate(*netuid_i, |total| {
                 *total = total.saturating_add(tao_in_i.into());
             });
-            TotalStake::<T>::mutate(|total| {
-                *total = total.saturating_add(tao_in_i.into());
-            });
-            TotalIssuance::<T>::mutate(|total| {
-                *total = total.saturating_add(tao_in_i.into());
-            });
+            let _ = Self::transfer_tao(
+                LedgerAccount::Issuance,
+                LedgerAccount::Stake,
+                tao_in_i,
+                LedgerReason::Emission,
+            );
             // Adjust protocol liquidity based on new reserves
             T::SwapInterface::adjust_protocol_liquidity(*netuid_i, tao_in_i, alpha_in_i);
+            // Match the TAO emission of subnets in the incentive matching program.
+            Self::match_subnet_emission(*netuid_i, tao_in_i);
         }
 
         // --- 5. Compute owner cuts and remove them from alpha_out remaining.
         // Remove owner cuts here so that we can properly seperate root dividends in the next step.
         // Owner cuts are accumulated and then fed to the drain at the end of this func.
-        let cut_percent: U96F32 = Self::get_float_subnet_owner_cut();
         let mut owner_cuts: BTreeMap<NetUid, U96F32> = BTreeMap::new();
         for netuid_i in subnets_to_emit_to.iter() {
             // Get alpha out.
             let alpha_out_i: U96F32 = *alpha_out.get(netuid_i).unwrap_or(&asfloat!(0));
             log::debug!("alpha_out_i: {alpha_out_i:?}");
+            // Get the owner cut of this subnet.
+            let cut_percent: U96F32 = Self::get_float_subnet_owner_cut_for_subnet(*netuid_i);
             // Calculate the owner cut.
             let owner_cut_i: U96F32 = alpha_out_i.saturating_mul(cut_percent);
             log::debug!("owner_cut_i: {owner_cut_i:?}");
@@ -248,17 +261,28 @@ impl<T: Config> Pallet<T> {
 
         // --- 7. Drain pending emission through the subnet based on tempo.
         // Run the epoch for *all* subnets, even if we don't emit anything.
+        let mut epochs = Vec::new();
         for &netuid in subnets.iter() {
             // Reveal matured weights.
             if let Err(e) = Self::reveal_crv3_commits(netuid) {
                 log::warn!("Failed to reveal commits for subnet {netuid} due to error: {e:?}");
             };
-            // Pass on subnets that have not reached their tempo.
-            if Self::should_run_epoch(netuid, current_block) {
+            // Pass on subnets that have not reached their tempo, or are in maintenance or halted by
+            // the emission circuit breaker, and withhold their pending emission until they resume.
+            // Also pass on subnets still processing their previous epoch over several blocks.
+            if Self::should_run_epoch(netuid, current_block)
+                && !Self::is_subnet_in_maintenance(netuid)
+                && !Self::is_emission_halted(netuid)
+                && !Self::is_multi_block_epoch_running(netuid)
+            {
                 // Restart counters.
                 BlocksSinceLastStep::<T>::insert(netuid, 0);
                 LastMechansimStepBlock::<T>::insert(netuid, current_block);
 
+                // Keep the matrices the epoch starts from for nodes keeping the epoch history.
+                Self::index_epoch_history(netuid, current_block);
+                epochs.push(netuid);
+
                 // Get and drain the subnet pending emission.
                 let pending_alpha = PendingEmission::<T>::get(netuid);
                 PendingEmission::<T>::insert(netuid, AlphaCurrency::ZERO);
@@ -275,19 +299,31 @@ impl<T: Config> Pallet<T> {
                 let owner_cut = PendingOwnerCut::<T>::get(netuid);
                 PendingOwnerCut::<T>::insert(netuid, AlphaCurrency::ZERO);
 
-                // Drain pending root divs, alpha emission, and owner cut.
-                Self::drain_pending_emission(
-                    netuid,
-                    pending_alpha,
-                    pending_tao,
-                    pending_swapped,
-                    owner_cut,
-                );
+                // Drain pending root divs, alpha emission, and owner cut, over several blocks if
+                // the subnet splits its epoch.
+                if Self::get_multi_block_epoch_chunk_size(netuid) > 0 {
+                    Self::start_multi_block_epoch(
+                        netuid,
+                        pending_alpha,
+                        pending_tao,
+                        pending_swapped,
+                        owner_cut,
+                    );
+                } else {
+                    Self::drain_pending_emission(
+                        netuid,
+                        pending_alpha,
+                        pending_tao,
+                        pending_swapped,
+                        owner_cut,
+                    );
+                }
             } else {
                 // Increment
                 BlocksSinceLastStep::<T>::mutate(netuid, |total| *total = total.saturating_add(1));
             }
         }
+        Self::index_epochs(current_block, &epochs);
     }
 
     pub fn calculate_dividends_and_incentives(
@@ -339,9 +375,6 @@ impl<T: Config> Pallet<T> {
         log::debug!("pending_tao: {pending_tao:?}");
         log::debug!("tao_weight: {tao_weight:?}");
 
-        // Setup.
-        let zero: U96F32 = asfloat!(0.0);
-
         // Accumulate root divs and alpha_divs. For each hotkey we compute their
         // local and root dividend proportion based on their alpha_stake/root_stake
         let mut total_root_divs: U96F32 = asfloat!(0);
@@ -350,23 +383,8 @@ impl<T: Config> Pallet<T> {
         let mut alpha_dividends: BTreeMap<T::AccountId, U96F32> = BTreeMap::new();
         for (hotkey, dividend) in dividends {
             if let Some((alpha_stake, root_stake)) = stake_map.get(&hotkey) {
-                let alpha_stake = alpha_stake.to_u64();
-                let root_stake = root_stake.to_u64();
-                // Get hotkey ALPHA on subnet.
-                let alpha_stake = asfloat!(alpha_stake);
-                // Get hotkey TAO on root.
-                let root_stake = asfloat!(root_stake);
-
-                // Convert TAO to alpha with weight.
-                let root_alpha = root_stake.saturating_mul(tao_weight);
-                // Get total from root and local
-                let total_alpha = alpha_stake.saturating_add(root_alpha);
-                // Compute root prop.
-                let root_prop = root_alpha.checked_div(total_alpha).unwrap_or(zero);
-                // Compute root dividends
-                let root_divs = dividend.saturating_mul(root_prop);
-                // Compute alpha dividends
-                let alpha_divs = dividend.saturating_sub(root_divs);
+                let (alpha_divs, root_divs) =
+                    Self::split_dividend(dividend, *alpha_stake, *root_stake, tao_weight);
                 // Record the alpha dividends.
                 alpha_dividends
                     .entry(hotkey.clone())
@@ -391,11 +409,9 @@ impl<T: Config> Pallet<T> {
         // Compute root divs as TAO. Here we take
         let mut tao_dividends: BTreeMap<T::AccountId, U96F32> = BTreeMap::new();
         for (hotkey, root_divs) in root_dividends {
-            // Root proportion.
-            let root_share: U96F32 = root_divs.checked_div(total_root_divs).unwrap_or(zero);
-            log::debug!("hotkey: {hotkey:?}, root_share: {root_share:?}");
             // Root proportion in TAO
-            let root_tao: U96F32 = asfloat!(pending_tao).saturating_mul(root_share);
+            let root_tao: U96F32 =
+                Self::get_dividend_share(pending_tao.to_u64(), root_divs, total_root_divs);
             log::debug!("hotkey: {hotkey:?}, root_tao: {root_tao:?}");
             // Record root dividends as TAO.
             tao_dividends
@@ -408,12 +424,9 @@ impl<T: Config> Pallet<T> {
         // Compute proportional alpha divs using the pending alpha and total alpha divs from the epoch.
         let mut prop_alpha_dividends: BTreeMap<T::AccountId, U96F32> = BTreeMap::new();
         for (hotkey, alpha_divs) in alpha_dividends {
-            // Alpha proportion.
-            let alpha_share: U96F32 = alpha_divs.checked_div(total_alpha_divs).unwrap_or(zero);
-            log::debug!("hotkey: {hotkey:?}, alpha_share: {alpha_share:?}");
-
             // Compute the proportional pending_alpha to this hotkey.
-            let prop_alpha = asfloat!(pending_alpha).saturating_mul(alpha_share);
+            let prop_alpha =
+                Self::get_dividend_share(pending_alpha.to_u64(), alpha_divs, total_alpha_divs);
             log::debug!("hotkey: {hotkey:?}, prop_alpha: {prop_alpha:?}");
             // Record the proportional alpha dividends.
             prop_alpha_dividends
@@ -426,6 +439,38 @@ impl<T: Config> Pallet<T> {
         (prop_alpha_dividends, tao_dividends)
     }
 
+    /// Splits the `dividend` of a hotkey into its alpha and root dividends, by the share of its
+    /// root stake, converted to alpha with the TAO weight, in its total stake.
+    pub fn split_dividend(
+        dividend: U96F32,
+        alpha_stake: AlphaCurrency,
+        root_stake: AlphaCurrency,
+        tao_weight: U96F32,
+    ) -> (U96F32, U96F32) {
+        // Get hotkey ALPHA on subnet.
+        let alpha_stake = asfloat!(alpha_stake.to_u64());
+        // Get hotkey TAO on root.
+        let root_stake = asfloat!(root_stake.to_u64());
+
+        // Convert TAO to alpha with weight.
+        let root_alpha = root_stake.saturating_mul(tao_weight);
+        // Get total from root and local
+        let total_alpha = alpha_stake.saturating_add(root_alpha);
+        // Compute root prop.
+        let root_prop = root_alpha.checked_div(total_alpha).unwrap_or(asfloat!(0));
+        // Compute root dividends
+        let root_divs = dividend.saturating_mul(root_prop);
+        // Compute alpha dividends
+        let alpha_divs = dividend.saturating_sub(root_divs);
+        (alpha_divs, root_divs)
+    }
+
+    /// The part of `pending` paid for `divs` out of the `total_divs` of all hotkeys.
+    pub fn get_dividend_share(pending: u64, divs: U96F32, total_divs: U96F32) -> U96F32 {
+        let share: U96F32 = divs.checked_div(total_divs).unwrap_or(asfloat!(0));
+        asfloat!(pending).saturating_mul(share)
+    }
+
     pub fn distribute_dividends_and_incentives(
         netuid: NetUid,
         owner_cut: AlphaCurrency,
@@ -433,9 +478,48 @@ impl<T: Config> Pallet<T> {
         alpha_dividends: BTreeMap<T::AccountId, U96F32>,
         tao_dividends: BTreeMap<T::AccountId, U96F32>,
     ) {
-        // Distribute the owner cut.
+        Self::distribute_owner_cut(netuid, owner_cut);
+
+        // Distribute mining incentives.
+        let mut total_incentives = AlphaCurrency::ZERO;
+        for (hotkey, incentive) in incentives {
+            total_incentives = total_incentives.saturating_add(incentive);
+            Self::distribute_incentive(netuid, hotkey, incentive);
+        }
+
+        // Distribute alpha divs.
+        let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
+        let mut total_alpha_dividends = AlphaCurrency::ZERO;
+        for (hotkey, alpha_divs) in alpha_dividends {
+            total_alpha_dividends = total_alpha_dividends.saturating_add(tou64!(alpha_divs).into());
+            Self::distribute_alpha_dividends(netuid, hotkey, alpha_divs);
+        }
+
+        // Distribute root tao divs.
+        let _ = TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
+        let mut total_tao_dividends = TaoCurrency::ZERO;
+        for (hotkey, root_tao) in tao_dividends {
+            total_tao_dividends = total_tao_dividends.saturating_add(tou64!(root_tao).into());
+            Self::distribute_tao_dividends(netuid, hotkey, root_tao);
+        }
+
+        Self::deposit_event(Event::EmissionDistributed {
+            netuid,
+            owner_cut,
+            incentives: total_incentives,
+            alpha_dividends: total_alpha_dividends,
+            tao_dividends: total_tao_dividends,
+        });
+    }
+
+    /// Pays out the owner cut of an epoch to the subnet owner.
+    pub fn distribute_owner_cut(netuid: NetUid, owner_cut: AlphaCurrency) {
         if let Ok(owner_coldkey) = SubnetOwner::<T>::try_get(netuid) {
             if let Ok(owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
+                // Pay the shares agreed with other subnets out of the owner cut.
+                let owner_cut = Self::distribute_emission_shares(netuid, owner_cut);
+                // Pay the treasury share out of the owner cut.
+                let owner_cut = Self::fund_subnet_treasury(netuid, &owner_hotkey, owner_cut);
                 // Increase stake for owner hotkey and coldkey.
                 log::debug!(
                     "owner_hotkey: {owner_hotkey:?} owner_coldkey: {owner_coldkey:?}, owner_cut: {owner_cut:?}"
@@ -452,90 +536,97 @@ impl<T: Config> Pallet<T> {
                 }
             }
         }
+    }
 
-        // Distribute mining incentives.
-        for (hotkey, incentive) in incentives {
-            log::debug!("incentives: hotkey: {incentive:?}");
+    /// Pays out the incentive of a miner hotkey for an epoch.
+    pub fn distribute_incentive(netuid: NetUid, hotkey: T::AccountId, incentive: AlphaCurrency) {
+        log::debug!("incentives: hotkey: {incentive:?}");
 
-            if let Ok(owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
-                if hotkey == owner_hotkey {
-                    log::debug!(
-                        "incentives: hotkey: {hotkey:?} is SN owner hotkey, skipping {incentive:?}"
-                    );
-                    continue; // Skip/burn miner-emission for SN owner hotkey.
-                }
+        if let Ok(owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
+            if hotkey == owner_hotkey {
+                log::debug!(
+                    "incentives: hotkey: {hotkey:?} is SN owner hotkey, skipping {incentive:?}"
+                );
+                Self::record_subnet_alpha_burned(netuid, incentive);
+                return; // Skip/burn miner-emission for SN owner hotkey.
             }
-            // Increase stake for miner.
-            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                &hotkey.clone(),
-                &Owner::<T>::get(hotkey.clone()),
-                netuid,
-                incentive,
-            );
         }
-
-        // Distribute alpha divs.
-        let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
-        for (hotkey, mut alpha_divs) in alpha_dividends {
-            // Get take prop
-            let alpha_take: U96F32 =
-                Self::get_hotkey_take_float(&hotkey).saturating_mul(alpha_divs);
-            // Remove take prop from alpha_divs
-            alpha_divs = alpha_divs.saturating_sub(alpha_take);
-            // Give the validator their take.
-            log::debug!("hotkey: {hotkey:?} alpha_take: {alpha_take:?}");
-            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                &hotkey,
-                &Owner::<T>::get(&hotkey),
-                netuid,
-                tou64!(alpha_take).into(),
-            );
-            // Give all other nominators.
-            log::debug!("hotkey: {hotkey:?} alpha_divs: {alpha_divs:?}");
-            Self::increase_stake_for_hotkey_on_subnet(&hotkey, netuid, tou64!(alpha_divs).into());
-            // Record dividends for this hotkey.
-            AlphaDividendsPerSubnet::<T>::mutate(netuid, &hotkey, |divs| {
-                *divs = divs.saturating_add(tou64!(alpha_divs).into());
-            });
-            // Record total hotkey alpha based on which this value of AlphaDividendsPerSubnet
-            // was calculated
-            let total_hotkey_alpha = TotalHotkeyAlpha::<T>::get(&hotkey, netuid);
-            TotalHotkeyAlphaLastEpoch::<T>::insert(hotkey, netuid, total_hotkey_alpha);
+        // Stream the incentive if the subnet vests miner emission.
+        if Self::get_miner_emission_vesting_period(netuid) > 0 {
+            Self::vest_miner_emission(netuid, &hotkey, incentive);
+            return;
         }
+        // Pay the miner, as stake or to its emission recipient.
+        Self::pay_hotkey_owner_emission(&hotkey, netuid, incentive);
+    }
 
-        // Distribute root tao divs.
-        let _ = TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
-        for (hotkey, mut root_tao) in tao_dividends {
-            // Get take prop
-            let tao_take: U96F32 = Self::get_hotkey_take_float(&hotkey).saturating_mul(root_tao);
-            // Remove take prop from root_tao
-            root_tao = root_tao.saturating_sub(tao_take);
-            // Give the validator their take.
-            log::debug!("hotkey: {hotkey:?} tao_take: {tao_take:?}");
-            let validator_stake = Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                &hotkey,
-                &Owner::<T>::get(hotkey.clone()),
-                NetUid::ROOT,
-                tou64!(tao_take).into(),
-            );
-            // Give rest to nominators.
-            log::debug!("hotkey: {hotkey:?} root_tao: {root_tao:?}");
-            Self::increase_stake_for_hotkey_on_subnet(
-                &hotkey,
-                NetUid::ROOT,
-                tou64!(root_tao).into(),
-            );
-            // Record root dividends for this validator on this subnet.
-            TaoDividendsPerSubnet::<T>::mutate(netuid, hotkey.clone(), |divs| {
-                *divs = divs.saturating_add(tou64!(root_tao).into());
-            });
-            // Update the total TAO on the subnet with root tao dividends.
-            SubnetTAO::<T>::mutate(NetUid::ROOT, |total| {
-                *total = total
-                    .saturating_add(validator_stake.to_u64().into())
-                    .saturating_add(tou64!(root_tao).into());
-            });
-        }
+    /// Pays out the alpha dividends of a validator hotkey for an epoch, taking its take.
+    pub fn distribute_alpha_dividends(
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        mut alpha_divs: U96F32,
+    ) {
+        // Get take prop
+        let alpha_take: U96F32 = Self::get_hotkey_take_float(&hotkey).saturating_mul(alpha_divs);
+        // Remove take prop from alpha_divs
+        alpha_divs = alpha_divs.saturating_sub(alpha_take);
+        // Give the validator their take, as stake or to its emission recipient.
+        log::debug!("hotkey: {hotkey:?} alpha_take: {alpha_take:?}");
+        Self::pay_hotkey_owner_emission(&hotkey, netuid, tou64!(alpha_take).into());
+        // Give the lockers the dividends earned by their boost, see `staking::stake_lock`.
+        log::debug!("hotkey: {hotkey:?} alpha_divs: {alpha_divs:?}");
+        let pool_divs = Self::pay_lock_boost_dividends(&hotkey, netuid, tou64!(alpha_divs).into());
+        // Give all other nominators, paying out those that do not auto-compound.
+        let opt_outs = Self::get_auto_compound_opt_out_shares(&hotkey, netuid, pool_divs);
+        Self::increase_stake_for_hotkey_on_subnet(&hotkey, netuid, pool_divs);
+        Self::pay_out_auto_compound_opt_outs(&hotkey, netuid, opt_outs);
+        // Record dividends for this hotkey.
+        AlphaDividendsPerSubnet::<T>::mutate(netuid, &hotkey, |divs| {
+            *divs = divs.saturating_add(tou64!(alpha_divs).into());
+        });
+        // Record total hotkey alpha based on which this value of AlphaDividendsPerSubnet
+        // was calculated
+        let total_hotkey_alpha = TotalHotkeyAlpha::<T>::get(&hotkey, netuid);
+        TotalHotkeyAlphaLastEpoch::<T>::insert(&hotkey, netuid, total_hotkey_alpha);
+        // Append to the trailing dividend history used for return-rate queries.
+        AlphaDividendsHistory::<T>::mutate(netuid, &hotkey, |history| {
+            history.push((tou64!(alpha_divs).into(), total_hotkey_alpha));
+            if history.len() > MAX_DIVIDEND_HISTORY_EPOCHS {
+                history.remove(0);
+            }
+        });
+    }
+
+    /// Pays out the root TAO dividends of a validator hotkey for an epoch, taking its take.
+    pub fn distribute_tao_dividends(netuid: NetUid, hotkey: T::AccountId, mut root_tao: U96F32) {
+        // Get take prop
+        let tao_take: U96F32 = Self::get_hotkey_take_float(&hotkey).saturating_mul(root_tao);
+        // Remove take prop from root_tao
+        root_tao = root_tao.saturating_sub(tao_take);
+        // Give the validator their take.
+        log::debug!("hotkey: {hotkey:?} tao_take: {tao_take:?}");
+        let validator_stake = Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &Owner::<T>::get(hotkey.clone()),
+            NetUid::ROOT,
+            tou64!(tao_take).into(),
+        );
+        // Give rest to nominators, paying out those that do not auto-compound.
+        log::debug!("hotkey: {hotkey:?} root_tao: {root_tao:?}");
+        let opt_outs =
+            Self::get_auto_compound_opt_out_shares(&hotkey, NetUid::ROOT, tou64!(root_tao).into());
+        Self::increase_stake_for_hotkey_on_subnet(&hotkey, NetUid::ROOT, tou64!(root_tao).into());
+        // Record root dividends for this validator on this subnet.
+        TaoDividendsPerSubnet::<T>::mutate(netuid, hotkey.clone(), |divs| {
+            *divs = divs.saturating_add(tou64!(root_tao).into());
+        });
+        // Update the total TAO on the subnet with root tao dividends.
+        SubnetTAO::<T>::mutate(NetUid::ROOT, |total| {
+            *total = total
+                .saturating_add(validator_stake.to_u64().into())
+                .saturating_add(tou64!(root_tao).into());
+        });
+        Self::pay_out_auto_compound_opt_outs(&hotkey, NetUid::ROOT, opt_outs);
     }
 
     pub fn get_stake_map(
@@ -593,13 +684,45 @@ impl<T: Config> Pallet<T> {
             "Draining pending alpha emission for netuid {netuid:?}, pending_alpha: {pending_alpha:?}, pending_tao: {pending_tao:?}, pending_swapped: {pending_swapped:?}, owner_cut: {owner_cut:?}"
         );
 
-        let tao_weight = Self::get_tao_weight();
-
         // Run the epoch.
         let hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)> =
             Self::epoch(netuid, pending_alpha.saturating_add(pending_swapped));
         log::debug!("hotkey_emission: {hotkey_emission:?}");
 
+        let (incentives, (alpha_dividends, tao_dividends)) = Self::calculate_epoch_distribution(
+            netuid,
+            pending_alpha,
+            pending_tao,
+            pending_swapped,
+            hotkey_emission,
+        );
+
+        Self::distribute_dividends_and_incentives(
+            netuid,
+            owner_cut,
+            incentives,
+            alpha_dividends,
+            tao_dividends,
+        );
+    }
+
+    /// Splits the pending emission of an epoch into miner incentives and validator dividends,
+    /// given the emission of each hotkey computed by the epoch.
+    pub fn calculate_epoch_distribution(
+        netuid: NetUid,
+        pending_alpha: AlphaCurrency,
+        pending_tao: TaoCurrency,
+        pending_swapped: AlphaCurrency,
+        hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)>,
+    ) -> (
+        BTreeMap<T::AccountId, AlphaCurrency>,
+        (
+            BTreeMap<T::AccountId, U96F32>,
+            BTreeMap<T::AccountId, U96F32>,
+        ),
+    ) {
+        let tao_weight = Self::get_tao_weight();
+
         // Compute the pending validator alpha.
         // This is the total alpha being injected,
         // minus the the alpha for the miners, (50%)
@@ -612,7 +735,25 @@ impl<T: Config> Pallet<T> {
             });
         log::debug!("incentive_sum: {incentive_sum:?}");
 
-        let pending_validator_alpha = if !incentive_sum.is_zero() {
+        let pending_validator_alpha =
+            Self::get_pending_validator_alpha(pending_alpha, pending_swapped, incentive_sum);
+
+        Self::calculate_dividend_and_incentive_distribution(
+            netuid,
+            pending_tao,
+            pending_validator_alpha,
+            hotkey_emission,
+            tao_weight,
+        )
+    }
+
+    /// The alpha emission of an epoch paid to the validators, given the sum of the incentives.
+    pub fn get_pending_validator_alpha(
+        pending_alpha: AlphaCurrency,
+        pending_swapped: AlphaCurrency,
+        incentive_sum: AlphaCurrency,
+    ) -> AlphaCurrency {
+        if !incentive_sum.is_zero() {
             pending_alpha
                 .saturating_add(pending_swapped)
                 .saturating_div(2.into())
@@ -620,24 +761,7 @@ impl<T: Config> Pallet<T> {
         } else {
             // If the incentive is 0, then Validators get 100% of the alpha.
             pending_alpha
-        };
-
-        let (incentives, (alpha_dividends, tao_dividends)) =
-            Self::calculate_dividend_and_incentive_distribution(
-                netuid,
-                pending_tao,
-                pending_validator_alpha,
-                hotkey_emission,
-                tao_weight,
-            );
-
-        Self::distribute_dividends_and_incentives(
-            netuid,
-            owner_cut,
-            incentives,
-            alpha_dividends,
-            tao_dividends,
-        );
+        }
     }
 
     /// Returns the self contribution of a hotkey on a subnet.
@@ -0,0 +1,85 @@
+use super::*;
+use codec::{Decode, Encode};
+use sp_std::vec::Vec;
+use subtensor_runtime_common::NetUid;
+
+/// Prefix of the offchain index entries holding the epoch history.
+pub const EPOCH_HISTORY_PREFIX: &[u8] = b"subtensor::epoch_history";
+
+/// Weights and bonds rows of every uid of a subnet when its epoch ran.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochMatrices {
+    pub weights: Vec<Vec<(u16, u16)>>,
+    pub bonds: Vec<Vec<(u16, u16)>>,
+}
+
+/// Summary of the [`EpochMatrices`] of an epoch, kept when the matrices are pruned.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochMatricesSummary {
+    pub num_uids: u16,
+    pub weight_rows: u16,
+    pub weight_entries: u32,
+    pub bond_entries: u32,
+}
+
+/// Offchain index entries of the epoch history.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum EpochHistoryKey {
+    /// Subnets whose epoch ran at a block.
+    Epochs(u64),
+    /// [`EpochMatrices`] of a subnet at a block.
+    Matrices(NetUid, u64),
+    /// [`EpochMatricesSummary`] of a subnet at a block.
+    Summary(NetUid, u64),
+}
+
+impl EpochHistoryKey {
+    pub fn key(&self) -> Vec<u8> {
+        (EPOCH_HISTORY_PREFIX, self).encode()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Writes the weights and bonds matrices the epoch of `netuid` starts from to the offchain
+    /// index, for nodes keeping the epoch history.
+    ///
+    /// The index only lives in the node's offchain database, so this leaves the state untouched
+    /// and does nothing unless the node enables offchain indexing.
+    pub fn index_epoch_history(netuid: NetUid, block: u64) {
+        let n = Self::get_subnetwork_n(netuid);
+        let matrices = EpochMatrices {
+            weights: (0..n).map(|uid| Weights::<T>::get(netuid, uid)).collect(),
+            bonds: (0..n).map(|uid| Bonds::<T>::get(netuid, uid)).collect(),
+        };
+        let entries = |rows: &Vec<Vec<(u16, u16)>>| {
+            rows.iter()
+                .fold(0u32, |acc, row| acc.saturating_add(row.len() as u32))
+        };
+        let summary = EpochMatricesSummary {
+            num_uids: n,
+            weight_rows: matrices
+                .weights
+                .iter()
+                .filter(|row| !row.is_empty())
+                .count() as u16,
+            weight_entries: entries(&matrices.weights),
+            bond_entries: entries(&matrices.bonds),
+        };
+
+        sp_io::offchain_index::set(
+            &EpochHistoryKey::Matrices(netuid, block).key(),
+            &matrices.encode(),
+        );
+        sp_io::offchain_index::set(
+            &EpochHistoryKey::Summary(netuid, block).key(),
+            &summary.encode(),
+        );
+    }
+
+    /// Records the subnets whose epoch ran at `block` in the offchain index.
+    pub fn index_epochs(block: u64, netuids: &[NetUid]) {
+        if !netuids.is_empty() {
+            sp_io::offchain_index::set(&EpochHistoryKey::Epochs(block).key(), &netuids.encode());
+        }
+    }
+}
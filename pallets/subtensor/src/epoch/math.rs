@@ -37,12 +37,12 @@ pub fn fixed64_to_u64(x: I64F64) -> u64 {
 
 #[allow(dead_code)]
 pub fn fixed64_to_fixed32(x: I64F64) -> I32F32 {
-    I32F32::saturating_from_num(x)
+    x.saturating_cast()
 }
 
 #[allow(dead_code)]
 pub fn fixed32_to_fixed64(x: I32F32) -> I64F64 {
-    I64F64::saturating_from_num(x)
+    x.saturating_cast()
 }
 
 #[allow(dead_code)]
@@ -52,7 +52,7 @@ pub fn u16_to_fixed(x: u16) -> I32F32 {
 
 #[allow(dead_code)]
 pub fn u16_proportion_to_fixed(x: u16) -> I32F32 {
-    I32F32::saturating_from_num(x).safe_div(I32F32::saturating_from_num(u16::MAX))
+    I32F32::from_u16_proportion(x)
 }
 
 #[allow(dead_code)]
@@ -62,7 +62,7 @@ pub fn fixed_to_fixed_u16_proportion(x: I32F32) -> I32F32 {
 
 #[allow(dead_code)]
 pub fn fixed_proportion_to_u16(x: I32F32) -> u16 {
-    fixed_to_u16(x.saturating_mul(I32F32::saturating_from_num(u16::MAX)))
+    x.to_u16_proportion()
 }
 
 #[allow(dead_code)]
@@ -901,16 +901,26 @@ pub fn col_clip_sparse(
     sparse_matrix: &[Vec<(u16, I32F32)>],
     col_threshold: &[I32F32],
 ) -> Vec<Vec<(u16, I32F32)>> {
-    let mut result: Vec<Vec<(u16, I32F32)>> = vec![vec![]; sparse_matrix.len()];
-    for (i, sparse_row) in sparse_matrix.iter().enumerate() {
-        for (j, value) in sparse_row.iter() {
-            if col_threshold[*j as usize] < *value {
-                if 0 < col_threshold[*j as usize] {
-                    result[i].push((*j, col_threshold[*j as usize]));
-                }
-            } else {
-                result[i].push((*j, *value));
+    sparse_matrix
+        .iter()
+        .map(|sparse_row| col_clip_row_sparse(sparse_row, col_threshold))
+        .collect()
+}
+
+// Apply column-wise threshold to a sparse matrix row.
+#[allow(clippy::indexing_slicing)]
+pub fn col_clip_row_sparse(
+    sparse_row: &[(u16, I32F32)],
+    col_threshold: &[I32F32],
+) -> Vec<(u16, I32F32)> {
+    let mut result: Vec<(u16, I32F32)> = vec![];
+    for (j, value) in sparse_row.iter() {
+        if col_threshold[*j as usize] < *value {
+            if 0 < col_threshold[*j as usize] {
+                result.push((*j, col_threshold[*j as usize]));
             }
+        } else {
+            result.push((*j, *value));
         }
     }
     result
@@ -1189,35 +1199,48 @@ pub fn interpolate_sparse(
         return mat2.to_owned();
     }
     assert!(mat1.len() == mat2.len());
-    let rows = mat1.len();
+    mat1.iter()
+        .zip(mat2)
+        .map(|(row1, row2)| interpolate_row_sparse(row1, row2, columns, ratio))
+        .collect()
+}
+
+// Element-wise interpolation of two sparse matrix rows: Result = A + ratio * (B - A).
+// ratio has intended range [0, 1]
+// ratio=0: Result = A
+// ratio=1: Result = B
+pub fn interpolate_row_sparse(
+    row1: &[(u16, I32F32)],
+    row2: &[(u16, I32F32)],
+    columns: u16,
+    ratio: I32F32,
+) -> Vec<(u16, I32F32)> {
+    if ratio == I32F32::saturating_from_num(0) {
+        return row1.to_owned();
+    }
+    if ratio == I32F32::saturating_from_num(1) {
+        return row2.to_owned();
+    }
     let zero: I32F32 = I32F32::saturating_from_num(0);
-    let mut result: Vec<Vec<(u16, I32F32)>> = vec![vec![]; rows];
-    for i in 0..rows {
-        let mut row1: Vec<I32F32> = vec![zero; columns as usize];
-        if let Some(row) = mat1.get(i) {
-            for (j, value) in row {
-                if let Some(entry) = row1.get_mut(*j as usize) {
-                    *entry = *value;
-                }
-            }
+    let mut dense1: Vec<I32F32> = vec![zero; columns as usize];
+    for (j, value) in row1 {
+        if let Some(entry) = dense1.get_mut(*j as usize) {
+            *entry = *value;
         }
-        let mut row2: Vec<I32F32> = vec![zero; columns as usize];
-        if let Some(row) = mat2.get(i) {
-            for (j, value) in row {
-                if let Some(entry) = row2.get_mut(*j as usize) {
-                    *entry = *value;
-                }
-            }
+    }
+    let mut dense2: Vec<I32F32> = vec![zero; columns as usize];
+    for (j, value) in row2 {
+        if let Some(entry) = dense2.get_mut(*j as usize) {
+            *entry = *value;
         }
-        for j in 0..columns as usize {
-            let v1 = row1.get(j).unwrap_or(&zero);
-            let v2 = row2.get(j).unwrap_or(&zero);
-            let interp = v1.saturating_add(ratio.saturating_mul(v2.saturating_sub(*v1)));
-            if zero < interp {
-                if let Some(res) = result.get_mut(i) {
-                    res.push((j as u16, interp));
-                }
-            }
+    }
+    let mut result: Vec<(u16, I32F32)> = vec![];
+    for j in 0..columns as usize {
+        let v1 = dense1.get(j).unwrap_or(&zero);
+        let v2 = dense2.get(j).unwrap_or(&zero);
+        let interp = v1.saturating_add(ratio.saturating_mul(v2.saturating_sub(*v1)));
+        if zero < interp {
+            result.push((j as u16, interp));
         }
     }
     result
@@ -1370,25 +1393,39 @@ pub fn mat_ema_sparse(
 ) -> Vec<Vec<(u16, I32F32)>> {
     assert!(new.len() == old.len());
     let n = new.len(); // assume square matrix, rows=cols
+    new.iter()
+        .zip(old)
+        .map(|(new_row, old_row)| row_ema_sparse(new_row, old_row, alpha, n))
+        .collect()
+}
+
+// Return sparse matrix row exponential moving average: `alpha * a_ij + one_minus_alpha * b_ij`.
+// `alpha` is the EMA coefficient, how much to add of the new observation, typically small,
+// higher alpha discounts older observations faster. `columns` is the width of the matrix.
+pub fn row_ema_sparse(
+    new: &[(u16, I32F32)],
+    old: &[(u16, I32F32)],
+    alpha: I32F32,
+    columns: usize,
+) -> Vec<(u16, I32F32)> {
     let zero: I32F32 = I32F32::saturating_from_num(0.0);
     let one_minus_alpha: I32F32 = I32F32::saturating_from_num(1.0).saturating_sub(alpha);
-    let mut result: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
-    for i in 0..new.len() {
-        let mut row: Vec<I32F32> = vec![zero; n];
-        for (j, value) in new[i].iter() {
-            row[*j as usize] = row[*j as usize].saturating_add(alpha.saturating_mul(*value));
+    let mut row: Vec<I32F32> = vec![zero; columns];
+    for (j, value) in new.iter() {
+        if let Some(entry) = row.get_mut(*j as usize) {
+            *entry = entry.saturating_add(alpha.saturating_mul(*value));
         }
-        for (j, value) in old[i].iter() {
-            row[*j as usize] =
-                row[*j as usize].saturating_add(one_minus_alpha.saturating_mul(*value));
-        }
-        for (j, value) in row.iter().enumerate() {
-            if *value > zero {
-                result[i].push((j as u16, *value))
-            }
+    }
+    for (j, value) in old.iter() {
+        if let Some(entry) = row.get_mut(*j as usize) {
+            *entry = entry.saturating_add(one_minus_alpha.saturating_mul(*value));
         }
     }
-    result
+    row.iter()
+        .enumerate()
+        .filter(|(_, value)| **value > zero)
+        .map(|(j, value)| (j as u16, *value))
+        .collect()
 }
 
 /// Calculates the exponential moving average (EMA) for a sparse matrix using dynamic alpha values.
@@ -1,3 +1,4 @@
 use super::*;
+pub mod history;
 pub mod math;
 pub mod run_epoch;
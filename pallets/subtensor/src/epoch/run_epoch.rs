@@ -2,8 +2,8 @@ use super::*;
 use crate::epoch::math::*;
 use frame_support::IterableStorageDoubleMap;
 use safe_math::*;
-use sp_std::vec;
-use substrate_fixed::types::{I32F32, I64F64, I96F32};
+use sp_std::{collections::btree_map::BTreeMap, vec};
+use substrate_fixed::types::{I32F32, I64F64};
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
 impl<T: Config> Pallet<T> {
@@ -48,6 +48,10 @@ impl<T: Config> Pallet<T> {
         // Logical negation of inactive.
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
+        // Stale mask, validators whose weights are older than the maximum weight age.
+        let stale: Vec<bool> = Self::get_stale_weights(netuid, &last_update, current_block);
+        log::trace!("Stale: {:?}", &stale);
+
         // Block at registration vector (block when each neuron was most recently registered).
         let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
         log::trace!("Block at registration: {:?}", &block_at_registration);
@@ -87,8 +91,8 @@ impl<T: Config> Pallet<T> {
         let (total_stake, _alpha_stake, _tao_stake): (Vec<I64F64>, Vec<I64F64>, Vec<I64F64>) =
             Self::get_stake_weights_for_network(netuid);
 
-        // Get the minimum stake required.
-        let min_stake = Self::get_stake_threshold();
+        // Get the minimum stake required for a validator permit on this subnet.
+        let min_stake = Self::get_stake_threshold_for_subnet(netuid);
 
         // Set stake of validators that doesn't meet the staking threshold to 0 as filter.
         let mut filtered_stake: Vec<I64F64> = total_stake
@@ -135,6 +139,9 @@ impl<T: Config> Pallet<T> {
         // Remove inactive stake.
         inplace_mask_vector(&inactive, &mut active_stake);
 
+        // Remove stake of validators with stale weights.
+        inplace_mask_vector(&stale, &mut active_stake);
+
         // Remove non-validator stake.
         inplace_mask_vector(&validator_forbids, &mut active_stake);
 
@@ -157,6 +164,10 @@ impl<T: Config> Pallet<T> {
         inplace_mask_rows(&validator_forbids, &mut weights);
         log::trace!("W (permit): {:?}", &weights);
 
+        // Mask weights older than the maximum weight age.
+        inplace_mask_rows(&stale, &mut weights);
+        log::trace!("W (permit+stale): {:?}", &weights);
+
         // Remove self-weight by masking diagonal; keep owner_uid self-weight.
         if let Some(owner_uid) = owner_uid {
             inplace_mask_diag_except_index(&mut weights, owner_uid);
@@ -224,6 +235,9 @@ impl<T: Config> Pallet<T> {
             // Access network bonds.
             let mut bonds: Vec<Vec<I32F32>> = Self::get_bonds_fixed_proportion(netuid);
             inplace_mask_cols(&recently_registered, &mut bonds); // mask outdated bonds
+            if Self::get_bonds_reset_on_deregistration(netuid) {
+                inplace_mask_rows(&inactive, &mut bonds); // reset bonds of dormant validators
+            }
             log::trace!("B: {:?}", &bonds);
 
             // Compute the Exponential Moving Average (EMA) of bonds.
@@ -252,6 +266,9 @@ impl<T: Config> Pallet<T> {
             let mut bonds: Vec<Vec<I32F32>> = Self::get_bonds(netuid);
             // Remove bonds referring to neurons that have registered since last tempo.
             inplace_mask_cols(&recently_registered, &mut bonds); // mask recently registered bonds
+            if Self::get_bonds_reset_on_deregistration(netuid) {
+                inplace_mask_rows(&inactive, &mut bonds); // reset bonds of dormant validators
+            }
             inplace_col_normalize(&mut bonds); // sum_i b_ij = 1
             log::trace!("B: {:?}", &bonds);
 
@@ -310,35 +327,23 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // Compute rao based emission scores. range: I96F32(0, rao_emission)
-        let float_rao_emission: I96F32 = I96F32::saturating_from_num(rao_emission);
+        // Compute rao based emission scores. range: [0, rao_emission]
+        let rao_emission: u64 = rao_emission.into();
 
-        let server_emission: Vec<I96F32> = normalized_server_emission
+        let server_emission: Vec<AlphaCurrency> = normalized_server_emission
             .iter()
-            .map(|se: &I32F32| I96F32::saturating_from_num(*se).saturating_mul(float_rao_emission))
-            .collect();
-        let server_emission: Vec<AlphaCurrency> = server_emission
-            .iter()
-            .map(|e: &I96F32| e.saturating_to_num::<u64>().into())
+            .map(|se: &I32F32| se.saturating_scale(rao_emission).into())
             .collect();
 
-        let validator_emission: Vec<I96F32> = normalized_validator_emission
-            .iter()
-            .map(|ve: &I32F32| I96F32::saturating_from_num(*ve).saturating_mul(float_rao_emission))
-            .collect();
-        let validator_emission: Vec<AlphaCurrency> = validator_emission
+        let validator_emission: Vec<AlphaCurrency> = normalized_validator_emission
             .iter()
-            .map(|e: &I96F32| e.saturating_to_num::<u64>().into())
+            .map(|ve: &I32F32| ve.saturating_scale(rao_emission).into())
             .collect();
 
-        // Used only to track combined emission in the storage.
-        let combined_emission: Vec<I96F32> = normalized_combined_emission
-            .iter()
-            .map(|ce: &I32F32| I96F32::saturating_from_num(*ce).saturating_mul(float_rao_emission))
-            .collect();
-        let combined_emission: Vec<AlphaCurrency> = combined_emission
+        // Only used to track emission in storage.
+        let combined_emission: Vec<AlphaCurrency> = normalized_combined_emission
             .iter()
-            .map(|e: &I96F32| AlphaCurrency::from(e.saturating_to_num::<u64>()))
+            .map(|ce: &I32F32| ce.saturating_scale(rao_emission).into())
             .collect();
 
         log::trace!("nSE: {:?}", &normalized_server_emission);
@@ -480,6 +485,10 @@ impl<T: Config> Pallet<T> {
         // Logical negation of inactive.
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
+        // Stale mask, validators whose weights are older than the maximum weight age.
+        let stale: Vec<bool> = Self::get_stale_weights(netuid, &last_update, current_block);
+        log::trace!("Stale: {:?}", &stale);
+
         // Block at registration vector (block when each neuron was most recently registered).
         let block_at_registration: Vec<u64> = Self::get_block_at_registration(netuid);
         log::trace!("Block at registration: {:?}", &block_at_registration);
@@ -497,8 +506,8 @@ impl<T: Config> Pallet<T> {
         let (total_stake, _alpha_stake, _tao_stake): (Vec<I64F64>, Vec<I64F64>, Vec<I64F64>) =
             Self::get_stake_weights_for_network(netuid);
 
-        // Get the minimum stake required.
-        let min_stake = Self::get_stake_threshold();
+        // Get the minimum stake required for a validator permit on this subnet.
+        let min_stake = Self::get_stake_threshold_for_subnet(netuid);
 
         // Set stake of validators that doesn't meet the staking threshold to 0 as filter.
         let mut filtered_stake: Vec<I64F64> = total_stake
@@ -545,6 +554,9 @@ impl<T: Config> Pallet<T> {
         // Remove inactive stake.
         inplace_mask_vector(&inactive, &mut active_stake);
 
+        // Remove stake of validators with stale weights.
+        inplace_mask_vector(&stale, &mut active_stake);
+
         // Remove non-validator stake.
         inplace_mask_vector(&validator_forbids, &mut active_stake);
 
@@ -558,13 +570,19 @@ impl<T: Config> Pallet<T> {
 
         let owner_uid: Option<u16> = Self::get_owner_uid(netuid);
 
-        // Access network weights row unnormalized.
-        let mut weights: Vec<Vec<(u16, I32F32)>> = Self::get_weights_sparse(netuid);
-        log::trace!("Weights: {:?}", &weights);
+        // Only permitted validators with fresh weights keep their weights, so only their storage
+        // rows are read.
+        let weight_rows: Vec<bool> = validator_permits
+            .iter()
+            .zip(stale.iter())
+            .map(|(permit, stale)| *permit && !*stale)
+            .collect();
 
-        // Mask weights that are not from permitted validators.
-        weights = mask_rows_sparse(&validator_forbids, &weights);
-        log::trace!("Weights (permit): {:?}", &weights);
+        // Access network weights row unnormalized, masking weights that are not from permitted
+        // validators or older than the maximum weight age.
+        let mut weights: Vec<Vec<(u16, I32F32)>> =
+            Self::get_weights_sparse_of_rows(netuid, &weight_rows);
+        log::trace!("Weights (permit+stale): {:?}", &weights);
 
         // Remove self-weight by masking diagonal; keep owner_uid self-weight.
         if let Some(owner_uid) = owner_uid {
@@ -587,12 +605,11 @@ impl<T: Config> Pallet<T> {
             let mut commit_blocks: Vec<u64> = vec![u64::MAX; n as usize]; // MAX ⇒ “no active commit”
 
             // helper: hotkey → uid
-            let uid_of = |acct: &T::AccountId| -> Option<usize> {
-                hotkeys
-                    .iter()
-                    .find(|(_, a)| a == acct)
-                    .map(|(uid, _)| *uid as usize)
-            };
+            let uids: BTreeMap<&T::AccountId, usize> = hotkeys
+                .iter()
+                .map(|(uid, hotkey)| (hotkey, *uid as usize))
+                .collect();
+            let uid_of = |acct: &T::AccountId| -> Option<usize> { uids.get(acct).copied() };
 
             // ---------- v2 ------------------------------------------------------
             for (who, q) in WeightCommits::<T>::iter_prefix(netuid) {
@@ -688,7 +705,12 @@ impl<T: Config> Pallet<T> {
         let mut ema_bonds: Vec<Vec<(u16, I32F32)>>;
         if Yuma3On::<T>::get(netuid) {
             // Access network bonds.
-            let mut bonds = Self::get_bonds_sparse_fixed_proportion(netuid);
+            let mut bonds = Self::get_bonds_sparse_of_rows(netuid, &validator_permits);
+            bonds.iter_mut().for_each(|bonds_row| {
+                bonds_row
+                    .iter_mut()
+                    .for_each(|(_, bond)| *bond = fixed_to_fixed_u16_proportion(*bond));
+            });
             log::trace!("Bonds: {:?}", &bonds);
 
             // Remove bonds referring to neurons that have registered since last tempo.
@@ -701,6 +723,10 @@ impl<T: Config> Pallet<T> {
                 &block_at_registration,
                 &|last_tempo, registered| last_tempo <= registered,
             );
+            // Reset the bonds of validators that went dormant, as if they had deregistered.
+            if Self::get_bonds_reset_on_deregistration(netuid) {
+                bonds = mask_rows_sparse(&inactive, &bonds);
+            }
             log::trace!("Bonds: (mask) {:?}", &bonds);
 
             // Compute the Exponential Moving Average (EMA) of bonds.
@@ -727,7 +753,8 @@ impl<T: Config> Pallet<T> {
         } else {
             // original Yuma - liquid alpha disabled
             // Access network bonds.
-            let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse(netuid);
+            let mut bonds: Vec<Vec<(u16, I32F32)>> =
+                Self::get_bonds_sparse_of_rows(netuid, &validator_permits);
             log::trace!("B: {:?}", &bonds);
 
             // Remove bonds referring to neurons that have registered since last tempo.
@@ -740,6 +767,10 @@ impl<T: Config> Pallet<T> {
                 &block_at_registration,
                 &|last_tempo, registered| last_tempo <= registered,
             );
+            // Reset the bonds of validators that went dormant, as if they had deregistered.
+            if Self::get_bonds_reset_on_deregistration(netuid) {
+                bonds = mask_rows_sparse(&inactive, &bonds);
+            }
             log::trace!("B (outdatedmask): {:?}", &bonds);
 
             // Normalize remaining bonds: sum_i b_ij = 1.
@@ -804,35 +835,23 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        // Compute rao based emission scores. range: I96F32(0, rao_emission)
-        let float_rao_emission: I96F32 = I96F32::saturating_from_num(rao_emission);
+        // Compute rao based emission scores. range: [0, rao_emission]
+        let rao_emission: u64 = rao_emission.into();
 
-        let server_emission: Vec<I96F32> = normalized_server_emission
+        let server_emission: Vec<AlphaCurrency> = normalized_server_emission
             .iter()
-            .map(|se: &I32F32| I96F32::saturating_from_num(*se).saturating_mul(float_rao_emission))
-            .collect();
-        let server_emission: Vec<AlphaCurrency> = server_emission
-            .iter()
-            .map(|e: &I96F32| e.saturating_to_num::<u64>().into())
+            .map(|se: &I32F32| se.saturating_scale(rao_emission).into())
             .collect();
 
-        let validator_emission: Vec<I96F32> = normalized_validator_emission
+        let validator_emission: Vec<AlphaCurrency> = normalized_validator_emission
             .iter()
-            .map(|ve: &I32F32| I96F32::saturating_from_num(*ve).saturating_mul(float_rao_emission))
-            .collect();
-        let validator_emission: Vec<AlphaCurrency> = validator_emission
-            .iter()
-            .map(|e: &I96F32| e.saturating_to_num::<u64>().into())
+            .map(|ve: &I32F32| ve.saturating_scale(rao_emission).into())
             .collect();
 
         // Only used to track emission in storage.
-        let combined_emission: Vec<I96F32> = normalized_combined_emission
-            .iter()
-            .map(|ce: &I32F32| I96F32::saturating_from_num(*ce).saturating_mul(float_rao_emission))
-            .collect();
-        let combined_emission: Vec<AlphaCurrency> = combined_emission
+        let combined_emission: Vec<AlphaCurrency> = normalized_combined_emission
             .iter()
-            .map(|e: &I96F32| AlphaCurrency::from(e.saturating_to_num::<u64>()))
+            .map(|ce: &I32F32| ce.saturating_scale(rao_emission).into())
             .collect();
 
         log::trace!(
@@ -937,14 +956,14 @@ impl<T: Config> Pallet<T> {
         I32F32::saturating_from_num(Self::get_rho(netuid))
     }
     pub fn get_float_kappa(netuid: NetUid) -> I32F32 {
-        I32F32::saturating_from_num(Self::get_kappa(netuid))
-            .safe_div(I32F32::saturating_from_num(u16::MAX))
+        I32F32::from_u16_proportion(Self::get_kappa(netuid))
     }
     pub fn get_float_bonds_penalty(netuid: NetUid) -> I32F32 {
-        I32F32::saturating_from_num(Self::get_bonds_penalty(netuid))
-            .safe_div(I32F32::saturating_from_num(u16::MAX))
+        I32F32::from_u16_proportion(Self::get_bonds_penalty(netuid))
     }
 
+    /// Block each uid was last registered at. A vacant uid counts as registered at `u64::MAX`, so
+    /// the weights and bonds still pointing to it are masked as outdated.
     pub fn get_block_at_registration(netuid: NetUid) -> Vec<u64> {
         let n = Self::get_subnetwork_n(netuid);
         let block_at_registration: Vec<u64> = (0..n)
@@ -952,7 +971,7 @@ impl<T: Config> Pallet<T> {
                 if Keys::<T>::contains_key(netuid, neuron_uid) {
                     Self::get_neuron_block_at_registration(netuid, neuron_uid)
                 } else {
-                    0
+                    u64::MAX
                 }
             })
             .collect();
@@ -973,12 +992,30 @@ impl<T: Config> Pallet<T> {
                 weights
                     .get_mut(uid_i as usize)
                     .expect("uid_i is filtered to be less than n; qed")
-                    .push((*uid_j, I32F32::saturating_from_num(*weight_ij)));
+                    .push((*uid_j, u16_to_fixed(*weight_ij)));
             }
         }
         weights
     }
 
+    /// Output unnormalized sparse weights of the rows selected by `rows`, other rows are empty.
+    /// Only the storage rows of the selected uids are read, the result still has a row per uid.
+    pub fn get_weights_sparse_of_rows(netuid: NetUid, rows: &[bool]) -> Vec<Vec<(u16, I32F32)>> {
+        let n = Self::get_subnetwork_n(netuid) as usize;
+        let mut weights: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
+        for (uid_i, weights_i) in weights.iter_mut().enumerate() {
+            if !rows.get(uid_i).copied().unwrap_or(false) {
+                continue;
+            }
+            *weights_i = Weights::<T>::get(netuid, uid_i as u16)
+                .into_iter()
+                .filter(|(uid_j, _)| *uid_j < n as u16)
+                .map(|(uid_j, weight_ij)| (uid_j, u16_to_fixed(weight_ij)))
+                .collect();
+        }
+        weights
+    }
+
     /// Output unnormalized weights in [n, n] matrix, input weights are assumed to be row max-upscaled in u16.
     pub fn get_weights(netuid: NetUid) -> Vec<Vec<I32F32>> {
         let n = Self::get_subnetwork_n(netuid) as usize;
@@ -1024,6 +1061,24 @@ impl<T: Config> Pallet<T> {
         bonds
     }
 
+    /// Output unnormalized sparse bonds of the rows selected by `rows`, other rows are empty.
+    /// Bonds are only kept for uids holding a validator permit, so selecting the permitted uids
+    /// returns all bonds while only reading the storage rows of the validators.
+    pub fn get_bonds_sparse_of_rows(netuid: NetUid, rows: &[bool]) -> Vec<Vec<(u16, I32F32)>> {
+        let n = Self::get_subnetwork_n(netuid) as usize;
+        let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
+        for (uid_i, bonds_i) in bonds.iter_mut().enumerate() {
+            if !rows.get(uid_i).copied().unwrap_or(false) {
+                continue;
+            }
+            *bonds_i = Bonds::<T>::get(netuid, uid_i as u16)
+                .into_iter()
+                .map(|(uid_j, bonds_ij)| (uid_j, u16_to_fixed(bonds_ij)))
+                .collect();
+        }
+        bonds
+    }
+
     /// Output unnormalized bonds in [n, n] matrix, input bonds are assumed to be column max-upscaled in u16.
     pub fn get_bonds(netuid: NetUid) -> Vec<Vec<I32F32>> {
         let n: usize = Self::get_subnetwork_n(netuid) as usize;
@@ -1080,15 +1135,8 @@ impl<T: Config> Pallet<T> {
         bonds: &[Vec<(u16, I32F32)>],
         netuid: NetUid,
     ) -> Vec<Vec<(u16, I32F32)>> {
-        // Retrieve the bonds moving average for the given network ID and scale it down.
-        let bonds_moving_average: I64F64 =
-            I64F64::saturating_from_num(Self::get_bonds_moving_average(netuid))
-                .safe_div(I64F64::saturating_from_num(1_000_000));
-
         // Calculate the alpha value for the EMA calculation.
-        // Alpha is derived by subtracting the scaled bonds moving average from 1.
-        let alpha: I32F32 = I32F32::saturating_from_num(1)
-            .saturating_sub(I32F32::saturating_from_num(bonds_moving_average));
+        let alpha: I32F32 = Self::get_float_bonds_ema_alpha(netuid);
 
         // Compute the Exponential Moving Average (EMA) of bonds using the calculated alpha value.
         let ema_bonds = mat_ema_sparse(bonds_delta, bonds, alpha);
@@ -1100,6 +1148,18 @@ impl<T: Config> Pallet<T> {
         ema_bonds
     }
 
+    /// The alpha of the Exponential Moving Average (EMA) of bonds with a normal alpha value.
+    pub fn get_float_bonds_ema_alpha(netuid: NetUid) -> I32F32 {
+        // Retrieve the bonds moving average for the given network ID and scale it down.
+        let bonds_moving_average: I64F64 =
+            I64F64::saturating_from_num(Self::get_bonds_moving_average(netuid))
+                .safe_div(I64F64::saturating_from_num(1_000_000));
+
+        // Alpha is derived by subtracting the scaled bonds moving average from 1.
+        I32F32::saturating_from_num(1)
+            .saturating_sub(I32F32::saturating_from_num(bonds_moving_average))
+    }
+
     /// Compute the Exponential Moving Average (EMA) of bonds using a normal alpha value.
     ///
     /// # Args:
@@ -1214,6 +1274,42 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Compute the Exponential Moving Average (EMA) of a single row of bonds, like
+    /// `compute_bonds_sparse` does for every row.
+    ///
+    /// # Args:
+    /// * `netuid` - The network ID.
+    /// * `weights` - The row of weights.
+    /// * `bonds` - The row of bonds.
+    /// * `consensus` - A vector of consensus values, one per column.
+    ///
+    /// # Returns:
+    /// The row of EMA bonds.
+    pub fn compute_bonds_row_sparse(
+        netuid: NetUid,
+        weights: &[(u16, I32F32)],
+        bonds: &[(u16, I32F32)],
+        consensus: &[I32F32],
+    ) -> Vec<(u16, I32F32)> {
+        if LiquidAlphaOn::<T>::get(netuid)
+            && !consensus.is_empty()
+            && consensus
+                .iter()
+                .any(|&c| c != I32F32::saturating_from_num(0))
+        {
+            let weights = [weights.to_vec()];
+            let bonds = [bonds.to_vec()];
+            let alphas: Vec<Vec<I32F32>> =
+                Self::compute_liquid_alpha_values_sparse(netuid, &weights, &bonds, consensus);
+            mat_ema_alpha_sparse(&weights, &bonds, &alphas)
+                .pop()
+                .unwrap_or_default()
+        } else {
+            let alpha: I32F32 = Self::compute_disabled_liquid_alpha(netuid);
+            row_ema_sparse(weights, bonds, alpha, consensus.len())
+        }
+    }
+
     /// Compute liquid alphas matrix
     /// There is a separate alpha param for each validator-miner binding
     ///
@@ -1392,12 +1488,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::LiquidAlphaDisabled
         );
 
-        let max_u16: u32 = u16::MAX as u32; // 65535
-        let min_alpha_low: u16 = (max_u16.safe_div(40)) as u16; // 1638
+        // Bounds set by root, 1638 (1/40) and u16::MAX by default.
+        let min_alpha_low: u16 = MinLiquidAlphaLow::<T>::get();
         let min_alpha_high: u16 = min_alpha_low;
+        let max_alpha_high: u16 = MaxLiquidAlphaHigh::<T>::get();
 
-        // --- 4. Ensure alpha high is greater than the minimum
+        // --- 4. Ensure alpha high is within range
         ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
+        ensure!(alpha_high <= max_alpha_high, Error::<T>::AlphaHighTooHigh);
 
         // -- 5. Ensure alpha low is within range
         ensure!(
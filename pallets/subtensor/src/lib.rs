@@ -13,7 +13,7 @@ use frame_support::{
     ensure,
     pallet_macros::import_section,
     pallet_prelude::*,
-    traits::{IsSubType, tokens::fungible},
+    traits::{GetCallIndex, IsSubType, tokens::fungible},
 };
 
 use codec::{Decode, DecodeWithMemTracking, Encode};
@@ -62,6 +62,33 @@ extern crate alloc;
 
 pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 
+/// Number of epochs of per-hotkey dividend history kept for return-rate queries.
+pub const MAX_DIVIDEND_HISTORY_EPOCHS: usize = 30;
+
+/// Maximum number of blocks covered by a network lock cost projection.
+pub const MAX_LOCK_COST_PROJECTION_BLOCKS: u64 = 7200;
+
+/// Maximum number of hotkeys a single add_stake_multiple call can split stake across.
+pub const MAX_STAKE_SPLITS: usize = 32;
+
+/// Maximum number of open registration bids per subnet.
+pub const MAX_REGISTRATION_BIDS: usize = 256;
+
+/// Maximum number of pending unbonding chunks kept per coldkey.
+pub const MAX_UNBONDING_CHUNKS: usize = 32;
+
+/// Maximum unbonding period that can be set for a subnet, in blocks (~28 days).
+pub const MAX_UNBONDING_PERIOD: u64 = 201_600;
+
+/// Maximum number of pending EVM withdrawal chunks kept per coldkey.
+pub const MAX_EVM_WITHDRAWAL_CHUNKS: usize = 32;
+
+/// Maximum delay of withdrawals from the EVM, in blocks (~7 days).
+pub const MAX_EVM_WITHDRAWAL_DELAY: u64 = 50_400;
+
+/// Maximum miner emission vesting period that can be set for a subnet, in blocks (~7 days).
+pub const MAX_MINER_EMISSION_VESTING_PERIOD: u64 = 50_400;
+
 #[deny(missing_docs)]
 #[import_section(errors::errors)]
 #[import_section(events::events)]
@@ -72,8 +99,24 @@ pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 #[frame_support::pallet]
 pub mod pallet {
     use crate::RateLimitKey;
+    use crate::coinbase::emission_vesting::VestingEmission;
+    use crate::coinbase::incentive_matching::IncentiveMatchingSchedule;
+    use crate::coinbase::multi_block_epoch::{
+        MultiBlockEpoch, MultiBlockEpochNeuronOf, MultiBlockEpochUid, MultiBlockEpochVector,
+    };
     use crate::migrations;
+    use crate::subnets::dissolution::NetworkDissolution;
+    use crate::subnets::evm_gas_sponsorship::EvmGasSponsorship;
+    use crate::subnets::governance::{SubnetProposalId, SubnetProposalOf};
     use crate::subnets::leasing::{LeaseId, SubnetLeaseOf};
+    use crate::subnets::sale::SubnetOfferOf;
+    use crate::staking::stake_lock::StakeLock;
+    use crate::subnets::slashing::{WeightOffenseEvidenceOf, WeightOffenseKind};
+    use crate::subnets::sponsored_registration::SponsorConsent;
+    use crate::subnets::treasury::SubnetTreasurySpendOf;
+    use crate::subnets::weights_multisig::HotkeyMultisigOf;
+    use crate::swap::coldkey_recovery::{ColdkeyRecoveryConfigOf, ColdkeyRecoveryOf};
+    use crate::utils::ledger::{LedgerAccount, LedgerReason};
     use frame_support::Twox64Concat;
     use frame_support::{
         BoundedVec,
@@ -86,11 +129,12 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use pallet_drand::types::RoundNumber;
     use sp_core::{ConstU32, H160, H256};
+    use sp_runtime::Perbill;
     use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
     use sp_std::collections::vec_deque::VecDeque;
     use sp_std::vec;
     use sp_std::vec::Vec;
-    use substrate_fixed::types::{I96F32, U64F64};
+    use substrate_fixed::types::{I32F32, I64F64, I96F32, U64F64, U96F32};
     use subtensor_macros::freeze_struct;
     use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
 
@@ -127,6 +171,14 @@ pub mod pallet {
     /// local one
     pub type LocalCallOf<T> = <T as Config>::RuntimeCall;
 
+    /// Address of a task scheduled through the pallet's anonymous scheduler.
+    pub type SchedulerAddressOf<T> =
+        <<T as Config>::Scheduler as frame_support::traits::schedule::v3::Anon<
+            BlockNumberFor<T>,
+            LocalCallOf<T>,
+            PalletsOriginOf<T>,
+        >>::Address;
+
     /// Data structure for Axon information.
     #[crate::freeze_struct("3545cfb0cac4c1f5")]
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
@@ -179,6 +231,116 @@ pub mod pallet {
         }
     }
 
+    /// Axon endpoint ip type whose host is the DNS name published in the hotkey's commitment.
+    pub const AXON_IP_TYPE_COMMITMENT: u8 = 0;
+    /// Maximum number of endpoints served by an axon.
+    pub const MAX_AXON_ENDPOINTS: u32 = 8;
+
+    /// Data structure for a single network endpoint of an axon.
+    #[freeze_struct("ee6c28f6baf283ef")]
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Default, TypeInfo, Clone, PartialEq, Eq, Debug,
+    )]
+    pub struct AxonEndpoint {
+        ///  Endpoint u128 encoded ip address of type v6 or v4, zero for a commitment host.
+        pub ip: u128,
+        ///  Endpoint u16 encoded port.
+        pub port: u16,
+        ///  Endpoint ip type, 4 for ipv4, 6 for ipv6 and `AXON_IP_TYPE_COMMITMENT` for the
+        ///  DNS name in the hotkey's commitment.
+        pub ip_type: u8,
+        ///  Endpoint protocol. TCP, UDP, other.
+        pub protocol: u8,
+    }
+
+    impl From<&AxonInfo> for AxonEndpoint {
+        fn from(axon: &AxonInfo) -> Self {
+            Self {
+                ip: axon.ip,
+                port: axon.port,
+                ip_type: axon.ip_type,
+                protocol: axon.protocol,
+            }
+        }
+    }
+
+    /// Data structure for Axon information with several endpoints.
+    ///
+    /// The first endpoint is the primary one, mirrored into `Axons` for clients reading `AxonInfo`.
+    #[freeze_struct("63b232a726d0d4b3")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct AxonInfoV2 {
+        ///  Axon serving block.
+        pub block: u64,
+        ///  Axon version
+        pub version: u32,
+        ///  Version of the protocol spoken by the axon.
+        pub protocol_version: u32,
+        ///  Endpoints the axon is reachable at, primary first.
+        pub endpoints: BoundedVec<AxonEndpoint, ConstU32<MAX_AXON_ENDPOINTS>>,
+        ///  SHA-256 fingerprint of the TLS certificate presented by the axon.
+        pub tls_fingerprint: Option<[u8; 32]>,
+    }
+
+    impl From<AxonInfo> for AxonInfoV2 {
+        fn from(axon: AxonInfo) -> Self {
+            let mut endpoints = BoundedVec::new();
+            let _ = endpoints.try_push(AxonEndpoint::from(&axon));
+            Self {
+                block: axon.block,
+                version: axon.version,
+                protocol_version: 0,
+                endpoints,
+                tls_fingerprint: None,
+            }
+        }
+    }
+
+    /// How a delegate uses its `DelegateStakingList`.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, Copy, PartialEq, Eq, Debug,
+    )]
+    pub enum DelegateStakingAccess {
+        /// Only the listed coldkeys can stake to the delegate.
+        Allowlist,
+        /// Every coldkey but the listed ones can stake to the delegate.
+        Denylist,
+    }
+
+    /// How long `add_stake_locked` locks the alpha it buys.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, Copy, PartialEq, Eq, Debug,
+    )]
+    pub enum StakeLockPeriod {
+        /// Locked for 30 days.
+        Days30,
+        /// Locked for 90 days.
+        Days90,
+        /// Locked for 180 days.
+        Days180,
+    }
+
+    /// The hash a subnet requires for registration proof of work.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Debug,
+        Default,
+    )]
+    pub enum RegistrationPowAlgorithm {
+        /// Keccak-256 of SHA-256, cheap to compute on GPUs.
+        #[default]
+        Sha256Keccak,
+        /// Memory-hard Argon2id, resisting GPU registration farms.
+        Argon2id,
+    }
+
     ///  Struct for Prometheus.
     pub type PrometheusInfoOf = PrometheusInfo;
 
@@ -324,6 +486,16 @@ pub mod pallet {
         TaoCurrency::ZERO
     }
     #[pallet::type_value]
+    /// Default multiplier of the fee of registration calls.
+    pub fn DefaultRegistrationFeeMultiplier<T: Config>() -> U64F64 {
+        U64F64::saturating_from_num(1)
+    }
+    #[pallet::type_value]
+    /// Default share of the block weight registration calls are priced to fill.
+    pub fn DefaultTargetRegistrationBlockFullness<T: Config>() -> Perbill {
+        Perbill::from_percent(1)
+    }
+    #[pallet::type_value]
     /// Default value for zero.
     pub fn DefaultZeroU128<T: Config>() -> u128 {
         0
@@ -566,6 +738,11 @@ pub mod pallet {
         5 // 5 tempos
     }
     #[pallet::type_value]
+    /// Default number of blocks before an epoch in which validator weights get priority.
+    pub fn DefaultWeightsPriorityWindow<T: Config>() -> u64 {
+        10
+    }
+    #[pallet::type_value]
     /// Default value for pending emission.
     pub fn DefaultPendingEmission<T: Config>() -> AlphaCurrency {
         0.into()
@@ -647,6 +824,11 @@ pub mod pallet {
         T::InitialWeightsVersionKey::get()
     }
     #[pallet::type_value]
+    /// Default grace period of a new weights version key: it applies immediately.
+    pub fn DefaultWeightsVersionKeyGracePeriod<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::type_value]
     /// Default minimum allowed weights.
     pub fn DefaultMinAllowedWeights<T: Config>() -> u16 {
         T::InitialMinAllowedWeights::get()
@@ -702,6 +884,11 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default highest validator stake threshold a subnet owner may choose.
+    pub fn DefaultMaxSubnetStakeThreshold<T: Config>() -> u64 {
+        1_000_000_000_000_000
+    }
+    #[pallet::type_value]
     /// Default Reveal Period Epochs
     pub fn DefaultRevealPeriodEpochs<T: Config>() -> u64 {
         1
@@ -796,6 +983,21 @@ pub mod pallet {
         (45875, 58982)
     }
     #[pallet::type_value]
+    /// Default lowest liquid alpha low a subnet owner may choose, 1/40.
+    pub fn DefaultMinLiquidAlphaLow<T: Config>() -> u16 {
+        1638
+    }
+    #[pallet::type_value]
+    /// Default emission, in percent of the expected emission, that trips the circuit breaker.
+    pub fn DefaultEmissionCircuitBreakerThreshold<T: Config>() -> u32 {
+        200
+    }
+    #[pallet::type_value]
+    /// Default highest liquid alpha high a subnet owner may choose.
+    pub fn DefaultMaxLiquidAlphaHigh<T: Config>() -> u16 {
+        u16::MAX
+    }
+    #[pallet::type_value]
     /// Default value for coldkey swap schedule duration
     pub fn DefaultColdkeySwapScheduleDuration<T: Config>() -> BlockNumberFor<T> {
         T::InitialColdkeySwapScheduleDuration::get()
@@ -814,7 +1016,7 @@ pub mod pallet {
             return 15;
         }
 
-        7_200
+        subtensor_runtime_common::time::scale_blocks(7_200)
     }
 
     #[pallet::type_value]
@@ -853,6 +1055,18 @@ pub mod pallet {
         U64F64::saturating_from_num(0)
     }
 
+    #[pallet::type_value]
+    /// Default unbonding period, unstaked TAO is credited immediately.
+    pub fn DefaultUnbondingPeriod<T: Config>() -> u64 {
+        0
+    }
+
+    #[pallet::type_value]
+    /// Default delay of withdrawals from the EVM, one day.
+    pub fn DefaultEvmWithdrawalDelay<T: Config>() -> u64 {
+        7200
+    }
+
     #[pallet::type_value]
     /// Default value for minimum activity cutoff
     pub fn DefaultMinActivityCutoff<T: Config>() -> u16 {
@@ -873,6 +1087,64 @@ pub mod pallet {
         50400
     }
 
+    #[pallet::type_value]
+    /// Default delegate take notice period, increases apply immediately.
+    pub fn DefaultDelegateTakeNoticePeriod<T: Config>() -> u64 {
+        0
+    }
+
+    #[pallet::type_value]
+    /// Default share of a subnet's outstanding alpha that must approve a subnet proposal (50%).
+    pub fn DefaultSubnetProposalQuorum<T: Config>() -> u16 {
+        u16::MAX / 2
+    }
+    #[pallet::type_value]
+    /// Default number of blocks after which a subnet leaves maintenance (7 days).
+    pub fn DefaultMaxSubnetMaintenanceDuration<T: Config>() -> u64 {
+        50400
+    }
+    #[pallet::type_value]
+    /// Default share of the stake of an offending hotkey slashed on the subnet (10%).
+    pub fn DefaultOffenseSlashFraction<T: Config>() -> u16 {
+        6553
+    }
+    #[pallet::type_value]
+    /// Default number of blocks a subnet proposal accepts votes (7 days).
+    pub fn DefaultSubnetProposalDuration<T: Config>() -> u64 {
+        50400
+    }
+    #[pallet::type_value]
+    /// Default value for the per-subnet owner cut rate limit
+    pub fn DefaultSetSubnetOwnerCutRateLimit<T: Config>() -> u64 {
+        50400
+    }
+    #[pallet::type_value]
+    /// Default value for the rate limit on growing the max UIDs of a subnet
+    pub fn DefaultGrowMaxAllowedUidsRateLimit<T: Config>() -> u64 {
+        50400
+    }
+    #[pallet::type_value]
+    /// Number of storage items a subnet dissolution processes per block.
+    pub fn DefaultNetworkDissolutionChunkSize<T: Config>() -> u32 {
+        512
+    }
+    #[pallet::type_value]
+    /// Upper bound on the max UIDs a subnet can grow to.
+    pub fn DefaultMaxAllowedUidsLimit<T: Config>() -> u16 {
+        4096
+    }
+    #[pallet::type_value]
+    /// Largest number of UIDs a subnet can grow by in one call.
+    pub fn DefaultMaxAllowedUidsGrowth<T: Config>() -> u16 {
+        256
+    }
+
+    #[pallet::type_value]
+    /// Default minimum per-subnet owner cut.
+    pub fn DefaultMinSubnetOwnerCut<T: Config>() -> u16 {
+        0
+    }
+
     #[pallet::storage]
     pub type MinActivityCutoff<T: Config> =
         StorageValue<_, u16, ValueQuery, DefaultMinActivityCutoff<T>>;
@@ -946,6 +1218,18 @@ pub mod pallet {
     pub type Delegates<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDelegateTake<T>>;
     #[pallet::storage]
+    /// MAP ( hot ) --> (take, effective_block) | Take increase waiting out the notice period.
+    pub type PendingDelegateTake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u16, u64), OptionQuery>;
+    #[pallet::storage]
+    /// DMAP ( effective_block, hot ) --> take | Take increases by the block they apply at.
+    pub type PendingDelegateTakeQueue<T: Config> =
+        StorageDoubleMap<_, Identity, u64, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( delegate_take_notice_period ) | Blocks before a delegate take increase applies.
+    pub type DelegateTakeNoticePeriod<T> =
+        StorageValue<_, u64, ValueQuery, DefaultDelegateTakeNoticePeriod<T>>;
+    #[pallet::storage]
     /// DMAP ( hot, netuid ) --> take | Returns the hotkey childkey take for a specific subnet
     pub type ChildkeyTake<T: Config> = StorageDoubleMap<
         _,
@@ -1014,6 +1298,16 @@ pub mod pallet {
         ValueQuery,
         DefaultZeroTao<T>,
     >;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> Vec<(alpha_dividends, total_hotkey_alpha)> | Trailing per-epoch dividend history, oldest first.
+    pub type AlphaDividendsHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(AlphaCurrency, AlphaCurrency)>,
+        ValueQuery,
+    >;
 
     /// ==================
     /// ==== Coinbase ====
@@ -1089,6 +1383,25 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the vector of hotkeys controlled by this coldkey.
     pub type OwnedHotkeys<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( hot ) --> weights_key | The key authorized to set weights for the hotkey.
+    pub type WeightsKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage] // --- MAP ( weights_key ) --> hot | The hotkey a weights key sets weights for.
+    pub type WeightsKeyHotkey<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+    #[pallet::storage] // --- MAP ( hot ) --> multisig | The operators approving the weights of the hotkey.
+    pub type HotkeyMultisigs<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, HotkeyMultisigOf<T>, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( hot, proposal ) --> operators | Operators approving a weights submission.
+    pub type WeightApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        H256,
+        Vec<T::AccountId>,
+        ValueQuery,
+    >;
 
     #[pallet::storage] // --- DMAP ( cold ) --> (block_expected, new_coldkey) | Maps coldkey to the block to swap at and new coldkey.
     pub type ColdkeySwapScheduled<T: Config> = StorageMap<
@@ -1100,6 +1413,115 @@ pub mod pallet {
         DefaultColdkeySwapScheduled<T>,
     >;
 
+    #[pallet::storage] // --- MAP ( cold ) --> config | The friends able to recover the coldkey.
+    pub type ColdkeyRecoveryConfigs<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ColdkeyRecoveryConfigOf<T>, OptionQuery>;
+
+    #[pallet::storage] // --- DMAP ( lost_cold, new_cold ) --> recovery | Recoveries of a coldkey in progress.
+    pub type ActiveColdkeyRecoveries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyRecoveryOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( hot ) --> cap | Maximum total stake a delegate accepts through add_stake.
+    pub type DelegateStakeCap<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, TaoCurrency, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( hot ) --> recipient | The account the emission of the hotkey accrues to as stake.
+    pub type EmissionRecipient<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> auto_compound | Whether the dividends of the hotkey are restaked for the coldkey, only stored when not.
+    pub type AutoCompound<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+        DefaultTrue<T>,
+    >;
+
+    #[pallet::storage] // --- MAP ( cold ) --> nonce | Sponsored registrations consented to by the coldkey.
+    pub type SponsorConsentNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    #[pallet::storage] // --- MAP ( hot ) --> access | Whether the delegate staking list is an allowlist or a denylist.
+    pub type DelegateStakingAccessMode<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DelegateStakingAccess, OptionQuery>;
+
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> listed | Coldkeys on the staking list of a delegate.
+    pub type DelegateStakingList<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::storage] // --- NMAP ( hot, cold, netuid ) --> lock | Alpha locked by a coldkey for boosted weight.
+    pub type StakeLocks<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+            NMapKey<Identity, NetUid>,               // subnet
+        ),
+        StakeLock,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- NMAP ( netuid, hot, cold ) --> boost | Weight added to the stake of a hotkey by the lock of a coldkey on it.
+    pub type LockBoost<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, NetUid>,               // subnet
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+        ),
+        AlphaCurrency,
+        ValueQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( period ) --> boost | Boost of a lock, in percent of the locked alpha, set by root.
+    pub type StakeLockBoost<T> = StorageMap<_, Twox64Concat, StakeLockPeriod, u16, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<(release_block, tao)> | Unstaked TAO waiting out the unbonding period.
+    pub type UnbondingQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(u64, TaoCurrency)>, ValueQuery>;
+
+    #[pallet::storage] // --- DMAP ( netuid, cold ) --> release_block | Earliest release of TAO unstaked from a subnet, carried over from the subnets the stake was moved from.
+    pub type UnbondingFloor<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    #[pallet::storage] // --- ITEM ( total_unbonding ) | Total TAO held in all unbonding queues.
+    pub type TotalUnbonding<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+
+    #[pallet::storage] // --- ITEM ( evm_withdrawal_delay ) | Blocks TAO withdrawn from the EVM waits before it can be claimed.
+    pub type EvmWithdrawalDelay<T> = StorageValue<_, u64, ValueQuery, DefaultEvmWithdrawalDelay<T>>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<(release_block, tao)> | TAO withdrawn from the EVM waiting out the delay.
+    pub type EvmWithdrawalQueue<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(u64, TaoCurrency)>, ValueQuery>;
+
+    #[pallet::storage] // --- ITEM ( total_evm_withdrawals ) | Total TAO held in all EVM withdrawal queues.
+    pub type TotalEvmWithdrawals<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+
+    #[pallet::storage] // --- MAP ( evm_address ) --> () | EVM addresses that deposited or withdrew through the bridge.
+    pub type EvmBridgeAddresses<T> = StorageMap<_, Identity, H160, (), OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> scheduler address | Scheduler task of the pending coldkey swap, used to cancel it.
+    pub type ColdkeySwapScheduledTask<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SchedulerAddressOf<T>, OptionQuery>;
+
     #[pallet::storage] // --- DMAP ( hot, netuid ) --> alpha | Returns the total amount of alpha a hotkey owns.
     pub type TotalHotkeyAlpha<T: Config> = StorageDoubleMap<
         _,
@@ -1186,6 +1608,16 @@ pub mod pallet {
     /// ITEM( subnet_owner_cut )
     pub type SubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> subnet_owner_cut | Owner cut chosen by the subnet owner, overrides SubnetOwnerCut.
+    pub type SubnetOwnerCutForSubnet<T> = StorageMap<_, Identity, NetUid, u16, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( min_subnet_owner_cut ) | Lowest owner cut a subnet owner may choose.
+    pub type MinSubnetOwnerCut<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMinSubnetOwnerCut<T>>;
+    #[pallet::storage]
+    /// ITEM( max_subnet_owner_cut ) | Highest owner cut a subnet owner may choose.
+    pub type MaxSubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
+    #[pallet::storage]
     /// ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
     #[pallet::storage]
@@ -1195,6 +1627,11 @@ pub mod pallet {
     /// ITEM( weights_version_key_rate_limit ) --- Rate limit in tempos.
     pub type WeightsVersionKeyRateLimit<T> =
         StorageValue<_, u64, ValueQuery, DefaultWeightsVersionKeyRateLimit<T>>;
+    #[pallet::storage]
+    /// ITEM( weights_priority_window ) | Blocks before the epoch of a subnet in which the weights
+    /// of its permitted validators get priority in the transaction pool.
+    pub type WeightsPriorityWindow<T> =
+        StorageValue<_, u64, ValueQuery, DefaultWeightsPriorityWindow<T>>;
 
     /// ============================
     /// ==== Rate Limiting =====
@@ -1267,6 +1704,80 @@ pub mod pallet {
     pub type NetworkPowRegistrationAllowed<T: Config> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultRegistrationAllowed<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> registration_auction_enabled | Registrations are auctioned each adjustment interval instead of PoW or burn.
+    pub type RegistrationAuctionEnabled<T: Config> =
+        StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultFalse<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Vec<(coldkey, hotkey, bid)> | Open registration bids for the current interval.
+    pub type RegistrationBids<T: Config> =
+        StorageMap<_, Identity, NetUid, Vec<(T::AccountId, T::AccountId, TaoCurrency)>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( total_registration_bids ) | Total TAO held in open registration bids.
+    pub type TotalRegistrationBids<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> burn | TAO burned to register the neuron holding the uid.
+    pub type RegistrationBurnPaid<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, TaoCurrency, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Vec<uid> | Uids freed by unregister, filled first by new registrations.
+    pub type VacantUids<T: Config> = StorageMap<_, Identity, NetUid, Vec<u16>, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( unregister_refund_fraction ) | Share of the registration burn, normalized to u16::MAX, refunded on unregister during immunity.
+    pub type UnregisterRefundFraction<T> = StorageValue<_, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> proposal | The open stake-weighted proposal of a subnet.
+    pub type SubnetProposals<T: Config> =
+        StorageMap<_, Identity, NetUid, SubnetProposalOf<T>, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> aye | Votes cast on the open proposal of a subnet.
+    pub type SubnetProposalVotes<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, bool, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( next_subnet_proposal_id ) | The id of the next subnet proposal.
+    pub type NextSubnetProposalId<T> = StorageValue<_, SubnetProposalId, ValueQuery>;
+    #[pallet::storage]
+    /// ITEM( subnet_proposal_quorum ) | Share of outstanding alpha, normalized to u16::MAX, that must approve a subnet proposal.
+    pub type SubnetProposalQuorum<T> =
+        StorageValue<_, u16, ValueQuery, DefaultSubnetProposalQuorum<T>>;
+    #[pallet::storage]
+    /// ITEM( subnet_proposal_duration ) | Number of blocks a subnet proposal accepts votes.
+    pub type SubnetProposalDuration<T> =
+        StorageValue<_, u64, ValueQuery, DefaultSubnetProposalDuration<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share | Share of the owner cut, normalized to u16::MAX, paid to the subnet treasury.
+    pub type SubnetTreasuryShare<T> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultZeroU16<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> spend | The open spend of the subnet treasury awaiting approval.
+    pub type SubnetTreasurySpends<T: Config> =
+        StorageMap<_, Identity, NetUid, SubnetTreasurySpendOf<T>, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> aye | Votes cast on the open spend of a subnet treasury.
+    pub type SubnetTreasurySpendVotes<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, bool, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> start_block | Block at which the subnet entered maintenance mode.
+    pub type SubnetMaintenanceStart<T: Config> =
+        StorageMap<_, Identity, NetUid, u64, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( max_subnet_maintenance_duration ) | Number of blocks after which a subnet leaves maintenance.
+    pub type MaxSubnetMaintenanceDuration<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxSubnetMaintenanceDuration<T>>;
+    #[pallet::storage]
+    /// ITEM( subnet_identity_fee ) | TAO burned from the subnet owner to set the subnet identity.
+    pub type SubnetIdentityFee<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> logo_hash | Hash of the logo served at the `logo_url` of the subnet identity.
+    pub type SubnetLogoHashes<T: Config> = StorageMap<_, Identity, NetUid, H256, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( offense_slash_fraction ) | Share of the stake of an offending hotkey, normalized to u16::MAX, slashed on the subnet.
+    pub type OffenseSlashFraction<T> =
+        StorageValue<_, u16, ValueQuery, DefaultOffenseSlashFraction<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, offense_id ) --> block | Offenses already punished, with the block they were reported at.
+    pub type ReportedOffenses<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, H256, u64, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> block_created
     pub type NetworkRegisteredAt<T: Config> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultNetworkRegisteredAt<T>>;
@@ -1341,6 +1852,113 @@ pub mod pallet {
     pub type ActivityCutoff<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultActivityCutoff<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> unbonding_period | Blocks unstaked TAO stays locked before it can be withdrawn.
+    pub type UnbondingPeriod<T> =
+        StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> vesting_period | Blocks over which miner emission is streamed, zero pays it at once.
+    pub type MinerEmissionVestingPeriod<T> =
+        StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> max_weight_age | Blocks after which weights no longer count, zero never.
+    pub type MaxWeightAge<T> = StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultZeroU64<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> fee | Fee paid to the subnet treasury on the extrinsics scoped to the subnet.
+    pub type SubnetTransactionFee<T> =
+        StorageMap<_, Identity, NetUid, TaoCurrency, ValueQuery, DefaultZeroTao<T>>;
+    #[pallet::storage]
+    /// ITEM( registration_fee_multiplier ) | Multiplier of the transaction fee of registration calls.
+    pub type RegistrationFeeMultiplier<T> =
+        StorageValue<_, U64F64, ValueQuery, DefaultRegistrationFeeMultiplier<T>>;
+    #[pallet::storage]
+    /// ITEM( target_registration_block_fullness ) | Share of the block weight registration calls
+    /// are priced to fill.
+    pub type TargetRegistrationBlockFullness<T> =
+        StorageValue<_, Perbill, ValueQuery, DefaultTargetRegistrationBlockFullness<T>>;
+    #[pallet::storage]
+    /// ITEM( registration_weight_this_block ) | Weight of the registration calls in this block.
+    pub type RegistrationWeightThisBlock<T> = StorageValue<_, Weight, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> chunk_size | Items per block of the subnet's epoch, zero runs it in one block.
+    pub type MultiBlockEpochChunkSize<T> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultZeroU16<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> epoch | Intermediate state of the subnet's epoch running over several blocks.
+    pub type MultiBlockEpochs<T: Config> =
+        StorageMap<_, Identity, NetUid, MultiBlockEpoch, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> uids | What the subnet's multi-block epoch read of each uid.
+    pub type MultiBlockEpochUids<T: Config> =
+        StorageMap<_, Identity, NetUid, Vec<MultiBlockEpochUid>, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> neuron | Hotkey, weight row and bond row of the uid in the subnet's multi-block epoch.
+    pub type MultiBlockEpochNeurons<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Identity,
+        u16,
+        MultiBlockEpochNeuronOf<T>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> column | Weights of the validators with active stake on the uid, in the subnet's multi-block epoch.
+    pub type MultiBlockEpochColumns<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, Vec<(u16, I32F32)>, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, vector ) --> values | Vector over the uids computed by the subnet's multi-block epoch.
+    pub type MultiBlockEpochVectors<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Identity,
+        MultiBlockEpochVector,
+        Vec<I32F32>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> registered | Uids registered while the subnet's multi-block epoch runs.
+    pub type MultiBlockEpochRegistrations<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> incentive | Incentive of the hotkey left to pay by the subnet's multi-block epoch.
+    pub type MultiBlockEpochIncentives<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        AlphaCurrency,
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> dividends | Dividends of the hotkey left to split by the subnet's multi-block epoch.
+    pub type MultiBlockEpochDividends<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, U96F32, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> alpha_divs | Alpha dividends of the hotkey left to pay by the subnet's multi-block epoch.
+    pub type MultiBlockEpochAlphaDividends<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, U96F32, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> root_divs | Root dividends of the hotkey left to pay by the subnet's multi-block epoch.
+    pub type MultiBlockEpochRootDividends<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, U96F32, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> dissolution | Intermediate state of the subnet's dissolution.
+    pub type NetworkDissolutions<T: Config> =
+        StorageMap<_, Identity, NetUid, NetworkDissolution, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> vesting | Miner emission of the hotkey that has not vested yet.
+    pub type VestingMinerEmission<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        VestingEmission,
+        OptionQuery,
+    >;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> max_weight_limit
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
@@ -1349,6 +1967,21 @@ pub mod pallet {
     pub type WeightsVersionKey<T> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultWeightsVersionKey<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> (weights_version_key, effective_block) | Version key waiting out
+    /// the grace period, during which both it and the current key are accepted.
+    pub type PendingWeightsVersionKey<T> =
+        StorageMap<_, Identity, NetUid, (u64, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> grace_period | Blocks before a new weights version key applies.
+    pub type WeightsVersionKeyGracePeriod<T> = StorageMap<
+        _,
+        Identity,
+        NetUid,
+        u64,
+        ValueQuery,
+        DefaultWeightsVersionKeyGracePeriod<T>,
+    >;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> min_allowed_weights
     pub type MinAllowedWeights<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultMinAllowedWeights<T>>;
@@ -1372,6 +2005,37 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> bonds_reset
     pub type BondsResetOn<T> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultBondsResetOn<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> bonds_reset_on_deregistration
+    pub type BondsResetOnDeregistration<T> =
+        StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultFalse<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, recipient_netuid ) --> share | Proposed shares of the owner cut.
+    pub type EmissionShareProposals<T> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, NetUid, u16, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, recipient_netuid ) --> share | Shares of the owner cut paid to the
+    /// owner of the recipient subnet.
+    pub type EmissionShares<T> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, NetUid, u16, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( emission_circuit_breaker_threshold ) | Emission, in percent of the expected
+    /// emission, that trips the circuit breaker. Zero disables the breaker.
+    pub type EmissionCircuitBreakerThreshold<T> =
+        StorageValue<_, u32, ValueQuery, DefaultEmissionCircuitBreakerThreshold<T>>;
+    #[pallet::storage]
+    /// ITEM( emission_halted ) | Whether the global emission circuit breaker has tripped.
+    pub type EmissionHalted<T> = StorageValue<_, bool, ValueQuery, DefaultFalse<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> emission_halted | Whether the circuit breaker of the subnet has
+    /// tripped.
+    pub type SubnetEmissionHalted<T> =
+        StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultFalse<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> schedule | The schedule matching the TAO emission of the subnet
+    /// with TAO from the incentive matching fund.
+    pub type IncentiveMatching<T> =
+        StorageMap<_, Identity, NetUid, IncentiveMatchingSchedule, OptionQuery>;
     /// --- MAP ( netuid ) --> weights_set_rate_limit
     #[pallet::storage]
     pub type WeightsSetRateLimit<T> =
@@ -1393,6 +2057,18 @@ pub mod pallet {
     pub type AdjustmentAlpha<T: Config> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultAdjustmentAlpha<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> (kp, ki, kd) | Gains of the PID controller of the burn, normalized
+    /// to u16::MAX. Zero gains leave the burn to the default adjustment.
+    pub type BurnControllerGains<T> = StorageMap<_, Identity, NetUid, (u16, u16, u16), ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> (integral, last_error) | State of the PID controller of the burn.
+    pub type BurnControllerState<T> =
+        StorageMap<_, Identity, NetUid, (I96F32, I96F32), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> pow_algorithm | The hash of the registration proof of work.
+    pub type RegistrationPowAlgorithms<T> =
+        StorageMap<_, Identity, NetUid, RegistrationPowAlgorithm, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> commit reveal v2 weights are enabled
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
@@ -1441,6 +2117,18 @@ pub mod pallet {
         DefaultRAORecycledForRegistration<T>,
     >;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> TAO burned on the subnet, by network registration locks and subnet key swaps.
+    pub type SubnetTaoBurned<T> =
+        StorageMap<_, Identity, NetUid, TaoCurrency, ValueQuery, DefaultZeroTao<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Alpha removed from AlphaOut, by registration burns and recycle_alpha.
+    pub type SubnetAlphaRecycled<T> =
+        StorageMap<_, Identity, NetUid, AlphaCurrency, ValueQuery, DefaultZeroAlpha<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> Alpha burned without reducing AlphaOut, by burn_alpha and withheld emissions.
+    pub type SubnetAlphaBurned<T> =
+        StorageMap<_, Identity, NetUid, AlphaCurrency, ValueQuery, DefaultZeroAlpha<T>>;
+    #[pallet::storage]
     /// --- ITEM ( tx_rate_limit )
     pub type TxRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultTxRateLimit<T>>;
     #[pallet::storage]
@@ -1464,6 +2152,14 @@ pub mod pallet {
     pub type AlphaValues<T> =
         StorageMap<_, Identity, NetUid, (u16, u16), ValueQuery, DefaultAlphaValues<T>>;
     #[pallet::storage]
+    /// ITEM( min_liquid_alpha_low ) | Lowest alpha low, and alpha high, a subnet owner may choose.
+    pub type MinLiquidAlphaLow<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMinLiquidAlphaLow<T>>;
+    #[pallet::storage]
+    /// ITEM( max_liquid_alpha_high ) | Highest alpha high a subnet owner may choose.
+    pub type MaxLiquidAlphaHigh<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMaxLiquidAlphaHigh<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> If subtoken trading enabled
     pub type SubtokenEnabled<T> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultFalse<T>>;
@@ -1584,6 +2280,17 @@ pub mod pallet {
         AxonInfoOf,
         OptionQuery,
     >;
+    #[pallet::storage]
+    /// --- MAP ( netuid, hotkey ) --> axon_info_v2 | All endpoints and protocol metadata of an axon.
+    pub type AxonsV2<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        AxonInfoV2,
+        OptionQuery,
+    >;
     /// --- MAP ( netuid, hotkey ) --> certificate
     #[pallet::storage]
     pub type NeuronCertificates<T: Config> = StorageDoubleMap<
@@ -1657,6 +2364,18 @@ pub mod pallet {
     /// ITEM( weights_min_stake )
     pub type StakeThreshold<T> = StorageValue<_, u64, ValueQuery, DefaultStakeThreshold<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> stake_threshold | Minimum stake for a validator permit on the subnet, raises StakeThreshold.
+    pub type SubnetStakeThreshold<T> =
+        StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultStakeThreshold<T>>;
+    #[pallet::storage]
+    /// ITEM( min_subnet_stake_threshold ) | Lowest validator stake threshold a subnet owner may choose.
+    pub type MinSubnetStakeThreshold<T> =
+        StorageValue<_, u64, ValueQuery, DefaultStakeThreshold<T>>;
+    #[pallet::storage]
+    /// ITEM( max_subnet_stake_threshold ) | Highest validator stake threshold a subnet owner may choose.
+    pub type MaxSubnetStakeThreshold<T> =
+        StorageValue<_, u64, ValueQuery, DefaultMaxSubnetStakeThreshold<T>>;
+    #[pallet::storage]
     /// --- MAP (netuid, who) --> VecDeque<(hash, commit_block, first_reveal_block, last_reveal_block)> | Stores a queue of commits for an account on a given netuid.
     pub type WeightCommits<T: Config> = StorageDoubleMap<
         _,
@@ -1731,6 +2450,11 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    /// --- MAP ( cold ) --> exempt | Coldkeys exempt from the staking operation rate limit.
+    pub type StakingRateLimitExempt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
     /// =============================
     /// ==== EVM related storage ====
     /// =============================
@@ -1738,6 +2462,20 @@ pub mod pallet {
     /// --- DMAP (netuid, uid) --> (H160, last_block_where_ownership_was_proven)
     pub type AssociatedEvmAddress<T: Config> =
         StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, u16, (H160, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> sponsorship | The EVM account paying the gas of the contracts sponsored by a subnet.
+    pub type EvmGasSponsorships<T> =
+        StorageMap<_, Identity, NetUid, EvmGasSponsorship, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( sponsor ) --> nonce | Gas sponsorships consented to by an EVM account.
+    pub type EvmGasSponsorConsentNonce<T> = StorageMap<_, Identity, H160, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( contract ) --> netuid | The subnet whose sponsor pays the gas of calls to a contract.
+    pub type EvmSponsoredContracts<T> =
+        StorageMap<_, Blake2_128Concat, H160, NetUid, OptionQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( target ) | The target of the Ethereum transaction being applied, used to charge its fee.
+    pub type EvmTransactionTarget<T> = StorageValue<_, H160, OptionQuery>;
 
     /// ========================
     /// ==== Subnet Leasing ====
@@ -1747,6 +2485,11 @@ pub mod pallet {
     pub type SubnetLeases<T: Config> =
         StorageMap<_, Twox64Concat, LeaseId, SubnetLeaseOf<T>, OptionQuery>;
 
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> subnet offer | The offer to sell a subnet.
+    pub type SubnetOffers<T: Config> =
+        StorageMap<_, Identity, NetUid, SubnetOfferOf<T>, OptionQuery>;
+
     #[pallet::storage]
     /// --- DMAP ( lease_id, contributor ) --> shares | The shares of a contributor for a given lease.
     pub type SubnetLeaseShares<T: Config> =
@@ -1771,6 +2514,10 @@ pub mod pallet {
     pub type CommitRevealWeightsVersion<T> =
         StorageValue<_, u16, ValueQuery, DefaultCommitRevealWeightsVersion<T>>;
 
+    #[pallet::storage]
+    /// --- ITEM ( disabled_calls ) | Bitmask of the call indices of this pallet paused by root.
+    pub type DisabledCalls<T> = StorageValue<_, [u8; 32], ValueQuery>;
+
     /// ==================
     /// ==== Genesis =====
     /// ==================
@@ -1794,6 +2541,9 @@ pub mod pallet {
         pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u64, u16))>)>,
         /// The total issued balance in genesis
         pub balances_issuance: TaoCurrency,
+        /// Subnets registered in genesis: the owner coldkey and hotkey, then the coldkey and
+        /// hotkey of every other neuron.
+        pub subnets: Vec<(T::AccountId, T::AccountId, Vec<(T::AccountId, T::AccountId)>)>,
     }
 
     impl<T: Config> Default for GenesisConfig<T> {
@@ -1801,6 +2551,7 @@ pub mod pallet {
             Self {
                 stakes: Default::default(),
                 balances_issuance: TaoCurrency::ZERO,
+                subnets: Default::default(),
             }
         }
     }
@@ -1808,6 +2559,9 @@ pub mod pallet {
     // ---- Subtensor helper functions.
     impl<T: Config> Pallet<T> {
         /// Returns the transaction priority for setting weights.
+        ///
+        /// Permitted validators get a boost in the last [`WeightsPriorityWindow`] blocks before
+        /// the epoch of the subnet, so their weights make it in under congestion.
         pub fn get_priority_set_weights(hotkey: &T::AccountId, netuid: NetUid) -> u64 {
             if let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, hotkey) {
                 // TODO rethink this.
@@ -1815,11 +2569,25 @@ pub mod pallet {
                 let current_block_number: u64 = Self::get_current_block_as_u64();
                 let default_priority: u64 =
                     current_block_number.saturating_sub(Self::get_last_update_for_uid(netuid, uid));
-                return default_priority.saturating_add(u32::MAX as u64);
+                let priority = default_priority.saturating_add(u32::MAX as u64);
+                if Self::get_validator_permit_for_uid(netuid, uid)
+                    && Self::is_in_weights_priority_window(netuid, current_block_number)
+                {
+                    return priority.saturating_add(u32::MAX as u64);
+                }
+                return priority;
             }
             0
         }
 
+        /// Whether `block_number` is in the last [`WeightsPriorityWindow`] blocks before the
+        /// epoch of `netuid`.
+        pub fn is_in_weights_priority_window(netuid: NetUid, block_number: u64) -> bool {
+            let tempo = Self::get_tempo(netuid);
+            Self::blocks_until_next_epoch(netuid, tempo, block_number)
+                < WeightsPriorityWindow::<T>::get()
+        }
+
         // FIXME this function is used both to calculate for alpha stake amount as well as tao
         // amount
         /// Returns the transaction priority for stake operations.
@@ -1918,6 +2686,7 @@ pub enum CustomTransactionError {
     BadRequest,
     ZeroMaxAmount,
     InvalidRevealRound,
+    CallDisabled,
 }
 
 impl From<CustomTransactionError> for u8 {
@@ -1940,6 +2709,7 @@ impl From<CustomTransactionError> for u8 {
             CustomTransactionError::BadRequest => 255,
             CustomTransactionError::ZeroMaxAmount => 14,
             CustomTransactionError::InvalidRevealRound => 15,
+            CustomTransactionError::CallDisabled => 16,
         }
     }
 }
@@ -1976,8 +2746,10 @@ where
         u64::MAX
     }
 
+    /// Priority of a weights call signed by `who`, a hotkey or its weights key.
     pub fn get_priority_set_weights(who: &T::AccountId, netuid: NetUid) -> u64 {
-        Pallet::<T>::get_priority_set_weights(who, netuid)
+        let hotkey = Pallet::<T>::get_weights_hotkey(who.clone());
+        Pallet::<T>::get_priority_set_weights(&hotkey, netuid)
     }
 
     pub fn get_priority_staking(
@@ -1988,8 +2760,28 @@ where
         Pallet::<T>::get_priority_staking(coldkey, hotkey, stake_amount)
     }
 
+    /// Whether the hotkey of `who`, a hotkey or its weights key, has enough stake to set weights.
     pub fn check_weights_min_stake(who: &T::AccountId, netuid: NetUid) -> bool {
-        Pallet::<T>::check_weights_min_stake(who, netuid)
+        let hotkey = Pallet::<T>::get_weights_hotkey(who.clone());
+        Pallet::<T>::check_weights_min_stake(&hotkey, netuid)
+    }
+
+    /// The subnet of `call` if it is subject to the subnet transaction fee.
+    ///
+    /// Registering with proof of work is free and signed by the new hotkey, so it is exempt.
+    pub fn get_subnet_fee_netuid(
+        call: &<T as frame_system::Config>::RuntimeCall,
+    ) -> Option<NetUid> {
+        match call.is_sub_type() {
+            Some(Call::set_weights { netuid, .. })
+            | Some(Call::set_weights_delta { netuid, .. })
+            | Some(Call::serve_axon { netuid, .. })
+            | Some(Call::serve_axon_tls { netuid, .. })
+            | Some(Call::serve_axon_v2 { netuid, .. })
+            | Some(Call::burned_register { netuid, .. })
+            | Some(Call::sponsored_burned_register { netuid, .. }) => Some(*netuid),
+            _ => None,
+        }
     }
 
     pub fn validity_ok(priority: u64) -> ValidTransaction {
@@ -2071,6 +2863,20 @@ where
             return Ok((Default::default(), None, origin));
         };
 
+        // Ensure the signer can pay the fee of the subnet, if any.
+        if Self::get_subnet_fee_netuid(call)
+            .is_some_and(|netuid| !Pallet::<T>::can_pay_subnet_transaction_fee(who, netuid))
+        {
+            return Err(CustomTransactionError::BalanceTooLow.into());
+        }
+
+        // Reject the calls paused by root.
+        if IsSubType::<Call<T>>::is_sub_type(call)
+            .is_some_and(|call| Pallet::<T>::is_call_disabled(call.get_call_index()))
+        {
+            return Err(CustomTransactionError::CallDisabled.into());
+        }
+
         match call.is_sub_type() {
             Some(Call::commit_weights { netuid, .. }) => {
                 if Self::check_weights_min_stake(who, *netuid) {
@@ -2099,7 +2905,8 @@ where
                     Err(CustomTransactionError::StakeAmountTooLow.into())
                 }
             }
-            Some(Call::set_weights { netuid, .. }) => {
+            Some(Call::set_weights { netuid, .. })
+            | Some(Call::set_weights_delta { netuid, .. }) => {
                 if Self::check_weights_min_stake(who, *netuid) {
                     let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     let validity = Self::validity_ok(priority);
@@ -2126,7 +2933,7 @@ where
                     if *reveal_round < pallet_drand::LastStoredRound::<T>::get() {
                         return Err(CustomTransactionError::InvalidRevealRound.into());
                     }
-                    let priority: u64 = Pallet::<T>::get_priority_set_weights(who, *netuid);
+                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     let validity = Self::validity_ok(priority);
                     Ok((validity, Some(who.clone()), origin))
                 } else {
@@ -2142,7 +2949,7 @@ where
                     if *reveal_round < pallet_drand::LastStoredRound::<T>::get() {
                         return Err(CustomTransactionError::InvalidRevealRound.into());
                     }
-                    let priority: u64 = Pallet::<T>::get_priority_set_weights(who, *netuid);
+                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                     let validity = Self::validity_ok(priority);
                     Ok((validity, Some(who.clone()), origin))
                 } else {
@@ -2164,6 +2971,13 @@ where
                 ));
                 Ok((validity, Some(who.clone()), origin))
             }
+            Some(Call::add_stake_multiple { .. }) => {
+                if ColdkeySwapScheduled::<T>::contains_key(who) {
+                    return Err(CustomTransactionError::ColdkeyInSwapSchedule.into());
+                }
+                let validity = Self::validity_ok(Self::get_priority_vanilla());
+                Ok((validity, Some(who.clone()), origin))
+            }
             Some(Call::add_stake_limit {
                 hotkey,
                 netuid: _,
@@ -2274,7 +3088,11 @@ where
                 ));
                 Ok((validity, Some(who.clone()), origin))
             }
-            Some(Call::register { netuid, .. } | Call::burned_register { netuid, .. }) => {
+            Some(
+                Call::register { netuid, .. }
+                | Call::burned_register { netuid, .. }
+                | Call::sponsored_burned_register { netuid, .. },
+            ) => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     return Err(CustomTransactionError::ColdkeyInSwapSchedule.into());
                 }
@@ -2360,19 +3178,27 @@ where
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
         // The transaction is not signed, given val is None, so we just skip this step.
-        if val.is_none() {
+        let Some(who) = val else {
             return Ok(None);
+        };
+
+        // Charge the fee of the subnet, if any.
+        if let Some(netuid) = Self::get_subnet_fee_netuid(call) {
+            Pallet::<T>::charge_subnet_transaction_fee(&who, netuid)
+                .map_err(|_| CustomTransactionError::BalanceTooLow)?;
         }
 
         match call.is_sub_type() {
             Some(Call::add_stake { .. }) => Ok(Some(CallType::AddStake)),
             Some(Call::remove_stake { .. }) => Ok(Some(CallType::RemoveStake)),
             Some(Call::set_weights { .. }) => Ok(Some(CallType::SetWeights)),
+            Some(Call::set_weights_delta { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::commit_weights { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::reveal_weights { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::register { .. }) => Ok(Some(CallType::Register)),
             Some(Call::serve_axon { .. }) => Ok(Some(CallType::Serve)),
             Some(Call::serve_axon_tls { .. }) => Ok(Some(CallType::Serve)),
+            Some(Call::serve_axon_v2 { .. }) => Ok(Some(CallType::Serve)),
             Some(Call::register_network { .. }) => Ok(Some(CallType::RegisterNetwork)),
             _ => Ok(Some(CallType::Other)),
         }
@@ -2611,6 +3437,10 @@ impl<T: Config + pallet_balances::Config<Balance = u64>>
 pub enum RateLimitKey {
     // The setting sn owner hotkey operation is rate limited per netuid
     SetSNOwnerHotkey(NetUid),
+    // The setting subnet owner cut operation is rate limited per netuid
+    SetSubnetOwnerCut(NetUid),
+    // Growing the max UIDs of a subnet is rate limited per netuid
+    GrowMaxAllowedUids(NetUid),
 }
 
 pub trait ProxyInterface<AccountId> {
@@ -2628,3 +3458,12 @@ impl<T> ProxyInterface<T> for () {
         Ok(())
     }
 }
+
+/// Receives every event of the pallet as it is deposited.
+pub trait EventBridge<T: Config> {
+    fn on_event(event: &Event<T>);
+}
+
+impl<T: Config> EventBridge<T> for () {
+    fn on_event(_: &Event<T>) {}
+}
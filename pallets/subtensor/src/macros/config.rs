@@ -18,7 +18,8 @@ mod config {
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
             + From<Call<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeCall>
-            + From<frame_system::Call<Self>>;
+            + From<frame_system::Call<Self>>
+            + GetDispatchInfo;
 
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -58,6 +59,12 @@ mod config {
         /// Interface to allow interacting with the proxy pallet.
         type ProxyInterface: crate::ProxyInterface<Self::AccountId>;
 
+        /// Mirrors pallet events outside of the pallet, e.g. as EVM logs.
+        type EventBridge: crate::EventBridge<Self>;
+
+        /// Maps an EVM address to the account holding its balance.
+        type EvmAddressMapping: sp_runtime::traits::Convert<H160, Self::AccountId>;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
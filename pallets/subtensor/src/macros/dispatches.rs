@@ -118,6 +118,8 @@ mod dispatches {
         /// 	- On failure of any of the weights in the batch.
         /// * BatchWeightItemFailed;
         /// 	- On failure for each failed item in the batch.
+        /// * BatchWeightItemFailedOnSubnet;
+        /// 	- On failure for each failed item in the batch, with its netuid.
         ///
         #[pallet::call_index(80)]
         #[pallet::weight((Weight::from_parts(78_450_000, 0)
@@ -268,7 +270,7 @@ mod dispatches {
         ///     4. Serialize and compress using the `ark-serialize` `CanonicalSerialize` trait.
         ///
         /// * reveal_round (`u64`):
-        ///    - The drand reveal round which will be avaliable during epoch `n+1` from the current
+        ///    - The drand reveal round which will be available during epoch `n+1` from the current
         ///      epoch.
         ///
         /// # Raises:
@@ -889,8 +891,8 @@ mod dispatches {
         /// 	- The seal is incorrect.
         ///
         #[pallet::call_index(6)]
-        #[pallet::weight((Weight::from_parts(161_800_000, 0)
-		.saturating_add(T::DbWeight::get().reads(26))
+        #[pallet::weight((Weight::from_parts(60_161_800_000, 0)
+		.saturating_add(T::DbWeight::get().reads(27))
 		.saturating_add(T::DbWeight::get().writes(23)), DispatchClass::Normal, Pays::No))]
         pub fn register(
             origin: OriginFor<T>,
@@ -936,6 +938,10 @@ mod dispatches {
         }
 
         /// The extrinsic for user to change its hotkey in subnet or all subnets.
+        ///
+        /// With `netuid` set, only that subnet's registration is rotated to the new hotkey,
+        /// keeping its UID, weights, bonds and emission history. This is rate limited per coldkey
+        /// and subnet and charged `KeySwapOnSubnetCost`.
         #[pallet::call_index(70)]
         #[pallet::weight((Weight::from_parts(211_900_000, 0)
         .saturating_add(T::DbWeight::get().reads(47))
@@ -1365,7 +1371,7 @@ mod dispatches {
             let bound_call = <T as Config>::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            let address = T::Scheduler::schedule(
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1374,7 +1380,13 @@ mod dispatches {
             )
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
+            // A previous task may still be pending if its swap failed, drop it before replacing it.
+            if let Some(previous) = ColdkeySwapScheduledTask::<T>::take(&who) {
+                let _ = T::Scheduler::cancel(previous);
+            }
+
             ColdkeySwapScheduled::<T>::insert(&who, (when, new_coldkey.clone()));
+            ColdkeySwapScheduledTask::<T>::insert(&who, address);
             // Emit the SwapScheduled event
             Self::deposit_event(Event::ColdkeySwapScheduled {
                 old_coldkey: who.clone(),
@@ -1506,10 +1518,14 @@ mod dispatches {
         ///
         /// * `subnet_contact` (Vec<u8>):
         ///     - The contact information for the subnet.
+        ///
+        /// # Raises:
+        /// * `NotEnoughBalanceToPaySubnetIdentityFee`:
+        ///     - The owner cannot pay the `SubnetIdentityFee`, which is burned.
         #[pallet::call_index(78)]
         #[pallet::weight((Weight::from_parts(15_000_000, 0)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
         pub fn set_subnet_identity(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1627,14 +1643,15 @@ mod dispatches {
         /// * `destination_hotkey` (T::AccountId):
         ///     - The hotkey account to move stake to.
         ///
-        /// * `origin_netuid` (T::AccountId):
+        /// * `origin_netuid` (NetUid):
         ///     - The subnet ID to move stake from.
         ///
-        /// * `destination_netuid` (T::AccountId):
+        /// * `destination_netuid` (NetUid):
         ///     - The subnet ID to move stake to.
         ///
-        /// * `alpha_amount` (T::AccountId):
-        ///     - The alpha stake amount to move.
+        /// * `alpha_amount` (AlphaCurrency):
+        ///     - The alpha stake amount to move. May be less than the full stake, the remainder
+        ///       stays on the origin hotkey. The unstake and restake happen atomically in this call.
         ///
         #[pallet::call_index(85)]
         #[pallet::weight((Weight::from_parts(123_700_000, 0)
@@ -2195,7 +2212,7 @@ mod dispatches {
         ///     4. Serialize and compress using the `ark-serialize` `CanonicalSerialize` trait.
         ///
         /// * reveal_round (`u64`):
-        ///    - The drand reveal round which will be avaliable during epoch `n+1` from the current
+        ///    - The drand reveal round which will be available during epoch `n+1` from the current
         ///      epoch.
         ///
         /// * commit_reveal_version (`u16`):
@@ -2219,5 +2236,1189 @@ mod dispatches {
                 commit_reveal_version,
             )
         }
+
+        /// Cancels the coldkey swap previously scheduled by the caller with
+        /// `schedule_swap_coldkey`, before it is executed.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - The origin of the call, which must be signed by the coldkey that scheduled the swap.
+        ///
+        /// # Errors
+        ///
+        /// * `SwapNotScheduled` - The coldkey has no pending scheduled swap.
+        /// * `FailedToCancelSchedule` - The scheduler could not cancel the pending task.
+        ///
+        /// # Notes
+        ///
+        /// The swap cost is only charged when the swap executes, so nothing is refunded here.
+        #[pallet::call_index(114)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Operational, Pays::Yes))]
+        pub fn cancel_swap_coldkey(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let address =
+                ColdkeySwapScheduledTask::<T>::get(&who).ok_or(Error::<T>::SwapNotScheduled)?;
+            let (_, new_coldkey) = ColdkeySwapScheduled::<T>::get(&who);
+
+            T::Scheduler::cancel(address).map_err(|_| Error::<T>::FailedToCancelSchedule)?;
+
+            ColdkeySwapScheduledTask::<T>::remove(&who);
+            ColdkeySwapScheduled::<T>::remove(&who);
+
+            Self::deposit_event(Event::ColdkeySwapCancelled {
+                old_coldkey: who,
+                new_coldkey,
+            });
+
+            Ok(())
+        }
+
+        /// ---- Withdraws every unbonding chunk of the caller whose release block has passed.
+        ///
+        /// Unstaking from a subnet with a non-zero unbonding period places the TAO in the
+        /// coldkey's unbonding queue instead of its free balance.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the caller's coldkey.
+        ///
+        /// # Raises:
+        /// * `NoUnbondedStake`:
+        ///     - Thrown if none of the caller's unbonding TAO has been released yet.
+        #[pallet::call_index(115)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_withdraw_unbonded(origin)
+        }
+
+        /// ---- Sets the maximum total stake a delegate hotkey accepts.
+        ///
+        /// `add_stake` calls that would take the hotkey's total stake above the cap fail. Stake
+        /// already above the cap is left untouched.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The delegate hotkey.
+        ///
+        /// * `cap` (Option<TaoCurrency>):
+        ///     - The maximum total stake in TAO, or `None` to remove the cap.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - The hotkey is not registered.
+        ///
+        /// * `NonAssociatedColdKey`:
+        ///     - The hotkey is not owned by the calling coldkey.
+        #[pallet::call_index(116)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_stake_cap(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            cap: Option<TaoCurrency>,
+        ) -> DispatchResult {
+            Self::do_set_delegate_stake_cap(origin, hotkey, cap)
+        }
+
+        /// --- Splits one TAO amount across several hotkeys on a subnet in a single call.
+        ///
+        /// Either every stake succeeds or none is added.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'netuid' (u16):
+        ///     - Subnetwork UID
+        ///
+        ///  * 'amount_staked' (u64):
+        /// 	- The total amount of TAO to stake.
+        ///
+        ///  * 'splits' (Vec<(u64, T::AccountId)>):
+        /// 	- Relative proportions and the hotkeys receiving them.
+        ///
+        /// # Raises:
+        ///  * 'InvalidStakeSplit':
+        /// 	- The split is empty, too long, has duplicate hotkeys or a zero proportion.
+        ///
+        ///  * Any error raised by add_stake for one of the hotkeys.
+        ///
+        #[pallet::call_index(117)]
+        #[pallet::weight((Weight::from_parts(270_800_000, 0)
+		.saturating_add(T::DbWeight::get().reads(26))
+		.saturating_add(T::DbWeight::get().writes(15))
+		.saturating_mul(splits.len() as u64), DispatchClass::Normal, Pays::Yes))]
+        pub fn add_stake_multiple(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            amount_staked: TaoCurrency,
+            splits: Vec<(u64, T::AccountId)>,
+        ) -> DispatchResult {
+            Self::do_add_stake_multiple(origin, netuid, amount_staked, splits)
+        }
+
+        /// Bid for a registration slot on a subnet that registers by auction.
+        ///
+        /// The bid is taken from the coldkey immediately. At the end of the adjustment interval the
+        /// highest `target_registrations_per_interval` bids are registered and their bids burned,
+        /// all other bids are refunded. Bidding again for the same hotkey replaces the previous bid.
+        #[pallet::call_index(118)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn bid_registration(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            bid: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_bid_registration(origin, netuid, hotkey, bid)
+        }
+
+        /// Open a proposal for the stakers of a subnet to approve a call, typically an
+        /// admin-utils hyperparameter change.
+        ///
+        /// Only the subnet owner can propose, and a subnet has at most one open proposal. Once
+        /// approved, the call is dispatched with the subnet owner origin.
+        #[pallet::call_index(119)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_subnet_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_change(origin, netuid, call)
+        }
+
+        /// Vote on the open proposal of a subnet with the caller's alpha stake on the subnet.
+        ///
+        /// The vote that brings the ayes to the quorum of the subnet's outstanding alpha, while
+        /// outnumbering the nays, executes the proposal.
+        #[pallet::call_index(120)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(crate::subnets::governance::MAX_SUBNET_PROPOSAL_CALL_WEIGHT)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_subnet_proposal(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            proposal_id: SubnetProposalId,
+            aye: bool,
+        ) -> DispatchResult {
+            Self::do_vote_subnet_proposal(origin, netuid, proposal_id, aye)
+        }
+
+        /// Voluntarily leave a subnet, freeing the uid of the hotkey immediately.
+        ///
+        /// The coldkey is refunded part of the registration burn if the neuron is still in
+        /// immunity, see `UnregisterRefundFraction`. The stake of the hotkey is untouched.
+        #[pallet::call_index(121)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(12))
+		.saturating_add(T::DbWeight::get().writes(16)), DispatchClass::Normal, Pays::Yes))]
+        pub fn unregister(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_unregister(origin, netuid, hotkey)
+        }
+
+        /// Serves several endpoints for the axon of the caller, with protocol metadata.
+        ///
+        /// An endpoint with ip type `AXON_IP_TYPE_COMMITMENT` and a zero ip is reachable at the DNS
+        /// name published in the hotkey's commitment. The first endpoint is the primary one and is
+        /// also served as the `AxonInfo` of the hotkey. See `do_serve_axon_v2`.
+        #[pallet::call_index(122)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_v2(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            version: u32,
+            protocol_version: u32,
+            endpoints: Vec<AxonEndpoint>,
+            tls_fingerprint: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            Self::do_serve_axon_v2(
+                origin,
+                netuid,
+                version,
+                protocol_version,
+                endpoints,
+                tls_fingerprint,
+            )
+        }
+
+        /// --- Sets whether the staking list of a delegate is an allowlist or a denylist.
+        ///
+        /// `add_stake` calls from coldkeys the delegate does not accept fail, the coldkey owning
+        /// the hotkey can always stake. Existing stake is left untouched.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The delegate hotkey.
+        ///
+        /// * `access` (Option<DelegateStakingAccess>):
+        ///     - The access mode, or `None` to let every coldkey stake.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - The hotkey is not registered.
+        ///
+        /// * `NonAssociatedColdKey`:
+        ///     - The hotkey is not owned by the calling coldkey.
+        #[pallet::call_index(123)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_staking_access(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            access: Option<DelegateStakingAccess>,
+        ) -> DispatchResult {
+            Self::do_set_delegate_staking_access(origin, hotkey, access)
+        }
+
+        /// --- Adds coldkeys to or removes them from the staking list of a delegate.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The delegate hotkey.
+        ///
+        /// * `coldkeys` (Vec<T::AccountId>):
+        ///     - At most `MAX_DELEGATE_STAKING_LIST_UPDATE` coldkeys to add or remove.
+        ///
+        /// * `listed` (bool):
+        ///     - `true` adds the coldkeys to the list, `false` removes them.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - The hotkey is not registered.
+        ///
+        /// * `NonAssociatedColdKey`:
+        ///     - The hotkey is not owned by the calling coldkey.
+        ///
+        /// * `TooManyDelegateStakingListUpdates`:
+        ///     - Too many coldkeys are given.
+        #[pallet::call_index(124)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(coldkeys.len() as u64)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_delegate_staking_list(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            coldkeys: Vec<T::AccountId>,
+            listed: bool,
+        ) -> DispatchResult {
+            Self::do_set_delegate_staking_list(origin, hotkey, coldkeys, listed)
+        }
+
+        /// Puts a subnet into maintenance mode, or takes it out of it.
+        ///
+        /// While in maintenance the subnet's epochs do not run and its emission is withheld until
+        /// it resumes, and registrations and weight setting fail with `SubnetInMaintenance`. The
+        /// subnet resumes automatically after `MaxSubnetMaintenanceDuration` blocks.
+        /// Only callable by the subnet owner or root.
+        #[pallet::call_index(125)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_subnet_maintenance(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            Self::do_set_subnet_maintenance(origin, netuid, enabled)
+        }
+
+        /// Reports a hotkey that signed two conflicting weight statements for the same subnet
+        /// epoch, see `subnets::slashing`.
+        ///
+        /// Both signatures are verified on-chain. The stake of the hotkey on the subnet is slashed
+        /// by `OffenseSlashFraction` and the slashed alpha is recycled. Callable by anyone.
+        ///
+        /// # Raises:
+        /// * `InvalidOffenseEvidence`:
+        ///     - The two statements commit to the same hash.
+        ///
+        /// * `InvalidOffenseSignature`:
+        ///     - A statement is not signed by the hotkey.
+        ///
+        /// * `OffenseAlreadyReported`:
+        ///     - The offense has already been punished.
+        #[pallet::call_index(126)]
+        #[pallet::weight((Weight::from_parts(150_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn report_offense(
+            origin: OriginFor<T>,
+            evidence: WeightOffenseEvidenceOf<T>,
+        ) -> DispatchResult {
+            Self::do_report_offense(origin, evidence)
+        }
+
+        /// --- Adds stake to a hotkey on a subnet and locks the alpha bought for a period.
+        ///
+        /// While locked the alpha cannot leave the hotkey, and `StakeLockBoost` percent of it is
+        /// added to the hotkey's stake weight in the epoch. See `staking::stake_lock`.
+        ///
+        /// # Args:
+        ///  * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        ///  * 'hotkey' (T::AccountId):
+        /// 	- The associated hotkey account.
+        ///
+        /// * 'netuid' (u16):
+        ///     - Subnetwork UID
+        ///
+        ///  * 'amount_staked' (u64):
+        /// 	- The amount of TAO to stake.
+        ///
+        ///  * 'period' (StakeLockPeriod):
+        /// 	- How long the alpha stays locked.
+        ///
+        /// # Raises:
+        ///  * Any error raised by add_stake.
+        ///
+        #[pallet::call_index(127)]
+        #[pallet::weight((Weight::from_parts(270_800_000, 0)
+		.saturating_add(T::DbWeight::get().reads(29))
+		.saturating_add(T::DbWeight::get().writes(17)), DispatchClass::Normal, Pays::Yes))]
+        pub fn add_stake_locked(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            amount_staked: TaoCurrency,
+            period: StakeLockPeriod,
+        ) -> DispatchResult {
+            Self::do_add_stake_locked(origin, hotkey, netuid, amount_staked, period)
+        }
+
+        /// --- Lifts the stake lock of the caller on a hotkey and subnet once it has expired,
+        /// removing its boost.
+        ///
+        /// # Raises:
+        ///  * 'NoStakeLock':
+        /// 	- The caller has no lock on the hotkey and subnet.
+        ///
+        ///  * 'StakeLockNotExpired':
+        /// 	- The unlock block of the lock has not been reached.
+        ///
+        #[pallet::call_index(128)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn unlock_stake(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_unlock_stake(origin, hotkey, netuid)
+        }
+
+        /// ---- Set or remove the hash of the logo of a subnet, served at the `logo_url` of its
+        /// identity.
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the calling coldkey, which must be the owner of the subnet.
+        ///
+        /// * `netuid` (u16):
+        ///     - The unique network identifier of the subnet.
+        ///
+        /// * `logo_hash` (Option<H256>):
+        ///     - The hash of the logo, or `None` to remove it.
+        #[pallet::call_index(129)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_subnet_logo_hash(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            logo_hash: Option<H256>,
+        ) -> DispatchResult {
+            Self::do_set_subnet_logo_hash(origin, netuid, logo_hash)
+        }
+
+        /// --- Stakes the miner emission of a hotkey that has vested so far on a subnet which
+        /// streams miner emission.
+        ///
+        /// # Raises:
+        ///  * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey.
+        ///
+        ///  * 'NoVestingEmission':
+        /// 	- The hotkey has no vesting emission on the subnet.
+        ///
+        #[pallet::call_index(130)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::Yes))]
+        pub fn claim_vested_emission(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_claim_vested_emission(origin, hotkey, netuid)
+        }
+
+        /// --- Authorizes a weights key to set, commit and reveal weights on behalf of a hotkey,
+        /// so the hotkey can be kept in cold storage. Replaces the previous weights key of the
+        /// hotkey, or revokes it when `weights_key` is `None`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey weights are set for.
+        ///
+        /// * 'weights_key' (Option<T::AccountId>):
+        /// 	- The key allowed to set weights for the hotkey.
+        ///
+        /// # Raises:
+        ///  * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey.
+        ///
+        ///  * 'InvalidWeightsKey':
+        /// 	- The weights key is a hotkey.
+        ///
+        ///  * 'WeightsKeyInUse':
+        /// 	- The weights key already sets weights for another hotkey.
+        ///
+        #[pallet::call_index(131)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_weights_key(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            weights_key: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_weights_key(origin, hotkey, weights_key)
+        }
+
+        /// --- Proposes to share part of the owner cut of a subnet with the owner of another
+        /// subnet. The agreement takes effect once the owner of the recipient subnet accepts it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owner of the subnet sharing its owner cut.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet sharing its owner cut.
+        ///
+        /// * 'recipient_netuid' (NetUid):
+        /// 	- The subnet whose owner receives the share.
+        ///
+        /// * 'share' (u16):
+        /// 	- The share of the owner cut, normalized to u16::MAX.
+        ///
+        /// # Raises:
+        ///  * 'BadOrigin':
+        /// 	- The caller does not own the subnet.
+        ///
+        ///  * 'InvalidEmissionShare':
+        /// 	- The share is zero or the recipient is the subnet itself.
+        ///
+        ///  * 'EmissionShareAlreadyExists':
+        /// 	- The subnets already have an agreement.
+        ///
+        #[pallet::call_index(132)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_emission_share(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            recipient_netuid: NetUid,
+            share: u16,
+        ) -> DispatchResult {
+            Self::do_propose_emission_share(origin, netuid, recipient_netuid, share)
+        }
+
+        /// --- Accepts a proposal to share part of the owner cut of a subnet.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owner of the recipient subnet.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet sharing its owner cut.
+        ///
+        /// * 'recipient_netuid' (NetUid):
+        /// 	- The subnet whose owner receives the share.
+        ///
+        /// # Raises:
+        ///  * 'BadOrigin':
+        /// 	- The caller does not own the recipient subnet.
+        ///
+        ///  * 'EmissionShareNotFound':
+        /// 	- There is no such proposal.
+        ///
+        ///  * 'EmissionShareTooHigh':
+        /// 	- The agreements of the subnet would share more than its owner cut.
+        ///
+        #[pallet::call_index(133)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(34))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn accept_emission_share(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            recipient_netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_accept_emission_share(origin, netuid, recipient_netuid)
+        }
+
+        /// --- Withdraws a proposal to share part of the owner cut of a subnet, or ends an
+        /// accepted agreement. Only the owner of the recipient subnet can end an agreement.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the owner of either subnet.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet sharing its owner cut.
+        ///
+        /// * 'recipient_netuid' (NetUid):
+        /// 	- The subnet whose owner receives the share.
+        ///
+        /// # Raises:
+        ///  * 'BadOrigin':
+        /// 	- The caller owns neither subnet.
+        ///
+        ///  * 'EmissionShareNotFound':
+        /// 	- There is no such proposal, or the caller cannot end the agreement.
+        ///
+        #[pallet::call_index(134)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn end_emission_share(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            recipient_netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_end_emission_share(origin, netuid, recipient_netuid)
+        }
+
+        /// --- Offers a subnet for sale, replacing its previous offer. The buyer pays the price to
+        /// the seller and takes ownership of the subnet atomically with `accept_subnet_offer`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the subnet owner.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet offered for sale.
+        ///
+        /// * 'price' (TaoCurrency):
+        /// 	- The price of the subnet.
+        ///
+        /// * 'buyer' (Option<T::AccountId>):
+        /// 	- The only coldkey allowed to buy the subnet, anyone if `None`.
+        ///
+        /// # Raises:
+        ///  * 'NotSubnetOwner':
+        /// 	- The caller does not own the subnet.
+        ///
+        ///  * 'CannotSellLeasedSubnet':
+        /// 	- The subnet is leased.
+        ///
+        #[pallet::call_index(135)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn offer_subnet(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            price: TaoCurrency,
+            buyer: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_offer_subnet(origin, netuid, price, buyer)
+        }
+
+        /// --- Withdraws the offer to sell a subnet.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the seller.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet offered for sale.
+        ///
+        /// # Raises:
+        ///  * 'SubnetOfferNotFound':
+        /// 	- The subnet is not offered for sale.
+        ///
+        ///  * 'NotSubnetOwner':
+        /// 	- The caller is not the seller.
+        ///
+        #[pallet::call_index(136)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_subnet_offer(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_cancel_subnet_offer(origin, netuid)
+        }
+
+        /// --- Buys a subnet offered for sale: pays its price to the seller and takes ownership of
+        /// the subnet in one step.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the buyer's coldkey.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet offered for sale.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The buyer's hotkey to become the subnet owner hotkey.
+        ///
+        /// * 'max_price' (TaoCurrency):
+        /// 	- The highest price the buyer is willing to pay.
+        ///
+        /// # Raises:
+        ///  * 'SubnetOfferNotFound':
+        /// 	- The subnet is not offered for sale, or the seller no longer owns it.
+        ///
+        ///  * 'NotSubnetOfferBuyer':
+        /// 	- The subnet is offered to another buyer.
+        ///
+        ///  * 'SubnetOfferPriceTooHigh':
+        /// 	- The price exceeds `max_price`.
+        ///
+        ///  * 'NonAssociatedColdKey':
+        /// 	- The buyer does not own the hotkey.
+        ///
+        #[pallet::call_index(137)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::Yes))]
+        pub fn accept_subnet_offer(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            max_price: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_accept_subnet_offer(origin, netuid, hotkey, max_price)
+        }
+
+        /// --- Sets the account the emission of a hotkey accrues to as stake on the hotkey, instead
+        /// of the coldkey owning the hotkey. Removes it when `recipient` is `None`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey earning the emission.
+        ///
+        /// * 'recipient' (Option<T::AccountId>):
+        /// 	- The account paid the emission of the hotkey.
+        ///
+        /// # Raises:
+        ///  * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey.
+        ///
+        #[pallet::call_index(138)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_emission_recipient(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            recipient: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_emission_recipient(origin, hotkey, recipient)
+        }
+
+        /// --- Registers a hotkey on a subnet by burning TAO paid by the caller, on behalf of the
+        /// coldkey the hotkey is registered to, see `subnets::sponsored_registration`.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the sponsor's coldkey, paying the burn.
+        ///
+        /// * 'netuid' (NetUid):
+        /// 	- The subnet to register on.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey to register.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey owning the hotkey.
+        ///
+        /// * 'consent' (SponsorConsent):
+        /// 	- The deadline and signature of the coldkey consenting to the registration.
+        ///
+        /// # Raises:
+        ///  * 'SponsorConsentExpired':
+        /// 	- The consent is past its deadline.
+        ///
+        ///  * 'InvalidSponsorConsent':
+        /// 	- The consent is not signed by the coldkey for this chain, subnet, hotkey, sponsor and
+        /// 	  the current nonce of the coldkey, or was already used.
+        ///
+        ///  * 'NotEnoughBalanceToStake':
+        /// 	- The sponsor cannot pay the burn.
+        ///
+        #[pallet::call_index(139)]
+        #[pallet::weight((Weight::from_parts(328_400_000, 0)
+		.saturating_add(T::DbWeight::get().reads(51))
+		.saturating_add(T::DbWeight::get().writes(44)), DispatchClass::Normal, Pays::Yes))]
+        pub fn sponsored_burned_register(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            coldkey: T::AccountId,
+            consent: SponsorConsent,
+        ) -> DispatchResult {
+            Self::do_sponsored_burned_registration(origin, netuid, hotkey, coldkey, consent)
+        }
+
+        /// --- Requires a threshold of operators to approve the weights of a hotkey, see
+        /// `subnets::weights_multisig`. Removes the multisig when `operators` is empty.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey owning the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey weights are set for.
+        ///
+        /// * 'operators' (Vec<T::AccountId>):
+        /// 	- The operators allowed to approve weights.
+        ///
+        /// * 'threshold' (u16):
+        /// 	- The number of approvals required to set weights.
+        ///
+        /// # Raises:
+        ///  * 'NonAssociatedColdKey':
+        /// 	- The caller does not own the hotkey.
+        ///
+        ///  * 'InvalidHotkeyMultisig':
+        /// 	- The operators contain duplicates or are too many, or the threshold is out of range.
+        ///
+        #[pallet::call_index(140)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_hotkey_multisig(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            operators: Vec<T::AccountId>,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::do_set_hotkey_multisig(origin, hotkey, operators, threshold)
+        }
+
+        /// --- Approves weights of a multisig hotkey. The weights are set once enough operators
+        /// have approved the same submission.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of an operator of the hotkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The multisig hotkey.
+        ///
+        /// * 'netuid', 'uids', 'values', 'version_key':
+        /// 	- The weights submission, as in `set_weights`.
+        ///
+        /// # Raises:
+        ///  * 'NotHotkeyMultisigOperator':
+        /// 	- The caller is not an operator of the hotkey.
+        ///
+        /// * Any error of `set_weights` when the approval completes the threshold.
+        ///
+        #[pallet::call_index(141)]
+        #[pallet::weight((Weight::from_parts(15_560_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4113))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_weights(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            uids: Vec<u16>,
+            values: Vec<u16>,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_approve_weights(origin, hotkey, netuid, uids, values, version_key)
+        }
+
+        /// --- Sets the caller weights by applying signed changes to its current weights.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        /// 	- The caller, a hotkey who wishes to set their weights.
+        ///
+        /// * `netuid` (u16):
+        /// 	- The network uid we are setting these weights on.
+        ///
+        /// * `deltas` (Vec<(u16, i32)>):
+        /// 	- The changes of the weights, as (uid, change) tuples. Uids whose
+        /// 		weight drops to zero are removed.
+        ///
+        /// * 'version_key' ( u64 ):
+        /// 	- The network version key to check if the validator is up to date.
+        ///
+        /// # Event:
+        /// * WeightsSet;
+        /// 	- On successfully setting the weights on chain.
+        ///
+        /// # Raises:
+        /// * 'DuplicateUids':
+        /// 	- Attempting to change the weight of a uid twice.
+        ///
+        /// * Any error of set_weights for the resulting weights.
+        #[pallet::call_index(142)]
+        #[pallet::weight((Weight::from_parts(15_540_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4111))
+        .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_delta(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            deltas: Vec<(u16, i32)>,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_set_weights_delta(origin, netuid, deltas, version_key)
+        }
+
+        /// Propose to pay TAO from the treasury of a subnet once its stakers approve it.
+        ///
+        /// Only the subnet owner can propose, and a subnet treasury has at most one open spend.
+        #[pallet::call_index(143)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_subnet_treasury_spend(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            dest: T::AccountId,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_propose_subnet_treasury_spend(origin, netuid, dest, amount)
+        }
+
+        /// Vote on the open spend of a subnet treasury with the caller's alpha stake on the subnet.
+        ///
+        /// The vote that brings the ayes to the quorum of subnet proposals, while outnumbering
+        /// the nays, pays the spend.
+        #[pallet::call_index(144)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_subnet_treasury_spend(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            spend_id: SubnetProposalId,
+            aye: bool,
+        ) -> DispatchResult {
+            Self::do_vote_subnet_treasury_spend(origin, netuid, spend_id, aye)
+        }
+
+        /// Set the friends able to recover the caller's coldkey, how many of them must vouch for
+        /// a recovery, and the blocks between the first vouch and the claim.
+        ///
+        /// No friends disable the recovery and cancel the recoveries in progress.
+        #[pallet::call_index(145)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_coldkey_recovery(
+            origin: OriginFor<T>,
+            friends: Vec<T::AccountId>,
+            threshold: u16,
+            delay: u64,
+        ) -> DispatchResult {
+            Self::do_set_coldkey_recovery(origin, friends, threshold, delay)
+        }
+
+        /// Vouch, as a friend of a lost coldkey, for recovering it to a new coldkey.
+        #[pallet::call_index(146)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vouch_coldkey_recovery(
+            origin: OriginFor<T>,
+            lost_coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_vouch_coldkey_recovery(origin, lost_coldkey, new_coldkey)
+        }
+
+        /// Claim, as the new coldkey, the recovery of a lost coldkey once enough friends vouched
+        /// and the delay passed, moving all the state of the lost coldkey to the caller.
+        #[pallet::call_index(147)]
+        #[pallet::weight((Weight::from_parts(161_700_000, 0)
+		.saturating_add(T::DbWeight::get().reads(16))
+		.saturating_add(T::DbWeight::get().writes(12)), DispatchClass::Normal, Pays::Yes))]
+        pub fn claim_coldkey_recovery(
+            origin: OriginFor<T>,
+            lost_coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_claim_coldkey_recovery(origin, lost_coldkey)
+        }
+
+        /// Cancel, as the coldkey being recovered, its recovery to a new coldkey.
+        #[pallet::call_index(148)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_coldkey_recovery(
+            origin: OriginFor<T>,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_cancel_coldkey_recovery(origin, new_coldkey)
+        }
+
+        /// Start dissolving a subnet, as its owner or root.
+        ///
+        /// The stakes on the subnet are converted back to TAO at the pool price and paid to their
+        /// coldkeys, and the subnet's storage is removed, over the following blocks. The lock is
+        /// refunded to the owner, unless the owner dissolves the subnet during its immunity
+        /// period, in which case it is recycled.
+        #[pallet::call_index(149)]
+        #[pallet::weight((Weight::from_parts(38_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(9))
+		.saturating_add(T::DbWeight::get().writes(6)), DispatchClass::Operational, Pays::Yes))]
+        pub fn dissolve_subnet(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_dissolve_subnet(origin, netuid)
+        }
+
+        /// ---- Deposits TAO from the caller's coldkey into an EVM account.
+        ///
+        /// The TAO is credited to the account holding the balance of the EVM address, which only
+        /// the EVM address controls.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the caller's coldkey.
+        ///
+        /// * `evm_address` (H160):
+        ///     - The EVM address to credit.
+        ///
+        /// * `amount` (TaoCurrency):
+        ///     - The amount of TAO to deposit.
+        ///
+        /// # Raises:
+        /// * `InsufficientBalance`:
+        ///     - Thrown if the coldkey does not hold the amount.
+        #[pallet::call_index(150)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn deposit_to_evm(
+            origin: OriginFor<T>,
+            evm_address: H160,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_deposit_to_evm(origin, evm_address, amount)
+        }
+
+        /// ---- Withdraws TAO from an EVM account into the withdrawal queue of a coldkey.
+        ///
+        /// Called by the balance transfer precompile on behalf of the EVM address. The TAO can be
+        /// claimed by the coldkey with `claim_evm_withdrawals` once [`EvmWithdrawalDelay`] passed.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the account holding the balance of the EVM address.
+        ///
+        /// * `evm_address` (H160):
+        ///     - The EVM address to debit.
+        ///
+        /// * `coldkey` (T::AccountId):
+        ///     - The coldkey to release the TAO to.
+        ///
+        /// * `amount` (TaoCurrency):
+        ///     - The amount of TAO to withdraw.
+        ///
+        /// # Raises:
+        /// * `EvmAddressNotCaller`:
+        ///     - Thrown if the EVM address does not map to the caller.
+        ///
+        /// * `InsufficientBalance`:
+        ///     - Thrown if the EVM account does not hold the amount.
+        #[pallet::call_index(151)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn withdraw_from_evm(
+            origin: OriginFor<T>,
+            evm_address: H160,
+            coldkey: T::AccountId,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_withdraw_from_evm(origin, evm_address, coldkey, amount)
+        }
+
+        /// ---- Claims every chunk of the caller's EVM withdrawal queue whose delay has passed.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the caller's coldkey.
+        ///
+        /// # Raises:
+        /// * `NoReleasedEvmWithdrawal`:
+        ///     - Thrown if none of the caller's withdrawn TAO has been released yet.
+        #[pallet::call_index(152)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn claim_evm_withdrawals(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_claim_evm_withdrawals(origin)
+        }
+
+        /// ---- Names the EVM account paying the gas of the contracts sponsored by a subnet.
+        ///
+        /// The sponsor signs, as an EIP-191 personal message, the message returned by
+        /// `get_evm_gas_sponsor_consent_message` for the subnet, the sponsor, the cap and the
+        /// deadline.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet sponsoring the gas.
+        ///
+        /// * `sponsor` (H160):
+        ///     - The EVM account paying the gas.
+        ///
+        /// * `cap` (TaoCurrency):
+        ///     - The most TAO the sponsor pays per period.
+        ///
+        /// * `deadline` (u64):
+        ///     - The last block the consent of the sponsor can be used at.
+        ///
+        /// * `signature` (Signature):
+        ///     - The signature of the sponsor over the consent message.
+        ///
+        /// # Raises:
+        /// * `SponsorConsentExpired`:
+        ///     - Thrown if the deadline has passed.
+        ///
+        /// * `InvalidSponsorConsent`:
+        ///     - Thrown if the consent is not signed by the sponsor for this chain and its current
+        ///       nonce, or was already used.
+        #[pallet::call_index(153)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_evm_gas_sponsor(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            sponsor: H160,
+            cap: TaoCurrency,
+            deadline: u64,
+            signature: Signature,
+        ) -> DispatchResult {
+            Self::do_set_evm_gas_sponsor(origin, netuid, sponsor, cap, deadline, signature)
+        }
+
+        /// ---- Stops the sponsor of a subnet paying the gas of its contracts.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet sponsoring the gas.
+        ///
+        /// # Raises:
+        /// * `NoEvmGasSponsor`:
+        ///     - Thrown if the subnet has no sponsor.
+        #[pallet::call_index(154)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn remove_evm_gas_sponsor(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_remove_evm_gas_sponsor(origin, netuid)
+        }
+
+        /// ---- Adds a contract to, or removes it from, the contracts whose gas is paid by the
+        /// sponsor of a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet sponsoring the gas.
+        ///
+        /// * `contract` (H160):
+        ///     - The address of the contract.
+        ///
+        /// * `sponsored` (bool):
+        ///     - Whether the gas of calls to the contract is sponsored.
+        ///
+        /// # Raises:
+        /// * `EvmContractSponsoredByOtherSubnet`:
+        ///     - Thrown if another subnet sponsors the gas of the contract.
+        #[pallet::call_index(155)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_evm_sponsored_contract(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            contract: H160,
+            sponsored: bool,
+        ) -> DispatchResult {
+            Self::do_set_evm_sponsored_contract(origin, netuid, contract, sponsored)
+        }
+
+        /// --- Sets whether the dividends a hotkey earns for the calling coldkey are restaked, see
+        /// `staking::auto_compound`. They are by default.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>Origin):
+        ///     - The signature of the nominating coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey the coldkey nominates.
+        ///
+        /// * `auto_compound` (bool):
+        ///     - Whether the dividends of the hotkey are restaked, or credited as TAO.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - Thrown if the hotkey does not exist.
+        ///
+        /// * `TooManyAutoCompoundOptOuts`:
+        ///     - Thrown if too many nominators of the hotkey do not restake its dividends.
+        #[pallet::call_index(156)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(66))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            auto_compound: bool,
+        ) -> DispatchResult {
+            Self::do_set_auto_compound(origin, hotkey, auto_compound)
+        }
     }
 }
@@ -140,6 +140,10 @@ mod errors {
         AlphaHighTooLow,
         /// Alpha low is out of range: alpha_low > 0 && alpha_low < 0.8
         AlphaLowOutOfRange,
+        /// Alpha high is above the maximum set by root.
+        AlphaHighTooHigh,
+        /// The minimum liquid alpha low is above the maximum liquid alpha high.
+        InvalidLiquidAlphaBounds,
         /// The coldkey has already been swapped
         ColdKeyAlreadyAssociated,
         /// The coldkey balance is not enough to pay for the swap
@@ -160,6 +164,84 @@ mod errors {
         SwapAlreadyScheduled,
         /// failed to swap coldkey
         FailedToSchedule,
+        /// No coldkey swap is scheduled for this coldkey.
+        SwapNotScheduled,
+        /// Failed to cancel the scheduled coldkey swap.
+        FailedToCancelSchedule,
+        /// The stake split is empty, too long, has duplicate hotkeys or zero proportions.
+        InvalidStakeSplit,
+        /// The stake would exceed the maximum total stake accepted by the delegate.
+        DelegateStakeCapExceeded,
+        /// The delegate does not accept stake from this coldkey.
+        DelegateStakingNotAllowed,
+        /// Too many coldkeys in a single delegate staking list update.
+        TooManyDelegateStakingListUpdates,
+        /// The subnet owner cut is outside the allowed bounds.
+        SubnetOwnerCutOutOfBounds,
+        /// The minimum subnet owner cut is above the maximum.
+        InvalidSubnetOwnerCutBounds,
+        /// The subnet validator stake threshold is outside the allowed bounds.
+        SubnetStakeThresholdOutOfBounds,
+        /// The minimum subnet validator stake threshold is above the maximum.
+        InvalidSubnetStakeThresholdBounds,
+        /// Registration on this subnet is by auction, use bid_registration.
+        RegistrationAuctionActive,
+        /// Registration auctions are not enabled on this subnet.
+        RegistrationAuctionNotEnabled,
+        /// The registration bid is below the subnet burn or the current bid for this hotkey.
+        RegistrationBidTooLow,
+        /// The subnet has reached the maximum number of open registration bids.
+        TooManyRegistrationBids,
+        /// The subnet is in maintenance mode.
+        SubnetInMaintenance,
+        /// The root subnet cannot be put into maintenance mode.
+        CannotPauseRootSubnet,
+        /// The offense evidence does not contain two conflicting statements.
+        InvalidOffenseEvidence,
+        /// A statement of the offense evidence is not signed by the hotkey.
+        InvalidOffenseSignature,
+        /// The offense has already been reported and punished.
+        OffenseAlreadyReported,
+        /// The operation would take locked stake out of the hotkey.
+        StakeLocked,
+        /// The stake lock has not reached its unlock block.
+        StakeLockNotExpired,
+        /// There is no stake lock on the hotkey and subnet.
+        NoStakeLock,
+        /// The subnet owner cannot pay the subnet identity fee.
+        NotEnoughBalanceToPaySubnetIdentityFee,
+        /// An axon must serve at least one endpoint.
+        NoAxonEndpoints,
+        /// An axon serves more than `MAX_AXON_ENDPOINTS` endpoints.
+        TooManyAxonEndpoints,
+        /// The subnet owner hotkey cannot leave the subnet.
+        CannotUnregisterSubnetOwnerHotkey,
+        /// The subnet already has an open proposal.
+        SubnetProposalAlreadyOpen,
+        /// The proposal is not the open proposal of the subnet.
+        SubnetProposalNotFound,
+        /// The proposal no longer accepts votes.
+        SubnetProposalExpired,
+        /// The coldkey already voted on the proposal.
+        AlreadyVotedOnSubnetProposal,
+        /// The coldkey has no stake on the subnet to vote with.
+        NotEnoughStakeToVote,
+        /// The proposed call is heavier than a vote can execute.
+        SubnetProposalCallTooHeavy,
+        /// The subnet proposal quorum must be greater than zero.
+        InvalidSubnetProposalQuorum,
+        /// The subnet treasury already has an open spend.
+        SubnetTreasurySpendAlreadyOpen,
+        /// The spend is not the open spend of the subnet treasury.
+        SubnetTreasurySpendNotFound,
+        /// The coldkey has no unbonded TAO ready to withdraw.
+        NoUnbondedStake,
+        /// The unbonding period exceeds the maximum allowed.
+        UnbondingPeriodTooLong,
+        /// The miner emission vesting period exceeds the maximum allowed.
+        MinerEmissionVestingPeriodTooLong,
+        /// The hotkey has no vesting miner emission on the subnet.
+        NoVestingEmission,
         /// New coldkey is hotkey
         NewColdKeyIsHotkey,
         /// Childkey take is invalid.
@@ -244,5 +326,72 @@ mod errors {
         SymbolAlreadyInUse,
         /// Incorrect commit-reveal version.
         IncorrectCommitRevealVersion,
+        /// A weights key cannot be a hotkey.
+        InvalidWeightsKey,
+        /// The weights key already sets weights for another hotkey.
+        WeightsKeyInUse,
+        /// An emission share must be positive and go to another subnet.
+        InvalidEmissionShare,
+        /// The subnets already have an emission sharing agreement.
+        EmissionShareAlreadyExists,
+        /// The emission sharing proposal or agreement does not exist.
+        EmissionShareNotFound,
+        /// The emission shares of a subnet exceed its owner cut.
+        EmissionShareTooHigh,
+        /// A leased subnet cannot be sold.
+        CannotSellLeasedSubnet,
+        /// The subnet is not offered for sale.
+        SubnetOfferNotFound,
+        /// The subnet is offered to another buyer.
+        NotSubnetOfferBuyer,
+        /// The price of the subnet exceeds the price the buyer is willing to pay.
+        SubnetOfferPriceTooHigh,
+        /// The consent to a sponsored registration or EVM gas sponsorship is not signed by the
+        /// consenting account.
+        InvalidSponsorConsent,
+        /// The consent to a sponsored registration or EVM gas sponsorship is past its deadline.
+        SponsorConsentExpired,
+        /// The operators or threshold of a multisig hotkey are invalid.
+        InvalidHotkeyMultisig,
+        /// The caller is not an operator of the multisig hotkey.
+        NotHotkeyMultisigOperator,
+        /// The weights of a multisig hotkey must be approved by its operators.
+        WeightsRequireMultisig,
+        /// The friends, threshold or new coldkey of a coldkey recovery are invalid.
+        InvalidColdkeyRecovery,
+        /// The coldkey has no friends able to recover it.
+        ColdkeyRecoveryNotSet,
+        /// The caller is not a friend able to recover the coldkey.
+        NotColdkeyRecoveryFriend,
+        /// The friend already vouched for the recovery.
+        AlreadyVouchedColdkeyRecovery,
+        /// The coldkey is not being recovered to the new coldkey.
+        ColdkeyRecoveryNotFound,
+        /// The recovery lacks vouches or its delay has not passed.
+        ColdkeyRecoveryNotReady,
+        /// The max UIDs of the root subnet cannot be grown.
+        CannotGrowRootSubnetUids,
+        /// The new max UIDs is not above the current max UIDs.
+        MaxAllowedUidsNotIncreased,
+        /// The new max UIDs exceeds the limit or grows the subnet by too much at once.
+        MaxAllowedUidsGrowthTooLarge,
+        /// The root subnet cannot be dissolved.
+        CannotDissolveRootSubnet,
+        /// The subnet is already being dissolved.
+        NetworkDissolutionInProgress,
+        /// The weight commit hash has already been committed and is not revealed yet.
+        DuplicateWeightCommit,
+        /// The EVM address does not map to the caller.
+        EvmAddressNotCaller,
+        /// The coldkey has no TAO withdrawn from the EVM ready to claim.
+        NoReleasedEvmWithdrawal,
+        /// The EVM withdrawal delay exceeds the maximum allowed.
+        EvmWithdrawalDelayTooLong,
+        /// The subnet has no EVM gas sponsor.
+        NoEvmGasSponsor,
+        /// The gas of calls to the contract is sponsored by another subnet.
+        EvmContractSponsoredByOtherSubnet,
+        /// The hotkey has too many nominators that do not auto-compound its dividends.
+        TooManyAutoCompoundOptOuts,
     }
 }
@@ -7,7 +7,7 @@ mod events {
     use codec::Compact;
 
     #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    #[pallet::generate_deposit(pub(super) fn deposit_pallet_event)]
     pub enum Event<T: Config> {
         /// a new network is added.
         NetworkAdded(NetUid, u16),
@@ -62,6 +62,18 @@ mod events {
         MaxRegistrationsPerBlockSet(NetUid, u16),
         /// an activity cutoff is set for a subnet.
         ActivityCutoffSet(NetUid, u16),
+        /// registration auctions are enabled or disabled for a subnet.
+        RegistrationAuctionEnabledSet(NetUid, bool),
+        /// the unbonding period is set for a subnet.
+        UnbondingPeriodSet(NetUid, u64),
+        /// the miner emission vesting period is set for a subnet.
+        MinerEmissionVestingPeriodSet(NetUid, u64),
+        /// the maximum weight age is set for a subnet.
+        MaxWeightAgeSet(NetUid, u64),
+        /// the transaction fee is set for a subnet.
+        SubnetTransactionFeeSet(NetUid, TaoCurrency),
+        /// the items processed per block by the epoch are set for a subnet.
+        MultiBlockEpochChunkSizeSet(NetUid, u16),
         /// Rho value is set.
         RhoSet(NetUid, u16),
         /// steepness of the sigmoid used to compute alpha values.
@@ -84,6 +96,8 @@ mod events {
         BondsPenaltySet(NetUid, u16),
         /// bonds reset is set for a subnet.
         BondsResetOnSet(NetUid, bool),
+        /// bonds reset on deregistration is set for a subnet.
+        BondsResetOnDeregistrationSet(NetUid, bool),
         /// setting the max number of allowed validators on a subnet.
         MaxAllowedValidatorsSet(NetUid, u16),
         /// the axon server information is added to the network.
@@ -96,6 +110,22 @@ mod events {
         DefaultTakeSet(u16),
         /// weights version key is set for a network.
         WeightsVersionKeySet(NetUid, u64),
+        /// A new weights version key is scheduled for a network, the current one stays accepted
+        /// until it applies.
+        WeightsVersionKeyScheduled {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The new weights version key.
+            version_key: u64,
+            /// The block at which it applies.
+            effective_block: u64,
+        },
+        /// The grace period of new weights version keys is set for a network.
+        WeightsVersionKeyGracePeriodSet(NetUid, u64),
+        /// the number of blocks before an epoch in which validator weights get priority is set.
+        WeightsPriorityWindowSet(u64),
+        /// the share of the block weight registration calls are priced to fill is set.
+        TargetRegistrationBlockFullnessSet(Perbill),
         /// setting min difficulty on a network.
         MinDifficultySet(NetUid, u64),
         /// setting max difficulty on a network.
@@ -140,6 +170,16 @@ mod events {
         Faucet(T::AccountId, u64),
         /// the subnet owner cut is set.
         SubnetOwnerCutSet(u16),
+        /// the owner cut for a subnet is set by its owner.
+        SubnetOwnerCutSetForSubnet(NetUid, u16),
+        /// the bounds of the per-subnet owner cut are set (min, max).
+        SubnetOwnerCutBoundsSet(u16, u16),
+        /// the validator stake threshold of a subnet is set.
+        SubnetStakeThresholdSet(NetUid, u64),
+        /// the bounds of the per-subnet validator stake threshold are set (min, max).
+        SubnetStakeThresholdBoundsSet(u64, u64),
+        /// the bounds of the liquid alpha values of subnets are set (min alpha low, max alpha high).
+        LiquidAlphaBoundsSet(u16, u16),
         /// the network creation rate limit is set.
         NetworkRateLimitSet(u64),
         /// the network immunity period is set.
@@ -154,6 +194,24 @@ mod events {
         TakeDecreased(T::AccountId, T::AccountId, u16),
         /// the take for a delegate is increased.
         TakeIncreased(T::AccountId, T::AccountId, u16),
+        /// a take increase for a delegate is scheduled (coldkey, hotkey, take, effective_block).
+        TakeIncreaseScheduled(T::AccountId, T::AccountId, u16, u64),
+        /// the delegate take notice period is set.
+        DelegateTakeNoticePeriodSet(u64),
+        /// the stake cap for a delegate is set (coldkey, hotkey, cap), `None` removes the cap.
+        DelegateStakeCapSet(T::AccountId, T::AccountId, Option<TaoCurrency>),
+        /// the staking access mode for a delegate is set (coldkey, hotkey, access), `None` lets
+        /// every coldkey stake.
+        DelegateStakingAccessSet(T::AccountId, T::AccountId, Option<DelegateStakingAccess>),
+        /// coldkeys are added to or removed from the staking list of a delegate.
+        DelegateStakingListUpdated {
+            /// The delegate hotkey
+            hotkey: T::AccountId,
+            /// The coldkeys added or removed
+            coldkeys: Vec<T::AccountId>,
+            /// Whether the coldkeys are added to the list
+            listed: bool,
+        },
         /// the hotkey is swapped
         HotkeySwapped {
             /// the account ID of coldkey
@@ -205,6 +263,410 @@ mod events {
             /// The swap cost
             swap_cost: TaoCurrency,
         },
+        /// A registration bid has been placed or raised.
+        RegistrationBidPlaced {
+            /// The subnet the bid is for
+            netuid: NetUid,
+            /// The coldkey paying the bid
+            coldkey: T::AccountId,
+            /// The hotkey to register
+            hotkey: T::AccountId,
+            /// The bid amount
+            bid: TaoCurrency,
+        },
+        /// A losing or invalidated registration bid has been refunded.
+        RegistrationBidRefunded {
+            /// The subnet the bid was for
+            netuid: NetUid,
+            /// The coldkey receiving the refund
+            coldkey: T::AccountId,
+            /// The hotkey of the bid
+            hotkey: T::AccountId,
+            /// The refunded amount
+            bid: TaoCurrency,
+        },
+        /// A subnet entered or left maintenance mode.
+        SubnetMaintenanceSet {
+            /// The subnet
+            netuid: NetUid,
+            /// Whether the subnet is in maintenance
+            enabled: bool,
+        },
+        /// A subnet left maintenance mode after `MaxSubnetMaintenanceDuration` blocks.
+        SubnetMaintenanceExpired(NetUid),
+        /// The maximum duration of subnet maintenance is set.
+        MaxSubnetMaintenanceDurationSet(u64),
+        /// A hotkey was slashed for a reported offense.
+        OffenderSlashed {
+            /// The subnet of the offense
+            netuid: NetUid,
+            /// The offending hotkey
+            hotkey: T::AccountId,
+            /// The kind of offense
+            kind: WeightOffenseKind,
+            /// The epoch of the offense
+            epoch: u64,
+            /// The account that reported the offense
+            reporter: T::AccountId,
+            /// The alpha slashed from the hotkey and recycled
+            slashed: AlphaCurrency,
+        },
+        /// The share of stake slashed for an offense is set.
+        OffenseSlashFractionSet(u16),
+        /// The weights key of a hotkey was set or revoked.
+        WeightsKeySet {
+            /// The coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The key allowed to set weights for the hotkey, `None` if revoked
+            weights_key: Option<T::AccountId>,
+        },
+        /// A subnet owner proposed to share its owner cut with the owner of another subnet.
+        EmissionShareProposed {
+            /// The subnet sharing its owner cut
+            netuid: NetUid,
+            /// The subnet whose owner receives the share
+            recipient_netuid: NetUid,
+            /// The share of the owner cut, normalized to u16::MAX
+            share: u16,
+        },
+        /// An emission sharing proposal was accepted by the owner of the recipient subnet.
+        EmissionShareAccepted {
+            /// The subnet sharing its owner cut
+            netuid: NetUid,
+            /// The subnet whose owner receives the share
+            recipient_netuid: NetUid,
+            /// The share of the owner cut, normalized to u16::MAX
+            share: u16,
+        },
+        /// An emission sharing proposal was withdrawn or an agreement ended.
+        EmissionShareEnded {
+            /// The subnet sharing its owner cut
+            netuid: NetUid,
+            /// The subnet whose owner receives the share
+            recipient_netuid: NetUid,
+        },
+        /// The emission of a subnet, or of all subnets when `netuid` is `None`, exceeded the
+        /// schedule and its distribution is halted until root resets the circuit breaker.
+        EmissionCircuitBreakerTripped {
+            /// The subnet, `None` for the global breaker
+            netuid: Option<NetUid>,
+            /// The emission expected from the schedule
+            expected: u64,
+            /// The emission of the block
+            emitted: u64,
+        },
+        /// The emission circuit breaker of a subnet, or the global one when `None`, is reset.
+        EmissionCircuitBreakerReset(Option<NetUid>),
+        /// The emission circuit breaker threshold is set, in percent of the expected emission.
+        EmissionCircuitBreakerThresholdSet(u32),
+        /// The gains (kp, ki, kd) of the PID controller of the burn are set for a subnet.
+        BurnControllerGainsSet(NetUid, u16, u16, u16),
+        /// The hash of the registration proof of work is set for a subnet.
+        RegistrationPowAlgorithmSet(NetUid, RegistrationPowAlgorithm),
+        /// A subnet was offered for sale.
+        SubnetOffered {
+            /// The subnet
+            netuid: NetUid,
+            /// The owner selling the subnet
+            seller: T::AccountId,
+            /// The price of the subnet
+            price: TaoCurrency,
+            /// The only coldkey allowed to buy the subnet, if any
+            buyer: Option<T::AccountId>,
+        },
+        /// The offer to sell a subnet was withdrawn.
+        SubnetOfferCancelled {
+            /// The subnet
+            netuid: NetUid,
+            /// The owner selling the subnet
+            seller: T::AccountId,
+        },
+        /// A subnet was sold and its ownership transferred to the buyer.
+        SubnetSold {
+            /// The subnet
+            netuid: NetUid,
+            /// The previous owner of the subnet
+            seller: T::AccountId,
+            /// The new owner of the subnet
+            buyer: T::AccountId,
+            /// The price paid to the seller
+            price: TaoCurrency,
+        },
+        /// A schedule matching the TAO emission of a subnet is set.
+        IncentiveMatchingSet {
+            /// The subnet
+            netuid: NetUid,
+            /// The most TAO the schedule matches
+            cap: TaoCurrency,
+            /// The block after which the schedule no longer matches
+            end_block: u64,
+        },
+        /// The schedule matching the TAO emission of a subnet is removed.
+        IncentiveMatchingRemoved(NetUid),
+        /// The schedule matching the TAO emission of a subnet has ended.
+        IncentiveMatchingEnded {
+            /// The subnet
+            netuid: NetUid,
+            /// The TAO matched by the schedule
+            matched: TaoCurrency,
+        },
+        /// The emission recipient of a hotkey was set or removed.
+        EmissionRecipientSet {
+            /// The coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The account paid the emission of the hotkey, `None` if removed
+            recipient: Option<T::AccountId>,
+        },
+        /// A neuron was registered with the burn paid by a sponsor.
+        NeuronRegistrationSponsored {
+            /// The subnet
+            netuid: NetUid,
+            /// The registered hotkey
+            hotkey: T::AccountId,
+            /// The coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// The coldkey that paid the burn
+            sponsor: T::AccountId,
+            /// The burn paid
+            burn: TaoCurrency,
+        },
+        /// The multisig of a hotkey was set, or removed when it has no operators.
+        HotkeyMultisigSet {
+            /// The coldkey owning the hotkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The operators approving the weights of the hotkey
+            operators: Vec<T::AccountId>,
+            /// The number of approvals required to set weights
+            threshold: u16,
+        },
+        /// An operator approved weights of a multisig hotkey.
+        WeightsApproved {
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// The approving operator
+            operator: T::AccountId,
+            /// The subnet of the weights
+            netuid: NetUid,
+            /// The approvals of the weights so far
+            approvals: u16,
+        },
+        /// A coldkey set the friends able to recover it.
+        ColdkeyRecoverySet {
+            /// The coldkey
+            coldkey: T::AccountId,
+            /// The friends, none disabling the recovery
+            friends: Vec<T::AccountId>,
+            /// The vouches required to recover
+            threshold: u16,
+            /// The blocks between the first vouch and the claim
+            delay: u64,
+        },
+        /// A friend vouched for recovering a coldkey.
+        ColdkeyRecoveryVouched {
+            /// The coldkey being recovered
+            lost_coldkey: T::AccountId,
+            /// The coldkey replacing it
+            new_coldkey: T::AccountId,
+            /// The vouching friend
+            friend: T::AccountId,
+            /// The vouches so far
+            vouches: u16,
+        },
+        /// A coldkey cancelled its recovery.
+        ColdkeyRecoveryCancelled {
+            /// The coldkey being recovered
+            lost_coldkey: T::AccountId,
+            /// The coldkey that would have replaced it
+            new_coldkey: T::AccountId,
+        },
+        /// A coldkey was recovered, moving all its state to the new coldkey.
+        ColdkeyRecovered {
+            /// The recovered coldkey
+            lost_coldkey: T::AccountId,
+            /// The coldkey replacing it
+            new_coldkey: T::AccountId,
+        },
+        /// A subnet transaction fee was paid to the subnet treasury.
+        SubnetTransactionFeePaid {
+            /// The subnet
+            netuid: NetUid,
+            /// The signer of the extrinsic
+            who: T::AccountId,
+            /// The fee paid
+            fee: TaoCurrency,
+        },
+        /// A coldkey was exempted from the staking operation rate limit.
+        StakingRateLimitExemptionGranted(T::AccountId),
+        /// A coldkey lost its exemption from the staking operation rate limit.
+        StakingRateLimitExemptionRevoked(T::AccountId),
+        /// Stake was added and locked for boosted weight.
+        StakeLocked {
+            /// The coldkey locking the stake
+            coldkey: T::AccountId,
+            /// The hotkey staked to
+            hotkey: T::AccountId,
+            /// The subnet
+            netuid: NetUid,
+            /// The alpha locked
+            alpha: AlphaCurrency,
+            /// The lock period
+            period: StakeLockPeriod,
+            /// The block from which the lock can be lifted
+            unlock_block: u64,
+        },
+        /// An expired stake lock was lifted.
+        StakeUnlocked {
+            /// The coldkey of the lock
+            coldkey: T::AccountId,
+            /// The hotkey of the lock
+            hotkey: T::AccountId,
+            /// The subnet
+            netuid: NetUid,
+            /// The alpha unlocked
+            alpha: AlphaCurrency,
+        },
+        /// The boost of a stake lock period is set.
+        StakeLockBoostSet(StakeLockPeriod, u16),
+        /// The vested miner emission of a hotkey was claimed and staked.
+        VestedEmissionClaimed {
+            /// The subnet
+            netuid: NetUid,
+            /// The hotkey of the miner
+            hotkey: T::AccountId,
+            /// The alpha staked
+            alpha: AlphaCurrency,
+        },
+        /// The unvested miner emission of a hotkey leaving a subnet was withheld and recycled.
+        VestingEmissionWithheld {
+            /// The subnet
+            netuid: NetUid,
+            /// The hotkey of the miner
+            hotkey: T::AccountId,
+            /// The alpha recycled
+            alpha: AlphaCurrency,
+        },
+        /// The fee to set a subnet identity is set.
+        SubnetIdentityFeeSet(TaoCurrency),
+        /// The logo hash of a subnet identity is set or removed.
+        SubnetLogoHashSet(NetUid, Option<H256>),
+        /// A neuron voluntarily left a subnet, freeing its uid.
+        NeuronUnregistered {
+            /// The subnet left
+            netuid: NetUid,
+            /// The freed uid
+            uid: u16,
+            /// The hotkey that held the uid
+            hotkey: T::AccountId,
+            /// The part of the registration burn refunded to the coldkey
+            refund: TaoCurrency,
+        },
+        /// The share of the registration burn refunded on unregister is set.
+        UnregisterRefundFractionSet(u16),
+        /// A subnet owner opened a proposal for the subnet's stakers to vote on.
+        SubnetProposalOpened {
+            /// The subnet of the proposal
+            netuid: NetUid,
+            /// The id of the proposal
+            proposal_id: SubnetProposalId,
+            /// The subnet owner who opened it
+            proposer: T::AccountId,
+        },
+        /// A staker voted on a subnet proposal.
+        SubnetProposalVoted {
+            /// The subnet of the proposal
+            netuid: NetUid,
+            /// The id of the proposal
+            proposal_id: SubnetProposalId,
+            /// The voting coldkey
+            coldkey: T::AccountId,
+            /// Whether the vote is for the proposal
+            aye: bool,
+            /// The alpha stake the vote counts with
+            stake: AlphaCurrency,
+        },
+        /// A subnet proposal reached quorum and its call was dispatched.
+        SubnetProposalExecuted {
+            /// The subnet of the proposal
+            netuid: NetUid,
+            /// The id of the proposal
+            proposal_id: SubnetProposalId,
+            /// The result of the dispatched call
+            result: DispatchResult,
+        },
+        /// The quorum of subnet proposals is set.
+        SubnetProposalQuorumSet(u16),
+        /// The voting duration of subnet proposals is set.
+        SubnetProposalDurationSet(u64),
+        /// The share of the owner cut paid to the treasury is set for a subnet.
+        SubnetTreasuryShareSet(NetUid, u16),
+        /// A subnet owner proposed a spend of the subnet treasury.
+        SubnetTreasurySpendProposed {
+            /// The subnet of the treasury
+            netuid: NetUid,
+            /// The id of the spend
+            spend_id: SubnetProposalId,
+            /// The account to pay
+            dest: T::AccountId,
+            /// The TAO to pay
+            amount: TaoCurrency,
+        },
+        /// A staker voted on a spend of the subnet treasury.
+        SubnetTreasurySpendVoted {
+            /// The subnet of the treasury
+            netuid: NetUid,
+            /// The id of the spend
+            spend_id: SubnetProposalId,
+            /// The voting coldkey
+            coldkey: T::AccountId,
+            /// Whether the vote is for the spend
+            aye: bool,
+            /// The alpha stake the vote counts with
+            stake: AlphaCurrency,
+        },
+        /// A spend of the subnet treasury reached quorum and was paid.
+        SubnetTreasurySpent {
+            /// The subnet of the treasury
+            netuid: NetUid,
+            /// The id of the spend
+            spend_id: SubnetProposalId,
+            /// The account paid
+            dest: T::AccountId,
+            /// The TAO paid
+            amount: TaoCurrency,
+            /// The result of the payment
+            result: DispatchResult,
+        },
+        /// Unstaked TAO has been placed in the coldkey's unbonding queue.
+        StakeUnbonding {
+            /// The coldkey the TAO will be released to
+            coldkey: T::AccountId,
+            /// The subnet the stake was removed from
+            netuid: NetUid,
+            /// The amount of TAO unbonding
+            amount: TaoCurrency,
+            /// The block from which the TAO can be withdrawn
+            release_block: u64,
+        },
+        /// Unbonded TAO has been withdrawn to the coldkey's free balance.
+        UnbondedWithdrawn {
+            /// The coldkey receiving the TAO
+            coldkey: T::AccountId,
+            /// The amount of TAO withdrawn
+            amount: TaoCurrency,
+        },
+        /// A scheduled coldkey swap has been cancelled
+        ColdkeySwapCancelled {
+            /// The account ID of the coldkey that scheduled the swap
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey the swap would have moved to
+            new_coldkey: T::AccountId,
+        },
         /// The arbitration period has been extended
         ArbitrationPeriodExtended {
             /// The account ID of the coldkey
@@ -399,5 +861,139 @@ mod events {
         ///
         /// - **version**: The required version.
         CommitRevealVersionSet(u16),
+
+        /// Root paused or resumed a call of this pallet.
+        CallDisabledSet {
+            /// The index of the call.
+            call_index: u8,
+            /// Whether the call is paused.
+            disabled: bool,
+        },
+
+        /// The dissolution of a subnet has started.
+        NetworkDissolutionStarted {
+            /// The subnet ID
+            netuid: NetUid,
+            /// Whether the lock is refunded to the owner, or recycled.
+            refund_lock: bool,
+        },
+
+        /// A subnet has been dissolved.
+        NetworkDissolved {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The TAO paid to the stakers for their alpha.
+            stake_refunded: TaoCurrency,
+            /// The lock refunded to the owner.
+            lock_refunded: TaoCurrency,
+        },
+
+        /// TAO moved between two accounts of the pallet's ledger.
+        TaoTransferred {
+            /// The account debited.
+            from: LedgerAccount<T::AccountId>,
+            /// The account credited.
+            to: LedgerAccount<T::AccountId>,
+            /// The TAO moved.
+            amount: TaoCurrency,
+            /// Why the TAO moved.
+            reason: LedgerReason,
+        },
+
+        /// The emission of an epoch was paid out on a subnet.
+        EmissionDistributed {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The alpha paid out of the owner cut.
+            owner_cut: AlphaCurrency,
+            /// The alpha paid to miners as incentive.
+            incentives: AlphaCurrency,
+            /// The alpha paid to validators and their nominators as dividends.
+            alpha_dividends: AlphaCurrency,
+            /// The TAO paid to root validators and their nominators as dividends.
+            tao_dividends: TaoCurrency,
+        },
+
+        /// TAO was deposited from a coldkey into an EVM account.
+        EvmDeposited {
+            /// The coldkey the TAO was deposited from
+            coldkey: T::AccountId,
+            /// The EVM address credited
+            evm_address: H160,
+            /// The TAO deposited
+            amount: TaoCurrency,
+        },
+        /// TAO was withdrawn from an EVM account and placed in the coldkey's EVM withdrawal queue.
+        EvmWithdrawalQueued {
+            /// The EVM address debited
+            evm_address: H160,
+            /// The coldkey the TAO will be released to
+            coldkey: T::AccountId,
+            /// The TAO withdrawn
+            amount: TaoCurrency,
+            /// The block from which the TAO can be claimed
+            release_block: u64,
+        },
+        /// TAO withdrawn from the EVM was claimed to the coldkey's free balance.
+        EvmWithdrawalClaimed {
+            /// The coldkey receiving the TAO
+            coldkey: T::AccountId,
+            /// The TAO claimed
+            amount: TaoCurrency,
+        },
+        /// The delay of withdrawals from the EVM is set.
+        EvmWithdrawalDelaySet(u64),
+
+        /// A subnet named the EVM account paying the gas of its sponsored contracts.
+        EvmGasSponsorSet {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The EVM account paying the gas
+            sponsor: H160,
+            /// The most TAO the sponsor pays per period
+            cap: TaoCurrency,
+        },
+        /// A subnet removed the sponsor of the gas of its contracts.
+        EvmGasSponsorRemoved {
+            /// The subnet ID
+            netuid: NetUid,
+        },
+        /// A contract was added to, or removed from, the contracts sponsored by a subnet.
+        EvmSponsoredContractSet {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The address of the contract
+            contract: H160,
+            /// Whether the gas of calls to the contract is sponsored
+            sponsored: bool,
+        },
+        /// The sponsor of a subnet paid the fee of an Ethereum transaction.
+        EvmGasSponsored {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The EVM account that paid the fee
+            sponsor: H160,
+            /// The sender of the transaction
+            sender: H160,
+            /// The fee paid
+            fee: TaoCurrency,
+        },
+
+        /// A weight set among a batch of weights failed on a subnet.
+        ///
+        /// - **who**: The hotkey that set the batch.
+        /// - **netuid**: The netuid the weights were not set on.
+        /// - **error**: The dispatch error emitted by the failed item.
+        BatchWeightItemFailedOnSubnet(T::AccountId, NetUid, sp_runtime::DispatchError),
+
+        /// A coldkey set whether the dividends of a hotkey it nominates are restaked.
+        AutoCompoundSet {
+            /// The nominating coldkey
+            coldkey: T::AccountId,
+            /// The hotkey
+            hotkey: T::AccountId,
+            /// Whether the dividends are restaked
+            auto_compound: bool,
+        },
     }
 }
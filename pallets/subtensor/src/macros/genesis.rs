@@ -107,6 +107,32 @@ mod genesis {
             BlockAtRegistration::<T>::insert(netuid, 0, block_number); // Fill block at registration.
             IsNetworkMember::<T>::insert(hotkey.clone(), netuid, true); // Fill network is member.
             TokenSymbol::<T>::insert(netuid, Pallet::<T>::get_symbol_for_subnet(netuid));
+
+            // Register the genesis subnets as if their owners had registered them, with the
+            // minimum lock as the initial pool.
+            for (owner_coldkey, owner_hotkey, neurons) in &self.subnets {
+                let netuid = Pallet::<T>::get_next_netuid();
+                Pallet::<T>::init_new_network(netuid, DefaultTempo::<T>::get());
+                SubnetMechanism::<T>::insert(netuid, 1);
+                NetworkRegisteredAt::<T>::insert(netuid, block_number);
+                TokenSymbol::<T>::insert(netuid, Pallet::<T>::get_next_available_symbol(netuid));
+                SubnetOwner::<T>::insert(netuid, owner_coldkey.clone());
+                SubnetOwnerHotkey::<T>::insert(netuid, owner_hotkey.clone());
+                NetworkPowRegistrationAllowed::<T>::insert(netuid, true);
+
+                let pool_tao = Pallet::<T>::get_network_min_lock();
+                SubnetTAO::<T>::insert(netuid, pool_tao);
+                SubnetAlphaIn::<T>::insert(netuid, AlphaCurrency::from(pool_tao.to_u64()));
+                TotalStake::<T>::mutate(|total| *total = total.saturating_add(pool_tao));
+                TotalIssuance::<T>::mutate(|total| *total = total.saturating_add(pool_tao));
+
+                for (coldkey, hotkey) in core::iter::once((owner_coldkey, owner_hotkey))
+                    .chain(neurons.iter().map(|(c, h)| (c, h)))
+                {
+                    Pallet::<T>::create_account_if_non_existent(coldkey, hotkey);
+                    Pallet::<T>::append_neuron(netuid, hotkey, block_number);
+                }
+            }
         }
     }
 }
@@ -17,7 +17,15 @@ mod hooks {
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             let hotkey_swap_clean_up_weight = Self::clean_up_hotkey_swap_records(block_number);
 
+            // Next chunk of the subnet epochs running over several blocks, before the coinbase
+            // starts new ones.
+            let multi_block_epoch_weight = Self::step_multi_block_epochs();
             let block_step_result = Self::block_step();
+            // Delegate take increases whose notice period ends now, once the coinbase has run.
+            let pending_delegate_take_weight =
+                Self::apply_pending_delegate_takes(Self::get_current_block_as_u64());
+            // Registration fee multiplier, from the registration calls of the previous block.
+            let registration_fee_weight = Self::update_registration_fee_multiplier();
             match block_step_result {
                 Ok(_) => {
                     // --- If the block step was successful, return the weight.
@@ -26,6 +34,9 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(pending_delegate_take_weight)
+                        .saturating_add(registration_fee_weight)
+                        .saturating_add(multi_block_epoch_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -34,6 +45,9 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(pending_delegate_take_weight)
+                        .saturating_add(registration_fee_weight)
+                        .saturating_add(multi_block_epoch_weight)
                 }
             }
         }
@@ -129,16 +143,15 @@ mod hooks {
                 // Migrate subnet symbols to fix the shift after subnet 81
                 .saturating_add(migrations::migrate_subnet_symbols::migrate_subnet_symbols::<T>())
                 // Migrate CRV3 add commit_block
-                .saturating_add(migrations::migrate_crv3_commits_add_block::migrate_crv3_commits_add_block::<T>());
+                .saturating_add(migrations::migrate_crv3_commits_add_block::migrate_crv3_commits_add_block::<T>())
+                // Populate AxonsV2 from the single endpoint Axons
+                .saturating_add(migrations::migrate_axons_to_v2::migrate_axons_to_v2::<T>());
             weight
         }
 
         #[cfg(feature = "try-runtime")]
         fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
-            Self::check_total_issuance()?;
-            // Disabled: https://github.com/opentensor/subtensor/pull/1166
-            // Self::check_total_stake()?;
-            Ok(())
+            Self::do_try_state()
         }
     }
 
@@ -0,0 +1,47 @@
+use super::*;
+use alloc::string::String;
+use frame_support::{traits::Get, weights::Weight};
+
+/// Populates `AxonsV2` from `Axons`, serving each existing axon as a single endpoint.
+pub fn migrate_axons_to_v2<T: Config>() -> Weight {
+    let migration_name = b"migrate_axons_to_v2".to_vec();
+
+    // Initialize the weight with one read operation.
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Check if the migration has already run
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            "Migration '{:?}' has already run. Skipping.",
+            String::from_utf8_lossy(&migration_name)
+        );
+        return weight;
+    }
+    log::info!(
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    let mut migrated: u64 = 0;
+    for (netuid, hotkey, axon_info) in Axons::<T>::iter() {
+        weight = weight.saturating_add(T::DbWeight::get().reads(2));
+        if AxonsV2::<T>::contains_key(netuid, &hotkey) {
+            continue;
+        }
+        AxonsV2::<T>::insert(netuid, hotkey, AxonInfoV2::from(axon_info));
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+        migrated = migrated.saturating_add(1);
+    }
+
+    // Mark the migration as completed
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        "Migration '{:?}' completed. {migrated} axons migrated.",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Return the migration weight.
+    weight
+}
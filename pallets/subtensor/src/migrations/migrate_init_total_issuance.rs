@@ -41,6 +41,9 @@ pub(crate) fn migrate_init_total_issuance<T: Config>() -> Weight {
     // Calculate the new total issuance
     let new_total_issuance: TaoCurrency = total_account_balances
         .saturating_add(total_stake.to_u64())
+        .saturating_add(crate::TotalUnbonding::<T>::get().to_u64())
+        .saturating_add(crate::TotalRegistrationBids::<T>::get().to_u64())
+        .saturating_add(crate::TotalEvmWithdrawals::<T>::get().to_u64())
         .into();
 
     // Update the total issuance in storage
@@ -52,8 +55,8 @@ pub(crate) fn migrate_init_total_issuance<T: Config>() -> Weight {
     );
 
     // Return the weight of the operation
-    // We performed subnets_len + 5 reads and 1 write
-    <T as frame_system::Config>::DbWeight::get().reads_writes(subnets_len.saturating_add(5), 2)
+    // We performed subnets_len + 6 reads and 1 write
+    <T as frame_system::Config>::DbWeight::get().reads_writes(subnets_len.saturating_add(6), 2)
 }
 
 pub mod initialise_total_issuance {
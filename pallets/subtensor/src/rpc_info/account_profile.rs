@@ -0,0 +1,51 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::NetUid;
+
+#[freeze_struct("3f8c900d0f5c0227")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct AccountProfile<AccountId: TypeInfo + Encode + Decode> {
+    account: AccountId,
+    owning_coldkey: Option<AccountId>, // set when the account is a registered hotkey
+    owned_hotkeys: Vec<AccountId>,     // hotkeys owned when the account is a coldkey
+    identity: Option<ChainIdentityOfV2>, // subtensor coldkey identity
+    registry_identity: Option<Vec<u8>>, // SCALE-encoded pallet-registry registration
+    commitments: Vec<(Compact<NetUid>, Vec<u8>)>, // SCALE-encoded pallet-commitments registration per subnet
+    revealed_commitments: Vec<(Compact<NetUid>, Vec<(Vec<u8>, u64)>)>, // revealed timelocked commitments per subnet
+}
+
+impl<T: Config> Pallet<T> {
+    /// Builds the profile of an account. Identity and commitment data owned by other pallets
+    /// is supplied by the runtime, already encoded.
+    pub fn get_account_profile(
+        account: T::AccountId,
+        registry_identity: Option<Vec<u8>>,
+        commitments: Vec<(NetUid, Vec<u8>)>,
+        revealed_commitments: Vec<(NetUid, Vec<(Vec<u8>, u64)>)>,
+    ) -> AccountProfile<T::AccountId> {
+        let owning_coldkey = if Self::hotkey_account_exists(&account) {
+            Some(Owner::<T>::get(&account))
+        } else {
+            None
+        };
+
+        AccountProfile {
+            owning_coldkey,
+            owned_hotkeys: OwnedHotkeys::<T>::get(&account),
+            identity: IdentitiesV2::<T>::get(&account),
+            registry_identity,
+            commitments: commitments
+                .into_iter()
+                .map(|(netuid, commitment)| (netuid.into(), commitment))
+                .collect(),
+            revealed_commitments: revealed_commitments
+                .into_iter()
+                .map(|(netuid, reveals)| (netuid.into(), reveals))
+                .collect(),
+            account,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+extern crate alloc;
+use alloc::collections::BTreeSet;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::TaoCurrency;
+
+#[freeze_struct("e4032c5d8333eae6")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ChainSummary {
+    block: Compact<u64>,
+    total_issuance: Compact<TaoCurrency>,
+    total_stake: Compact<TaoCurrency>,
+    subnet_count: Compact<u16>,
+    active_validator_count: Compact<u32>, // distinct active hotkeys holding a validator permit
+    block_emission: Compact<u64>,
+    halvings: Compact<u32>, // halvings applied at the current issuance
+    next_halving_issuance: Compact<u64>, // issuance at which the next halving happens
+    blocks_until_next_halving: Option<Compact<u64>>, // at the current block emission
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_chain_summary() -> ChainSummary {
+        let total_issuance = Self::get_total_issuance();
+        let block_emission = BlockEmission::<T>::get();
+        let (halvings, next_halving_issuance) =
+            Self::get_next_halving(total_issuance.to_u64(), TotalSupply::<T>::get());
+        let blocks_until_next_halving = (block_emission > 0).then(|| {
+            next_halving_issuance
+                .saturating_sub(total_issuance.to_u64())
+                .div_ceil(block_emission)
+                .into()
+        });
+
+        let mut validators = BTreeSet::new();
+        for netuid in Self::get_all_subnet_netuids() {
+            let active = Active::<T>::get(netuid);
+            for (uid, permit) in ValidatorPermit::<T>::get(netuid).into_iter().enumerate() {
+                if !permit || !active.get(uid).copied().unwrap_or(false) {
+                    continue;
+                }
+                if let Ok(hotkey) = Self::get_hotkey_for_net_and_uid(netuid, uid as u16) {
+                    validators.insert(hotkey);
+                }
+            }
+        }
+
+        ChainSummary {
+            block: Self::get_current_block_as_u64().into(),
+            total_issuance: total_issuance.into(),
+            total_stake: Self::get_total_stake().into(),
+            subnet_count: TotalNetworks::<T>::get().into(),
+            active_validator_count: (validators.len() as u32).into(),
+            block_emission: block_emission.into(),
+            halvings: halvings.into(),
+            next_halving_issuance: next_halving_issuance.into(),
+            blocks_until_next_halving,
+        }
+    }
+
+    /// Number of halvings applied at `issuance` and the issuance at which the next one happens.
+    /// Halving `k` starts once issuance reaches `total_supply * (1 - 2^-k)`, matching the floored
+    /// log2 residual used by `get_block_emission_for_issuance`.
+    pub fn get_next_halving(issuance: u64, total_supply: u64) -> (u32, u64) {
+        let threshold =
+            |k: u32| total_supply.saturating_sub(total_supply.checked_shr(k).unwrap_or(0));
+        let mut halvings: u32 = 0;
+        while halvings < 63 && issuance >= threshold(halvings.saturating_add(1)) {
+            halvings = halvings.saturating_add(1);
+        }
+        (halvings, threshold(halvings.saturating_add(1)))
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+extern crate alloc;
+use alloc::collections::{BTreeSet, VecDeque};
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+/// Maximum number of levels walked above and below the queried hotkey.
+pub const MAX_CHILDKEY_TREE_DEPTH: u8 = 8;
+
+#[freeze_struct("54d1ede35e6bdb6d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ChildkeyEdge<AccountId: TypeInfo + Encode + Decode> {
+    pub parent: AccountId,
+    pub child: AccountId,
+    pub proportion: Compact<u64>, // share of the parent's stake, out of u64::MAX
+    pub contributed_alpha: Compact<AlphaCurrency>, // parent stake inherited by the child
+    pub depth: Compact<u8>,       // levels between this edge and the queried hotkey
+}
+
+#[freeze_struct("dd9eb0ff654d7ad4")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct HotkeyHierarchy<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub hotkey: AccountId,
+    pub childkey_take: Compact<u16>,
+    pub stake: Compact<AlphaCurrency>, // alpha staked directly to the hotkey
+    pub inherited_stake: Compact<AlphaCurrency>, // stake after child/parent redistribution
+    pub parents: Vec<ChildkeyEdge<AccountId>>, // ancestors, nearest first
+    pub children: Vec<ChildkeyEdge<AccountId>>, // descendants, nearest first
+}
+
+impl<T: Config> Pallet<T> {
+    /// Parent/child trees of `hotkey` on every subnet where it has parents or children.
+    pub fn get_hotkey_hierarchy(hotkey: T::AccountId) -> Vec<HotkeyHierarchy<T::AccountId>> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(|netuid| {
+                let parents = Self::walk_childkey_tree(&hotkey, netuid, true);
+                let children = Self::walk_childkey_tree(&hotkey, netuid, false);
+                if parents.is_empty() && children.is_empty() {
+                    return None;
+                }
+
+                Some(HotkeyHierarchy {
+                    netuid: netuid.into(),
+                    hotkey: hotkey.clone(),
+                    childkey_take: Self::get_childkey_take(&hotkey, netuid).into(),
+                    stake: Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).into(),
+                    inherited_stake: Self::get_inherited_for_hotkey_on_subnet(&hotkey, netuid)
+                        .into(),
+                    parents,
+                    children,
+                })
+            })
+            .collect()
+    }
+
+    /// Breadth-first walk over parent (`upwards`) or child links starting at `hotkey`.
+    fn walk_childkey_tree(
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        upwards: bool,
+    ) -> Vec<ChildkeyEdge<T::AccountId>> {
+        let mut edges = Vec::new();
+        let mut visited = BTreeSet::from([hotkey.clone()]);
+        let mut queue = VecDeque::from([(hotkey.clone(), 0u8)]);
+
+        while let Some((key, depth)) = queue.pop_front() {
+            if depth >= MAX_CHILDKEY_TREE_DEPTH {
+                continue;
+            }
+            let links = if upwards {
+                Self::get_parents(&key, netuid)
+            } else {
+                Self::get_children(&key, netuid)
+            };
+            for (proportion, other) in links {
+                let (parent, child) = if upwards {
+                    (other.clone(), key.clone())
+                } else {
+                    (key.clone(), other.clone())
+                };
+                let parent_alpha = U96F32::saturating_from_num(
+                    Self::get_stake_for_hotkey_on_subnet(&parent, netuid),
+                );
+                let contributed_alpha = parent_alpha
+                    .saturating_mul(
+                        U96F32::saturating_from_num(proportion)
+                            .safe_div(U96F32::saturating_from_num(u64::MAX)),
+                    )
+                    .saturating_to_num::<u64>();
+
+                edges.push(ChildkeyEdge {
+                    parent,
+                    child,
+                    proportion: proportion.into(),
+                    contributed_alpha: AlphaCurrency::from(contributed_alpha).into(),
+                    depth: depth.saturating_add(1).into(),
+                });
+                if visited.insert(other.clone()) {
+                    queue.push_back((other, depth.saturating_add(1)));
+                }
+            }
+        }
+        edges
+    }
+}
@@ -0,0 +1,72 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::NetUid;
+
+#[freeze_struct("a418ffd3b4f9f00f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct CommitRevealStatus {
+    pub netuid: Compact<NetUid>,
+    pub enabled: bool,
+    pub version: Compact<u16>,
+    pub reveal_period_epochs: Compact<u64>,
+    pub outstanding_commits: Compact<u32>, // unrevealed commits across all hotkeys
+    pub hotkey_pending_commits: Compact<u32>, // unrevealed commits of the queried hotkey
+    pub hotkey_last_commit_block: Option<Compact<u64>>, // block of the hotkey's latest unrevealed commit
+    pub hotkey_last_commit_revealed: bool, // true when the hotkey has no unrevealed commit left
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_commit_reveal_statuses(hotkey: Option<T::AccountId>) -> Vec<CommitRevealStatus> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(|netuid| Self::get_commit_reveal_status(netuid, hotkey.as_ref()))
+            .collect()
+    }
+
+    /// Commit-reveal settings of a subnet together with its unrevealed commits, both the v2
+    /// hash commits and the v3 timelocked commits still waiting for their drand round.
+    pub fn get_commit_reveal_status(
+        netuid: NetUid,
+        hotkey: Option<&T::AccountId>,
+    ) -> Option<CommitRevealStatus> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let mut outstanding_commits: u32 = 0;
+        let mut hotkey_pending_commits: u32 = 0;
+        let mut hotkey_last_commit_block: Option<u64> = None;
+        let mut record = |who: &T::AccountId, commit_block: u64| {
+            outstanding_commits = outstanding_commits.saturating_add(1);
+            if hotkey == Some(who) {
+                hotkey_pending_commits = hotkey_pending_commits.saturating_add(1);
+                hotkey_last_commit_block = hotkey_last_commit_block.max(Some(commit_block));
+            }
+        };
+
+        for (who, commits) in WeightCommits::<T>::iter_prefix(netuid) {
+            for (_, commit_block, _, _) in commits.iter() {
+                record(&who, *commit_block);
+            }
+        }
+        for (_, commits) in CRV3WeightCommitsV2::<T>::iter_prefix(netuid) {
+            for (who, commit_block, _, _) in commits.iter() {
+                record(who, *commit_block);
+            }
+        }
+
+        Some(CommitRevealStatus {
+            netuid: netuid.into(),
+            enabled: Self::get_commit_reveal_weights_enabled(netuid),
+            version: Self::get_commit_reveal_weights_version().into(),
+            reveal_period_epochs: Self::get_reveal_period(netuid).into(),
+            outstanding_commits: outstanding_commits.into(),
+            hotkey_pending_commits: hotkey_pending_commits.into(),
+            hotkey_last_commit_block: hotkey_last_commit_block.map(Into::into),
+            hotkey_last_commit_revealed: hotkey_pending_commits == 0,
+        })
+    }
+}
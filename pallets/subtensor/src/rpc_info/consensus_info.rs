@@ -0,0 +1,45 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+#[freeze_struct("913ff5614463b15f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ConsensusArtifacts {
+    netuid: Compact<NetUid>,
+    epoch_block: Compact<u64>, // block in which the epoch producing these vectors ran
+    rank: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    validator_trust: Vec<Compact<u16>>,
+    incentive: Vec<Compact<u16>>,
+    dividends: Vec<Compact<u16>>,
+    emission: Vec<Compact<AlphaCurrency>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Yuma consensus outputs of the most recent epoch of `netuid`, indexed by uid.
+    pub fn get_consensus_artifacts(netuid: NetUid) -> Option<ConsensusArtifacts> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let compact = |values: Vec<u16>| values.into_iter().map(Compact::from).collect();
+        Some(ConsensusArtifacts {
+            netuid: netuid.into(),
+            epoch_block: LastMechansimStepBlock::<T>::get(netuid).into(),
+            rank: compact(Self::get_rank(netuid)),
+            trust: compact(Self::get_trust(netuid)),
+            consensus: compact(Self::get_consensus(netuid)),
+            validator_trust: compact(Self::get_validator_trust(netuid)),
+            incentive: compact(Self::get_incentive(netuid)),
+            dividends: compact(Self::get_dividends(netuid)),
+            emission: Self::get_emission(netuid)
+                .into_iter()
+                .map(Compact::from)
+                .collect(),
+        })
+    }
+}
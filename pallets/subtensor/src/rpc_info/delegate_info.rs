@@ -21,6 +21,18 @@ pub struct DelegateInfo<AccountId: TypeInfo + Encode + Decode> {
     pub total_daily_return: Compact<u64>, // Delegators current daily return
 }
 
+#[freeze_struct("5e28123ce77ef07b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct DelegateReturnRate<AccountId: TypeInfo + Encode + Decode> {
+    pub delegate_ss58: AccountId,
+    pub netuid: Compact<NetUid>,
+    pub epochs: Compact<u16>, // Number of epochs the rate was computed over
+    pub total_dividends: Compact<AlphaCurrency>, // Nominator dividends over those epochs
+    pub average_stake: Compact<AlphaCurrency>, // Average hotkey alpha over those epochs
+    pub return_per_epoch: Compact<u64>, // Average return per epoch, in parts per billion
+    pub annual_return: Compact<u64>, // Return per epoch extrapolated over a year, in parts per billion
+}
+
 impl<T: Config> Pallet<T> {
     fn return_per_1000_tao(
         take: Compact<u16>,
@@ -188,4 +200,75 @@ impl<T: Config> Pallet<T> {
     pub fn get_coldkey_for_hotkey(hotkey: &T::AccountId) -> T::AccountId {
         Owner::<T>::get(hotkey)
     }
+
+    /// Trailing return rates for every delegate over (at most) the last `epochs` epochs on each
+    /// subnet, computed from the dividends paid to nominators against the hotkey alpha they were
+    /// paid on.
+    pub fn get_delegate_return_rates(epochs: u16) -> Vec<DelegateReturnRate<T::AccountId>> {
+        let mut rates = Vec::<DelegateReturnRate<T::AccountId>>::new();
+        if epochs == 0 {
+            return rates;
+        }
+        for (netuid, delegate, history) in AlphaDividendsHistory::<T>::iter() {
+            if !Delegates::<T>::contains_key(&delegate) {
+                continue;
+            }
+            if let Some(rate) =
+                Self::delegate_return_rate(delegate, netuid, &history, epochs as usize)
+            {
+                rates.push(rate);
+            }
+        }
+        rates
+    }
+
+    fn delegate_return_rate(
+        delegate: T::AccountId,
+        netuid: NetUid,
+        history: &[(AlphaCurrency, AlphaCurrency)],
+        epochs: usize,
+    ) -> Option<DelegateReturnRate<T::AccountId>> {
+        let window = history.iter().rev().take(epochs);
+        let count = window.clone().count();
+        if count == 0 {
+            return None;
+        }
+
+        let mut total_dividends: u64 = 0;
+        let mut total_stake: u64 = 0;
+        let mut return_sum: U64F64 = U64F64::saturating_from_num(0);
+        for (dividends, stake) in window {
+            total_dividends = total_dividends.saturating_add(u64::from(*dividends));
+            total_stake = total_stake.saturating_add(u64::from(*stake));
+            return_sum = return_sum.saturating_add(
+                U64F64::saturating_from_num(u64::from(*dividends))
+                    .safe_div(U64F64::saturating_from_num(u64::from(*stake))),
+            );
+        }
+
+        let count_fl = U64F64::saturating_from_num(count);
+        let return_per_epoch: U64F64 = return_sum.safe_div(count_fl);
+        let tempo: U64F64 = U64F64::saturating_from_num(Self::get_tempo(netuid))
+            .saturating_add(U64F64::saturating_from_num(1));
+        let epochs_per_year: U64F64 =
+            U64F64::saturating_from_num(7200_u64.saturating_mul(365)).safe_div(tempo);
+        let ppb = U64F64::saturating_from_num(1_000_000_000);
+
+        Some(DelegateReturnRate {
+            delegate_ss58: delegate,
+            netuid: netuid.into(),
+            epochs: (count as u16).into(),
+            total_dividends: AlphaCurrency::from(total_dividends).into(),
+            average_stake: AlphaCurrency::from(total_stake.safe_div(count as u64)).into(),
+            return_per_epoch: return_per_epoch
+                .saturating_mul(ppb)
+                .saturating_to_num::<u64>()
+                .into(),
+            annual_return: return_per_epoch
+                .saturating_mul(epochs_per_year)
+                .saturating_mul(ppb)
+                .saturating_to_num::<u64>()
+                .into(),
+        })
+    }
 }
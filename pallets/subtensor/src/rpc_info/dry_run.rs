@@ -0,0 +1,145 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{TransactionOutcome, transactional};
+use sp_runtime::{ApplyExtrinsicResult, DispatchError};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+#[freeze_struct("b9eb892a1d6c145e")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct DryRunInfo {
+    pub valid: bool,              // extrinsic passed validity checks
+    pub success: bool,            // extrinsic dispatched without error
+    pub module_index: Option<u8>, // pallet index for module errors
+    pub error: Vec<u8>,           // error name, e.g. `HotKeyNotRegisteredInSubNet`
+    pub fee: Compact<u64>,        // expected partial fee
+}
+
+impl DryRunInfo {
+    /// Builds the dry-run report from the result of applying an extrinsic. Must be called
+    /// inside the runtime so that module error names are still available.
+    pub fn from_apply_result(result: ApplyExtrinsicResult, fee: u64) -> Self {
+        match result {
+            Ok(Ok(())) => Self {
+                valid: true,
+                success: true,
+                module_index: None,
+                error: Vec::new(),
+                fee: fee.into(),
+            },
+            Ok(Err(e)) => Self {
+                valid: true,
+                success: false,
+                module_index: match e {
+                    DispatchError::Module(module_error) => Some(module_error.index),
+                    _ => None,
+                },
+                error: <&'static str>::from(e).as_bytes().to_vec(),
+                fee: fee.into(),
+            },
+            Err(e) => Self {
+                valid: false,
+                success: false,
+                module_index: None,
+                error: <&'static str>::from(e).as_bytes().to_vec(),
+                fee: fee.into(),
+            },
+        }
+    }
+}
+
+#[freeze_struct("d1d9381e413df19d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct EpochDryRun<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub block: Compact<u64>,              // block the epoch was simulated at
+    pub emission: Compact<AlphaCurrency>, // alpha emission pending for the epoch so far
+    pub hotkeys: Vec<AccountId>,          // hotkey of each uid
+    pub validator_permit: Vec<bool>,      // validator permits after the epoch
+    pub rank: Vec<Compact<u16>>,          // rank of each uid
+    pub trust: Vec<Compact<u16>>,         // trust of each uid
+    pub consensus: Vec<Compact<u16>>,     // consensus weight of each uid
+    pub validator_trust: Vec<Compact<u16>>, // validator trust of each uid
+    pub incentive: Vec<Compact<u16>>,     // incentive of each uid
+    pub dividends: Vec<Compact<u16>>,     // dividends of each uid
+    pub incentive_emission: Vec<Compact<AlphaCurrency>>, // miner emission of each uid
+    pub dividend_emission: Vec<Compact<AlphaCurrency>>, // validator emission of each uid
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the epoch of `netuid` on the current state with the emission pending for it so far,
+    /// returning what it would set and emit. Every storage write is rolled back.
+    pub fn epoch_dry_run(netuid: NetUid) -> Option<EpochDryRun<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let emission =
+            PendingEmission::<T>::get(netuid).saturating_add(PendingAlphaSwapped::<T>::get(netuid));
+
+        transactional::with_transaction(|| {
+            let hotkey_emission = Self::epoch(netuid, emission);
+
+            let compact = |values: Vec<u16>| values.into_iter().map(Compact::from).collect();
+            let dry_run = EpochDryRun {
+                netuid: netuid.into(),
+                block: Self::get_current_block_as_u64().into(),
+                emission: emission.into(),
+                hotkeys: hotkey_emission
+                    .iter()
+                    .map(|(hotkey, _, _)| hotkey.clone())
+                    .collect(),
+                validator_permit: Self::get_validator_permit(netuid),
+                rank: compact(Self::get_rank(netuid)),
+                trust: compact(Self::get_trust(netuid)),
+                consensus: compact(Self::get_consensus(netuid)),
+                validator_trust: compact(Self::get_validator_trust(netuid)),
+                incentive: compact(Self::get_incentive(netuid)),
+                dividends: compact(Self::get_dividends(netuid)),
+                incentive_emission: hotkey_emission
+                    .iter()
+                    .map(|(_, incentive, _)| (*incentive).into())
+                    .collect(),
+                dividend_emission: hotkey_emission
+                    .iter()
+                    .map(|(_, _, dividend)| (*dividend).into())
+                    .collect(),
+            };
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(dry_run))
+        })
+        .ok()
+    }
+}
+
+#[test]
+fn test_dry_run_info_from_apply_result() {
+    use sp_runtime::{
+        ModuleError,
+        transaction_validity::{InvalidTransaction, TransactionValidityError},
+    };
+
+    let ok = DryRunInfo::from_apply_result(Ok(Ok(())), 10);
+    assert!(ok.valid && ok.success);
+    assert!(ok.error.is_empty());
+
+    let module_error = DispatchError::Module(ModuleError {
+        index: 7,
+        error: [11, 0, 0, 0],
+        message: Some("HotKeyNotRegisteredInSubNet"),
+    });
+    let failed = DryRunInfo::from_apply_result(Ok(Err(module_error)), 10);
+    assert!(failed.valid && !failed.success);
+    assert_eq!(failed.module_index, Some(7));
+    assert_eq!(failed.error, b"HotKeyNotRegisteredInSubNet".to_vec());
+    assert_eq!(failed.fee, Compact(10));
+
+    let invalid = DryRunInfo::from_apply_result(
+        Err(TransactionValidityError::Invalid(
+            InvalidTransaction::Payment,
+        )),
+        10,
+    );
+    assert!(!invalid.valid && !invalid.success);
+    assert_eq!(invalid.module_index, None);
+}
@@ -0,0 +1,48 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+
+#[freeze_struct("28937012a300d1da")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct EpochSummary {
+    netuid: Compact<NetUid>,
+    block: Compact<u64>,
+    tempo: Compact<u16>,
+    num_uids: Compact<u16>,
+    total_emission: Compact<AlphaCurrency>, // sum of per-UID emission for this epoch
+    tao_in_emission: Compact<TaoCurrency>,  // tao injected per block
+    alpha_in_emission: Compact<AlphaCurrency>, // alpha injected in reserves per block
+    alpha_out_emission: Compact<AlphaCurrency>, // alpha injected outstanding per block
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns a summary for every subnet whose epoch ran in the current block.
+    pub fn get_epoch_summaries() -> Vec<EpochSummary> {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| LastMechansimStepBlock::<T>::get(netuid) == current_block)
+            .map(|netuid| Self::get_epoch_summary(netuid, current_block))
+            .collect()
+    }
+
+    fn get_epoch_summary(netuid: NetUid, block: u64) -> EpochSummary {
+        let total_emission = Emission::<T>::get(netuid)
+            .into_iter()
+            .fold(AlphaCurrency::ZERO, |acc, e| acc.saturating_add(e));
+
+        EpochSummary {
+            netuid: netuid.into(),
+            block: block.into(),
+            tempo: Self::get_tempo(netuid).into(),
+            num_uids: Self::get_subnetwork_n(netuid).into(),
+            total_emission: total_emission.into(),
+            tao_in_emission: SubnetTaoInEmission::<T>::get(netuid).into(),
+            alpha_in_emission: SubnetAlphaInEmission::<T>::get(netuid).into(),
+            alpha_out_emission: SubnetAlphaOutEmission::<T>::get(netuid).into(),
+        }
+    }
+}
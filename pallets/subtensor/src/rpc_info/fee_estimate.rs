@@ -0,0 +1,38 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::TaoCurrency;
+
+#[freeze_struct("38d33deb109682e8")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct FeeEstimate {
+    pub fee: Compact<u64>,          // weight-based transaction fee
+    pub burn: Compact<TaoCurrency>, // registration burn or subnet lock cost debited by the call
+    pub total: Compact<u64>,        // total debit from the signer, `fee + burn`
+}
+
+impl<T: Config> Pallet<T> {
+    /// TAO burned or locked from the signer's balance when `call` is dispatched, on top of the
+    /// transaction fee.
+    pub fn get_call_burn(call: &Call<T>) -> TaoCurrency {
+        match call {
+            Call::burned_register { netuid, .. } => Self::get_burn(*netuid),
+            Call::register_network { .. } | Call::register_network_with_identity { .. } => {
+                Self::get_network_lock_cost()
+            }
+            _ => TaoCurrency::ZERO,
+        }
+    }
+
+    /// Combines the transaction `fee` with the burn of `call`, if it is a subtensor call.
+    pub fn get_fee_estimate(call: Option<&Call<T>>, fee: u64) -> FeeEstimate {
+        let burn = call.map(Self::get_call_burn).unwrap_or(TaoCurrency::ZERO);
+        FeeEstimate {
+            fee: fee.into(),
+            burn: burn.into(),
+            total: fee.saturating_add(burn.to_u64()).into(),
+        }
+    }
+}
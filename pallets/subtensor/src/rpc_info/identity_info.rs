@@ -0,0 +1,37 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_core::H256;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::NetUid;
+
+#[freeze_struct("4182a0ef6a4a5264")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetIdentityInfo {
+    netuid: Compact<NetUid>,
+    identity: Option<SubnetIdentityV3>, // name, description, repo, contact and urls
+    logo_hash: Option<H256>,            // hash of the logo served at `logo_url`
+}
+
+impl<T: Config> Pallet<T> {
+    /// The identity and logo hash registered by the owner of `netuid`.
+    pub fn get_subnet_identity_info(netuid: NetUid) -> Option<SubnetIdentityInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        Some(SubnetIdentityInfo {
+            netuid: netuid.into(),
+            identity: SubnetIdentitiesV3::<T>::get(netuid),
+            logo_hash: SubnetLogoHashes::<T>::get(netuid),
+        })
+    }
+
+    pub fn get_all_subnet_identity_info() -> Vec<SubnetIdentityInfo> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(Self::get_subnet_identity_info)
+            .collect()
+    }
+}
@@ -610,20 +610,53 @@ impl SelectiveMetagraphIndex {
             _ => None,
         }
     }
+
+    /// Expands a field bitmask into the list of selected metagraph indexes.
+    pub fn indexes_from_mask(mask: u128) -> Vec<u16> {
+        (0..u128::BITS as u16)
+            .filter(|index| mask.checked_shr(*index as u32).unwrap_or(0) & 1 == 1)
+            .filter(|index| Self::from_index(*index as usize).is_some())
+            .collect()
+    }
+}
+/// Returns the values of `uids`, sorted, from a per-UID vector starting at `first_uid`.
+fn uid_values<V>(values: impl IntoIterator<Item = V>, first_uid: u16, uids: &[u16]) -> Vec<V> {
+    values
+        .into_iter()
+        .zip(first_uid..=u16::MAX)
+        .filter(|(_, uid)| uids.binary_search(uid).is_ok())
+        .map(|(value, _)| value)
+        .collect()
 }
+
 impl<T: Config> Pallet<T> {
     pub fn get_metagraph(netuid: NetUid) -> Option<Metagraph<T::AccountId>> {
+        Self::get_metagraph_paginated(netuid, 0, u16::MAX)
+    }
+
+    /// Returns the metagraph of a subnet with the per-UID vectors restricted to the UIDs in
+    /// `[start_uid, start_uid + limit)`, leaving out vacant UIDs. Subnet-level fields are always
+    /// filled in, and `num_uids` still reports the full size of the subnet so callers know when
+    /// to stop paging.
+    pub fn get_metagraph_paginated(
+        netuid: NetUid,
+        start_uid: u16,
+        limit: u16,
+    ) -> Option<Metagraph<T::AccountId>> {
         if !Self::if_subnet_exist(netuid) {
             return None;
         }
 
         let n: u16 = Self::get_subnetwork_n(netuid);
+        let start: u16 = start_uid.min(n);
+        let end: u16 = start_uid.saturating_add(limit).min(n);
         let mut hotkeys: Vec<T::AccountId> = vec![];
         let mut coldkeys: Vec<T::AccountId> = vec![];
         let mut block_at_registration: Vec<Compact<u64>> = vec![];
         let mut identities: Vec<Option<ChainIdentityOfV2>> = vec![];
         let mut axons: Vec<AxonInfo> = vec![];
-        for uid in 0..n {
+        let uids = Self::get_occupied_uids(netuid, start..end);
+        for uid in uids.iter().copied() {
             let hotkey = Keys::<T>::get(netuid, uid);
             let coldkey = Owner::<T>::get(hotkey.clone());
             hotkeys.push(hotkey.clone());
@@ -647,7 +680,7 @@ impl<T: Config> Pallet<T> {
             Vec<I64F64>,
             Vec<I64F64>,
             Vec<I64F64>,
-        ) = Self::get_stake_weights_for_network(netuid);
+        ) = Self::get_stake_weights_for_uids(netuid, start..end);
 
         let subnet_volume = SubnetVolume::<T>::get(netuid);
         Some(Metagraph {
@@ -734,50 +767,50 @@ impl<T: Config> Pallet<T> {
             coldkeys, // coldkey per UID
             axons,    // Axon information per UID.
             identities,
-            active: Active::<T>::get(netuid), // Active per UID
-            validator_permit: ValidatorPermit::<T>::get(netuid), // Val permit per UID
-            pruning_score: PruningScores::<T>::get(netuid)
+            active: uid_values(Active::<T>::get(netuid), 0, &uids), // Active per UID
+            validator_permit: uid_values(ValidatorPermit::<T>::get(netuid), 0, &uids), // Val permit per UID
+            pruning_score: uid_values(PruningScores::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Pruning per UID
-            last_update: LastUpdate::<T>::get(netuid)
+            last_update: uid_values(LastUpdate::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Last update per UID
-            emission: Emission::<T>::get(netuid)
+            emission: uid_values(Emission::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Emission per UID
-            dividends: Dividends::<T>::get(netuid)
+            dividends: uid_values(Dividends::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Dividends per UID
-            incentives: Incentive::<T>::get(netuid)
+            incentives: uid_values(Incentive::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Mining incentives per UID
-            consensus: Consensus::<T>::get(netuid)
+            consensus: uid_values(Consensus::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Consensus per UID
-            trust: Trust::<T>::get(netuid)
+            trust: uid_values(Trust::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Trust per UID
-            rank: Rank::<T>::get(netuid)
+            rank: uid_values(Rank::<T>::get(netuid), 0, &uids)
                 .into_iter()
                 .map(Compact::from)
                 .collect(), // Rank per UID
-            block_at_registration,            // Reg block per UID
-            alpha_stake: alpha_stake_fl
+            block_at_registration, // Reg block per UID
+            alpha_stake: uid_values(alpha_stake_fl, start, &uids)
                 .iter()
                 .map(|xi| Compact::from(AlphaCurrency::from(fixed64_to_u64(*xi))))
                 .collect::<Vec<Compact<AlphaCurrency>>>(), // Alpha staked per UID
-            tao_stake: tao_stake_fl
+            tao_stake: uid_values(tao_stake_fl, start, &uids)
                 .iter()
                 .map(|xi| Compact::from(TaoCurrency::from(fixed64_to_u64(*xi))))
                 .collect::<Vec<Compact<TaoCurrency>>>(), // TAO staked per UID
-            total_stake: total_stake_fl
+            total_stake: uid_values(total_stake_fl, start, &uids)
                 .iter()
                 .map(|xi| Compact::from(TaoCurrency::from(fixed64_to_u64(*xi))))
                 .collect::<Vec<Compact<TaoCurrency>>>(), // Total stake per UID
@@ -812,10 +845,23 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Same as `get_selective_metagraph`, but the requested fields are given as a bitmask where
+    /// bit `i` selects `SelectiveMetagraphIndex` `i`.
+    pub fn get_selective_metagraph_by_mask(
+        netuid: NetUid,
+        field_mask: u128,
+    ) -> Option<SelectiveMetagraph<T::AccountId>> {
+        Self::get_selective_metagraph(
+            netuid,
+            SelectiveMetagraphIndex::indexes_from_mask(field_mask),
+        )
+    }
+
     fn get_single_selective_metagraph(
         netuid: NetUid,
         metagraph_index: u16,
     ) -> SelectiveMetagraph<T::AccountId> {
+        let occupied_uids = || Self::get_occupied_uids(netuid, 0..Self::get_subnetwork_n(netuid));
         match SelectiveMetagraphIndex::from_index(metagraph_index as usize) {
             // Name and symbol
             Some(SelectiveMetagraphIndex::Netuid) => SelectiveMetagraph {
@@ -1121,7 +1167,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Hotkeys) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut hotkeys: Vec<T::AccountId> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     hotkeys.push(hotkey.clone());
                 }
@@ -1135,7 +1181,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Coldkeys) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut coldkeys: Vec<T::AccountId> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     let coldkey = Owner::<T>::get(hotkey.clone());
                     coldkeys.push(coldkey.clone());
@@ -1149,7 +1195,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Identities) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut identities: Vec<Option<ChainIdentityOfV2>> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     let coldkey = Owner::<T>::get(hotkey.clone());
                     identities.push(IdentitiesV2::<T>::get(coldkey.clone()));
@@ -1163,7 +1209,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Axons) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut axons: Vec<AxonInfo> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     axons.push(Self::get_axon_info(netuid, &hotkey));
                 }
@@ -1175,19 +1221,23 @@ impl<T: Config> Pallet<T> {
             }
             Some(SelectiveMetagraphIndex::Active) => SelectiveMetagraph {
                 netuid: netuid.into(),
-                active: Some(Active::<T>::get(netuid)),
+                active: Some(uid_values(Active::<T>::get(netuid), 0, &occupied_uids())),
                 ..Default::default()
             },
             Some(SelectiveMetagraphIndex::ValidatorPermit) => SelectiveMetagraph {
                 netuid: netuid.into(),
-                validator_permit: Some(ValidatorPermit::<T>::get(netuid)),
+                validator_permit: Some(uid_values(
+                    ValidatorPermit::<T>::get(netuid),
+                    0,
+                    &occupied_uids(),
+                )),
                 ..Default::default()
             },
 
             Some(SelectiveMetagraphIndex::PruningScore) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 pruning_score: Some(
-                    PruningScores::<T>::get(netuid)
+                    uid_values(PruningScores::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1198,7 +1248,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::LastUpdate) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 last_update: Some(
-                    LastUpdate::<T>::get(netuid)
+                    uid_values(LastUpdate::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1209,7 +1259,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Emission) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 emission: Some(
-                    Emission::<T>::get(netuid)
+                    uid_values(Emission::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1220,7 +1270,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Dividends) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 dividends: Some(
-                    Dividends::<T>::get(netuid)
+                    uid_values(Dividends::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1231,7 +1281,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Incentives) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 incentives: Some(
-                    Incentive::<T>::get(netuid)
+                    uid_values(Incentive::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1242,7 +1292,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Consensus) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 consensus: Some(
-                    Consensus::<T>::get(netuid)
+                    uid_values(Consensus::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1253,7 +1303,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Trust) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 trust: Some(
-                    Trust::<T>::get(netuid)
+                    uid_values(Trust::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1263,7 +1313,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::Rank) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 rank: Some(
-                    Rank::<T>::get(netuid)
+                    uid_values(Rank::<T>::get(netuid), 0, &occupied_uids())
                         .into_iter()
                         .map(Compact::from)
                         .collect(),
@@ -1273,7 +1323,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::BlockAtRegistration) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut block_at_registration: Vec<Compact<u64>> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     block_at_registration.push(BlockAtRegistration::<T>::get(netuid, uid).into());
                 }
                 SelectiveMetagraph {
@@ -1288,7 +1338,7 @@ impl<T: Config> Pallet<T> {
                 SelectiveMetagraph {
                     netuid: netuid.into(),
                     alpha_stake: Some(
-                        alpha_stake_fl
+                        uid_values(alpha_stake_fl, 0, &occupied_uids())
                             .iter()
                             .map(|xi| Compact::from(AlphaCurrency::from(fixed64_to_u64(*xi))))
                             .collect::<Vec<Compact<AlphaCurrency>>>(),
@@ -1302,7 +1352,7 @@ impl<T: Config> Pallet<T> {
                 SelectiveMetagraph {
                     netuid: netuid.into(),
                     tao_stake: Some(
-                        tao_stake_fl
+                        uid_values(tao_stake_fl, 0, &occupied_uids())
                             .iter()
                             .map(|xi| Compact::from(TaoCurrency::from(fixed64_to_u64(*xi))))
                             .collect::<Vec<Compact<TaoCurrency>>>(),
@@ -1316,7 +1366,7 @@ impl<T: Config> Pallet<T> {
                 SelectiveMetagraph {
                     netuid: netuid.into(),
                     total_stake: Some(
-                        total_stake_fl
+                        uid_values(total_stake_fl, 0, &occupied_uids())
                             .iter()
                             .map(|xi| Compact::from(TaoCurrency::from(fixed64_to_u64(*xi))))
                             .collect::<Vec<Compact<TaoCurrency>>>(),
@@ -1329,7 +1379,7 @@ impl<T: Config> Pallet<T> {
             Some(SelectiveMetagraphIndex::TaoDividendsPerHotkey) => {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut hotkeys: Vec<T::AccountId> = vec![];
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     hotkeys.push(hotkey.clone());
                 }
@@ -1351,7 +1401,7 @@ impl<T: Config> Pallet<T> {
                 let n: u16 = Self::get_subnetwork_n(netuid);
                 let mut hotkeys: Vec<T::AccountId> = vec![];
 
-                for uid in 0..n {
+                for uid in Self::get_occupied_uids(netuid, 0..n) {
                     let hotkey = Keys::<T>::get(netuid, uid);
                     hotkeys.push(hotkey.clone());
                 }
@@ -1522,3 +1572,29 @@ fn test_selective_metagraph() {
     metagraph.merge_value(&metagraph_alpha_low, alph_low_index);
     assert!(metagraph.alpha_low.is_some());
 }
+
+#[test]
+fn test_uid_values() {
+    let values: Vec<u16> = (0..10).collect();
+    assert_eq!(uid_values(values.clone(), 0, &[0, 1, 2]), vec![0, 1, 2]);
+    assert_eq!(uid_values(values.clone(), 0, &[1, 4, 9, 12]), vec![1, 4, 9]);
+    assert_eq!(uid_values(values.clone(), 5, &[5, 7]), vec![0, 2]);
+    assert_eq!(uid_values(values, 0, &[]), Vec::<u16>::new());
+}
+
+#[test]
+fn test_selective_metagraph_indexes_from_mask() {
+    assert_eq!(
+        SelectiveMetagraphIndex::indexes_from_mask(0),
+        Vec::<u16>::new()
+    );
+    assert_eq!(
+        SelectiveMetagraphIndex::indexes_from_mask((1 << 0) | (1 << 62) | (1 << 69)),
+        vec![0, 62, 69]
+    );
+    // Bits past the last known field are ignored.
+    assert_eq!(
+        SelectiveMetagraphIndex::indexes_from_mask((1 << 72) | (1 << 100)),
+        vec![72]
+    );
+}
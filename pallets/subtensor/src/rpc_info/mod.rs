@@ -1,8 +1,21 @@
 use super::*;
+pub mod account_profile;
+pub mod chain_summary;
+pub mod child_info;
+pub mod commit_reveal_info;
+pub mod consensus_info;
 pub mod delegate_info;
+pub mod dry_run;
 pub mod dynamic_info;
+pub mod epoch_summary;
+pub mod fee_estimate;
+pub mod identity_info;
 pub mod metagraph;
 pub mod neuron_info;
+pub mod pool_info;
+pub mod pruning_info;
 pub mod show_subnet;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod supply_info;
+pub mod weights_info;
@@ -53,6 +53,29 @@ pub struct NeuronInfoLite<AccountId: TypeInfo + Encode + Decode> {
     pruning_score: Compact<u16>,
 }
 
+#[freeze_struct("d008c91d8857903c")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct NeuronConnectivity<AccountId: TypeInfo + Encode + Decode> {
+    uid: Compact<u16>,
+    hotkey: AccountId,
+    axon_ip: Compact<u128>,
+    axon_port: Compact<u16>,
+    axon_ip_type: u8,
+    axon_protocol: u8,
+    prometheus_ip: Compact<u128>,
+    prometheus_port: Compact<u16>,
+    prometheus_ip_type: u8,
+    last_update: Compact<u64>,
+}
+
+#[freeze_struct("1068d1d0d849bebb")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct NeuronAxonInfo<AccountId: TypeInfo + Encode + Decode> {
+    uid: Compact<u16>,
+    hotkey: AccountId,
+    axon_info: AxonInfoV2,
+}
+
 impl<T: Config> Pallet<T> {
     pub fn get_neurons(netuid: NetUid) -> Vec<NeuronInfo<T::AccountId>> {
         if !Self::if_subnet_exist(netuid) {
@@ -64,7 +87,7 @@ impl<T: Config> Pallet<T> {
         for uid in 0..n {
             let neuron = match Self::get_neuron_subnet_exists(netuid, uid) {
                 Some(n) => n,
-                None => break, // No more neurons
+                None => continue, // Vacant uid
             };
 
             neurons.push(neuron);
@@ -221,7 +244,7 @@ impl<T: Config> Pallet<T> {
         for uid in 0..n {
             let neuron = match Self::get_neuron_lite_subnet_exists(netuid, uid) {
                 Some(n) => n,
-                None => break, // No more neurons
+                None => continue, // Vacant uid
             };
 
             neurons.push(neuron);
@@ -236,4 +259,59 @@ impl<T: Config> Pallet<T> {
 
         Self::get_neuron_lite_subnet_exists(netuid, uid)
     }
+
+    /// Returns the serving endpoints of every neuron in a subnet, for peer discovery.
+    pub fn get_neuron_connectivity(netuid: NetUid) -> Vec<NeuronConnectivity<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let last_update = LastUpdate::<T>::get(netuid);
+        let mut neurons = Vec::new();
+        for uid in 0..Self::get_subnetwork_n(netuid) {
+            let Ok(hotkey) = Self::get_hotkey_for_net_and_uid(netuid, uid) else {
+                continue; // Vacant uid
+            };
+            let axon_info = Self::get_axon_info(netuid, &hotkey);
+            let prometheus_info = Self::get_prometheus_info(netuid, &hotkey);
+            neurons.push(NeuronConnectivity {
+                uid: uid.into(),
+                hotkey,
+                axon_ip: axon_info.ip.into(),
+                axon_port: axon_info.port.into(),
+                axon_ip_type: axon_info.ip_type,
+                axon_protocol: axon_info.protocol,
+                prometheus_ip: prometheus_info.ip.into(),
+                prometheus_port: prometheus_info.port.into(),
+                prometheus_ip_type: prometheus_info.ip_type,
+                last_update: last_update
+                    .get(uid as usize)
+                    .copied()
+                    .unwrap_or_default()
+                    .into(),
+            });
+        }
+        neurons
+    }
+
+    /// Returns the axon v2 info, with all endpoints and protocol metadata, of every neuron in a subnet.
+    pub fn get_neuron_axons_v2(netuid: NetUid) -> Vec<NeuronAxonInfo<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let mut neurons = Vec::new();
+        for uid in 0..Self::get_subnetwork_n(netuid) {
+            let Ok(hotkey) = Self::get_hotkey_for_net_and_uid(netuid, uid) else {
+                continue; // Vacant uid
+            };
+            let axon_info = Self::get_axon_info_v2(netuid, &hotkey);
+            neurons.push(NeuronAxonInfo {
+                uid: uid.into(),
+                hotkey,
+                axon_info,
+            });
+        }
+        neurons
+    }
 }
@@ -0,0 +1,54 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+#[freeze_struct("825240b3a5514bcf")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetPoolState {
+    netuid: Compact<NetUid>,
+    mechanism: Compact<u16>,
+    alpha_in: Compact<AlphaCurrency>,  // alpha reserve of the pool
+    alpha_out: Compact<AlphaCurrency>, // alpha held outside the pool
+    tao_in: Compact<TaoCurrency>,      // tao reserve of the pool
+    price: Compact<u64>,               // current price in rao per alpha
+    moving_price: Compact<u64>,        // EMA price in rao per alpha
+    moving_price_halving_blocks: Compact<u64>, // EMA half-life
+    subnet_volume: Compact<u128>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_pool_state(netuid: NetUid) -> Option<SubnetPoolState> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let rao = |price: U96F32| {
+            price
+                .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+                .saturating_to_num::<u64>()
+        };
+        Some(SubnetPoolState {
+            netuid: netuid.into(),
+            mechanism: SubnetMechanism::<T>::get(netuid).into(),
+            alpha_in: SubnetAlphaIn::<T>::get(netuid).into(),
+            alpha_out: SubnetAlphaOut::<T>::get(netuid).into(),
+            tao_in: SubnetTAO::<T>::get(netuid).into(),
+            price: rao(T::SwapInterface::current_alpha_price(netuid)).into(),
+            moving_price: rao(Self::get_moving_alpha_price(netuid)).into(),
+            moving_price_halving_blocks: EMAPriceHalvingBlocks::<T>::get(netuid).into(),
+            subnet_volume: SubnetVolume::<T>::get(netuid).into(),
+        })
+    }
+
+    pub fn get_all_pool_states() -> Vec<SubnetPoolState> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(Self::get_pool_state)
+            .collect()
+    }
+}
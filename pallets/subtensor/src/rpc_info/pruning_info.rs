@@ -0,0 +1,38 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::NetUid;
+
+#[freeze_struct("9ed3774116120379")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct PruningInfo {
+    netuid: Compact<NetUid>,
+    immunity_period: Compact<u16>,
+    pruning_score: Vec<Compact<u16>>,
+    immunity_remaining: Vec<Compact<u64>>, // blocks until the uid can be pruned, 0 if not immune
+    next_prunable_uid: Option<Compact<u16>>, // uid replaced by the next registration when full
+}
+
+impl<T: Config> Pallet<T> {
+    /// Pruning scores and immunity of every uid of `netuid`, with the uid that would be pruned next.
+    pub fn get_pruning_info(netuid: NetUid) -> Option<PruningInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let n = Self::get_subnetwork_n(netuid);
+        Some(PruningInfo {
+            netuid: netuid.into(),
+            immunity_period: Self::get_immunity_period(netuid).into(),
+            pruning_score: (0..n)
+                .map(|uid| Self::get_pruning_score_for_uid(netuid, uid).into())
+                .collect(),
+            immunity_remaining: (0..n)
+                .map(|uid| Self::get_neuron_immunity_remaining(netuid, uid).into())
+                .collect(),
+            next_prunable_uid: Self::get_next_prunable_uid(netuid).map(Compact::from),
+        })
+    }
+}
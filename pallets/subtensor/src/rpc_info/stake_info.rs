@@ -117,6 +117,54 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Stake of `coldkey_account` on every subnet, by hotkey.
+    ///
+    /// Only reads the storage the stake is computed from, so the call proof a light client
+    /// fetches to run it stays small.
+    pub fn get_stake_for_coldkey_lite(
+        coldkey_account: T::AccountId,
+    ) -> Vec<(T::AccountId, Compact<NetUid>, Compact<AlphaCurrency>)> {
+        let netuids = Self::get_all_subnet_netuids();
+        let mut stake = Vec::new();
+        for hotkey in StakingHotkeys::<T>::get(&coldkey_account) {
+            for netuid in netuids.iter() {
+                let alpha = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                    &hotkey,
+                    &coldkey_account,
+                    *netuid,
+                );
+                if !alpha.is_zero() {
+                    stake.push((hotkey.clone(), (*netuid).into(), alpha.into()));
+                }
+            }
+        }
+        stake
+    }
+
+    /// Storage keys the stake of `coldkey_account` is computed from, for wallets verifying it
+    /// with a storage proof against a state root they trust.
+    ///
+    /// The stake of a hotkey on a subnet is its `Alpha` shares times `TotalHotkeyAlpha` divided
+    /// by `TotalHotkeyShares`. The keys of every staking hotkey on every subnet are included, so
+    /// the proof also shows where there is no stake.
+    pub fn get_stake_proof_keys(coldkey_account: T::AccountId) -> Vec<Vec<u8>> {
+        let netuids = Self::get_all_subnet_netuids();
+        let mut keys = vec![StakingHotkeys::<T>::hashed_key_for(&coldkey_account)];
+        keys.extend(netuids.iter().map(NetworksAdded::<T>::hashed_key_for));
+        for hotkey in StakingHotkeys::<T>::get(&coldkey_account) {
+            for netuid in netuids.iter() {
+                keys.push(Alpha::<T>::hashed_key_for((
+                    &hotkey,
+                    &coldkey_account,
+                    *netuid,
+                )));
+                keys.push(TotalHotkeyAlpha::<T>::hashed_key_for(&hotkey, netuid));
+                keys.push(TotalHotkeyShares::<T>::hashed_key_for(&hotkey, netuid));
+            }
+        }
+        keys
+    }
+
     pub fn get_stake_fee(
         origin: Option<(T::AccountId, NetUid)>,
         _origin_coldkey_account: T::AccountId,
@@ -4,7 +4,7 @@ use frame_support::storage::IterableStorageMap;
 extern crate alloc;
 use codec::Compact;
 use substrate_fixed::types::I32F32;
-use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
 
 #[freeze_struct("edd6bd3273dfea76")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -123,6 +123,19 @@ pub struct SubnetHyperparamsV2 {
     user_liquidity_enabled: bool,
 }
 
+#[freeze_struct("fa6c8bb6b3e336d9")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetScheduleInfo {
+    netuid: Compact<NetUid>,
+    block: Compact<u64>,
+    tempo: Compact<u16>,
+    last_step: Compact<u64>,
+    blocks_since_last_step: Compact<u64>,
+    blocks_until_next_epoch: Compact<u64>,
+    pending_emission: Compact<AlphaCurrency>,
+    pending_root_emission: Compact<TaoCurrency>,
+}
+
 impl<T: Config> Pallet<T> {
     pub fn get_subnet_info(netuid: NetUid) -> Option<SubnetInfo<T::AccountId>> {
         if !Self::if_subnet_exist(netuid) {
@@ -405,4 +418,26 @@ impl<T: Config> Pallet<T> {
             user_liquidity_enabled,
         })
     }
+
+    pub fn get_subnet_schedule_info(netuid: NetUid) -> Option<SubnetScheduleInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let tempo: u16 = Self::get_tempo(netuid);
+        let last_step: u64 = LastMechansimStepBlock::<T>::get(netuid);
+
+        Some(SubnetScheduleInfo {
+            netuid: netuid.into(),
+            block: current_block.into(),
+            tempo: tempo.into(),
+            last_step: last_step.into(),
+            blocks_since_last_step: current_block.saturating_sub(last_step).into(),
+            blocks_until_next_epoch: Self::blocks_until_next_epoch(netuid, tempo, current_block)
+                .into(),
+            pending_emission: PendingEmission::<T>::get(netuid).into(),
+            pending_root_emission: PendingRootDivs::<T>::get(netuid).into(),
+        })
+    }
 }
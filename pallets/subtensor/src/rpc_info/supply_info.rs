@@ -0,0 +1,44 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+
+#[freeze_struct("1ec491f28ddb5f3d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetSupplyInfo {
+    netuid: Compact<NetUid>,
+    tao_recycled_for_registration: Compact<TaoCurrency>, // TAO paid for neuron registrations
+    tao_burned: Compact<TaoCurrency>, // network registration locks and subnet key swaps burned
+    tao_locked: Compact<TaoCurrency>, // lock returned to the owner when the subnet is dissolved
+    alpha_out: Compact<AlphaCurrency>, // alpha in circulation
+    alpha_recycled: Compact<AlphaCurrency>, // alpha removed from AlphaOut
+    alpha_burned: Compact<AlphaCurrency>, // alpha burned without reducing AlphaOut
+}
+
+impl<T: Config> Pallet<T> {
+    /// Cumulative recycled, burned and locked amounts of `netuid`, to reconstruct its supply.
+    pub fn get_subnet_supply_info(netuid: NetUid) -> Option<SubnetSupplyInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        Some(SubnetSupplyInfo {
+            netuid: netuid.into(),
+            tao_recycled_for_registration: Self::get_rao_recycled(netuid).into(),
+            tao_burned: SubnetTaoBurned::<T>::get(netuid).into(),
+            tao_locked: Self::get_subnet_locked_balance(netuid).into(),
+            alpha_out: SubnetAlphaOut::<T>::get(netuid).into(),
+            alpha_recycled: SubnetAlphaRecycled::<T>::get(netuid).into(),
+            alpha_burned: SubnetAlphaBurned::<T>::get(netuid).into(),
+        })
+    }
+
+    pub fn get_all_subnet_supply_info() -> Vec<SubnetSupplyInfo> {
+        Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter_map(Self::get_subnet_supply_info)
+            .collect()
+    }
+}
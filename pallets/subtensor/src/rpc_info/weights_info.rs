@@ -0,0 +1,115 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::NetUid;
+
+/// A sparse matrix entry: (row uid, column uid, value).
+pub type SparseEntry = (Compact<u16>, Compact<u16>, Compact<u16>);
+
+#[freeze_struct("b09736511aa6868b")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetWeightsSparse {
+    netuid: Compact<NetUid>,
+    num_uids: Compact<u16>,
+    weights: Vec<SparseEntry>, // non-zero weights as (validator uid, miner uid, weight)
+    bonds: Vec<SparseEntry>,   // non-zero bonds as (validator uid, miner uid, bond)
+}
+
+#[freeze_struct("4f0a5dc263312931")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct StaleWeightsInfo {
+    netuid: Compact<NetUid>,
+    block: Compact<u64>,
+    max_weight_age: Compact<u64>,  // zero if weights never expire
+    stale_uids: Vec<Compact<u16>>, // validators whose weights no longer count in the epoch
+}
+
+#[freeze_struct("6418adfc64a85335")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct WeightsVersionInfo {
+    netuid: Compact<NetUid>,
+    block: Compact<u64>,
+    version_key: Compact<u64>, // zero if any version is accepted
+    pending_version_key: Option<Compact<u64>>,
+    effective_block: Option<Compact<u64>>, // block at which the pending key applies
+    grace_period: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the weights and bonds matrices of a subnet in sparse triplet form.
+    pub fn get_weights_sparse(netuid: NetUid) -> Option<SubnetWeightsSparse> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let n: u16 = Self::get_subnetwork_n(netuid);
+        let mut weights: Vec<SparseEntry> = Vec::new();
+        let mut bonds: Vec<SparseEntry> = Vec::new();
+        for uid in 0..n {
+            weights.extend(
+                Weights::<T>::get(netuid, uid)
+                    .into_iter()
+                    .filter(|(_, value)| *value > 0)
+                    .map(|(dest, value)| (uid.into(), dest.into(), value.into())),
+            );
+            bonds.extend(
+                Bonds::<T>::get(netuid, uid)
+                    .into_iter()
+                    .filter(|(_, value)| *value > 0)
+                    .map(|(dest, value)| (uid.into(), dest.into(), value.into())),
+            );
+        }
+
+        Some(SubnetWeightsSparse {
+            netuid: netuid.into(),
+            num_uids: n.into(),
+            weights,
+            bonds,
+        })
+    }
+
+    /// Returns the maximum weight age of a subnet and the validator permit holders whose weights
+    /// are older than it, which have zero weight in the epoch.
+    pub fn get_stale_weights_info(netuid: NetUid) -> Option<StaleWeightsInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let block = Self::get_current_block_as_u64();
+        let last_update = Self::get_last_update(netuid);
+        let stale_uids = Self::get_stale_weights(netuid, &last_update, block)
+            .into_iter()
+            .zip(Self::get_validator_permit(netuid))
+            .zip(0u16..)
+            .filter(|((stale, permit), _)| *stale && *permit)
+            .map(|(_, uid)| uid.into())
+            .collect();
+
+        Some(StaleWeightsInfo {
+            netuid: netuid.into(),
+            block: block.into(),
+            max_weight_age: Self::get_max_weight_age(netuid).into(),
+            stale_uids,
+        })
+    }
+
+    /// Returns the weights version key a subnet requires now, and the one it will require once
+    /// the grace period ends, if any.
+    pub fn get_weights_version_info(netuid: NetUid) -> Option<WeightsVersionInfo> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let pending = Self::get_pending_weights_version_key(netuid);
+        Some(WeightsVersionInfo {
+            netuid: netuid.into(),
+            block: Self::get_current_block_as_u64().into(),
+            version_key: Self::get_weights_version_key(netuid).into(),
+            pending_version_key: pending.map(|(version_key, _)| version_key.into()),
+            effective_block: pending.map(|(_, effective_block)| effective_block.into()),
+            grace_period: Self::get_weights_version_key_grace_period(netuid).into(),
+        })
+    }
+}
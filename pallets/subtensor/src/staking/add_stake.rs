@@ -1,7 +1,11 @@
+use safe_math::*;
+use sp_std::collections::btree_set::BTreeSet;
 use substrate_fixed::types::I96F32;
 use subtensor_runtime_common::{NetUid, TaoCurrency};
 use subtensor_swap_interface::{OrderType, SwapHandler};
 
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+
 use super::*;
 
 impl<T: Config> Pallet<T> {
@@ -62,10 +66,14 @@ impl<T: Config> Pallet<T> {
         )?;
 
         // 3. Ensure the remove operation from the coldkey is a success.
-        let tao_staked: I96F32 =
-            Self::remove_balance_from_coldkey_account(&coldkey, stake_to_be_added.into())?
-                .to_u64()
-                .into();
+        let tao_staked: I96F32 = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Stake,
+            stake_to_be_added,
+            LedgerReason::Stake,
+        )?
+        .to_u64()
+        .into();
 
         // 4. Swap the stake into alpha on the subnet and increase counters.
         // Emit the staking event.
@@ -159,8 +167,12 @@ impl<T: Config> Pallet<T> {
         }
 
         // 5. Ensure the remove operation from the coldkey is a success.
-        let tao_staked =
-            Self::remove_balance_from_coldkey_account(&coldkey, possible_stake.into())?;
+        let tao_staked = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Stake,
+            possible_stake,
+            LedgerReason::Stake,
+        )?;
 
         // 6. Swap the stake into alpha on the subnet and increase counters.
         // Emit the staking event.
@@ -170,6 +182,77 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic add_stake_multiple: Splits one TAO amount across
+    /// several hotkeys on a subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - Subnetwork UID
+    ///
+    /// * 'stake_to_be_added' (u64):
+    ///     -  The total amount of TAO to stake.
+    ///
+    /// * 'splits' (Vec<(u64, T::AccountId)>):
+    ///     -  Relative proportions and hotkeys. Each hotkey receives its proportion of the total,
+    ///        rounding dust goes to the last hotkey.
+    ///
+    /// # Event:
+    /// * StakeAdded;
+    ///     -  Once per hotkey.
+    ///
+    /// # Raises:
+    /// * 'InvalidStakeSplit':
+    ///     -  The split is empty, longer than MAX_STAKE_SPLITS, has duplicate hotkeys or a zero proportion.
+    ///
+    /// * Any error of add_stake for one of the hotkeys, in which case no stake is added at all.
+    ///
+    pub fn do_add_stake_multiple(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        stake_to_be_added: TaoCurrency,
+        splits: Vec<(u64, T::AccountId)>,
+    ) -> dispatch::DispatchResult {
+        // 1. Validate the split.
+        ensure!(
+            !splits.is_empty() && splits.len() <= MAX_STAKE_SPLITS,
+            Error::<T>::InvalidStakeSplit
+        );
+        ensure!(
+            splits.iter().all(|(proportion, _)| *proportion > 0),
+            Error::<T>::InvalidStakeSplit
+        );
+        let unique: BTreeSet<&T::AccountId> = splits.iter().map(|(_, hotkey)| hotkey).collect();
+        ensure!(unique.len() == splits.len(), Error::<T>::InvalidStakeSplit);
+
+        // 2. Compute each hotkey's share, the last one takes the rounding remainder.
+        let total: u128 = u64::from(stake_to_be_added).into();
+        let proportion_sum: u128 = splits.iter().fold(0u128, |acc, (proportion, _)| {
+            acc.saturating_add((*proportion).into())
+        });
+        let mut remaining = stake_to_be_added;
+        let last = splits.len().saturating_sub(1);
+
+        // 3. Stake into each hotkey. The extrinsic is transactional, so any failure reverts every
+        // stake added before it.
+        for (i, (proportion, hotkey)) in splits.into_iter().enumerate() {
+            let amount = if i == last {
+                remaining
+            } else {
+                let share = total
+                    .saturating_mul(proportion.into())
+                    .safe_div(proportion_sum);
+                TaoCurrency::from(u64::try_from(share).unwrap_or(u64::MAX))
+            };
+            remaining = remaining.saturating_sub(amount);
+            Self::do_add_stake(origin.clone(), hotkey, netuid, amount)?;
+        }
+
+        Ok(())
+    }
+
     // Returns the maximum amount of RAO that can be executed with price limit
     pub fn get_max_amount_add(netuid: NetUid, limit_price: TaoCurrency) -> Result<u64, Error<T>> {
         // Corner case: root and stao
@@ -0,0 +1,115 @@
+//! This file defines auto-compounding, the preference of a coldkey for restaking the dividends of
+//! a hotkey it nominates.
+//!
+//! The dividends a hotkey earns for its nominators are added to the stake of the hotkey, so by
+//! default they compound. A coldkey can turn this off for a hotkey with `set_auto_compound`. Its
+//! share of the dividends of the hotkey is then unstaked as they are distributed and credited to
+//! it as TAO, through the unbonding queue of the subnet if it has one. Only coldkeys that opted
+//! out are stored, at most [`MAX_AUTO_COMPOUND_OPT_OUTS`] per hotkey, which bounds the work done
+//! in the coinbase.
+
+use super::*;
+use safe_math::*;
+use sp_std::vec::Vec;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// Maximum number of coldkeys that do not auto-compound the dividends of a hotkey.
+pub const MAX_AUTO_COMPOUND_OPT_OUTS: usize = 64;
+
+impl<T: Config> Pallet<T> {
+    /// Sets whether the dividends `hotkey` earns for the calling coldkey are restaked.
+    pub fn do_set_auto_compound(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        auto_compound: bool,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        if auto_compound {
+            AutoCompound::<T>::remove(&hotkey, &coldkey);
+        } else if AutoCompound::<T>::get(&hotkey, &coldkey) {
+            ensure!(
+                AutoCompound::<T>::iter_prefix(&hotkey).count() < MAX_AUTO_COMPOUND_OPT_OUTS,
+                Error::<T>::TooManyAutoCompoundOptOuts
+            );
+            AutoCompound::<T>::insert(&hotkey, &coldkey, false);
+        }
+
+        Self::deposit_event(Event::AutoCompoundSet {
+            coldkey,
+            hotkey,
+            auto_compound,
+        });
+
+        Ok(())
+    }
+
+    /// The shares of `dividends` on `netuid` of the coldkeys that do not auto-compound the
+    /// dividends of `hotkey`, read before the dividends are added to the stake of the hotkey.
+    pub fn get_auto_compound_opt_out_shares(
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        dividends: AlphaCurrency,
+    ) -> Vec<(T::AccountId, AlphaCurrency)> {
+        let total = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+        if total.is_zero() || dividends.is_zero() {
+            return Vec::new();
+        }
+
+        AutoCompound::<T>::iter_prefix(hotkey)
+            .filter(|(_, auto_compound)| !auto_compound)
+            .filter_map(|(coldkey, _)| {
+                let stake =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, &coldkey, netuid);
+                let share = U96F32::saturating_from_num(dividends.to_u64())
+                    .saturating_mul(U96F32::saturating_from_num(stake.to_u64()))
+                    .safe_div(U96F32::saturating_from_num(total.to_u64()))
+                    .saturating_to_num::<u64>();
+                (share > 0).then(|| (coldkey, share.into()))
+            })
+            .collect()
+    }
+
+    /// Unstakes the dividend `shares` from `hotkey` on `netuid` and credits them as TAO, once the
+    /// dividends have been added to the stake of the hotkey.
+    ///
+    /// A share the pool cannot swap stays staked.
+    pub fn pay_out_auto_compound_opt_outs(
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        shares: Vec<(T::AccountId, AlphaCurrency)>,
+    ) {
+        for (coldkey, alpha) in shares {
+            match Self::unstake_from_subnet(
+                hotkey,
+                &coldkey,
+                netuid,
+                alpha,
+                T::SwapInterface::min_price().into(),
+                false,
+            ) {
+                Ok(tao) => Self::credit_unstaked_tao(&coldkey, netuid, tao),
+                Err(e) => log::debug!(
+                    "dividends of {coldkey:?} on {hotkey:?} not paid out on {netuid:?}: {e:?}"
+                ),
+            }
+        }
+    }
+
+    /// Moves the auto-compounding preferences of the nominators of `old_hotkey` to `new_hotkey`,
+    /// returning how many were moved.
+    pub fn swap_auto_compound(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) -> u64 {
+        let mut moved = 0u64;
+        for (coldkey, auto_compound) in AutoCompound::<T>::drain_prefix(old_hotkey) {
+            AutoCompound::<T>::insert(new_hotkey, coldkey, auto_compound);
+            moved = moved.saturating_add(1);
+        }
+        moved
+    }
+}
@@ -49,8 +49,9 @@ impl<T: Config> Pallet<T> {
         let min_take = MinDelegateTake::<T>::get();
         ensure!(take >= min_take, Error::<T>::DelegateTakeTooLow);
 
-        // --- 4. Set the new take value.
+        // --- 4. Set the new take value. Decreases apply immediately and drop any pending increase.
         Delegates::<T>::insert(hotkey.clone(), take);
+        Self::remove_pending_delegate_take(&hotkey);
 
         // --- 5. Set last block for rate limiting
         let block: u64 = Self::get_current_block_as_u64();
@@ -0,0 +1,143 @@
+use super::*;
+
+/// Maximum number of coldkeys added to or removed from a delegate staking list in one call.
+pub const MAX_DELEGATE_STAKING_LIST_UPDATE: u32 = 64;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_delegate_staking_access
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'access' (Option<DelegateStakingAccess>):
+    ///     - Whether `DelegateStakingList` is an allowlist or a denylist, `None` lets every
+    ///       coldkey stake.
+    ///
+    /// # Event:
+    /// * DelegateStakingAccessSet;
+    ///     - On successfully setting the access mode for this hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey we are delegating is not registered on the network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    pub fn do_set_delegate_staking_access(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        access: Option<DelegateStakingAccess>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_delegate_staking_access( origin:{coldkey:?} hotkey:{hotkey:?}, access:{access:?} )"
+        );
+
+        // --- 2. Ensure we are delegating a known key.
+        //        Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Set or clear the access mode. The list itself is kept.
+        match access {
+            Some(access) => DelegateStakingAccessMode::<T>::insert(&hotkey, access),
+            None => DelegateStakingAccessMode::<T>::remove(&hotkey),
+        }
+
+        // --- 4. Emit the access mode.
+        Self::deposit_event(Event::DelegateStakingAccessSet(coldkey, hotkey, access));
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_delegate_staking_list
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'coldkeys' (Vec<T::AccountId>):
+    ///     - The coldkeys to add to or remove from the staking list of the hotkey.
+    ///
+    /// * 'listed' (bool):
+    ///     - Whether the coldkeys are added to (`true`) or removed from (`false`) the list.
+    ///
+    /// # Event:
+    /// * DelegateStakingListUpdated;
+    ///     - On successfully updating the list of this hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey we are delegating is not registered on the network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    /// * 'TooManyDelegateStakingListUpdates':
+    ///     - More than `MAX_DELEGATE_STAKING_LIST_UPDATE` coldkeys are given.
+    ///
+    pub fn do_set_delegate_staking_list(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        coldkeys: Vec<T::AccountId>,
+        listed: bool,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_delegate_staking_list( origin:{coldkey:?} hotkey:{hotkey:?}, coldkeys:{coldkeys:?}, listed:{listed:?} )"
+        );
+
+        // --- 2. Ensure we are delegating a known key.
+        //        Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+        ensure!(
+            coldkeys.len() <= MAX_DELEGATE_STAKING_LIST_UPDATE as usize,
+            Error::<T>::TooManyDelegateStakingListUpdates
+        );
+
+        // --- 3. Update the list.
+        for staker in coldkeys.iter() {
+            if listed {
+                DelegateStakingList::<T>::insert(&hotkey, staker, true);
+            } else {
+                DelegateStakingList::<T>::remove(&hotkey, staker);
+            }
+        }
+
+        // --- 4. Emit the update.
+        Self::deposit_event(Event::DelegateStakingListUpdated {
+            hotkey,
+            coldkeys,
+            listed,
+        });
+
+        Ok(())
+    }
+
+    /// Whether `hotkey` accepts stake from `coldkey` under its `DelegateStakingAccessMode`.
+    ///
+    /// The coldkey owning the hotkey can always stake to it.
+    pub fn delegate_accepts_staker(hotkey: &T::AccountId, coldkey: &T::AccountId) -> bool {
+        let Some(access) = DelegateStakingAccessMode::<T>::get(hotkey) else {
+            return true;
+        };
+        if Self::coldkey_owns_hotkey(coldkey, hotkey) {
+            return true;
+        }
+
+        let listed = DelegateStakingList::<T>::get(hotkey, coldkey);
+        match access {
+            DelegateStakingAccess::Allowlist => listed,
+            DelegateStakingAccess::Denylist => !listed,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+use subtensor_runtime_common::TaoCurrency;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_delegate_stake_cap
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>::RuntimeOrigin):
+    ///     - The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey we are delegating (must be owned by the coldkey.)
+    ///
+    /// * 'cap' (Option<TaoCurrency>):
+    ///     - The maximum total stake the hotkey accepts, `None` removes the cap.
+    ///
+    /// # Event:
+    /// * DelegateStakeCapSet;
+    ///     - On successfully setting the cap for this hotkey.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     - The hotkey we are delegating is not registered on the network.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey we are delegating is not owned by the calling coldkey.
+    ///
+    pub fn do_set_delegate_stake_cap(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        cap: Option<TaoCurrency>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the coldkey signature.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_set_delegate_stake_cap( origin:{coldkey:?} hotkey:{hotkey:?}, cap:{cap:?} )"
+        );
+
+        // --- 2. Ensure we are delegating a known key.
+        //        Ensure that the coldkey is the owner.
+        Self::do_take_checks(&coldkey, &hotkey)?;
+
+        // --- 3. Set or clear the cap.
+        match cap {
+            Some(cap) => DelegateStakeCap::<T>::insert(&hotkey, cap),
+            None => DelegateStakeCap::<T>::remove(&hotkey),
+        }
+
+        // --- 4. Emit the cap value.
+        Self::deposit_event(Event::DelegateStakeCapSet(coldkey, hotkey, cap));
+
+        Ok(())
+    }
+}
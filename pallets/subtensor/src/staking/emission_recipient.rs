@@ -0,0 +1,55 @@
+//! This file defines emission recipients, which receive the emission of a hotkey.
+//!
+//! By default the incentive and validator take earned by a hotkey accrue as stake of its owning
+//! coldkey. The owner can instead set an emission recipient with `set_emission_recipient`, such as
+//! an exchange or treasury address. The emission of the hotkey then accrues as stake of the
+//! recipient on the hotkey, which the recipient unstakes like any other stake. Nothing is sold
+//! inside the coinbase, so the emission never moves the subnet price.
+
+use super::*;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    /// Sets the account receiving the emission of `hotkey`, or restores the default of staking it
+    /// to the owning coldkey when `None`.
+    pub fn do_set_emission_recipient(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        recipient: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        match &recipient {
+            Some(recipient) => EmissionRecipient::<T>::insert(&hotkey, recipient),
+            None => EmissionRecipient::<T>::remove(&hotkey),
+        }
+
+        Self::deposit_event(Event::EmissionRecipientSet {
+            coldkey,
+            hotkey,
+            recipient,
+        });
+
+        Ok(())
+    }
+
+    /// Pays `alpha` emitted on `netuid` to the owner of `hotkey`: as stake on the hotkey of its
+    /// emission recipient if it has one, or of the owning coldkey otherwise.
+    pub fn pay_hotkey_owner_emission(hotkey: &T::AccountId, netuid: NetUid, alpha: AlphaCurrency) {
+        let coldkey =
+            EmissionRecipient::<T>::get(hotkey).unwrap_or_else(|| Owner::<T>::get(hotkey));
+        Self::increase_stake_for_hotkey_and_coldkey_on_subnet(hotkey, &coldkey, netuid, alpha);
+    }
+
+    /// Moves the emission recipient of `old_hotkey` to `new_hotkey`.
+    pub fn swap_emission_recipient(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) {
+        if let Some(recipient) = EmissionRecipient::<T>::take(old_hotkey) {
+            EmissionRecipient::<T>::insert(new_hotkey, recipient);
+        }
+    }
+}
@@ -208,7 +208,7 @@ impl<T: Config> Pallet<T> {
 
                 if let Ok(cleared_stake) = maybe_cleared_stake {
                     // Add the stake to the coldkey account.
-                    Self::add_balance_to_coldkey_account(coldkey, cleared_stake.into());
+                    Self::pay_unstaked_tao(coldkey, cleared_stake);
                 } else {
                     // Just clear small alpha
                     let alpha =
@@ -27,6 +27,10 @@ impl<T: Config> Pallet<T> {
     /// * 'TxRateLimitExceeded':
     ///     - Thrown if key has hit transaction rate limit
     ///
+    /// # Notes:
+    /// If DelegateTakeNoticePeriod is non-zero the increase is queued and only applied once the
+    /// notice period has passed (TakeIncreaseScheduled is emitted instead of TakeIncreased).
+    ///
     /// * 'DelegateTakeTooLow':
     ///     - The delegate is setting a take which is not greater than the previous.
     ///
@@ -65,14 +69,62 @@ impl<T: Config> Pallet<T> {
         // Set last block for rate limiting
         Self::set_last_tx_block_delegate_take(&hotkey, block);
 
-        // --- 6. Set the new take value.
-        Delegates::<T>::insert(hotkey.clone(), take);
+        // --- 6. Set the new take value, or queue it behind the notice period so nominators
+        // can react before it applies. A new increase replaces any pending one.
+        let notice_period = Self::get_delegate_take_notice_period();
+        Self::remove_pending_delegate_take(&hotkey);
+        if notice_period == 0 {
+            Delegates::<T>::insert(hotkey.clone(), take);
+
+            // --- 7. Emit the take value.
+            log::debug!("TakeIncreased( coldkey:{coldkey:?}, hotkey:{hotkey:?}, take:{take:?} )");
+            Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+        } else {
+            let effective_block = block.saturating_add(notice_period);
+            PendingDelegateTake::<T>::insert(&hotkey, (take, effective_block));
+            PendingDelegateTakeQueue::<T>::insert(effective_block, &hotkey, take);
 
-        // --- 7. Emit the take value.
-        log::debug!("TakeIncreased( coldkey:{coldkey:?}, hotkey:{hotkey:?}, take:{take:?} )");
-        Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+            // --- 7. Emit the scheduled take value.
+            log::debug!(
+                "TakeIncreaseScheduled( coldkey:{coldkey:?}, hotkey:{hotkey:?}, take:{take:?}, effective_block:{effective_block:?} )"
+            );
+            Self::deposit_event(Event::TakeIncreaseScheduled(
+                coldkey,
+                hotkey,
+                take,
+                effective_block,
+            ));
+        }
 
         // --- 8. Ok and return.
         Ok(())
     }
+
+    /// Drops the queued take increase of `hotkey`, if any.
+    pub fn remove_pending_delegate_take(hotkey: &T::AccountId) {
+        if let Some((_, effective_block)) = PendingDelegateTake::<T>::take(hotkey) {
+            PendingDelegateTakeQueue::<T>::remove(effective_block, hotkey);
+        }
+    }
+
+    /// Applies the queued take increases whose notice period ends at `block_number`, returning
+    /// the weight used.
+    pub fn apply_pending_delegate_takes(block_number: u64) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let max_take = MaxDelegateTake::<T>::get();
+
+        for (hotkey, take) in PendingDelegateTakeQueue::<T>::drain_prefix(block_number) {
+            PendingDelegateTake::<T>::remove(&hotkey);
+            // Re-check the bound, governance may have lowered it during the notice period.
+            let take = take.min(max_take);
+            Delegates::<T>::insert(&hotkey, take);
+
+            let coldkey = Owner::<T>::get(&hotkey);
+            log::debug!("TakeIncreased( coldkey:{coldkey:?}, hotkey:{hotkey:?}, take:{take:?} )");
+            Self::deposit_event(Event::TakeIncreased(coldkey, hotkey, take));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+        }
+
+        weight
+    }
 }
@@ -1,11 +1,17 @@
 use super::*;
 pub mod account;
 pub mod add_stake;
+pub mod auto_compound;
 pub mod decrease_take;
+pub mod delegate_access;
+pub mod delegate_stake_cap;
+pub mod emission_recipient;
 pub mod helpers;
 pub mod increase_take;
 pub mod move_stake;
 pub mod recycle_alpha;
 pub mod remove_stake;
 pub mod set_children;
+pub mod stake_lock;
 pub mod stake_utils;
+pub mod unbonding;
@@ -349,6 +349,14 @@ impl<T: Config> Pallet<T> {
             max_amount
         };
 
+        // The moved stake keeps the unbonding period of the subnet it leaves.
+        Self::carry_unbonding_floor(
+            origin_coldkey,
+            origin_netuid,
+            destination_coldkey,
+            destination_netuid,
+        );
+
         if origin_netuid != destination_netuid {
             // do not pay remove fees to avoid double fees in moves transactions
             let tao_unstaked = Self::unstake_from_subnet(
@@ -47,6 +47,7 @@ impl<T: Config> Pallet<T> {
         let alpha_available =
             Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
         let amount = amount.min(alpha_available);
+        Self::ensure_stake_not_locked(&hotkey, &coldkey, netuid, amount)?;
 
         ensure!(
             SubnetAlphaOut::<T>::get(netuid) >= amount,
@@ -62,6 +63,7 @@ impl<T: Config> Pallet<T> {
         SubnetAlphaOut::<T>::mutate(netuid, |total| {
             *total = total.saturating_sub(actual_alpha_decrease);
         });
+        Self::record_subnet_alpha_recycled(netuid, actual_alpha_decrease);
 
         Self::deposit_event(Event::AlphaRecycled(
             coldkey,
@@ -117,6 +119,7 @@ impl<T: Config> Pallet<T> {
         let alpha_available =
             Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
         let amount = amount.min(alpha_available);
+        Self::ensure_stake_not_locked(&hotkey, &coldkey, netuid, amount)?;
 
         ensure!(
             SubnetAlphaOut::<T>::get(netuid) >= amount,
@@ -129,6 +132,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // This is a burn, so we don't need to update AlphaOut.
+        Self::record_subnet_alpha_burned(netuid, actual_alpha_decrease);
 
         // Deposit event
         Self::deposit_event(Event::AlphaBurned(
@@ -76,8 +76,8 @@ impl<T: Config> Pallet<T> {
             false,
         )?;
 
-        // 4. We add the balance to the coldkey. If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, tao_unstaked.into());
+        // 4. We credit the coldkey, through the unbonding queue if the subnet has one. If the above fails we will not credit this coldkey.
+        Self::credit_unstaked_tao(&coldkey, netuid, tao_unstaked);
 
         // 5. If the stake is below the minimum, we clear the nomination from storage.
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
@@ -171,8 +171,8 @@ impl<T: Config> Pallet<T> {
                     false,
                 )?;
 
-                // Add the balance to the coldkey. If the above fails we will not credit this coldkey.
-                Self::add_balance_to_coldkey_account(&coldkey, tao_unstaked.into());
+                // Credit the coldkey, through the unbonding queue if the subnet has one. If the above fails we will not credit this coldkey.
+                Self::credit_unstaked_tao(&coldkey, netuid, tao_unstaked);
 
                 // If the stake is below the minimum, we clear the nomination from storage.
                 Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
@@ -267,6 +267,9 @@ impl<T: Config> Pallet<T> {
                     // Increment total
                     total_tao_unstaked = total_tao_unstaked.saturating_add(tao_unstaked);
 
+                    // The root stake keeps the unbonding period of the subnet it comes from.
+                    Self::carry_unbonding_floor(&coldkey, netuid, &coldkey, NetUid::ROOT);
+
                     // If the stake is below the minimum, we clear the nomination from storage.
                     Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
                 }
@@ -369,8 +372,8 @@ impl<T: Config> Pallet<T> {
             false,
         )?;
 
-        // 5. We add the balance to the coldkey. If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, tao_unstaked.into());
+        // 5. We credit the coldkey, through the unbonding queue if the subnet has one. If the above fails we will not credit this coldkey.
+        Self::credit_unstaked_tao(&coldkey, netuid, tao_unstaked);
 
         // 6. If the stake is below the minimum, we clear the nomination from storage.
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, netuid);
@@ -0,0 +1,257 @@
+//! This file defines conviction staking, where stake is locked for a period in exchange for a boost
+//! of its weight.
+//!
+//! `add_stake_locked` adds stake like `add_stake` and locks the alpha it bought until the end of
+//! the chosen `StakeLockPeriod`. While locked, the alpha cannot be unstaked, moved, transferred or
+//! recycled, and `StakeLockBoost` percent of it is added to the stake of the hotkey in the epoch,
+//! weighting its consensus and dividends. The boost is kept per locking coldkey in [`LockBoost`],
+//! and the share of the dividends of the hotkey it earns is paid to the lockers only, so the other
+//! nominators of the hotkey do not share it. Locking again on the same hotkey and subnet adds to
+//! the existing lock and keeps the later unlock block.
+//!
+//! The lock is lifted, and its boost removed, by `unlock_stake` once the unlock block is reached.
+
+use super::*;
+use safe_math::*;
+use sp_std::vec::Vec;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+
+/// The alpha locked by a coldkey on a hotkey and subnet.
+#[freeze_struct("c9d720f12ca72070")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StakeLock {
+    /// The locked alpha.
+    pub alpha: AlphaCurrency,
+    /// The weight added to the hotkey's stake for the lock.
+    pub boost: AlphaCurrency,
+    /// The block from which the lock can be lifted.
+    pub unlock_block: u64,
+}
+
+impl StakeLock {
+    /// Combines two locks of the same coldkey, hotkey and subnet.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            alpha: self.alpha.saturating_add(other.alpha),
+            boost: self.boost.saturating_add(other.boost),
+            unlock_block: self.unlock_block.max(other.unlock_block),
+        }
+    }
+}
+
+impl StakeLockPeriod {
+    /// The number of blocks the stake stays locked.
+    pub fn blocks(&self) -> u64 {
+        match self {
+            StakeLockPeriod::Days30 => 216_000,
+            StakeLockPeriod::Days90 => 648_000,
+            StakeLockPeriod::Days180 => 1_296_000,
+        }
+    }
+
+    /// The boost, in percent of the locked alpha, used until root sets `StakeLockBoost`.
+    pub fn default_boost(&self) -> u16 {
+        match self {
+            StakeLockPeriod::Days30 => 10,
+            StakeLockPeriod::Days90 => 25,
+            StakeLockPeriod::Days180 => 50,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds stake to `hotkey` on `netuid` and locks the alpha it bought for `period`.
+    pub fn do_add_stake_locked(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        stake_to_be_added: TaoCurrency,
+        period: StakeLockPeriod,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin.clone())?;
+
+        let alpha_before =
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        Self::do_add_stake(origin, hotkey.clone(), netuid, stake_to_be_added)?;
+        let alpha = Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid)
+            .saturating_sub(alpha_before);
+
+        let boost = Self::get_stake_lock_boost_amount(period, alpha);
+        let lock = StakeLock {
+            alpha,
+            boost,
+            unlock_block: Self::get_current_block_as_u64().saturating_add(period.blocks()),
+        };
+        let lock = match StakeLocks::<T>::get((&hotkey, &coldkey, netuid)) {
+            Some(existing) => existing.merge(lock),
+            None => lock,
+        };
+        StakeLocks::<T>::insert((&hotkey, &coldkey, netuid), lock);
+        LockBoost::<T>::insert((netuid, &hotkey, &coldkey), lock.boost);
+
+        Self::deposit_event(Event::StakeLocked {
+            coldkey,
+            hotkey,
+            netuid,
+            alpha,
+            period,
+            unlock_block: lock.unlock_block,
+        });
+
+        Ok(())
+    }
+
+    /// Lifts the expired stake lock of the caller on `hotkey` and `netuid`, removing its boost.
+    pub fn do_unlock_stake(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        let lock =
+            StakeLocks::<T>::get((&hotkey, &coldkey, netuid)).ok_or(Error::<T>::NoStakeLock)?;
+        ensure!(
+            Self::get_current_block_as_u64() >= lock.unlock_block,
+            Error::<T>::StakeLockNotExpired
+        );
+
+        StakeLocks::<T>::remove((&hotkey, &coldkey, netuid));
+        LockBoost::<T>::remove((netuid, &hotkey, &coldkey));
+
+        Self::deposit_event(Event::StakeUnlocked {
+            coldkey,
+            hotkey,
+            netuid,
+            alpha: lock.alpha,
+        });
+
+        Ok(())
+    }
+
+    /// The alpha of `coldkey` on `hotkey` and `netuid` that cannot leave the stake.
+    pub fn get_locked_stake(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+    ) -> AlphaCurrency {
+        StakeLocks::<T>::get((hotkey, coldkey, netuid))
+            .map(|lock| lock.alpha)
+            .unwrap_or(AlphaCurrency::ZERO)
+    }
+
+    /// Ensures removing `alpha` from the stake of `coldkey` on `hotkey` and `netuid` leaves its
+    /// locked alpha in place.
+    pub fn ensure_stake_not_locked(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+    ) -> Result<(), Error<T>> {
+        let locked = Self::get_locked_stake(hotkey, coldkey, netuid);
+        if locked.is_zero() {
+            return Ok(());
+        }
+
+        let remaining = Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid)
+            .saturating_sub(alpha);
+        ensure!(remaining >= locked, Error::<T>::StakeLocked);
+
+        Ok(())
+    }
+
+    /// The weight added to the stake of a hotkey for `alpha` locked for `period`.
+    pub fn get_stake_lock_boost_amount(
+        period: StakeLockPeriod,
+        alpha: AlphaCurrency,
+    ) -> AlphaCurrency {
+        let boost = StakeLockBoost::<T>::get(period).unwrap_or_else(|| period.default_boost());
+        U96F32::saturating_from_num(alpha.to_u64())
+            .saturating_mul(U96F32::saturating_from_num(boost))
+            .safe_div(U96F32::saturating_from_num(100))
+            .saturating_to_num::<u64>()
+            .into()
+    }
+
+    /// The weight added to the stake of `hotkey` on `netuid` by the locks on it.
+    pub fn get_hotkey_lock_boost(netuid: NetUid, hotkey: &T::AccountId) -> AlphaCurrency {
+        LockBoost::<T>::iter_prefix_values((netuid, hotkey))
+            .fold(AlphaCurrency::ZERO, |total, boost| {
+                total.saturating_add(boost)
+            })
+    }
+
+    /// Pays the lockers on `hotkey` the share of its `dividends` on `netuid` earned by their
+    /// boost, as stake, returning the dividends left for all the nominators.
+    ///
+    /// The boost earns the share it adds to the weight of the hotkey, and each locker is paid in
+    /// proportion to its boost.
+    pub fn pay_lock_boost_dividends(
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        dividends: AlphaCurrency,
+    ) -> AlphaCurrency {
+        let boosts: Vec<(T::AccountId, AlphaCurrency)> =
+            LockBoost::<T>::iter_prefix((netuid, hotkey)).collect();
+        let total_boost = boosts
+            .iter()
+            .fold(AlphaCurrency::ZERO, |total, (_, boost)| {
+                total.saturating_add(*boost)
+            });
+        if total_boost.is_zero() || dividends.is_zero() {
+            return dividends;
+        }
+
+        let weight =
+            Self::get_inherited_for_hotkey_on_subnet(hotkey, netuid).saturating_add(total_boost);
+        let boost_dividends = U96F32::saturating_from_num(dividends.to_u64())
+            .saturating_mul(U96F32::saturating_from_num(total_boost.to_u64()))
+            .safe_div(U96F32::saturating_from_num(weight.to_u64()));
+
+        let mut paid = AlphaCurrency::ZERO;
+        for (coldkey, boost) in boosts {
+            let share: AlphaCurrency = boost_dividends
+                .saturating_mul(U96F32::saturating_from_num(boost.to_u64()))
+                .safe_div(U96F32::saturating_from_num(total_boost.to_u64()))
+                .saturating_to_num::<u64>()
+                .into();
+            if share.is_zero() {
+                continue;
+            }
+            let share = Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                hotkey, &coldkey, netuid, share,
+            );
+            paid = paid.saturating_add(share);
+        }
+
+        dividends.saturating_sub(paid)
+    }
+
+    /// Sets the boost, in percent of the locked alpha, of locks of `period`.
+    pub fn set_stake_lock_boost(period: StakeLockPeriod, boost: u16) {
+        StakeLockBoost::<T>::insert(period, boost);
+        Self::deposit_event(Event::StakeLockBoostSet(period, boost));
+    }
+
+    /// Moves the stake lock of `old_hotkey` and `old_coldkey` to the new keys, merging it into any
+    /// lock they already have.
+    pub fn swap_stake_lock(
+        old_hotkey: &T::AccountId,
+        old_coldkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+        netuid: NetUid,
+    ) {
+        let Some(lock) = StakeLocks::<T>::take((old_hotkey, old_coldkey, netuid)) else {
+            return;
+        };
+        let lock = match StakeLocks::<T>::get((new_hotkey, new_coldkey, netuid)) {
+            Some(existing) => existing.merge(lock),
+            None => lock,
+        };
+        StakeLocks::<T>::insert((new_hotkey, new_coldkey, netuid), lock);
+        LockBoost::<T>::remove((netuid, old_hotkey, old_coldkey));
+        LockBoost::<T>::insert((netuid, new_hotkey, new_coldkey), lock.boost);
+    }
+}
@@ -150,22 +150,35 @@ impl<T: Config> Pallet<T> {
     ///
     pub fn get_stake_weights_for_network(
         netuid: NetUid,
+    ) -> (Vec<I64F64>, Vec<I64F64>, Vec<I64F64>) {
+        // Step 1: Get subnetwork size
+        let n: u16 = Self::get_subnetwork_n(netuid);
+
+        Self::get_stake_weights_for_uids(netuid, 0..n)
+    }
+
+    /// Calculates the weighted combination of alpha and global tao for the hotkeys of the UIDs
+    /// in `uids` on a subnet, ordered by uid.
+    ///
+    pub fn get_stake_weights_for_uids(
+        netuid: NetUid,
+        uids: sp_std::ops::Range<u16>,
     ) -> (Vec<I64F64>, Vec<I64F64>, Vec<I64F64>) {
         // Retrieve the global tao weight.
         let tao_weight: I64F64 = I64F64::saturating_from_num(Self::get_tao_weight());
         log::debug!("tao_weight: {tao_weight:?}");
 
-        // Step 1: Get subnetwork size
-        let n: u16 = Self::get_subnetwork_n(netuid);
-
         // Step 2: Get stake of all hotkeys (neurons) ordered by uid
-        let alpha_stake: Vec<I64F64> = (0..n)
+        let alpha_stake: Vec<I64F64> = uids
+            .clone()
             .map(|uid| {
                 if Keys::<T>::contains_key(netuid, uid) {
                     let hotkey: T::AccountId = Keys::<T>::get(netuid, uid);
-                    I64F64::saturating_from_num(Self::get_inherited_for_hotkey_on_subnet(
-                        &hotkey, netuid,
-                    ))
+                    // Locked stake weighs more, see `staking::stake_lock`.
+                    I64F64::saturating_from_num(
+                        Self::get_inherited_for_hotkey_on_subnet(&hotkey, netuid)
+                            .saturating_add(Self::get_hotkey_lock_boost(netuid, &hotkey)),
+                    )
                 } else {
                     I64F64::saturating_from_num(0)
                 }
@@ -175,7 +188,7 @@ impl<T: Config> Pallet<T> {
 
         // Step 3: Calculate the global tao stake vector.
         // Initialize a vector to store global tao stakes for each neuron.
-        let tao_stake: Vec<I64F64> = (0..n)
+        let tao_stake: Vec<I64F64> = uids
             .map(|uid| {
                 if Keys::<T>::contains_key(netuid, uid) {
                     let hotkey: T::AccountId = Keys::<T>::get(netuid, uid);
@@ -563,7 +576,8 @@ impl<T: Config> Pallet<T> {
 
     /// Swaps TAO for the alpha token on the subnet.
     ///
-    /// Updates TaoIn, AlphaIn, and AlphaOut
+    /// Updates TaoIn, AlphaIn, and AlphaOut. The caller books the TAO paid in to
+    /// [`LedgerAccount::Stake`](crate::utils::ledger::LedgerAccount::Stake).
     pub fn swap_tao_for_alpha(
         netuid: NetUid,
         tao: TaoCurrency,
@@ -599,9 +613,6 @@ impl<T: Config> Pallet<T> {
                 *total = total.saturating_add((swap_result.tao_reserve_delta as u64).into());
             });
 
-            // Increase Total Tao reserves.
-            TotalStake::<T>::mutate(|total| *total = total.saturating_add(tao));
-
             // Increase total subnet TAO volume.
             SubnetVolume::<T>::mutate(netuid, |total| {
                 *total = total.saturating_add(tao.to_u64() as u128);
@@ -623,7 +634,8 @@ impl<T: Config> Pallet<T> {
 
     /// Swaps a subnet's Alpha token for TAO.
     ///
-    /// Updates TaoIn, AlphaIn, and AlphaOut
+    /// Updates TaoIn, AlphaIn, and AlphaOut. The caller books the TAO paid out of
+    /// [`LedgerAccount::Stake`](crate::utils::ledger::LedgerAccount::Stake).
     pub fn swap_alpha_for_tao(
         netuid: NetUid,
         alpha: AlphaCurrency,
@@ -667,11 +679,6 @@ impl<T: Config> Pallet<T> {
                     .into(),
             );
 
-            // Reduce total TAO reserves.
-            TotalStake::<T>::mutate(|total| {
-                *total = total.saturating_sub(swap_result.amount_paid_out.into())
-            });
-
             // Increase total subnet TAO volume.
             SubnetVolume::<T>::mutate(netuid, |total| {
                 *total = total.saturating_add(swap_result.amount_paid_out.into())
@@ -964,6 +971,15 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAccountNotExists
         );
 
+        // Ensure the delegate accepts this much more stake.
+        Self::ensure_delegate_stake_cap(hotkey, stake_to_be_added)?;
+
+        // Ensure the delegate accepts stake from this coldkey.
+        ensure!(
+            Self::delegate_accepts_staker(hotkey, coldkey),
+            Error::<T>::DelegateStakingNotAllowed
+        );
+
         let swap_result =
             T::SwapInterface::sim_swap(netuid.into(), OrderType::Buy, stake_to_be_added.into())
                 .map_err(|_| Error::<T>::InsufficientLiquidity)?;
@@ -1015,6 +1031,7 @@ impl<T: Config> Pallet<T> {
         // Bypass this check if the user unstakes full amount
         let remaining_alpha_stake =
             Self::calculate_reduced_stake_on_subnet(hotkey, coldkey, netuid, alpha_unstaked)?;
+        Self::ensure_stake_not_locked(hotkey, coldkey, netuid, alpha_unstaked)?;
         match T::SwapInterface::sim_swap(netuid.into(), OrderType::Sell, alpha_unstaked.into()) {
             Ok(res) => {
                 if !remaining_alpha_stake.is_zero() {
@@ -1078,6 +1095,21 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Ensures the delegate `hotkey` stays under its stake cap, if any, when `tao` more stake is
+    /// delegated to it.
+    pub fn ensure_delegate_stake_cap(
+        hotkey: &T::AccountId,
+        tao: TaoCurrency,
+    ) -> Result<(), Error<T>> {
+        if let Some(cap) = DelegateStakeCap::<T>::get(hotkey) {
+            ensure!(
+                Self::get_total_stake_for_hotkey(hotkey).saturating_add(tao) <= cap,
+                Error::<T>::DelegateStakeCapExceeded
+            );
+        }
+        Ok(())
+    }
+
     /// Validate stake transition user input
     /// That works for move_stake, transfer_stake, and swap_stake
     ///
@@ -1148,6 +1180,18 @@ impl<T: Config> Pallet<T> {
             alpha_amount <= origin_alpha,
             Error::<T>::NotEnoughStakeToWithdraw
         );
+        Self::ensure_stake_not_locked(origin_hotkey, origin_coldkey, origin_netuid, alpha_amount)?;
+
+        // Ensure the destination delegate accepts the stake moved to it.
+        if origin_hotkey != destination_hotkey {
+            let alpha_price = U96F32::saturating_from_num(T::SwapInterface::current_alpha_price(
+                origin_netuid.into(),
+            ));
+            let tao_value = U96F32::saturating_from_num(alpha_amount)
+                .saturating_mul(alpha_price)
+                .saturating_to_num::<u64>();
+            Self::ensure_delegate_stake_cap(destination_hotkey, tao_value.into())?;
+        }
 
         // If origin and destination netuid are different, do the swap-related checks
         if origin_netuid != destination_netuid {
@@ -1235,6 +1279,9 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         netuid: NetUid,
     ) {
+        if StakingRateLimitExempt::<T>::get(coldkey) {
+            return;
+        }
         StakingOperationRateLimiter::<T>::insert((hotkey, coldkey, netuid), true);
     }
 
@@ -1244,12 +1291,24 @@ impl<T: Config> Pallet<T> {
         netuid: NetUid,
     ) -> Result<(), Error<T>> {
         ensure!(
-            !StakingOperationRateLimiter::<T>::contains_key((hotkey, coldkey, netuid)),
+            StakingRateLimitExempt::<T>::get(coldkey)
+                || !StakingOperationRateLimiter::<T>::contains_key((hotkey, coldkey, netuid)),
             Error::<T>::StakingOperationRateLimitExceeded
         );
 
         Ok(())
     }
+
+    /// Grants or revokes the exemption of `coldkey` from the staking operation rate limit.
+    pub fn set_staking_rate_limit_exemption(coldkey: &T::AccountId, exempt: bool) {
+        if exempt {
+            StakingRateLimitExempt::<T>::insert(coldkey, true);
+            Self::deposit_event(Event::StakingRateLimitExemptionGranted(coldkey.clone()));
+        } else {
+            StakingRateLimitExempt::<T>::remove(coldkey);
+            Self::deposit_event(Event::StakingRateLimitExemptionRevoked(coldkey.clone()));
+        }
+    }
 }
 
 ///////////////////////////////////////////
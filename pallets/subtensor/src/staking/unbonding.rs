@@ -0,0 +1,145 @@
+use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+
+impl<T: Config> Pallet<T> {
+    /// Credits TAO freed by an unstake to `coldkey`.
+    ///
+    /// If the subnet has no unbonding period the TAO goes straight to the free balance, otherwise
+    /// it is placed in the coldkey's [`UnbondingQueue`] until `now + period`, or the coldkey's
+    /// [`UnbondingFloor`] on the subnet if later. Chunks with the same release block are merged,
+    /// and once the queue holds [`MAX_UNBONDING_CHUNKS`] entries new TAO is folded into the last
+    /// chunk, which only ever delays its release.
+    pub fn credit_unstaked_tao(coldkey: &T::AccountId, netuid: NetUid, tao: TaoCurrency) {
+        let now = Self::get_current_block_as_u64();
+        let floor = UnbondingFloor::<T>::get(netuid, coldkey);
+        if floor != 0 && floor <= now {
+            UnbondingFloor::<T>::remove(netuid, coldkey);
+        }
+        let release_block = now
+            .saturating_add(Self::get_unbonding_period(netuid))
+            .max(floor);
+        if release_block <= now {
+            Self::pay_unstaked_tao(coldkey, tao);
+            return;
+        }
+        if tao.is_zero() {
+            return;
+        }
+
+        UnbondingQueue::<T>::mutate(coldkey, |queue| {
+            // Release blocks are non-decreasing for a given period, but periods differ across
+            // subnets, so keep the queue sorted.
+            match queue.iter_mut().find(|(block, _)| *block == release_block) {
+                Some((_, amount)) => *amount = amount.saturating_add(tao),
+                None if queue.len() < MAX_UNBONDING_CHUNKS => {
+                    queue.push((release_block, tao));
+                    queue.sort_by_key(|(block, _)| *block);
+                }
+                None => {
+                    if let Some((block, amount)) = queue.last_mut() {
+                        *block = (*block).max(release_block);
+                        *amount = amount.saturating_add(tao);
+                    }
+                }
+            }
+        });
+        let _ = Self::transfer_tao(
+            LedgerAccount::Stake,
+            LedgerAccount::Unbonding,
+            tao,
+            LedgerReason::Unstake,
+        );
+
+        Self::deposit_event(Event::StakeUnbonding {
+            coldkey: coldkey.clone(),
+            netuid,
+            amount: tao,
+            release_block,
+        });
+    }
+
+    /// Carries the unbonding period of the stake `origin_coldkey` moves out of `origin_netuid`
+    /// over to `destination_coldkey` on `destination_netuid`, so moving, swapping or transferring
+    /// alpha before unstaking it cannot release the TAO earlier than unstaking it directly.
+    ///
+    /// The [`UnbondingFloor`] is per coldkey and subnet, so until it passes it also applies to the
+    /// stake the destination coldkey already had on the destination subnet.
+    pub fn carry_unbonding_floor(
+        origin_coldkey: &T::AccountId,
+        origin_netuid: NetUid,
+        destination_coldkey: &T::AccountId,
+        destination_netuid: NetUid,
+    ) {
+        let now = Self::get_current_block_as_u64();
+        let floor = UnbondingFloor::<T>::get(origin_netuid, origin_coldkey)
+            .max(now.saturating_add(Self::get_unbonding_period(origin_netuid)));
+        let destination_release =
+            now.saturating_add(Self::get_unbonding_period(destination_netuid));
+        if floor > destination_release {
+            UnbondingFloor::<T>::mutate(destination_netuid, destination_coldkey, |current| {
+                *current = (*current).max(floor)
+            });
+        }
+    }
+
+    /// Total TAO in `coldkey`'s unbonding queue, and the part of it that can be withdrawn now.
+    pub fn get_unbonding_balance(coldkey: &T::AccountId) -> (TaoCurrency, TaoCurrency) {
+        let now = Self::get_current_block_as_u64();
+        UnbondingQueue::<T>::get(coldkey).iter().fold(
+            (TaoCurrency::ZERO, TaoCurrency::ZERO),
+            |(total, released), (block, amount)| {
+                let released = if *block <= now {
+                    released.saturating_add(*amount)
+                } else {
+                    released
+                };
+                (total.saturating_add(*amount), released)
+            },
+        )
+    }
+
+    /// ---- The implementation for the extrinsic withdraw_unbonded: Moves every unbonding chunk
+    /// whose release block has passed to the coldkey's free balance.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// # Event:
+    /// * UnbondedWithdrawn;
+    ///     -  On successfully releasing the unbonded TAO.
+    ///
+    /// # Raises:
+    /// * 'NoUnbondedStake':
+    ///     -  Thrown if no chunk of the coldkey's unbonding queue has been released yet.
+    ///
+    pub fn do_withdraw_unbonded(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let now = Self::get_current_block_as_u64();
+
+        let (released, pending): (Vec<_>, Vec<_>) = UnbondingQueue::<T>::get(&coldkey)
+            .into_iter()
+            .partition(|(block, _)| *block <= now);
+        let amount = released.iter().fold(TaoCurrency::ZERO, |acc, (_, amount)| {
+            acc.saturating_add(*amount)
+        });
+        ensure!(!amount.is_zero(), Error::<T>::NoUnbondedStake);
+
+        if pending.is_empty() {
+            UnbondingQueue::<T>::remove(&coldkey);
+        } else {
+            UnbondingQueue::<T>::insert(&coldkey, pending);
+        }
+        Self::transfer_tao(
+            LedgerAccount::Unbonding,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            amount,
+            LedgerReason::Unbonded,
+        )?;
+
+        Self::deposit_event(Event::UnbondedWithdrawn { coldkey, amount });
+
+        Ok(())
+    }
+}
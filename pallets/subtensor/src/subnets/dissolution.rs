@@ -0,0 +1,342 @@
+//! This file defines the dissolution of a subnet.
+//!
+//! A subnet is dissolved by its owner or by root over consecutive blocks, each processing at most
+//! [`DefaultNetworkDissolutionChunkSize`] storage items, so that subnets with many stakers keep
+//! every block under the weight limit:
+//!
+//! 1. `Stakes`: every alpha stake on the subnet is converted back to TAO at the pool price
+//!    snapshotted when the dissolution started, and paid to its coldkey.
+//! 2. `Hotkeys`: the stake totals, childkeys and emission records of the hotkeys on the subnet
+//!    are removed.
+//! 3. `Network`: the TAO left in the pool is recycled, the lock is refunded to the owner or
+//!    recycled, and the remaining storage of the subnet is removed in a single block.
+//!
+//! As soon as the dissolution starts, the subnet stops emitting, running epochs, accepting
+//! registrations and accepting stake. The intermediate state is persisted in
+//! `NetworkDissolutions` between blocks.
+
+use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use frame_support::storage::IterableStorageDoubleMap;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// The stage a subnet dissolution is in.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum NetworkDissolutionPhase {
+    /// Converting the alpha stakes back to TAO.
+    Stakes,
+    /// Removing the storage of the hotkeys on the subnet.
+    Hotkeys,
+    /// Recycling the pool, settling the lock and removing the subnet.
+    Network,
+}
+
+/// The intermediate state of a subnet dissolution.
+#[freeze_struct("4b837343b1077357")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct NetworkDissolution {
+    /// The stage the dissolution is in.
+    pub phase: NetworkDissolutionPhase,
+    /// The block the dissolution started.
+    pub started: u64,
+    /// The TAO paid for each alpha staked on the subnet.
+    pub tao_per_alpha: U96F32,
+    /// Whether the lock is refunded to the owner, or recycled.
+    pub refund_lock: bool,
+    /// The raw key of the last storage item processed by the current phase, empty before the
+    /// first one.
+    pub cursor: Vec<u8>,
+    /// The TAO paid to the stakers so far.
+    pub stake_refunded: TaoCurrency,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns true if the subnet is being dissolved.
+    pub fn is_network_dissolving(netuid: NetUid) -> bool {
+        NetworkDissolutions::<T>::contains_key(netuid)
+    }
+
+    /// Starts dissolving a subnet.
+    ///
+    /// Every alpha stake on the subnet is converted back to TAO at the lower of the pool price
+    /// and the pool TAO per alpha outstanding, so the stakers never receive more TAO than the
+    /// pool holds. The lock is refunded to the owner when root dissolves the subnet, or when the
+    /// owner dissolves it after the network immunity period. It is recycled otherwise.
+    ///
+    /// # Errors
+    /// - `BadOrigin`: If the caller is neither root nor the subnet owner.
+    /// - `Error::SubnetNotExists`: If the specified subnet does not exist.
+    /// - `Error::CannotDissolveRootSubnet`: If the subnet is the root subnet.
+    /// - `Error::NetworkDissolutionInProgress`: If the subnet is already being dissolved.
+    pub fn do_dissolve_subnet(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        // Ensure the caller is either root or subnet owner.
+        let maybe_owner = ensure_signed_or_root(origin.clone())?;
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(!netuid.is_root(), Error::<T>::CannotDissolveRootSubnet);
+        ensure!(
+            !Self::is_network_dissolving(netuid),
+            Error::<T>::NetworkDissolutionInProgress
+        );
+
+        // Refund the lock unless the owner gives up the subnet during its immunity period.
+        let current_block = Self::get_current_block_as_u64();
+        let refund_lock = maybe_owner.is_none()
+            || current_block.saturating_sub(NetworkRegisteredAt::<T>::get(netuid))
+                >= Self::get_network_immunity_period();
+
+        // Snapshot the price the stakes are converted at.
+        let pool_tao = U96F32::saturating_from_num(
+            SubnetTAO::<T>::get(netuid)
+                .saturating_add(SubnetTaoProvided::<T>::get(netuid))
+                .to_u64(),
+        );
+        let alpha_out = U96F32::saturating_from_num(SubnetAlphaOut::<T>::get(netuid).to_u64());
+        let price = T::SwapInterface::current_alpha_price(netuid);
+        let tao_per_alpha = if alpha_out > U96F32::saturating_from_num(0) {
+            price.min(pool_tao.safe_div(alpha_out))
+        } else {
+            price
+        };
+
+        // Stop the subnet from emitting, running epochs and accepting registrations and stake.
+        NetworkRegistrationAllowed::<T>::insert(netuid, false);
+        NetworkPowRegistrationAllowed::<T>::insert(netuid, false);
+        SubtokenEnabled::<T>::insert(netuid, false);
+        FirstEmissionBlockNumber::<T>::remove(netuid);
+        Self::clear_multi_block_epoch(netuid);
+
+        NetworkDissolutions::<T>::insert(
+            netuid,
+            NetworkDissolution {
+                phase: NetworkDissolutionPhase::Stakes,
+                started: current_block,
+                tao_per_alpha,
+                refund_lock,
+                cursor: Vec::new(),
+                stake_refunded: TaoCurrency::ZERO,
+            },
+        );
+
+        log::debug!("NetworkDissolutionStarted( netuid:{netuid:?}, refund_lock:{refund_lock:?} )");
+        Self::deposit_event(Event::NetworkDissolutionStarted {
+            netuid,
+            refund_lock,
+        });
+
+        Ok(())
+    }
+
+    /// Processes the next chunk of every subnet dissolution.
+    pub fn step_network_dissolutions() {
+        let netuids: Vec<NetUid> = NetworkDissolutions::<T>::iter_keys().collect();
+        for netuid in netuids {
+            Self::step_network_dissolution(netuid);
+        }
+    }
+
+    /// Processes the next chunk of the dissolution of `netuid`, if it is being dissolved.
+    pub fn step_network_dissolution(netuid: NetUid) {
+        let Some(mut dissolution) = NetworkDissolutions::<T>::get(netuid) else {
+            return;
+        };
+        let chunk_size = DefaultNetworkDissolutionChunkSize::<T>::get().max(1);
+
+        match dissolution.phase {
+            NetworkDissolutionPhase::Stakes => {
+                let mut keys = if dissolution.cursor.is_empty() {
+                    Alpha::<T>::iter_keys()
+                } else {
+                    Alpha::<T>::iter_keys_from(dissolution.cursor.clone())
+                };
+                let mut done = true;
+                for _ in 0..chunk_size {
+                    let Some((hotkey, coldkey, alpha_netuid)) = keys.next() else {
+                        break;
+                    };
+                    if alpha_netuid == netuid {
+                        let tao = Self::refund_dissolved_stake(
+                            &hotkey,
+                            &coldkey,
+                            netuid,
+                            dissolution.tao_per_alpha,
+                        );
+                        dissolution.stake_refunded = dissolution.stake_refunded.saturating_add(tao);
+                    }
+                    done = false;
+                }
+                if done {
+                    dissolution.phase = NetworkDissolutionPhase::Hotkeys;
+                    dissolution.cursor = Vec::new();
+                } else {
+                    dissolution.cursor = keys.last_raw_key().to_vec();
+                }
+            }
+            NetworkDissolutionPhase::Hotkeys => {
+                let mut keys = if dissolution.cursor.is_empty() {
+                    TotalHotkeyShares::<T>::iter_keys()
+                } else {
+                    TotalHotkeyShares::<T>::iter_keys_from(dissolution.cursor.clone())
+                };
+                let mut done = true;
+                for _ in 0..chunk_size {
+                    let Some((hotkey, shares_netuid)) = keys.next() else {
+                        break;
+                    };
+                    if shares_netuid == netuid {
+                        Self::remove_hotkey_from_dissolved_network(&hotkey, netuid);
+                    }
+                    done = false;
+                }
+                if done {
+                    dissolution.phase = NetworkDissolutionPhase::Network;
+                    dissolution.cursor = Vec::new();
+                } else {
+                    dissolution.cursor = keys.last_raw_key().to_vec();
+                }
+            }
+            NetworkDissolutionPhase::Network => {
+                Self::finish_network_dissolution(netuid, &dissolution);
+                NetworkDissolutions::<T>::remove(netuid);
+                return;
+            }
+        }
+
+        NetworkDissolutions::<T>::insert(netuid, dissolution);
+    }
+
+    /// Converts the stake of `coldkey` on `hotkey` back to TAO at `tao_per_alpha`, pays it to
+    /// the coldkey and removes the stake. Returns the TAO paid.
+    fn refund_dissolved_stake(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        tao_per_alpha: U96F32,
+    ) -> TaoCurrency {
+        let alpha = Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
+        Alpha::<T>::remove((hotkey, coldkey, netuid));
+
+        // Never pay more than the pool holds.
+        let pool_tao =
+            SubnetTAO::<T>::get(netuid).saturating_add(SubnetTaoProvided::<T>::get(netuid));
+        let tao = TaoCurrency::from(
+            tao_per_alpha
+                .saturating_mul(U96F32::saturating_from_num(alpha.to_u64()))
+                .saturating_to_num::<u64>(),
+        )
+        .min(pool_tao);
+
+        if !tao.is_zero() {
+            Self::decrease_provided_tao_reserve(netuid, tao);
+            let _ = Self::transfer_tao(
+                LedgerAccount::Stake,
+                LedgerAccount::Coldkey(coldkey.clone()),
+                tao,
+                LedgerReason::NetworkDissolution,
+            );
+        }
+
+        Self::deposit_event(Event::StakeRemoved(
+            coldkey.clone(),
+            hotkey.clone(),
+            tao,
+            alpha,
+            netuid,
+            0,
+        ));
+
+        tao
+    }
+
+    /// Removes the stake totals, childkeys and emission records of `hotkey` on `netuid`.
+    fn remove_hotkey_from_dissolved_network(hotkey: &T::AccountId, netuid: NetUid) {
+        TotalHotkeyAlpha::<T>::remove(hotkey, netuid);
+        TotalHotkeyAlphaLastEpoch::<T>::remove(hotkey, netuid);
+        TotalHotkeyShares::<T>::remove(hotkey, netuid);
+        LastHotkeyEmissionOnNetuid::<T>::remove(hotkey, netuid);
+        ChildKeys::<T>::remove(hotkey, netuid);
+        ParentKeys::<T>::remove(hotkey, netuid);
+        ChildkeyTake::<T>::remove(hotkey, netuid);
+    }
+
+    /// Recycles the TAO left in the pool, settles the lock and removes the subnet.
+    fn finish_network_dissolution(netuid: NetUid, dissolution: &NetworkDissolution) {
+        // Remove the hotkeys registered without stake on the subnet.
+        for (_uid, hotkey) in
+            <Keys<T> as IterableStorageDoubleMap<NetUid, u16, T::AccountId>>::iter_prefix(netuid)
+        {
+            Self::remove_hotkey_from_dissolved_network(&hotkey, netuid);
+        }
+
+        // Recycle the TAO left in the pool.
+        let pool_tao =
+            SubnetTAO::<T>::get(netuid).saturating_add(SubnetTaoProvided::<T>::get(netuid));
+        let _ = Self::transfer_tao(
+            LedgerAccount::Stake,
+            LedgerAccount::Issuance,
+            pool_tao,
+            LedgerReason::NetworkDissolution,
+        );
+
+        // Settle the lock, which `remove_network` refunds to the owner.
+        let lock = Self::get_subnet_locked_balance(netuid);
+        let lock_refunded = if dissolution.refund_lock {
+            lock
+        } else {
+            Self::set_subnet_locked_balance(netuid, TaoCurrency::ZERO);
+            TaoCurrency::ZERO
+        };
+
+        // Remove the pool and the remaining storage of the subnet.
+        SubnetTAO::<T>::remove(netuid);
+        SubnetTaoProvided::<T>::remove(netuid);
+        SubnetAlphaIn::<T>::remove(netuid);
+        SubnetAlphaInProvided::<T>::remove(netuid);
+        SubnetAlphaOut::<T>::remove(netuid);
+        SubnetVolume::<T>::remove(netuid);
+        SubnetMovingPrice::<T>::remove(netuid);
+        SubnetTaoInEmission::<T>::remove(netuid);
+        SubnetAlphaInEmission::<T>::remove(netuid);
+        SubnetAlphaOutEmission::<T>::remove(netuid);
+        PendingEmission::<T>::remove(netuid);
+        PendingAlphaSwapped::<T>::remove(netuid);
+        PendingOwnerCut::<T>::remove(netuid);
+        PendingRootDivs::<T>::remove(netuid);
+        SubnetMechanism::<T>::remove(netuid);
+        TokenSymbol::<T>::remove(netuid);
+        SubtokenEnabled::<T>::remove(netuid);
+        SubnetOwnerHotkey::<T>::remove(netuid);
+        BlocksSinceLastStep::<T>::remove(netuid);
+        LastMechansimStepBlock::<T>::remove(netuid);
+        NetworkRegistrationAllowed::<T>::remove(netuid);
+        NetworkPowRegistrationAllowed::<T>::remove(netuid);
+        let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = PendingChildKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = LastHotkeySwapOnNetuid::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = BlockAtRegistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = Axons::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AxonsV2::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = Prometheus::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = NeuronCertificates::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = CRV3WeightCommits::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = CRV3WeightCommitsV2::<T>::clear_prefix(netuid, u32::MAX, None);
+        Self::remove_network(netuid);
+
+        log::debug!(
+            "NetworkDissolved( netuid:{netuid:?}, stake_refunded:{:?}, lock_refunded:{lock_refunded:?} )",
+            dissolution.stake_refunded
+        );
+        Self::deposit_event(Event::NetworkDissolved {
+            netuid,
+            stake_refunded: dissolution.stake_refunded,
+            lock_refunded,
+        });
+        Self::deposit_event(Event::NetworkRemoved(netuid));
+    }
+}
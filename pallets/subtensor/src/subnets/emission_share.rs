@@ -0,0 +1,171 @@
+//! This file defines emission sharing agreements between subnets.
+//!
+//! The owner of a subnet can propose to share part of its owner cut with the owner of another
+//! subnet, for example a subnet relying on the services of another one. The agreement takes effect
+//! once the owner of the recipient subnet accepts it. From then on, every time the owner cut of the
+//! source subnet is distributed, the agreed share of it is staked to the owner of the recipient
+//! subnet, on the source subnet.
+//!
+//! A pending proposal can be withdrawn by the source owner, while an accepted agreement can only be
+//! ended by the recipient owner, so the source owner cannot walk away from it unilaterally.
+
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    /// Proposes to share `share` (normalized to u16::MAX) of the owner cut of `netuid` with the
+    /// owner of `recipient_netuid`, replacing any pending proposal between the two subnets.
+    pub fn do_propose_emission_share(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        recipient_netuid: NetUid,
+        share: u16,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            Self::if_subnet_exist(recipient_netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            share > 0 && netuid != recipient_netuid,
+            Error::<T>::InvalidEmissionShare
+        );
+        ensure!(
+            !EmissionShares::<T>::contains_key(netuid, recipient_netuid),
+            Error::<T>::EmissionShareAlreadyExists
+        );
+
+        EmissionShareProposals::<T>::insert(netuid, recipient_netuid, share);
+
+        Self::deposit_event(Event::EmissionShareProposed {
+            netuid,
+            recipient_netuid,
+            share,
+        });
+
+        Ok(())
+    }
+
+    /// Accepts the proposal of `netuid` to share its owner cut with `recipient_netuid`. Called by
+    /// the owner of `recipient_netuid`.
+    pub fn do_accept_emission_share(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        recipient_netuid: NetUid,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, recipient_netuid)?;
+
+        let share = EmissionShareProposals::<T>::get(netuid, recipient_netuid)
+            .ok_or(Error::<T>::EmissionShareNotFound)?;
+
+        // The agreements of a subnet cannot share more than its whole owner cut.
+        let total_share = EmissionShares::<T>::iter_prefix_values(netuid)
+            .fold(u32::from(share), |total, share| {
+                total.saturating_add(u32::from(share))
+            });
+        ensure!(
+            total_share <= u32::from(u16::MAX),
+            Error::<T>::EmissionShareTooHigh
+        );
+
+        EmissionShareProposals::<T>::remove(netuid, recipient_netuid);
+        EmissionShares::<T>::insert(netuid, recipient_netuid, share);
+
+        Self::deposit_event(Event::EmissionShareAccepted {
+            netuid,
+            recipient_netuid,
+            share,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraws the pending proposal of `netuid` to `recipient_netuid`, when called by the owner of
+    /// `netuid`, or ends the agreement between them, when called by the owner of
+    /// `recipient_netuid`.
+    pub fn do_end_emission_share(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        recipient_netuid: NetUid,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        if coldkey == SubnetOwner::<T>::get(recipient_netuid)
+            && EmissionShares::<T>::contains_key(netuid, recipient_netuid)
+        {
+            EmissionShares::<T>::remove(netuid, recipient_netuid);
+        } else if coldkey == SubnetOwner::<T>::get(netuid)
+            || coldkey == SubnetOwner::<T>::get(recipient_netuid)
+        {
+            ensure!(
+                EmissionShareProposals::<T>::contains_key(netuid, recipient_netuid),
+                Error::<T>::EmissionShareNotFound
+            );
+            EmissionShareProposals::<T>::remove(netuid, recipient_netuid);
+        } else {
+            return Err(DispatchError::BadOrigin);
+        }
+
+        Self::deposit_event(Event::EmissionShareEnded {
+            netuid,
+            recipient_netuid,
+        });
+
+        Ok(())
+    }
+
+    /// Stakes the agreed shares of the owner cut of `netuid` to the owners of the recipient
+    /// subnets and returns what is left for the owner of `netuid`.
+    pub fn distribute_emission_shares(netuid: NetUid, owner_cut: AlphaCurrency) -> AlphaCurrency {
+        let mut remaining = owner_cut;
+        for (recipient_netuid, share) in EmissionShares::<T>::iter_prefix(netuid) {
+            let (Ok(owner_coldkey), Ok(owner_hotkey)) = (
+                SubnetOwner::<T>::try_get(recipient_netuid),
+                SubnetOwnerHotkey::<T>::try_get(recipient_netuid),
+            ) else {
+                continue;
+            };
+
+            let shared: AlphaCurrency = U96F32::saturating_from_num(owner_cut.to_u64())
+                .saturating_mul(U96F32::saturating_from_num(share))
+                .safe_div(U96F32::saturating_from_num(u16::MAX))
+                .saturating_to_num::<u64>()
+                .min(remaining.to_u64())
+                .into();
+            if shared.is_zero() {
+                continue;
+            }
+
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hotkey,
+                &owner_coldkey,
+                netuid,
+                shared,
+            );
+            remaining = remaining.saturating_sub(shared);
+        }
+        remaining
+    }
+
+    /// Removes the proposals and agreements of a subnet being removed.
+    pub fn remove_emission_shares(netuid: NetUid) {
+        let _ = EmissionShares::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = EmissionShareProposals::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        let shares: Vec<_> = EmissionShares::<T>::iter_keys()
+            .filter(|(_, recipient_netuid)| *recipient_netuid == netuid)
+            .collect();
+        for (source_netuid, recipient_netuid) in shares {
+            EmissionShares::<T>::remove(source_netuid, recipient_netuid);
+        }
+        let proposals: Vec<_> = EmissionShareProposals::<T>::iter_keys()
+            .filter(|(_, recipient_netuid)| *recipient_netuid == netuid)
+            .collect();
+        for (source_netuid, recipient_netuid) in proposals {
+            EmissionShareProposals::<T>::remove(source_netuid, recipient_netuid);
+        }
+    }
+}
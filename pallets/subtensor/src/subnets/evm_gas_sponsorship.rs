@@ -0,0 +1,286 @@
+//! This file defines the sponsorship of EVM gas by subnets.
+//!
+//! The owner of a subnet can name an EVM account, the sponsor, that pays the gas of Ethereum
+//! transactions calling the contracts the owner whitelists, such as the user-facing dApp of the
+//! subnet. The sponsor consents by signing an EIP-191 message binding the subnet, the spending cap
+//! and a deadline block, so an owner cannot spend the balance of an account that did not agree.
+//! The consent also binds the genesis hash, so it is only valid on this chain, and the
+//! `EvmGasSponsorConsentNonce` of the sponsor, which each use increments, so it is used at most
+//! once.
+//!
+//! The sponsor pays at most its cap in every period of [`EVM_GAS_SPONSORSHIP_PERIOD`] blocks. Once
+//! the cap is reached, or if the sponsor cannot pay, the sender of the transaction pays as usual.
+//! The runtime charges the fees: it records the target of the Ethereum transaction being applied
+//! in [`EvmTransactionTarget`] and asks [`Pallet::evm_transaction_sponsor`] who pays for it.
+
+use super::*;
+use sp_core::{H160, ecdsa::Signature};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+/// Domain separator of the consents signed by sponsors.
+pub const EVM_GAS_SPONSORSHIP_CONTEXT: &[u8] = b"subtensor:evm-gas-sponsorship";
+
+/// Blocks over which the spending cap of a sponsor applies (~1 day).
+pub const EVM_GAS_SPONSORSHIP_PERIOD: u64 = 7200;
+
+/// The sponsorship of the EVM gas of a subnet.
+#[freeze_struct("343129c47da78ced")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct EvmGasSponsorship {
+    /// The EVM account paying the gas.
+    pub sponsor: H160,
+    /// The most TAO the sponsor pays per period.
+    pub cap: TaoCurrency,
+    /// The TAO the sponsor paid in the current period.
+    pub spent: TaoCurrency,
+    /// The first block of the current period.
+    pub period_start: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic set_evm_gas_sponsor: Names the EVM account paying
+    /// the gas of the contracts sponsored by a subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the subnet owner.
+    ///
+    /// * 'netuid' (NetUid):
+    ///     -  The subnet sponsoring the gas.
+    ///
+    /// * 'sponsor' (H160):
+    ///     -  The EVM account paying the gas.
+    ///
+    /// * 'cap' (TaoCurrency):
+    ///     -  The most TAO the sponsor pays per period.
+    ///
+    /// * 'deadline' (u64):
+    ///     -  The last block the consent of the sponsor can be used at.
+    ///
+    /// * 'signature' (Signature):
+    ///     -  The EIP-191 signature of the sponsor over the consent message.
+    ///
+    /// # Event:
+    /// * EvmGasSponsorSet;
+    ///     -  On successfully naming the sponsor.
+    ///
+    /// # Raises:
+    /// * 'SponsorConsentExpired':
+    ///     -  Thrown if the deadline has passed.
+    ///
+    /// * 'InvalidSponsorConsent':
+    ///     -  Thrown if the consent is not signed by the sponsor for this chain and its current
+    ///        nonce, or was already used.
+    ///
+    pub fn do_set_evm_gas_sponsor(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        sponsor: H160,
+        cap: TaoCurrency,
+        deadline: u64,
+        signature: Signature,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        let now = Self::get_current_block_as_u64();
+        ensure!(now <= deadline, Error::<T>::SponsorConsentExpired);
+        let message = Self::get_evm_gas_sponsor_consent_message(netuid, sponsor, cap, deadline);
+        ensure!(
+            Self::recover_evm_signer(message, signature).ok() == Some(sponsor),
+            Error::<T>::InvalidSponsorConsent
+        );
+        EvmGasSponsorConsentNonce::<T>::mutate(sponsor, |nonce| *nonce = nonce.saturating_add(1));
+
+        EvmGasSponsorships::<T>::insert(
+            netuid,
+            EvmGasSponsorship {
+                sponsor,
+                cap,
+                spent: TaoCurrency::ZERO,
+                period_start: now,
+            },
+        );
+
+        Self::deposit_event(Event::EvmGasSponsorSet {
+            netuid,
+            sponsor,
+            cap,
+        });
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic remove_evm_gas_sponsor: Stops the sponsor of a
+    /// subnet paying the gas of its contracts. The contracts stay whitelisted.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the subnet owner.
+    ///
+    /// * 'netuid' (NetUid):
+    ///     -  The subnet sponsoring the gas.
+    ///
+    /// # Event:
+    /// * EvmGasSponsorRemoved;
+    ///     -  On successfully removing the sponsor.
+    ///
+    /// # Raises:
+    /// * 'NoEvmGasSponsor':
+    ///     -  Thrown if the subnet has no sponsor.
+    ///
+    pub fn do_remove_evm_gas_sponsor(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            EvmGasSponsorships::<T>::take(netuid).is_some(),
+            Error::<T>::NoEvmGasSponsor
+        );
+
+        Self::deposit_event(Event::EvmGasSponsorRemoved { netuid });
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_evm_sponsored_contract: Adds a contract to, or
+    /// removes it from, the contracts whose gas is paid by the sponsor of a subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the subnet owner.
+    ///
+    /// * 'netuid' (NetUid):
+    ///     -  The subnet sponsoring the gas.
+    ///
+    /// * 'contract' (H160):
+    ///     -  The address of the contract.
+    ///
+    /// * 'sponsored' (bool):
+    ///     -  Whether the gas of calls to the contract is sponsored.
+    ///
+    /// # Event:
+    /// * EvmSponsoredContractSet;
+    ///     -  On successfully updating the contract.
+    ///
+    /// # Raises:
+    /// * 'EvmContractSponsoredByOtherSubnet':
+    ///     -  Thrown if another subnet sponsors the gas of the contract.
+    ///
+    pub fn do_set_evm_sponsored_contract(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        contract: H160,
+        sponsored: bool,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            EvmSponsoredContracts::<T>::get(contract).is_none_or(|other| other == netuid),
+            Error::<T>::EvmContractSponsoredByOtherSubnet
+        );
+
+        if sponsored {
+            EvmSponsoredContracts::<T>::insert(contract, netuid);
+        } else {
+            EvmSponsoredContracts::<T>::remove(contract);
+        }
+
+        Self::deposit_event(Event::EvmSponsoredContractSet {
+            netuid,
+            contract,
+            sponsored,
+        });
+
+        Ok(())
+    }
+
+    /// The message `sponsor` signs to consent to paying the EVM gas of `netuid`. It includes the
+    /// current `EvmGasSponsorConsentNonce` of the sponsor.
+    pub fn get_evm_gas_sponsor_consent_message(
+        netuid: NetUid,
+        sponsor: H160,
+        cap: TaoCurrency,
+        deadline: u64,
+    ) -> Vec<u8> {
+        (
+            EVM_GAS_SPONSORSHIP_CONTEXT,
+            Self::get_block_hash_from_u64(0),
+            netuid,
+            EvmGasSponsorConsentNonce::<T>::get(sponsor),
+            cap,
+            deadline,
+        )
+            .encode()
+    }
+
+    /// The TAO the sponsor of `netuid` can still pay in the current period.
+    pub fn get_evm_gas_sponsor_allowance(netuid: NetUid) -> TaoCurrency {
+        let now = Self::get_current_block_as_u64();
+        EvmGasSponsorships::<T>::get(netuid)
+            .map(|sponsorship| {
+                if now
+                    >= sponsorship
+                        .period_start
+                        .saturating_add(EVM_GAS_SPONSORSHIP_PERIOD)
+                {
+                    sponsorship.cap
+                } else {
+                    sponsorship.cap.saturating_sub(sponsorship.spent)
+                }
+            })
+            .unwrap_or(TaoCurrency::ZERO)
+    }
+
+    /// Records the target of the Ethereum transaction being applied, or clears it with `None` once
+    /// the transaction is applied.
+    pub fn set_evm_transaction_target(target: Option<H160>) {
+        EvmTransactionTarget::<T>::set(target);
+    }
+
+    /// The subnet and the sponsor paying a fee of up to `max_fee` for the Ethereum transaction
+    /// being applied, if its target is a sponsored contract and the cap of the sponsor allows it.
+    pub fn evm_transaction_sponsor(max_fee: TaoCurrency) -> Option<(NetUid, H160)> {
+        let netuid = EvmSponsoredContracts::<T>::get(EvmTransactionTarget::<T>::get()?)?;
+        let sponsorship = EvmGasSponsorships::<T>::get(netuid)?;
+        (Self::get_evm_gas_sponsor_allowance(netuid) >= max_fee)
+            .then_some((netuid, sponsorship.sponsor))
+    }
+
+    /// Adds `fee`, paid by the sponsor of `netuid` for a transaction sent by `sender`, to the
+    /// spending of the sponsor in the current period.
+    pub fn record_evm_gas_sponsored(netuid: NetUid, sender: H160, fee: TaoCurrency) {
+        let now = Self::get_current_block_as_u64();
+        let Some(sponsor) = EvmGasSponsorships::<T>::mutate(netuid, |maybe_sponsorship| {
+            maybe_sponsorship.as_mut().map(|sponsorship| {
+                if now
+                    >= sponsorship
+                        .period_start
+                        .saturating_add(EVM_GAS_SPONSORSHIP_PERIOD)
+                {
+                    sponsorship.period_start = now;
+                    sponsorship.spent = TaoCurrency::ZERO;
+                }
+                sponsorship.spent = sponsorship.spent.saturating_add(fee);
+                sponsorship.sponsor
+            })
+        }) else {
+            return;
+        };
+
+        Self::deposit_event(Event::EvmGasSponsored {
+            netuid,
+            sponsor,
+            sender,
+            fee,
+        });
+    }
+
+    /// Removes the sponsor of `netuid` and the contracts it sponsors.
+    pub fn remove_evm_gas_sponsorship(netuid: NetUid) {
+        EvmGasSponsorships::<T>::remove(netuid);
+        let contracts: Vec<_> = EvmSponsoredContracts::<T>::iter()
+            .filter(|(_, contract_netuid)| *contract_netuid == netuid)
+            .map(|(contract, _)| contract)
+            .collect();
+        for contract in contracts {
+            EvmSponsoredContracts::<T>::remove(contract);
+        }
+    }
+}
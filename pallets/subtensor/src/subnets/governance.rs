@@ -0,0 +1,207 @@
+//! This file defines stake-weighted governance of a subnet by its stakers.
+//!
+//! The subnet owner opens a proposal carrying a call, typically an admin-utils hyperparameter
+//! setter, with the `propose_subnet_change` extrinsic. Coldkeys staking on the subnet then vote for
+//! or against it with `vote_subnet_proposal`, weighted by their alpha stake on the subnet at the
+//! time of the vote.
+//!
+//! As soon as the ayes reach `SubnetProposalQuorum` of the subnet's outstanding alpha and outnumber
+//! the nays, the call is dispatched with the subnet owner's signed origin, so a proposal can only do
+//! what the owner could do directly. A subnet has at most one open proposal, which expires after
+//! `SubnetProposalDuration` blocks and can then be replaced by a new one.
+
+use super::*;
+use frame_support::{
+    dispatch::{GetDispatchInfo, RawOrigin},
+    weights::Weight,
+};
+use safe_math::*;
+use sp_runtime::traits::Dispatchable;
+use sp_std::boxed::Box;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+pub type SubnetProposalId = u32;
+
+/// Maximum weight of a call that can be proposed, covered by the weight of `vote_subnet_proposal`.
+pub const MAX_SUBNET_PROPOSAL_CALL_WEIGHT: Weight = Weight::from_parts(100_000_000, 10_000);
+
+#[freeze_struct("751637a3ac0123")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SubnetProposal<AccountId, Call> {
+    /// The id of the proposal, unique across subnets.
+    pub id: SubnetProposalId,
+    /// The subnet owner who opened the proposal.
+    pub proposer: AccountId,
+    /// The call dispatched with the subnet owner origin when the proposal passes.
+    pub call: Box<Call>,
+    /// The last block at which votes are accepted.
+    pub end_block: u64,
+    /// The alpha stake that voted for the proposal.
+    pub ayes: AlphaCurrency,
+    /// The alpha stake that voted against the proposal.
+    pub nays: AlphaCurrency,
+}
+
+pub type SubnetProposalOf<T> =
+    SubnetProposal<<T as frame_system::Config>::AccountId, <T as Config>::RuntimeCall>;
+
+impl<T: Config> Pallet<T> {
+    /// Opens a proposal on `netuid` to dispatch `call` as the subnet owner once stakers approve it.
+    ///
+    /// An expired proposal of the subnet is replaced, discarding its votes.
+    pub fn do_propose_subnet_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        call: Box<<T as Config>::RuntimeCall>,
+    ) -> DispatchResult {
+        let proposer = ensure_signed(origin)?;
+
+        // Ensure the caller owns the subnet.
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == proposer,
+            DispatchError::BadOrigin
+        );
+
+        // Ensure the call is cheap enough to be executed by a vote.
+        ensure!(
+            call.get_dispatch_info()
+                .call_weight
+                .all_lte(MAX_SUBNET_PROPOSAL_CALL_WEIGHT),
+            Error::<T>::SubnetProposalCallTooHeavy
+        );
+
+        // Ensure the subnet has no open proposal.
+        let current_block = Self::get_current_block_as_u64();
+        if let Some(open) = SubnetProposals::<T>::get(netuid) {
+            ensure!(
+                current_block > open.end_block,
+                Error::<T>::SubnetProposalAlreadyOpen
+            );
+            let _ = SubnetProposalVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+
+        let id = NextSubnetProposalId::<T>::get();
+        NextSubnetProposalId::<T>::put(id.saturating_add(1));
+        SubnetProposals::<T>::insert(
+            netuid,
+            SubnetProposal {
+                id,
+                proposer: proposer.clone(),
+                call,
+                end_block: current_block.saturating_add(SubnetProposalDuration::<T>::get()),
+                ayes: AlphaCurrency::ZERO,
+                nays: AlphaCurrency::ZERO,
+            },
+        );
+
+        Self::deposit_event(Event::SubnetProposalOpened {
+            netuid,
+            proposal_id: id,
+            proposer,
+        });
+
+        Ok(())
+    }
+
+    /// Votes for or against the open proposal of `netuid` with the caller's alpha stake on the
+    /// subnet, executing the proposal if the vote makes it pass.
+    pub fn do_vote_subnet_proposal(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        proposal_id: SubnetProposalId,
+        aye: bool,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        // Ensure the proposal is open.
+        let mut proposal = SubnetProposals::<T>::get(netuid)
+            .filter(|proposal| proposal.id == proposal_id)
+            .ok_or(Error::<T>::SubnetProposalNotFound)?;
+        ensure!(
+            Self::get_current_block_as_u64() <= proposal.end_block,
+            Error::<T>::SubnetProposalExpired
+        );
+
+        // Ensure the coldkey votes once, with some stake.
+        ensure!(
+            !SubnetProposalVotes::<T>::contains_key(netuid, &coldkey),
+            Error::<T>::AlreadyVotedOnSubnetProposal
+        );
+        let stake = Self::get_alpha_stake_for_coldkey_on_subnet(&coldkey, netuid);
+        ensure!(!stake.is_zero(), Error::<T>::NotEnoughStakeToVote);
+
+        if aye {
+            proposal.ayes = proposal.ayes.saturating_add(stake);
+        } else {
+            proposal.nays = proposal.nays.saturating_add(stake);
+        }
+        SubnetProposalVotes::<T>::insert(netuid, &coldkey, aye);
+
+        Self::deposit_event(Event::SubnetProposalVoted {
+            netuid,
+            proposal_id,
+            coldkey,
+            aye,
+            stake,
+        });
+
+        if Self::subnet_vote_passes(netuid, proposal.ayes, proposal.nays) {
+            Self::execute_subnet_proposal(netuid, proposal);
+        } else {
+            SubnetProposals::<T>::insert(netuid, proposal);
+        }
+
+        Ok(())
+    }
+
+    /// Total alpha staked by `coldkey` on `netuid` across its staking hotkeys.
+    pub fn get_alpha_stake_for_coldkey_on_subnet(
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+    ) -> AlphaCurrency {
+        StakingHotkeys::<T>::get(coldkey)
+            .iter()
+            .map(|hotkey| Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid))
+            .fold(AlphaCurrency::ZERO, |acc, stake| acc.saturating_add(stake))
+    }
+
+    /// Removes the open proposal of `netuid` and its votes, without executing it.
+    pub fn remove_subnet_proposal(netuid: NetUid) {
+        SubnetProposals::<T>::remove(netuid);
+        let _ = SubnetProposalVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+    }
+
+    /// Whether a vote on `netuid` with `ayes` and `nays` passes: the ayes outnumber the nays and
+    /// reach the quorum of the subnet's outstanding alpha.
+    pub fn subnet_vote_passes(netuid: NetUid, ayes: AlphaCurrency, nays: AlphaCurrency) -> bool {
+        let quorum = U96F32::saturating_from_num(SubnetProposalQuorum::<T>::get())
+            .safe_div(U96F32::saturating_from_num(u16::MAX));
+        let required = U96F32::saturating_from_num(SubnetAlphaOut::<T>::get(netuid).to_u64())
+            .saturating_mul(quorum);
+
+        ayes > nays && U96F32::saturating_from_num(ayes.to_u64()) >= required
+    }
+
+    fn execute_subnet_proposal(netuid: NetUid, proposal: SubnetProposalOf<T>) {
+        Self::remove_subnet_proposal(netuid);
+
+        let owner = SubnetOwner::<T>::get(netuid);
+        let result = proposal
+            .call
+            .dispatch(RawOrigin::Signed(owner).into())
+            .map(|_| ())
+            .map_err(|e| e.error);
+        log::debug!(
+            "SubnetProposalExecuted( netuid:{netuid:?} proposal_id:{:?} result:{result:?} )",
+            proposal.id
+        );
+
+        Self::deposit_event(Event::SubnetProposalExecuted {
+            netuid,
+            proposal_id: proposal.id,
+            result,
+        });
+    }
+}
@@ -329,13 +329,13 @@ impl<T: Config> Pallet<T> {
                 .saturating_mul(U64F64::from(tao_unstaked.to_u64()))
                 .floor()
                 .saturating_to_num::<u64>();
-            Self::add_balance_to_coldkey_account(&contributor, tao_for_contributor);
+            Self::pay_unstaked_tao(&contributor, tao_for_contributor.into());
             tao_distributed = tao_distributed.saturating_add(tao_for_contributor.into());
         }
 
         // Distribute the leftover tao to the beneficiary
         let beneficiary_cut_tao = tao_unstaked.saturating_sub(tao_distributed);
-        Self::add_balance_to_coldkey_account(&lease.beneficiary, beneficiary_cut_tao.into());
+        Self::pay_unstaked_tao(&lease.beneficiary, beneficiary_cut_tao);
 
         // Reset the accumulated dividends
         AccumulatedLeaseDividends::<T>::insert(lease_id, AlphaCurrency::ZERO);
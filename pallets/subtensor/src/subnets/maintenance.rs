@@ -0,0 +1,65 @@
+//! This file defines the maintenance mode of a subnet.
+//!
+//! While a subnet is in maintenance its epochs do not run, so its emission keeps accruing in
+//! `PendingEmission` and is paid out by the first epoch after the subnet resumes. Registrations and
+//! weight setting are rejected with `SubnetInMaintenance`. Maintenance ends when the owner or root
+//! turns it off, or automatically `MaxSubnetMaintenanceDuration` blocks after it started.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Puts `netuid` into maintenance mode or takes it out of it.
+    ///
+    /// Turning maintenance on for a subnet already in maintenance keeps its original start block,
+    /// so the automatic resume cannot be postponed.
+    pub fn do_set_subnet_maintenance(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        enabled: bool,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(!netuid.is_root(), Error::<T>::CannotPauseRootSubnet);
+
+        if enabled {
+            if !SubnetMaintenanceStart::<T>::contains_key(netuid) {
+                SubnetMaintenanceStart::<T>::insert(netuid, Self::get_current_block_as_u64());
+            }
+        } else {
+            SubnetMaintenanceStart::<T>::remove(netuid);
+        }
+
+        log::debug!("SubnetMaintenanceSet( netuid:{netuid:?} enabled:{enabled:?} )");
+        Self::deposit_event(Event::SubnetMaintenanceSet { netuid, enabled });
+
+        Ok(())
+    }
+
+    pub fn is_subnet_in_maintenance(netuid: NetUid) -> bool {
+        SubnetMaintenanceStart::<T>::contains_key(netuid)
+    }
+
+    pub fn ensure_subnet_not_in_maintenance(netuid: NetUid) -> Result<(), Error<T>> {
+        ensure!(
+            !Self::is_subnet_in_maintenance(netuid),
+            Error::<T>::SubnetInMaintenance
+        );
+        Ok(())
+    }
+
+    /// Resumes every subnet whose maintenance started `MaxSubnetMaintenanceDuration` blocks ago.
+    pub fn resume_expired_subnet_maintenance(block_number: u64) {
+        let max_duration = MaxSubnetMaintenanceDuration::<T>::get();
+        let expired: Vec<NetUid> = SubnetMaintenanceStart::<T>::iter()
+            .filter(|(_, start)| block_number.saturating_sub(*start) >= max_duration)
+            .map(|(netuid, _)| netuid)
+            .collect();
+
+        for netuid in expired {
+            SubnetMaintenanceStart::<T>::remove(netuid);
+            log::debug!("SubnetMaintenanceExpired( netuid:{netuid:?} )");
+            Self::deposit_event(Event::SubnetMaintenanceExpired(netuid));
+        }
+    }
+}
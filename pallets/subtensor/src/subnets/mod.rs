@@ -1,8 +1,22 @@
 use super::*;
+pub mod dissolution;
+pub mod emission_share;
+pub mod evm_gas_sponsorship;
+pub mod governance;
 pub mod leasing;
+pub mod maintenance;
 pub mod registration;
+pub mod registration_auction;
+pub mod registration_fee;
+pub mod sale;
 pub mod serving;
+pub mod slashing;
+pub mod sponsored_registration;
 pub mod subnet;
 pub mod symbols;
+pub mod transaction_fee;
+pub mod treasury;
 pub mod uids;
 pub mod weights;
+pub mod weights_key;
+pub mod weights_multisig;
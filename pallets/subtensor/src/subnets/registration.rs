@@ -1,13 +1,23 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use argon2::{Algorithm, Argon2, Params, Version};
+use safe_math::*;
 use sp_core::{H256, U256};
 use sp_io::hashing::{keccak_256, sha2_256};
 use sp_runtime::Saturating;
-use subtensor_runtime_common::{Currency, NetUid};
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
 use subtensor_swap_interface::SwapHandler;
 use system::pallet_prelude::BlockNumberFor;
 
 const LOG_TARGET: &str = "runtime::subtensor::registration";
 
+/// Memory, in KiB, of the Argon2id registration proof of work, the 19 MiB OWASP recommends as the
+/// minimum for Argon2id, so each attempt needs its own memory rather than a GPU core.
+const ARGON2_POW_MEMORY_KIB: u32 = 19 * 1024;
+/// Passes over memory of the Argon2id registration proof of work.
+const ARGON2_POW_ITERATIONS: u32 = 2;
+
 impl<T: Config> Pallet<T> {
     pub fn register_neuron(netuid: NetUid, hotkey: &T::AccountId) -> u16 {
         // Init param
@@ -15,7 +25,13 @@ impl<T: Config> Pallet<T> {
         let block_number: u64 = Self::get_current_block_as_u64();
         let current_subnetwork_n: u16 = Self::get_subnetwork_n(netuid);
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if let Some(vacant_uid) = Self::take_vacant_uid(netuid) {
+            // A uid freed by a voluntary exit is filled first.
+            neuron_uid = vacant_uid;
+
+            Self::replace_neuron(netuid, neuron_uid, hotkey, block_number);
+            log::debug!("fill vacant neuron slot");
+        } else if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
             // No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             neuron_uid = current_subnetwork_n;
@@ -73,6 +89,17 @@ impl<T: Config> Pallet<T> {
         let coldkey = ensure_signed(origin)?;
         log::debug!("do_registration( coldkey:{coldkey:?} netuid:{netuid:?} hotkey:{hotkey:?} )");
 
+        Self::burned_register_neuron(&coldkey, &coldkey, netuid, hotkey).map(|_| ())
+    }
+
+    /// Registers `hotkey`, owned by `coldkey`, on `netuid`, with the burn paid by `payer`. Returns
+    /// the burn paid.
+    pub fn burned_register_neuron(
+        payer: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+    ) -> Result<TaoCurrency, DispatchError> {
         // --- 2. Ensure the passed network is valid.
         ensure!(
             !netuid.is_root(),
@@ -88,6 +115,11 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::get_registration_auction_enabled(netuid),
+            Error::<T>::RegistrationAuctionActive
+        );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -109,19 +141,19 @@ impl<T: Config> Pallet<T> {
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
 
-        // --- 7. Ensure the callers coldkey has enough stake to perform the transaction.
+        // --- 7. Ensure the payer has enough balance to perform the transaction.
         let registration_cost = Self::get_burn(netuid);
         ensure!(
-            Self::can_remove_balance_from_coldkey_account(&coldkey, registration_cost.into()),
+            Self::can_remove_balance_from_coldkey_account(payer, registration_cost.into()),
             Error::<T>::NotEnoughBalanceToStake
         );
 
         // If the network account does not exist we will create it here.
-        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        Self::create_account_if_non_existent(coldkey, &hotkey);
 
         // --- 8. Ensure that the pairing is correct.
         ensure!(
-            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Self::coldkey_owns_hotkey(coldkey, &hotkey),
             Error::<T>::NonAssociatedColdKey
         );
 
@@ -131,9 +163,13 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        // --- 10. Ensure the remove operation from the coldkey is a success.
-        let actual_burn_amount =
-            Self::remove_balance_from_coldkey_account(&coldkey, registration_cost.into())?;
+        // --- 10. Ensure the remove operation from the payer is a success.
+        let actual_burn_amount = Self::transfer_tao(
+            LedgerAccount::Coldkey(payer.clone()),
+            LedgerAccount::Stake,
+            registration_cost,
+            LedgerReason::Registration,
+        )?;
 
         // Tokens are swapped and then burned.
         let burned_alpha = Self::swap_tao_for_alpha(
@@ -146,22 +182,24 @@ impl<T: Config> Pallet<T> {
         SubnetAlphaOut::<T>::mutate(netuid, |total| {
             *total = total.saturating_sub(burned_alpha.into())
         });
+        Self::record_subnet_alpha_recycled(netuid, burned_alpha.into());
 
         // Actually perform the registration.
         let neuron_uid: u16 = Self::register_neuron(netuid, &hotkey);
+        RegistrationBurnPaid::<T>::insert(netuid, neuron_uid, actual_burn_amount);
 
         // --- 14. Record the registration and increment block and interval counters.
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
-        Self::increase_rao_recycled(netuid, Self::get_burn(netuid).into());
+        Self::increase_rao_recycled(netuid, actual_burn_amount);
 
         // --- 15. Deposit successful event.
         log::debug!("NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?}  ) ");
         Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey));
 
         // --- 16. Ok and done.
-        Ok(())
+        Ok(actual_burn_amount)
     }
 
     /// ---- The implementation for the extrinsic do_registration.
@@ -246,6 +284,11 @@ impl<T: Config> Pallet<T> {
             Self::get_network_pow_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::get_registration_auction_enabled(netuid),
+            Error::<T>::RegistrationAuctionActive
+        );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -281,15 +324,17 @@ impl<T: Config> Pallet<T> {
 
         // --- 8. Ensure the supplied work passes the difficulty.
         let difficulty: U256 = Self::get_difficulty(netuid);
-        let work_hash: H256 = Self::vec_to_hash(work.clone());
+        let work_hash: H256 = Self::vec_to_hash(work.clone()).ok_or(Error::<T>::InvalidSeal)?;
         ensure!(
             Self::hash_meets_difficulty(&work_hash, difficulty),
             Error::<T>::InvalidDifficulty
         ); // Check that the work meets difficulty.
 
-        // --- 7. Check Work is the product of the nonce, the block number, and hotkey. Add this as used work.
-        let seal: H256 = Self::create_seal_hash(block_number, nonce, &hotkey);
-        ensure!(seal == work_hash, Error::<T>::InvalidSeal);
+        // --- 7. Check Work is the product of the nonce, the block number, and hotkey, with the hash
+        // of the subnet. Add this as used work.
+        let seal: Option<H256> =
+            Self::create_seal_hash_for_subnet(netuid, block_number, nonce, &hotkey);
+        ensure!(seal == Some(work_hash), Error::<T>::InvalidSeal);
         UsedWork::<T>::insert(work.clone(), current_block_number);
 
         // DEPRECATED --- 8. Ensure that the key passes the registration requirement
@@ -356,7 +401,7 @@ impl<T: Config> Pallet<T> {
 
         // --- 3. Ensure the supplied work passes the difficulty.
         let difficulty: U256 = U256::from(1_000_000); // Base faucet difficulty.
-        let work_hash: H256 = Self::vec_to_hash(work.clone());
+        let work_hash: H256 = Self::vec_to_hash(work.clone()).ok_or(Error::<T>::InvalidSeal)?;
         ensure!(
             Self::hash_meets_difficulty(&work_hash, difficulty),
             Error::<T>::InvalidDifficulty
@@ -369,9 +414,13 @@ impl<T: Config> Pallet<T> {
 
         // --- 5. Add Balance via faucet.
         let balance_to_add: u64 = 1_000_000_000_000;
-        Self::coinbase(100_000_000_000.into()); // We are creating tokens here from the coinbase.
-
-        Self::add_balance_to_coldkey_account(&coldkey, balance_to_add);
+        // We are creating tokens here from the coinbase.
+        Self::transfer_tao(
+            LedgerAccount::Issuance,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            balance_to_add.into(),
+            LedgerReason::Faucet,
+        )?;
 
         // --- 6. Deposit successful event.
         log::debug!("Faucet( coldkey:{coldkey:?} amount:{balance_to_add:?} ) ");
@@ -381,85 +430,164 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    pub fn vec_to_hash(vec_hash: Vec<u8>) -> H256 {
-        let de_ref_hash = &vec_hash; // b: &Vec<u8>
-        let de_de_ref_hash: &[u8] = de_ref_hash; // c: &[u8]
-        let real_hash: H256 = H256::from_slice(de_de_ref_hash);
-        real_hash
+    /// Decodes the hash of a proof of work, `None` unless the work is exactly 32 bytes.
+    pub fn vec_to_hash(vec_hash: Vec<u8>) -> Option<H256> {
+        let real_hash: [u8; 32] = vec_hash.try_into().ok()?;
+        Some(H256::from(real_hash))
     }
 
-    /// Determine which peer to prune from the network by finding the element with the lowest pruning score out of
-    /// immunity period. If there is a tie for lowest pruning score, the neuron registered earliest is pruned.
-    /// If all neurons are in immunity period, the neuron with the lowest pruning score is pruned. If there is a tie for
-    /// the lowest pruning score, the immune neuron registered earliest is pruned.
-    /// Ties for earliest registration are broken by the neuron with the lowest uid.
+    /// ---- The implementation for the extrinsic unregister: voluntarily leaving a subnet.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the coldkey owning the hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet to leave.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The registered hotkey leaving the subnet.
+    ///
+    /// # Event:
+    /// * NeuronUnregistered;
+    ///     - On freeing the uid of the hotkey.
+    ///
+    /// # Raises:
+    /// * 'NonAssociatedColdKey':
+    ///     - The coldkey does not own the hotkey.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the subnet.
+    ///
+    /// * 'CannotUnregisterSubnetOwnerHotkey':
+    ///     - The hotkey is the subnet owner hotkey.
+    ///
+    /// The uid is freed immediately and filled by the next registration on the subnet. A neuron
+    /// still in immunity is refunded `UnregisterRefundFraction` of the burn it paid to register,
+    /// scaled by the share of its immunity period that remains. The refund is paid out of the TAO
+    /// reserve of the subnet pool, which the burn was swapped into, so no TAO is issued.
+    pub fn do_unregister(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller is the coldkey owning the hotkey.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!("do_unregister( coldkey:{coldkey:?} netuid:{netuid:?} hotkey:{hotkey:?} )");
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 2. Ensure the hotkey holds a uid that may be freed.
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)
+            .map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            SubnetOwnerHotkey::<T>::get(netuid) != hotkey,
+            Error::<T>::CannotUnregisterSubnetOwnerHotkey
+        );
+
+        // --- 3. Refund part of the registration burn for the immunity left, out of the TAO
+        // recycled by the registrations of the subnet, which leaves the subnet pool untouched.
+        let refund = Self::transfer_tao(
+            LedgerAccount::Issuance,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            Self::get_unregister_refund(netuid, uid),
+            LedgerReason::Registration,
+        )?;
+        Self::decrease_rao_recycled(netuid, refund);
+
+        // --- 4. Free the uid.
+        Self::vacate_neuron(netuid, uid, &hotkey);
+
+        // --- 5. Deposit successful event.
+        log::debug!(
+            "NeuronUnregistered( netuid:{netuid:?} uid:{uid:?} hotkey:{hotkey:?} refund:{refund:?} )"
+        );
+        Self::deposit_event(Event::NeuronUnregistered {
+            netuid,
+            uid,
+            hotkey,
+            refund,
+        });
+
+        Ok(())
+    }
+
+    /// The TAO refunded to a neuron leaving the subnet now: the burn it paid to register, times
+    /// `UnregisterRefundFraction`, times the share of its immunity period that remains, at most
+    /// the TAO recycled by the registrations of the subnet.
+    pub fn get_unregister_refund(netuid: NetUid, uid: u16) -> TaoCurrency {
+        let immunity_period = u64::from(Self::get_immunity_period(netuid));
+        if immunity_period == 0 {
+            return TaoCurrency::ZERO;
+        }
+
+        let burn_paid =
+            U96F32::saturating_from_num(RegistrationBurnPaid::<T>::get(netuid, uid).to_u64());
+        let fraction = U96F32::saturating_from_num(UnregisterRefundFraction::<T>::get())
+            .safe_div(U96F32::saturating_from_num(u16::MAX));
+        let remaining =
+            U96F32::saturating_from_num(Self::get_neuron_immunity_remaining(netuid, uid))
+                .safe_div(U96F32::saturating_from_num(immunity_period));
+
+        let refund: TaoCurrency = burn_paid
+            .saturating_mul(fraction)
+            .saturating_mul(remaining)
+            .saturating_to_num::<u64>()
+            .into();
+        refund.min(Self::get_rao_recycled(netuid))
+    }
+
+    /// Determine which peer to prune from the network and mark it as pruned.
+    ///
+    /// The choice is made by `get_next_prunable_uid`. Returns 0 if the network has no prunable
+    /// neuron.
     pub fn get_neuron_to_prune(netuid: NetUid) -> u16 {
-        let mut min_score: u16 = u16::MAX;
-        let mut min_score_in_immunity: u16 = u16::MAX;
-        let mut earliest_registration: u64 = u64::MAX;
-        let mut earliest_registration_in_immunity: u64 = u64::MAX;
-        let mut uid_to_prune: u16 = 0;
-        let mut uid_to_prune_in_immunity: u16 = 0;
-
-        // This boolean is used instead of checking if min_score == u16::MAX, to avoid the case
-        // where all non-immune neurons have pruning score u16::MAX
-        // This may be unlikely in practice.
-        let mut found_non_immune = false;
-
-        let neurons_n = Self::get_subnetwork_n(netuid);
-        if neurons_n == 0 {
-            return 0; // If there are no neurons in this network.
+        match Self::get_next_prunable_uid(netuid) {
+            Some(uid_to_prune) => {
+                Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+                uid_to_prune
+            }
+            None => 0,
         }
+    }
 
-        for neuron_uid in 0..neurons_n {
+    /// The uid that the next registration on a full network would replace, if any.
+    ///
+    /// Neurons out of immunity are always pruned before immune ones. Within each group the neuron
+    /// with the lowest pruning score (its normalized emission from the last epoch) is chosen, ties
+    /// going to the neuron registered earliest and then to the lowest uid. The subnet owner hotkey
+    /// is never pruned.
+    pub fn get_next_prunable_uid(netuid: NetUid) -> Option<u16> {
+        let owner_hotkey = SubnetOwnerHotkey::<T>::try_get(netuid).ok();
+
+        // Iterating in uid order and only replacing on a strictly smaller key keeps the lowest
+        // uid among equal keys.
+        let mut candidate: Option<((bool, u16, u64), u16)> = None;
+        for neuron_uid in 0..Self::get_subnetwork_n(netuid) {
             // Do not deregister the owner's hotkey from the `SubnetOwnerHotkey` map
             if let Ok(hotkey) = Self::get_hotkey_for_net_and_uid(netuid, neuron_uid) {
-                if let Ok(top_sn_owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
-                    if top_sn_owner_hotkey == hotkey {
-                        continue;
-                    }
+                if owner_hotkey.as_ref() == Some(&hotkey) {
+                    continue;
                 }
             }
 
-            let pruning_score: u16 = Self::get_pruning_score_for_uid(netuid, neuron_uid);
-            let block_at_registration: u64 =
-                Self::get_neuron_block_at_registration(netuid, neuron_uid);
-            let is_immune = Self::get_neuron_is_immune(netuid, neuron_uid);
-
-            if is_immune {
-                // if the immune neuron has a lower pruning score than the minimum for immune neurons,
-                // or, if the pruning scores are equal and the immune neuron was registered earlier than the current minimum for immune neurons,
-                // then update the minimum pruning score and the uid to prune for immune neurons
-                if pruning_score < min_score_in_immunity
-                    || (pruning_score == min_score_in_immunity
-                        && block_at_registration < earliest_registration_in_immunity)
-                {
-                    min_score_in_immunity = pruning_score;
-                    earliest_registration_in_immunity = block_at_registration;
-                    uid_to_prune_in_immunity = neuron_uid;
-                }
-            } else {
-                found_non_immune = true;
-                // if the non-immune neuron has a lower pruning score than the minimum for non-immune neurons,
-                // or, if the pruning scores are equal and the non-immune neuron was registered earlier than the current minimum for non-immune neurons,
-                // then update the minimum pruning score and the uid to prune for non-immune neurons
-                if pruning_score < min_score
-                    || (pruning_score == min_score && block_at_registration < earliest_registration)
-                {
-                    min_score = pruning_score;
-                    earliest_registration = block_at_registration;
-                    uid_to_prune = neuron_uid;
-                }
+            let key = (
+                Self::get_neuron_is_immune(netuid, neuron_uid),
+                Self::get_pruning_score_for_uid(netuid, neuron_uid),
+                Self::get_neuron_block_at_registration(netuid, neuron_uid),
+            );
+            if candidate.is_none_or(|(min_key, _)| key < min_key) {
+                candidate = Some((key, neuron_uid));
             }
         }
 
-        if found_non_immune {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
-            uid_to_prune
-        } else {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
-            uid_to_prune_in_immunity
-        }
+        candidate.map(|(_, uid)| uid)
     }
 
     /// Determine whether the given hash satisfies the given difficulty.
@@ -552,6 +680,44 @@ impl<T: Config> Pallet<T> {
         seal_hash
     }
 
+    /// Creates the seal of a registration proof of work with the memory-hard Argon2id, salted with
+    /// the hash of the block and hotkey.
+    pub fn create_argon2_seal_hash(
+        block_number_u64: u64,
+        nonce_u64: u64,
+        hotkey: &T::AccountId,
+    ) -> Option<H256> {
+        let nonce = nonce_u64.to_le_bytes();
+        let block_hash_at_number: H256 = Self::get_block_hash_from_u64(block_number_u64);
+        let block_and_hotkey_hash: H256 =
+            Self::hash_block_and_hotkey(block_hash_at_number.as_fixed_bytes(), hotkey);
+
+        let params = Params::new(ARGON2_POW_MEMORY_KIB, ARGON2_POW_ITERATIONS, 1, Some(32)).ok()?;
+        let mut seal = [0u8; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(&nonce, block_and_hotkey_hash.as_bytes(), &mut seal)
+            .ok()?;
+
+        Some(H256::from(seal))
+    }
+
+    /// Creates the seal of a registration proof of work on `netuid`, with the hash of the subnet.
+    pub fn create_seal_hash_for_subnet(
+        netuid: NetUid,
+        block_number_u64: u64,
+        nonce_u64: u64,
+        hotkey: &T::AccountId,
+    ) -> Option<H256> {
+        match RegistrationPowAlgorithms::<T>::get(netuid) {
+            RegistrationPowAlgorithm::Sha256Keccak => {
+                Some(Self::create_seal_hash(block_number_u64, nonce_u64, hotkey))
+            }
+            RegistrationPowAlgorithm::Argon2id => {
+                Self::create_argon2_seal_hash(block_number_u64, nonce_u64, hotkey)
+            }
+        }
+    }
+
     /// Helper function for creating nonce and work.
     pub fn create_work_for_block_number(
         netuid: NetUid,
@@ -560,11 +726,15 @@ impl<T: Config> Pallet<T> {
         hotkey: &T::AccountId,
     ) -> (u64, Vec<u8>) {
         let difficulty: U256 = Self::get_difficulty(netuid);
+        let seal = |nonce: u64| {
+            Self::create_seal_hash_for_subnet(netuid, block_number, nonce, hotkey)
+                .unwrap_or_default()
+        };
         let mut nonce: u64 = start_nonce;
-        let mut work: H256 = Self::create_seal_hash(block_number, nonce, hotkey);
+        let mut work: H256 = seal(nonce);
         while !Self::hash_meets_difficulty(&work, difficulty) {
             nonce.saturating_inc();
-            work = Self::create_seal_hash(block_number, nonce, hotkey);
+            work = seal(nonce);
         }
         let vec_work: Vec<u8> = Self::hash_to_vec(work);
         (nonce, vec_work)
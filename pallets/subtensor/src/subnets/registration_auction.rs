@@ -0,0 +1,246 @@
+use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+impl<T: Config> Pallet<T> {
+    /// ---- The implementation for the extrinsic bid_registration.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling coldkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The subnet to register on.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     - The hotkey to register, must be new or owned by the coldkey.
+    ///
+    /// * 'bid' (TaoCurrency):
+    ///     - The amount of TAO bid, at least the subnet burn.
+    ///
+    /// # Event:
+    /// * RegistrationBidPlaced;
+    ///     - On successfully placing the bid.
+    ///
+    /// # Raises:
+    /// * 'RegistrationAuctionNotEnabled':
+    ///     - The subnet does not register by auction.
+    ///
+    /// * 'HotKeyAlreadyRegisteredInSubNet':
+    ///     - The hotkey is already registered on the subnet.
+    ///
+    /// * 'NonAssociatedColdKey':
+    ///     - The hotkey, or its current bid, belongs to another coldkey.
+    ///
+    /// * 'RegistrationBidTooLow':
+    ///     - The bid is below the subnet burn or does not raise the current bid for the hotkey.
+    ///
+    /// * 'TooManyRegistrationBids':
+    ///     - The subnet already has MAX_REGISTRATION_BIDS open bids.
+    ///
+    pub fn do_bid_registration(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        bid: TaoCurrency,
+    ) -> DispatchResult {
+        // --- 1. Check that the caller has signed the transaction.
+        let coldkey = ensure_signed(origin)?;
+        log::debug!(
+            "do_bid_registration( coldkey:{coldkey:?} netuid:{netuid:?} hotkey:{hotkey:?} bid:{bid:?} )"
+        );
+
+        // --- 2. Ensure the subnet registers by auction.
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+        ensure!(
+            Self::get_registration_auction_enabled(netuid),
+            Error::<T>::RegistrationAuctionNotEnabled
+        );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
+
+        // --- 3. Ensure the hotkey can be registered by this coldkey.
+        ensure!(
+            !Uids::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&hotkey) || Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        // --- 4. Ensure the bid meets the reserve price and raises any previous bid.
+        ensure!(
+            bid >= Self::get_burn(netuid),
+            Error::<T>::RegistrationBidTooLow
+        );
+        let mut bids = RegistrationBids::<T>::get(netuid);
+        let previous = bids
+            .iter()
+            .position(|(_, bid_hotkey, _)| *bid_hotkey == hotkey);
+        match previous.and_then(|index| bids.get(index)) {
+            Some((bid_coldkey, _, previous_bid)) => {
+                ensure!(*bid_coldkey == coldkey, Error::<T>::NonAssociatedColdKey);
+                ensure!(bid > *previous_bid, Error::<T>::RegistrationBidTooLow);
+            }
+            None => ensure!(
+                bids.len() < MAX_REGISTRATION_BIDS,
+                Error::<T>::TooManyRegistrationBids
+            ),
+        }
+
+        // --- 5. Take the bid, then release the previous one.
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, bid.into()),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        let actual_bid = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::RegistrationBids,
+            bid,
+            LedgerReason::RegistrationBid,
+        )?;
+        if let Some(index) = previous {
+            let (_, _, previous_bid) = bids.remove(index);
+            Self::transfer_tao(
+                LedgerAccount::RegistrationBids,
+                LedgerAccount::Coldkey(coldkey.clone()),
+                previous_bid,
+                LedgerReason::RegistrationBid,
+            )?;
+        }
+        bids.push((coldkey.clone(), hotkey.clone(), actual_bid));
+        RegistrationBids::<T>::insert(netuid, bids);
+
+        // --- 6. Deposit successful event.
+        Self::deposit_event(Event::RegistrationBidPlaced {
+            netuid,
+            coldkey,
+            hotkey,
+            bid: actual_bid,
+        });
+
+        Ok(())
+    }
+
+    /// Closes the registration auction of `netuid` for the current interval.
+    ///
+    /// The highest `target_registrations_per_interval` bids are registered (ties go to the earlier
+    /// bid) and their TAO is swapped to alpha and burned, like a burned registration. Every other
+    /// bid, and any winning bid whose registration fails, is refunded.
+    pub fn settle_registration_auction(netuid: NetUid) {
+        let mut bids = RegistrationBids::<T>::take(netuid);
+        if bids.is_empty() {
+            return;
+        }
+        // Stable sort keeps bid order for equal amounts.
+        bids.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
+
+        let slots = usize::from(Self::get_target_registrations_per_interval(netuid));
+        for (rank, (coldkey, hotkey, bid)) in bids.into_iter().enumerate() {
+            let registered = rank < slots
+                && transactional::with_transaction(|| {
+                    match Self::register_auction_winner(netuid, &coldkey, &hotkey, bid) {
+                        Ok(()) => TransactionOutcome::Commit(Ok::<bool, DispatchError>(true)),
+                        Err(e) => {
+                            log::debug!("Registration of auction winner {hotkey:?} failed: {e:?}");
+                            TransactionOutcome::Rollback(Ok(false))
+                        }
+                    }
+                })
+                .unwrap_or(false);
+
+            if !registered {
+                Self::refund_registration_bid(netuid, coldkey, hotkey, bid);
+            }
+        }
+    }
+
+    /// Refunds every open registration bid of `netuid`.
+    pub fn refund_registration_bids(netuid: NetUid) {
+        for (coldkey, hotkey, bid) in RegistrationBids::<T>::take(netuid) {
+            Self::refund_registration_bid(netuid, coldkey, hotkey, bid);
+        }
+    }
+
+    fn refund_registration_bid(
+        netuid: NetUid,
+        coldkey: T::AccountId,
+        hotkey: T::AccountId,
+        bid: TaoCurrency,
+    ) {
+        let _ = Self::transfer_tao(
+            LedgerAccount::RegistrationBids,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            bid,
+            LedgerReason::RegistrationBid,
+        );
+        Self::deposit_event(Event::RegistrationBidRefunded {
+            netuid,
+            coldkey,
+            hotkey,
+            bid,
+        });
+    }
+
+    fn register_auction_winner(
+        netuid: NetUid,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        bid: TaoCurrency,
+    ) -> DispatchResult {
+        // The hotkey may have been registered, or taken by another coldkey, since the bid.
+        ensure!(
+            !Uids::<T>::contains_key(netuid, hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        Self::create_account_if_non_existent(coldkey, hotkey);
+        ensure!(
+            Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::get_max_allowed_uids(netuid) != 0,
+            Error::<T>::NoNeuronIdAvailable
+        );
+
+        // The bid is swapped and then burned.
+        Self::transfer_tao(
+            LedgerAccount::RegistrationBids,
+            LedgerAccount::Stake,
+            bid,
+            LedgerReason::RegistrationBid,
+        )?;
+        let burned_alpha =
+            Self::swap_tao_for_alpha(netuid, bid, T::SwapInterface::max_price().into(), false)?
+                .amount_paid_out;
+        SubnetAlphaOut::<T>::mutate(netuid, |total| {
+            *total = total.saturating_sub(burned_alpha.into())
+        });
+        Self::record_subnet_alpha_recycled(netuid, burned_alpha.into());
+
+        let neuron_uid: u16 = Self::register_neuron(netuid, hotkey);
+        RegistrationBurnPaid::<T>::insert(netuid, neuron_uid, bid);
+
+        BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+        RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::increase_rao_recycled(netuid, bid);
+
+        log::debug!("NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?} ) ");
+        Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey.clone()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! This file defines the congestion pricing of registration calls.
+//!
+//! Registrations come in bursts when a subnet opens up, and the general fee multiplier is tuned
+//! for the chain as a whole, so it either ignores such bursts or prices out everyone else. The
+//! transaction fee of registration calls is therefore scaled by a separate multiplier, which
+//! moves towards a target share of the block weight filled by registration calls: it rises by up
+//! to 1/8 after every block that registration calls filled past the target and falls by up to 1/8
+//! after every block they did not, never dropping below one.
+
+use super::*;
+use frame_support::weights::Weight;
+use sp_runtime::Perbill;
+use substrate_fixed::types::U64F64;
+
+/// The highest multiplier of the fee of registration calls.
+pub const MAX_REGISTRATION_FEE_MULTIPLIER: u64 = 10_000;
+
+/// The inverse of the largest change of the multiplier in a block.
+const REGISTRATION_FEE_ADJUSTMENT_DENOMINATOR: u64 = 8;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_registration_fee_multiplier() -> U64F64 {
+        RegistrationFeeMultiplier::<T>::get()
+    }
+    pub fn get_target_registration_block_fullness() -> Perbill {
+        TargetRegistrationBlockFullness::<T>::get()
+    }
+    pub fn set_target_registration_block_fullness(fullness: Perbill) {
+        TargetRegistrationBlockFullness::<T>::put(fullness);
+        Self::deposit_event(Event::TargetRegistrationBlockFullnessSet(fullness));
+    }
+
+    /// Records that a registration call of `weight` was included in this block.
+    pub fn note_registration_weight(weight: Weight) {
+        RegistrationWeightThisBlock::<T>::mutate(|total| *total = total.saturating_add(weight));
+    }
+
+    /// Moves the multiplier of the fee of registration calls towards the target block fullness,
+    /// based on the weight of the registration calls in the previous block, and resets that
+    /// weight. Runs when the block is initialized, and returns its weight.
+    pub fn update_registration_fee_multiplier() -> Weight {
+        let used = RegistrationWeightThisBlock::<T>::take().ref_time();
+        let max_block = <T as frame_system::Config>::BlockWeights::get()
+            .max_block
+            .ref_time();
+        let target = Self::get_target_registration_block_fullness().mul_floor(max_block);
+
+        let one = U64F64::saturating_from_num(1);
+        let multiplier = Self::get_registration_fee_multiplier();
+        let next = if target == 0 {
+            one
+        } else {
+            // A block more than twice over the target counts as twice over it.
+            let used = used.min(target.saturating_mul(2));
+            let step = multiplier
+                .saturating_mul(U64F64::saturating_from_num(used.abs_diff(target)))
+                .checked_div(U64F64::saturating_from_num(
+                    target.saturating_mul(REGISTRATION_FEE_ADJUSTMENT_DENOMINATOR),
+                ))
+                .unwrap_or_default();
+            if used > target {
+                multiplier.saturating_add(step)
+            } else {
+                multiplier.saturating_sub(step)
+            }
+        };
+
+        RegistrationFeeMultiplier::<T>::put(next.clamp(
+            one,
+            U64F64::saturating_from_num(MAX_REGISTRATION_FEE_MULTIPLIER),
+        ));
+
+        T::DbWeight::get().reads_writes(3, 2)
+    }
+
+    /// Whether the transaction fee of `call` is scaled by the registration fee multiplier.
+    pub fn is_registration_call(call: &Call<T>) -> bool {
+        matches!(
+            call,
+            Call::register { .. }
+                | Call::burned_register { .. }
+                | Call::root_register { .. }
+                | Call::register_network { .. }
+                | Call::register_network_with_identity { .. }
+                | Call::register_leased_network { .. }
+                | Call::bid_registration { .. }
+        )
+    }
+
+    /// Scales the transaction `fee` of a registration call by the registration fee multiplier.
+    pub fn scale_registration_fee(fee: u64) -> u64 {
+        Self::get_registration_fee_multiplier()
+            .saturating_mul(U64F64::saturating_from_num(fee))
+            .saturating_to_num::<u64>()
+    }
+}
@@ -0,0 +1,136 @@
+//! This file defines the sale of subnets.
+//!
+//! The owner of a subnet can offer it for sale at a price with `offer_subnet`, optionally to a
+//! single buyer. A buyer accepting the offer with `accept_subnet_offer` pays the price to the seller
+//! and becomes the owner of the subnet in the same extrinsic, so neither party has to trust the
+//! other or an intermediary: the payment and the transfer of ownership both happen, or neither does.
+//!
+//! An offer is only valid while the seller owns the subnet, and leased subnets cannot be sold since
+//! they are owned by the lease.
+
+use super::*;
+use frame_support::traits::{fungible::*, tokens::Preservation};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+/// An offer to sell a subnet.
+#[freeze_struct("969a7f43c5e23813")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SubnetOffer<AccountId> {
+    /// The owner of the subnet when it was offered.
+    pub seller: AccountId,
+    /// The price of the subnet.
+    pub price: TaoCurrency,
+    /// The only coldkey allowed to buy the subnet, if any.
+    pub buyer: Option<AccountId>,
+}
+
+pub type SubnetOfferOf<T> = SubnetOffer<<T as frame_system::Config>::AccountId>;
+
+impl<T: Config> Pallet<T> {
+    /// Offers `netuid` for sale at `price`, to `buyer` only if set, replacing the previous offer.
+    pub fn do_offer_subnet(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        price: TaoCurrency,
+        buyer: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let seller = ensure_signed(origin)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == seller,
+            Error::<T>::NotSubnetOwner
+        );
+        ensure!(
+            !SubnetUidToLeaseId::<T>::contains_key(netuid),
+            Error::<T>::CannotSellLeasedSubnet
+        );
+
+        SubnetOffers::<T>::insert(
+            netuid,
+            SubnetOffer {
+                seller: seller.clone(),
+                price,
+                buyer: buyer.clone(),
+            },
+        );
+
+        Self::deposit_event(Event::SubnetOffered {
+            netuid,
+            seller,
+            price,
+            buyer,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraws the offer to sell `netuid`.
+    pub fn do_cancel_subnet_offer(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        let seller = ensure_signed(origin)?;
+
+        let offer = SubnetOffers::<T>::get(netuid).ok_or(Error::<T>::SubnetOfferNotFound)?;
+        ensure!(offer.seller == seller, Error::<T>::NotSubnetOwner);
+
+        SubnetOffers::<T>::remove(netuid);
+
+        Self::deposit_event(Event::SubnetOfferCancelled { netuid, seller });
+
+        Ok(())
+    }
+
+    /// Buys `netuid` at the price of its offer, at most `max_price`, and makes `hotkey`, owned by
+    /// the buyer, the subnet owner hotkey.
+    pub fn do_accept_subnet_offer(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        max_price: TaoCurrency,
+    ) -> DispatchResult {
+        let buyer = ensure_signed(origin)?;
+
+        let offer = SubnetOffers::<T>::get(netuid).ok_or(Error::<T>::SubnetOfferNotFound)?;
+        // The offer lapses once the seller no longer owns the subnet.
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == offer.seller
+                && !SubnetUidToLeaseId::<T>::contains_key(netuid),
+            Error::<T>::SubnetOfferNotFound
+        );
+        ensure!(
+            offer.buyer.as_ref().is_none_or(|allowed| *allowed == buyer),
+            Error::<T>::NotSubnetOfferBuyer
+        );
+        ensure!(
+            offer.price <= max_price,
+            Error::<T>::SubnetOfferPriceTooHigh
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&buyer, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        <T as Config>::Currency::transfer(
+            &buyer,
+            &offer.seller,
+            offer.price.into(),
+            Preservation::Expendable,
+        )?;
+
+        SubnetOffers::<T>::remove(netuid);
+        SubnetOwner::<T>::insert(netuid, buyer.clone());
+        Self::set_subnet_owner_hotkey(netuid, &hotkey);
+
+        Self::deposit_event(Event::SubnetSold {
+            netuid,
+            seller: offer.seller,
+            buyer,
+            price: offer.price,
+        });
+
+        Ok(())
+    }
+}
@@ -108,8 +108,122 @@ impl<T: Config> Pallet<T> {
             axon_validated.err().unwrap_or(Error::<T>::InvalidPort)
         );
 
+        // The served endpoint replaces the primary endpoint of the axon v2 info.
+        AxonsV2::<T>::mutate(netuid, &hotkey_id, |maybe_axon_v2| {
+            let axon_v2 = maybe_axon_v2.get_or_insert_with(Default::default);
+            axon_v2.block = prev_axon.block;
+            axon_v2.version = prev_axon.version;
+            let primary = AxonEndpoint::from(&prev_axon);
+            if let Some(first) = axon_v2.endpoints.first_mut() {
+                *first = primary;
+            } else {
+                let _ = axon_v2.endpoints.try_push(primary);
+            }
+        });
+        Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
+
+        // We deposit axon served event.
+        log::debug!("AxonServed( hotkey:{:?} ) ", hotkey_id.clone());
+        Self::deposit_event(Event::AxonServed(netuid, hotkey_id));
+
+        // Return is successful dispatch.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic serve_axon_v2 which sets all the endpoints of an axon.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'version' (u32):
+    ///     - The bittensor version identifier.
+    ///
+    /// * 'protocol_version' (u32):
+    ///     - The version of the protocol spoken by the axon.
+    ///
+    /// * 'endpoints' (Vec<AxonEndpoint>):
+    ///     - The endpoints of the axon, primary first. The primary endpoint is also served as the
+    ///       `AxonInfo` of the hotkey.
+    ///
+    /// * 'tls_fingerprint' (Option<[u8; 32]>):
+    ///     - SHA-256 fingerprint of the TLS certificate presented by the axon.
+    ///
+    /// # Event:
+    /// * AxonServed;
+    ///     - On successfully serving the axon info.
+    ///
+    /// # Raises:
+    /// * 'NoAxonEndpoints':
+    ///     - No endpoint is given.
+    ///
+    /// * 'TooManyAxonEndpoints':
+    ///     - More than `MAX_AXON_ENDPOINTS` endpoints are given.
+    ///
+    /// * 'InvalidIpType', 'InvalidIpAddress', 'InvalidPort':
+    ///     - An endpoint is not valid, see `validate_axon_endpoint`.
+    ///
+    /// * 'ServingRateLimitExceeded':
+    ///     - Attempting to serve the axon within the rate limit min.
+    ///
+    pub fn do_serve_axon_v2(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        version: u32,
+        protocol_version: u32,
+        endpoints: Vec<AxonEndpoint>,
+        tls_fingerprint: Option<[u8; 32]>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        // Ensure the hotkey is registered somewhere.
+        ensure!(
+            Self::is_hotkey_registered_on_any_network(&hotkey_id),
+            Error::<T>::HotKeyNotRegisteredInNetwork
+        );
+
+        // Validate the endpoints.
+        let endpoints: BoundedVec<AxonEndpoint, ConstU32<MAX_AXON_ENDPOINTS>> = endpoints
+            .try_into()
+            .map_err(|_| Error::<T>::TooManyAxonEndpoints)?;
+        let primary = endpoints.first().ok_or(Error::<T>::NoAxonEndpoints)?;
+        for endpoint in endpoints.iter() {
+            Self::validate_axon_endpoint(endpoint)?;
+        }
+
+        // Check the rate limit against the last serving of the axon.
+        let mut prev_axon = Self::get_axon_info(netuid, &hotkey_id);
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::axon_passes_rate_limit(netuid, &prev_axon, current_block),
+            Error::<T>::ServingRateLimitExceeded
+        );
+
+        // We mirror the primary endpoint into the axon meta.
+        prev_axon.block = current_block;
+        prev_axon.version = version;
+        prev_axon.ip = primary.ip;
+        prev_axon.port = primary.port;
+        prev_axon.ip_type = primary.ip_type;
+        prev_axon.protocol = primary.protocol;
         Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
 
+        AxonsV2::<T>::insert(
+            netuid,
+            hotkey_id.clone(),
+            AxonInfoV2 {
+                block: current_block,
+                version,
+                protocol_version,
+                endpoints,
+                tls_fingerprint,
+            },
+        );
+
         // We deposit axon served event.
         log::debug!("AxonServed( hotkey:{:?} ) ", hotkey_id.clone());
         Self::deposit_event(Event::AxonServed(netuid, hotkey_id));
@@ -257,6 +371,13 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// The axon v2 info of the hotkey, built from its `AxonInfo` if it never served a v2 axon.
+    pub fn get_axon_info_v2(netuid: NetUid, hotkey: &T::AccountId) -> AxonInfoV2 {
+        AxonsV2::<T>::get(netuid, hotkey)
+            .or_else(|| Axons::<T>::get(netuid, hotkey).map(AxonInfoV2::from))
+            .unwrap_or_default()
+    }
+
     pub fn get_prometheus_info(netuid: NetUid, hotkey: &T::AccountId) -> PrometheusInfoOf {
         if let Some(prometheus) = Prometheus::<T>::get(netuid, hotkey) {
             prometheus
@@ -308,6 +429,28 @@ impl<T: Config> Pallet<T> {
         Ok(true)
     }
 
+    /// An endpoint is valid if it has a port and either a valid ip or, for
+    /// `AXON_IP_TYPE_COMMITMENT`, a zero ip.
+    pub fn validate_axon_endpoint(endpoint: &AxonEndpoint) -> Result<(), Error<T>> {
+        ensure!(endpoint.port != 0, Error::<T>::InvalidPort);
+        if endpoint.ip_type == AXON_IP_TYPE_COMMITMENT {
+            ensure!(endpoint.ip == 0, Error::<T>::InvalidIpAddress);
+            return Ok(());
+        }
+
+        ensure!(
+            Self::is_valid_ip_type(endpoint.ip_type),
+            Error::<T>::InvalidIpType
+        );
+        ensure!(
+            // allow axon to be served with a zero ip address for testing purposes
+            Self::is_valid_ip_address(endpoint.ip_type, endpoint.ip, true),
+            Error::<T>::InvalidIpAddress
+        );
+
+        Ok(())
+    }
+
     pub fn validate_prometheus_data(
         prom_info: &PrometheusInfoOf,
     ) -> Result<bool, pallet::Error<T>> {
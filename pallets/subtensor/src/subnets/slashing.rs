@@ -0,0 +1,177 @@
+//! This file defines the reporting and slashing of provable validator misbehavior.
+//!
+//! Before committing or revealing weights, a hotkey signs a statement binding the commit hash, or
+//! the hash of the revealed weights, to a subnet and an epoch. Two statements signed by the same
+//! hotkey for the same subnet, epoch and kind of action, but over different hashes, prove that the
+//! hotkey equivocated.
+//!
+//! Anyone can submit such a pair with the `report_offense` extrinsic. The signatures are verified
+//! on-chain and the stake of the offending hotkey on the subnet is slashed by
+//! `OffenseSlashFraction`. As with emission, the slash is shared by all the stakers of the hotkey.
+//! The slashed alpha is recycled. Each offense can be reported once.
+
+use super::*;
+use safe_math::*;
+use sp_core::{H256, sr25519};
+use sp_runtime::traits::{BlakeTwo256, Hash, Verify};
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// Domain separator of the statements signed by hotkeys.
+pub const WEIGHT_STATEMENT_CONTEXT: &[u8] = b"subtensor:weight-statement";
+
+/// The kind of action a signed weight statement commits to.
+#[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WeightOffenseKind {
+    /// Two different weight commits for the same epoch.
+    CommitEquivocation,
+    /// Two different reveals for the same epoch.
+    RevealEquivocation,
+}
+
+/// A hash signed by a hotkey for a subnet epoch.
+#[freeze_struct("deeac08f803113a6")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct WeightStatement {
+    /// The commit hash, or the hash of the revealed weights.
+    pub hash: H256,
+    /// The sr25519 signature of the hotkey over the statement message.
+    pub signature: sr25519::Signature,
+}
+
+/// Two conflicting statements signed by `hotkey`.
+#[freeze_struct("6576d96b1780f1a5")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct WeightOffenseEvidence<AccountId> {
+    /// The offending hotkey.
+    pub hotkey: AccountId,
+    /// The kind of action both statements commit to.
+    pub kind: WeightOffenseKind,
+    /// The subnet of both statements.
+    pub netuid: NetUid,
+    /// The epoch of both statements.
+    pub epoch: u64,
+    /// The first statement.
+    pub first: WeightStatement,
+    /// The second statement, over a different hash.
+    pub second: WeightStatement,
+}
+
+pub type WeightOffenseEvidenceOf<T> = WeightOffenseEvidence<<T as frame_system::Config>::AccountId>;
+
+impl<T: Config> Pallet<T> {
+    /// Verifies the evidence of an offense and slashes the offending hotkey on the subnet.
+    pub fn do_report_offense(
+        origin: T::RuntimeOrigin,
+        evidence: WeightOffenseEvidenceOf<T>,
+    ) -> DispatchResult {
+        let reporter = ensure_signed(origin)?;
+
+        ensure!(
+            Self::if_subnet_exist(evidence.netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            Self::hotkey_account_exists(&evidence.hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // Ensure the statements conflict and were both signed by the hotkey.
+        ensure!(
+            evidence.first.hash != evidence.second.hash,
+            Error::<T>::InvalidOffenseEvidence
+        );
+        ensure!(
+            Self::verify_weight_statement(&evidence, &evidence.first)
+                && Self::verify_weight_statement(&evidence, &evidence.second),
+            Error::<T>::InvalidOffenseSignature
+        );
+
+        // Ensure the offense is punished once.
+        let offense_id = Self::get_offense_id(&evidence);
+        ensure!(
+            !ReportedOffenses::<T>::contains_key(evidence.netuid, offense_id),
+            Error::<T>::OffenseAlreadyReported
+        );
+        ReportedOffenses::<T>::insert(
+            evidence.netuid,
+            offense_id,
+            Self::get_current_block_as_u64(),
+        );
+
+        let slashed = Self::slash_hotkey_on_subnet(&evidence.hotkey, evidence.netuid);
+
+        log::debug!(
+            "OffenderSlashed( netuid:{:?} hotkey:{:?} kind:{:?} epoch:{:?} slashed:{slashed:?} )",
+            evidence.netuid,
+            evidence.hotkey,
+            evidence.kind,
+            evidence.epoch
+        );
+        Self::deposit_event(Event::OffenderSlashed {
+            netuid: evidence.netuid,
+            hotkey: evidence.hotkey,
+            kind: evidence.kind,
+            epoch: evidence.epoch,
+            reporter,
+            slashed,
+        });
+
+        Ok(())
+    }
+
+    /// The message a hotkey signs to commit to `hash` for `kind` on `netuid` at `epoch`.
+    pub fn get_weight_statement_message(
+        kind: WeightOffenseKind,
+        netuid: NetUid,
+        epoch: u64,
+        hash: &H256,
+    ) -> Vec<u8> {
+        (WEIGHT_STATEMENT_CONTEXT, kind, netuid, epoch, hash).encode()
+    }
+
+    /// Slashes `OffenseSlashFraction` of the stake of `hotkey` on `netuid`, recycling it.
+    pub fn slash_hotkey_on_subnet(hotkey: &T::AccountId, netuid: NetUid) -> AlphaCurrency {
+        let stake = Self::get_stake_for_hotkey_on_subnet(hotkey, netuid);
+        let fraction = U96F32::saturating_from_num(OffenseSlashFraction::<T>::get())
+            .safe_div(U96F32::saturating_from_num(u16::MAX));
+        let slashed: AlphaCurrency = U96F32::saturating_from_num(stake.to_u64())
+            .saturating_mul(fraction)
+            .saturating_to_num::<u64>()
+            .min(SubnetAlphaOut::<T>::get(netuid).to_u64())
+            .into();
+        if slashed.is_zero() {
+            return slashed;
+        }
+
+        Self::decrease_stake_for_hotkey_on_subnet(hotkey, netuid, slashed.to_u64());
+        SubnetAlphaOut::<T>::mutate(netuid, |total| {
+            *total = total.saturating_sub(slashed);
+        });
+        Self::record_subnet_alpha_recycled(netuid, slashed);
+
+        slashed
+    }
+
+    fn verify_weight_statement(
+        evidence: &WeightOffenseEvidenceOf<T>,
+        statement: &WeightStatement,
+    ) -> bool {
+        let Ok(public) = <[u8; 32]>::try_from(evidence.hotkey.encode()) else {
+            return false;
+        };
+        let message = Self::get_weight_statement_message(
+            evidence.kind,
+            evidence.netuid,
+            evidence.epoch,
+            &statement.hash,
+        );
+        statement
+            .signature
+            .verify(message.as_slice(), &sr25519::Public::from_raw(public))
+    }
+
+    fn get_offense_id(evidence: &WeightOffenseEvidenceOf<T>) -> H256 {
+        BlakeTwo256::hash_of(&(&evidence.hotkey, evidence.kind, evidence.epoch))
+    }
+}
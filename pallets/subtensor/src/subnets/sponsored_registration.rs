@@ -0,0 +1,104 @@
+//! This file defines burned registrations sponsored by a third party.
+//!
+//! An onboarding service can pay the registration burn of a neuron it does not control with
+//! `sponsored_burned_register`. The coldkey the hotkey is registered to signs a consent binding the
+//! subnet, the hotkey, the sponsor and a deadline block, so the sponsor never holds the keys of the
+//! user and cannot register a hotkey the user did not ask for. The signature is verified on-chain
+//! and the registration then proceeds as a regular burned registration, with the burn paid by the
+//! sponsor.
+//!
+//! The consent also binds the genesis hash, so it is only valid on this chain, and the
+//! `SponsorConsentNonce` of the coldkey, which each sponsored registration increments, so a consent
+//! is used at most once.
+
+use super::*;
+use sp_core::sr25519;
+use sp_runtime::traits::Verify;
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+/// Domain separator of the consents signed by coldkeys.
+pub const SPONSORED_REGISTRATION_CONTEXT: &[u8] = b"subtensor:sponsored-registration";
+
+/// The consent of a coldkey to a sponsored registration.
+#[freeze_struct("45e980f4ef8129cd")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SponsorConsent {
+    /// The last block the consent can be used at.
+    pub deadline: u64,
+    /// The sr25519 signature of the coldkey over the consent message.
+    pub signature: sr25519::Signature,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Registers `hotkey`, owned by `coldkey`, on `netuid` with the burn paid by the caller, given
+    /// the consent of `coldkey`.
+    pub fn do_sponsored_burned_registration(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        coldkey: T::AccountId,
+        consent: SponsorConsent,
+    ) -> DispatchResult {
+        let sponsor = ensure_signed(origin)?;
+
+        ensure!(
+            Self::get_current_block_as_u64() <= consent.deadline,
+            Error::<T>::SponsorConsentExpired
+        );
+        ensure!(
+            Self::verify_sponsor_consent(netuid, &hotkey, &coldkey, &sponsor, &consent),
+            Error::<T>::InvalidSponsorConsent
+        );
+
+        let burn = Self::burned_register_neuron(&sponsor, &coldkey, netuid, hotkey.clone())?;
+        SponsorConsentNonce::<T>::mutate(&coldkey, |nonce| *nonce = nonce.saturating_add(1));
+
+        Self::deposit_event(Event::NeuronRegistrationSponsored {
+            netuid,
+            hotkey,
+            coldkey,
+            sponsor,
+            burn,
+        });
+
+        Ok(())
+    }
+
+    /// The message `coldkey` signs to consent to the sponsored registration of `hotkey`. It
+    /// includes the current `SponsorConsentNonce` of the coldkey.
+    pub fn get_sponsor_consent_message(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        sponsor: &T::AccountId,
+        deadline: u64,
+    ) -> Vec<u8> {
+        (
+            SPONSORED_REGISTRATION_CONTEXT,
+            Self::get_block_hash_from_u64(0),
+            netuid,
+            hotkey,
+            sponsor,
+            SponsorConsentNonce::<T>::get(coldkey),
+            deadline,
+        )
+            .encode()
+    }
+
+    fn verify_sponsor_consent(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        sponsor: &T::AccountId,
+        consent: &SponsorConsent,
+    ) -> bool {
+        let Ok(public) = <[u8; 32]>::try_from(coldkey.encode()) else {
+            return false;
+        };
+        let message =
+            Self::get_sponsor_consent_message(netuid, hotkey, coldkey, sponsor, consent.deadline);
+        consent
+            .signature
+            .verify(message.as_slice(), &sr25519::Public::from_raw(public))
+    }
+}
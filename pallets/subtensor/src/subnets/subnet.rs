@@ -1,4 +1,6 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use frame_system::ensure_signed_or_root;
 use sp_core::Get;
 use subtensor_runtime_common::{NetUid, TaoCurrency};
 
@@ -151,13 +153,20 @@ impl<T: Config> Pallet<T> {
         // --- 6. Determine the netuid to register.
         let netuid_to_register = Self::get_next_netuid();
 
-        // --- 7. Perform the lock operation.
-        let actual_tao_lock_amount =
-            Self::remove_balance_from_coldkey_account(&coldkey, lock_amount.into())?;
+        // --- 7. Perform the lock operation. The lock is burned, and the initial pool TAO is
+        // issued back into the pool below.
+        let actual_tao_lock_amount = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Issuance,
+            lock_amount,
+            LedgerReason::NetworkLock,
+        )?;
         log::debug!("actual_tao_lock_amount: {actual_tao_lock_amount:?}");
 
-        // --- 8. Set the lock amount for use to determine pricing.
+        // --- 8. Set the lock amount for use to determine pricing, and record it to be refunded
+        // when the subnet is dissolved.
         Self::set_network_last_lock(actual_tao_lock_amount);
+        Self::set_subnet_locked_balance(netuid_to_register, actual_tao_lock_amount);
 
         // --- 9. Set initial and custom parameters for the network.
         let default_tempo = DefaultTempo::<T>::get();
@@ -195,12 +204,20 @@ impl<T: Config> Pallet<T> {
         SubnetOwnerHotkey::<T>::insert(netuid_to_register, hotkey.clone());
 
         if actual_tao_lock_amount_less_pool_tao > TaoCurrency::ZERO {
-            Self::burn_tokens(actual_tao_lock_amount_less_pool_tao);
+            Self::record_subnet_tao_burned(
+                netuid_to_register,
+                actual_tao_lock_amount_less_pool_tao,
+            );
         }
 
         if actual_tao_lock_amount > TaoCurrency::ZERO && pool_initial_tao > TaoCurrency::ZERO {
             // Record in TotalStake the initial TAO in the pool.
-            Self::increase_total_stake(pool_initial_tao);
+            Self::transfer_tao(
+                LedgerAccount::Issuance,
+                LedgerAccount::Stake,
+                pool_initial_tao,
+                LedgerReason::NetworkLock,
+            )?;
         }
 
         // --- 15. Add the identity if it exists
@@ -328,6 +345,10 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SubNetworkDoesNotExist
         );
         Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            !Self::is_network_dissolving(netuid),
+            Error::<T>::NetworkDissolutionInProgress
+        );
         ensure!(
             FirstEmissionBlockNumber::<T>::get(netuid).is_none(),
             Error::<T>::FirstEmissionBlockNumberAlreadySet
@@ -419,6 +440,62 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Sets the owner cut of a subnet within [`MinSubnetOwnerCut`]..=[`MaxSubnetOwnerCut`].
+    ///
+    /// The cut is the share of the subnet's alpha emission paid to the subnet owner, and
+    /// overrides the global [`SubnetOwnerCut`] for this subnet.
+    ///
+    /// # Errors
+    /// - `BadOrigin`: If the caller is neither root nor the subnet owner.
+    /// - `Error::SubnetNotExists`: If the specified subnet does not exist.
+    /// - `Error::SubnetOwnerCutOutOfBounds`: If the cut is outside the governance bounds.
+    /// - `Error::TxRateLimitExceeded`: If the function is called more frequently than the allowed rate limit.
+    ///
+    /// # Rate Limiting
+    /// This function is rate-limited to one call per subnet per [`DefaultSetSubnetOwnerCutRateLimit`] blocks.
+    pub fn do_set_subnet_owner_cut_for_subnet(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        subnet_owner_cut: u16,
+    ) -> DispatchResult {
+        // Ensure the caller is either root or subnet owner.
+        let maybe_owner = ensure_signed_or_root(origin.clone())?;
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+        // Ensure that the subnet exists.
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        // Ensure the cut is within the governance bounds.
+        ensure!(
+            subnet_owner_cut >= MinSubnetOwnerCut::<T>::get()
+                && subnet_owner_cut <= MaxSubnetOwnerCut::<T>::get(),
+            Error::<T>::SubnetOwnerCutOutOfBounds
+        );
+
+        // Rate limit changes made by the subnet owner.
+        if let Some(owner) = maybe_owner {
+            ensure!(
+                Self::passes_rate_limit_on_subnet(
+                    &TransactionType::SetSubnetOwnerCut,
+                    &owner, // ignored
+                    netuid, // Specific to a subnet.
+                ),
+                Error::<T>::TxRateLimitExceeded
+            );
+            Self::set_last_transaction_block_on_subnet(
+                &owner,
+                netuid,
+                &TransactionType::SetSubnetOwnerCut,
+                Self::get_current_block_as_u64(),
+            );
+        }
+
+        SubnetOwnerCutForSubnet::<T>::insert(netuid, subnet_owner_cut);
+        Self::deposit_event(Event::SubnetOwnerCutSetForSubnet(netuid, subnet_owner_cut));
+
+        Ok(())
+    }
+
     pub fn is_valid_subnet_for_emission(netuid: NetUid) -> bool {
         FirstEmissionBlockNumber::<T>::get(netuid).is_some()
     }
@@ -0,0 +1,63 @@
+//! This file defines the subnet transaction fee.
+//!
+//! The owner of a subnet can levy a small fee, on top of the regular transaction fee, on the
+//! extrinsics scoped to the subnet: setting weights, serving axons and burn registering neurons.
+//! Registering with proof of work stays free. The fee is charged by
+//! `SubtensorTransactionExtension` before the extrinsic is dispatched, so it is paid even if the
+//! extrinsic fails, and goes to the subnet treasury.
+//!
+//! Transactions whose signer cannot pay the fee are invalid and never enter the pool.
+
+use super::*;
+use frame_support::traits::{
+    fungible::*,
+    tokens::{Fortitude, Preservation},
+};
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+
+/// The highest fee a subnet can levy on its extrinsics, in RAO.
+pub const MAX_SUBNET_TRANSACTION_FEE: u64 = 100_000_000;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_subnet_transaction_fee(netuid: NetUid) -> TaoCurrency {
+        SubnetTransactionFee::<T>::get(netuid)
+    }
+    pub fn set_subnet_transaction_fee(netuid: NetUid, fee: TaoCurrency) {
+        SubnetTransactionFee::<T>::insert(netuid, fee);
+        Self::deposit_event(Event::SubnetTransactionFeeSet(netuid, fee));
+    }
+
+    /// Whether `who` can pay the transaction fee of `netuid`.
+    pub fn can_pay_subnet_transaction_fee(who: &T::AccountId, netuid: NetUid) -> bool {
+        let fee = Self::get_subnet_transaction_fee(netuid);
+        fee.is_zero()
+            || <T as Config>::Currency::reducible_balance(
+                who,
+                Preservation::Preserve,
+                Fortitude::Polite,
+            ) >= fee.into()
+    }
+
+    /// Charges `who` the transaction fee of `netuid` and pays it to the subnet treasury.
+    pub fn charge_subnet_transaction_fee(who: &T::AccountId, netuid: NetUid) -> DispatchResult {
+        let fee = Self::get_subnet_transaction_fee(netuid);
+        if fee.is_zero() {
+            return Ok(());
+        }
+
+        <T as Config>::Currency::transfer(
+            who,
+            &Self::get_subnet_treasury_account(netuid),
+            fee.into(),
+            Preservation::Preserve,
+        )?;
+
+        Self::deposit_event(Event::SubnetTransactionFeePaid {
+            netuid,
+            who: who.clone(),
+            fee,
+        });
+
+        Ok(())
+    }
+}
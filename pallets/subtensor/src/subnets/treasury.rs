@@ -0,0 +1,250 @@
+//! This file defines the subnet treasury.
+//!
+//! Every subnet has a keyless treasury account, derived from its netuid, that holds TAO for the
+//! subnet rather than its owner. The treasury receives the subnet transaction fee and
+//! `SubnetTreasuryShare` of the owner cut, which is unstaked to TAO as it is distributed.
+//!
+//! The treasury can only be spent by the subnet's stakers: the subnet owner proposes a spend with
+//! `propose_subnet_treasury_spend`, and coldkeys staking on the subnet vote on it with
+//! `vote_subnet_treasury_spend`. The spend is paid once it passes with the quorum and duration of
+//! subnet proposals. A subnet has at most one open spend, which can be replaced once expired.
+//!
+//! When the subnet is removed, the remaining treasury goes to the subnet owner.
+
+use super::governance::SubnetProposalId;
+use super::*;
+use frame_support::traits::{fungible::*, tokens::Preservation};
+use safe_math::*;
+use sp_core::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// A spend of the subnet treasury awaiting the approval of the subnet's stakers.
+#[freeze_struct("847d17d3a192f93e")]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SubnetTreasurySpend<AccountId> {
+    /// The id of the spend, shared with subnet proposals.
+    pub id: SubnetProposalId,
+    /// The account paid when the spend passes.
+    pub dest: AccountId,
+    /// The TAO paid when the spend passes.
+    pub amount: TaoCurrency,
+    /// The last block at which votes are accepted.
+    pub end_block: u64,
+    /// The alpha stake that voted for the spend.
+    pub ayes: AlphaCurrency,
+    /// The alpha stake that voted against the spend.
+    pub nays: AlphaCurrency,
+}
+
+pub type SubnetTreasurySpendOf<T> = SubnetTreasurySpend<<T as frame_system::Config>::AccountId>;
+
+impl<T: Config> Pallet<T> {
+    /// The keyless treasury account of `netuid`.
+    pub fn get_subnet_treasury_account(netuid: NetUid) -> T::AccountId {
+        let entropy = (b"subtensor/subnet_treasury", netuid).using_encoded(blake2_256);
+        Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("infinite length input; no invalid inputs for type; qed")
+    }
+
+    pub fn get_subnet_treasury_share(netuid: NetUid) -> u16 {
+        SubnetTreasuryShare::<T>::get(netuid)
+    }
+    pub fn set_subnet_treasury_share(netuid: NetUid, share: u16) {
+        SubnetTreasuryShare::<T>::insert(netuid, share);
+        Self::deposit_event(Event::SubnetTreasuryShareSet(netuid, share));
+    }
+
+    /// Proposes to pay `amount` from the treasury of `netuid` to `dest` once stakers approve it.
+    ///
+    /// An expired spend of the subnet is replaced, discarding its votes.
+    pub fn do_propose_subnet_treasury_spend(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        dest: T::AccountId,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(!amount.is_zero(), Error::<T>::AmountTooLow);
+
+        // Ensure the subnet has no open spend.
+        let current_block = Self::get_current_block_as_u64();
+        if let Some(open) = SubnetTreasurySpends::<T>::get(netuid) {
+            ensure!(
+                current_block > open.end_block,
+                Error::<T>::SubnetTreasurySpendAlreadyOpen
+            );
+            let _ = SubnetTreasurySpendVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+
+        let id = NextSubnetProposalId::<T>::get();
+        NextSubnetProposalId::<T>::put(id.saturating_add(1));
+        SubnetTreasurySpends::<T>::insert(
+            netuid,
+            SubnetTreasurySpend {
+                id,
+                dest: dest.clone(),
+                amount,
+                end_block: current_block.saturating_add(SubnetProposalDuration::<T>::get()),
+                ayes: AlphaCurrency::ZERO,
+                nays: AlphaCurrency::ZERO,
+            },
+        );
+
+        Self::deposit_event(Event::SubnetTreasurySpendProposed {
+            netuid,
+            spend_id: id,
+            dest,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Votes for or against the open spend of `netuid` with the caller's alpha stake on the
+    /// subnet, paying the spend if the vote makes it pass.
+    pub fn do_vote_subnet_treasury_spend(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        spend_id: SubnetProposalId,
+        aye: bool,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        // Ensure the spend is open.
+        let mut spend = SubnetTreasurySpends::<T>::get(netuid)
+            .filter(|spend| spend.id == spend_id)
+            .ok_or(Error::<T>::SubnetTreasurySpendNotFound)?;
+        ensure!(
+            Self::get_current_block_as_u64() <= spend.end_block,
+            Error::<T>::SubnetProposalExpired
+        );
+
+        // Ensure the coldkey votes once, with some stake.
+        ensure!(
+            !SubnetTreasurySpendVotes::<T>::contains_key(netuid, &coldkey),
+            Error::<T>::AlreadyVotedOnSubnetProposal
+        );
+        let stake = Self::get_alpha_stake_for_coldkey_on_subnet(&coldkey, netuid);
+        ensure!(!stake.is_zero(), Error::<T>::NotEnoughStakeToVote);
+
+        if aye {
+            spend.ayes = spend.ayes.saturating_add(stake);
+        } else {
+            spend.nays = spend.nays.saturating_add(stake);
+        }
+        SubnetTreasurySpendVotes::<T>::insert(netuid, &coldkey, aye);
+
+        Self::deposit_event(Event::SubnetTreasurySpendVoted {
+            netuid,
+            spend_id,
+            coldkey,
+            aye,
+            stake,
+        });
+
+        if Self::subnet_vote_passes(netuid, spend.ayes, spend.nays) {
+            Self::execute_subnet_treasury_spend(netuid, spend);
+        } else {
+            SubnetTreasurySpends::<T>::insert(netuid, spend);
+        }
+
+        Ok(())
+    }
+
+    /// Routes the treasury share of the owner cut of `netuid` to the treasury in TAO, staking it
+    /// to `owner_hotkey` on behalf of the treasury and unstaking it. Returns what is left of the
+    /// owner cut.
+    pub fn fund_subnet_treasury(
+        netuid: NetUid,
+        owner_hotkey: &T::AccountId,
+        owner_cut: AlphaCurrency,
+    ) -> AlphaCurrency {
+        let share = Self::get_subnet_treasury_share(netuid);
+        if share == 0 {
+            return owner_cut;
+        }
+
+        let treasury_cut: AlphaCurrency = U96F32::saturating_from_num(owner_cut.to_u64())
+            .saturating_mul(U96F32::saturating_from_num(share))
+            .safe_div(U96F32::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u64>()
+            .min(owner_cut.to_u64())
+            .into();
+        if treasury_cut.is_zero() {
+            return owner_cut;
+        }
+
+        let treasury = Self::get_subnet_treasury_account(netuid);
+        Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            owner_hotkey,
+            &treasury,
+            netuid,
+            treasury_cut,
+        );
+        // Below the minimum stake, the share accrues as stake of the treasury until it can be
+        // unstaked.
+        let stake =
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(owner_hotkey, &treasury, netuid);
+        if Self::validate_remove_stake(&treasury, owner_hotkey, netuid, stake, stake, false).is_ok()
+        {
+            match Self::unstake_from_subnet(
+                owner_hotkey,
+                &treasury,
+                netuid,
+                stake,
+                T::SwapInterface::min_price().into(),
+                false,
+            ) {
+                Ok(tao) => Self::pay_unstaked_tao(&treasury, tao),
+                Err(err) => log::debug!("Couldn't fund the treasury of {netuid:?}: {err:?}"),
+            }
+        }
+        owner_cut.saturating_sub(treasury_cut)
+    }
+
+    /// Removes the treasury of a subnet being removed, paying its balance to `owner_coldkey`.
+    pub fn remove_subnet_treasury(netuid: NetUid, owner_coldkey: &T::AccountId) {
+        SubnetTreasuryShare::<T>::remove(netuid);
+        SubnetTreasurySpends::<T>::remove(netuid);
+        let _ = SubnetTreasurySpendVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        let treasury = Self::get_subnet_treasury_account(netuid);
+        let balance = <T as Config>::Currency::balance(&treasury);
+        if let Err(err) = <T as Config>::Currency::transfer(
+            &treasury,
+            owner_coldkey,
+            balance,
+            Preservation::Expendable,
+        ) {
+            log::debug!("Couldn't pay the treasury of {netuid:?} to its owner: {err:?}");
+        }
+    }
+
+    fn execute_subnet_treasury_spend(netuid: NetUid, spend: SubnetTreasurySpendOf<T>) {
+        SubnetTreasurySpends::<T>::remove(netuid);
+        let _ = SubnetTreasurySpendVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        let result = <T as Config>::Currency::transfer(
+            &Self::get_subnet_treasury_account(netuid),
+            &spend.dest,
+            spend.amount.into(),
+            Preservation::Expendable,
+        )
+        .map(|_| ());
+        log::debug!(
+            "SubnetTreasurySpent( netuid:{netuid:?} spend_id:{:?} result:{result:?} )",
+            spend.id
+        );
+
+        Self::deposit_event(Event::SubnetTreasurySpent {
+            netuid,
+            spend_id: spend.id,
+            dest: spend.dest,
+            amount: spend.amount,
+            result,
+        });
+    }
+}
@@ -25,6 +25,20 @@ impl<T: Config> Pallet<T> {
         Incentive::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
         Dividends::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
         Bonds::<T>::remove(netuid, neuron_uid); // Remove bonds for Validator.
+        if Self::get_bonds_reset_on_deregistration(netuid) {
+            Self::remove_bonds_on_uid(netuid, neuron_uid); // Remove bonds on the Miner.
+        }
+    }
+
+    /// Removes the bonds the validators of the subnet hold on the neuron under `uid`.
+    pub fn remove_bonds_on_uid(netuid: NetUid, uid: u16) {
+        let rows: Vec<(u16, Vec<(u16, u16)>)> = Bonds::<T>::iter_prefix(netuid)
+            .filter(|(_, bonds)| bonds.iter().any(|(j, _)| *j == uid))
+            .collect();
+        for (i, mut bonds) in rows {
+            bonds.retain(|(j, _)| *j != uid);
+            Bonds::<T>::insert(netuid, i, bonds);
+        }
     }
 
     /// Replace the neuron under this uid.
@@ -38,12 +52,13 @@ impl<T: Config> Pallet<T> {
             "replace_neuron( netuid: {netuid:?} | uid_to_replace: {uid_to_replace:?} | new_hotkey: {new_hotkey:?} ) "
         );
 
-        // 1. Get the old hotkey under this position.
-        let old_hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_replace);
+        // 1. Get the old hotkey under this position, if the uid is not vacant.
+        let maybe_old_hotkey: Option<T::AccountId> =
+            Keys::<T>::try_get(netuid, uid_to_replace).ok();
 
         // Do not replace owner hotkey from `SubnetOwnerHotkey`
         if let Ok(sn_owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
-            if sn_owner_hotkey == old_hotkey.clone() {
+            if maybe_old_hotkey.as_ref() == Some(&sn_owner_hotkey) {
                 log::warn!(
                     "replace_neuron: Skipped replacement because neuron is the subnet owner hotkey. \
                     netuid: {netuid:?}, uid_to_replace: {uid_to_replace:?}, new_hotkey: {new_hotkey:?}, owner_hotkey: {sn_owner_hotkey:?}"
@@ -53,27 +68,73 @@ impl<T: Config> Pallet<T> {
         }
 
         // 2. Remove previous set memberships.
-        Uids::<T>::remove(netuid, old_hotkey.clone());
-        AssociatedEvmAddress::<T>::remove(netuid, uid_to_replace);
-        IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
-        #[allow(unknown_lints)]
-        Keys::<T>::remove(netuid, uid_to_replace);
+        if let Some(old_hotkey) = maybe_old_hotkey {
+            Self::remove_neuron_memberships(netuid, uid_to_replace, &old_hotkey);
+        }
+        RegistrationBurnPaid::<T>::remove(netuid, uid_to_replace);
 
         // 3. Create new set memberships.
         Self::set_active_for_uid(netuid, uid_to_replace, true); // Set to active by default.
         Keys::<T>::insert(netuid, uid_to_replace, new_hotkey.clone()); // Make hotkey - uid association.
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
+        Self::note_multi_block_epoch_registration(netuid, uid_to_replace); // Keep it out of a running epoch.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
 
-        // 4. Clear neuron certificates
-        NeuronCertificates::<T>::remove(netuid, old_hotkey.clone());
-
-        // 5. Reset new neuron's values.
+        // 4. Reset new neuron's values.
         Self::clear_neuron(netuid, uid_to_replace);
+    }
+
+    /// Removes the association between `hotkey` and its `uid`, along with the hotkey's
+    /// certificate and axon on the subnet, and withholds its unvested miner emission.
+    pub fn remove_neuron_memberships(netuid: NetUid, uid: u16, hotkey: &T::AccountId) {
+        Self::withhold_vesting_emission(netuid, hotkey);
+        Uids::<T>::remove(netuid, hotkey);
+        AssociatedEvmAddress::<T>::remove(netuid, uid);
+        IsNetworkMember::<T>::remove(hotkey, netuid);
+        #[allow(unknown_lints)]
+        Keys::<T>::remove(netuid, uid);
+
+        // Clear neuron certificates
+        NeuronCertificates::<T>::remove(netuid, hotkey);
+
+        // Reset axon info for the uid.
+        Axons::<T>::remove(netuid, hotkey);
+        AxonsV2::<T>::remove(netuid, hotkey);
+    }
+
+    /// Frees `uid` so that the next registration on the subnet fills it.
+    pub fn vacate_neuron(netuid: NetUid, uid: u16, hotkey: &T::AccountId) {
+        Self::remove_neuron_memberships(netuid, uid, hotkey);
+        Self::clear_neuron(netuid, uid);
+        Self::set_active_for_uid(netuid, uid, false);
+        ValidatorPermit::<T>::mutate(netuid, |v| Self::set_element_at(v, usize::from(uid), false));
+        Weights::<T>::remove(netuid, uid);
+        RegistrationBurnPaid::<T>::remove(netuid, uid);
+        VacantUids::<T>::mutate(netuid, |vacant| {
+            if let Err(index) = vacant.binary_search(&uid) {
+                vacant.insert(index, uid);
+            }
+        });
+    }
+
+    /// Takes the lowest vacant uid of the subnet, if any.
+    pub fn take_vacant_uid(netuid: NetUid) -> Option<u16> {
+        VacantUids::<T>::mutate_exists(netuid, |maybe_vacant| {
+            let vacant = maybe_vacant.as_mut()?;
+            let uid = (!vacant.is_empty()).then(|| vacant.remove(0));
+            if vacant.is_empty() {
+                *maybe_vacant = None;
+            }
+            uid
+        })
+    }
 
-        // 5a. reset axon info for the new uid.
-        Axons::<T>::remove(netuid, old_hotkey);
+    /// The uids in `uids` that hold a neuron, leaving out the vacant ones.
+    pub fn get_occupied_uids(netuid: NetUid, uids: sp_std::ops::Range<u16>) -> Vec<u16> {
+        let vacant = VacantUids::<T>::get(netuid);
+        uids.filter(|uid| vacant.binary_search(uid).is_err())
+            .collect()
     }
 
     /// Appends the uid to the network.
@@ -104,9 +165,98 @@ impl<T: Config> Pallet<T> {
         Keys::<T>::insert(netuid, next_uid, new_hotkey.clone()); // Make hotkey - uid association.
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
+        Self::note_multi_block_epoch_registration(netuid, next_uid); // Keep it out of a running epoch.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
     }
 
+    /// Grows the max UIDs of a subnet to `max_allowed_uids`.
+    ///
+    /// The per-uid vectors are resized to the filled slots, so the new slots are appended at the
+    /// right position, and stale weights and bonds rows of the new slots are removed. Weights
+    /// that still point at a new slot are masked by the epoch until they are set after the
+    /// registration in that slot. Registrations fill the new slots before any neuron is pruned,
+    /// so neurons under immunity keep their slots.
+    ///
+    /// # Errors
+    /// - `BadOrigin`: If the caller is neither root nor the subnet owner.
+    /// - `Error::SubnetNotExists`: If the specified subnet does not exist.
+    /// - `Error::CannotGrowRootSubnetUids`: If the subnet is the root subnet.
+    /// - `Error::MaxAllowedUidsNotIncreased`: If the new max is not above the current max.
+    /// - `Error::MaxAllowedUidsGrowthTooLarge`: If the new max is above [`DefaultMaxAllowedUidsLimit`]
+    ///   or grows the subnet by more than [`DefaultMaxAllowedUidsGrowth`].
+    /// - `Error::TxRateLimitExceeded`: If the function is called more frequently than the allowed rate limit.
+    ///
+    /// # Rate Limiting
+    /// This function is rate-limited to one call per subnet per [`DefaultGrowMaxAllowedUidsRateLimit`] blocks.
+    pub fn do_grow_max_allowed_uids(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        max_allowed_uids: u16,
+    ) -> DispatchResult {
+        // Ensure the caller is either root or subnet owner.
+        let maybe_owner = ensure_signed_or_root(origin.clone())?;
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+        // Ensure that the subnet exists and is not the root subnet.
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(!netuid.is_root(), Error::<T>::CannotGrowRootSubnetUids);
+
+        // Ensure the subnet grows within the limits.
+        let current_max: u16 = Self::get_max_allowed_uids(netuid);
+        ensure!(
+            max_allowed_uids > current_max,
+            Error::<T>::MaxAllowedUidsNotIncreased
+        );
+        ensure!(
+            max_allowed_uids <= DefaultMaxAllowedUidsLimit::<T>::get()
+                && max_allowed_uids.saturating_sub(current_max)
+                    <= DefaultMaxAllowedUidsGrowth::<T>::get(),
+            Error::<T>::MaxAllowedUidsGrowthTooLarge
+        );
+
+        // Rate limit changes made by the subnet owner.
+        if let Some(owner) = maybe_owner {
+            ensure!(
+                Self::passes_rate_limit_on_subnet(
+                    &TransactionType::GrowMaxAllowedUids,
+                    &owner, // ignored
+                    netuid, // Specific to a subnet.
+                ),
+                Error::<T>::TxRateLimitExceeded
+            );
+            Self::set_last_transaction_block_on_subnet(
+                &owner,
+                netuid,
+                &TransactionType::GrowMaxAllowedUids,
+                Self::get_current_block_as_u64(),
+            );
+        }
+
+        // Resize the per-uid vectors to the filled slots.
+        let n: usize = Self::get_subnetwork_n(netuid) as usize;
+        Rank::<T>::mutate(netuid, |v| v.resize(n, 0));
+        Trust::<T>::mutate(netuid, |v| v.resize(n, 0));
+        Active::<T>::mutate(netuid, |v| v.resize(n, true));
+        Emission::<T>::mutate(netuid, |v| v.resize(n, 0.into()));
+        Consensus::<T>::mutate(netuid, |v| v.resize(n, 0));
+        Incentive::<T>::mutate(netuid, |v| v.resize(n, 0));
+        Dividends::<T>::mutate(netuid, |v| v.resize(n, 0));
+        LastUpdate::<T>::mutate(netuid, |v| v.resize(n, 0));
+        PruningScores::<T>::mutate(netuid, |v| v.resize(n, 0));
+        ValidatorTrust::<T>::mutate(netuid, |v| v.resize(n, 0));
+        ValidatorPermit::<T>::mutate(netuid, |v| v.resize(n, false));
+
+        // Remove stale weights and bonds rows of the new slots.
+        for uid in current_max..max_allowed_uids {
+            Weights::<T>::remove(netuid, uid);
+            Bonds::<T>::remove(netuid, uid);
+        }
+
+        Self::set_max_allowed_uids(netuid, max_allowed_uids);
+
+        Ok(())
+    }
+
     /// Returns true if the uid is set on the network.
     ///
     pub fn is_uid_exist_on_network(netuid: NetUid, uid: u16) -> bool {
@@ -7,9 +7,15 @@ use sp_runtime::{
     BoundedVec,
     traits::{BlakeTwo256, Hash},
 };
-use sp_std::{collections::vec_deque::VecDeque, vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, vec_deque::VecDeque},
+    vec,
+};
 use subtensor_runtime_common::NetUid;
 
+/// The domain the payload of weight commit hashes is prefixed with.
+pub const WEIGHTS_COMMIT_DOMAIN: &[u8] = b"subtensor/commit_weights/v1";
+
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for committing weight hashes.
     ///
@@ -36,6 +42,9 @@ impl<T: Config> Pallet<T> {
     /// * `TooManyUnrevealedCommits`:
     ///   - Raised if the hotkey has reached the maximum number of unrevealed commits.
     ///
+    /// * `DuplicateWeightCommit`:
+    ///   - Raised if the hotkey has already committed the hash on the network and not revealed it.
+    ///
     /// # Events:
     /// * `WeightsCommitted`:
     ///   - Emitted upon successfully storing the weight hash.
@@ -45,7 +54,7 @@ impl<T: Config> Pallet<T> {
         commit_hash: H256,
     ) -> DispatchResult {
         // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        let who = Self::ensure_weights_origin(origin)?;
 
         log::debug!("do_commit_weights(hotkey: {who:?}, netuid: {netuid:?})");
 
@@ -54,6 +63,7 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
 
         // 3. Ensure the hotkey is registered on the network.
         ensure!(
@@ -89,6 +99,12 @@ impl<T: Config> Pallet<T> {
             // 8. Verify that the number of unrevealed commits is within the allowed limit.
             ensure!(commits.len() < 10, Error::<T>::TooManyUnrevealedCommits);
 
+            // 8.1. Ensure the hash is not already committed and unrevealed.
+            ensure!(
+                !commits.iter().any(|(hash, _, _, _)| *hash == commit_hash),
+                Error::<T>::DuplicateWeightCommit
+            );
+
             // 9. Append the new commit with calculated reveal blocks.
             commits.push_back((
                 commit_hash,
@@ -143,7 +159,7 @@ impl<T: Config> Pallet<T> {
         commit_hashes: Vec<H256>,
     ) -> dispatch::DispatchResult {
         // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin.clone())?;
+        let hotkey = Self::ensure_weights_origin(origin.clone())?;
         log::debug!(
             "do_batch_commit_weights( origin:{hotkey:?}, netuids:{netuids:?}, hashes:{commit_hashes:?} )"
         );
@@ -234,7 +250,7 @@ impl<T: Config> Pallet<T> {
         commit_reveal_version: u16,
     ) -> DispatchResult {
         // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        let who = Self::ensure_weights_origin(origin)?;
 
         log::debug!("do_commit_v3_weights(hotkey: {who:?}, netuid: {netuid:?})");
 
@@ -243,6 +259,7 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
 
         // 3. Ensure correct client version
         ensure!(
@@ -348,7 +365,7 @@ impl<T: Config> Pallet<T> {
         version_key: u64,
     ) -> DispatchResult {
         // --- 1. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        let who = Self::ensure_weights_origin(origin.clone())?;
 
         log::debug!("do_reveal_weights( hotkey:{who:?} netuid:{netuid:?})");
 
@@ -377,19 +394,16 @@ impl<T: Config> Pallet<T> {
             }
 
             // --- 5. Hash the provided data.
-            let provided_hash: H256 = BlakeTwo256::hash_of(&(
-                who.clone(),
-                netuid,
-                uids.clone(),
-                values.clone(),
-                salt.clone(),
-                version_key,
-            ));
+            let provided_hashes =
+                Self::get_reveal_hashes(&who, netuid, &uids, &values, &salt, version_key);
+            let is_expired = expired_hashes
+                .iter()
+                .any(|hash| provided_hashes.contains(hash));
 
             // --- 6. After removing expired commits, check if any commits are left.
             if commits.is_empty() {
                 // Check if provided_hash matches any expired commits
-                if expired_hashes.contains(&provided_hash) {
+                if is_expired {
                     return Err(Error::<T>::ExpiredWeightCommit.into());
                 } else {
                     return Err(Error::<T>::NoWeightsCommitFound.into());
@@ -399,12 +413,13 @@ impl<T: Config> Pallet<T> {
             // --- 7. Search for the provided_hash in the non-expired commits.
             if let Some(position) = commits
                 .iter()
-                .position(|(hash, _, _, _)| *hash == provided_hash)
+                .position(|(hash, _, _, _)| provided_hashes.contains(hash))
             {
                 // --- 8. Get the commit block for the commit being revealed.
-                let (_, commit_block, _, _) = commits
+                let (provided_hash, commit_block, _, _) = commits
                     .get(position)
                     .ok_or(Error::<T>::NoWeightsCommitFound)?;
+                let provided_hash = *provided_hash;
 
                 // --- 9. Ensure the commit is ready to be revealed in the current block range.
                 ensure!(
@@ -432,7 +447,7 @@ impl<T: Config> Pallet<T> {
                 Ok(())
             } else {
                 // --- 15. The provided_hash does not match any non-expired commits.
-                if expired_hashes.contains(&provided_hash) {
+                if is_expired {
                     Err(Error::<T>::ExpiredWeightCommit.into())
                 } else {
                     Err(Error::<T>::InvalidRevealCommitHashNotMatch.into())
@@ -498,7 +513,7 @@ impl<T: Config> Pallet<T> {
         );
 
         // --- 2. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        let who = Self::ensure_weights_origin(origin.clone())?;
 
         log::debug!("do_batch_reveal_weights( hotkey:{who:?} netuid:{netuid:?})");
 
@@ -536,15 +551,16 @@ impl<T: Config> Pallet<T> {
                 .zip(values_list)
                 .zip(salts_list.into_iter().zip(version_keys))
             {
-                // --- 6a. Hash the provided data.
-                let provided_hash: H256 = BlakeTwo256::hash_of(&(
-                    who.clone(),
-                    netuid,
-                    uids.clone(),
-                    values.clone(),
-                    salt.clone(),
-                    version_key,
-                ));
+                // --- 6a. Hash the provided data, settling on the hash that was committed.
+                let [commit_hash, legacy_hash] =
+                    Self::get_reveal_hashes(&who, netuid, &uids, &values, &salt, version_key);
+                let provided_hash = if commits.iter().any(|(hash, _, _, _)| *hash == legacy_hash)
+                    || expired_hashes.contains(&legacy_hash)
+                {
+                    legacy_hash
+                } else {
+                    commit_hash
+                };
                 provided_hashes.push(provided_hash);
                 reveals.push((uids, values, version_key, provided_hash));
             }
@@ -680,11 +696,22 @@ impl<T: Config> Pallet<T> {
         version_key: u64,
     ) -> dispatch::DispatchResult {
         // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin)?;
+        let hotkey = Self::ensure_weights_origin(origin)?;
         log::debug!(
             "do_set_weights( origin:{hotkey:?} netuid:{netuid:?}, uids:{uids:?}, values:{values:?})"
         );
 
+        Self::set_weights_for_hotkey(hotkey, netuid, uids, values, version_key)
+    }
+
+    /// Sets the weights of `hotkey` on `netuid` once its origin has been checked.
+    pub fn set_weights_for_hotkey(
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
         // --- Check that the netuid is not the root network.
         ensure!(!netuid.is_root(), Error::<T>::CanNotSetRootNetworkWeights);
 
@@ -699,6 +726,7 @@ impl<T: Config> Pallet<T> {
             Self::if_subnet_exist(netuid),
             Error::<T>::SubNetworkDoesNotExist
         );
+        Self::ensure_subnet_not_in_maintenance(netuid)?;
 
         // --- 4. Check to see if the number of uids is within the max allowed uids for this network.
         ensure!(
@@ -785,6 +813,75 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic set_weights_delta.
+    ///
+    /// Applies signed changes to the current weights of the caller, as stored on chain, and sets
+    /// the result with the same checks as set_weights. A uid whose weight drops to zero is removed.
+    ///
+    /// # Args:
+    ///  * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///    - The signature of the calling hotkey.
+    ///
+    ///  * 'netuid' (u16):
+    ///    - The u16 network identifier.
+    ///
+    ///  * 'deltas' ( Vec<(u16, i32)> ):
+    ///    - The changes of the weights, as (uid, change) tuples.
+    ///
+    ///  * 'version_key' ( u64 ):
+    ///    - The network version key.
+    ///
+    /// # Raises:
+    ///  * 'DuplicateUids':
+    ///    - Attempting to change the weight of a uid twice.
+    ///
+    ///  * Any error of set_weights for the resulting weights.
+    ///
+    pub fn do_set_weights_delta(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        deltas: Vec<(u16, i32)>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
+        let hotkey = Self::ensure_weights_origin(origin)?;
+        log::debug!(
+            "do_set_weights_delta( origin:{hotkey:?} netuid:{netuid:?}, deltas:{deltas:?})"
+        );
+
+        // --- 2. Ensure each uid is changed once.
+        let delta_uids: Vec<u16> = deltas.iter().map(|(uid, _)| *uid).collect();
+        ensure!(
+            !Self::has_duplicate_uids(&delta_uids),
+            Error::<T>::DuplicateUids
+        );
+
+        // --- 3. Merge the changes into the current weights.
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        let mut weights: BTreeMap<u16, i64> = Weights::<T>::get(netuid, neuron_uid)
+            .into_iter()
+            .map(|(uid, value)| (uid, i64::from(value)))
+            .collect();
+        for (uid, delta) in deltas {
+            let weight = weights.entry(uid).or_insert(0);
+            *weight = weight
+                .saturating_add(i64::from(delta))
+                .clamp(0, i64::from(u16::MAX));
+        }
+        let (uids, values): (Vec<u16>, Vec<u16>) = weights
+            .into_iter()
+            .filter_map(|(uid, value)| {
+                u16::try_from(value)
+                    .ok()
+                    .filter(|value| *value > 0)
+                    .map(|value| (uid, value))
+            })
+            .unzip();
+
+        // --- 4. Set the merged weights.
+        Self::set_weights_for_hotkey(hotkey, netuid, uids, values, version_key)
+    }
+
     /// ---- The implementation for the extrinsic batch_set_weights.
     ///
     /// This call runs a batch of set weights calls, continuing on errors.
@@ -810,6 +907,8 @@ impl<T: Config> Pallet<T> {
     ///    - Emitted when at least on of the weight sets has an error.
     ///  * BatchWeightItemFailed;
     ///    - Emitted for each error within the batch.
+    ///  * BatchWeightItemFailedOnSubnet;
+    ///    - Emitted for each error within the batch, with the netuid that failed.
     ///  * BatchWeightsCompleted;
     ///    - Emitted when the batch of weights is completed.
     ///  * InputLengthsUnequal;
@@ -822,7 +921,7 @@ impl<T: Config> Pallet<T> {
         version_keys: Vec<Compact<u64>>,
     ) -> dispatch::DispatchResult {
         // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin.clone())?;
+        let hotkey = Self::ensure_weights_origin(origin.clone())?;
         log::debug!(
             "do_batch_set_weights( origin:{hotkey:?} netuids:{netuids:?}, weights:{weights:?}"
         );
@@ -858,13 +957,18 @@ impl<T: Config> Pallet<T> {
             .collect();
 
         let mut completed_with_errors: bool = false;
-        for result in results {
+        for (netuid, result) in netuids.iter().zip(results) {
             if let Some(err) = result.err() {
                 if !completed_with_errors {
                     Self::deposit_event(Event::BatchCompletedWithErrors());
                     completed_with_errors = true;
                 }
                 Self::deposit_event(Event::BatchWeightItemFailed(err));
+                Self::deposit_event(Event::BatchWeightItemFailedOnSubnet(
+                    hotkey.clone(),
+                    (*netuid).into(),
+                    err,
+                ));
             }
         }
 
@@ -882,8 +986,13 @@ impl<T: Config> Pallet<T> {
 
     /// Returns true if version_key is up-to-date.
     ///
+    /// While a new version key waits out its grace period, keys meeting either the current or the
+    /// new one are accepted.
     pub fn check_version_key(netuid: NetUid, version_key: u64) -> bool {
-        let network_version_key: u64 = WeightsVersionKey::<T>::get(netuid);
+        let mut network_version_key: u64 = WeightsVersionKey::<T>::get(netuid);
+        if let Some((pending_version_key, _)) = PendingWeightsVersionKey::<T>::get(netuid) {
+            network_version_key = network_version_key.min(pending_version_key);
+        }
         log::debug!(
             "check_version_key( network_version_key:{network_version_key:?}, version_key:{version_key:?} )"
         );
@@ -1042,7 +1151,11 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn is_commit_expired(netuid: NetUid, commit_block: u64) -> bool {
-        let current_block: u64 = Self::get_current_block_as_u64();
+        Self::is_commit_expired_at(netuid, commit_block, Self::get_current_block_as_u64())
+    }
+
+    /// Whether a commit made at `commit_block` has expired at `current_block`.
+    pub fn is_commit_expired_at(netuid: NetUid, commit_block: u64, current_block: u64) -> bool {
         let current_epoch: u64 = Self::get_epoch_index(netuid, current_block);
         let commit_epoch: u64 = Self::get_epoch_index(netuid, commit_block);
         let reveal_period: u64 = Self::get_reveal_period(netuid);
@@ -1050,6 +1163,50 @@ impl<T: Config> Pallet<T> {
         current_epoch > commit_epoch.saturating_add(reveal_period)
     }
 
+    /// Returns the hash a hotkey commits to for the weights it reveals later.
+    ///
+    /// The payload is prefixed with [`WEIGHTS_COMMIT_DOMAIN`] and the genesis hash and includes
+    /// the hotkey and netuid, so a commit can only be revealed on this chain, on its subnet and
+    /// by its hotkey.
+    pub fn get_commit_hash(
+        who: &T::AccountId,
+        netuid: NetUid,
+        uids: &[u16],
+        values: &[u16],
+        salt: &[u16],
+        version_key: u64,
+    ) -> H256 {
+        BlakeTwo256::hash_of(&(
+            WEIGHTS_COMMIT_DOMAIN,
+            Self::get_block_hash_from_u64(0),
+            who,
+            netuid,
+            uids,
+            values,
+            salt,
+            version_key,
+        ))
+    }
+
+    /// Returns the hashes a reveal can match: the domain-separated [`Self::get_commit_hash`] and
+    /// the legacy hash without the domain, which existing clients still commit to.
+    ///
+    /// TODO: stop accepting the legacy hash after 2027-04-01, once clients commit to
+    /// [`Self::get_commit_hash`], and return the domain-separated hash only.
+    fn get_reveal_hashes(
+        who: &T::AccountId,
+        netuid: NetUid,
+        uids: &[u16],
+        values: &[u16],
+        salt: &[u16],
+        version_key: u64,
+    ) -> [H256; 2] {
+        [
+            Self::get_commit_hash(who, netuid, uids, values, salt, version_key),
+            BlakeTwo256::hash_of(&(who, netuid, uids, values, salt, version_key)),
+        ]
+    }
+
     pub fn get_reveal_blocks(netuid: NetUid, commit_block: u64) -> (u64, u64) {
         let reveal_period: u64 = Self::get_reveal_period(netuid);
         let tempo: u64 = Self::get_tempo(netuid) as u64;
@@ -0,0 +1,81 @@
+//! This file defines weights keys, which set weights on behalf of a hotkey.
+//!
+//! The coldkey owning a hotkey can authorize a separate weights key with `set_weights_key`. The
+//! weights key can then set, commit and reveal weights exactly as the hotkey would, while the hotkey
+//! itself stays in cold storage. It cannot do anything else on behalf of the hotkey: every other call
+//! signed by the weights key acts on the weights key's own account.
+//!
+//! A hotkey has at most one weights key, and a weights key serves a single hotkey.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Authorizes `weights_key` to set weights for `hotkey`, replacing its previous weights key, or
+    /// revokes the weights key of `hotkey` when `None`.
+    pub fn do_set_weights_key(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        weights_key: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        if let Some(weights_key) = &weights_key {
+            // A weights key cannot be a hotkey, nor serve another hotkey.
+            ensure!(
+                *weights_key != hotkey && !Self::hotkey_account_exists(weights_key),
+                Error::<T>::InvalidWeightsKey
+            );
+            ensure!(
+                WeightsKeyHotkey::<T>::get(weights_key).is_none_or(|owner| owner == hotkey),
+                Error::<T>::WeightsKeyInUse
+            );
+        }
+
+        if let Some(previous) = WeightsKeys::<T>::take(&hotkey) {
+            WeightsKeyHotkey::<T>::remove(previous);
+        }
+        if let Some(weights_key) = &weights_key {
+            WeightsKeys::<T>::insert(&hotkey, weights_key);
+            WeightsKeyHotkey::<T>::insert(weights_key, &hotkey);
+        }
+
+        Self::deposit_event(Event::WeightsKeySet {
+            coldkey,
+            hotkey,
+            weights_key,
+        });
+
+        Ok(())
+    }
+
+    /// Ensures `origin` is signed and returns the hotkey it sets weights for: the hotkey the signer
+    /// is the weights key of, or the signer itself. Multisig hotkeys only set weights through the
+    /// approval of their operators.
+    pub fn ensure_weights_origin(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        let hotkey = Self::get_weights_hotkey(who);
+        ensure!(
+            !HotkeyMultisigs::<T>::contains_key(&hotkey),
+            Error::<T>::WeightsRequireMultisig
+        );
+        Ok(hotkey)
+    }
+
+    /// The hotkey `who` sets weights for.
+    pub fn get_weights_hotkey(who: T::AccountId) -> T::AccountId {
+        WeightsKeyHotkey::<T>::get(&who).unwrap_or(who)
+    }
+
+    /// Moves the weights key of `old_hotkey` to `new_hotkey`.
+    pub fn swap_weights_key(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) {
+        if let Some(weights_key) = WeightsKeys::<T>::take(old_hotkey) {
+            WeightsKeys::<T>::insert(new_hotkey, &weights_key);
+            WeightsKeyHotkey::<T>::insert(weights_key, new_hotkey);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! This file defines multisig hotkeys, whose weights are approved by several operators.
+//!
+//! The coldkey owning a hotkey can require a threshold of operators to approve its weights with
+//! `set_hotkey_multisig`, so a validator team does not depend on a single operator holding the
+//! hotkey. Each operator approves a submission with `approve_weights`, and the weights are set once
+//! `threshold` operators have approved the same netuid, uids, values and version key. Approvals are
+//! aggregated on-chain, so operators only ever sign with their own keys.
+//!
+//! While a multisig is set, neither the hotkey nor its weights key can set, commit or reveal
+//! weights on their own. Setting weights clears all pending approvals of the hotkey.
+
+use super::*;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use subtensor_runtime_common::NetUid;
+
+/// The most operators a multisig hotkey can have.
+pub const MAX_HOTKEY_MULTISIG_OPERATORS: usize = 16;
+
+/// The operators approving the weights of a hotkey.
+#[freeze_struct("a2f9e8402c4e3ccc")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HotkeyMultisig<AccountId> {
+    /// The operators allowed to approve weights.
+    pub operators: Vec<AccountId>,
+    /// The number of approvals required to set weights.
+    pub threshold: u16,
+}
+
+pub type HotkeyMultisigOf<T> = HotkeyMultisig<<T as frame_system::Config>::AccountId>;
+
+impl<T: Config> Pallet<T> {
+    /// Requires `threshold` of `operators` to approve the weights of `hotkey`, or removes the
+    /// multisig of `hotkey` when `operators` is empty.
+    pub fn do_set_hotkey_multisig(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        operators: Vec<T::AccountId>,
+        threshold: u16,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+
+        if operators.is_empty() {
+            HotkeyMultisigs::<T>::remove(&hotkey);
+        } else {
+            let mut unique = operators.clone();
+            unique.sort();
+            unique.dedup();
+            ensure!(
+                unique.len() == operators.len()
+                    && operators.len() <= MAX_HOTKEY_MULTISIG_OPERATORS
+                    && threshold > 0
+                    && usize::from(threshold) <= operators.len(),
+                Error::<T>::InvalidHotkeyMultisig
+            );
+            HotkeyMultisigs::<T>::insert(
+                &hotkey,
+                HotkeyMultisig {
+                    operators: operators.clone(),
+                    threshold,
+                },
+            );
+        }
+        let _ = WeightApprovals::<T>::clear_prefix(&hotkey, u32::MAX, None);
+
+        Self::deposit_event(Event::HotkeyMultisigSet {
+            coldkey,
+            hotkey,
+            operators,
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    /// Approves the weights of multisig `hotkey` on `netuid`, setting them once the threshold of
+    /// operators have approved them.
+    pub fn do_approve_weights(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> DispatchResult {
+        let operator = ensure_signed(origin)?;
+
+        let multisig =
+            HotkeyMultisigs::<T>::get(&hotkey).ok_or(Error::<T>::NotHotkeyMultisigOperator)?;
+        ensure!(
+            multisig.operators.contains(&operator),
+            Error::<T>::NotHotkeyMultisigOperator
+        );
+
+        let proposal = Self::get_weights_proposal_hash(netuid, &uids, &values, version_key);
+        let mut approvals = WeightApprovals::<T>::get(&hotkey, proposal);
+        if !approvals.contains(&operator) {
+            approvals.push(operator.clone());
+        }
+        let approval_count = u16::try_from(approvals.len()).unwrap_or(u16::MAX);
+
+        Self::deposit_event(Event::WeightsApproved {
+            hotkey: hotkey.clone(),
+            operator,
+            netuid,
+            approvals: approval_count,
+        });
+
+        if approval_count < multisig.threshold {
+            WeightApprovals::<T>::insert(&hotkey, proposal, approvals);
+            return Ok(());
+        }
+
+        let _ = WeightApprovals::<T>::clear_prefix(&hotkey, u32::MAX, None);
+        Self::set_weights_for_hotkey(hotkey, netuid, uids, values, version_key)
+    }
+
+    /// The hash identifying a weights submission approved by operators.
+    pub fn get_weights_proposal_hash(
+        netuid: NetUid,
+        uids: &[u16],
+        values: &[u16],
+        version_key: u64,
+    ) -> H256 {
+        BlakeTwo256::hash_of(&(netuid, uids, values, version_key))
+    }
+
+    /// Moves the multisig of `old_hotkey` to `new_hotkey`, dropping its pending approvals.
+    pub fn swap_hotkey_multisig(old_hotkey: &T::AccountId, new_hotkey: &T::AccountId) {
+        if let Some(multisig) = HotkeyMultisigs::<T>::take(old_hotkey) {
+            HotkeyMultisigs::<T>::insert(new_hotkey, multisig);
+        }
+        let _ = WeightApprovals::<T>::clear_prefix(old_hotkey, u32::MAX, None);
+    }
+}
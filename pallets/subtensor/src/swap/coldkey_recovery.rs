@@ -0,0 +1,207 @@
+//! This file defines the social recovery of coldkeys.
+//!
+//! A coldkey can name up to `MAX_COLDKEY_RECOVERY_FRIENDS` friends with `set_coldkey_recovery`,
+//! along with how many of them must vouch for a recovery and a delay. If the coldkey is lost, its
+//! friends vouch with `vouch_coldkey_recovery` for the new coldkey that should replace it. Once
+//! enough friends vouched and the delay has passed since the first vouch, the new coldkey claims
+//! the recovery with `claim_coldkey_recovery`.
+//!
+//! Claiming performs a full coldkey swap in one extrinsic: hotkey ownership, stake, subnet
+//! ownership, identity, balance and every other coldkey-keyed state move to the new coldkey, or
+//! nothing does. Unlike a scheduled swap, no swap cost is burned, since the lost coldkey may not be
+//! able to sign for it.
+//!
+//! During the delay, the coldkey, if it is not actually lost, can cancel the recovery with
+//! `cancel_coldkey_recovery`.
+
+use super::*;
+use frame_support::weights::Weight;
+use sp_core::Get;
+
+/// The most friends a coldkey can name for its recovery.
+pub const MAX_COLDKEY_RECOVERY_FRIENDS: usize = 9;
+
+/// The friends able to recover a coldkey.
+#[freeze_struct("e4de29b7e7605457")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ColdkeyRecoveryConfig<AccountId> {
+    /// The friends who can vouch for a recovery.
+    pub friends: Vec<AccountId>,
+    /// The number of friends who must vouch for a recovery.
+    pub threshold: u16,
+    /// The blocks between the first vouch and the claim of a recovery.
+    pub delay: u64,
+}
+
+/// A recovery of a coldkey in progress.
+#[freeze_struct("cbc7dc73b4d589e3")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ColdkeyRecovery<AccountId> {
+    /// The block of the first vouch.
+    pub started: u64,
+    /// The friends who vouched so far.
+    pub vouches: Vec<AccountId>,
+}
+
+pub type ColdkeyRecoveryConfigOf<T> = ColdkeyRecoveryConfig<<T as frame_system::Config>::AccountId>;
+pub type ColdkeyRecoveryOf<T> = ColdkeyRecovery<<T as frame_system::Config>::AccountId>;
+
+impl<T: Config> Pallet<T> {
+    /// Sets the friends able to recover the caller, or disables its recovery and cancels the
+    /// recoveries in progress when `friends` is empty.
+    pub fn do_set_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        friends: Vec<T::AccountId>,
+        threshold: u16,
+        delay: u64,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        if friends.is_empty() {
+            ColdkeyRecoveryConfigs::<T>::remove(&coldkey);
+            let _ = ActiveColdkeyRecoveries::<T>::clear_prefix(&coldkey, u32::MAX, None);
+        } else {
+            let mut unique = friends.clone();
+            unique.sort();
+            unique.dedup();
+            ensure!(
+                friends.len() <= MAX_COLDKEY_RECOVERY_FRIENDS
+                    && unique.len() == friends.len()
+                    && !friends.contains(&coldkey)
+                    && threshold > 0
+                    && usize::from(threshold) <= friends.len(),
+                Error::<T>::InvalidColdkeyRecovery
+            );
+            ColdkeyRecoveryConfigs::<T>::insert(
+                &coldkey,
+                ColdkeyRecoveryConfig {
+                    friends: friends.clone(),
+                    threshold,
+                    delay,
+                },
+            );
+        }
+
+        Self::deposit_event(Event::ColdkeyRecoverySet {
+            coldkey,
+            friends,
+            threshold,
+            delay,
+        });
+
+        Ok(())
+    }
+
+    /// Vouches, as a friend of `lost_coldkey`, for recovering it to `new_coldkey`.
+    pub fn do_vouch_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        lost_coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let friend = ensure_signed(origin)?;
+
+        let config = ColdkeyRecoveryConfigs::<T>::get(&lost_coldkey)
+            .ok_or(Error::<T>::ColdkeyRecoveryNotSet)?;
+        ensure!(
+            config.friends.contains(&friend),
+            Error::<T>::NotColdkeyRecoveryFriend
+        );
+        ensure!(
+            lost_coldkey != new_coldkey,
+            Error::<T>::InvalidColdkeyRecovery
+        );
+
+        let mut recovery = ActiveColdkeyRecoveries::<T>::get(&lost_coldkey, &new_coldkey)
+            .unwrap_or_else(|| ColdkeyRecovery {
+                started: Self::get_current_block_as_u64(),
+                vouches: Vec::new(),
+            });
+        ensure!(
+            !recovery.vouches.contains(&friend),
+            Error::<T>::AlreadyVouchedColdkeyRecovery
+        );
+        recovery.vouches.push(friend.clone());
+        let vouches = recovery.vouches.len() as u16;
+        ActiveColdkeyRecoveries::<T>::insert(&lost_coldkey, &new_coldkey, recovery);
+
+        Self::deposit_event(Event::ColdkeyRecoveryVouched {
+            lost_coldkey,
+            new_coldkey,
+            friend,
+            vouches,
+        });
+
+        Ok(())
+    }
+
+    /// Claims, as `new_coldkey`, the recovery of `lost_coldkey`, swapping all its state to the
+    /// caller.
+    pub fn do_claim_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        lost_coldkey: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        let new_coldkey = ensure_signed(origin)?;
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+
+        let config = ColdkeyRecoveryConfigs::<T>::get(&lost_coldkey)
+            .ok_or(Error::<T>::ColdkeyRecoveryNotSet)?;
+        let recovery = ActiveColdkeyRecoveries::<T>::get(&lost_coldkey, &new_coldkey)
+            .ok_or(Error::<T>::ColdkeyRecoveryNotFound)?;
+        ensure!(
+            recovery.vouches.len() >= usize::from(config.threshold)
+                && Self::get_current_block_as_u64()
+                    >= recovery.started.saturating_add(config.delay),
+            Error::<T>::ColdkeyRecoveryNotReady
+        );
+
+        // The new coldkey must be fresh, as for a scheduled swap.
+        ensure!(
+            StakingHotkeys::<T>::get(&new_coldkey).is_empty(),
+            Error::<T>::ColdKeyAlreadyAssociated
+        );
+        ensure!(
+            !Self::hotkey_account_exists(&new_coldkey),
+            Error::<T>::NewColdKeyIsHotkey
+        );
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+
+        ColdkeyRecoveryConfigs::<T>::remove(&lost_coldkey);
+        let _ = ActiveColdkeyRecoveries::<T>::clear_prefix(&lost_coldkey, u32::MAX, None);
+        weight.saturating_accrue(T::DbWeight::get().writes(2));
+
+        Self::perform_swap_coldkey(&lost_coldkey, &new_coldkey, &mut weight)?;
+
+        Self::set_last_tx_block(&new_coldkey, Self::get_current_block_as_u64());
+        ColdkeySwapScheduled::<T>::remove(&lost_coldkey);
+        ColdkeySwapScheduledTask::<T>::remove(&lost_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().writes(3));
+
+        Self::deposit_event(Event::ColdkeyRecovered {
+            lost_coldkey,
+            new_coldkey,
+        });
+
+        Ok(Some(weight).into())
+    }
+
+    /// Cancels, as the coldkey being recovered, its recovery to `new_coldkey`.
+    pub fn do_cancel_coldkey_recovery(
+        origin: T::RuntimeOrigin,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            ActiveColdkeyRecoveries::<T>::contains_key(&coldkey, &new_coldkey),
+            Error::<T>::ColdkeyRecoveryNotFound
+        );
+        ActiveColdkeyRecoveries::<T>::remove(&coldkey, &new_coldkey);
+
+        Self::deposit_event(Event::ColdkeyRecoveryCancelled {
+            lost_coldkey: coldkey,
+            new_coldkey,
+        });
+
+        Ok(())
+    }
+}
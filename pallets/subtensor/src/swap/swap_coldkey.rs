@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
 use frame_support::weights::Weight;
 use sp_core::Get;
 use substrate_fixed::types::U64F64;
@@ -51,36 +52,35 @@ impl<T: Config> Pallet<T> {
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
-        // 5. Swap the identity if the old coldkey has one
-        if let Some(identity) = IdentitiesV2::<T>::take(old_coldkey) {
-            IdentitiesV2::<T>::insert(new_coldkey, identity);
-        }
-
-        // 6. Ensure sufficient balance for the swap cost
+        // 5. Ensure sufficient balance for the swap cost
         ensure!(
             Self::can_remove_balance_from_coldkey_account(old_coldkey, swap_cost.into()),
             Error::<T>::NotEnoughBalanceToPaySwapColdKey
         );
 
-        // 7. Remove and burn the swap cost from the old coldkey's account
-        let actual_burn_amount =
-            Self::remove_balance_from_coldkey_account(old_coldkey, swap_cost.into())?;
-        Self::burn_tokens(actual_burn_amount);
+        // 6. Remove and burn the swap cost from the old coldkey's account
+        Self::transfer_tao(
+            LedgerAccount::Coldkey(old_coldkey.clone()),
+            LedgerAccount::Issuance,
+            swap_cost,
+            LedgerReason::Fee,
+        )?;
 
-        // 8. Update the weight for the balance operations
+        // 7. Update the weight for the balance operations
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
-        // 9. Perform the actual coldkey swap
+        // 8. Perform the actual coldkey swap
         let _ = Self::perform_swap_coldkey(old_coldkey, new_coldkey, &mut weight);
 
-        // 10. Update the last transaction block for the new coldkey
+        // 9. Update the last transaction block for the new coldkey
         Self::set_last_tx_block(new_coldkey, Self::get_current_block_as_u64());
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-        // 11. Remove the coldkey swap scheduled record
+        // 10. Remove the coldkey swap scheduled record
         ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ColdkeySwapScheduledTask::<T>::remove(old_coldkey);
 
-        // 12. Emit the ColdkeySwapped event
+        // 11. Emit the ColdkeySwapped event
         Self::deposit_event(Event::ColdkeySwapped {
             old_coldkey: old_coldkey.clone(),
             new_coldkey: new_coldkey.clone(),
@@ -124,9 +124,21 @@ impl<T: Config> Pallet<T> {
     ///    - For each hotkey owned by the old coldkey, transfer ownership to the new coldkey.
     ///    - Update the list of owned hotkeys for both old and new coldkeys.
     ///
-    /// 7. Transfer remaining balance:
+    /// 7. Swap unbonding queue:
+    ///    - Move any TAO still unbonding for the old coldkey to the new coldkey.
+    ///
+    /// 8. Swap registration bids:
+    ///    - Make the new coldkey the owner of any open registration bid of the old coldkey.
+    ///
+    /// 9. Swap subnet proposal and treasury spend votes:
+    ///    - Keep the votes of the old coldkey, so its stake cannot vote twice.
+    ///
+    /// 10. Transfer remaining balance:
     ///    - Transfer any remaining balance from the old coldkey to the new coldkey.
     ///
+    /// 11. Swap identity:
+    ///    - Move the identity of the old coldkey, if any, to the new coldkey.
+    ///
     /// Throughout the process, the function updates the transaction weight to reflect the operations performed.
     ///
     /// # Notes
@@ -173,6 +185,8 @@ impl<T: Config> Pallet<T> {
                 );
                 // Remove the value from the old account.
                 Alpha::<T>::remove((&hotkey, old_coldkey, netuid));
+                // Move the lock of the stake with it.
+                Self::swap_stake_lock(&hotkey, old_coldkey, &hotkey, new_coldkey, netuid);
             }
             // Add the weight for the read and write.
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
@@ -196,6 +210,11 @@ impl<T: Config> Pallet<T> {
         let old_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(old_coldkey);
         let mut new_staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::get(new_coldkey);
         for hotkey in old_staking_hotkeys {
+            // The new coldkey keeps the auto-compounding preference on the hotkey.
+            if !AutoCompound::<T>::take(&hotkey, old_coldkey) {
+                AutoCompound::<T>::insert(&hotkey, new_coldkey, false);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
             // If the hotkey is not already in the new coldkey, add it.
             if !new_staking_hotkeys.contains(&hotkey) {
                 new_staking_hotkeys.push(hotkey);
@@ -215,6 +234,8 @@ impl<T: Config> Pallet<T> {
             Owner::<T>::remove(owned_hotkey);
             // Add the hotkey to the new coldkey.
             Owner::<T>::insert(owned_hotkey, new_coldkey.clone());
+            // The new coldkey chooses where the emission of the hotkey is paid.
+            EmissionRecipient::<T>::remove(owned_hotkey);
             // Addd the owned hotkey to the new set of owned hotkeys.
             if !new_owned_hotkeys.contains(owned_hotkey) {
                 new_owned_hotkeys.push(owned_hotkey.clone());
@@ -224,7 +245,68 @@ impl<T: Config> Pallet<T> {
         OwnedHotkeys::<T>::insert(new_coldkey, new_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 7. Transfer remaining balance.
+        // 7. Swap the unbonding and EVM withdrawal queues.
+        // UnbondingQueue: MAP ( coldkey ) --> Vec<(release_block, tao)> | Unstaked TAO waiting to be released.
+        let old_unbonding = UnbondingQueue::<T>::take(old_coldkey);
+        if !old_unbonding.is_empty() {
+            UnbondingQueue::<T>::mutate(new_coldkey, |queue| {
+                queue.extend(old_unbonding);
+                queue.sort_by_key(|(release_block, _)| *release_block);
+            });
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // UnbondingFloor: DMAP ( netuid, coldkey ) --> release_block | Earliest release of unstaked TAO.
+        for netuid in Self::get_all_subnet_netuids() {
+            let old_floor = UnbondingFloor::<T>::take(netuid, old_coldkey);
+            if old_floor != 0 {
+                UnbondingFloor::<T>::mutate(netuid, new_coldkey, |floor| {
+                    *floor = (*floor).max(old_floor)
+                });
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // EvmWithdrawalQueue: MAP ( coldkey ) --> Vec<(release_block, tao)> | TAO withdrawn from the EVM waiting to be released.
+        let old_evm_withdrawals = EvmWithdrawalQueue::<T>::take(old_coldkey);
+        if !old_evm_withdrawals.is_empty() {
+            EvmWithdrawalQueue::<T>::mutate(new_coldkey, |queue| {
+                queue.extend(old_evm_withdrawals);
+                queue.sort_by_key(|(release_block, _)| *release_block);
+            });
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // 8. Swap open registration bids, so refunds go to the new coldkey.
+        // RegistrationBids: MAP ( netuid ) --> Vec<(coldkey, hotkey, bid)>
+        for netuid in Self::get_all_subnet_netuids() {
+            RegistrationBids::<T>::mutate_exists(netuid, |maybe_bids| {
+                if let Some(bids) = maybe_bids {
+                    for (coldkey, _, _) in bids.iter_mut() {
+                        if coldkey == old_coldkey {
+                            *coldkey = new_coldkey.clone();
+                        }
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // 9. Swap votes on open subnet proposals and treasury spends, so the moved stake cannot
+        // vote again.
+        // SubnetProposalVotes: DMAP ( netuid, coldkey ) --> aye
+        // SubnetTreasurySpendVotes: DMAP ( netuid, coldkey ) --> aye
+        for netuid in Self::get_all_subnet_netuids() {
+            if let Some(aye) = SubnetProposalVotes::<T>::take(netuid, old_coldkey) {
+                SubnetProposalVotes::<T>::insert(netuid, new_coldkey, aye);
+            }
+            if let Some(aye) = SubnetTreasurySpendVotes::<T>::take(netuid, old_coldkey) {
+                SubnetTreasurySpendVotes::<T>::insert(netuid, new_coldkey, aye);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 4));
+        }
+
+        // 10. Transfer remaining balance.
         // Balance: MAP ( coldkey ) --> u64 | Balance of the coldkey.
         // Transfer any remaining balance from old_coldkey to new_coldkey
         let remaining_balance = Self::get_coldkey_balance(old_coldkey);
@@ -234,6 +316,13 @@ impl<T: Config> Pallet<T> {
         }
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
+        // 11. Swap identity.
+        // IdentitiesV2: MAP ( coldkey ) --> identity | Identity of the coldkey.
+        if let Some(identity) = IdentitiesV2::<T>::take(old_coldkey) {
+            IdentitiesV2::<T>::insert(new_coldkey, identity);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
         // Return ok.
         Ok(())
     }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
 use frame_support::weights::Weight;
 use sp_core::Get;
 use substrate_fixed::types::U64F64;
@@ -12,7 +13,9 @@ impl<T: Config> Pallet<T> {
     /// * `origin` - The origin of the transaction, and also the coldkey account.
     /// * `old_hotkey` - The old hotkey to be swapped.
     /// * `new_hotkey` - The new hotkey to replace the old one.
-    /// * `netuid` - The hotkey swap in a subnet or all subnets.
+    /// * `netuid` - The hotkey swap in a subnet or all subnets. When set, only the registration
+    ///   on that subnet moves to the new hotkey, keeping its UID, weights, bonds and emission
+    ///   history, and `KeySwapOnSubnetCost` is burned instead of the global swap cost.
     ///
     /// # Returns
     ///
@@ -25,6 +28,8 @@ impl<T: Config> Pallet<T> {
     /// * `NewHotKeyIsSameWithOld` - If the new hotkey is the same as the old hotkey.
     /// * `HotKeyAlreadyRegisteredInSubNet` - If the new hotkey is already registered in the subnet.
     /// * `NotEnoughBalanceToPaySwapHotKey` - If there is not enough balance to pay for the swap.
+    /// * `HotKeySwapOnSubnetIntervalNotPassed` - If the coldkey swapped a hotkey on the subnet
+    ///   less than `HotkeySwapOnSubnetInterval` blocks ago.
     pub fn do_swap_hotkey(
         origin: T::RuntimeOrigin,
         old_hotkey: &T::AccountId,
@@ -99,12 +104,13 @@ impl<T: Config> Pallet<T> {
 
         weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 0));
 
-        // 14. Remove the swap cost from the coldkey's account
-        let actual_burn_amount =
-            Self::remove_balance_from_coldkey_account(&coldkey, swap_cost.into())?;
-
-        // 18. Burn the tokens
-        Self::burn_tokens(actual_burn_amount);
+        // 14. Remove the swap cost from the coldkey's account and burn it
+        Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Issuance,
+            swap_cost,
+            LedgerReason::Fee,
+        )?;
         weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 2));
 
         // 19. Perform the hotkey swap
@@ -190,6 +196,28 @@ impl<T: Config> Pallet<T> {
 
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
+        // 4.1 Swap the weights key.
+        // WeightsKeys( hotkey ) -> weights_key -- the key authorized to set weights for the hotkey.
+        Self::swap_weights_key(old_hotkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+
+        // 4.2 Swap the emission recipient.
+        // EmissionRecipient( hotkey ) -> recipient -- the account paid the emission of the hotkey.
+        Self::swap_emission_recipient(old_hotkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 4.3 Swap the multisig.
+        // HotkeyMultisigs( hotkey ) -> multisig -- the operators approving the weights of the hotkey.
+        Self::swap_hotkey_multisig(old_hotkey, new_hotkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+
+        // 4.4 Swap the auto-compounding preferences.
+        // AutoCompound( hotkey, coldkey ) -> auto_compound -- whether the dividends of the hotkey are restaked.
+        let opt_outs = Self::swap_auto_compound(old_hotkey, new_hotkey);
+        weight.saturating_accrue(
+            T::DbWeight::get().reads_writes(opt_outs.saturating_add(1), opt_outs.saturating_mul(2)),
+        );
+
         // 5. execute the hotkey swap on all subnets
         for netuid in Self::get_all_subnet_netuids() {
             Self::perform_hotkey_swap_on_one_subnet(old_hotkey, new_hotkey, weight, netuid);
@@ -225,6 +253,28 @@ impl<T: Config> Pallet<T> {
             Delegates::<T>::insert(new_hotkey, old_delegate_take);
             weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
         }
+        // PendingDelegateTake( hotkey ) -> (take, effective_block) -- queued take increase.
+        if let Some((take, effective_block)) = PendingDelegateTake::<T>::take(old_hotkey) {
+            PendingDelegateTake::<T>::insert(new_hotkey, (take, effective_block));
+            PendingDelegateTakeQueue::<T>::remove(effective_block, old_hotkey);
+            PendingDelegateTakeQueue::<T>::insert(effective_block, new_hotkey, take);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 4));
+        }
+        // DelegateStakeCap( hotkey ) -> cap -- the maximum stake the delegate accepts.
+        if let Some(cap) = DelegateStakeCap::<T>::take(old_hotkey) {
+            DelegateStakeCap::<T>::insert(new_hotkey, cap);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        // DelegateStakingAccessMode( hotkey ) -> access -- the staking list mode of the delegate.
+        if let Some(access) = DelegateStakingAccessMode::<T>::take(old_hotkey) {
+            DelegateStakingAccessMode::<T>::insert(new_hotkey, access);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+        // DelegateStakingList( hotkey, coldkey ) -> listed -- the staking list of the delegate.
+        for (staker, listed) in DelegateStakingList::<T>::drain_prefix(old_hotkey) {
+            DelegateStakingList::<T>::insert(new_hotkey, staker, listed);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
 
         // 11. Alpha already update in perform_hotkey_swap_on_one_subnet
         // Update the StakingHotkeys for the case where hotkey staked by multiple coldkeys.
@@ -299,12 +349,17 @@ impl<T: Config> Pallet<T> {
         );
 
         // 5. Remove the swap cost from the coldkey's account
-        let actual_burn_amount = Self::remove_balance_from_coldkey_account(coldkey, swap_cost)?;
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 0));
 
         // 6. Burn the tokens
-        Self::burn_tokens(actual_burn_amount);
-        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        let actual_burn_amount = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Issuance,
+            swap_cost.into(),
+            LedgerReason::Fee,
+        )?;
+        Self::record_subnet_tao_burned(netuid, actual_burn_amount);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
         // 7. Swap owner.
         // Owner( hotkey ) -> coldkey -- the coldkey that owns the hotkey.
@@ -325,6 +380,15 @@ impl<T: Config> Pallet<T> {
         // 9. Perform the hotkey swap
         Self::perform_hotkey_swap_on_one_subnet(old_hotkey, new_hotkey, &mut weight, netuid);
 
+        // DelegateStakeCap( hotkey ) -> cap -- the old hotkey keeps its cap on the other subnets,
+        // so the new hotkey inherits it unless it has its own.
+        if let Some(cap) = DelegateStakeCap::<T>::get(old_hotkey) {
+            if !DelegateStakeCap::<T>::contains_key(new_hotkey) {
+                DelegateStakeCap::<T>::insert(new_hotkey, cap);
+            }
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+
         // 10. Update the last transaction block for the coldkey
         Self::set_last_tx_block(coldkey, block);
         LastHotkeySwapOnNetuid::<T>::insert(netuid, coldkey, block);
@@ -366,6 +430,13 @@ impl<T: Config> Pallet<T> {
         });
         weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 
+        // 2.1 Swap the miner emission vesting for the hotkey.
+        // VestingMinerEmission( netuid, hotkey ) -> vesting -- the unvested emission of the hotkey.
+        if let Some(vesting) = VestingMinerEmission::<T>::take(netuid, old_hotkey) {
+            VestingMinerEmission::<T>::insert(netuid, new_hotkey, vesting);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
         // 3. Swap all subnet specific info.
 
         // 3.1 Remove the previous hotkey and insert the new hotkey from membership.
@@ -409,6 +480,11 @@ impl<T: Config> Pallet<T> {
                 Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
                 weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
             }
+            if let Ok(old_axon_info_v2) = AxonsV2::<T>::try_get(netuid, old_hotkey) {
+                AxonsV2::<T>::remove(netuid, old_hotkey);
+                AxonsV2::<T>::insert(netuid, new_hotkey, old_axon_info_v2);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
         }
 
         // 3.5 Swap WeightCommits
@@ -554,7 +630,15 @@ impl<T: Config> Pallet<T> {
         );
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 8.3 Swap TaoDividendsPerSubnet
+        // 8.3 Swap AlphaDividendsHistory
+        // The new hotkey keeps its own history if it has one.
+        let old_history = AlphaDividendsHistory::<T>::take(netuid, old_hotkey);
+        if !AlphaDividendsHistory::<T>::contains_key(netuid, new_hotkey) {
+            AlphaDividendsHistory::<T>::insert(netuid, new_hotkey, old_history);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // 8.4 Swap TaoDividendsPerSubnet
         let old_hotkey_tao_dividends = TaoDividendsPerSubnet::<T>::get(netuid, old_hotkey);
         let new_hotkey_tao_dividends = TaoDividendsPerSubnet::<T>::get(netuid, new_hotkey);
         TaoDividendsPerSubnet::<T>::remove(netuid, old_hotkey);
@@ -583,6 +667,10 @@ impl<T: Config> Pallet<T> {
                 );
                 weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
 
+                // StakeLocks( hotkey, coldkey, netuid ) -> lock -- the lock of the stake.
+                Self::swap_stake_lock(old_hotkey, &coldkey, new_hotkey, &coldkey, netuid);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
                 // Swap StakingHotkeys.
                 // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
                 let mut staking_hotkeys = StakingHotkeys::<T>::get(&coldkey);
@@ -4178,3 +4178,50 @@ fn test_do_set_childkey_take_rate_limit_exceeded() {
         ));
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_get_hotkey_hierarchy --exact --show-output --nocapture
+#[test]
+fn test_get_hotkey_hierarchy() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let grandparent = U256::from(2);
+        let parent = U256::from(3);
+        let child = U256::from(4);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+
+        // grandparent -> parent (half) -> child (all)
+        ChildKeys::<Test>::insert(grandparent, netuid, vec![(u64::MAX / 2, parent)]);
+        ParentKeys::<Test>::insert(parent, netuid, vec![(u64::MAX / 2, grandparent)]);
+        ChildKeys::<Test>::insert(parent, netuid, vec![(u64::MAX, child)]);
+        ParentKeys::<Test>::insert(child, netuid, vec![(u64::MAX, parent)]);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &grandparent,
+            &coldkey,
+            netuid,
+            1_000.into(),
+        );
+
+        let hierarchy = SubtensorModule::get_hotkey_hierarchy(parent);
+        assert_eq!(hierarchy.len(), 1);
+        let tree = &hierarchy[0];
+        assert_eq!(tree.parents.len(), 1);
+        assert_eq!(tree.parents[0].parent, grandparent);
+        assert_abs_diff_eq!(
+            u64::from(tree.parents[0].contributed_alpha.0),
+            500,
+            epsilon = 1
+        );
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].child, child);
+
+        // The full tree below the grandparent spans two levels.
+        let tree = &SubtensorModule::get_hotkey_hierarchy(grandparent)[0];
+        assert!(tree.parents.is_empty());
+        let depths: Vec<u8> = tree.children.iter().map(|edge| edge.depth.0).collect();
+        assert_eq!(depths, vec![1, 2]);
+
+        // Hotkeys without parents or children have no hierarchy.
+        assert!(SubtensorModule::get_hotkey_hierarchy(U256::from(99)).is_empty());
+    });
+}
@@ -5,7 +5,7 @@ use crate::tests::mock;
 use crate::*;
 use alloc::collections::BTreeMap;
 use approx::assert_abs_diff_eq;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok, weights::Weight};
 use pallet_subtensor_swap::position::PositionId;
 use sp_core::U256;
 use substrate_fixed::types::{I64F64, I96F32, U96F32};
@@ -2557,3 +2557,577 @@ fn test_coinbase_v3_liquidity_update() {
         assert!(liquidity_before < liquidity_after);
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_next_halving_matches_block_emission --exact --show-output --nocapture
+#[test]
+fn test_next_halving_matches_block_emission() {
+    new_test_ext(1).execute_with(|| {
+        let total_supply: u64 = 21_000_000_000_000_000;
+        for issuance in [
+            0,
+            10_000_000_000_000_000,
+            12_000_000_000_000_000,
+            16_000_000_000_000_000,
+        ] {
+            let (halvings, next) = SubtensorModule::get_next_halving(issuance, total_supply);
+            let emission = SubtensorModule::get_block_emission_for_issuance(issuance).unwrap();
+            assert_eq!(emission, 1_000_000_000 >> halvings);
+            // Emission has halved shortly after the projected issuance.
+            assert_eq!(
+                SubtensorModule::get_block_emission_for_issuance(next + 100_000_000_000_000)
+                    .unwrap(),
+                emission / 2
+            );
+        }
+    });
+}
+
+#[test]
+fn test_miner_emission_vesting() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let miner_hk = U256::from(3);
+        let miner_ck = U256::from(4);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        register_ok_neuron(netuid, miner_hk, miner_ck, 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &miner_hk).unwrap();
+        SubtensorModule::set_miner_emission_vesting_period(netuid, 100);
+
+        let start = SubtensorModule::get_current_block_as_u64();
+        let mut incentives: BTreeMap<U256, AlphaCurrency> = BTreeMap::new();
+        incentives.insert(miner_hk, 1_000.into());
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            AlphaCurrency::ZERO,
+            incentives,
+            BTreeMap::new(),
+            BTreeMap::new(),
+        );
+
+        // Nothing is staked until the emission vests.
+        let stake = || SubtensorModule::get_stake_for_hotkey_on_subnet(&miner_hk, netuid);
+        assert_eq!(stake(), AlphaCurrency::ZERO);
+        assert_eq!(
+            VestingMinerEmission::<Test>::get(netuid, miner_hk).map(|v| v.remaining),
+            Some(1_000.into())
+        );
+
+        // Half of the period releases half of the emission.
+        System::set_block_number(start + 50);
+        assert_ok!(SubtensorModule::claim_vested_emission(
+            RuntimeOrigin::signed(miner_ck),
+            miner_hk,
+            netuid
+        ));
+        assert_eq!(stake(), 500.into());
+
+        // Deregistering stakes what vested and recycles the rest.
+        System::set_block_number(start + 75);
+        let recycled_before = SubnetAlphaRecycled::<Test>::get(netuid);
+        SubtensorModule::vacate_neuron(netuid, uid, &miner_hk);
+        assert_eq!(stake(), 750.into());
+        assert_eq!(VestingMinerEmission::<Test>::get(netuid, miner_hk), None);
+        assert_eq!(
+            SubnetAlphaRecycled::<Test>::get(netuid),
+            recycled_before + 250.into()
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_emission_share_agreement --exact --show-output --nocapture
+#[test]
+fn test_emission_share_agreement() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk_a = U256::from(1);
+        let owner_ck_a = U256::from(2);
+        let owner_hk_b = U256::from(3);
+        let owner_ck_b = U256::from(4);
+        let netuid_a = add_dynamic_network(&owner_hk_a, &owner_ck_a);
+        let netuid_b = add_dynamic_network(&owner_hk_b, &owner_ck_b);
+
+        // Only the owner of the sharing subnet can propose, and only the recipient can accept.
+        assert_noop!(
+            SubtensorModule::propose_emission_share(
+                RuntimeOrigin::signed(owner_ck_b),
+                netuid_a,
+                netuid_b,
+                u16::MAX / 4
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::propose_emission_share(
+            RuntimeOrigin::signed(owner_ck_a),
+            netuid_a,
+            netuid_b,
+            u16::MAX / 4
+        ));
+        assert_noop!(
+            SubtensorModule::accept_emission_share(
+                RuntimeOrigin::signed(owner_ck_a),
+                netuid_a,
+                netuid_b
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::accept_emission_share(
+            RuntimeOrigin::signed(owner_ck_b),
+            netuid_a,
+            netuid_b
+        ));
+        assert_eq!(
+            EmissionShares::<Test>::get(netuid_a, netuid_b),
+            Some(u16::MAX / 4)
+        );
+
+        // A quarter of the owner cut of subnet A goes to the owner of subnet B.
+        let stake_a = || SubtensorModule::get_stake_for_hotkey_on_subnet(&owner_hk_a, netuid_a);
+        let stake_b = || SubtensorModule::get_stake_for_hotkey_on_subnet(&owner_hk_b, netuid_a);
+        let (before_a, before_b) = (stake_a(), stake_b());
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid_a,
+            1_000_000.into(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+        );
+        assert_abs_diff_eq!(u64::from(stake_b() - before_b), 250_000, epsilon = 10);
+        assert_abs_diff_eq!(u64::from(stake_a() - before_a), 750_000, epsilon = 10);
+
+        // The sharing owner cannot end the agreement, the recipient can.
+        assert_noop!(
+            SubtensorModule::end_emission_share(
+                RuntimeOrigin::signed(owner_ck_a),
+                netuid_a,
+                netuid_b
+            ),
+            Error::<Test>::EmissionShareNotFound
+        );
+        assert_ok!(SubtensorModule::end_emission_share(
+            RuntimeOrigin::signed(owner_ck_b),
+            netuid_a,
+            netuid_b
+        ));
+        assert_eq!(EmissionShares::<Test>::get(netuid_a, netuid_b), None);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_emission_circuit_breaker_halts_distribution --exact --show-output --nocapture
+#[test]
+fn test_emission_circuit_breaker_halts_distribution() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        SubtensorModule::set_tempo(netuid, 2);
+
+        // Emission within the threshold does not trip the breaker.
+        SubtensorModule::check_emission_circuit_breaker(
+            Some(netuid),
+            U96F32::from_num(1_000),
+            U96F32::from_num(2_000),
+        );
+        assert!(!SubtensorModule::is_emission_halted(netuid));
+
+        // A subnet emitting more than the threshold is halted.
+        SubtensorModule::set_emission_circuit_breaker_threshold(50);
+        step_block(1);
+        assert!(SubnetEmissionHalted::<Test>::get(netuid));
+        assert!(System::events().iter().any(|e| matches!(
+            e.event,
+            RuntimeEvent::SubtensorModule(Event::EmissionCircuitBreakerTripped {
+                netuid: Some(tripped),
+                ..
+            }) if tripped == netuid
+        )));
+
+        // Epochs stop draining the pending emission, which keeps accumulating.
+        let last_step = LastMechansimStepBlock::<Test>::get(netuid);
+        let pending = PendingEmission::<Test>::get(netuid);
+        step_block(6);
+        assert_eq!(LastMechansimStepBlock::<Test>::get(netuid), last_step);
+        assert!(PendingEmission::<Test>::get(netuid) >= pending);
+
+        // Once root resets the breakers, the pending emission is distributed again.
+        SubtensorModule::set_emission_circuit_breaker_threshold(0);
+        SubtensorModule::reset_emission_circuit_breaker(Some(netuid));
+        SubtensorModule::reset_emission_circuit_breaker(None);
+        step_block(3);
+        assert!(LastMechansimStepBlock::<Test>::get(netuid) > last_step);
+    });
+}
+
+#[test]
+fn test_incentive_matching_buys_pending_emission() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        let fund = SubtensorModule::get_incentive_matching_account();
+        SubtensorModule::add_balance_to_coldkey_account(&fund, 10_000_000_000);
+        let cap = TaoCurrency::from(1_500_000);
+        SubtensorModule::set_incentive_matching(netuid, cap, 100);
+
+        // The TAO emission is matched with TAO from the fund, buying pending alpha.
+        let pending = PendingEmission::<Test>::get(netuid);
+        let balance = SubtensorModule::get_coldkey_balance(&fund);
+        SubtensorModule::match_subnet_emission(netuid, TaoCurrency::from(1_000_000));
+        assert!(PendingEmission::<Test>::get(netuid) > pending);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&fund),
+            balance.saturating_sub(1_000_000)
+        );
+
+        // The match is limited by the cap, after which the schedule ends.
+        SubtensorModule::match_subnet_emission(netuid, TaoCurrency::from(1_000_000));
+        assert_eq!(
+            IncentiveMatching::<Test>::get(netuid).map(|schedule| schedule.matched),
+            Some(cap)
+        );
+        SubtensorModule::match_subnet_emission(netuid, TaoCurrency::from(1_000_000));
+        assert!(IncentiveMatching::<Test>::get(netuid).is_none());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&fund),
+            balance.saturating_sub(1_500_000)
+        );
+
+        // A schedule past its end block no longer matches.
+        SubtensorModule::set_incentive_matching(netuid, cap, 1);
+        step_block(2);
+        assert!(IncentiveMatching::<Test>::get(netuid).is_none());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&fund),
+            balance.saturating_sub(1_500_000)
+        );
+    });
+}
+
+#[test]
+fn test_emission_recipient_receives_incentive_as_stake() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let miner_hk = U256::from(3);
+        let miner_ck = U256::from(4);
+        let recipient = U256::from(5);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        setup_reserves(
+            netuid,
+            TaoCurrency::from(1_000_000_000_000),
+            AlphaCurrency::from(1_000_000_000_000),
+        );
+        register_ok_neuron(netuid, miner_hk, miner_ck, 0);
+        let incentives = || BTreeMap::from([(miner_hk, AlphaCurrency::from(1_000_000_000))]);
+        let stake = || {
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &miner_hk, &miner_ck, netuid,
+            )
+        };
+
+        // Only the owner of the hotkey can set its emission recipient.
+        assert_noop!(
+            SubtensorModule::set_emission_recipient(
+                RuntimeOrigin::signed(recipient),
+                miner_hk,
+                Some(recipient)
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // By default the incentive accrues as stake of the owning coldkey.
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            AlphaCurrency::ZERO,
+            incentives(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+        );
+        let staked = stake();
+        assert_abs_diff_eq!(u64::from(staked), 1_000_000_000, epsilon = 10);
+
+        // With a recipient, the incentive accrues as stake of the recipient instead.
+        assert_ok!(SubtensorModule::set_emission_recipient(
+            RuntimeOrigin::signed(miner_ck),
+            miner_hk,
+            Some(recipient)
+        ));
+        assert_eq!(EmissionRecipient::<Test>::get(miner_hk), Some(recipient));
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            AlphaCurrency::ZERO,
+            incentives(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+        );
+        assert_abs_diff_eq!(u64::from(stake()), u64::from(staked), epsilon = 10);
+        assert_abs_diff_eq!(
+            u64::from(SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &miner_hk, &recipient, netuid,
+            )),
+            1_000_000_000,
+            epsilon = 10
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&recipient), 0);
+
+        // Removing the recipient restores the default.
+        assert_ok!(SubtensorModule::set_emission_recipient(
+            RuntimeOrigin::signed(miner_ck),
+            miner_hk,
+            None
+        ));
+        assert!(EmissionRecipient::<Test>::get(miner_hk).is_none());
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_auto_compound_opt_out_pays_dividends_as_tao --exact --show-output --nocapture
+#[test]
+fn test_auto_compound_opt_out_pays_dividends_as_tao() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let nominator = U256::from(3);
+        let opted_out = U256::from(4);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        setup_reserves(
+            netuid,
+            TaoCurrency::from(1_000_000_000_000),
+            AlphaCurrency::from(1_000_000_000_000),
+        );
+        for coldkey in [nominator, opted_out] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hk,
+                &coldkey,
+                netuid,
+                AlphaCurrency::from(1_000_000_000),
+            );
+        }
+        let stake = |coldkey: U256| {
+            u64::from(SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hk, &coldkey, netuid,
+            ))
+        };
+
+        // The hotkey must exist.
+        assert_noop!(
+            SubtensorModule::set_auto_compound(
+                RuntimeOrigin::signed(opted_out),
+                U256::from(99),
+                false
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        assert_ok!(SubtensorModule::set_auto_compound(
+            RuntimeOrigin::signed(opted_out),
+            owner_hk,
+            false
+        ));
+        assert!(!AutoCompound::<Test>::get(owner_hk, opted_out));
+        assert!(AutoCompound::<Test>::get(owner_hk, nominator));
+
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            AlphaCurrency::ZERO,
+            BTreeMap::new(),
+            BTreeMap::from([(owner_hk, U96F32::from_num(1_000_000_000))]),
+            BTreeMap::new(),
+        );
+
+        // The nominator restakes its dividends, the opted out coldkey is paid them in TAO.
+        assert!(stake(nominator) > 1_000_000_000);
+        assert_abs_diff_eq!(stake(opted_out), 1_000_000_000, epsilon = 10);
+        assert!(SubtensorModule::get_coldkey_balance(&opted_out) > 0);
+
+        // Turning auto-compounding back on restores the default.
+        assert_ok!(SubtensorModule::set_auto_compound(
+            RuntimeOrigin::signed(opted_out),
+            owner_hk,
+            true
+        ));
+        assert_eq!(AutoCompound::<Test>::iter_prefix(owner_hk).count(), 0);
+        let balance = SubtensorModule::get_coldkey_balance(&opted_out);
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            AlphaCurrency::ZERO,
+            BTreeMap::new(),
+            BTreeMap::from([(owner_hk, U96F32::from_num(1_000_000_000))]),
+            BTreeMap::new(),
+        );
+        assert!(stake(opted_out) > 1_000_000_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&opted_out), balance);
+    });
+}
+
+/// Registers `n` staked neurons on `netuid`, each setting weights on the last two uids.
+fn setup_multi_block_epoch_subnet(netuid: NetUid, n: u16) {
+    add_network(netuid, 1, 0);
+    SubtensorModule::set_max_registrations_per_block(netuid, 1000);
+    SubtensorModule::set_target_registrations_per_interval(netuid, 1000);
+    for uid in 0..n {
+        let hotkey = U256::from(uid);
+        let coldkey = U256::from(100 + uid);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            AlphaCurrency::from(1_000_000_000 * (uid as u64 + 1)),
+        );
+    }
+    System::set_block_number(System::block_number() + 1);
+    for uid in 0..n {
+        Weights::<Test>::insert(netuid, uid, vec![(n - 2, u16::MAX), (n - 1, u16::MAX / 2)]);
+        SubtensorModule::set_last_update_for_uid(netuid, uid, System::block_number());
+    }
+    System::set_block_number(System::block_number() + 1);
+}
+
+/// The stake, incentive, dividends and bonds of the first `n` uids of `netuid`, and the
+/// incentives and alpha dividends of the last `EmissionDistributed` of `netuid`.
+#[allow(clippy::type_complexity)]
+fn multi_block_epoch_outcome(
+    netuid: NetUid,
+    n: u16,
+) -> (
+    Vec<AlphaCurrency>,
+    Vec<u16>,
+    Vec<u16>,
+    Vec<Vec<(u16, u16)>>,
+    Option<(AlphaCurrency, AlphaCurrency)>,
+) {
+    let first = |values: Vec<u16>| values.into_iter().take(n as usize).collect::<Vec<u16>>();
+    let stakes: Vec<AlphaCurrency> = (0..n)
+        .map(|uid| SubtensorModule::get_stake_for_hotkey_on_subnet(&U256::from(uid), netuid))
+        .collect();
+    let bonds: Vec<Vec<(u16, u16)>> = (0..n).map(|uid| Bonds::<Test>::get(netuid, uid)).collect();
+    let emission = System::events().iter().rev().find_map(|e| match e.event {
+        RuntimeEvent::SubtensorModule(Event::EmissionDistributed {
+            netuid: distributed,
+            incentives,
+            alpha_dividends,
+            ..
+        }) if distributed == netuid => Some((incentives, alpha_dividends)),
+        _ => None,
+    });
+    (
+        stakes,
+        first(Incentive::<Test>::get(netuid)),
+        first(Dividends::<Test>::get(netuid)),
+        bonds,
+        emission,
+    )
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_multi_block_epoch_matches_single_block_epoch --exact --show-output --nocapture
+#[test]
+fn test_multi_block_epoch_matches_single_block_epoch() {
+    let pending_alpha = AlphaCurrency::from(1_000_000_000);
+    let run_epoch = |chunk_size: u16| {
+        new_test_ext(1).execute_with(|| {
+            let netuid = NetUid::from(1);
+            let n = 4u16;
+            setup_multi_block_epoch_subnet(netuid, n);
+
+            SubtensorModule::set_multi_block_epoch_chunk_size(netuid, chunk_size);
+            if chunk_size == 0 {
+                SubtensorModule::drain_pending_emission(
+                    netuid,
+                    pending_alpha,
+                    TaoCurrency::ZERO,
+                    AlphaCurrency::ZERO,
+                    AlphaCurrency::ZERO,
+                );
+            } else {
+                SubtensorModule::start_multi_block_epoch(
+                    netuid,
+                    pending_alpha,
+                    TaoCurrency::ZERO,
+                    AlphaCurrency::ZERO,
+                    AlphaCurrency::ZERO,
+                );
+                // Each pass over the uids takes at least one block.
+                let mut blocks = 0;
+                while SubtensorModule::is_multi_block_epoch_running(netuid) {
+                    System::set_block_number(System::block_number() + 1);
+                    assert!(SubtensorModule::step_multi_block_epochs() != Weight::zero());
+                    blocks += 1;
+                }
+                assert!(blocks > 6 * n as usize);
+                assert_eq!(
+                    MultiBlockEpochNeurons::<Test>::iter_prefix(netuid).count(),
+                    0
+                );
+                assert_eq!(
+                    MultiBlockEpochVectors::<Test>::iter_prefix(netuid).count(),
+                    0
+                );
+            }
+
+            multi_block_epoch_outcome(netuid, n)
+        })
+    };
+
+    let single_block = run_epoch(0);
+    assert!(single_block.1.iter().any(|incentive| *incentive > 0));
+    assert!(single_block.4.is_some());
+    assert_eq!(run_epoch(1), single_block);
+    assert_eq!(run_epoch(3), single_block);
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_multi_block_epoch_ignores_neurons_registered_while_running --exact --show-output --nocapture
+#[test]
+fn test_multi_block_epoch_ignores_neurons_registered_while_running() {
+    let pending_alpha = AlphaCurrency::from(1_000_000_000);
+    let n = 4u16;
+    let single_block = new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        setup_multi_block_epoch_subnet(netuid, n);
+        SubtensorModule::drain_pending_emission(
+            netuid,
+            pending_alpha,
+            TaoCurrency::ZERO,
+            AlphaCurrency::ZERO,
+            AlphaCurrency::ZERO,
+        );
+        multi_block_epoch_outcome(netuid, n)
+    });
+
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        setup_multi_block_epoch_subnet(netuid, n);
+        SubtensorModule::set_multi_block_epoch_chunk_size(netuid, 1);
+        SubtensorModule::start_multi_block_epoch(
+            netuid,
+            pending_alpha,
+            TaoCurrency::ZERO,
+            AlphaCurrency::ZERO,
+            AlphaCurrency::ZERO,
+        );
+
+        // A neuron registers while the epoch reads the snapshot.
+        System::set_block_number(System::block_number() + 1);
+        SubtensorModule::step_multi_block_epochs();
+        let new_hotkey = U256::from(n);
+        register_ok_neuron(netuid, new_hotkey, U256::from(100 + n), 0);
+        let new_uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &new_hotkey).unwrap();
+        assert_eq!(new_uid, n);
+        assert!(MultiBlockEpochRegistrations::<Test>::get(netuid, new_uid));
+
+        while SubtensorModule::is_multi_block_epoch_running(netuid) {
+            System::set_block_number(System::block_number() + 1);
+            SubtensorModule::step_multi_block_epochs();
+        }
+
+        // The epoch pays and writes what it would have in a single block, and leaves the new
+        // neuron as it registered.
+        assert_eq!(multi_block_epoch_outcome(netuid, n), single_block);
+        assert_eq!(Incentive::<Test>::get(netuid).len(), n as usize + 1);
+        assert_eq!(Incentive::<Test>::get(netuid)[new_uid as usize], 0);
+        assert_eq!(Dividends::<Test>::get(netuid)[new_uid as usize], 0);
+        assert!(Bonds::<Test>::get(netuid, new_uid).is_empty());
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_on_subnet(&new_hotkey, netuid),
+            AlphaCurrency::ZERO
+        );
+        assert!(!MultiBlockEpochRegistrations::<Test>::get(netuid, new_uid));
+    });
+}
@@ -1,11 +1,13 @@
 use super::mock::*;
+use crate::*;
 
+use approx::assert_abs_diff_eq;
 use codec::Compact;
 use frame_support::assert_ok;
 use scale_info::prelude::collections::HashMap;
 use sp_core::U256;
 use substrate_fixed::types::U64F64;
-use subtensor_runtime_common::NetUid;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
 #[test]
 fn test_return_per_1000_tao() {
@@ -164,3 +166,44 @@ fn test_get_delegated() {
         }
     });
 }
+
+#[test]
+fn test_get_delegate_return_rates() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let delegate = U256::from(1);
+        let non_delegate = U256::from(2);
+        add_network(netuid, 99, 0);
+        Delegates::<Test>::insert(delegate, 0);
+
+        // Three epochs at 1%, 2% and 3% return on 1000 alpha, oldest first.
+        AlphaDividendsHistory::<Test>::insert(
+            netuid,
+            delegate,
+            vec![
+                (AlphaCurrency::from(10), AlphaCurrency::from(1_000)),
+                (AlphaCurrency::from(20), AlphaCurrency::from(1_000)),
+                (AlphaCurrency::from(30), AlphaCurrency::from(1_000)),
+            ],
+        );
+        AlphaDividendsHistory::<Test>::insert(
+            netuid,
+            non_delegate,
+            vec![(AlphaCurrency::from(10), AlphaCurrency::from(1_000))],
+        );
+
+        assert!(SubtensorModule::get_delegate_return_rates(0).is_empty());
+
+        let rates = SubtensorModule::get_delegate_return_rates(2);
+        assert_eq!(rates.len(), 1);
+        let rate = &rates[0];
+        assert_eq!(rate.delegate_ss58, delegate);
+        assert_eq!(rate.epochs, Compact(2));
+        assert_eq!(rate.total_dividends, Compact(AlphaCurrency::from(50)));
+        assert_eq!(rate.average_stake, Compact(AlphaCurrency::from(1_000)));
+        // (3% + 2%) / 2 = 2.5% per epoch
+        assert_abs_diff_eq!(rate.return_per_epoch.0, 25_000_000, epsilon = 1);
+        // tempo 99 -> 100 blocks per epoch -> 26_280 epochs per year
+        assert_abs_diff_eq!(rate.annual_return.0, 25_000_000 * 26_280, epsilon = 26_280);
+    });
+}
@@ -3802,3 +3802,422 @@ fn test_epoch_does_not_mask_outside_window_but_masks_inside() {
         );
     });
 }
+
+#[test]
+fn test_validator_permits_respect_subnet_stake_threshold() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, u16::MAX - 1, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 2);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 2);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+
+        // uid 0 stakes 100, uid 1 stakes 1_000.
+        for (key, stake) in [(0u64, 100u64), (1, 1_000)] {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(key),
+                &U256::from(key),
+                netuid,
+                stake.into(),
+            );
+        }
+
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 0));
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 1));
+
+        // The subnet threshold removes the permit of the small validator.
+        SubtensorModule::set_subnet_stake_threshold(netuid, 500);
+        run_to_block(1);
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert!(!SubtensorModule::get_validator_permit_for_uid(netuid, 0));
+        assert!(SubtensorModule::get_validator_permit_for_uid(netuid, 1));
+    });
+}
+
+#[test]
+fn test_bonds_reset_on_deregistration_drops_dormant_validator_bonds() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+
+        // uids 0 and 1 are validators, uid 2 is a miner.
+        for key in 0..3u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        for key in 0..2u64 {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(key),
+                &U256::from(key),
+                netuid,
+                1_000.into(),
+            );
+        }
+        run_to_block(100);
+
+        // uid 0 holds bonds on the miner but has been dormant longer than the activity cutoff.
+        let setup = || {
+            SubtensorModule::set_activity_cutoff(netuid, 10);
+            SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+            SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+            LastUpdate::<Test>::insert(netuid, vec![0, 100, 0]);
+            Weights::<Test>::insert(netuid, 0, Vec::<(u16, u16)>::new());
+            Weights::<Test>::insert(netuid, 1, vec![(2, u16::MAX)]);
+            Bonds::<Test>::insert(netuid, 0, vec![(2, u16::MAX)]);
+            Bonds::<Test>::insert(netuid, 1, Vec::<(u16, u16)>::new());
+        };
+        let bonds_of = |uid: u16| -> u64 {
+            Bonds::<Test>::get(netuid, uid)
+                .iter()
+                .map(|(_, bond)| u64::from(*bond))
+                .sum()
+        };
+
+        // By default the dormant validator keeps its bonds.
+        setup();
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert!(bonds_of(0) > 0);
+
+        SubtensorModule::set_bonds_reset_on_deregistration(netuid, true);
+        setup();
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert_eq!(bonds_of(0), 0);
+        assert!(bonds_of(1) > 0);
+    });
+}
+
+#[test]
+fn test_alpha_sigmoid_stays_within_alpha_bounds() {
+    let mut rng = StdRng::seed_from_u64(327);
+    let unit = Uniform::new_inclusive(0.0, 1.0);
+    for _ in 0..10_000 {
+        let (a, b): (f64, f64) = (rng.sample(unit), rng.sample(unit));
+        let alpha_low = I32F32::from_num(a.min(b));
+        let alpha_high = I32F32::from_num(a.max(b));
+        let steepness = I32F32::from_num(rng.gen_range(0..=i16::MAX));
+
+        let alpha = SubtensorModule::alpha_sigmoid(
+            I32F32::from_num(rng.sample(unit)),
+            I32F32::from_num(rng.sample(unit)),
+            I32F32::from_num(rng.sample(unit)),
+            alpha_low,
+            alpha_high,
+            steepness,
+        );
+        assert!(alpha >= alpha_low && alpha <= alpha_high);
+    }
+}
+
+#[test]
+fn test_set_alpha_values_respects_liquid_alpha_bounds() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_liquid_alpha_enabled(netuid, true);
+
+        let mut rng = StdRng::seed_from_u64(327);
+        for _ in 0..1_000 {
+            let mut bounds = [rng.gen_range(1..=u16::MAX), rng.gen_range(1..=u16::MAX)];
+            bounds.sort_unstable();
+            SubtensorModule::set_liquid_alpha_bounds(bounds[0], bounds[1]);
+            let alpha_low: u16 = rng.gen_range(0..=u16::MAX);
+            let alpha_high: u16 = rng.gen_range(0..=u16::MAX);
+
+            let result = SubtensorModule::do_set_alpha_values(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                alpha_low,
+                alpha_high,
+            );
+            let within_bounds =
+                bounds[0] <= alpha_low && alpha_low <= alpha_high && alpha_high <= bounds[1];
+            assert_eq!(result.is_ok(), within_bounds);
+            if within_bounds {
+                assert_eq!(
+                    SubtensorModule::get_alpha_values(netuid),
+                    (alpha_low, alpha_high)
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn test_stale_weights_earn_no_dividends() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        let n: u16 = 4;
+        let netuid = NetUid::from(1);
+        let block_number: u64 = System::block_number();
+        let stake: u64 = 1;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+
+        // === Register [validator1, validator2, server1, server2]
+        for key in 0..n as u64 {
+            SubtensorModule::add_balance_to_coldkey_account(&U256::from(key), stake);
+            let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+                netuid,
+                block_number,
+                key * 1_000_000,
+                &U256::from(key),
+            );
+            assert_ok!(SubtensorModule::register(
+                RuntimeOrigin::signed(U256::from(key)),
+                netuid,
+                block_number,
+                nonce,
+                work,
+                U256::from(key),
+                U256::from(key)
+            ));
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(key),
+                &U256::from(key),
+                netuid,
+                stake.into(),
+            );
+        }
+
+        // === Issue validator permits and set weights
+        SubtensorModule::set_max_allowed_validators(netuid, n);
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        next_block_no_epoch(netuid);
+        for uid in 0..(n / 2) as u64 {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(uid)),
+                netuid,
+                ((n / 2)..n).collect(),
+                vec![u16::MAX / (n / 2); (n / 2) as usize],
+                0
+            ));
+        }
+
+        // === Only uid 0 keeps its weights fresh
+        SubtensorModule::set_max_weight_age(netuid, 10);
+        run_to_block_no_epoch(netuid, 20);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(U256::from(0)),
+            netuid,
+            ((n / 2)..n).collect(),
+            vec![u16::MAX / (n / 2); (n / 2) as usize],
+            0
+        ));
+        assert_eq!(
+            SubtensorModule::get_stale_weights(
+                netuid,
+                &SubtensorModule::get_last_update(netuid),
+                SubtensorModule::get_current_block_as_u64()
+            ),
+            vec![false, true, false, false]
+        );
+        let info = SubtensorModule::get_stale_weights_info(netuid).unwrap();
+        assert_eq!(info.encode(), {
+            let stale_uids: Vec<codec::Compact<u16>> = vec![1.into()];
+            (
+                codec::Compact(netuid),
+                codec::Compact(SubtensorModule::get_current_block_as_u64()),
+                codec::Compact(10u64),
+                stale_uids,
+            )
+                .encode()
+        });
+
+        // === The stale validator earns no dividends
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert_eq!(SubtensorModule::get_dividends_for_uid(netuid, 0), u16::MAX);
+        assert_eq!(SubtensorModule::get_dividends_for_uid(netuid, 1), 0);
+
+        // === Without a maximum weight age, old weights count again
+        SubtensorModule::set_max_weight_age(netuid, 0);
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        assert!(SubtensorModule::get_dividends_for_uid(netuid, 1) > 0);
+    });
+}
+
+// Test that only the selected rows of weights and bonds are read, matching the full matrices on
+// those rows.
+#[test]
+fn test_get_weights_and_bonds_sparse_of_rows() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let n: u16 = 4;
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        for uid in 0..n {
+            register_ok_neuron(netuid, U256::from(uid), U256::from(uid), 0);
+        }
+        for uid in 0..n {
+            Weights::<Test>::insert(netuid, uid, vec![(uid, u16::MAX), ((uid + 1) % n, 1)]);
+            Bonds::<Test>::insert(netuid, uid, vec![((uid + 1) % n, u16::MAX)]);
+        }
+
+        let rows = vec![true, false, true, false];
+        let weights = SubtensorModule::get_weights_sparse(netuid);
+        let weights_of_rows = SubtensorModule::get_weights_sparse_of_rows(netuid, &rows);
+        let bonds = SubtensorModule::get_bonds_sparse(netuid);
+        let bonds_of_rows = SubtensorModule::get_bonds_sparse_of_rows(netuid, &rows);
+        assert_eq!(weights_of_rows.len(), n as usize);
+        assert_eq!(bonds_of_rows.len(), n as usize);
+        for (uid, selected) in rows.iter().enumerate() {
+            if *selected {
+                assert_eq!(weights_of_rows[uid], weights[uid]);
+                assert_eq!(bonds_of_rows[uid], bonds[uid]);
+            } else {
+                assert!(weights_of_rows[uid].is_empty());
+                assert!(bonds_of_rows[uid].is_empty());
+            }
+        }
+    });
+}
+
+// Test that the epoch dry run reports what the epoch would set and emit, without writing storage.
+#[test]
+fn test_epoch_dry_run() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let n: u16 = 4;
+        let emission = AlphaCurrency::from(1_000_000_000);
+        assert!(SubtensorModule::epoch_dry_run(netuid).is_none());
+
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_max_allowed_validators(netuid, n);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        for uid in 0..n {
+            register_ok_neuron(
+                netuid,
+                U256::from(uid),
+                U256::from(uid),
+                uid as u64 * 100_000,
+            );
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(uid),
+                &U256::from(uid),
+                netuid,
+                (1_000 * (uid as u64 + 1)).into(),
+            );
+        }
+        SubtensorModule::epoch(netuid, emission);
+        let block = next_block_no_epoch(netuid);
+        for uid in 0..n {
+            Weights::<Test>::insert(netuid, uid, vec![(2, u16::MAX), (3, u16::MAX / 2)]);
+            SubtensorModule::set_last_update_for_uid(netuid, uid, block);
+        }
+        PendingEmission::<Test>::insert(netuid, emission);
+
+        let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+        let dry_run = SubtensorModule::epoch_dry_run(netuid).unwrap();
+        assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+        assert_eq!(dry_run.emission, emission.into());
+
+        // The epoch sets and emits what the dry run reported.
+        let hotkey_emission = SubtensorModule::epoch(netuid, emission);
+        let compact = |values: Vec<u16>| -> Vec<codec::Compact<u16>> {
+            values.into_iter().map(Into::into).collect()
+        };
+        assert_eq!(
+            dry_run.hotkeys,
+            hotkey_emission
+                .iter()
+                .map(|(hotkey, _, _)| *hotkey)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            dry_run.incentive_emission,
+            hotkey_emission
+                .iter()
+                .map(|(_, incentive, _)| (*incentive).into())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            dry_run.dividend_emission,
+            hotkey_emission
+                .iter()
+                .map(|(_, _, dividend)| (*dividend).into())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            dry_run.incentive,
+            compact(SubtensorModule::get_incentive(netuid))
+        );
+        assert_eq!(
+            dry_run.dividends,
+            compact(SubtensorModule::get_dividends(netuid))
+        );
+        assert_eq!(
+            dry_run.validator_permit,
+            SubtensorModule::get_validator_permit(netuid)
+        );
+        assert!(dry_run.incentive.iter().any(|i| i.0 > 0));
+    });
+}
+
+// Test that the epoch history is written to the offchain index.
+#[test]
+fn test_index_epoch_history() {
+    use crate::epoch::history::{EpochHistoryKey, EpochMatrices, EpochMatricesSummary};
+    use codec::{Decode, Encode};
+    use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+    let netuid = NetUid::from(1);
+    let mut ext = new_test_ext(1);
+    ext.execute_with(|| {
+        add_network(netuid, 1, 0);
+        SubnetworkN::<Test>::insert(netuid, 3);
+        Weights::<Test>::insert(netuid, 0, vec![(1, u16::MAX), (2, u16::MAX / 2)]);
+        Weights::<Test>::insert(netuid, 2, vec![(1, u16::MAX)]);
+        Bonds::<Test>::insert(netuid, 0, vec![(1, 100)]);
+
+        SubtensorModule::index_epoch_history(netuid, 10);
+        SubtensorModule::index_epochs(10, &[netuid]);
+        SubtensorModule::index_epochs(11, &[]);
+    });
+    ext.persist_offchain_overlay();
+
+    let db = ext.offchain_db();
+    let get = |key: EpochHistoryKey| db.get(STORAGE_PREFIX, &key.key());
+    assert_eq!(
+        get(EpochHistoryKey::Matrices(netuid, 10)),
+        Some(
+            EpochMatrices {
+                weights: vec![
+                    vec![(1, u16::MAX), (2, u16::MAX / 2)],
+                    vec![],
+                    vec![(1, u16::MAX)]
+                ],
+                bonds: vec![vec![(1, 100)], vec![], vec![]],
+            }
+            .encode()
+        )
+    );
+    let summary = get(EpochHistoryKey::Summary(netuid, 10)).unwrap();
+    assert_eq!(
+        EpochMatricesSummary::decode(&mut &summary[..]).unwrap(),
+        EpochMatricesSummary {
+            num_uids: 3,
+            weight_rows: 2,
+            weight_entries: 3,
+            bond_entries: 1,
+        }
+    );
+    assert_eq!(
+        get(EpochHistoryKey::Epochs(10)),
+        Some(vec![netuid].encode())
+    );
+    assert_eq!(get(EpochHistoryKey::Epochs(11)), None);
+}
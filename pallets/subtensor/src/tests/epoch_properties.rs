@@ -0,0 +1,179 @@
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
+
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use sp_core::U256;
+use subtensor_runtime_common::NetUid;
+
+use super::mock::*;
+use crate::*;
+
+const RAO_EMISSION: u64 = 1_000_000_000;
+
+/// A random subnet: the stake of every hotkey, the weights they set and the hyperparameters.
+#[derive(Clone, Debug)]
+struct Subnet {
+    stakes: Vec<u64>,
+    weights: Vec<Vec<u16>>,
+    kappa: u16,
+    bonds_moving_average: u64,
+    bonds_penalty: u16,
+    yuma3_enabled: bool,
+}
+
+fn subnet() -> impl Strategy<Value = Subnet> {
+    (2_usize..=8)
+        .prop_flat_map(|n| {
+            (
+                prop::collection::vec(0_u64..=1_000_000_000_000, n),
+                prop::collection::vec(prop::collection::vec(any::<u16>(), n), n),
+                any::<u16>(),
+                0_u64..=1_000_000,
+                any::<u16>(),
+                any::<bool>(),
+            )
+        })
+        .prop_map(
+            |(stakes, weights, kappa, bonds_moving_average, bonds_penalty, yuma3_enabled)| Subnet {
+                stakes,
+                weights,
+                kappa,
+                bonds_moving_average,
+                bonds_penalty,
+                yuma3_enabled,
+            },
+        )
+}
+
+/// A random subnet with a permutation of its hotkeys.
+fn subnet_and_order() -> impl Strategy<Value = (Subnet, Vec<usize>)> {
+    subnet().prop_flat_map(|subnet| {
+        let order = Just((0..subnet.stakes.len()).collect::<Vec<_>>()).prop_shuffle();
+        (Just(subnet), order)
+    })
+}
+
+fn hotkey(key: usize) -> U256 {
+    U256::from(1_000 + key)
+}
+
+/// Registers the hotkeys of `subnet` in `order`, so that hotkey `order[uid]` gets `uid`, sets
+/// their stake and weights, and runs the epoch twice, the first one issuing the validator
+/// permits. Returns the server and validator emission of every hotkey.
+fn run_epoch(subnet: &Subnet, order: &[usize]) -> BTreeMap<U256, (u64, u64)> {
+    let netuid = NetUid::from(1);
+    let n = subnet.stakes.len() as u16;
+
+    add_network(netuid, 1, 0);
+    SubtensorModule::set_max_allowed_uids(netuid, n);
+    SubtensorModule::set_max_allowed_validators(netuid, n);
+    SubtensorModule::set_max_registrations_per_block(netuid, n);
+    SubtensorModule::set_target_registrations_per_interval(netuid, n);
+    SubtensorModule::set_stake_threshold(0);
+    SubtensorModule::set_kappa(netuid, subnet.kappa);
+    SubtensorModule::set_bonds_moving_average(netuid, subnet.bonds_moving_average);
+    SubtensorModule::set_bonds_penalty(netuid, subnet.bonds_penalty);
+    SubtensorModule::set_yuma3_enabled(netuid, subnet.yuma3_enabled);
+
+    for (nonce, &key) in order.iter().enumerate() {
+        register_ok_neuron(netuid, hotkey(key), hotkey(key), nonce as u64 * 100_000);
+    }
+    for (key, &stake) in subnet.stakes.iter().enumerate() {
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey(key),
+            &hotkey(key),
+            netuid,
+            stake.into(),
+        );
+    }
+
+    // Set the weights in the block after registration, so that none of them is outdated.
+    let block = next_block_no_epoch(netuid);
+    let uid = |key: usize| SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey(key));
+    for (key, row) in subnet.weights.iter().enumerate() {
+        let uid_i = uid(key).unwrap();
+        let row: Vec<(u16, u16)> = row
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| **w > 0)
+            .map(|(key_j, w)| (uid(key_j).unwrap(), *w))
+            .collect();
+        Weights::<Test>::insert(netuid, uid_i, row);
+        SubtensorModule::set_last_update_for_uid(netuid, uid_i, block);
+    }
+
+    SubtensorModule::epoch(netuid, RAO_EMISSION.into());
+    SubtensorModule::epoch(netuid, RAO_EMISSION.into())
+        .into_iter()
+        .map(|(hotkey, server, validator)| (hotkey, (server.into(), validator.into())))
+        .collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // The epoch distributes the whole emission, up to rounding, whenever any hotkey has stake.
+    #[test]
+    fn epoch_conserves_emission(subnet in subnet()) {
+        new_test_ext(1).execute_with(|| -> Result<(), TestCaseError> {
+            let order: Vec<usize> = (0..subnet.stakes.len()).collect();
+            let emission = run_epoch(&subnet, &order);
+
+            let total: u64 = emission.values().map(|(server, validator)| server + validator).sum();
+            prop_assert!(total <= RAO_EMISSION, "emitted {total} > {RAO_EMISSION}");
+            if subnet.stakes.iter().any(|&stake| stake > 0) {
+                // Each of the n server and n validator emissions rounds down by less than one
+                // rao, and the normalized vectors are truncated to 32 fractional bits.
+                let tolerance = 2 * emission.len() as u64 + RAO_EMISSION / 1_000_000;
+                prop_assert!(
+                    total + tolerance >= RAO_EMISSION,
+                    "emitted {total}, expected {RAO_EMISSION}"
+                );
+            } else {
+                prop_assert_eq!(total, 0);
+            }
+            Ok(())
+        })?;
+    }
+
+    // Dividends only go to hotkeys with stake and a validator permit.
+    #[test]
+    fn epoch_pays_dividends_to_validators_only(subnet in subnet()) {
+        new_test_ext(1).execute_with(|| -> Result<(), TestCaseError> {
+            let netuid = NetUid::from(1);
+            let order: Vec<usize> = (0..subnet.stakes.len()).collect();
+            let emission = run_epoch(&subnet, &order);
+            let dividends = SubtensorModule::get_dividends(netuid);
+            let permits = SubtensorModule::get_validator_permit(netuid);
+
+            for (key, &stake) in subnet.stakes.iter().enumerate() {
+                let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey(key))
+                    .unwrap() as usize;
+                let (_, validator) = emission[&hotkey(key)];
+                if stake == 0 {
+                    prop_assert_eq!(validator, 0, "hotkey {} without stake earned dividends", key);
+                    prop_assert_eq!(dividends[uid], 0);
+                }
+                if !permits[uid] {
+                    prop_assert_eq!(dividends[uid], 0, "uid {} without permit earned dividends", uid);
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    // Relabeling the uids relabels the outcome: every hotkey earns the same emission whatever
+    // uid it was registered at.
+    #[test]
+    fn epoch_is_permutation_invariant((subnet, order) in subnet_and_order()) {
+        let identity: Vec<usize> = (0..subnet.stakes.len()).collect();
+        let expected = new_test_ext(1).execute_with(|| run_epoch(&subnet, &identity));
+        let permuted = new_test_ext(1).execute_with(|| run_epoch(&subnet, &order));
+        prop_assert_eq!(expected, permuted);
+    }
+}
@@ -5,9 +5,11 @@
 )]
 
 use super::mock::*;
+use crate::subnets::evm_gas_sponsorship::EVM_GAS_SPONSORSHIP_PERIOD;
 use crate::*;
 use frame_support::testing_prelude::*;
 use sp_core::{H160, Pair, U256, blake2_256, ecdsa, keccak_256};
+use subtensor_runtime_common::TaoCurrency;
 
 fn public_to_evm_key(pubkey: &ecdsa::Public) -> H160 {
     use libsecp256k1::PublicKey;
@@ -199,3 +201,264 @@ fn test_associate_evm_key_using_wrong_hash_function() {
         );
     });
 }
+
+#[test]
+fn test_deposit_withdraw_and_claim_evm_tao() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let evm_address = H160::from_low_u64_be(42);
+        let evm_account = SubtensorModule::evm_address_account(evm_address);
+        let delay = 10;
+        assert_ok!(SubtensorModule::set_evm_withdrawal_delay(delay));
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        // The EVM address already holds TAO from outside the bridge.
+        SubtensorModule::add_balance_to_coldkey_account(&evm_account, 50_000);
+        let issuance_before = SubtensorModule::get_total_issuance();
+
+        // The deposit moves the TAO to the account of the EVM address.
+        assert_ok!(SubtensorModule::deposit_to_evm(
+            RuntimeOrigin::signed(coldkey),
+            evm_address,
+            400_000.into()
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 600_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&evm_account), 450_000);
+        // The locked total is read from the balance, so it includes the TAO from outside the bridge.
+        assert_eq!(SubtensorModule::get_total_evm_locked(), 450_000.into());
+
+        // Only the account of the EVM address can withdraw from it.
+        assert_noop!(
+            SubtensorModule::withdraw_from_evm(
+                RuntimeOrigin::signed(coldkey),
+                evm_address,
+                coldkey,
+                100_000.into()
+            ),
+            Error::<Test>::EvmAddressNotCaller
+        );
+
+        // The withdrawal waits out the delay in the queue of the coldkey.
+        assert_ok!(SubtensorModule::withdraw_from_evm(
+            RuntimeOrigin::signed(evm_account),
+            evm_address,
+            coldkey,
+            100_000.into()
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&evm_account), 350_000);
+        assert_eq!(SubtensorModule::get_total_evm_locked(), 350_000.into());
+        assert_eq!(TotalEvmWithdrawals::<Test>::get(), 100_000.into());
+        assert_eq!(
+            SubtensorModule::get_evm_withdrawal_balance(&coldkey),
+            (100_000.into(), TaoCurrency::ZERO)
+        );
+        assert_noop!(
+            SubtensorModule::claim_evm_withdrawals(RuntimeOrigin::signed(coldkey)),
+            Error::<Test>::NoReleasedEvmWithdrawal
+        );
+
+        step_block(delay as u16);
+        assert_ok!(SubtensorModule::claim_evm_withdrawals(
+            RuntimeOrigin::signed(coldkey)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 700_000);
+        assert!(EvmWithdrawalQueue::<Test>::get(coldkey).is_empty());
+        assert_eq!(TotalEvmWithdrawals::<Test>::get(), TaoCurrency::ZERO);
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance_before);
+        assert_ok!(SubtensorModule::check_total_evm_withdrawals());
+    });
+}
+
+fn sponsor_consent(
+    pair: &ecdsa::Pair,
+    netuid: NetUid,
+    cap: TaoCurrency,
+    deadline: u64,
+) -> ecdsa::Signature {
+    sign_evm_message(
+        pair,
+        SubtensorModule::get_evm_gas_sponsor_consent_message(
+            netuid,
+            public_to_evm_key(&pair.public()),
+            cap,
+            deadline,
+        ),
+    )
+}
+
+#[test]
+fn test_evm_gas_sponsorship() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 1, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        let pair = ecdsa::Pair::generate().0;
+        let sponsor = public_to_evm_key(&pair.public());
+        let contract = H160::from_low_u64_be(7);
+        let sender = H160::from_low_u64_be(8);
+        let cap = TaoCurrency::from(1_000);
+        let deadline = 100;
+
+        assert_ok!(SubtensorModule::set_evm_gas_sponsor(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            sponsor,
+            cap,
+            deadline,
+            sponsor_consent(&pair, netuid, cap, deadline),
+        ));
+        assert_ok!(SubtensorModule::set_evm_sponsored_contract(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            contract,
+            true
+        ));
+
+        // Only transactions calling the contract are sponsored.
+        SubtensorModule::set_evm_transaction_target(Some(sender));
+        assert_eq!(SubtensorModule::evm_transaction_sponsor(100.into()), None);
+        SubtensorModule::set_evm_transaction_target(Some(contract));
+        assert_eq!(
+            SubtensorModule::evm_transaction_sponsor(100.into()),
+            Some((netuid, sponsor))
+        );
+
+        // The sponsor pays up to its cap in a period.
+        SubtensorModule::record_evm_gas_sponsored(netuid, sender, 700.into());
+        System::assert_last_event(
+            Event::EvmGasSponsored {
+                netuid,
+                sponsor,
+                sender,
+                fee: 700.into(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_evm_gas_sponsor_allowance(netuid),
+            300.into()
+        );
+        assert_eq!(SubtensorModule::evm_transaction_sponsor(400.into()), None);
+
+        // The cap applies again in the next period.
+        System::set_block_number(1 + EVM_GAS_SPONSORSHIP_PERIOD);
+        assert_eq!(SubtensorModule::get_evm_gas_sponsor_allowance(netuid), cap);
+        assert_eq!(
+            SubtensorModule::evm_transaction_sponsor(400.into()),
+            Some((netuid, sponsor))
+        );
+
+        assert_ok!(SubtensorModule::remove_evm_gas_sponsor(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::evm_transaction_sponsor(1.into()), None);
+        SubtensorModule::set_evm_transaction_target(None);
+    });
+}
+
+#[test]
+fn test_evm_gas_sponsor_requires_consent() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 1, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        let pair = ecdsa::Pair::generate().0;
+        let sponsor = public_to_evm_key(&pair.public());
+        let cap = TaoCurrency::from(1_000);
+        let signature = sponsor_consent(&pair, netuid, cap, 100);
+
+        assert_noop!(
+            SubtensorModule::set_evm_gas_sponsor(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                sponsor,
+                cap,
+                100,
+                signature.clone(),
+            ),
+            DispatchError::BadOrigin
+        );
+        // The consent binds the cap.
+        assert_noop!(
+            SubtensorModule::set_evm_gas_sponsor(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                sponsor,
+                cap + 1.into(),
+                100,
+                signature.clone(),
+            ),
+            Error::<Test>::InvalidSponsorConsent
+        );
+
+        // The consent cannot be replayed once used.
+        assert_ok!(SubtensorModule::set_evm_gas_sponsor(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            sponsor,
+            cap,
+            100,
+            signature.clone(),
+        ));
+        assert_eq!(EvmGasSponsorConsentNonce::<Test>::get(sponsor), 1);
+        assert_noop!(
+            SubtensorModule::set_evm_gas_sponsor(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                sponsor,
+                cap,
+                100,
+                signature,
+            ),
+            Error::<Test>::InvalidSponsorConsent
+        );
+
+        let signature = sponsor_consent(&pair, netuid, cap, 100);
+        System::set_block_number(101);
+        assert_noop!(
+            SubtensorModule::set_evm_gas_sponsor(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                sponsor,
+                cap,
+                100,
+                signature,
+            ),
+            Error::<Test>::SponsorConsentExpired
+        );
+    });
+}
+
+#[test]
+fn test_evm_sponsored_contract_belongs_to_one_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let contract = H160::from_low_u64_be(7);
+        for (netuid, owner) in [(1, 1), (2, 2)] {
+            add_network(NetUid::from(netuid), 1, 0);
+            SubnetOwner::<Test>::insert(NetUid::from(netuid), U256::from(owner));
+        }
+
+        assert_ok!(SubtensorModule::set_evm_sponsored_contract(
+            RuntimeOrigin::signed(U256::from(1)),
+            NetUid::from(1),
+            contract,
+            true
+        ));
+        assert_noop!(
+            SubtensorModule::set_evm_sponsored_contract(
+                RuntimeOrigin::signed(U256::from(2)),
+                NetUid::from(2),
+                contract,
+                true
+            ),
+            Error::<Test>::EvmContractSponsoredByOtherSubnet
+        );
+
+        SubtensorModule::remove_network(NetUid::from(1));
+        assert_eq!(EvmSponsoredContracts::<Test>::get(contract), None);
+    });
+}
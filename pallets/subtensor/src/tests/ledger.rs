@@ -0,0 +1,153 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_core::U256;
+use subtensor_runtime_common::TaoCurrency;
+
+use super::mock::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use crate::*;
+
+#[test]
+fn test_transfer_tao_balances_every_debit() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let issuance_before = SubtensorModule::get_total_issuance();
+        let stake_before = SubtensorModule::get_total_stake();
+
+        // Debiting the issuance mints.
+        assert_ok!(SubtensorModule::transfer_tao(
+            LedgerAccount::Issuance,
+            LedgerAccount::Coldkey(coldkey),
+            1_000_000.into(),
+            LedgerReason::Faucet,
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 1_000_000);
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + 1_000_000.into()
+        );
+
+        // Moving it into stake leaves the issuance untouched.
+        assert_eq!(
+            SubtensorModule::transfer_tao(
+                LedgerAccount::Coldkey(coldkey),
+                LedgerAccount::Stake,
+                400_000.into(),
+                LedgerReason::Stake,
+            ),
+            Ok(400_000.into())
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 600_000);
+        assert_eq!(
+            SubtensorModule::get_total_stake(),
+            stake_before + 400_000.into()
+        );
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + 1_000_000.into()
+        );
+        System::assert_last_event(
+            Event::TaoTransferred {
+                from: LedgerAccount::Coldkey(coldkey),
+                to: LedgerAccount::Stake,
+                amount: 400_000.into(),
+                reason: LedgerReason::Stake,
+            }
+            .into(),
+        );
+
+        // Crediting the issuance burns.
+        assert_ok!(SubtensorModule::transfer_tao(
+            LedgerAccount::Stake,
+            LedgerAccount::Issuance,
+            400_000.into(),
+            LedgerReason::NetworkDissolution,
+        ));
+        assert_eq!(SubtensorModule::get_total_stake(), stake_before);
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + 600_000.into()
+        );
+    });
+}
+
+#[test]
+fn test_transfer_tao_from_empty_coldkey_fails() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        assert_noop!(
+            SubtensorModule::transfer_tao(
+                LedgerAccount::Coldkey(coldkey),
+                LedgerAccount::Stake,
+                1_000.into(),
+                LedgerReason::Stake,
+            ),
+            Error::<Test>::ZeroBalanceAfterWithdrawn
+        );
+
+        // Nothing to move is a no-op.
+        assert_eq!(
+            SubtensorModule::transfer_tao(
+                LedgerAccount::Coldkey(coldkey),
+                LedgerAccount::Stake,
+                TaoCurrency::ZERO,
+                LedgerReason::Stake,
+            ),
+            Ok(TaoCurrency::ZERO)
+        );
+    });
+}
+
+#[test]
+fn test_add_and_remove_stake_go_through_the_ledger() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        let amount = TaoCurrency::from(1_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount.into());
+        let stake_before = SubtensorModule::get_total_stake();
+
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount
+        ));
+        System::assert_has_event(
+            Event::TaoTransferred {
+                from: LedgerAccount::Coldkey(coldkey),
+                to: LedgerAccount::Stake,
+                amount,
+                reason: LedgerReason::Stake,
+            }
+            .into(),
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), stake_before + amount);
+
+        remove_stake_rate_limit_for_tests(&hotkey, &coldkey, netuid);
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha
+        ));
+        let paid_out = TaoCurrency::from(SubtensorModule::get_coldkey_balance(&coldkey));
+        System::assert_has_event(
+            Event::TaoTransferred {
+                from: LedgerAccount::Stake,
+                to: LedgerAccount::Coldkey(coldkey),
+                amount: paid_out,
+                reason: LedgerReason::Unstake,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake(),
+            stake_before + amount - paid_out
+        );
+    });
+}
@@ -55,6 +55,23 @@ fn test_initialise_ti() {
     });
 }
 
+#[test]
+fn test_init_total_issuance_counts_evm_withdrawals() {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    new_test_ext(1).execute_with(|| {
+        pallet_balances::TotalIssuance::<Test>::put(1000);
+        crate::TotalEvmWithdrawals::<Test>::put(TaoCurrency::from(50));
+
+        crate::migrations::migrate_init_total_issuance::initialise_total_issuance::Migration::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            crate::TotalIssuance::<Test>::get(),
+            TaoCurrency::from(1000 + 50)
+        );
+    });
+}
+
 #[test]
 fn test_migration_transfer_nets_to_foundation() {
     new_test_ext(1).execute_with(|| {
@@ -1132,3 +1149,48 @@ fn test_migrate_disable_commit_reveal() {
         );
     });
 }
+
+#[test]
+fn test_migrate_axons_to_v2() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &str = "migrate_axons_to_v2";
+        let netuid = NetUid::from(1);
+        let (hotkey, served_hotkey) = (U256::from(1), U256::from(2));
+        let axon_info = AxonInfo {
+            block: 5,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        };
+        Axons::<Test>::insert(netuid, hotkey, axon_info.clone());
+
+        // An axon already served with v2 is left as is.
+        let served = AxonInfoV2 {
+            protocol_version: 3,
+            ..AxonInfoV2::from(axon_info.clone())
+        };
+        Axons::<Test>::insert(netuid, served_hotkey, axon_info.clone());
+        AxonsV2::<Test>::insert(netuid, served_hotkey, served.clone());
+
+        let weight = crate::migrations::migrate_axons_to_v2::migrate_axons_to_v2::<Test>();
+
+        assert!(HasMigrationRun::<Test>::get(
+            MIGRATION_NAME.as_bytes().to_vec()
+        ));
+        assert!(!weight.is_zero(), "Migration weight should be non-zero");
+        let migrated = AxonsV2::<Test>::get(netuid, hotkey).unwrap();
+        assert_eq!(migrated.block, axon_info.block);
+        assert_eq!(migrated.version, axon_info.version);
+        assert_eq!(migrated.protocol_version, 0);
+        assert_eq!(migrated.tls_fingerprint, None);
+        assert_eq!(
+            migrated.endpoints.into_inner(),
+            vec![AxonEndpoint::from(&axon_info)]
+        );
+        assert_eq!(AxonsV2::<Test>::get(netuid, served_hotkey), Some(served));
+    });
+}
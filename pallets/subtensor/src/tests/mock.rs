@@ -16,11 +16,11 @@ use frame_support::{
 use frame_system as system;
 use frame_system::{EnsureNever, EnsureRoot, RawOrigin, limits, offchain::CreateTransactionBase};
 use pallet_collective::MemberCount;
-use sp_core::{ConstU64, Get, H256, U256, offchain::KeyTypeId};
+use sp_core::{ConstU64, Get, H160, H256, U256, offchain::KeyTypeId};
 use sp_runtime::Perbill;
 use sp_runtime::{
     BuildStorage,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Convert, IdentityLookup},
 };
 use sp_std::{cell::RefCell, cmp::Ordering};
 use subtensor_runtime_common::{NetUid, TaoCurrency};
@@ -453,6 +453,8 @@ impl crate::Config for Test {
     type KeySwapOnSubnetCost = InitialKeySwapOnSubnetCost;
     type HotkeySwapOnSubnetInterval = HotkeySwapOnSubnetInterval;
     type ProxyInterface = FakeProxier;
+    type EventBridge = ();
+    type EvmAddressMapping = EvmAddressToAccount;
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
 }
 
@@ -531,6 +533,14 @@ thread_local! {
     pub static PROXIES: RefCell<FakeProxier> = const { RefCell::new(FakeProxier(vec![])) };
 }
 
+pub struct EvmAddressToAccount;
+
+impl Convert<H160, U256> for EvmAddressToAccount {
+    fn convert(evm_address: H160) -> U256 {
+        U256::from_big_endian(evm_address.as_bytes())
+    }
+}
+
 pub struct FakeProxier(pub Vec<(U256, U256)>);
 
 impl ProxyInterface<U256> for FakeProxier {
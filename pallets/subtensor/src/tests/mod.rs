@@ -6,8 +6,10 @@ mod delegate_info;
 mod difficulty;
 mod emission;
 mod epoch;
+mod epoch_properties;
 mod evm;
 mod leasing;
+mod ledger;
 mod math;
 mod migration;
 mod mock;
@@ -18,11 +20,13 @@ mod recycle_alpha;
 mod registration;
 mod senate;
 mod serving;
+mod slashing;
 mod staking;
 mod staking2;
 mod subnet;
 mod swap_coldkey;
 mod swap_hotkey;
 mod swap_hotkey_with_subnet;
+mod try_state;
 mod uids;
 mod weights;
@@ -75,6 +75,62 @@ fn test_do_move_success() {
     });
 }
 
+#[test]
+fn test_do_move_respects_delegate_stake_cap() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10.into();
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &origin_hotkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &destination_hotkey);
+        SubtensorModule::stake_into_subnet(
+            &origin_hotkey,
+            &coldkey,
+            netuid,
+            stake_amount,
+            <Test as Config>::SwapInterface::max_price().into(),
+            false,
+        )
+        .unwrap();
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &origin_hotkey,
+            &coldkey,
+            netuid,
+        );
+
+        // The destination delegate does not accept that much stake
+        DelegateStakeCap::<Test>::insert(destination_hotkey, stake_amount / 2.into());
+        assert_noop!(
+            SubtensorModule::do_move_stake(
+                RuntimeOrigin::signed(coldkey),
+                origin_hotkey,
+                destination_hotkey,
+                netuid,
+                netuid,
+                alpha,
+            ),
+            Error::<Test>::DelegateStakeCapExceeded
+        );
+
+        // The origin delegate's own cap does not restrict moving stake away from it
+        DelegateStakeCap::<Test>::insert(origin_hotkey, TaoCurrency::ZERO);
+        DelegateStakeCap::<Test>::remove(destination_hotkey);
+        assert_ok!(SubtensorModule::do_move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            netuid,
+            netuid,
+            alpha,
+        ));
+    });
+}
+
 // 2. test_do_move_different_subnets
 // Description: Test moving stake between two hotkeys in different subnets
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test move -- test_do_move_different_subnets --exact --nocapture
@@ -1899,3 +1955,80 @@ fn test_swap_stake_limits_destination_netuid() {
         )));
     });
 }
+
+#[test]
+fn test_move_stake_keeps_origin_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let origin_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let destination_netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let coldkey = U256::from(1);
+        let origin_hotkey = U256::from(2);
+        let destination_hotkey = U256::from(3);
+        let stake_amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+        let unbonding_period = 100;
+
+        mock::setup_reserves(
+            origin_netuid,
+            (stake_amount * 100).into(),
+            (stake_amount * 100).into(),
+        );
+        mock::setup_reserves(
+            destination_netuid,
+            (stake_amount * 100).into(),
+            (stake_amount * 100).into(),
+        );
+        SubtensorModule::set_unbonding_period(origin_netuid, unbonding_period);
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &origin_hotkey);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &destination_hotkey);
+        SubtensorModule::stake_into_subnet(
+            &origin_hotkey,
+            &coldkey,
+            origin_netuid,
+            stake_amount.into(),
+            <Test as Config>::SwapInterface::max_price().into(),
+            false,
+        )
+        .unwrap();
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &origin_hotkey,
+            &coldkey,
+            origin_netuid,
+        );
+
+        // Moving to a subnet without unbonding period carries the origin's period over.
+        assert_ok!(SubtensorModule::do_move_stake(
+            RuntimeOrigin::signed(coldkey),
+            origin_hotkey,
+            destination_hotkey,
+            origin_netuid,
+            destination_netuid,
+            alpha,
+        ));
+        let release_block = SubtensorModule::get_current_block_as_u64() + unbonding_period;
+        assert_eq!(
+            UnbondingFloor::<Test>::get(destination_netuid, coldkey),
+            release_block
+        );
+
+        // Unstaking from the destination queues the TAO until the origin's period has passed.
+        next_block();
+        let moved = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &destination_hotkey,
+            &coldkey,
+            destination_netuid,
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            destination_hotkey,
+            destination_netuid,
+            moved
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+        let queue = UnbondingQueue::<Test>::get(coldkey);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].0, release_block);
+    });
+}
@@ -3,7 +3,8 @@ use crate::*;
 use frame_support::assert_ok;
 use frame_system::Config;
 use sp_core::U256;
-use subtensor_runtime_common::TaoCurrency;
+use sp_runtime::DispatchError;
+use subtensor_runtime_common::{AlphaCurrency, TaoCurrency};
 
 #[test]
 fn test_registration_ok() {
@@ -333,6 +334,37 @@ fn test_register_subnet_high_lock_cost() {
     })
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::networks::test_network_lock_cost_projection --exact --show-output --nocapture
+#[test]
+fn test_network_lock_cost_projection() {
+    new_test_ext(1).execute_with(|| {
+        NetworkMinLockCost::<Test>::set(TaoCurrency::from(1_000));
+        NetworkLastLockCost::<Test>::set(TaoCurrency::from(1_000_000));
+        NetworkLastRegistered::<Test>::set(1);
+        NetworkLockReductionInterval::<Test>::set(1_000);
+
+        let projection = SubtensorModule::get_network_lock_cost_projection(10);
+        assert_eq!(projection.len(), 10);
+
+        // Each entry matches the cost at that future block and decays monotonically.
+        let current_block = SubtensorModule::get_current_block_as_u64();
+        for (offset, cost) in projection.iter().enumerate() {
+            assert_eq!(
+                *cost,
+                SubtensorModule::get_network_lock_cost_at(current_block + offset as u64 + 1)
+            );
+        }
+        assert!(projection.windows(2).all(|w| w[0] >= w[1]));
+        assert!(projection[0] <= SubtensorModule::get_network_lock_cost());
+
+        // Projection length is capped.
+        assert_eq!(
+            SubtensorModule::get_network_lock_cost_projection(u64::MAX).len() as u64,
+            MAX_LOCK_COST_PROJECTION_BLOCKS
+        );
+    })
+}
+
 #[test]
 fn test_tempo_greater_than_weight_set_rate_limit() {
     new_test_ext(1).execute_with(|| {
@@ -349,3 +381,134 @@ fn test_tempo_greater_than_weight_set_rate_limit() {
         assert!(tempo as u64 >= weights_set_rate_limit);
     })
 }
+
+#[test]
+fn test_dissolve_subnet_refunds_stakes_and_lock() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let staker = U256::from(3);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        let lock = SubtensorModule::get_subnet_locked_balance(netuid);
+        assert!(lock > TaoCurrency::ZERO);
+
+        let alpha = AlphaCurrency::from(1_000_000_000);
+        setup_reserves(
+            netuid,
+            TaoCurrency::from(10_000_000_000),
+            AlphaCurrency::from(10_000_000_000),
+        );
+        SubnetAlphaOut::<Test>::insert(netuid, alpha);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker,
+            netuid,
+            alpha,
+        );
+        let staker_balance = SubtensorModule::get_coldkey_balance(&staker);
+        let owner_balance = SubtensorModule::get_coldkey_balance(&owner_coldkey);
+
+        // Only the owner or root can dissolve the subnet, once
+        assert_eq!(
+            SubtensorModule::dissolve_subnet(RuntimeOrigin::signed(staker), netuid),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(SubtensorModule::dissolve_subnet(
+            RuntimeOrigin::root(),
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::dissolve_subnet(RuntimeOrigin::root(), netuid),
+            Err(Error::<Test>::NetworkDissolutionInProgress.into())
+        );
+        assert!(!SubtokenEnabled::<Test>::get(netuid));
+        assert!(FirstEmissionBlockNumber::<Test>::get(netuid).is_none());
+
+        for _ in 0..10 {
+            SubtensorModule::step_network_dissolution(netuid);
+        }
+        assert!(!SubtensorModule::is_network_dissolving(netuid));
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+
+        // The staker was paid for its alpha at the pool price
+        let refunded = SubtensorModule::get_coldkey_balance(&staker) - staker_balance;
+        assert!(refunded > 0 && refunded <= u64::from(alpha));
+        assert!(!Alpha::<Test>::contains_key((owner_hotkey, staker, netuid)));
+        assert_eq!(
+            TotalHotkeyAlpha::<Test>::get(owner_hotkey, netuid),
+            AlphaCurrency::ZERO
+        );
+        assert_eq!(SubnetTAO::<Test>::get(netuid), TaoCurrency::ZERO);
+
+        // Root dissolutions refund the lock to the owner
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&owner_coldkey),
+            owner_balance + u64::from(lock)
+        );
+        assert_eq!(
+            SubtensorModule::get_subnet_locked_balance(netuid),
+            TaoCurrency::ZERO
+        );
+    });
+}
+
+#[test]
+fn test_dissolve_subnet_by_owner_during_immunity_recycles_lock() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        let owner_balance = SubtensorModule::get_coldkey_balance(&owner_coldkey);
+
+        assert_ok!(SubtensorModule::dissolve_subnet(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid
+        ));
+        for _ in 0..10 {
+            SubtensorModule::step_network_dissolution(netuid);
+        }
+
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&owner_coldkey),
+            owner_balance
+        );
+    });
+}
+
+#[test]
+fn test_genesis_subnets_are_registered() {
+    use sp_runtime::BuildStorage;
+
+    let owner_coldkey = U256::from(1);
+    let owner_hotkey = U256::from(2);
+    let neurons = vec![
+        (U256::from(3), U256::from(4)),
+        (U256::from(5), U256::from(6)),
+    ];
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .expect("system genesis builds");
+    crate::GenesisConfig::<Test> {
+        subnets: vec![(owner_coldkey, owner_hotkey, neurons)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .expect("subtensor genesis builds");
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // Netuid 1 is the subnet every genesis starts with.
+        let netuid = NetUid::from(2);
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+        assert_eq!(SubnetOwner::<Test>::get(netuid), owner_coldkey);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), owner_hotkey);
+        assert_eq!(SubnetworkN::<Test>::get(netuid), 3);
+        assert_eq!(Uids::<Test>::get(netuid, owner_hotkey), Some(0));
+        assert_eq!(Uids::<Test>::get(netuid, U256::from(6)), Some(2));
+        assert_eq!(Owner::<Test>::get(U256::from(6)), U256::from(5));
+        assert_eq!(
+            SubnetTAO::<Test>::get(netuid),
+            SubtensorModule::get_network_min_lock()
+        );
+    });
+}
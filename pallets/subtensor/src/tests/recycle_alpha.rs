@@ -543,3 +543,58 @@ fn test_burn_errors() {
         );
     });
 }
+
+#[test]
+fn test_recycle_and_burn_update_subnet_supply_counters() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+
+        let owner_coldkey = U256::from(1001);
+        let owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        Balances::make_free_balance_be(&coldkey, 1_000_000_000);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        let stake = 200_000;
+        increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake.into(), netuid);
+
+        let initial_recycled = SubnetAlphaRecycled::<Test>::get(netuid);
+        let initial_burned = SubnetAlphaBurned::<Test>::get(netuid);
+        let amount = AlphaCurrency::from(stake / 4);
+
+        assert_ok!(SubtensorModule::recycle_alpha(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            amount,
+            netuid
+        ));
+        assert_eq!(
+            SubnetAlphaRecycled::<Test>::get(netuid),
+            initial_recycled + amount
+        );
+        assert_eq!(SubnetAlphaBurned::<Test>::get(netuid), initial_burned);
+
+        assert_ok!(SubtensorModule::burn_alpha(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            amount,
+            netuid
+        ));
+        assert_eq!(
+            SubnetAlphaRecycled::<Test>::get(netuid),
+            initial_recycled + amount
+        );
+        assert_eq!(
+            SubnetAlphaBurned::<Test>::get(netuid),
+            initial_burned + amount
+        );
+
+        // The counters are exposed through the supply info of existing subnets.
+        let supply_info = SubtensorModule::get_subnet_supply_info(netuid).unwrap();
+        assert!(SubtensorModule::get_all_subnet_supply_info().contains(&supply_info));
+        assert!(SubtensorModule::get_subnet_supply_info(NetUid::from(99)).is_none());
+    });
+}
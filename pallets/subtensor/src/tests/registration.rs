@@ -6,13 +6,20 @@ use frame_support::sp_runtime::{DispatchError, transaction_validity::Transaction
 use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::{Config, RawOrigin};
-use sp_core::U256;
+use sp_core::{Pair, U256, sr25519};
+use sp_runtime::Perbill;
 use sp_runtime::traits::{DispatchInfoOf, TransactionExtension, TxBaseImplication};
-use subtensor_runtime_common::{AlphaCurrency, Currency as CurrencyT, NetUid};
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::{AlphaCurrency, Currency as CurrencyT, NetUid, TaoCurrency};
 
 use super::mock;
 use super::mock::*;
-use crate::{AxonInfoOf, CustomTransactionError, Error, SubtensorTransactionExtension};
+use crate::subnets::sponsored_registration::SponsorConsent;
+use crate::{
+    AxonInfoOf, CustomTransactionError, Error, Event, RegistrationBids, RegistrationBurnPaid,
+    RegistrationPowAlgorithm, SponsorConsentNonce, SubnetTAO, SubtensorTransactionExtension,
+    TotalRegistrationBids, VacantUids,
+};
 
 /********************************************
     subscribing::subscribe() tests
@@ -48,6 +55,60 @@ fn test_registration_subscribe_ok_dispatch_info_ok() {
     });
 }
 
+#[test]
+fn test_registration_with_argon2_pow() {
+    new_test_ext(1).execute_with(|| {
+        let block_number: u64 = 0;
+        let netuid = NetUid::from(1);
+        let hotkey_account_id = U256::from(1);
+        let coldkey_account_id = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_difficulty(netuid, 10_000);
+        SubtensorModule::set_min_difficulty(netuid, 1);
+
+        // Switching the hash restarts the difficulty from the minimum.
+        SubtensorModule::set_registration_pow_algorithm(netuid, RegistrationPowAlgorithm::Argon2id);
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 1);
+
+        // Work done with the default hash is rejected.
+        let nonce: u64 = 7;
+        let work = SubtensorModule::create_seal_hash(block_number, nonce, &hotkey_account_id);
+        assert_noop!(
+            SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                block_number,
+                nonce,
+                SubtensorModule::hash_to_vec(work),
+                hotkey_account_id,
+                coldkey_account_id
+            ),
+            Error::<Test>::InvalidSeal
+        );
+
+        let (nonce, work): (u64, Vec<u8>) = SubtensorModule::create_work_for_block_number(
+            netuid,
+            block_number,
+            nonce,
+            &hotkey_account_id,
+        );
+        assert_eq!(
+            SubtensorModule::create_argon2_seal_hash(block_number, nonce, &hotkey_account_id),
+            SubtensorModule::vec_to_hash(work.clone())
+        );
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            block_number,
+            nonce,
+            work,
+            hotkey_account_id,
+            coldkey_account_id
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 1);
+    });
+}
+
 #[test]
 fn test_registration_difficulty() {
     new_test_ext(1).execute_with(|| {
@@ -464,6 +525,33 @@ fn test_burned_registration_rate_allows_burn_adjustment() {
     });
 }
 
+#[test]
+fn test_fee_estimate_includes_registration_burn() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+
+        let register = crate::Call::<Test>::burned_register { netuid, hotkey };
+        let estimate = SubtensorModule::get_fee_estimate(Some(&register), 50);
+        assert_eq!(estimate.fee, 50.into());
+        assert_eq!(estimate.burn, TaoCurrency::from(1000).into());
+        assert_eq!(estimate.total, 1050.into());
+
+        let register_network = crate::Call::<Test>::register_network { hotkey };
+        assert_eq!(
+            SubtensorModule::get_call_burn(&register_network),
+            SubtensorModule::get_network_lock_cost()
+        );
+
+        // Calls without a burn only pay the transaction fee.
+        let estimate = SubtensorModule::get_fee_estimate(None, 50);
+        assert_eq!(estimate.burn, TaoCurrency::ZERO.into());
+        assert_eq!(estimate.total, 50.into());
+    });
+}
+
 #[test]
 fn test_burned_registration_ok() {
     new_test_ext(1).execute_with(|| {
@@ -515,6 +603,190 @@ fn test_burned_registration_ok() {
     });
 }
 
+#[test]
+fn test_registration_auction_settles_top_bids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let burn_cost = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        let reserve = 1_000_000_000_000;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        SubtensorModule::set_target_registrations_per_interval(netuid, 2);
+        SubtensorModule::set_registration_auction_enabled(netuid, true);
+
+        // Burned registration is closed while the auction runs.
+        let coldkey = U256::from(667);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(1)
+            ),
+            Error::<Test>::RegistrationAuctionActive
+        );
+
+        // Bids below the burn are rejected.
+        assert_noop!(
+            SubtensorModule::bid_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(1),
+                (burn_cost - 1).into()
+            ),
+            Error::<Test>::RegistrationBidTooLow
+        );
+
+        let bids: [(u64, u64); 3] = [(1, 2_000), (2, 5_000), (3, 3_000)];
+        for (i, bid) in bids {
+            let coldkey = U256::from(100 + i);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+            assert_ok!(SubtensorModule::bid_registration(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(i),
+                bid.into()
+            ));
+            assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000 - bid);
+        }
+        assert_eq!(
+            TotalRegistrationBids::<Test>::get(),
+            TaoCurrency::from(10_000)
+        );
+
+        SubtensorModule::settle_registration_auction(netuid);
+
+        // The two highest bids are registered and burned, the lowest is refunded.
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(2)).is_ok());
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(3)).is_ok());
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(1)).is_err());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&U256::from(101)),
+            10_000
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&U256::from(102)),
+            5_000
+        );
+        assert_eq!(SubtensorModule::get_registrations_this_interval(netuid), 2);
+        assert!(RegistrationBids::<Test>::get(netuid).is_empty());
+        assert_eq!(TotalRegistrationBids::<Test>::get(), TaoCurrency::ZERO);
+    });
+}
+
+#[test]
+fn test_unregister_refunds_burn_and_frees_uid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let burn_cost = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        let reserve = 1_000_000_000_000;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        SubtensorModule::set_immunity_period(netuid, 100);
+        SubtensorModule::set_unregister_refund_fraction(u16::MAX);
+
+        let coldkey = U256::from(667);
+        let (hotkey, other_hotkey) = (U256::from(1), U256::from(2));
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        for hk in [hotkey, other_hotkey] {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hk
+            ));
+        }
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        assert_eq!(
+            RegistrationBurnPaid::<Test>::get(netuid, uid),
+            TaoCurrency::from(burn_cost)
+        );
+
+        // Only the coldkey owning the hotkey can unregister it.
+        assert_noop!(
+            SubtensorModule::unregister(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(668)),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // Half the immunity period is left, so half the burn is refunded.
+        System::set_block_number(System::block_number() + 50);
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+        let subnet_tao_before = SubnetTAO::<Test>::get(netuid);
+        let issuance_before = SubtensorModule::get_total_issuance();
+        let recycled_before = SubtensorModule::get_rao_recycled(netuid);
+        assert_ok!(SubtensorModule::unregister(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance_before + burn_cost / 2
+        );
+        // The refund is issued out of the recycled TAO, the subnet pool keeps its reserve.
+        assert_eq!(SubnetTAO::<Test>::get(netuid), subnet_tao_before);
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + (burn_cost / 2).into()
+        );
+        assert_eq!(
+            SubtensorModule::get_rao_recycled(netuid),
+            recycled_before - (burn_cost / 2).into()
+        );
+        System::assert_last_event(
+            Event::NeuronUnregistered {
+                netuid,
+                uid,
+                hotkey,
+                refund: (burn_cost / 2).into(),
+            }
+            .into(),
+        );
+
+        // The uid is vacant and the subnet size unchanged.
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).is_err());
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid, &hotkey
+        ));
+        assert_eq!(VacantUids::<Test>::get(netuid), vec![uid]);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(!RegistrationBurnPaid::<Test>::contains_key(netuid, uid));
+        // The neuron RPCs leave the vacant uid out.
+        let other_uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &other_hotkey).unwrap();
+        assert_eq!(
+            SubtensorModule::get_neurons_lite(netuid),
+            vec![SubtensorModule::get_neuron_lite(netuid, other_uid).unwrap()]
+        );
+        assert_eq!(SubtensorModule::get_neurons(netuid).len(), 1);
+        // Weights still pointing to the vacant uid are masked as outdated by the epoch.
+        assert_eq!(
+            SubtensorModule::get_block_at_registration(netuid)
+                .get(uid as usize)
+                .copied(),
+            Some(u64::MAX)
+        );
+
+        // The next registration fills the vacant uid.
+        let new_hotkey = U256::from(3);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            new_hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &new_hotkey),
+            Ok(uid)
+        );
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(VacantUids::<Test>::get(netuid).is_empty());
+    });
+}
+
 #[test]
 fn test_burn_registration_without_neuron_slot() {
     new_test_ext(1).execute_with(|| {
@@ -635,6 +907,84 @@ fn test_burn_adjustment() {
     });
 }
 
+// Simulates registrations responding to the burn: a registration budget buys as many
+// registrations as it can afford, and bursts of demand raise the budget for a while.
+#[test]
+fn test_burn_controller_converges_under_bursty_registrations() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let target: u16 = 3;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_target_registrations_per_interval(netuid, target);
+        SubtensorModule::set_min_burn(netuid, 100_000_000.into());
+        SubtensorModule::set_max_burn(netuid, 100_000_000_000.into());
+        SubtensorModule::set_burn_controller_gains(
+            netuid,
+            u16::MAX / 2,
+            u16::MAX / 10,
+            u16::MAX / 10,
+        );
+        assert!(SubtensorModule::is_burn_controller_enabled(netuid));
+
+        let mut burn = TaoCurrency::from(1_000_000_000);
+        let mut registrations_log: Vec<(u16, TaoCurrency)> = Vec::new();
+        for interval in 0..120 {
+            let budget: u64 = if (30..70).contains(&interval) {
+                15_000_000_000
+            } else {
+                3_000_000_000
+            };
+            let registrations =
+                u16::try_from(budget.saturating_div(burn.to_u64())).unwrap_or(u16::MAX);
+            burn = SubtensorModule::controlled_burn(netuid, burn, registrations, target);
+            registrations_log.push((registrations, burn));
+        }
+
+        // The registrations meet the target at the end of each phase.
+        for end in [30, 70, 120] {
+            for (registrations, _) in registrations_log
+                .iter()
+                .take(end)
+                .skip(end.saturating_sub(10))
+            {
+                assert!(registrations.abs_diff(target) <= 1);
+            }
+        }
+        // The burn rises with the burst of demand and falls back after it.
+        let burn_at = |interval: usize| registrations_log.get(interval).unwrap().1;
+        let (burn_before, burn_during, burn_after) = (burn_at(29), burn_at(69), burn_at(119));
+        assert!(burn_during > burn_before.saturating_mul(3.into()));
+        assert!(burn_after < burn_during.saturating_div(3.into()));
+    });
+}
+
+#[test]
+fn test_burn_controller_replaces_burn_adjustment() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let init_burn_cost: u64 = InitialMinBurn::get() + 10_000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, init_burn_cost.into());
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        SubtensorModule::set_burn_controller_gains(netuid, u16::MAX / 4, 0, 0);
+
+        // Three registrations for a target of one doubles the error, raising the burn by half.
+        SubtensorModule::set_registrations_this_interval(netuid, 3);
+        SubtensorModule::set_burn_registrations_this_interval(netuid, 3);
+        step_block(1);
+        assert_abs_diff_eq!(
+            SubtensorModule::get_burn(netuid),
+            (init_burn_cost.saturating_mul(3).saturating_div(2)).into(),
+            epsilon = 1000.into()
+        );
+
+        // Zero gains restore the default adjustment.
+        SubtensorModule::set_burn_controller_gains(netuid, 0, 0, 0);
+        assert!(!SubtensorModule::is_burn_controller_enabled(netuid));
+    });
+}
+
 #[test]
 fn test_burn_registration_pruning_scenarios() {
     new_test_ext(1).execute_with(|| {
@@ -1221,6 +1571,31 @@ fn test_registration_invalid_seal() {
     });
 }
 
+#[test]
+fn test_registration_malformed_work() {
+    new_test_ext(1).execute_with(|| {
+        let block_number: u64 = 0;
+        let netuid = NetUid::from(1);
+        let hotkey_account_id = U256::from(1);
+        let coldkey_account_id = U256::from(667);
+        add_network(netuid, 13, 0);
+
+        // Work that is not a 32 byte hash is rejected instead of panicking.
+        for work in [vec![], vec![0; 31], vec![0; 33]] {
+            let result = SubtensorModule::register(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                block_number,
+                0,
+                work,
+                hotkey_account_id,
+                coldkey_account_id,
+            );
+            assert_eq!(result, Err(Error::<Test>::InvalidSeal.into()));
+        }
+    });
+}
+
 #[test]
 fn test_registration_invalid_block_number() {
     new_test_ext(1).execute_with(|| {
@@ -2241,3 +2616,140 @@ fn test_registration_disabled() {
 //         );
 //     });
 // }
+
+#[test]
+fn test_sponsored_burned_registration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let pair = sr25519::Pair::from_seed(&[9u8; 32]);
+        let coldkey = U256::from_little_endian(&pair.public().0);
+        let hotkey = U256::from(1);
+        let sponsor = U256::from(2);
+        let burn_cost = 1000;
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        SubtensorModule::add_balance_to_coldkey_account(&sponsor, 10_000);
+        let consent = |sponsor: &U256, deadline: u64| SponsorConsent {
+            deadline,
+            signature: pair.sign(&SubtensorModule::get_sponsor_consent_message(
+                netuid, &hotkey, &coldkey, sponsor, deadline,
+            )),
+        };
+
+        // The consent is bound to its sponsor and deadline.
+        assert_noop!(
+            SubtensorModule::sponsored_burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(sponsor),
+                netuid,
+                hotkey,
+                coldkey,
+                consent(&U256::from(3), 100)
+            ),
+            Error::<Test>::InvalidSponsorConsent
+        );
+        assert_noop!(
+            SubtensorModule::sponsored_burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(sponsor),
+                netuid,
+                hotkey,
+                coldkey,
+                consent(&sponsor, 0)
+            ),
+            Error::<Test>::SponsorConsentExpired
+        );
+
+        // The sponsor pays the burn, the hotkey is registered to the coldkey.
+        let used_consent = consent(&sponsor, 100);
+        assert_ok!(SubtensorModule::sponsored_burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(sponsor),
+            netuid,
+            hotkey,
+            coldkey,
+            used_consent.clone()
+        ));
+        assert!(SubtensorModule::is_hotkey_registered_on_network(
+            netuid, &hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            coldkey
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&sponsor), 9_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+
+        // The consent cannot be replayed once used.
+        assert_noop!(
+            SubtensorModule::sponsored_burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(sponsor),
+                netuid,
+                hotkey,
+                coldkey,
+                used_consent
+            ),
+            Error::<Test>::InvalidSponsorConsent
+        );
+        assert_eq!(SponsorConsentNonce::<Test>::get(coldkey), 1);
+    });
+}
+
+#[test]
+fn test_registration_fee_multiplier_follows_block_fullness() {
+    new_test_ext(1).execute_with(|| {
+        let one = U64F64::from_num(1);
+        let max_block = BlockWeights::get().max_block;
+        SubtensorModule::set_target_registration_block_fullness(Perbill::from_percent(1));
+        let target = frame_support::weights::Weight::from_parts(
+            Perbill::from_percent(1).mul_floor(max_block.ref_time()),
+            0,
+        );
+
+        // A block twice over the target raises the multiplier by 1/8.
+        SubtensorModule::note_registration_weight(target.saturating_mul(2));
+        SubtensorModule::update_registration_fee_multiplier();
+        assert_eq!(
+            SubtensorModule::get_registration_fee_multiplier(),
+            U64F64::from_num(1.125)
+        );
+        assert_eq!(SubtensorModule::scale_registration_fee(1_000), 1_125);
+
+        // Blocks far over the target count as twice over it.
+        SubtensorModule::note_registration_weight(max_block);
+        SubtensorModule::update_registration_fee_multiplier();
+        assert_eq!(
+            SubtensorModule::get_registration_fee_multiplier(),
+            U64F64::from_num(1.265625)
+        );
+
+        // Empty blocks bring it back down, but never below one.
+        for _ in 0..5 {
+            SubtensorModule::update_registration_fee_multiplier();
+        }
+        assert_eq!(SubtensorModule::get_registration_fee_multiplier(), one);
+
+        // A zero target disables the congestion pricing.
+        SubtensorModule::set_target_registration_block_fullness(Perbill::zero());
+        SubtensorModule::note_registration_weight(max_block);
+        SubtensorModule::update_registration_fee_multiplier();
+        assert_eq!(SubtensorModule::get_registration_fee_multiplier(), one);
+    });
+}
+
+#[test]
+fn test_is_registration_call() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        assert!(SubtensorModule::is_registration_call(
+            &crate::Call::burned_register {
+                netuid: NetUid::from(1),
+                hotkey,
+            }
+        ));
+        assert!(!SubtensorModule::is_registration_call(
+            &crate::Call::add_stake {
+                hotkey,
+                netuid: NetUid::from(1),
+                amount_staked: TaoCurrency::from(1),
+            }
+        ));
+    });
+}
@@ -10,7 +10,7 @@ use frame_support::{
     dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays},
 };
 use frame_system::{Config, RawOrigin};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::traits::TxBaseImplication;
 
 mod test {
@@ -97,6 +97,143 @@ fn test_serving_ok() {
     });
 }
 
+#[test]
+fn test_serving_axon_v2_ok() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        let ipv4 = AxonEndpoint {
+            ip: test::ipv4(8, 8, 8, 8),
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+        };
+        let ipv6 = AxonEndpoint {
+            ip: test::ipv6(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            port: 129,
+            ip_type: 6,
+            protocol: 0,
+        };
+        let dns = AxonEndpoint {
+            ip: 0,
+            port: 443,
+            ip_type: AXON_IP_TYPE_COMMITMENT,
+            protocol: 0,
+        };
+        let fingerprint = [7u8; 32];
+        assert_ok!(SubtensorModule::serve_axon_v2(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            1,
+            vec![ipv4.clone(), ipv6.clone(), dns.clone()],
+            Some(fingerprint)
+        ));
+
+        let axon_v2 = SubtensorModule::get_axon_info_v2(netuid, &hotkey_account_id);
+        assert_eq!(axon_v2.version, 2);
+        assert_eq!(axon_v2.protocol_version, 1);
+        assert_eq!(axon_v2.tls_fingerprint, Some(fingerprint));
+        assert_eq!(
+            axon_v2.endpoints.into_inner(),
+            vec![ipv4.clone(), ipv6.clone(), dns.clone()]
+        );
+
+        // The primary endpoint is served as the axon info.
+        let axon = SubtensorModule::get_axon_info(netuid, &hotkey_account_id);
+        assert_eq!(axon.ip, ipv4.ip);
+        assert_eq!(axon.port, ipv4.port);
+        assert_eq!(axon.ip_type, ipv4.ip_type);
+
+        // Serving the axon with the v1 call only replaces the primary endpoint.
+        let new_ip = test::ipv4(1, 1, 1, 1);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            3,
+            new_ip,
+            130,
+            4,
+            0,
+            0,
+            0
+        ));
+        let axon_v2 = SubtensorModule::get_axon_info_v2(netuid, &hotkey_account_id);
+        assert_eq!(axon_v2.version, 3);
+        assert_eq!(axon_v2.tls_fingerprint, Some(fingerprint));
+        assert_eq!(
+            axon_v2.endpoints.into_inner(),
+            vec![
+                AxonEndpoint {
+                    ip: new_ip,
+                    port: 130,
+                    ip_type: 4,
+                    protocol: 0,
+                },
+                ipv6,
+                dns
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_serving_axon_v2_invalid_endpoints() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        let endpoint = AxonEndpoint {
+            ip: test::ipv4(8, 8, 8, 8),
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+        };
+        let serve = |endpoints: Vec<AxonEndpoint>| {
+            SubtensorModule::serve_axon_v2(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                2,
+                1,
+                endpoints,
+                None,
+            )
+        };
+
+        assert_noop!(serve(vec![]), Error::<Test>::NoAxonEndpoints);
+        assert_noop!(
+            serve(vec![endpoint.clone(); MAX_AXON_ENDPOINTS as usize + 1]),
+            Error::<Test>::TooManyAxonEndpoints
+        );
+        assert_noop!(
+            serve(vec![AxonEndpoint {
+                port: 0,
+                ..endpoint.clone()
+            }]),
+            Error::<Test>::InvalidPort
+        );
+        assert_noop!(
+            serve(vec![AxonEndpoint {
+                ip_type: 5,
+                ..endpoint.clone()
+            }]),
+            Error::<Test>::InvalidIpType
+        );
+        // A commitment host carries no ip.
+        assert_noop!(
+            serve(vec![AxonEndpoint {
+                ip_type: AXON_IP_TYPE_COMMITMENT,
+                ..endpoint
+            }]),
+            Error::<Test>::InvalidIpAddress
+        );
+    });
+}
+
 #[test]
 fn test_serving_tls_ok() {
     new_test_ext(1).execute_with(|| {
@@ -1341,6 +1478,85 @@ fn test_set_identity_for_non_existent_subnet() {
     });
 }
 
+#[test]
+fn test_set_subnet_identity_burns_fee_and_sets_logo_hash() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let netuid = NetUid::from(1);
+        let fee = TaoCurrency::from(1_000);
+
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, coldkey);
+        SubnetIdentityFee::<Test>::set(fee);
+
+        let set_identity = || {
+            SubtensorModule::do_set_subnet_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                b"Test Subnet".to_vec(),
+                b"https://github.com/test/subnet".to_vec(),
+                b"contact@testsubnet.com".to_vec(),
+                b"subnet.com".to_vec(),
+                b"discord.com".to_vec(),
+                b"I am the describer".to_vec(),
+                b"https://testsubnet.com/logo.png".to_vec(),
+                vec![],
+            )
+        };
+
+        // The owner must be able to pay the fee.
+        assert_noop!(
+            set_identity(),
+            Error::<Test>::NotEnoughBalanceToPaySubnetIdentityFee
+        );
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        let burned_before = SubnetTaoBurned::<Test>::get(netuid);
+        assert_ok!(set_identity());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 9_000);
+        assert_eq!(
+            SubnetTaoBurned::<Test>::get(netuid),
+            burned_before.saturating_add(fee)
+        );
+
+        // Only the owner can set the logo hash.
+        let logo_hash = H256::repeat_byte(7);
+        assert_noop!(
+            SubtensorModule::set_subnet_logo_hash(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                Some(logo_hash)
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_ok!(SubtensorModule::set_subnet_logo_hash(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            Some(logo_hash)
+        ));
+        assert_eq!(SubnetLogoHashes::<Test>::get(netuid), Some(logo_hash));
+
+        let info = SubtensorModule::get_subnet_identity_info(netuid)
+            .expect("Subnet identity info should exist");
+        assert_eq!(
+            info.encode(),
+            (
+                codec::Compact(netuid),
+                SubnetIdentitiesV3::<Test>::get(netuid),
+                Some(logo_hash)
+            )
+                .encode()
+        );
+
+        assert_ok!(SubtensorModule::set_subnet_logo_hash(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            None
+        ));
+        assert_eq!(SubnetLogoHashes::<Test>::get(netuid), None);
+    });
+}
+
 #[test]
 fn test_set_subnet_identity_dispatch_info_ok() {
     new_test_ext(1).execute_with(|| {
@@ -1443,3 +1659,84 @@ fn test_serve_axon_validate() {
         assert_ok!(result_ok);
     });
 }
+
+#[test]
+fn test_serve_axon_pays_subnet_transaction_fee() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::set_subnet_transaction_fee(netuid, 100_000.into());
+
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::serve_axon {
+            netuid,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        });
+        let info: crate::DispatchInfo =
+            crate::DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = crate::SubtensorTransactionExtension::<Test>::new();
+        let validate = || {
+            extension.validate(
+                RawOrigin::Signed(hotkey).into(),
+                &call,
+                &info,
+                10,
+                (),
+                &TxBaseImplication(()),
+                TransactionSource::External,
+            )
+        };
+
+        // The hotkey cannot pay the fee.
+        assert_eq!(
+            validate().unwrap_err(),
+            CustomTransactionError::BalanceTooLow.into()
+        );
+
+        // Once funded, the fee is paid to the subnet treasury.
+        SubtensorModule::add_balance_to_coldkey_account(&hotkey, 1_000_000);
+        assert_ok!(validate());
+        assert_ok!(extension.clone().prepare(
+            Some(hotkey),
+            &RawOrigin::Signed(hotkey).into(),
+            &call,
+            &info,
+            10,
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&hotkey), 900_000);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&SubtensorModule::get_subnet_treasury_account(
+                netuid
+            )),
+            100_000
+        );
+
+        // Registering with proof of work pays no subnet fee, burn registering does.
+        let register = RuntimeCall::SubtensorModule(SubtensorCall::register {
+            netuid,
+            block_number: 0,
+            nonce: 0,
+            work: vec![],
+            hotkey,
+            coldkey,
+        });
+        let burned_register =
+            RuntimeCall::SubtensorModule(SubtensorCall::burned_register { netuid, hotkey });
+        assert_eq!(
+            crate::SubtensorTransactionExtension::<Test>::get_subnet_fee_netuid(&register),
+            None
+        );
+        assert_eq!(
+            crate::SubtensorTransactionExtension::<Test>::get_subnet_fee_netuid(&burned_register),
+            Some(netuid)
+        );
+    });
+}
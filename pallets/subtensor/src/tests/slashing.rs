@@ -0,0 +1,104 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H256, Pair, U256, sr25519};
+use subtensor_runtime_common::{AlphaCurrency, Currency as CurrencyT};
+
+use super::mock::*;
+use crate::subnets::slashing::{WeightOffenseEvidence, WeightOffenseKind, WeightStatement};
+use crate::*;
+
+fn sign_statement(
+    pair: &sr25519::Pair,
+    kind: WeightOffenseKind,
+    netuid: NetUid,
+    epoch: u64,
+    hash: H256,
+) -> WeightStatement {
+    let message = SubtensorModule::get_weight_statement_message(kind, netuid, epoch, &hash);
+    WeightStatement {
+        hash,
+        signature: pair.sign(&message),
+    }
+}
+
+#[test]
+fn test_report_offense_slashes_equivocating_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+        let hotkey = U256::from_little_endian(&pair.public().0);
+        let coldkey = U256::from(1);
+        let reporter = U256::from(3);
+
+        let netuid = add_dynamic_network(&U256::from(1002), &U256::from(1001));
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 200_000.into(), netuid);
+        OffenseSlashFraction::<Test>::set(u16::MAX / 2);
+
+        let kind = WeightOffenseKind::CommitEquivocation;
+        let epoch = 5;
+        let first = sign_statement(&pair, kind, netuid, epoch, H256::repeat_byte(1));
+        let evidence = WeightOffenseEvidence {
+            hotkey,
+            kind,
+            netuid,
+            epoch,
+            first: first.clone(),
+            second: sign_statement(&pair, kind, netuid, epoch, H256::repeat_byte(2)),
+        };
+
+        // Two statements over the same hash are not an offense.
+        assert_noop!(
+            SubtensorModule::report_offense(
+                RuntimeOrigin::signed(reporter),
+                WeightOffenseEvidence {
+                    second: first.clone(),
+                    ..evidence.clone()
+                }
+            ),
+            Error::<Test>::InvalidOffenseEvidence
+        );
+
+        // A statement signed by another key is rejected.
+        let other = sr25519::Pair::from_seed(&[8u8; 32]);
+        assert_noop!(
+            SubtensorModule::report_offense(
+                RuntimeOrigin::signed(reporter),
+                WeightOffenseEvidence {
+                    second: sign_statement(&other, kind, netuid, epoch, H256::repeat_byte(2)),
+                    ..evidence.clone()
+                }
+            ),
+            Error::<Test>::InvalidOffenseSignature
+        );
+
+        let stake_before = SubtensorModule::get_stake_for_hotkey_on_subnet(&hotkey, netuid);
+        let alpha_out_before = SubnetAlphaOut::<Test>::get(netuid);
+        let recycled_before = SubnetAlphaRecycled::<Test>::get(netuid);
+        assert_ok!(SubtensorModule::report_offense(
+            RuntimeOrigin::signed(reporter),
+            evidence.clone()
+        ));
+
+        let slashed = stake_before.saturating_sub(SubtensorModule::get_stake_for_hotkey_on_subnet(
+            &hotkey, netuid,
+        ));
+        assert!(!slashed.is_zero());
+        assert!(slashed <= AlphaCurrency::from(stake_before.to_u64() / 2 + 1));
+        assert_eq!(
+            SubnetAlphaOut::<Test>::get(netuid),
+            alpha_out_before.saturating_sub(slashed)
+        );
+        assert_eq!(
+            SubnetAlphaRecycled::<Test>::get(netuid),
+            recycled_before + slashed
+        );
+
+        // The same offense cannot be punished twice.
+        assert_noop!(
+            SubtensorModule::report_offense(RuntimeOrigin::signed(reporter), evidence),
+            Error::<Test>::OffenseAlreadyReported
+        );
+    });
+}
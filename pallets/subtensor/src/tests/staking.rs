@@ -11,7 +11,7 @@ use pallet_subtensor_swap::tick::TickIndex;
 use safe_math::FixedExt;
 use sp_core::{Get, H256, U256};
 use substrate_fixed::traits::FromFixed;
-use substrate_fixed::types::{I96F32, I110F18, U64F64, U96F32};
+use substrate_fixed::types::{I64F64, I96F32, I110F18, U64F64, U96F32};
 use subtensor_runtime_common::{AlphaCurrency, Currency as CurrencyT, NetUid, TaoCurrency};
 use subtensor_swap_interface::{OrderType, SwapHandler};
 
@@ -108,6 +108,243 @@ fn test_add_stake_ok_no_emission() {
     });
 }
 
+#[test]
+fn test_add_stake_respects_delegate_stake_cap() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(533453);
+        let coldkey_account_id = U256::from(55453);
+        let nominator = U256::from(55454);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+
+        let netuid = add_dynamic_network(&hotkey_account_id, &coldkey_account_id);
+        mock::setup_reserves(
+            netuid,
+            (amount * 1_000_000).into(),
+            (amount * 10_000_000).into(),
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, amount * 3);
+
+        // Only the owning coldkey can set the cap
+        assert_noop!(
+            SubtensorModule::set_delegate_stake_cap(
+                RuntimeOrigin::signed(nominator),
+                hotkey_account_id,
+                Some(amount.into())
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::set_delegate_stake_cap(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            Some((amount * 3 / 2).into())
+        ));
+
+        // The first stake fits under the cap, the second would exceed it
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey_account_id,
+            netuid,
+            amount.into()
+        ));
+        assert_noop!(
+            SubtensorModule::add_stake(
+                RuntimeOrigin::signed(nominator),
+                hotkey_account_id,
+                netuid,
+                amount.into()
+            ),
+            Error::<Test>::DelegateStakeCapExceeded
+        );
+
+        // Removing the cap allows staking again
+        assert_ok!(SubtensorModule::set_delegate_stake_cap(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            None
+        ));
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(nominator),
+            hotkey_account_id,
+            netuid,
+            amount.into()
+        ));
+    });
+}
+
+#[test]
+fn test_add_stake_respects_delegate_staking_access() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(533453);
+        let coldkey_account_id = U256::from(55453);
+        let allowed = U256::from(55454);
+        let other = U256::from(55455);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+
+        let netuid = add_dynamic_network(&hotkey_account_id, &coldkey_account_id);
+        mock::setup_reserves(
+            netuid,
+            (amount * 1_000_000).into(),
+            (amount * 10_000_000).into(),
+        );
+        for coldkey in [coldkey_account_id, allowed, other] {
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount * 3);
+        }
+        let add_stake = |coldkey: U256| {
+            SubtensorModule::add_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey_account_id,
+                netuid,
+                amount.into(),
+            )
+        };
+
+        // Only the owning coldkey can manage access
+        assert_noop!(
+            SubtensorModule::set_delegate_staking_access(
+                RuntimeOrigin::signed(allowed),
+                hotkey_account_id,
+                Some(DelegateStakingAccess::Allowlist)
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_ok!(SubtensorModule::set_delegate_staking_list(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            vec![allowed],
+            true
+        ));
+        assert_ok!(SubtensorModule::set_delegate_staking_access(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            Some(DelegateStakingAccess::Allowlist)
+        ));
+
+        // The allowlist admits the listed coldkey and the owner only
+        assert_ok!(add_stake(allowed));
+        assert_ok!(add_stake(coldkey_account_id));
+        assert_noop!(add_stake(other), Error::<Test>::DelegateStakingNotAllowed);
+
+        // The denylist rejects the listed coldkey only
+        assert_ok!(SubtensorModule::set_delegate_staking_access(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            Some(DelegateStakingAccess::Denylist)
+        ));
+        assert_noop!(add_stake(allowed), Error::<Test>::DelegateStakingNotAllowed);
+        assert_ok!(add_stake(other));
+
+        // Removing the coldkey from the list, or the mode, allows staking again
+        assert_ok!(SubtensorModule::set_delegate_staking_list(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            vec![allowed],
+            false
+        ));
+        System::assert_last_event(
+            Event::DelegateStakingListUpdated {
+                hotkey: hotkey_account_id,
+                coldkeys: vec![allowed],
+                listed: false,
+            }
+            .into(),
+        );
+        assert_ok!(add_stake(allowed));
+        assert_ok!(SubtensorModule::set_delegate_staking_access(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            None
+        ));
+        assert!(!DelegateStakingAccessMode::<Test>::contains_key(
+            hotkey_account_id
+        ));
+
+        assert_noop!(
+            SubtensorModule::set_delegate_staking_list(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                vec![
+                    other;
+                    staking::delegate_access::MAX_DELEGATE_STAKING_LIST_UPDATE as usize + 1
+                ],
+                true
+            ),
+            Error::<Test>::TooManyDelegateStakingListUpdates
+        );
+    });
+}
+
+#[test]
+fn test_add_stake_multiple() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_hotkey = U256::from(533453);
+        let subnet_owner_coldkey = U256::from(55453);
+        let hotkey1 = U256::from(1);
+        let hotkey2 = U256::from(2);
+        let coldkey = U256::from(3);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        mock::setup_reserves(
+            netuid,
+            (amount * 1_000_000).into(),
+            (amount * 10_000_000).into(),
+        );
+        register_ok_neuron(netuid, hotkey1, U256::from(10), 0);
+        register_ok_neuron(netuid, hotkey2, U256::from(11), 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount * 4);
+
+        // Invalid splits are rejected
+        assert_noop!(
+            SubtensorModule::add_stake_multiple(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                amount.into(),
+                vec![]
+            ),
+            Error::<Test>::InvalidStakeSplit
+        );
+        assert_noop!(
+            SubtensorModule::add_stake_multiple(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                amount.into(),
+                vec![(1, hotkey1), (1, hotkey1)]
+            ),
+            Error::<Test>::InvalidStakeSplit
+        );
+
+        // All or nothing: an unregistered hotkey reverts the whole call
+        assert_noop!(
+            SubtensorModule::add_stake_multiple(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                (amount * 2).into(),
+                vec![(1, hotkey1), (1, U256::from(99))]
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+
+        // 3:1 split
+        assert_ok!(SubtensorModule::add_stake_multiple(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            (amount * 4).into(),
+            vec![(3, hotkey1), (1, hotkey2)]
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+        let stake1 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey1, &coldkey, netuid);
+        let stake2 =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey2, &coldkey, netuid);
+        assert!(stake2 > AlphaCurrency::ZERO);
+        assert_abs_diff_eq!(
+            stake1.to_u64(),
+            stake2.to_u64() * 3,
+            epsilon = stake1.to_u64() / 100
+        );
+    });
+}
+
 #[test]
 fn test_dividends_with_run_to_block() {
     new_test_ext(1).execute_with(|| {
@@ -443,6 +680,66 @@ fn test_remove_stake_ok_no_emission() {
     });
 }
 
+#[test]
+fn test_remove_stake_with_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let amount = DefaultMinStake::<Test>::get() * 10.into();
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        let unbonding_period = 10;
+        SubtensorModule::set_unbonding_period(netuid, unbonding_period);
+
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+            amount.to_u64().into(),
+        );
+        let (amount_tao, fee) = mock::swap_alpha_to_tao(netuid, amount.to_u64().into());
+        SubnetTAO::<Test>::mutate(netuid, |v| *v += amount_tao + fee.into());
+        TotalStake::<Test>::mutate(|v| *v += amount_tao + fee.into());
+
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount.to_u64().into()
+        ));
+
+        // The TAO is queued instead of credited
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        let (unbonding, released) = SubtensorModule::get_unbonding_balance(&coldkey_account_id);
+        assert!(unbonding > TaoCurrency::ZERO);
+        assert_eq!(released, TaoCurrency::ZERO);
+        assert_eq!(TotalUnbonding::<Test>::get(), unbonding);
+        assert_noop!(
+            SubtensorModule::withdraw_unbonded(RuntimeOrigin::signed(coldkey_account_id)),
+            Error::<Test>::NoUnbondedStake
+        );
+
+        // Once the period has passed the TAO can be withdrawn
+        step_block(unbonding_period as u16);
+        assert_eq!(
+            SubtensorModule::get_unbonding_balance(&coldkey_account_id),
+            (unbonding, unbonding)
+        );
+        assert_ok!(SubtensorModule::withdraw_unbonded(RuntimeOrigin::signed(
+            coldkey_account_id
+        )));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            unbonding.to_u64()
+        );
+        assert!(UnbondingQueue::<Test>::get(coldkey_account_id).is_empty());
+        assert_eq!(TotalUnbonding::<Test>::get(), TaoCurrency::ZERO);
+    });
+}
+
 #[test]
 fn test_remove_stake_amount_too_low() {
     new_test_ext(1).execute_with(|| {
@@ -1992,6 +2289,58 @@ fn test_delegate_take_can_not_be_increased_beyond_limit() {
     });
 }
 
+// Test that take increases wait out the notice period while decreases apply immediately
+#[test]
+fn test_increase_take_waits_for_notice_period() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey0 = U256::from(1);
+        let coldkey0 = U256::from(3);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey0, coldkey0, 124124);
+
+        let min_take = SubtensorModule::get_min_delegate_take();
+        Delegates::<Test>::insert(hotkey0, min_take);
+        let notice_period: u64 = 10;
+        SubtensorModule::set_delegate_take_notice_period(notice_period);
+
+        // The increase is queued, not applied
+        assert_ok!(SubtensorModule::do_increase_take(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            min_take + 2
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), min_take);
+        let effective_block = System::block_number() + notice_period;
+        assert_eq!(
+            PendingDelegateTake::<Test>::get(hotkey0),
+            Some((min_take + 2, effective_block))
+        );
+        assert_eq!(
+            PendingDelegateTakeQueue::<Test>::get(effective_block, hotkey0),
+            Some(min_take + 2)
+        );
+
+        // It applies once the notice period has passed
+        step_block(notice_period as u16);
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), min_take + 2);
+        assert!(PendingDelegateTake::<Test>::get(hotkey0).is_none());
+        assert!(PendingDelegateTakeQueue::<Test>::get(effective_block, hotkey0).is_none());
+
+        // A decrease applies immediately and cancels any pending increase
+        PendingDelegateTake::<Test>::insert(hotkey0, (min_take + 3, u64::MAX));
+        PendingDelegateTakeQueue::<Test>::insert(u64::MAX, hotkey0, min_take + 3);
+        assert_ok!(SubtensorModule::do_decrease_take(
+            RuntimeOrigin::signed(coldkey0),
+            hotkey0,
+            min_take + 1
+        ));
+        assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), min_take + 1);
+        assert!(PendingDelegateTake::<Test>::get(hotkey0).is_none());
+        assert!(PendingDelegateTakeQueue::<Test>::get(u64::MAX, hotkey0).is_none());
+    });
+}
+
 // Test rate-limiting on increase_take
 #[test]
 fn test_rate_limits_enforced_on_increase_take() {
@@ -5425,3 +5774,214 @@ fn test_stake_rate_limits() {
         )));
     });
 }
+
+#[test]
+fn test_stake_rate_limit_exempt_coldkey() {
+    new_test_ext(0).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let hot1 = U256::from(1);
+        let cold1 = U256::from(3);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+        let fee = DefaultMinStake::<Test>::get().to_u64();
+        let init_balance = amount + fee + ExistentialDeposit::get();
+
+        register_ok_neuron(netuid, hot1, cold1, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&cold1, init_balance);
+
+        SubtensorModule::set_staking_rate_limit_exemption(&cold1, true);
+        System::assert_last_event(Event::StakingRateLimitExemptionGranted(cold1).into());
+
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(cold1),
+            hot1,
+            netuid,
+            (amount + fee).into()
+        ));
+        assert!(!StakingOperationRateLimiter::<Test>::contains_key((
+            hot1, cold1, netuid
+        )));
+
+        // The exempt coldkey unstakes in the same block.
+        let stake =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hot1, &cold1, netuid);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(cold1),
+            hot1,
+            netuid,
+            AlphaCurrency::from(stake.to_u64() / 2)
+        ));
+
+        // Once revoked, the rate limit applies again.
+        SubtensorModule::set_staking_rate_limit_exemption(&cold1, false);
+        System::assert_last_event(Event::StakingRateLimitExemptionRevoked(cold1).into());
+        assert!(!StakingRateLimitExempt::<Test>::contains_key(cold1));
+
+        next_block();
+        SubtensorModule::add_balance_to_coldkey_account(&cold1, init_balance);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(cold1),
+            hot1,
+            netuid,
+            (amount + fee).into()
+        ));
+        assert_err!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(cold1),
+                hot1,
+                netuid,
+                amount.into()
+            ),
+            Error::<Test>::StakingOperationRateLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn test_add_stake_locked_boosts_weight_until_unlocked() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(10);
+        let subnet_owner_hotkey = U256::from(20);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(3);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+        mock::setup_reserves(netuid, (amount * 100).into(), (amount * 100).into());
+
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount * 2);
+
+        let period = StakeLockPeriod::Days30;
+        assert_ok!(SubtensorModule::add_stake_locked(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount.into(),
+            period
+        ));
+
+        let lock = StakeLocks::<Test>::get((hotkey, coldkey, netuid)).unwrap();
+        let stake =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        assert_eq!(lock.alpha, stake);
+        assert_eq!(
+            lock.boost,
+            AlphaCurrency::from(stake.to_u64() * u64::from(period.default_boost()) / 100)
+        );
+        assert_eq!(
+            LockBoost::<Test>::get((netuid, hotkey, coldkey)),
+            lock.boost
+        );
+        assert_eq!(
+            SubtensorModule::get_hotkey_lock_boost(netuid, &hotkey),
+            lock.boost
+        );
+
+        // The boost is added to the stake weight of the hotkey.
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        let (_, alpha_stake, _) = SubtensorModule::get_stake_weights_for_network(netuid);
+        assert_eq!(
+            alpha_stake[uid as usize],
+            I64F64::saturating_from_num(
+                SubtensorModule::get_inherited_for_hotkey_on_subnet(&hotkey, netuid) + lock.boost
+            )
+        );
+
+        // Locked alpha cannot be unstaked, and the lock cannot be lifted before it expires.
+        next_block();
+        assert_noop!(
+            SubtensorModule::remove_stake(RuntimeOrigin::signed(coldkey), hotkey, netuid, stake),
+            Error::<Test>::StakeLocked
+        );
+        assert_noop!(
+            SubtensorModule::unlock_stake(RuntimeOrigin::signed(coldkey), hotkey, netuid),
+            Error::<Test>::StakeLockNotExpired
+        );
+
+        System::set_block_number(lock.unlock_block);
+        assert_ok!(SubtensorModule::unlock_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid
+        ));
+        assert!(!StakeLocks::<Test>::contains_key((hotkey, coldkey, netuid)));
+        assert!(!LockBoost::<Test>::contains_key((netuid, hotkey, coldkey)));
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            stake
+        ));
+    });
+}
+
+#[test]
+fn test_lock_boost_dividends_paid_to_locker_only() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hk = U256::from(1);
+        let owner_ck = U256::from(2);
+        let locker = U256::from(3);
+        let nominator = U256::from(4);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        Delegates::<Test>::insert(owner_hk, 0);
+        let alpha = 1_000_000_000;
+        for coldkey in [locker, nominator] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hk,
+                &coldkey,
+                netuid,
+                alpha.into(),
+            );
+        }
+        // The locker boosts the weight of the hotkey by half of it.
+        LockBoost::<Test>::insert((netuid, owner_hk, locker), AlphaCurrency::from(alpha));
+        let stake = |coldkey: U256| {
+            u64::from(SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hk, &coldkey, netuid,
+            ))
+        };
+
+        let dividends = 3_000_000_000;
+        SubtensorModule::distribute_alpha_dividends(netuid, owner_hk, U96F32::from_num(dividends));
+
+        // The nominator earns its share of the unboosted stake, the locker earns its share and
+        // the dividends of its boost.
+        let nominator_gain = stake(nominator) - alpha;
+        let locker_gain = stake(locker) - alpha;
+        assert_abs_diff_eq!(nominator_gain, dividends / 3, epsilon = 10);
+        assert_abs_diff_eq!(locker_gain, dividends * 2 / 3, epsilon = 10);
+        assert_eq!(
+            AlphaDividendsPerSubnet::<Test>::get(netuid, owner_hk),
+            AlphaCurrency::from(dividends)
+        );
+    });
+}
+
+#[test]
+fn test_get_stake_weights_for_uids_matches_network() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 4);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 4);
+        for i in 0..4u64 {
+            let hotkey = U256::from(100 + i);
+            let coldkey = U256::from(200 + i);
+            register_ok_neuron(netuid, hotkey, coldkey, i);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &coldkey,
+                netuid,
+                AlphaCurrency::from(1_000 * (i + 1)),
+            );
+        }
+
+        let (total, alpha, tao) = SubtensorModule::get_stake_weights_for_network(netuid);
+        let (page_total, page_alpha, page_tao) =
+            SubtensorModule::get_stake_weights_for_uids(netuid, 1..3);
+        assert_eq!(page_total, total[1..3].to_vec());
+        assert_eq!(page_alpha, alpha[1..3].to_vec());
+        assert_eq!(page_tao, tao[1..3].to_vec());
+    });
+}
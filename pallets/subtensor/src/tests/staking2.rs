@@ -876,3 +876,45 @@ fn test_stake_fee_calculation() {
         assert_ne!(stake_fee, default_fee);
     });
 }
+
+#[test]
+fn test_get_stake_for_coldkey_lite_and_proof_keys() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+        StakingHotkeys::<Test>::insert(coldkey, vec![hotkey]);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &coldkey,
+            netuid2,
+            AlphaCurrency::from(1_000),
+        );
+
+        // Only subnets with stake are returned.
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_lite(coldkey),
+            vec![(hotkey, netuid2.into(), AlphaCurrency::from(1_000).into())]
+        );
+
+        let keys = SubtensorModule::get_stake_proof_keys(coldkey);
+        let netuids = SubtensorModule::get_all_subnet_netuids();
+        assert_eq!(keys.len(), 1 + netuids.len() * 4);
+        assert!(keys.contains(&StakingHotkeys::<Test>::hashed_key_for(coldkey)));
+        for netuid in netuids {
+            assert!(keys.contains(&NetworksAdded::<Test>::hashed_key_for(netuid)));
+            assert!(keys.contains(&Alpha::<Test>::hashed_key_for((hotkey, coldkey, netuid))));
+            assert!(keys.contains(&TotalHotkeyAlpha::<Test>::hashed_key_for(hotkey, netuid)));
+            assert!(keys.contains(&TotalHotkeyShares::<Test>::hashed_key_for(hotkey, netuid)));
+        }
+
+        // A coldkey without staking hotkeys only needs its hotkeys and the subnets.
+        assert_eq!(
+            SubtensorModule::get_stake_proof_keys(U256::from(3)).len(),
+            1 + SubtensorModule::get_all_subnet_netuids().len()
+        );
+    });
+}
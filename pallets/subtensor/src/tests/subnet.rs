@@ -5,6 +5,7 @@ use crate::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use subtensor_runtime_common::{AlphaCurrency, TaoCurrency};
 
 use super::mock;
@@ -894,3 +895,326 @@ fn test_update_symbol_fails_if_symbol_already_in_use() {
         );
     });
 }
+
+#[test]
+fn test_subnet_proposal_executes_at_quorum() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetAlphaOut::<Test>::insert(netuid, AlphaCurrency::from(1_000));
+
+        // Three stakers on the subnet, and one without stake.
+        let stakers = [
+            (U256::from(10), 300),
+            (U256::from(11), 200),
+            (U256::from(12), 300),
+        ];
+        for (coldkey, stake) in stakers {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hotkey,
+                &coldkey,
+                netuid,
+                AlphaCurrency::from(stake),
+            );
+        }
+
+        let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: b"raise tempo".to_vec(),
+        }));
+
+        // Only the subnet owner can propose, once at a time.
+        assert_noop!(
+            SubtensorModule::propose_subnet_change(
+                RuntimeOrigin::signed(U256::from(10)),
+                netuid,
+                call.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::propose_subnet_change(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            call.clone()
+        ));
+        assert_noop!(
+            SubtensorModule::propose_subnet_change(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                call
+            ),
+            Error::<Test>::SubnetProposalAlreadyOpen
+        );
+        let proposal_id = SubnetProposals::<Test>::get(netuid).unwrap().id;
+
+        // Votes are weighted by stake and counted once per coldkey.
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(U256::from(10)),
+            netuid,
+            proposal_id,
+            true
+        ));
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(
+                RuntimeOrigin::signed(U256::from(10)),
+                netuid,
+                proposal_id,
+                true
+            ),
+            Error::<Test>::AlreadyVotedOnSubnetProposal
+        );
+        assert_noop!(
+            SubtensorModule::vote_subnet_proposal(
+                RuntimeOrigin::signed(U256::from(13)),
+                netuid,
+                proposal_id,
+                true
+            ),
+            Error::<Test>::NotEnoughStakeToVote
+        );
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(U256::from(11)),
+            netuid,
+            proposal_id,
+            false
+        ));
+        let proposal = SubnetProposals::<Test>::get(netuid).unwrap();
+        assert_eq!(proposal.ayes, AlphaCurrency::from(300));
+        assert_eq!(proposal.nays, AlphaCurrency::from(200));
+
+        // Reaching half of the outstanding alpha executes the call as the subnet owner.
+        assert_ok!(SubtensorModule::vote_subnet_proposal(
+            RuntimeOrigin::signed(U256::from(12)),
+            netuid,
+            proposal_id,
+            true
+        ));
+        assert!(SubnetProposals::<Test>::get(netuid).is_none());
+        assert_eq!(SubnetProposalVotes::<Test>::iter_prefix(netuid).count(), 0);
+        System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+            sender: owner_coldkey,
+            hash: BlakeTwo256::hash(b"raise tempo"),
+        }));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            Event::SubnetProposalExecuted {
+                netuid,
+                proposal_id,
+                result: Ok(()),
+            },
+        ));
+    });
+}
+
+#[test]
+fn test_subnet_maintenance_pauses_epochs_and_registration() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::set_tempo(netuid, 2);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        MaxSubnetMaintenanceDuration::<Test>::set(20);
+
+        let hotkey = U256::from(3);
+        let coldkey = U256::from(4);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        // Only the subnet owner or root can pause the subnet, never the root subnet.
+        assert_noop!(
+            SubtensorModule::set_subnet_maintenance(RuntimeOrigin::signed(coldkey), netuid, true),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::set_subnet_maintenance(RuntimeOrigin::root(), NetUid::ROOT, true),
+            Error::<Test>::CannotPauseRootSubnet
+        );
+        assert_ok!(SubtensorModule::set_subnet_maintenance(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            true
+        ));
+        let start = SubnetMaintenanceStart::<Test>::get(netuid).unwrap();
+
+        // Registration and weight setting are rejected.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000);
+        assert_noop!(
+            SubtensorModule::burned_register(RuntimeOrigin::signed(coldkey), netuid, U256::from(5)),
+            Error::<Test>::SubnetInMaintenance
+        );
+        assert_noop!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![uid],
+                vec![u16::MAX],
+                0
+            ),
+            Error::<Test>::SubnetInMaintenance
+        );
+
+        // Epochs do not run and the pending emission is kept.
+        let last_step = LastMechansimStepBlock::<Test>::get(netuid);
+        let pending = AlphaCurrency::from(1_000);
+        PendingEmission::<Test>::insert(netuid, pending);
+        step_block(6);
+        assert_eq!(LastMechansimStepBlock::<Test>::get(netuid), last_step);
+        assert!(PendingEmission::<Test>::get(netuid) >= pending);
+
+        // Enabling again does not postpone the automatic resume.
+        assert_ok!(SubtensorModule::set_subnet_maintenance(
+            RuntimeOrigin::root(),
+            netuid,
+            true
+        ));
+        assert_eq!(SubnetMaintenanceStart::<Test>::get(netuid), Some(start));
+
+        // The subnet resumes after the maximum duration and its epochs run again.
+        step_block(20);
+        assert!(!SubtensorModule::is_subnet_in_maintenance(netuid));
+        assert!(System::events().iter().any(|e| {
+            e.event == RuntimeEvent::SubtensorModule(Event::SubnetMaintenanceExpired(netuid))
+        }));
+        step_block(3);
+        assert!(LastMechansimStepBlock::<Test>::get(netuid) > last_step);
+    });
+}
+
+#[test]
+fn test_subnet_offer_transfers_ownership_against_payment() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let buyer_coldkey = U256::from(3);
+        let buyer_hotkey = U256::from(4);
+        let other_coldkey = U256::from(5);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        let price = TaoCurrency::from(1_000_000_000);
+        SubtensorModule::create_account_if_non_existent(&buyer_coldkey, &buyer_hotkey);
+        SubtensorModule::add_balance_to_coldkey_account(&buyer_coldkey, 2_000_000_000);
+
+        // Only the owner can offer the subnet.
+        assert_noop!(
+            SubtensorModule::offer_subnet(
+                RuntimeOrigin::signed(buyer_coldkey),
+                netuid,
+                price,
+                None
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_ok!(SubtensorModule::offer_subnet(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            price,
+            Some(buyer_coldkey)
+        ));
+
+        // Only the chosen buyer can accept, at a price they agreed to.
+        assert_noop!(
+            SubtensorModule::accept_subnet_offer(
+                RuntimeOrigin::signed(other_coldkey),
+                netuid,
+                buyer_hotkey,
+                price
+            ),
+            Error::<Test>::NotSubnetOfferBuyer
+        );
+        assert_noop!(
+            SubtensorModule::accept_subnet_offer(
+                RuntimeOrigin::signed(buyer_coldkey),
+                netuid,
+                buyer_hotkey,
+                price - 1.into()
+            ),
+            Error::<Test>::SubnetOfferPriceTooHigh
+        );
+
+        let seller_balance = SubtensorModule::get_coldkey_balance(&owner_coldkey);
+        assert_ok!(SubtensorModule::accept_subnet_offer(
+            RuntimeOrigin::signed(buyer_coldkey),
+            netuid,
+            buyer_hotkey,
+            price
+        ));
+        assert_eq!(SubnetOwner::<Test>::get(netuid), buyer_coldkey);
+        assert_eq!(SubnetOwnerHotkey::<Test>::get(netuid), buyer_hotkey);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&owner_coldkey),
+            seller_balance + u64::from(price)
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&buyer_coldkey),
+            1_000_000_000
+        );
+        assert!(!SubnetOffers::<Test>::contains_key(netuid));
+
+        // The previous owner can no longer offer the subnet.
+        assert_noop!(
+            SubtensorModule::offer_subnet(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                price,
+                None
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+    });
+}
+
+#[test]
+fn test_subnet_treasury_spend_paid_at_quorum() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let owner_hotkey = U256::from(2);
+        let dest = U256::from(3);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubnetAlphaOut::<Test>::insert(netuid, AlphaCurrency::from(1_000));
+        for coldkey in [U256::from(10), U256::from(11)] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hotkey,
+                &coldkey,
+                netuid,
+                AlphaCurrency::from(300),
+            );
+        }
+        let treasury = SubtensorModule::get_subnet_treasury_account(netuid);
+        SubtensorModule::add_balance_to_coldkey_account(&treasury, 1_000_000);
+
+        // Only the subnet owner can propose a spend.
+        assert_noop!(
+            SubtensorModule::propose_subnet_treasury_spend(
+                RuntimeOrigin::signed(U256::from(10)),
+                netuid,
+                dest,
+                400_000.into()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::propose_subnet_treasury_spend(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            dest,
+            400_000.into()
+        ));
+        let spend_id = SubnetTreasurySpends::<Test>::get(netuid).unwrap().id;
+
+        // The spend is paid once the ayes reach the quorum.
+        assert_ok!(SubtensorModule::vote_subnet_treasury_spend(
+            RuntimeOrigin::signed(U256::from(10)),
+            netuid,
+            spend_id,
+            true
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&dest), 0);
+        assert_ok!(SubtensorModule::vote_subnet_treasury_spend(
+            RuntimeOrigin::signed(U256::from(11)),
+            netuid,
+            spend_id,
+            true
+        ));
+        assert!(SubnetTreasurySpends::<Test>::get(netuid).is_none());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&dest), 400_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&treasury), 600_000);
+    });
+}
@@ -1809,6 +1809,61 @@ fn test_schedule_swap_coldkey_duplicate() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_cancel_swap_coldkey --exact --show-output --nocapture
+#[test]
+fn test_cancel_swap_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+
+        let swap_cost = SubtensorModule::get_key_swap_cost();
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, swap_cost.to_u64() + 1_000);
+        let balance_before = SubtensorModule::get_coldkey_balance(&old_coldkey);
+
+        // Nothing to cancel yet
+        assert_noop!(
+            SubtensorModule::cancel_swap_coldkey(<<Test as Config>::RuntimeOrigin>::signed(
+                old_coldkey
+            )),
+            Error::<Test>::SwapNotScheduled
+        );
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        assert!(ColdkeySwapScheduledTask::<Test>::contains_key(old_coldkey));
+
+        assert_ok!(SubtensorModule::cancel_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey)
+        ));
+        System::assert_last_event(
+            Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapScheduledTask::<Test>::contains_key(old_coldkey));
+
+        // The swap never executes and nothing is charged
+        let execution_block = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        run_to_block(execution_block + 1);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            balance_before
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 0);
+
+        // The coldkey can schedule again right away
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=info cargo test --package pallet-subtensor --lib -- tests::swap_coldkey::test_schedule_swap_coldkey_execution --exact --show-output --nocapture
 #[test]
 fn test_schedule_swap_coldkey_execution() {
@@ -2593,3 +2648,145 @@ fn test_coldkey_in_swap_schedule_prevents_critical_calls() {
         );
     });
 }
+
+#[test]
+fn test_claim_coldkey_recovery() {
+    new_test_ext(1).execute_with(|| {
+        let lost_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let friends = vec![U256::from(10), U256::from(11), U256::from(12)];
+        let netuid = NetUid::from(1u16);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, lost_coldkey, 0);
+        SubnetOwner::<Test>::insert(netuid, lost_coldkey);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &lost_coldkey,
+            netuid,
+            1_000.into(),
+        );
+        let identity = ChainIdentityV2 {
+            name: b"lost".to_vec(),
+            url: vec![],
+            github_repo: vec![],
+            image: vec![],
+            discord: vec![],
+            description: vec![],
+            additional: vec![],
+        };
+        IdentitiesV2::<Test>::insert(lost_coldkey, identity.clone());
+
+        // The threshold cannot exceed the friends.
+        assert_noop!(
+            SubtensorModule::set_coldkey_recovery(
+                RuntimeOrigin::signed(lost_coldkey),
+                friends.clone(),
+                4,
+                10
+            ),
+            Error::<Test>::InvalidColdkeyRecovery
+        );
+        assert_ok!(SubtensorModule::set_coldkey_recovery(
+            RuntimeOrigin::signed(lost_coldkey),
+            friends.clone(),
+            2,
+            10
+        ));
+
+        // Only friends vouch, and the recovery needs enough of them.
+        assert_noop!(
+            SubtensorModule::vouch_coldkey_recovery(
+                RuntimeOrigin::signed(U256::from(13)),
+                lost_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::NotColdkeyRecoveryFriend
+        );
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            RuntimeOrigin::signed(U256::from(10)),
+            lost_coldkey,
+            new_coldkey
+        ));
+        assert_noop!(
+            SubtensorModule::claim_coldkey_recovery(
+                RuntimeOrigin::signed(new_coldkey),
+                lost_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotReady
+        );
+
+        // Enough vouches still wait for the delay.
+        assert_ok!(SubtensorModule::vouch_coldkey_recovery(
+            RuntimeOrigin::signed(U256::from(11)),
+            lost_coldkey,
+            new_coldkey
+        ));
+        assert_noop!(
+            SubtensorModule::claim_coldkey_recovery(
+                RuntimeOrigin::signed(new_coldkey),
+                lost_coldkey
+            ),
+            Error::<Test>::ColdkeyRecoveryNotReady
+        );
+        step_block(10);
+
+        // The claim moves the whole coldkey to the new one.
+        assert_ok!(SubtensorModule::claim_coldkey_recovery(
+            RuntimeOrigin::signed(new_coldkey),
+            lost_coldkey
+        ));
+        assert_eq!(Owner::<Test>::get(hotkey), new_coldkey);
+        assert_eq!(OwnedHotkeys::<Test>::get(new_coldkey), vec![hotkey]);
+        assert_eq!(SubnetOwner::<Test>::get(netuid), new_coldkey);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &new_coldkey,
+                netuid
+            ),
+            1_000.into()
+        );
+        assert_eq!(IdentitiesV2::<Test>::get(new_coldkey), Some(identity));
+        assert!(IdentitiesV2::<Test>::get(lost_coldkey).is_none());
+        assert!(ColdkeyRecoveryConfigs::<Test>::get(lost_coldkey).is_none());
+        assert_eq!(
+            ActiveColdkeyRecoveries::<Test>::iter_prefix(lost_coldkey).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_transaction_extension_rejects_disabled_call() {
+    new_test_ext(1).execute_with(|| {
+        let who = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let call =
+            RuntimeCall::SubtensorModule(SubtensorCall::schedule_swap_coldkey { new_coldkey });
+        let info: crate::DispatchInfo =
+            crate::DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = crate::SubtensorTransactionExtension::<Test>::new();
+        let validate = || {
+            extension.validate(
+                RawOrigin::Signed(who).into(),
+                &call,
+                &info,
+                10,
+                (),
+                &TxBaseImplication(()),
+                TransactionSource::External,
+            )
+        };
+
+        // Pause schedule_swap_coldkey, at call index 73
+        SubtensorModule::set_call_disabled(73, true);
+        assert_eq!(
+            validate().unwrap_err(),
+            CustomTransactionError::CallDisabled.into()
+        );
+
+        SubtensorModule::set_call_disabled(73, false);
+        assert_ok!(validate());
+    });
+}
@@ -174,6 +174,37 @@ fn test_swap_delegates() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey_with_subnet -- test_swap_delegate_stake_cap --exact --nocapture
+#[test]
+fn test_swap_delegate_stake_cap() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+
+        let netuid = add_dynamic_network(&old_hotkey, &coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, u64::MAX);
+
+        DelegateStakeCap::<Test>::insert(old_hotkey, TaoCurrency::from(1_000));
+        System::set_block_number(System::block_number() + HotkeySwapOnSubnetInterval::get());
+        assert_ok!(SubtensorModule::do_swap_hotkey(
+            RuntimeOrigin::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey,
+            Some(netuid)
+        ));
+
+        assert_eq!(
+            DelegateStakeCap::<Test>::get(old_hotkey),
+            Some(TaoCurrency::from(1_000))
+        );
+        assert_eq!(
+            DelegateStakeCap::<Test>::get(new_hotkey),
+            Some(TaoCurrency::from(1_000))
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey_with_subnet -- test_swap_subnet_membership --exact --nocapture
 #[test]
 fn test_swap_subnet_membership() {
@@ -0,0 +1,117 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+use frame_support::{assert_err, assert_ok};
+use sp_core::U256;
+use substrate_fixed::types::U64F64;
+use subtensor_runtime_common::NetUid;
+
+use super::mock::*;
+use crate::*;
+
+fn setup_staked_subnet(netuid: NetUid) -> (U256, U256) {
+    let hotkey = U256::from(1);
+    let coldkey = U256::from(2);
+    add_network(netuid, 1, 0);
+    register_ok_neuron(netuid, hotkey, coldkey, 0);
+    register_ok_neuron(netuid, U256::from(3), U256::from(4), 100_000);
+    SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+        &hotkey,
+        &coldkey,
+        netuid,
+        1_000_000_000.into(),
+    );
+    SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+        &hotkey,
+        &U256::from(5),
+        netuid,
+        500_000_000.into(),
+    );
+    StakingHotkeys::<Test>::insert(coldkey, vec![hotkey]);
+    (hotkey, coldkey)
+}
+
+#[test]
+fn test_check_hotkey_shares() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (hotkey, coldkey) = setup_staked_subnet(netuid);
+        assert_ok!(SubtensorModule::check_hotkey_shares());
+
+        // Shares credited to a staker without updating the hotkey's total are caught.
+        Alpha::<Test>::mutate((hotkey, coldkey, netuid), |share| {
+            *share = share.saturating_add(U64F64::from_num(10))
+        });
+        assert_err!(
+            SubtensorModule::check_hotkey_shares(),
+            "Alpha shares do not sum to TotalHotkeyShares"
+        );
+    });
+}
+
+#[test]
+fn test_check_staking_hotkeys_owned() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (hotkey, coldkey) = setup_staked_subnet(netuid);
+        assert_ok!(SubtensorModule::check_staking_hotkeys_owned());
+
+        StakingHotkeys::<Test>::insert(coldkey, vec![hotkey, U256::from(99)]);
+        assert_err!(
+            SubtensorModule::check_staking_hotkeys_owned(),
+            "Staking hotkey without an Owner"
+        );
+    });
+}
+
+#[test]
+fn test_check_subnetwork_n() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        setup_staked_subnet(netuid);
+        assert_ok!(SubtensorModule::check_subnetwork_n());
+
+        SubnetworkN::<Test>::mutate(netuid, |n| *n += 1);
+        assert_err!(
+            SubtensorModule::check_subnetwork_n(),
+            "SubnetworkN does not match the Keys entries"
+        );
+    });
+}
+
+#[test]
+fn test_try_state_after_unregister() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        let (hotkey, coldkey) = (U256::from(3), U256::from(4));
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 100_000);
+        crate::migrations::migrate_init_total_issuance::migrate_init_total_issuance::<Test>();
+        assert_ok!(SubtensorModule::do_try_state());
+
+        // The uid freed by unregister stays counted in SubnetworkN until it is filled again.
+        assert_ok!(SubtensorModule::unregister(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert_eq!(VacantUids::<Test>::get(netuid).len(), 1);
+        assert_ok!(SubtensorModule::do_try_state());
+    });
+}
+
+#[test]
+fn test_check_keys_uids() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let (hotkey, _) = setup_staked_subnet(netuid);
+        assert_ok!(SubtensorModule::check_keys_uids());
+
+        Uids::<Test>::insert(netuid, hotkey, 1);
+        assert_err!(
+            SubtensorModule::check_keys_uids(),
+            "Keys entry without a matching Uids entry"
+        );
+    });
+}
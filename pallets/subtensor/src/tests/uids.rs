@@ -563,3 +563,76 @@ fn test_get_neuron_to_prune_owner_pruned_if_not_in_sn_owner_hotkey_map() {
         );
     });
 }
+
+#[test]
+fn test_get_next_prunable_uid_prefers_non_immune_then_score_then_age() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        // Long tempo so no epoch overwrites the pruning scores set below.
+        add_network(netuid, 100, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 100);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 100);
+        SubtensorModule::set_immunity_period(netuid, 10);
+        assert_eq!(SubtensorModule::get_next_prunable_uid(netuid), None);
+
+        // uids 0 and 1 register at block 0, uid 2 at block 5.
+        register_ok_neuron(netuid, U256::from(0), U256::from(100), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(101), 1);
+        run_to_block(5);
+        register_ok_neuron(netuid, U256::from(2), U256::from(102), 2);
+
+        // All neurons are immune, the lowest score is chosen.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 3);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 2);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 2, 1);
+        assert_eq!(SubtensorModule::get_next_prunable_uid(netuid), Some(2));
+        assert_eq!(SubtensorModule::get_neuron_immunity_remaining(netuid, 0), 5);
+        assert_eq!(
+            SubtensorModule::get_neuron_immunity_remaining(netuid, 2),
+            10
+        );
+
+        // Once out of immunity, uids 0 and 1 are pruned first even with higher scores.
+        run_to_block(10);
+        assert_eq!(SubtensorModule::get_neuron_immunity_remaining(netuid, 0), 0);
+        assert_eq!(SubtensorModule::get_next_prunable_uid(netuid), Some(1));
+
+        // Equal scores and registration blocks go to the lowest uid.
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 3);
+        assert_eq!(SubtensorModule::get_next_prunable_uid(netuid), Some(0));
+
+        // Looking up the next prunable uid does not touch the pruning scores.
+        assert_eq!(SubtensorModule::get_pruning_score_for_uid(netuid, 0), 3);
+        assert!(SubtensorModule::get_pruning_info(netuid).is_some());
+        assert!(SubtensorModule::get_pruning_info(NetUid::from(2)).is_none());
+    });
+}
+
+#[test]
+fn test_replace_neuron_resets_bonds_on_deregistration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 100, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        for key in 0..3u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+        }
+        // uid 0 bonds to uids 1 and 2, uid 1 bonds to uid 2.
+        Bonds::<Test>::insert(netuid, 0, vec![(1, 100), (2, 200)]);
+        Bonds::<Test>::insert(netuid, 1, vec![(2, 300)]);
+
+        // By default only the bonds of the replaced neuron are removed.
+        SubtensorModule::replace_neuron(netuid, 1, &U256::from(10), 0);
+        assert!(Bonds::<Test>::get(netuid, 1).is_empty());
+        assert_eq!(Bonds::<Test>::get(netuid, 0), vec![(1, 100), (2, 200)]);
+
+        // With the reset on, the bonds held on the replaced neuron are removed as well.
+        SubtensorModule::set_bonds_reset_on_deregistration(netuid, true);
+        Bonds::<Test>::insert(netuid, 1, vec![(2, 300)]);
+        SubtensorModule::replace_neuron(netuid, 2, &U256::from(11), 0);
+        assert_eq!(Bonds::<Test>::get(netuid, 0), vec![(1, 100)]);
+        assert!(Bonds::<Test>::get(netuid, 1).is_empty());
+        assert!(Bonds::<Test>::get(netuid, 2).is_empty());
+    });
+}
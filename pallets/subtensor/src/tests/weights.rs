@@ -1730,6 +1730,113 @@ fn test_commit_reveal_weights_ok() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_commit_weights_cross_netuid_replay --exact --show-output --nocapture
+#[test]
+fn test_commit_weights_cross_netuid_replay() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let other_netuid = NetUid::from(2);
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+        let attacker: U256 = U256::from(5);
+
+        let commit_hash = SubtensorModule::get_commit_hash(
+            &hotkey,
+            netuid,
+            &uids,
+            &weight_values,
+            &salt,
+            version_key,
+        );
+
+        // The payload is bound to the subnet and the hotkey.
+        assert_ne!(
+            SubtensorModule::get_commit_hash(
+                &hotkey,
+                other_netuid,
+                &uids,
+                &weight_values,
+                &salt,
+                version_key
+            ),
+            commit_hash
+        );
+        assert_ne!(
+            SubtensorModule::get_commit_hash(
+                &attacker,
+                netuid,
+                &uids,
+                &weight_values,
+                &salt,
+                version_key
+            ),
+            commit_hash
+        );
+
+        System::set_block_number(0);
+
+        let tempo: u16 = 5;
+        add_network(netuid, tempo, 0);
+        add_network(other_netuid, tempo, 0);
+
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        register_ok_neuron(other_netuid, attacker, U256::from(6), 200_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_weights_set_rate_limit(other_netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_commit_reveal_weights_enabled(other_netuid, true);
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        // The hotkey cannot commit the same hash twice while it is unrevealed.
+        assert_err!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            Error::<Test>::DuplicateWeightCommit
+        );
+
+        // Another hotkey committing the captured hash does not block the hotkey, but cannot
+        // reveal it on its subnet.
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(attacker),
+            other_netuid,
+            commit_hash
+        ));
+
+        step_epochs(1, netuid);
+
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+        assert_err!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(attacker),
+                other_netuid,
+                uids,
+                weight_values,
+                salt,
+                version_key,
+            ),
+            Error::<Test>::InvalidRevealCommitHashNotMatch
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_commit_reveal_tempo_interval --exact --show-output --nocapture
 #[test]
 fn test_commit_reveal_tempo_interval() {
@@ -6807,3 +6914,307 @@ fn test_reveal_crv3_commits_legacy_payload_success() {
         );
     });
 }
+
+#[test]
+fn test_commit_reveal_status_tracks_pending_commits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let other = U256::from(2);
+        add_network(netuid, 5, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let status = SubtensorModule::get_commit_reveal_status(netuid, Some(&hotkey)).unwrap();
+        assert!(status.enabled);
+        assert_eq!(status.outstanding_commits, 0.into());
+        assert!(status.hotkey_last_commit_revealed);
+
+        WeightCommits::<Test>::insert(
+            netuid,
+            hotkey,
+            VecDeque::from(vec![(H256::zero(), 3, 10, 20), (H256::zero(), 7, 14, 24)]),
+        );
+        WeightCommits::<Test>::insert(
+            netuid,
+            other,
+            VecDeque::from(vec![(H256::zero(), 5, 12, 22)]),
+        );
+
+        let status = SubtensorModule::get_commit_reveal_status(netuid, Some(&hotkey)).unwrap();
+        assert_eq!(status.outstanding_commits, 3.into());
+        assert_eq!(status.hotkey_pending_commits, 2.into());
+        assert_eq!(status.hotkey_last_commit_block, Some(7.into()));
+        assert!(!status.hotkey_last_commit_revealed);
+
+        // Without a hotkey only subnet-wide figures are reported.
+        let status = SubtensorModule::get_commit_reveal_status(netuid, None).unwrap();
+        assert_eq!(status.outstanding_commits, 3.into());
+        assert_eq!(status.hotkey_pending_commits, 0.into());
+
+        assert!(SubtensorModule::get_commit_reveal_status(NetUid::from(99), None).is_none());
+    });
+}
+
+#[test]
+fn test_batch_set_weights_reports_failed_netuids() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid_ok = NetUid::from(1);
+        let netuid_err = NetUid::from(2);
+        for netuid in [netuid_ok, netuid_err] {
+            add_network(netuid, 1, 0);
+            register_ok_neuron(netuid, hotkey, coldkey, 10);
+        }
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid_ok, false);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid_err, true);
+
+        assert_ok!(SubtensorModule::batch_set_weights(
+            RuntimeOrigin::signed(hotkey),
+            vec![netuid_ok.into(), netuid_err.into()],
+            vec![vec![(0.into(), 1.into())], vec![(0.into(), 1.into())]],
+            vec![0.into(), 0.into()],
+        ));
+
+        // The failed item names its netuid, the batch still reports every netuid.
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::BatchWeightItemFailed(
+            Error::<Test>::CommitRevealEnabled.into(),
+        )));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            Event::BatchWeightItemFailedOnSubnet(
+                hotkey,
+                netuid_err,
+                Error::<Test>::CommitRevealEnabled.into(),
+            ),
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::BatchWeightsCompleted(
+            vec![netuid_ok.into(), netuid_err.into()],
+            hotkey,
+        )));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_set_weights_with_weights_key --exact --show-output --nocapture
+// Tests that a weights key authorized by the hotkey owner sets weights for the hotkey.
+#[test]
+fn test_set_weights_with_weights_key() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey = U256::from(55);
+        let coldkey = U256::from(66);
+        let weights_key = U256::from(77);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        // Only the owner of the hotkey can authorize a weights key, which cannot be a hotkey.
+        assert_err!(
+            SubtensorModule::set_weights_key(
+                RuntimeOrigin::signed(weights_key),
+                hotkey,
+                Some(weights_key)
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_err!(
+            SubtensorModule::set_weights_key(RuntimeOrigin::signed(coldkey), hotkey, Some(hotkey)),
+            Error::<Test>::InvalidWeightsKey
+        );
+
+        // Without authorization the weights key sets weights for itself.
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(weights_key),
+                netuid,
+                vec![uid],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            Some(weights_key)
+        ));
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            vec![uid],
+            vec![1],
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, uid), vec![(uid, u16::MAX)]);
+
+        // Once revoked, the weights key no longer acts for the hotkey.
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            None
+        ));
+        assert_eq!(WeightsKeyHotkey::<Test>::get(weights_key), None);
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(weights_key),
+                netuid,
+                vec![uid],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+    });
+}
+
+#[test]
+fn test_approve_weights_of_multisig_hotkey() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey = U256::from(55);
+        let coldkey = U256::from(66);
+        let operators = vec![U256::from(1), U256::from(2), U256::from(3)];
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        let approve = |operator: U256, value: u16| {
+            SubtensorModule::approve_weights(
+                RuntimeOrigin::signed(operator),
+                hotkey,
+                netuid,
+                vec![uid],
+                vec![value],
+                0,
+            )
+        };
+
+        // The threshold cannot exceed the operators.
+        assert_err!(
+            SubtensorModule::set_hotkey_multisig(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                operators.clone(),
+                4
+            ),
+            Error::<Test>::InvalidHotkeyMultisig
+        );
+        assert_ok!(SubtensorModule::set_hotkey_multisig(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            operators.clone(),
+            2
+        ));
+
+        // The hotkey can no longer set weights alone, nor can strangers approve them.
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![uid],
+                vec![1],
+                0
+            ),
+            Error::<Test>::WeightsRequireMultisig
+        );
+        assert_err!(
+            approve(U256::from(4), 1),
+            Error::<Test>::NotHotkeyMultisigOperator
+        );
+
+        // Approvals of different weights do not add up, nor do repeated approvals.
+        assert_ok!(approve(U256::from(1), 1));
+        assert_ok!(approve(U256::from(1), 1));
+        assert_ok!(approve(U256::from(2), 2));
+        assert!(Weights::<Test>::get(netuid, uid).is_empty());
+
+        // The weights are set once the threshold approves them.
+        assert_ok!(approve(U256::from(3), 1));
+        assert_eq!(Weights::<Test>::get(netuid, uid), vec![(uid, u16::MAX)]);
+        assert_eq!(WeightApprovals::<Test>::iter_prefix(hotkey).count(), 0);
+
+        // Without a multisig, the hotkey sets weights alone again.
+        assert_ok!(SubtensorModule::set_hotkey_multisig(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            vec![],
+            0
+        ));
+        assert!(HotkeyMultisigs::<Test>::get(hotkey).is_none());
+    });
+}
+
+#[test]
+fn test_set_weights_delta() {
+    new_test_ext(0).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        for key in 1u64..=3 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), 2143124);
+        }
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0, 1, 2],
+            vec![u16::MAX, 30_000, 10_000],
+            0
+        ));
+
+        // A uid cannot be changed twice.
+        assert_err!(
+            SubtensorModule::set_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![(1, 1), (1, -1)],
+                0
+            ),
+            Error::<Test>::DuplicateUids
+        );
+
+        // Weights dropping to zero are removed, and the others are merged.
+        assert_ok!(SubtensorModule::set_weights_delta(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![(1, -40_000), (2, 20_000)],
+            0
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, 0),
+            vec![(0, u16::MAX), (2, 30_000)]
+        );
+    });
+}
+
+#[test]
+fn test_set_weights_priority_near_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let validator = U256::from(1);
+        let miner = U256::from(2);
+        add_network(netuid, 10, 0);
+        register_ok_neuron(netuid, validator, validator, 2143124);
+        register_ok_neuron(netuid, miner, miner, 3124124);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_weights_priority_window(3);
+
+        // Netuid 1 with tempo 10 runs its epoch when (block + 2) % 11 == 0.
+        System::set_block_number(15);
+        assert!(!SubtensorModule::is_in_weights_priority_window(netuid, 15));
+        let validator_before = SubtensorModule::get_priority_set_weights(&validator, netuid);
+        let miner_before = SubtensorModule::get_priority_set_weights(&miner, netuid);
+
+        System::set_block_number(17);
+        assert!(SubtensorModule::is_in_weights_priority_window(netuid, 17));
+        assert_eq!(
+            SubtensorModule::get_priority_set_weights(&validator, netuid),
+            validator_before + 2 + u32::MAX as u64
+        );
+        // Without a validator permit there is no boost.
+        assert_eq!(
+            SubtensorModule::get_priority_set_weights(&miner, netuid),
+            miner_before + 2
+        );
+    });
+}
@@ -0,0 +1,222 @@
+use super::*;
+use crate::utils::ledger::LedgerAccount;
+use sp_std::vec::Vec;
+
+impl<T: Config> Event<T> {
+    /// Accounts (hotkeys and coldkeys) referenced by this event.
+    ///
+    /// Used by node-side indexers to map accounts to the blocks they appear in, so it only has to
+    /// be kept in sync with events that carry account ids.
+    pub fn accounts(&self) -> Vec<&T::AccountId> {
+        match self {
+            Event::StakeAdded(coldkey, hotkey, ..)
+            | Event::StakeRemoved(coldkey, hotkey, ..)
+            | Event::DelegateAdded(coldkey, hotkey, _)
+            | Event::TakeDecreased(coldkey, hotkey, _)
+            | Event::TakeIncreased(coldkey, hotkey, _)
+            | Event::TakeIncreaseScheduled(coldkey, hotkey, ..)
+            | Event::DelegateStakeCapSet(coldkey, hotkey, _)
+            | Event::DelegateStakingAccessSet(coldkey, hotkey, _)
+            | Event::StakeSwapped(coldkey, hotkey, ..)
+            | Event::AlphaRecycled(coldkey, hotkey, ..)
+            | Event::AlphaBurned(coldkey, hotkey, ..) => sp_std::vec![coldkey, hotkey],
+            Event::RegistrationBidPlaced {
+                coldkey, hotkey, ..
+            }
+            | Event::RegistrationBidRefunded {
+                coldkey, hotkey, ..
+            }
+            | Event::AutoCompoundSet {
+                coldkey, hotkey, ..
+            }
+            | Event::StakeLocked {
+                coldkey, hotkey, ..
+            }
+            | Event::StakeUnlocked {
+                coldkey, hotkey, ..
+            } => sp_std::vec![coldkey, hotkey],
+            Event::SubnetProposalOpened {
+                proposer: account, ..
+            }
+            | Event::SubnetProposalVoted {
+                coldkey: account, ..
+            }
+            | Event::SubnetTreasurySpendProposed { dest: account, .. }
+            | Event::SubnetTreasurySpendVoted {
+                coldkey: account, ..
+            }
+            | Event::SubnetTreasurySpent { dest: account, .. }
+            | Event::SubnetOfferCancelled {
+                seller: account, ..
+            } => sp_std::vec![account],
+            Event::OffenderSlashed {
+                hotkey, reporter, ..
+            } => sp_std::vec![hotkey, reporter],
+            Event::SubnetOffered { seller, buyer, .. } => {
+                let mut accounts = sp_std::vec![seller];
+                accounts.extend(buyer.as_ref());
+                accounts
+            }
+            Event::SubnetSold { seller, buyer, .. } => sp_std::vec![seller, buyer],
+            Event::HotkeyMultisigSet {
+                coldkey,
+                hotkey,
+                operators,
+                ..
+            } => {
+                let mut accounts = sp_std::vec![coldkey, hotkey];
+                accounts.extend(operators.iter());
+                accounts
+            }
+            Event::WeightsApproved {
+                hotkey, operator, ..
+            } => sp_std::vec![hotkey, operator],
+            Event::SubnetTransactionFeePaid { who, .. } => sp_std::vec![who],
+            Event::NeuronRegistrationSponsored {
+                hotkey,
+                coldkey,
+                sponsor,
+                ..
+            } => sp_std::vec![hotkey, coldkey, sponsor],
+            Event::WeightsKeySet {
+                coldkey,
+                hotkey,
+                weights_key,
+            } => {
+                let mut accounts = sp_std::vec![coldkey, hotkey];
+                accounts.extend(weights_key.as_ref());
+                accounts
+            }
+            Event::EmissionRecipientSet {
+                coldkey,
+                hotkey,
+                recipient,
+            } => {
+                let mut accounts = sp_std::vec![coldkey, hotkey];
+                accounts.extend(recipient.as_ref());
+                accounts
+            }
+            Event::ColdkeyRecoverySet {
+                coldkey, friends, ..
+            } => {
+                let mut accounts = sp_std::vec![coldkey];
+                accounts.extend(friends.iter());
+                accounts
+            }
+            Event::ColdkeyRecoveryVouched {
+                lost_coldkey,
+                new_coldkey,
+                friend,
+                ..
+            } => sp_std::vec![lost_coldkey, new_coldkey, friend],
+            Event::ColdkeyRecoveryCancelled {
+                lost_coldkey,
+                new_coldkey,
+            }
+            | Event::ColdkeyRecovered {
+                lost_coldkey,
+                new_coldkey,
+            } => sp_std::vec![lost_coldkey, new_coldkey],
+            Event::StakeMoved(coldkey, origin_hotkey, _, destination_hotkey, ..) => {
+                sp_std::vec![coldkey, origin_hotkey, destination_hotkey]
+            }
+            Event::StakeTransferred(origin_coldkey, destination_coldkey, hotkey, ..) => {
+                sp_std::vec![origin_coldkey, destination_coldkey, hotkey]
+            }
+            Event::NeuronRegistered(_, _, hotkey)
+            | Event::AxonServed(_, hotkey)
+            | Event::PrometheusServed(_, hotkey)
+            | Event::SubnetOwnerHotkeySet(_, hotkey)
+            | Event::CRV3WeightsRevealed(_, hotkey)
+            | Event::EvmKeyAssociated { hotkey, .. }
+            | Event::NeuronUnregistered { hotkey, .. }
+            | Event::VestedEmissionClaimed { hotkey, .. }
+            | Event::VestingEmissionWithheld { hotkey, .. } => sp_std::vec![hotkey],
+            Event::ChildKeyTakeSet(account, _)
+            | Event::Faucet(account, _)
+            | Event::ChainIdentitySet(account)
+            | Event::StakeUnbonding {
+                coldkey: account, ..
+            }
+            | Event::UnbondedWithdrawn {
+                coldkey: account, ..
+            }
+            | Event::EvmDeposited {
+                coldkey: account, ..
+            }
+            | Event::EvmWithdrawalQueued {
+                coldkey: account, ..
+            }
+            | Event::EvmWithdrawalClaimed {
+                coldkey: account, ..
+            }
+            | Event::ArbitrationPeriodExtended { coldkey: account }
+            | Event::StakingRateLimitExemptionGranted(account)
+            | Event::StakingRateLimitExemptionRevoked(account)
+            | Event::DissolveNetworkScheduled { account, .. }
+            | Event::CRV3WeightsCommitted(account, ..)
+            | Event::WeightsCommitted(account, ..)
+            | Event::WeightsRevealed(account, ..)
+            | Event::WeightsBatchRevealed(account, ..)
+            | Event::BatchWeightsCompleted(_, account)
+            | Event::BatchWeightItemFailedOnSubnet(account, ..)
+            | Event::SubnetLeaseCreated {
+                beneficiary: account,
+                ..
+            }
+            | Event::SubnetLeaseTerminated {
+                beneficiary: account,
+                ..
+            } => sp_std::vec![account],
+            Event::HotkeySwapped {
+                coldkey,
+                old_hotkey,
+                new_hotkey,
+            }
+            | Event::HotkeySwappedOnSubnet {
+                coldkey,
+                old_hotkey,
+                new_hotkey,
+                ..
+            } => sp_std::vec![coldkey, old_hotkey, new_hotkey],
+            Event::SenateAdjusted {
+                old_member,
+                new_member,
+            } => old_member.iter().chain([new_member]).collect(),
+            Event::ColdkeySwapped {
+                old_coldkey,
+                new_coldkey,
+                ..
+            }
+            | Event::ColdkeySwapScheduled {
+                old_coldkey,
+                new_coldkey,
+                ..
+            }
+            | Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            | Event::AllBalanceUnstakedAndTransferredToNewColdkey {
+                current_coldkey: old_coldkey,
+                new_coldkey,
+                ..
+            } => sp_std::vec![old_coldkey, new_coldkey],
+            Event::DelegateStakingListUpdated {
+                hotkey, coldkeys, ..
+            } => core::iter::once(hotkey).chain(coldkeys.iter()).collect(),
+            Event::SetChildrenScheduled(hotkey, _, _, children)
+            | Event::SetChildren(hotkey, _, children) => core::iter::once(hotkey)
+                .chain(children.iter().map(|(_, child)| child))
+                .collect(),
+            Event::TaoTransferred { from, to, .. } => [from, to]
+                .into_iter()
+                .filter_map(|account| match account {
+                    LedgerAccount::Coldkey(coldkey) => Some(coldkey),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Deposits an event of the pallet, after handing it to the configured event bridge.
+    pub(crate) fn deposit_event(event: Event<T>) {
+        T::EventBridge::on_event(&event);
+        Self::deposit_pallet_event(event);
+    }
+}
@@ -22,6 +22,25 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// The EVM address whose key signed `message` as an EIP-191 personal message.
+    pub(crate) fn recover_evm_signer<M: AsRef<[u8]>>(
+        message: M,
+        mut signature: Signature,
+    ) -> Result<H160, Error<T>> {
+        // Normalize the v value to 0 or 1
+        if signature.0[64] >= 27 {
+            signature.0[64] = signature.0[64].saturating_sub(27);
+        }
+
+        let public = signature
+            .recover_prehashed(&Self::hash_message_eip191(message))
+            .ok_or(Error::<T>::InvalidIdentity)?;
+        let secp_pubkey = libsecp256k1::PublicKey::parse_compressed(&public.0)
+            .map_err(|_| Error::<T>::UnableToRecoverPublicKey)?;
+        let uncompressed = secp_pubkey.serialize();
+        Ok(H160::from_slice(&keccak_256(&uncompressed[1..])[12..]))
+    }
+
     /// Associate an EVM key with a hotkey.
     ///
     /// This function accepts a Signature, which is a signed message containing the hotkey concatenated with
@@ -46,26 +65,15 @@ impl<T: Config> Pallet<T> {
         netuid: NetUid,
         evm_key: H160,
         block_number: u64,
-        mut signature: Signature,
+        signature: Signature,
     ) -> dispatch::DispatchResult {
         let hotkey = ensure_signed(origin)?;
 
-        // Normalize the v value to 0 or 1
-        if signature.0[64] >= 27 {
-            signature.0[64] = signature.0[64].saturating_sub(27);
-        }
-
         let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
 
         let block_hash = keccak_256(block_number.encode().as_ref());
         let message = [hotkey.encode().as_ref(), block_hash.as_ref()].concat();
-        let public = signature
-            .recover_prehashed(&Self::hash_message_eip191(message))
-            .ok_or(Error::<T>::InvalidIdentity)?;
-        let secp_pubkey = libsecp256k1::PublicKey::parse_compressed(&public.0)
-            .map_err(|_| Error::<T>::UnableToRecoverPublicKey)?;
-        let uncompressed = secp_pubkey.serialize();
-        let hashed_evm_key = H160::from_slice(&keccak_256(&uncompressed[1..])[12..]);
+        let hashed_evm_key = Self::recover_evm_signer(message, signature)?;
 
         ensure!(
             evm_key == hashed_evm_key,
@@ -0,0 +1,232 @@
+use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
+use frame_system::ensure_signed;
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+use subtensor_runtime_common::{Currency, TaoCurrency};
+
+impl<T: Config> Pallet<T> {
+    pub fn get_evm_withdrawal_delay() -> u64 {
+        EvmWithdrawalDelay::<T>::get()
+    }
+    pub fn set_evm_withdrawal_delay(delay: u64) -> DispatchResult {
+        ensure!(
+            delay <= MAX_EVM_WITHDRAWAL_DELAY,
+            Error::<T>::EvmWithdrawalDelayTooLong
+        );
+        EvmWithdrawalDelay::<T>::put(delay);
+        Self::deposit_event(Event::EvmWithdrawalDelaySet(delay));
+        Ok(())
+    }
+
+    /// The account holding the balance of `evm_address`.
+    pub fn evm_address_account(evm_address: H160) -> T::AccountId {
+        T::EvmAddressMapping::convert(evm_address)
+    }
+
+    /// TAO held by `evm_address`.
+    pub fn get_evm_address_balance(evm_address: H160) -> TaoCurrency {
+        Self::get_coldkey_balance(&Self::evm_address_account(evm_address)).into()
+    }
+
+    /// TAO held by the EVM addresses that used the bridge. It is read from their balances, so it
+    /// accounts for every flow in and out of them, not only those of the bridge.
+    pub fn get_total_evm_locked() -> TaoCurrency {
+        EvmBridgeAddresses::<T>::iter_keys().fold(TaoCurrency::ZERO, |total, evm_address| {
+            total.saturating_add(Self::get_evm_address_balance(evm_address))
+        })
+    }
+
+    /// Total TAO in `coldkey`'s EVM withdrawal queue, and the part of it that can be claimed now.
+    pub fn get_evm_withdrawal_balance(coldkey: &T::AccountId) -> (TaoCurrency, TaoCurrency) {
+        let now = Self::get_current_block_as_u64();
+        EvmWithdrawalQueue::<T>::get(coldkey).iter().fold(
+            (TaoCurrency::ZERO, TaoCurrency::ZERO),
+            |(total, released), (block, amount)| {
+                let released = if *block <= now {
+                    released.saturating_add(*amount)
+                } else {
+                    released
+                };
+                (total.saturating_add(*amount), released)
+            },
+        )
+    }
+
+    /// ---- The implementation for the extrinsic deposit_to_evm: Moves TAO from the caller's
+    /// coldkey to the account holding the balance of an EVM address.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'evm_address' (H160):
+    ///     -  The EVM address to credit.
+    ///
+    /// * 'amount' (TaoCurrency):
+    ///     -  The amount of TAO to deposit.
+    ///
+    /// # Event:
+    /// * EvmDeposited;
+    ///     -  On successfully depositing the TAO.
+    ///
+    /// # Raises:
+    /// * 'InsufficientBalance':
+    ///     -  Thrown if the coldkey does not hold the amount.
+    ///
+    pub fn do_deposit_to_evm(
+        origin: T::RuntimeOrigin,
+        evm_address: H160,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, amount.into()),
+            Error::<T>::InsufficientBalance
+        );
+
+        let amount = Self::transfer_tao(
+            LedgerAccount::Coldkey(coldkey.clone()),
+            LedgerAccount::Coldkey(Self::evm_address_account(evm_address)),
+            amount,
+            LedgerReason::EvmDeposit,
+        )?;
+        EvmBridgeAddresses::<T>::insert(evm_address, ());
+
+        Self::deposit_event(Event::EvmDeposited {
+            coldkey,
+            evm_address,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic withdraw_from_evm: Moves TAO from the account
+    /// holding the balance of an EVM address to the EVM withdrawal queue of a coldkey, where it
+    /// waits out [`EvmWithdrawalDelay`].
+    ///
+    /// Chunks with the same release block are merged, and once the queue holds
+    /// [`MAX_EVM_WITHDRAWAL_CHUNKS`] entries new TAO is folded into the last chunk.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the account holding the balance of the EVM address.
+    ///
+    /// * 'evm_address' (H160):
+    ///     -  The EVM address to debit.
+    ///
+    /// * 'coldkey' (T::AccountId):
+    ///     -  The coldkey to release the TAO to.
+    ///
+    /// * 'amount' (TaoCurrency):
+    ///     -  The amount of TAO to withdraw.
+    ///
+    /// # Event:
+    /// * EvmWithdrawalQueued;
+    ///     -  On successfully queueing the withdrawal.
+    ///
+    /// # Raises:
+    /// * 'EvmAddressNotCaller':
+    ///     -  Thrown if the EVM address does not map to the caller.
+    ///
+    /// * 'InsufficientBalance':
+    ///     -  Thrown if the EVM account does not hold the amount.
+    ///
+    pub fn do_withdraw_from_evm(
+        origin: T::RuntimeOrigin,
+        evm_address: H160,
+        coldkey: T::AccountId,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        let who = ensure_signed(origin)?;
+        ensure!(
+            Self::evm_address_account(evm_address) == who,
+            Error::<T>::EvmAddressNotCaller
+        );
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&who, amount.into()),
+            Error::<T>::InsufficientBalance
+        );
+
+        let amount = Self::transfer_tao(
+            LedgerAccount::Coldkey(who),
+            LedgerAccount::EvmWithdrawals,
+            amount,
+            LedgerReason::EvmWithdrawal,
+        )?;
+        EvmBridgeAddresses::<T>::insert(evm_address, ());
+
+        let release_block =
+            Self::get_current_block_as_u64().saturating_add(Self::get_evm_withdrawal_delay());
+        EvmWithdrawalQueue::<T>::mutate(&coldkey, |queue| {
+            // The delay can be changed by root, so keep the queue sorted.
+            match queue.iter_mut().find(|(block, _)| *block == release_block) {
+                Some((_, queued)) => *queued = queued.saturating_add(amount),
+                None if queue.len() < MAX_EVM_WITHDRAWAL_CHUNKS => {
+                    queue.push((release_block, amount));
+                    queue.sort_by_key(|(block, _)| *block);
+                }
+                None => {
+                    if let Some((block, queued)) = queue.last_mut() {
+                        *block = (*block).max(release_block);
+                        *queued = queued.saturating_add(amount);
+                    }
+                }
+            }
+        });
+
+        Self::deposit_event(Event::EvmWithdrawalQueued {
+            evm_address,
+            coldkey,
+            amount,
+            release_block,
+        });
+
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic claim_evm_withdrawals: Moves every chunk of the
+    /// caller's EVM withdrawal queue whose release block has passed to its free balance.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// # Event:
+    /// * EvmWithdrawalClaimed;
+    ///     -  On successfully claiming the TAO.
+    ///
+    /// # Raises:
+    /// * 'NoReleasedEvmWithdrawal':
+    ///     -  Thrown if no chunk of the coldkey's EVM withdrawal queue has been released yet.
+    ///
+    pub fn do_claim_evm_withdrawals(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let now = Self::get_current_block_as_u64();
+
+        let (released, pending): (Vec<_>, Vec<_>) = EvmWithdrawalQueue::<T>::get(&coldkey)
+            .into_iter()
+            .partition(|(block, _)| *block <= now);
+        let amount = released.iter().fold(TaoCurrency::ZERO, |acc, (_, amount)| {
+            acc.saturating_add(*amount)
+        });
+        ensure!(!amount.is_zero(), Error::<T>::NoReleasedEvmWithdrawal);
+
+        if pending.is_empty() {
+            EvmWithdrawalQueue::<T>::remove(&coldkey);
+        } else {
+            EvmWithdrawalQueue::<T>::insert(&coldkey, pending);
+        }
+        Self::transfer_tao(
+            LedgerAccount::EvmWithdrawals,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            amount,
+            LedgerReason::EvmWithdrawal,
+        )?;
+
+        Self::deposit_event(Event::EvmWithdrawalClaimed { coldkey, amount });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use super::*;
+use crate::utils::ledger::{LedgerAccount, LedgerReason};
 use frame_support::ensure;
 use frame_system::ensure_signed;
+use sp_core::H256;
 use sp_std::vec::Vec;
-use subtensor_runtime_common::NetUid;
+use subtensor_runtime_common::{Currency, NetUid};
 
 impl<T: Config> Pallet<T> {
     /// Sets the identity for a coldkey.
@@ -134,6 +136,22 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidIdentity
         );
 
+        // Burn the identity fee from the owner
+        let fee = SubnetIdentityFee::<T>::get();
+        if !fee.is_zero() {
+            ensure!(
+                Self::can_remove_balance_from_coldkey_account(&coldkey, fee.into()),
+                Error::<T>::NotEnoughBalanceToPaySubnetIdentityFee
+            );
+            let actual_fee = Self::transfer_tao(
+                LedgerAccount::Coldkey(coldkey.clone()),
+                LedgerAccount::Issuance,
+                fee,
+                LedgerReason::Fee,
+            )?;
+            Self::record_subnet_tao_burned(netuid, actual_fee);
+        }
+
         // Store the validated identity in the blockchain state
         SubnetIdentitiesV3::<T>::insert(netuid, identity.clone());
 
@@ -147,6 +165,33 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Sets or removes the hash of the logo of a subnet.
+    ///
+    /// The hash lets clients check the image served at the `logo_url` of the subnet identity.
+    /// Only the owner of the subnet can set it.
+    pub fn do_set_subnet_logo_hash(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        logo_hash: Option<H256>,
+    ) -> dispatch::DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::get_subnet_owner(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+
+        match logo_hash {
+            Some(hash) => SubnetLogoHashes::<T>::insert(netuid, hash),
+            None => SubnetLogoHashes::<T>::remove(netuid),
+        }
+
+        log::debug!("SubnetLogoHashSet( netuid:{netuid:?} logo_hash:{logo_hash:?} ) ");
+        Self::deposit_event(Event::SubnetLogoHashSet(netuid, logo_hash));
+
+        Ok(())
+    }
+
     /// Validates the given ChainIdentityOf struct.
     ///
     /// This function checks if the total length of all fields in the ChainIdentityOf struct
@@ -0,0 +1,140 @@
+use super::*;
+use subtensor_runtime_common::{Currency, TaoCurrency};
+
+/// An account of the pallet's internal TAO ledger.
+///
+/// Every TAO movement inside the pallet is a transfer between two of these accounts, so the
+/// balances of the coldkeys plus [`TotalStake`], [`TotalUnbonding`], [`TotalRegistrationBids`] and
+/// [`TotalEvmWithdrawals`] always add up to [`TotalIssuance`]. The one exception is the TAO the
+/// coinbase buys the subsidized alpha with, which is added to [`TotalStake`] without being issued.
+#[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub enum LedgerAccount<AccountId> {
+    /// The free balance of a coldkey.
+    Coldkey(AccountId),
+    /// TAO held in the subnet pools, tracked by [`TotalStake`].
+    Stake,
+    /// TAO waiting out an unbonding period, tracked by [`TotalUnbonding`].
+    Unbonding,
+    /// TAO escrowed in registration bids, tracked by [`TotalRegistrationBids`].
+    RegistrationBids,
+    /// TAO withdrawn from the EVM waiting out the delay, tracked by [`TotalEvmWithdrawals`].
+    EvmWithdrawals,
+    /// The balancing account: debiting it issues new TAO and crediting it burns TAO.
+    Issuance,
+}
+
+/// Why TAO moved between two [`LedgerAccount`]s.
+#[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LedgerReason {
+    /// A coldkey staked TAO into a subnet pool.
+    Stake,
+    /// TAO was paid out of a subnet pool for unstaked alpha.
+    Unstake,
+    /// Unbonded TAO was withdrawn to its coldkey.
+    Unbonded,
+    /// Block emission was injected into a subnet pool.
+    Emission,
+    /// A burned registration paid into a subnet pool.
+    Registration,
+    /// A registration bid was placed, refunded or settled.
+    RegistrationBid,
+    /// A subnet lock was paid, or seeded a new subnet pool.
+    NetworkLock,
+    /// A subnet lock was refunded to its owner.
+    NetworkLockRefund,
+    /// A subnet was dissolved and its pool paid out or burned.
+    NetworkDissolution,
+    /// A fee was burned, e.g. for swapping keys or setting an identity.
+    Fee,
+    /// An incentive matching fund matched a subnet's emission.
+    IncentiveMatching,
+    /// The faucet issued TAO.
+    Faucet,
+    /// A coldkey deposited TAO into an EVM account.
+    EvmDeposit,
+    /// An EVM account withdrew TAO, or the withdrawal was claimed by its coldkey.
+    EvmWithdrawal,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Moves `amount` of TAO from `from` to `to` and deposits [`Event::TaoTransferred`].
+    ///
+    /// The debit is applied first and the credit is for exactly the amount debited, so every
+    /// movement balances. Coldkeys are debited best-effort and fail when nothing can be
+    /// withdrawn; the pallet totals saturate at zero, so debiting them never fails. Returns the
+    /// amount moved.
+    pub fn transfer_tao(
+        from: LedgerAccount<T::AccountId>,
+        to: LedgerAccount<T::AccountId>,
+        amount: TaoCurrency,
+        reason: LedgerReason,
+    ) -> Result<TaoCurrency, DispatchError> {
+        if amount.is_zero() {
+            return Ok(TaoCurrency::ZERO);
+        }
+
+        let amount = Self::debit_tao(&from, amount)?;
+        Self::credit_tao(&to, amount);
+
+        Self::deposit_event(Event::TaoTransferred {
+            from,
+            to,
+            amount,
+            reason,
+        });
+
+        Ok(amount)
+    }
+
+    /// Pays `amount` of unstaked TAO out of [`LedgerAccount::Stake`] to `coldkey`.
+    pub fn pay_unstaked_tao(coldkey: &T::AccountId, amount: TaoCurrency) {
+        let _ = Self::transfer_tao(
+            LedgerAccount::Stake,
+            LedgerAccount::Coldkey(coldkey.clone()),
+            amount,
+            LedgerReason::Unstake,
+        );
+    }
+
+    fn debit_tao(
+        account: &LedgerAccount<T::AccountId>,
+        amount: TaoCurrency,
+    ) -> Result<TaoCurrency, DispatchError> {
+        match account {
+            LedgerAccount::Coldkey(coldkey) => {
+                return Self::remove_balance_from_coldkey_account(coldkey, amount.into());
+            }
+            LedgerAccount::Stake => Self::decrease_total_stake(amount),
+            LedgerAccount::Unbonding => {
+                TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount))
+            }
+            LedgerAccount::RegistrationBids => {
+                TotalRegistrationBids::<T>::mutate(|total| *total = total.saturating_sub(amount))
+            }
+            LedgerAccount::EvmWithdrawals => {
+                TotalEvmWithdrawals::<T>::mutate(|total| *total = total.saturating_sub(amount))
+            }
+            LedgerAccount::Issuance => Self::coinbase(amount),
+        }
+        Ok(amount)
+    }
+
+    fn credit_tao(account: &LedgerAccount<T::AccountId>, amount: TaoCurrency) {
+        match account {
+            LedgerAccount::Coldkey(coldkey) => {
+                Self::add_balance_to_coldkey_account(coldkey, amount.into())
+            }
+            LedgerAccount::Stake => Self::increase_total_stake(amount),
+            LedgerAccount::Unbonding => {
+                TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount))
+            }
+            LedgerAccount::RegistrationBids => {
+                TotalRegistrationBids::<T>::mutate(|total| *total = total.saturating_add(amount))
+            }
+            LedgerAccount::EvmWithdrawals => {
+                TotalEvmWithdrawals::<T>::mutate(|total| *total = total.saturating_add(amount))
+            }
+            LedgerAccount::Issuance => Self::burn_tokens(amount),
+        }
+    }
+}
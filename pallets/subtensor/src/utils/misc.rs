@@ -210,6 +210,48 @@ impl<T: Config> Pallet<T> {
     pub fn get_stake_threshold() -> u64 {
         StakeThreshold::<T>::get()
     }
+    /// Minimum stake for a validator permit on `netuid`, the higher of the global and subnet thresholds.
+    pub fn get_stake_threshold_for_subnet(netuid: NetUid) -> u64 {
+        StakeThreshold::<T>::get().max(SubnetStakeThreshold::<T>::get(netuid))
+    }
+    pub fn set_subnet_stake_threshold(netuid: NetUid, stake_threshold: u64) {
+        SubnetStakeThreshold::<T>::insert(netuid, stake_threshold);
+        Self::deposit_event(Event::SubnetStakeThresholdSet(netuid, stake_threshold));
+    }
+    pub fn set_max_subnet_maintenance_duration(duration: u64) {
+        MaxSubnetMaintenanceDuration::<T>::set(duration);
+        Self::deposit_event(Event::MaxSubnetMaintenanceDurationSet(duration));
+    }
+    pub fn set_subnet_identity_fee(fee: TaoCurrency) {
+        SubnetIdentityFee::<T>::set(fee);
+        Self::deposit_event(Event::SubnetIdentityFeeSet(fee));
+    }
+    pub fn set_offense_slash_fraction(fraction: u16) {
+        OffenseSlashFraction::<T>::set(fraction);
+        Self::deposit_event(Event::OffenseSlashFractionSet(fraction));
+    }
+    pub fn set_unregister_refund_fraction(fraction: u16) {
+        UnregisterRefundFraction::<T>::set(fraction);
+        Self::deposit_event(Event::UnregisterRefundFractionSet(fraction));
+    }
+    pub fn set_subnet_proposal_quorum(quorum: u16) {
+        SubnetProposalQuorum::<T>::set(quorum);
+        Self::deposit_event(Event::SubnetProposalQuorumSet(quorum));
+    }
+    pub fn set_subnet_proposal_duration(duration: u64) {
+        SubnetProposalDuration::<T>::set(duration);
+        Self::deposit_event(Event::SubnetProposalDurationSet(duration));
+    }
+    pub fn set_subnet_stake_threshold_bounds(min: u64, max: u64) {
+        MinSubnetStakeThreshold::<T>::set(min);
+        MaxSubnetStakeThreshold::<T>::set(max);
+        Self::deposit_event(Event::SubnetStakeThresholdBoundsSet(min, max));
+    }
+    pub fn set_liquid_alpha_bounds(min_alpha_low: u16, max_alpha_high: u16) {
+        MinLiquidAlphaLow::<T>::set(min_alpha_low);
+        MaxLiquidAlphaHigh::<T>::set(max_alpha_high);
+        Self::deposit_event(Event::LiquidAlphaBoundsSet(min_alpha_low, max_alpha_high));
+    }
 
     // ============================
     // ==== Subnetwork Getters ====
@@ -276,6 +318,15 @@ impl<T: Config> Pallet<T> {
     pub fn coinbase(amount: TaoCurrency) {
         TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(amount));
     }
+    pub fn record_subnet_tao_burned(netuid: NetUid, amount: TaoCurrency) {
+        SubnetTaoBurned::<T>::mutate(netuid, |total| *total = total.saturating_add(amount));
+    }
+    pub fn record_subnet_alpha_recycled(netuid: NetUid, amount: AlphaCurrency) {
+        SubnetAlphaRecycled::<T>::mutate(netuid, |total| *total = total.saturating_add(amount));
+    }
+    pub fn record_subnet_alpha_burned(netuid: NetUid, amount: AlphaCurrency) {
+        SubnetAlphaBurned::<T>::mutate(netuid, |total| *total = total.saturating_add(amount));
+    }
 
     pub fn set_subnet_locked_balance(netuid: NetUid, amount: TaoCurrency) {
         SubnetLocked::<T>::insert(netuid, amount);
@@ -310,6 +361,13 @@ impl<T: Config> Pallet<T> {
         TxDelegateTakeRateLimit::<T>::put(tx_rate_limit);
         Self::deposit_event(Event::TxDelegateTakeRateLimitSet(tx_rate_limit));
     }
+    pub fn get_delegate_take_notice_period() -> u64 {
+        DelegateTakeNoticePeriod::<T>::get()
+    }
+    pub fn set_delegate_take_notice_period(notice_period: u64) {
+        DelegateTakeNoticePeriod::<T>::put(notice_period);
+        Self::deposit_event(Event::DelegateTakeNoticePeriodSet(notice_period));
+    }
     pub fn set_min_delegate_take(take: u16) {
         MinDelegateTake::<T>::put(take);
         Self::deposit_event(Event::MinDelegateTakeSet(take));
@@ -387,6 +445,56 @@ impl<T: Config> Pallet<T> {
         WeightsVersionKey::<T>::insert(netuid, weights_version_key);
         Self::deposit_event(Event::WeightsVersionKeySet(netuid, weights_version_key));
     }
+    pub fn get_pending_weights_version_key(netuid: NetUid) -> Option<(u64, u64)> {
+        PendingWeightsVersionKey::<T>::get(netuid)
+    }
+    pub fn get_weights_version_key_grace_period(netuid: NetUid) -> u64 {
+        WeightsVersionKeyGracePeriod::<T>::get(netuid)
+    }
+    pub fn get_weights_priority_window() -> u64 {
+        WeightsPriorityWindow::<T>::get()
+    }
+    pub fn set_weights_priority_window(window: u64) {
+        WeightsPriorityWindow::<T>::put(window);
+        Self::deposit_event(Event::WeightsPriorityWindowSet(window));
+    }
+    pub fn set_weights_version_key_grace_period(netuid: NetUid, grace_period: u64) {
+        WeightsVersionKeyGracePeriod::<T>::insert(netuid, grace_period);
+        Self::deposit_event(Event::WeightsVersionKeyGracePeriodSet(netuid, grace_period));
+    }
+
+    /// Requires `weights_version_key` on `netuid` once the grace period of the subnet has passed,
+    /// replacing any key already scheduled. Without a grace period the key applies immediately.
+    pub fn schedule_weights_version_key(netuid: NetUid, weights_version_key: u64) {
+        let grace_period = Self::get_weights_version_key_grace_period(netuid);
+        if grace_period == 0 {
+            PendingWeightsVersionKey::<T>::remove(netuid);
+            Self::set_weights_version_key(netuid, weights_version_key);
+            return;
+        }
+
+        let effective_block = Self::get_current_block_as_u64().saturating_add(grace_period);
+        PendingWeightsVersionKey::<T>::insert(netuid, (weights_version_key, effective_block));
+        Self::deposit_event(Event::WeightsVersionKeyScheduled {
+            netuid,
+            version_key: weights_version_key,
+            effective_block,
+        });
+    }
+
+    /// Applies every scheduled weights version key whose grace period has ended by
+    /// `block_number`.
+    pub fn apply_pending_weights_version_keys(block_number: u64) {
+        let due: Vec<(NetUid, u64)> = PendingWeightsVersionKey::<T>::iter()
+            .filter(|(_, (_, effective_block))| *effective_block <= block_number)
+            .map(|(netuid, (version_key, _))| (netuid, version_key))
+            .collect();
+
+        for (netuid, version_key) in due {
+            PendingWeightsVersionKey::<T>::remove(netuid);
+            Self::set_weights_version_key(netuid, version_key);
+        }
+    }
 
     pub fn get_weights_set_rate_limit(netuid: NetUid) -> u64 {
         WeightsSetRateLimit::<T>::get(netuid)
@@ -450,6 +558,14 @@ impl<T: Config> Pallet<T> {
         let immunity_period = Self::get_immunity_period(netuid);
         current_block.saturating_sub(registered_at) < u64::from(immunity_period)
     }
+    /// Number of blocks until a neuron leaves its immunity period, 0 if it is not immune.
+    pub fn get_neuron_immunity_remaining(netuid: NetUid, uid: u16) -> u64 {
+        let registered_at = Self::get_neuron_block_at_registration(netuid, uid);
+        let current_block = Self::get_current_block_as_u64();
+        registered_at
+            .saturating_add(u64::from(Self::get_immunity_period(netuid)))
+            .saturating_sub(current_block)
+    }
 
     pub fn get_min_allowed_weights(netuid: NetUid) -> u16 {
         MinAllowedWeights::<T>::get(netuid)
@@ -488,6 +604,30 @@ impl<T: Config> Pallet<T> {
         CommitRevealWeightsVersion::<T>::set(version);
         Self::deposit_event(Event::CommitRevealVersionSet(version));
     }
+
+    /// Returns true if root paused the call of this pallet at `call_index`.
+    pub fn is_call_disabled(call_index: u8) -> bool {
+        let (byte, bit) = (usize::from(call_index / 8), call_index % 8);
+        DisabledCalls::<T>::get()
+            .get(byte)
+            .is_some_and(|mask| mask & 1_u8.rotate_left(u32::from(bit)) != 0)
+    }
+    pub fn set_call_disabled(call_index: u8, disabled: bool) {
+        let (byte, bit) = (usize::from(call_index / 8), call_index % 8);
+        DisabledCalls::<T>::mutate(|calls| {
+            if let Some(mask) = calls.get_mut(byte) {
+                if disabled {
+                    *mask |= 1_u8.rotate_left(u32::from(bit));
+                } else {
+                    *mask &= !1_u8.rotate_left(u32::from(bit));
+                }
+            }
+        });
+        Self::deposit_event(Event::CallDisabledSet {
+            call_index,
+            disabled,
+        });
+    }
     pub fn get_rho(netuid: NetUid) -> u16 {
         Rho::<T>::get(netuid)
     }
@@ -503,6 +643,41 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::ActivityCutoffSet(netuid, activity_cutoff));
     }
 
+    pub fn get_unbonding_period(netuid: NetUid) -> u64 {
+        UnbondingPeriod::<T>::get(netuid)
+    }
+    pub fn set_unbonding_period(netuid: NetUid, unbonding_period: u64) {
+        UnbondingPeriod::<T>::insert(netuid, unbonding_period);
+        Self::deposit_event(Event::UnbondingPeriodSet(netuid, unbonding_period));
+    }
+
+    pub fn get_miner_emission_vesting_period(netuid: NetUid) -> u64 {
+        MinerEmissionVestingPeriod::<T>::get(netuid)
+    }
+    pub fn set_miner_emission_vesting_period(netuid: NetUid, vesting_period: u64) {
+        MinerEmissionVestingPeriod::<T>::insert(netuid, vesting_period);
+        Self::deposit_event(Event::MinerEmissionVestingPeriodSet(netuid, vesting_period));
+    }
+
+    pub fn get_max_weight_age(netuid: NetUid) -> u64 {
+        MaxWeightAge::<T>::get(netuid)
+    }
+    pub fn set_max_weight_age(netuid: NetUid, max_weight_age: u64) {
+        MaxWeightAge::<T>::insert(netuid, max_weight_age);
+        Self::deposit_event(Event::MaxWeightAgeSet(netuid, max_weight_age));
+    }
+    /// Stale mask of the neurons of `netuid` at `current_block`: whether their weights are older
+    /// than the maximum weight age.
+    pub fn get_stale_weights(netuid: NetUid, last_update: &[u64], current_block: u64) -> Vec<bool> {
+        let max_weight_age = Self::get_max_weight_age(netuid);
+        last_update
+            .iter()
+            .map(|updated| {
+                max_weight_age > 0 && updated.saturating_add(max_weight_age) < current_block
+            })
+            .collect()
+    }
+
     // Registration Toggle utils
     pub fn get_network_registration_allowed(netuid: NetUid) -> bool {
         NetworkRegistrationAllowed::<T>::get(netuid)
@@ -520,6 +695,32 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::PowRegistrationAllowed(netuid, registration_allowed));
     }
 
+    pub fn get_registration_pow_algorithm(netuid: NetUid) -> RegistrationPowAlgorithm {
+        RegistrationPowAlgorithms::<T>::get(netuid)
+    }
+    /// Switching the hash restarts the difficulty from the minimum, as the previous difficulty was
+    /// set for the hash rate of the other algorithm.
+    pub fn set_registration_pow_algorithm(netuid: NetUid, algorithm: RegistrationPowAlgorithm) {
+        if RegistrationPowAlgorithms::<T>::get(netuid) != algorithm {
+            Self::set_difficulty(netuid, Self::get_min_difficulty(netuid));
+        }
+        RegistrationPowAlgorithms::<T>::insert(netuid, algorithm);
+        Self::deposit_event(Event::RegistrationPowAlgorithmSet(netuid, algorithm));
+    }
+
+    pub fn get_registration_auction_enabled(netuid: NetUid) -> bool {
+        RegistrationAuctionEnabled::<T>::get(netuid)
+    }
+    /// Switches `netuid` between auctioned and PoW/burn registration. Open bids are refunded
+    /// when auctions are turned off.
+    pub fn set_registration_auction_enabled(netuid: NetUid, enabled: bool) {
+        RegistrationAuctionEnabled::<T>::insert(netuid, enabled);
+        if !enabled {
+            Self::refund_registration_bids(netuid);
+        }
+        Self::deposit_event(Event::RegistrationAuctionEnabledSet(netuid, enabled));
+    }
+
     pub fn get_target_registrations_per_interval(netuid: NetUid) -> u16 {
         TargetRegistrationsPerInterval::<T>::get(netuid)
     }
@@ -600,6 +801,14 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::BondsResetOnSet(netuid, bonds_reset));
     }
 
+    pub fn get_bonds_reset_on_deregistration(netuid: NetUid) -> bool {
+        BondsResetOnDeregistration::<T>::get(netuid)
+    }
+    pub fn set_bonds_reset_on_deregistration(netuid: NetUid, enabled: bool) {
+        BondsResetOnDeregistration::<T>::insert(netuid, enabled);
+        Self::deposit_event(Event::BondsResetOnDeregistrationSet(netuid, enabled));
+    }
+
     pub fn get_max_registrations_per_block(netuid: NetUid) -> u16 {
         MaxRegistrationsPerBlock::<T>::get(netuid)
     }
@@ -625,6 +834,19 @@ impl<T: Config> Pallet<T> {
         SubnetOwnerCut::<T>::set(subnet_owner_cut);
         Self::deposit_event(Event::SubnetOwnerCutSet(subnet_owner_cut));
     }
+    /// Owner cut applied to `netuid`: the owner's choice if set, otherwise the global cut.
+    pub fn get_subnet_owner_cut_for_subnet(netuid: NetUid) -> u16 {
+        SubnetOwnerCutForSubnet::<T>::get(netuid).unwrap_or_else(SubnetOwnerCut::<T>::get)
+    }
+    pub fn get_float_subnet_owner_cut_for_subnet(netuid: NetUid) -> U96F32 {
+        U96F32::saturating_from_num(Self::get_subnet_owner_cut_for_subnet(netuid))
+            .safe_div(U96F32::saturating_from_num(u16::MAX))
+    }
+    pub fn set_subnet_owner_cut_bounds(min: u16, max: u16) {
+        MinSubnetOwnerCut::<T>::set(min);
+        MaxSubnetOwnerCut::<T>::set(max);
+        Self::deposit_event(Event::SubnetOwnerCutBoundsSet(min, max));
+    }
 
     pub fn get_owned_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
         OwnedHotkeys::<T>::get(coldkey)
@@ -649,6 +871,11 @@ impl<T: Config> Pallet<T> {
         let rao_recycled = curr_rao_recycled.saturating_add(inc_rao_recycled);
         Self::set_rao_recycled(netuid, rao_recycled);
     }
+    pub fn decrease_rao_recycled(netuid: NetUid, dec_rao_recycled: TaoCurrency) {
+        let curr_rao_recycled = Self::get_rao_recycled(netuid);
+        let rao_recycled = curr_rao_recycled.saturating_sub(dec_rao_recycled);
+        Self::set_rao_recycled(netuid, rao_recycled);
+    }
 
     pub fn set_senate_required_stake_perc(required_percent: u64) {
         SenateRequiredStakePercentage::<T>::put(required_percent);
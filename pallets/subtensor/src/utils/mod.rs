@@ -1,7 +1,11 @@
 use super::*;
+pub mod event_accounts;
+pub mod event_bridge;
 pub mod evm;
+pub mod evm_bridge;
 pub mod identity;
+pub mod ledger;
 pub mod misc;
 pub mod rate_limiting;
-#[cfg(feature = "try-runtime")]
+#[cfg(any(feature = "try-runtime", feature = "std", test))]
 pub mod try_state;
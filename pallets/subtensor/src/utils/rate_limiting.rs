@@ -11,6 +11,8 @@ pub enum TransactionType {
     RegisterNetwork,
     SetWeightsVersionKey,
     SetSNOwnerHotkey,
+    SetSubnetOwnerCut,
+    GrowMaxAllowedUids,
 }
 
 /// Implement conversion from TransactionType to u16
@@ -23,6 +25,8 @@ impl From<TransactionType> for u16 {
             TransactionType::RegisterNetwork => 3,
             TransactionType::SetWeightsVersionKey => 4,
             TransactionType::SetSNOwnerHotkey => 5,
+            TransactionType::SetSubnetOwnerCut => 6,
+            TransactionType::GrowMaxAllowedUids => 7,
         }
     }
 }
@@ -36,6 +40,8 @@ impl From<u16> for TransactionType {
             3 => TransactionType::RegisterNetwork,
             4 => TransactionType::SetWeightsVersionKey,
             5 => TransactionType::SetSNOwnerHotkey,
+            6 => TransactionType::SetSubnetOwnerCut,
+            7 => TransactionType::GrowMaxAllowedUids,
             _ => TransactionType::Unknown,
         }
     }
@@ -62,6 +68,8 @@ impl<T: Config> Pallet<T> {
             TransactionType::SetWeightsVersionKey => (Tempo::<T>::get(netuid) as u64)
                 .saturating_mul(WeightsVersionKeyRateLimit::<T>::get()),
             TransactionType::SetSNOwnerHotkey => DefaultSetSNOwnerHotkeyRateLimit::<T>::get(),
+            TransactionType::SetSubnetOwnerCut => DefaultSetSubnetOwnerCutRateLimit::<T>::get(),
+            TransactionType::GrowMaxAllowedUids => DefaultGrowMaxAllowedUidsRateLimit::<T>::get(),
 
             _ => Self::get_rate_limit(tx_type),
         }
@@ -112,6 +120,12 @@ impl<T: Config> Pallet<T> {
             TransactionType::SetSNOwnerHotkey => {
                 Self::get_rate_limited_last_block(&RateLimitKey::SetSNOwnerHotkey(netuid))
             }
+            TransactionType::SetSubnetOwnerCut => {
+                Self::get_rate_limited_last_block(&RateLimitKey::SetSubnetOwnerCut(netuid))
+            }
+            TransactionType::GrowMaxAllowedUids => {
+                Self::get_rate_limited_last_block(&RateLimitKey::GrowMaxAllowedUids(netuid))
+            }
             _ => {
                 let tx_as_u16: u16 = (*tx_type).into();
                 TransactionKeyLastBlock::<T>::get((hotkey, netuid, tx_as_u16))
@@ -139,6 +153,12 @@ impl<T: Config> Pallet<T> {
             TransactionType::SetSNOwnerHotkey => {
                 Self::set_rate_limited_last_block(&RateLimitKey::SetSNOwnerHotkey(netuid), block)
             }
+            TransactionType::SetSubnetOwnerCut => {
+                Self::set_rate_limited_last_block(&RateLimitKey::SetSubnetOwnerCut(netuid), block)
+            }
+            TransactionType::GrowMaxAllowedUids => {
+                Self::set_rate_limited_last_block(&RateLimitKey::GrowMaxAllowedUids(netuid), block)
+            }
             _ => {
                 let tx_as_u16: u16 = (*tx_type).into();
                 TransactionKeyLastBlock::<T>::insert((key, netuid, tx_as_u16), block);
@@ -1,17 +1,50 @@
 use frame_support::traits::fungible::Inspect;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::{vec, vec::Vec};
+use substrate_fixed::types::U64F64;
 
 use super::*;
 
 impl<T: Config> Pallet<T> {
-    /// Checks [`TotalIssuance`] equals the sum of currency issuance, total stake, and total subnet
-    /// locked.
+    /// Checks the invariants of the pallet's storage, run by try-runtime after every block.
+    pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        Self::check_total_issuance()?;
+        // Disabled: https://github.com/opentensor/subtensor/pull/1166
+        // Self::check_total_stake()?;
+        Self::check_hotkey_shares()?;
+        Self::check_staking_hotkeys_owned()?;
+        Self::check_subnetwork_n()?;
+        Self::check_keys_uids()?;
+        Self::check_total_evm_withdrawals()?;
+        Ok(())
+    }
+
+    /// Runs every storage check, including those disabled in [`Self::do_try_state`], and
+    /// returns the result of each by name. Used by the node's `check-db` command.
+    pub fn check_invariants() -> Vec<(&'static str, Result<(), sp_runtime::TryRuntimeError>)> {
+        vec![
+            ("total_issuance", Self::check_total_issuance()),
+            ("total_stake", Self::check_total_stake()),
+            ("hotkey_shares", Self::check_hotkey_shares()),
+            ("staking_hotkeys_owned", Self::check_staking_hotkeys_owned()),
+            ("subnetwork_n", Self::check_subnetwork_n()),
+            ("keys_uids", Self::check_keys_uids()),
+            ("total_evm_withdrawals", Self::check_total_evm_withdrawals()),
+        ]
+    }
+
+    /// Checks [`TotalIssuance`] equals the sum of currency issuance, total stake, TAO still
+    /// unbonding, held in registration bids or withdrawn from the EVM, and total subnet locked.
     pub(crate) fn check_total_issuance() -> Result<(), sp_runtime::TryRuntimeError> {
         // Get the total currency issuance
         let currency_issuance = <T as Config>::Currency::total_issuance();
 
         // Calculate the expected total issuance
-        let expected_total_issuance =
-            currency_issuance.saturating_add(TotalStake::<T>::get().into());
+        let expected_total_issuance = currency_issuance
+            .saturating_add(TotalStake::<T>::get().into())
+            .saturating_add(TotalUnbonding::<T>::get().into())
+            .saturating_add(TotalRegistrationBids::<T>::get().into())
+            .saturating_add(TotalEvmWithdrawals::<T>::get().into());
 
         // Verify the diff between calculated TI and actual TI is less than delta
         //
@@ -36,7 +69,6 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Checks the sum of all stakes matches the [`TotalStake`].
-    #[allow(dead_code)]
     pub(crate) fn check_total_stake() -> Result<(), sp_runtime::TryRuntimeError> {
         // Calculate the total staked amount
         let total_staked =
@@ -65,4 +97,102 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Checks the alpha shares of the stakers of every hotkey on every subnet sum to the
+    /// [`TotalHotkeyShares`] of the hotkey, up to one share of rounding.
+    pub(crate) fn check_hotkey_shares() -> Result<(), sp_runtime::TryRuntimeError> {
+        let mut shares: BTreeMap<(T::AccountId, NetUid), U64F64> = BTreeMap::new();
+        for ((hotkey, _, netuid), share) in Alpha::<T>::iter() {
+            let total = shares.entry((hotkey, netuid)).or_default();
+            *total = total.saturating_add(share);
+        }
+
+        for (hotkey, netuid, total_shares) in TotalHotkeyShares::<T>::iter() {
+            let staked_shares = shares.remove(&(hotkey, netuid)).unwrap_or_default();
+            let diff = if total_shares > staked_shares {
+                total_shares.saturating_sub(staked_shares)
+            } else {
+                staked_shares.saturating_sub(total_shares)
+            };
+            ensure!(
+                diff <= U64F64::saturating_from_num(1),
+                "Alpha shares do not sum to TotalHotkeyShares",
+            );
+        }
+        ensure!(
+            shares
+                .values()
+                .all(|share| *share <= U64F64::saturating_from_num(1)),
+            "Alpha shares staked to a hotkey without TotalHotkeyShares",
+        );
+
+        Ok(())
+    }
+
+    /// Checks every hotkey in [`StakingHotkeys`] has an [`Owner`].
+    pub(crate) fn check_staking_hotkeys_owned() -> Result<(), sp_runtime::TryRuntimeError> {
+        for (_, hotkeys) in StakingHotkeys::<T>::iter() {
+            for hotkey in hotkeys {
+                ensure!(
+                    Owner::<T>::contains_key(&hotkey),
+                    "Staking hotkey without an Owner",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the [`SubnetworkN`] of every subnet, less its [`VacantUids`], matches its [`Keys`]
+    /// and [`Uids`] entries.
+    pub(crate) fn check_subnetwork_n() -> Result<(), sp_runtime::TryRuntimeError> {
+        for netuid in Self::get_all_subnet_netuids() {
+            let n = usize::from(SubnetworkN::<T>::get(netuid))
+                .saturating_sub(VacantUids::<T>::get(netuid).len());
+            ensure!(
+                Keys::<T>::iter_prefix(netuid).count() == n,
+                "SubnetworkN does not match the Keys entries",
+            );
+            ensure!(
+                Uids::<T>::iter_prefix(netuid).count() == n,
+                "SubnetworkN does not match the Uids entries",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks [`Keys`] and [`Uids`] map every subnet's uids and hotkeys to each other.
+    pub(crate) fn check_keys_uids() -> Result<(), sp_runtime::TryRuntimeError> {
+        for (netuid, uid, hotkey) in Keys::<T>::iter() {
+            ensure!(
+                Uids::<T>::get(netuid, &hotkey) == Some(uid),
+                "Keys entry without a matching Uids entry",
+            );
+        }
+        for (netuid, hotkey, uid) in Uids::<T>::iter() {
+            ensure!(
+                Keys::<T>::try_get(netuid, uid).ok() == Some(hotkey),
+                "Uids entry without a matching Keys entry",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks [`TotalEvmWithdrawals`] equals the TAO held in all EVM withdrawal queues.
+    pub(crate) fn check_total_evm_withdrawals() -> Result<(), sp_runtime::TryRuntimeError> {
+        let queued = EvmWithdrawalQueue::<T>::iter_values()
+            .flatten()
+            .fold(TaoCurrency::ZERO, |acc, (_, amount)| {
+                acc.saturating_add(amount)
+            });
+
+        ensure!(
+            queued == TotalEvmWithdrawals::<T>::get(),
+            "TotalEvmWithdrawals does not match the EVM withdrawal queues",
+        );
+
+        Ok(())
+    }
 }
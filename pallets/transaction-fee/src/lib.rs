@@ -30,7 +30,7 @@ use subtensor_swap_interface::SwapHandler;
 use core::marker::PhantomData;
 use smallvec::smallvec;
 use sp_std::vec::Vec;
-use substrate_fixed::types::U96F32;
+use substrate_fixed::types::{U64F64, U96F32};
 use subtensor_runtime_common::{Balance, Currency, NetUid};
 
 // Tests
@@ -205,10 +205,11 @@ where
     }
 }
 
-/// Enum that describes either a withdrawn amount of transaction fee in TAO or the
-/// fact that fee was charged in Alpha (without an amount because it is not needed)
+/// Enum that describes either a withdrawn amount of transaction fee in TAO, along with the
+/// multiplier the fee was scaled by, or the fact that fee was charged in Alpha (without an amount
+/// because it is not needed)
 pub enum WithdrawnFee<T: frame_system::Config, F: Balanced<AccountIdOf<T>>> {
-    Tao(Credit<AccountIdOf<T>, F>),
+    Tao(Credit<AccountIdOf<T>, F>, U64F64),
     Alpha,
 }
 
@@ -293,6 +294,37 @@ impl<F, OU> SubtensorTxFeeHandler<F, OU> {
 
         alpha_vec
     }
+
+    /// Returns the multiplier of the fee of the given call. Registration calls are priced by the
+    /// congestion of registrations, see [`pallet_subtensor::RegistrationFeeMultiplier`], every
+    /// other call pays the regular fee.
+    pub fn fee_multiplier<T>(call: &CallOf<T>) -> U64F64
+    where
+        T: frame_system::Config + pallet_subtensor::Config,
+        CallOf<T>: IsSubType<pallet_subtensor::Call<T>>,
+    {
+        match call.is_sub_type() {
+            Some(call) if pallet_subtensor::Pallet::<T>::is_registration_call(call) => {
+                pallet_subtensor::Pallet::<T>::get_registration_fee_multiplier()
+            }
+            _ => U64F64::saturating_from_num(1),
+        }
+    }
+
+    /// Scales the part of `fee` that is not `tip` by `multiplier`.
+    fn scale_fee<B>(fee: B, tip: B, multiplier: U64F64) -> B
+    where
+        B: Into<u64> + From<u64> + Copy,
+    {
+        let tip: u64 = tip.into();
+        let base = U64F64::saturating_from_num(fee.into().saturating_sub(tip));
+        B::from(
+            multiplier
+                .saturating_mul(base)
+                .saturating_to_num::<u64>()
+                .saturating_add(tip),
+        )
+    }
 }
 
 impl<T, F, OU> OnChargeTransaction<T> for SubtensorTxFeeHandler<F, OU>
@@ -301,7 +333,7 @@ where
     CallOf<T>: IsSubType<pallet_subtensor::Call<T>>,
     F: Balanced<T::AccountId>,
     OU: OnUnbalanced<Credit<T::AccountId, F>> + AlphaFeeHandler<T>,
-    <F as Inspect<AccountIdOf<T>>>::Balance: Into<u64>,
+    <F as Inspect<AccountIdOf<T>>>::Balance: Into<u64> + From<u64>,
 {
     type LiquidityInfo = Option<WithdrawnFee<T, F>>;
     type Balance = <F as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -309,14 +341,23 @@ where
     fn withdraw_fee(
         who: &AccountIdOf<T>,
         call: &CallOf<T>,
-        _dispatch_info: &DispatchInfoOf<CallOf<T>>,
+        dispatch_info: &DispatchInfoOf<CallOf<T>>,
         fee: Self::Balance,
-        _tip: Self::Balance,
+        tip: Self::Balance,
     ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
         if fee.is_zero() {
             return Ok(None);
         }
 
+        let multiplier = Self::fee_multiplier::<T>(call);
+        if call
+            .is_sub_type()
+            .is_some_and(pallet_subtensor::Pallet::<T>::is_registration_call)
+        {
+            pallet_subtensor::Pallet::<T>::note_registration_weight(dispatch_info.total_weight());
+        }
+        let fee = Self::scale_fee(fee, tip, multiplier);
+
         // Traditional fees in TAO
         match F::withdraw(
             who,
@@ -325,7 +366,7 @@ where
             frame_support::traits::tokens::Preservation::Preserve,
             frame_support::traits::tokens::Fortitude::Polite,
         ) {
-            Ok(imbalance) => Ok(Some(WithdrawnFee::Tao(imbalance))),
+            Ok(imbalance) => Ok(Some(WithdrawnFee::Tao(imbalance, multiplier))),
             Err(_) => {
                 let alpha_vec = Self::fees_in_alpha::<T>(who, call);
                 if !alpha_vec.is_empty() {
@@ -343,12 +384,14 @@ where
         call: &CallOf<T>,
         _dispatch_info: &DispatchInfoOf<CallOf<T>>,
         fee: Self::Balance,
-        _tip: Self::Balance,
+        tip: Self::Balance,
     ) -> Result<(), TransactionValidityError> {
         if fee.is_zero() {
             return Ok(());
         }
 
+        let fee = Self::scale_fee(fee, tip, Self::fee_multiplier::<T>(call));
+
         // Prefer traditional fees in TAO
         match F::can_withdraw(who, fee) {
             WithdrawConsequence::Success => Ok(()),
@@ -378,7 +421,9 @@ where
             // Fee may be paid in TAO or in Alpha. Only refund and update total issuance for
             // TAO fees because Alpha fees are charged precisely and do not need any adjustments
             match withdrawn {
-                WithdrawnFee::Tao(paid) => {
+                WithdrawnFee::Tao(paid, multiplier) => {
+                    // Scale the actual fee the same way the withdrawn one was
+                    let corrected_fee = Self::scale_fee(corrected_fee, tip, multiplier);
                     // Calculate how much refund we should return
                     let refund_amount = paid.peek().saturating_sub(corrected_fee);
                     // refund to the account that paid the fees if it exists. otherwise, don't refund
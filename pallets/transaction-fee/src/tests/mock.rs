@@ -13,11 +13,11 @@ use frame_system::{
 };
 pub use pallet_subtensor::*;
 pub use sp_core::U256;
-use sp_core::{ConstU64, H256};
+use sp_core::{ConstU64, H160, H256};
 use sp_runtime::{
     BuildStorage, KeyTypeId, Perbill,
     testing::TestXt,
-    traits::{BlakeTwo256, ConstU32, IdentityLookup, One},
+    traits::{BlakeTwo256, ConstU32, Convert, IdentityLookup, One},
 };
 use sp_std::cmp::Ordering;
 use sp_weights::Weight;
@@ -216,6 +216,14 @@ parameter_types! {
     pub const LeaseDividendsDistributionInterval: u32 = 100; // 100 blocks
 }
 
+pub struct EvmAddressToAccount;
+
+impl Convert<H160, U256> for EvmAddressToAccount {
+    fn convert(evm_address: H160) -> U256 {
+        U256::from_big_endian(evm_address.as_bytes())
+    }
+}
+
 impl pallet_subtensor::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -287,6 +295,8 @@ impl pallet_subtensor::Config for Test {
     type KeySwapOnSubnetCost = InitialKeySwapOnSubnetCost;
     type HotkeySwapOnSubnetInterval = HotkeySwapOnSubnetInterval;
     type ProxyInterface = ();
+    type EventBridge = ();
+    type EvmAddressMapping = EvmAddressToAccount;
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
 }
 
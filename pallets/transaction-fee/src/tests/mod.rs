@@ -1194,3 +1194,38 @@ fn test_recycle_alpha_fees_alpha() {
         assert!(actual_alpha_fee > 0.into());
     });
 }
+
+// cargo test --package subtensor-transaction-fee --lib -- tests::test_registration_fees_scale_with_congestion --exact --show-output
+#[test]
+fn test_registration_fees_scale_with_congestion() {
+    new_test_ext().execute_with(|| {
+        let sn = setup_subnets(1, 1);
+        let coldkey = U256::from(100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, TAO);
+        let call = RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register {
+            netuid: sn.subnets[0].netuid,
+            hotkey: U256::from(100_001),
+        });
+        let info = call.get_dispatch_info();
+        let fee = pallet_transaction_payment::Pallet::<Test>::compute_fee(0, &info, 0);
+        pallet_subtensor::RegistrationFeeMultiplier::<Test>::put(U64F64::from_num(2));
+
+        // Charge the fee without dispatching the registration
+        let balance_before = Balances::free_balance(coldkey);
+        let ext = pallet_transaction_payment::ChargeTransactionPayment::<Test>::from(0);
+        assert_ok!(ext.validate_and_prepare(
+            RuntimeOrigin::signed(coldkey).into(),
+            &call,
+            &info,
+            0,
+            0,
+        ));
+
+        // The registration pays twice the regular fee and counts towards the block fullness
+        assert_eq!(balance_before - Balances::free_balance(coldkey), 2 * fee);
+        assert_eq!(
+            pallet_subtensor::RegistrationWeightThisBlock::<Test>::get(),
+            info.total_weight()
+        );
+    });
+}
@@ -1,11 +1,13 @@
 use core::marker::PhantomData;
 
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use pallet_evm::PrecompileHandle;
+use pallet_evm::{AddressMapping, GasWeightMapping, PrecompileHandle};
 use precompile_utils::EvmResult;
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
+use subtensor_runtime_common::{Currency, TaoCurrency};
 
 use crate::{PrecompileExt, PrecompileHandleExt};
 
@@ -13,13 +15,18 @@ pub(crate) struct BalanceTransferPrecompile<R>(PhantomData<R>);
 
 impl<R> PrecompileExt<R::AccountId> for BalanceTransferPrecompile<R>
 where
-    R: frame_system::Config + pallet_balances::Config + pallet_evm::Config,
+    R: frame_system::Config
+        + pallet_balances::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config,
     R::AccountId: From<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall:
         GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_balances::Call<R>>
+        + From<pallet_subtensor::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
 {
@@ -29,13 +36,18 @@ where
 #[precompile_utils::precompile]
 impl<R> BalanceTransferPrecompile<R>
 where
-    R: frame_system::Config + pallet_balances::Config + pallet_evm::Config,
+    R: frame_system::Config
+        + pallet_balances::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config,
     R::AccountId: From<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall:
         GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_balances::Call<R>>
+        + From<pallet_subtensor::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
 {
@@ -57,4 +69,46 @@ where
 
         handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(Self::account_id()))
     }
+
+    #[precompile::public("withdraw(bytes32,uint256)")]
+    fn withdraw(handle: &mut impl PrecompileHandle, coldkey: H256, amount: U256) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let amount: u64 = amount.unique_saturated_into();
+        let call = pallet_subtensor::Call::<R>::withdraw_from_evm {
+            evm_address: handle.context().caller,
+            coldkey: R::AccountId::from(coldkey.0),
+            amount: amount.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getPendingWithdrawal(bytes32)")]
+    #[precompile::view]
+    fn get_pending_withdrawal(
+        _handle: &mut impl PrecompileHandle,
+        coldkey: H256,
+    ) -> EvmResult<(u64, u64)> {
+        let (total, released) = pallet_subtensor::Pallet::<R>::get_evm_withdrawal_balance(
+            &R::AccountId::from(coldkey.0),
+        );
+        Ok((total.to_u64(), released.to_u64()))
+    }
+
+    #[precompile::public("getTotalEvmLocked()")]
+    #[precompile::view]
+    fn get_total_evm_locked(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        // Each address costs a read of the map and of its balance.
+        let read_cost = <R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            <R as frame_system::Config>::DbWeight::get().reads(2),
+        );
+        let mut total = TaoCurrency::ZERO;
+        for evm_address in pallet_subtensor::EvmBridgeAddresses::<R>::iter_keys() {
+            handle.record_cost(read_cost)?;
+            total = total.saturating_add(pallet_subtensor::Pallet::<R>::get_evm_address_balance(
+                evm_address,
+            ));
+        }
+        Ok(total.to_u64())
+    }
 }
@@ -0,0 +1,122 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, hashing::HashToCurve, pairing::Pairing};
+use ark_serialize::CanonicalDeserialize;
+use fp_evm::{ExitError, ExitSucceed, LinearCostPrecompile, PrecompileFailure};
+use tle::curves::drand::TinyBLS381;
+use w3f_bls::EngineBLS;
+
+use crate::{PrecompileExt, parse_slice};
+
+/// Verifies BLS12-381 signatures in G1 against public keys in G2, the scheme of drand quicknet.
+///
+/// The input is `verify(bytes32 message, bytes32[3] publicKey, bytes32[2] signature)`: the
+/// compressed 96 bytes public key, then the compressed 48 bytes signature left-aligned in 64 bytes.
+pub(crate) struct Bls12381Verify<A>(PhantomData<A>);
+
+impl<A> PrecompileExt<A> for Bls12381Verify<A>
+where
+    A: From<[u8; 32]>,
+{
+    const INDEX: u64 = 1028;
+}
+
+impl<A> LinearCostPrecompile for Bls12381Verify<A>
+where
+    A: From<[u8; 32]>,
+{
+    // Two pairings and a hash to the curve, priced after the EIP-2537 pairing check.
+    const BASE: u64 = 120_000;
+    const WORD: u64 = 3;
+
+    fn execute(input: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+        if input.len() < 196 {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("input must contain 192 bytes".into()),
+            });
+        };
+
+        let mut buf = [0u8; 32];
+
+        let msg = parse_slice(input, 4, 36)?;
+        let pk = G2Affine::deserialize_compressed(parse_slice(input, 36, 132)?).map_err(|_| {
+            PrecompileFailure::Error {
+                exit_status: ExitError::Other("Public key recover failed".into()),
+            }
+        })?;
+        let sig =
+            G1Affine::deserialize_compressed(parse_slice(input, 132, 180)?).map_err(|_| {
+                PrecompileFailure::Error {
+                    exit_status: ExitError::Other("Signature recover failed".into()),
+                }
+            })?;
+        let msg_on_curve = <TinyBLS381 as EngineBLS>::hash_to_curve_map()
+            .hash(msg)
+            .map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::Other("Message hash failed".into()),
+            })?;
+
+        // e(sig, g2) == e(H(m), pk)
+        if Bls12_381::pairing(sig, G2Affine::generator()) == Bls12_381::pairing(msg_on_curve, pk) {
+            buf[31] = 1u8;
+        };
+
+        Ok((ExitSucceed::Returned, buf.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    use ark_bls12_381::Fr;
+    use ark_ec::CurveGroup;
+    use ark_serialize::CanonicalSerialize;
+    use sp_core::crypto::AccountId32;
+
+    fn input(msg: [u8; 32], sk: Fr) -> Vec<u8> {
+        let pk = (G2Affine::generator() * sk).into_affine();
+        let msg_on_curve = <TinyBLS381 as EngineBLS>::hash_to_curve_map()
+            .hash(&msg)
+            .unwrap();
+        let sig = (msg_on_curve * sk).into_affine();
+
+        let mut input = [0u8; 4].to_vec();
+        input.extend(msg);
+        pk.serialize_compressed(&mut input).unwrap();
+        sig.serialize_compressed(&mut input).unwrap();
+        input.resize(196, 0);
+        input
+    }
+
+    fn verify(input: &[u8]) -> bool {
+        let (_, output) = Bls12381Verify::<AccountId32>::execute(input, 0).unwrap();
+        output.last() == Some(&1)
+    }
+
+    #[test]
+    fn bls12381_verify_accepts_valid_signature() {
+        assert!(verify(&input([7u8; 32], Fr::from(42u64))));
+    }
+
+    #[test]
+    fn bls12381_verify_rejects_other_message() {
+        let mut input = input([7u8; 32], Fr::from(42u64));
+        if let Some(byte) = input.get_mut(4) {
+            *byte = 8;
+        }
+        assert!(!verify(&input));
+    }
+
+    #[test]
+    fn bls12381_verify_rejects_short_input() {
+        let input = input([7u8; 32], Fr::from(42u64));
+        assert!(Bls12381Verify::<AccountId32>::execute(input.get(..180).unwrap(), 0).is_err());
+    }
+}
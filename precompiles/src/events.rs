@@ -0,0 +1,115 @@
+// Mirrors selected subtensor pallet events as EVM logs, so Solidity indexers can follow chain
+// activity without a substrate indexer.
+//
+// The logs are deposited as pallet_evm Log events emitted by a system contract address that has no
+// code, 0x000000000000000000000000000000000000080b. Their signatures are declared in
+// solidity/subtensorEvents.sol:
+//   - StakeAdded(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid, uint256 tao,
+//     uint256 alpha, uint256 fee)
+//   - NeuronRegistered(uint16 indexed netuid, bytes32 indexed hotkey, uint16 uid)
+//   - EmissionDistributed(uint16 indexed netuid, uint256 ownerCut, uint256 incentives,
+//     uint256 alphaDividends, uint256 taoDividends)
+//
+// Every non-indexed argument takes a 32 bytes word of the log data, in declaration order.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use fp_evm::Log;
+use pallet_subtensor::{Event, EventBridge};
+use sp_core::{H160, H256, hashing::keccak_256};
+
+/// The address of the system contract emitting the mirrored events. No precompile may use it.
+pub const SUBTENSOR_EVENTS_INDEX: u64 = 2059;
+
+const STAKE_ADDED_SIGNATURE: &[u8] = b"StakeAdded(bytes32,bytes32,uint16,uint256,uint256,uint256)";
+const NEURON_REGISTERED_SIGNATURE: &[u8] = b"NeuronRegistered(uint16,bytes32,uint16)";
+const EMISSION_DISTRIBUTED_SIGNATURE: &[u8] =
+    b"EmissionDistributed(uint16,uint256,uint256,uint256,uint256)";
+
+pub struct SubtensorEventBridge<R>(PhantomData<R>);
+
+impl<R> EventBridge<R> for SubtensorEventBridge<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: Into<[u8; 32]>,
+{
+    fn on_event(event: &Event<R>) {
+        let Some(log) = Self::to_log(event) else {
+            return;
+        };
+
+        let event: <R as pallet_evm::Config>::RuntimeEvent = pallet_evm::Event::Log { log }.into();
+        frame_system::Pallet::<R>::deposit_event(event.into());
+    }
+}
+
+impl<R> SubtensorEventBridge<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: Into<[u8; 32]>,
+{
+    /// Returns the EVM log mirroring `event`, if it is mirrored.
+    pub fn to_log(event: &Event<R>) -> Option<Log> {
+        let (signature, indexed, data) = match event {
+            Event::StakeAdded(coldkey, hotkey, tao, alpha, netuid, fee) => (
+                STAKE_ADDED_SIGNATURE,
+                [account_word::<R>(coldkey), account_word::<R>(hotkey)].to_vec(),
+                [
+                    u64::from(u16::from(*netuid)),
+                    u64::from(*tao),
+                    u64::from(*alpha),
+                    *fee,
+                ]
+                .to_vec(),
+            ),
+            Event::NeuronRegistered(netuid, uid, hotkey) => (
+                NEURON_REGISTERED_SIGNATURE,
+                [
+                    H256::from_low_u64_be(u16::from(*netuid).into()),
+                    account_word::<R>(hotkey),
+                ]
+                .to_vec(),
+                [u64::from(*uid)].to_vec(),
+            ),
+            Event::EmissionDistributed {
+                netuid,
+                owner_cut,
+                incentives,
+                alpha_dividends,
+                tao_dividends,
+            } => (
+                EMISSION_DISTRIBUTED_SIGNATURE,
+                [H256::from_low_u64_be(u16::from(*netuid).into())].to_vec(),
+                [
+                    u64::from(*owner_cut),
+                    u64::from(*incentives),
+                    u64::from(*alpha_dividends),
+                    u64::from(*tao_dividends),
+                ]
+                .to_vec(),
+            ),
+            _ => return None,
+        };
+
+        let mut topics = Vec::with_capacity(indexed.len().saturating_add(1));
+        topics.push(H256(keccak_256(signature)));
+        topics.extend(indexed);
+
+        Some(Log {
+            address: H160::from_low_u64_be(SUBTENSOR_EVENTS_INDEX),
+            topics,
+            data: data
+                .into_iter()
+                .flat_map(|word| H256::from_low_u64_be(word).0)
+                .collect(),
+        })
+    }
+}
+
+fn account_word<R: frame_system::Config>(account: &R::AccountId) -> H256
+where
+    R::AccountId: Into<[u8; 32]>,
+{
+    H256(account.clone().into())
+}
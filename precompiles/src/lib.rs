@@ -27,6 +27,7 @@ use pallet_admin_utils::PrecompileEnum;
 
 use crate::alpha::*;
 use crate::balance_transfer::*;
+use crate::bls::*;
 use crate::crowdloan::*;
 use crate::ed25519::*;
 use crate::extensions::*;
@@ -39,10 +40,14 @@ use crate::storage_query::*;
 use crate::subnet::*;
 use crate::uid_lookup::*;
 
+pub use crate::events::{SUBTENSOR_EVENTS_INDEX, SubtensorEventBridge};
+
 mod alpha;
 mod balance_transfer;
+mod bls;
 mod crowdloan;
 mod ed25519;
+mod events;
 mod extensions;
 mod leasing;
 mod metagraph;
@@ -107,7 +112,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 24] {
+    pub fn used_addresses() -> [H160; 25] {
         [
             hash(1),
             hash(2),
@@ -122,6 +127,7 @@ where
             hash(1025),
             hash(Ed25519Verify::<R::AccountId>::INDEX),
             hash(Sr25519Verify::<R::AccountId>::INDEX),
+            hash(Bls12381Verify::<R::AccountId>::INDEX),
             hash(BalanceTransferPrecompile::<R>::INDEX),
             hash(StakingPrecompile::<R>::INDEX),
             hash(SubnetPrecompile::<R>::INDEX),
@@ -182,6 +188,9 @@ where
             a if a == hash(Sr25519Verify::<R::AccountId>::INDEX) => {
                 Some(Sr25519Verify::<R::AccountId>::execute(handle))
             }
+            a if a == hash(Bls12381Verify::<R::AccountId>::INDEX) => {
+                Some(Bls12381Verify::<R::AccountId>::execute(handle))
+            }
             // Subtensor specific precompiles :
             a if a == hash(BalanceTransferPrecompile::<R>::INDEX) => {
                 BalanceTransferPrecompile::<R>::try_execute::<R>(
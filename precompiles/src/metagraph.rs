@@ -44,6 +44,20 @@ where
         Ok(pallet_subtensor::Pallet::<R>::get_total_stake_for_hotkey(&hotkey).to_u64())
     }
 
+    #[precompile::public("getAlphaStake(uint16,uint16)")]
+    #[precompile::view]
+    fn get_alpha_stake(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
+            .map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::InvalidRange,
+            })?;
+
+        Ok(
+            pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_on_subnet(&hotkey, netuid.into())
+                .to_u64(),
+        )
+    }
+
     #[precompile::public("getRank(uint16,uint16)")]
     #[precompile::view]
     fn get_rank(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
@@ -167,6 +181,36 @@ where
 
         Ok(H256::from_slice(coldkey.as_slice()))
     }
+
+    #[precompile::public("getNeuron(uint16,uint16)")]
+    #[precompile::view]
+    fn get_neuron(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<NeuronInfo> {
+        let netuid = NetUid::from(netuid);
+        let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid, uid)
+            .map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::InvalidRange,
+            })?;
+        let coldkey = pallet_subtensor::Owner::<R>::get(&hotkey);
+
+        Ok(NeuronInfo {
+            hotkey: H256::from_slice(hotkey.as_slice()),
+            coldkey: H256::from_slice(coldkey.as_slice()),
+            alpha_stake: pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_on_subnet(
+                &hotkey, netuid,
+            )
+            .to_u64(),
+            incentive: pallet_subtensor::Pallet::<R>::get_incentive_for_uid(netuid, uid),
+            dividends: pallet_subtensor::Pallet::<R>::get_dividends_for_uid(netuid, uid),
+            emission: pallet_subtensor::Pallet::<R>::get_emission_for_uid(netuid, uid).into(),
+            vtrust: pallet_subtensor::Pallet::<R>::get_validator_trust_for_uid(netuid, uid),
+            validator_permit: pallet_subtensor::Pallet::<R>::get_validator_permit_for_uid(
+                netuid, uid,
+            ),
+            active: pallet_subtensor::Pallet::<R>::get_active_for_uid(netuid, uid),
+            last_update: pallet_subtensor::Pallet::<R>::get_last_update_for_uid(netuid, uid),
+            axon: pallet_subtensor::Pallet::<R>::get_axon_info(netuid, &hotkey).into(),
+        })
+    }
 }
 
 #[derive(Codec)]
@@ -191,3 +235,18 @@ impl From<SubtensorModuleAxonInfo> for AxonInfo {
         }
     }
 }
+
+#[derive(Codec)]
+struct NeuronInfo {
+    hotkey: H256,
+    coldkey: H256,
+    alpha_stake: u64,
+    incentive: u16,
+    dividends: u16,
+    emission: u64,
+    vtrust: u16,
+    validator_permit: bool,
+    active: bool,
+    last_update: u64,
+    axon: AxonInfo,
+}
@@ -4,4 +4,18 @@ address constant ISUBTENSOR_BALANCE_TRANSFER_ADDRESS = 0x00000000000000000000000
 
 interface ISubtensorBalanceTransfer {
     function transfer(bytes32 data) external payable;
+
+    /// Withdraws `amount` rao from the caller into the EVM withdrawal queue of `coldkey`.
+    /// The coldkey claims it with the claim_evm_withdrawals extrinsic once the delay passed.
+    function withdraw(bytes32 coldkey, uint256 amount) external;
+
+    /// Returns the TAO in the EVM withdrawal queue of `coldkey`, and the part of it that can be
+    /// claimed now, in rao.
+    function getPendingWithdrawal(
+        bytes32 coldkey
+    ) external view returns (uint64 total, uint64 released);
+
+    /// Returns the TAO held by the EVM addresses that deposited or withdrew through the bridge,
+    /// in rao.
+    function getTotalEvmLocked() external view returns (uint64);
 }
\ No newline at end of file
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: GPL-3.0
+pragma solidity ^0.8.0;
+
+address constant IBLS12381VERIFY_ADDRESS = 0x0000000000000000000000000000000000000404;
+
+interface IBls12381Verify {
+    /**
+     * @dev Verifies a BLS12-381 signature in G1 against a public key in G2, as signed by drand quicknet.
+     *
+     * @param message The 32-byte signature payload message, hashed to G1 with the drand domain separation tag.
+     * @param publicKey The 96-byte compressed G2 public key.
+     * @param signature The 48-byte compressed G1 signature, left-aligned and zero-padded to 64 bytes.
+     * @return bool Returns true if the signature is valid for the given message and public key, false otherwise.
+     */
+    function verify(bytes32 message, bytes32[3] calldata publicKey, bytes32[2] calldata signature) external pure returns (bool);
+}
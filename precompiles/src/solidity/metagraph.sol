@@ -11,6 +11,20 @@ struct AxonInfo {
   uint8 protocol;
 }
 
+struct NeuronInfo {
+  bytes32 hotkey;
+  bytes32 coldkey;
+  uint64 alpha_stake;
+  uint16 incentive;
+  uint16 dividends;
+  uint64 emission;
+  uint16 vtrust;
+  bool validator_permit;
+  bool active;
+  uint64 last_update;
+  AxonInfo axon;
+}
+
 interface IMetagraph {
   
   /**
@@ -28,6 +42,14 @@ interface IMetagraph {
    */
   function getStake(uint16 netuid, uint16 uid) external view returns (uint64);
 
+  /**
+   * @dev Retrieves the alpha staked on a node with a given network identifier (netuid) and unique identifier (uid), on that network only.
+   * @param netuid The network identifier for which to retrieve the alpha stake.
+   * @param uid The unique identifier for which to retrieve the alpha stake.
+   * @return The alpha staked on the node with the specified netuid and uid.
+   */
+  function getAlphaStake(uint16 netuid, uint16 uid) external view returns (uint64);
+
   /**
    * @dev Retrieves the rank of a node with a given network identifier (netuid) and unique identifier (uid).
    * @param netuid The network identifier for which to retrieve the rank.
@@ -131,4 +153,12 @@ interface IMetagraph {
    * @return The coldkey of the node with the specified netuid and uid.
    */
   function getColdkey(uint16 netuid, uint16 uid) external view returns (bytes32);
+
+  /**
+   * @dev Retrieves the keys, alpha stake, scores and axon information of a node with a given network identifier (netuid) and unique identifier (uid) in one call.
+   * @param netuid The network identifier for which to retrieve the node.
+   * @param uid The unique identifier for which to retrieve the node.
+   * @return The information of the node with the specified netuid and uid.
+   */
+  function getNeuron(uint16 netuid, uint16 uid) external view returns (NeuronInfo memory);
 }
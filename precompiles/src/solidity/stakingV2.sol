@@ -16,6 +16,9 @@ interface IStaking {
      * @param amount The amount to stake in rao.
      * @param netuid The subnet to stake to (uint256).
      *
+     * The amount is withdrawn from the coldkey of the sender, see `addressToColdkey`. Value sent
+     * along with the call is not staked, so callers should send none.
+     *
      * Requirements:
      * - `hotkey` must be a valid hotkey registered on the network, ensuring that the stake is
      *   correctly attributed.
@@ -195,6 +198,19 @@ interface IStaking {
         uint256 netuid
     ) external view returns (uint256);
 
+    /**
+     * @dev Returns the coldkey that stakes on behalf of an EVM address.
+     *
+     * Every write operation of this precompile is signed by the coldkey that Frontier's
+     * HashedAddressMapping derives from the sender, blake2_256("evm:" ++ address). Its free balance
+     * is the balance of the address, it receives unstaked TAO, and no private key exists for it, so
+     * only the address itself can move its stake.
+     *
+     * @param evmAddress The EVM address (H160).
+     * @return The coldkey public key (32 bytes) of the address.
+     */
+    function addressToColdkey(address evmAddress) external view returns (bytes32);
+
     /**
      * @dev Returns the minimum required stake for a nominator.
      *
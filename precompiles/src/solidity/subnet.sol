@@ -206,4 +206,60 @@ interface ISubnet {
         uint16 netuid,
         uint64 commitRevealWeightsInterval
     ) external payable;
+
+    /// Returns the TAO locked by registering a network now, in rao.
+    function getNetworkRegistrationCost() external view returns (uint64);
+
+    /// Returns the coldkey public key (32 bytes) of the owner of a subnet.
+    function getSubnetOwner(uint16 netuid) external view returns (bytes32);
+
+    function getBondsPenalty(uint16 netuid) external view returns (uint16);
+
+    function setBondsPenalty(
+        uint16 netuid,
+        uint16 bondsPenalty
+    ) external payable;
+
+    function getMaxWeightAge(uint16 netuid) external view returns (uint64);
+
+    function setMaxWeightAge(
+        uint16 netuid,
+        uint64 maxWeightAge
+    ) external payable;
+
+    function getMinerEmissionVestingPeriod(
+        uint16 netuid
+    ) external view returns (uint64);
+
+    function setMinerEmissionVestingPeriod(
+        uint16 netuid,
+        uint64 vestingPeriod
+    ) external payable;
+
+    function getSubnetTransactionFee(
+        uint16 netuid
+    ) external view returns (uint64);
+
+    function setSubnetTransactionFee(
+        uint16 netuid,
+        uint64 fee
+    ) external payable;
+
+    function getSubnetTreasuryShare(
+        uint16 netuid
+    ) external view returns (uint16);
+
+    function setSubnetTreasuryShare(
+        uint16 netuid,
+        uint16 share
+    ) external payable;
+
+    function getWeightsVersionKeyGracePeriod(
+        uint16 netuid
+    ) external view returns (uint64);
+
+    function setWeightsVersionKeyGracePeriod(
+        uint16 netuid,
+        uint64 gracePeriod
+    ) external payable;
 }
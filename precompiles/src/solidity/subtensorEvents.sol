@@ -0,0 +1,33 @@
+pragma solidity ^0.8.0;
+
+address constant ISUBTENSOR_EVENTS_ADDRESS = 0x000000000000000000000000000000000000080b;
+
+/// Events of the subtensor pallet, mirrored as logs of ISUBTENSOR_EVENTS_ADDRESS.
+/// There is no contract at the address, the interface only declares the log signatures.
+interface ISubtensorEvents {
+    /// Stake was added to a hotkey on a subnet.
+    event StakeAdded(
+        bytes32 indexed coldkey,
+        bytes32 indexed hotkey,
+        uint16 netuid,
+        uint256 tao,
+        uint256 alpha,
+        uint256 fee
+    );
+
+    /// A neuron was registered on a subnet.
+    event NeuronRegistered(
+        uint16 indexed netuid,
+        bytes32 indexed hotkey,
+        uint16 uid
+    );
+
+    /// The emission of an epoch was paid out on a subnet.
+    event EmissionDistributed(
+        uint16 indexed netuid,
+        uint256 ownerCut,
+        uint256 incentives,
+        uint256 alphaDividends,
+        uint256 taoDividends
+    );
+}
@@ -24,6 +24,26 @@
 //     sender, which effectively unstakes the specified amount and credits it to the message sender
 //   - Precompile checks the result of do_remove_stake and, in case of a failure, reverts the transaction.
 //
+// The above describes the deprecated StakingPrecompile. StakingPrecompileV2 stakes from the coldkey
+// of the sender:
+//
+// Coldkey of an EVM account:
+//   - Every write operation is dispatched with the signed origin of the coldkey that
+//     HashedAddressMapping<BlakeTwo256> derives from the message sender, i.e.
+//     blake2_256(b"evm:" ++ h160). The mapping is one-way, so nobody holds the private key of that
+//     coldkey and only the EVM account itself can move its balance or stake. A contract calling
+//     the precompile acts as its own coldkey, which is how staking contracts hold stake natively.
+//   - The balance of an EVM account is the free balance of its coldkey, so TAO sent to an EVM
+//     address can be staked by it and unstaked TAO is spendable from it right away.
+//     addressToColdkey returns the coldkey of any address.
+//
+// Value transfer:
+//   - addStake, addStakeLimit, removeStake, removeStakeLimit, removeStakeFull, moveStake and
+//     transferStake take amounts as arguments. addStake stays payable for the existing callers,
+//     but the value sent along is not staked and callers should send none.
+//   - addStake withdraws the staked amount from the coldkey of the sender, and the remove
+//     operations credit the unstaked TAO to it.
+//
 
 use alloc::vec::Vec;
 use core::marker::PhantomData;
@@ -33,7 +53,7 @@ use pallet_evm::{
     AddressMapping, BalanceConverter, EvmBalance, ExitError, PrecompileFailure, PrecompileHandle,
     SubstrateBalance,
 };
-use precompile_utils::EvmResult;
+use precompile_utils::{EvmResult, prelude::Address};
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
 use sp_std::vec;
@@ -291,6 +311,15 @@ where
         Ok(u64::from(stake).into())
     }
 
+    #[precompile::public("addressToColdkey(address)")]
+    #[precompile::view]
+    fn address_to_coldkey(_: &mut impl PrecompileHandle, address: Address) -> EvmResult<H256> {
+        let coldkey: [u8; 32] =
+            <R as pallet_evm::Config>::AddressMapping::into_account_id(address.0).into();
+
+        Ok(H256::from(coldkey))
+    }
+
     #[precompile::public("getNominatorMinRequiredStake()")]
     #[precompile::view]
     fn get_nominator_min_required_stake(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
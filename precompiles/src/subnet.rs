@@ -5,7 +5,7 @@ use frame_support::traits::ConstU32;
 use frame_system::RawOrigin;
 use pallet_evm::{AddressMapping, PrecompileHandle};
 use precompile_utils::{EvmResult, prelude::BoundedString};
-use sp_core::H256;
+use sp_core::{ByteArray, H256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::vec;
 use subtensor_runtime_common::{Currency, NetUid};
@@ -20,7 +20,7 @@ where
         + pallet_evm::Config
         + pallet_subtensor::Config
         + pallet_admin_utils::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + GetDispatchInfo
@@ -37,7 +37,7 @@ where
         + pallet_evm::Config
         + pallet_subtensor::Config
         + pallet_admin_utils::Config,
-    R::AccountId: From<[u8; 32]>,
+    R::AccountId: From<[u8; 32]> + ByteArray,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + GetDispatchInfo
@@ -776,4 +776,178 @@ where
             RawOrigin::Signed(handle.caller_account_id::<R>()),
         )
     }
+
+    #[precompile::public("getNetworkRegistrationCost()")]
+    #[precompile::view]
+    fn get_network_registration_cost(_: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        Ok(pallet_subtensor::Pallet::<R>::get_network_lock_cost().to_u64())
+    }
+
+    #[precompile::public("getSubnetOwner(uint16)")]
+    #[precompile::view]
+    fn get_subnet_owner(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<H256> {
+        let owner = pallet_subtensor::SubnetOwner::<R>::get(NetUid::from(netuid));
+
+        Ok(H256::from_slice(owner.as_slice()))
+    }
+
+    #[precompile::public("getBondsPenalty(uint16)")]
+    #[precompile::view]
+    fn get_bonds_penalty(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        Ok(pallet_subtensor::BondsPenalty::<R>::get(NetUid::from(
+            netuid,
+        )))
+    }
+
+    #[precompile::public("setBondsPenalty(uint16,uint16)")]
+    #[precompile::payable]
+    fn set_bonds_penalty(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        bonds_penalty: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_bonds_penalty {
+            netuid: netuid.into(),
+            bonds_penalty,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getMaxWeightAge(uint16)")]
+    #[precompile::view]
+    fn get_max_weight_age(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        Ok(pallet_subtensor::MaxWeightAge::<R>::get(NetUid::from(
+            netuid,
+        )))
+    }
+
+    #[precompile::public("setMaxWeightAge(uint16,uint64)")]
+    #[precompile::payable]
+    fn set_max_weight_age(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        max_weight_age: u64,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_max_weight_age {
+            netuid: netuid.into(),
+            max_weight_age,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getMinerEmissionVestingPeriod(uint16)")]
+    #[precompile::view]
+    fn get_miner_emission_vesting_period(
+        _: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<u64> {
+        Ok(pallet_subtensor::MinerEmissionVestingPeriod::<R>::get(
+            NetUid::from(netuid),
+        ))
+    }
+
+    #[precompile::public("setMinerEmissionVestingPeriod(uint16,uint64)")]
+    #[precompile::payable]
+    fn set_miner_emission_vesting_period(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        vesting_period: u64,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_miner_emission_vesting_period {
+            netuid: netuid.into(),
+            vesting_period,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getSubnetTransactionFee(uint16)")]
+    #[precompile::view]
+    fn get_subnet_transaction_fee(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        Ok(pallet_subtensor::SubnetTransactionFee::<R>::get(NetUid::from(netuid)).to_u64())
+    }
+
+    #[precompile::public("setSubnetTransactionFee(uint16,uint64)")]
+    #[precompile::payable]
+    fn set_subnet_transaction_fee(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        fee: u64,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_subnet_transaction_fee {
+            netuid: netuid.into(),
+            fee: fee.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getSubnetTreasuryShare(uint16)")]
+    #[precompile::view]
+    fn get_subnet_treasury_share(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        Ok(pallet_subtensor::SubnetTreasuryShare::<R>::get(
+            NetUid::from(netuid),
+        ))
+    }
+
+    #[precompile::public("setSubnetTreasuryShare(uint16,uint16)")]
+    #[precompile::payable]
+    fn set_subnet_treasury_share(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        share: u16,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_subnet_treasury_share {
+            netuid: netuid.into(),
+            share,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("getWeightsVersionKeyGracePeriod(uint16)")]
+    #[precompile::view]
+    fn get_weights_version_key_grace_period(
+        _: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<u64> {
+        Ok(pallet_subtensor::WeightsVersionKeyGracePeriod::<R>::get(
+            NetUid::from(netuid),
+        ))
+    }
+
+    #[precompile::public("setWeightsVersionKeyGracePeriod(uint16,uint64)")]
+    #[precompile::payable]
+    fn set_weights_version_key_grace_period(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        grace_period: u64,
+    ) -> EvmResult<()> {
+        let call = pallet_admin_utils::Call::<R>::sudo_set_weights_version_key_grace_period {
+            netuid: netuid.into(),
+            grace_period,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
 }
@@ -0,0 +1,100 @@
+//! Saturating conversions between fixed-point types, integers and u16 proportions.
+//!
+//! Every conversion saturates at the bounds of its target type and rounds toward zero, so the
+//! epoch and emission code never depends on the ad-hoc cast used at a call site.
+
+use substrate_fixed::traits::Fixed;
+use substrate_fixed::types::I96F32;
+
+use crate::FixedExt;
+
+pub trait FixedConvert: Fixed {
+    /// Converts to the fixed-point type `F`, saturating at its bounds.
+    fn saturating_cast<F: Fixed>(self) -> F {
+        F::saturating_from_num(self)
+    }
+
+    /// The proportion `x / u16::MAX`.
+    fn from_u16_proportion(x: u16) -> Self {
+        Self::saturating_from_num(x).safe_div(Self::saturating_from_num(u16::MAX))
+    }
+
+    /// Converts a proportion in `[0, 1]` to its u16 representation scaled to `u16::MAX`.
+    fn to_u16_proportion(self) -> u16 {
+        self.saturating_mul(Self::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u16>()
+    }
+
+    /// Scales `amount` by `self`, computing in I96F32 so that any u64 amount is exact.
+    fn saturating_scale(self, amount: u64) -> u64 {
+        I96F32::saturating_from_num(self)
+            .saturating_mul(I96F32::saturating_from_num(amount))
+            .saturating_to_num::<u64>()
+    }
+}
+
+impl<T: Fixed> FixedConvert for T {}
+
+#[cfg(test)]
+mod tests {
+    use substrate_fixed::types::{I32F32, I64F64, U64F64};
+
+    use super::*;
+
+    #[test]
+    fn test_saturating_cast_widening_is_lossless() {
+        for x in [
+            I32F32::from_num(0),
+            I32F32::from_num(-1.5),
+            I32F32::from_num(0.000_000_001),
+            I32F32::MAX,
+            I32F32::MIN,
+        ] {
+            assert_eq!(x.saturating_cast::<I64F64>().saturating_cast::<I32F32>(), x);
+        }
+    }
+
+    #[test]
+    fn test_saturating_cast_saturates() {
+        assert_eq!(I64F64::MAX.saturating_cast::<I32F32>(), I32F32::MAX);
+        assert_eq!(I64F64::MIN.saturating_cast::<I32F32>(), I32F32::MIN);
+        assert_eq!(
+            I32F32::from_num(-1).saturating_cast::<U64F64>(),
+            U64F64::from_num(0)
+        );
+    }
+
+    #[test]
+    fn test_u16_proportion_bounds() {
+        assert_eq!(I32F32::from_u16_proportion(0), I32F32::from_num(0));
+        assert_eq!(I32F32::from_u16_proportion(u16::MAX), I32F32::from_num(1));
+        assert_eq!(I32F32::from_num(0).to_u16_proportion(), 0);
+        assert_eq!(I32F32::from_num(1).to_u16_proportion(), u16::MAX);
+        // Out of range proportions saturate.
+        assert_eq!(I32F32::from_num(2).to_u16_proportion(), u16::MAX);
+        assert_eq!(I32F32::from_num(-1).to_u16_proportion(), 0);
+    }
+
+    #[test]
+    fn test_u16_proportion_round_trip_is_within_one() {
+        for x in (0..=u16::MAX).step_by(97) {
+            let round_trip = I32F32::from_u16_proportion(x).to_u16_proportion();
+            assert!(
+                x.abs_diff(round_trip) <= 1,
+                "{x} round trips to {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_saturating_scale() {
+        assert_eq!(I32F32::from_num(1).saturating_scale(u64::MAX), u64::MAX);
+        assert_eq!(I32F32::from_num(0).saturating_scale(u64::MAX), 0);
+        assert_eq!(
+            I32F32::from_num(0.5).saturating_scale(1_000_000_000),
+            500_000_000
+        );
+        assert_eq!(I32F32::from_num(2).saturating_scale(u64::MAX), u64::MAX);
+        assert_eq!(I32F32::from_num(-1).saturating_scale(1_000), 0);
+    }
+}
@@ -8,6 +8,9 @@ use core::f64::consts::LN_2;
 use sp_arithmetic::traits::UniqueSaturatedInto;
 use substrate_fixed::traits::Fixed;
 
+pub mod convert;
+pub use convert::*;
+
 /// Safe division trait
 pub trait SafeDiv {
     /// Safe division that returns supplied default value for division by zero
@@ -0,0 +1,92 @@
+// Charges the fees of Ethereum transactions calling the contracts sponsored by a subnet to the
+// sponsor of the subnet, see pallets/subtensor/src/subnets/evm_gas_sponsorship.rs.
+//
+// The target of the transaction is not passed to `OnChargeEVMTransaction`, so `apply_self_contained`
+// records it in the subtensor pallet before dispatching the transaction and clears it afterwards.
+// The sponsor is charged through the default handler, as if it had sent the transaction.
+//
+// The transaction pool still checks that the sender could pay the fee, as frontier validates
+// Ethereum transactions against the balance of their sender, so only the charge itself is moved.
+
+use fp_ethereum::{TransactionAction, TransactionData};
+use pallet_evm::{BalanceConverter, EvmBalance, OnChargeEVMTransaction};
+use sp_core::{H160, U256};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+use crate::{EthereumTransaction, Runtime, SubtensorEvmBalanceConverter, SubtensorModule};
+
+type DefaultLiquidityInfo = <() as OnChargeEVMTransaction<Runtime>>::LiquidityInfo;
+
+pub struct SponsoredEvmFees;
+
+impl SponsoredEvmFees {
+    /// Records the contract called by `transaction` before it is applied.
+    pub fn enter(transaction: &EthereumTransaction) {
+        let target = match TransactionData::from(transaction).action {
+            TransactionAction::Call(target) => Some(target),
+            TransactionAction::Create => None,
+        };
+        SubtensorModule::set_evm_transaction_target(target);
+    }
+
+    /// Clears the contract recorded by `enter` once the transaction is applied.
+    pub fn exit() {
+        SubtensorModule::set_evm_transaction_target(None);
+    }
+
+    fn fee_in_tao(fee: U256) -> Option<TaoCurrency> {
+        SubtensorEvmBalanceConverter::into_substrate_balance(EvmBalance::new(fee))
+            .map(|fee| fee.into_u256().low_u64().into())
+    }
+}
+
+impl OnChargeEVMTransaction<Runtime> for SponsoredEvmFees {
+    // The subnet and the sponsor paying the fee, if it is sponsored.
+    type LiquidityInfo = (Option<(NetUid, H160)>, DefaultLiquidityInfo);
+
+    fn withdraw_fee(
+        who: &H160,
+        fee: U256,
+    ) -> Result<Self::LiquidityInfo, pallet_evm::Error<Runtime>> {
+        let sponsorship = Self::fee_in_tao(fee).and_then(SubtensorModule::evm_transaction_sponsor);
+        if let Some((netuid, sponsor)) = sponsorship {
+            // A sponsor that cannot pay leaves the fee to the sender.
+            if let Ok(paid) = <() as OnChargeEVMTransaction<Runtime>>::withdraw_fee(&sponsor, fee) {
+                return Ok((Some((netuid, sponsor)), paid));
+            }
+        }
+
+        <() as OnChargeEVMTransaction<Runtime>>::withdraw_fee(who, fee).map(|paid| (None, paid))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &H160,
+        corrected_fee: U256,
+        base_fee: U256,
+        (sponsorship, paid): Self::LiquidityInfo,
+    ) -> Self::LiquidityInfo {
+        let payer = match sponsorship {
+            Some((netuid, sponsor)) => {
+                SubtensorModule::record_evm_gas_sponsored(
+                    netuid,
+                    *who,
+                    Self::fee_in_tao(corrected_fee).unwrap_or_default(),
+                );
+                sponsor
+            }
+            None => *who,
+        };
+
+        let tip = <() as OnChargeEVMTransaction<Runtime>>::correct_and_deposit_fee(
+            &payer,
+            corrected_fee,
+            base_fee,
+            paid,
+        );
+        (sponsorship, tip)
+    }
+
+    fn pay_priority_fee((_, tip): Self::LiquidityInfo) {
+        <() as OnChargeEVMTransaction<Runtime>>::pay_priority_fee(tip);
+    }
+}
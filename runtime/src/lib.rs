@@ -11,7 +11,8 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use core::num::NonZeroU64;
 
 pub mod check_nonce;
-mod migrations;
+pub mod evm_gas_sponsorship;
+pub mod migrations;
 
 extern crate alloc;
 
@@ -30,13 +31,28 @@ use pallet_grandpa::{
 };
 use pallet_registry::CanRegisterIdentity;
 use pallet_subtensor::rpc_info::{
-    delegate_info::DelegateInfo,
+    account_profile::AccountProfile,
+    chain_summary::ChainSummary,
+    child_info::HotkeyHierarchy,
+    commit_reveal_info::CommitRevealStatus,
+    consensus_info::ConsensusArtifacts,
+    delegate_info::{DelegateInfo, DelegateReturnRate},
+    dry_run::{DryRunInfo, EpochDryRun},
     dynamic_info::DynamicInfo,
+    epoch_summary::EpochSummary,
+    fee_estimate::FeeEstimate,
+    identity_info::SubnetIdentityInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
-    neuron_info::{NeuronInfo, NeuronInfoLite},
+    neuron_info::{NeuronAxonInfo, NeuronConnectivity, NeuronInfo, NeuronInfoLite},
+    pool_info::SubnetPoolState,
+    pruning_info::PruningInfo,
     show_subnet::SubnetState,
     stake_info::StakeInfo,
-    subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    subnet_info::{
+        SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2, SubnetScheduleInfo,
+    },
+    supply_info::SubnetSupplyInfo,
+    weights_info::{StaleWeightsInfo, SubnetWeightsSparse, WeightsVersionInfo},
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,7 +75,7 @@ use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
-use subtensor_precompiles::Precompiles;
+use subtensor_precompiles::{Precompiles, SubtensorEventBridge};
 use subtensor_runtime_common::{AlphaCurrency, TaoCurrency, time::*, *};
 
 // A few exports that help ease life for downstream crates.
@@ -212,7 +228,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 301,
+    spec_version: 381,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -390,8 +406,10 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                 | RuntimeCall::Timestamp(_)
                 | RuntimeCall::SubtensorModule(
                     pallet_subtensor::Call::set_weights { .. }
+                        | pallet_subtensor::Call::set_weights_delta { .. }
                         | pallet_subtensor::Call::set_tao_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
+                        | pallet_subtensor::Call::serve_axon_v2 { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
         )
@@ -652,6 +670,20 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::offer_subnet { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::accept_subnet_offer { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_emission_recipient { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_coldkey_recovery { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::deposit_to_evm { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::withdraw_from_evm { .. }
+                    )
             ),
             ProxyType::NonFungibile => !matches!(
                 c,
@@ -673,13 +705,31 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_stake_limit { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_multiple { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_locked { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unlock_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::withdraw_unbonded { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::sponsored_burned_register { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::bid_registration { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::deposit_to_evm { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::withdraw_from_evm { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::claim_evm_withdrawals { .. }
+                    )
             ),
             ProxyType::Transfer => matches!(
                 c,
@@ -687,6 +737,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
                     | RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::deposit_to_evm { .. })
             ),
             ProxyType::SmallTransfer => match c {
                 RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
@@ -712,6 +763,27 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         | RuntimeCall::SubtensorModule(
                             pallet_subtensor::Call::update_symbol { .. }
                         )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::propose_subnet_change { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::propose_subnet_treasury_spend { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::set_subnet_maintenance { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::set_subnet_logo_hash { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::set_evm_gas_sponsor { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::remove_evm_gas_sponsor { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::set_evm_sponsored_contract { .. }
+                        )
                 ) && !matches!(
                     c,
                     RuntimeCall::AdminUtils(
@@ -724,6 +796,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::sponsored_burned_register { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::bid_registration { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
                     | RuntimeCall::Triumvirate(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_tao_weights { .. })
                     | RuntimeCall::Sudo(..)
@@ -757,11 +834,23 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::remove_stake_full_limit { .. }
                     )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_multiple { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_locked { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unlock_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::withdraw_unbonded { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_auto_compound { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::sponsored_burned_register { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::register { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::bid_registration { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unregister { .. })
             ),
             ProxyType::RootWeights => matches!(
                 c,
@@ -804,6 +893,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::AdminUtils(
                         pallet_admin_utils::Call::sudo_set_weights_version_key { .. }
                     )
+                    | RuntimeCall::AdminUtils(
+                        pallet_admin_utils::Call::sudo_set_weights_version_key_grace_period { .. }
+                    )
                     | RuntimeCall::AdminUtils(
                         pallet_admin_utils::Call::sudo_set_adjustment_alpha { .. }
                     )
@@ -907,6 +999,15 @@ impl ProxyInterface<AccountId> for Proxier {
     }
 }
 
+pub struct EvmAddressToAccountId;
+impl sp_runtime::traits::Convert<H160, AccountId> for EvmAddressToAccountId {
+    fn convert(evm_address: H160) -> AccountId {
+        <<Runtime as pallet_evm::Config>::AddressMapping as pallet_evm::AddressMapping<
+            AccountId,
+        >>::into_account_id(evm_address)
+    }
+}
+
 parameter_types! {
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
         BlockWeights::get().max_block;
@@ -1088,13 +1189,13 @@ impl pallet_commitments::GetTempoInterface for Runtime {
 }
 
 #[cfg(not(feature = "fast-blocks"))]
-pub const INITIAL_SUBNET_TEMPO: u16 = 360;
+pub const INITIAL_SUBNET_TEMPO: u16 = scale_tempo(360);
 
 #[cfg(feature = "fast-blocks")]
 pub const INITIAL_SUBNET_TEMPO: u16 = 10;
 
 #[cfg(not(feature = "fast-blocks"))]
-pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 216000; // 30 days at 12 seconds per block
+pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = scale_blocks(216000); // 30 days at 12 seconds per block
 
 #[cfg(feature = "fast-blocks")]
 pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 5;
@@ -1132,12 +1233,12 @@ parameter_types! {
     pub const SubtensorInitialWeightsVersionKey: u64 = 0;
     pub const SubtensorInitialMinDifficulty: u64 = 10_000_000;
     pub const SubtensorInitialMaxDifficulty: u64 = u64::MAX / 4;
-    pub const SubtensorInitialServingRateLimit: u64 = 50;
+    pub const SubtensorInitialServingRateLimit: u64 = scale_blocks(50);
     pub const SubtensorInitialBurn: u64 = 100_000_000; // 0.1 tao
     pub const SubtensorInitialMinBurn: u64 = 500_000; // 500k RAO
     pub const SubtensorInitialMaxBurn: u64 = 100_000_000_000; // 100 tao
-    pub const SubtensorInitialTxRateLimit: u64 = 1000;
-    pub const SubtensorInitialTxDelegateTakeRateLimit: u64 = 216000; // 30 days at 12 seconds per block
+    pub const SubtensorInitialTxRateLimit: u64 = scale_blocks(1000);
+    pub const SubtensorInitialTxDelegateTakeRateLimit: u64 = scale_blocks(216000); // 30 days at 12 seconds per block
     pub const SubtensorInitialTxChildKeyTakeRateLimit: u64 = INITIAL_CHILDKEY_TAKE_RATELIMIT;
     pub const SubtensorInitialRAORecycledForRegistration: u64 = 0; // 0 rao
     pub const SubtensorInitialSenateRequiredStakePercentage: u64 = 1; // 1 percent of total stake
@@ -1147,7 +1248,7 @@ parameter_types! {
     pub const SubtensorInitialSubnetOwnerCut: u16 = 11_796; // 18 percent
     // pub const SubtensorInitialSubnetLimit: u16 = 12; // (DEPRECATED)
     pub const SubtensorInitialNetworkLockReductionInterval: u64 = 14 * 7200;
-    pub const SubtensorInitialNetworkRateLimit: u64 = 7200;
+    pub const SubtensorInitialNetworkRateLimit: u64 = scale_blocks(7200);
     pub const SubtensorInitialKeySwapCost: u64 = 100_000_000; // 0.1 TAO
     pub const InitialAlphaHigh: u16 = 58982; // Represents 0.9 as per the production default
     pub const InitialAlphaLow: u16 = 45875; // Represents 0.7 as per the production default
@@ -1162,11 +1263,13 @@ parameter_types! {
     pub const DurationOfStartCall: u64 = if cfg!(feature = "fast-blocks") {
         10 // Only 10 blocks for fast blocks
     } else {
-        7 * 24 * 60 * 60 / 12 // 7 days
+        scale_blocks(
+            7 * 24 * 60 * 60 / 12 // 7 days
+        )
     };
     pub const SubtensorInitialKeySwapOnSubnetCost: u64 = 1_000_000; // 0.001 TAO
     pub const HotkeySwapOnSubnetInterval : BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
-    pub const LeaseDividendsDistributionInterval: BlockNumber = 100; // 100 blocks
+    pub const LeaseDividendsDistributionInterval: BlockNumber = scale_block_number(100); // 100 blocks
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -1240,6 +1343,8 @@ impl pallet_subtensor::Config for Runtime {
     type KeySwapOnSubnetCost = SubtensorInitialKeySwapOnSubnetCost;
     type HotkeySwapOnSubnetInterval = HotkeySwapOnSubnetInterval;
     type ProxyInterface = Proxier;
+    type EventBridge = SubtensorEventBridge<Runtime>;
+    type EvmAddressMapping = EvmAddressToAccountId;
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
 }
 
@@ -1412,7 +1517,7 @@ impl pallet_evm::Config for Runtime {
     type ChainId = ConfigurableChainId;
     type BlockGasLimit = BlockGasLimit;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type OnChargeTransaction = ();
+    type OnChargeTransaction = evm_gas_sponsorship::SponsoredEvmFees;
     type OnCreate = ();
     type FindAuthor = FindAuthorTruncated<Aura>;
     type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
@@ -1541,10 +1646,13 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
         info: Self::SignedInfo,
     ) -> Option<sp_runtime::DispatchResultWithInfo<PostDispatchInfoOf<Self>>> {
         match self {
-            call @ RuntimeCall::Ethereum(pallet_ethereum::Call::transact { .. }) => {
-                Some(call.dispatch(RuntimeOrigin::from(
-                    pallet_ethereum::RawOrigin::EthereumTransaction(info),
-                )))
+            RuntimeCall::Ethereum(transact { transaction }) => {
+                evm_gas_sponsorship::SponsoredEvmFees::enter(&transaction);
+                let result = RuntimeCall::Ethereum(transact { transaction }).dispatch(
+                    RuntimeOrigin::from(pallet_ethereum::RawOrigin::EthereumTransaction(info)),
+                );
+                evm_gas_sponsorship::SponsoredEvmFees::exit();
+                Some(result)
             }
             _ => None,
         }
@@ -1559,12 +1667,12 @@ parameter_types! {
     pub const MinimumBlockDuration: BlockNumber = if cfg!(feature = "fast-blocks") {
         50
     } else {
-        50400 // 7 days minimum (7 * 24 * 60 * 60 / 12)
+        scale_block_number(50400) // 7 days minimum (7 * 24 * 60 * 60 / 12)
     };
     pub const MaximumBlockDuration: BlockNumber = if cfg!(feature = "fast-blocks") {
        20000
     } else {
-        432000 // 60 days maximum (60 * 24 * 60 * 60 / 12)
+        scale_block_number(432000) // 60 days maximum (60 * 24 * 60 * 60 / 12)
     };
     pub const RefundContributorsLimit: u32 = 50;
     pub const MaxContributors: u32 = 500;
@@ -1650,6 +1758,8 @@ type Migrations = (
     pallet_subtensor::migrations::migrate_init_total_issuance::initialise_total_issuance::Migration<
         Runtime,
     >,
+    // One-off migrations go below, wrapped in `migrations::Checked` so they run once and report
+    // what they did.
 );
 
 // Unchecked extrinsic type as expected by this runtime.
@@ -2255,6 +2365,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl subtensor_custom_rpc_runtime_api::DelegateInfoRuntimeApi<Block> for Runtime {
         fn get_delegates() -> Vec<DelegateInfo<AccountId32>> {
             SubtensorModule::get_delegates()
@@ -2267,8 +2378,17 @@ impl_runtime_apis! {
         fn get_delegated(delegatee_account: AccountId32) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))> {
             SubtensorModule::get_delegated(delegatee_account)
         }
+
+        fn get_delegate_return_rates(epochs: u16) -> Vec<DelegateReturnRate<AccountId32>> {
+            SubtensorModule::get_delegate_return_rates(epochs)
+        }
+
+        fn get_hotkey_hierarchy(hotkey_account: AccountId32) -> Vec<HotkeyHierarchy<AccountId32>> {
+            SubtensorModule::get_hotkey_hierarchy(hotkey_account)
+        }
     }
 
+    #[api_version(2)]
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
         fn get_neurons_lite(netuid: NetUid) -> Vec<NeuronInfoLite<AccountId32>> {
             SubtensorModule::get_neurons_lite(netuid)
@@ -2278,6 +2398,14 @@ impl_runtime_apis! {
             SubtensorModule::get_neuron_lite(netuid, uid)
         }
 
+        fn get_neuron_connectivity(netuid: NetUid) -> Vec<NeuronConnectivity<AccountId32>> {
+            SubtensorModule::get_neuron_connectivity(netuid)
+        }
+
+        fn get_neuron_axons_v2(netuid: NetUid) -> Vec<NeuronAxonInfo<AccountId32>> {
+            SubtensorModule::get_neuron_axons_v2(netuid)
+        }
+
         fn get_neurons(netuid: NetUid) -> Vec<NeuronInfo<AccountId32>> {
             SubtensorModule::get_neurons(netuid)
         }
@@ -2287,6 +2415,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(2)]
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
         fn get_subnet_info(netuid: NetUid) -> Option<SubnetInfo<AccountId32>> {
             SubtensorModule::get_subnet_info(netuid)
@@ -2336,8 +2465,77 @@ impl_runtime_apis! {
             SubtensorModule::get_selective_metagraph(netuid, metagraph_indexes)
         }
 
+        fn get_metagraph_paginated(netuid: NetUid, start_uid: u16, limit: u16) -> Option<Metagraph<AccountId32>> {
+            SubtensorModule::get_metagraph_paginated(netuid, start_uid, limit)
+        }
+
+        fn get_selective_metagraph_by_mask(netuid: NetUid, field_mask: u128) -> Option<SelectiveMetagraph<AccountId32>> {
+            SubtensorModule::get_selective_metagraph_by_mask(netuid, field_mask)
+        }
+
+        fn get_epoch_summaries() -> Vec<EpochSummary> {
+            SubtensorModule::get_epoch_summaries()
+        }
+
+        fn get_subnet_schedule_info(netuid: NetUid) -> Option<SubnetScheduleInfo> {
+            SubtensorModule::get_subnet_schedule_info(netuid)
+        }
+
+        fn get_chain_summary() -> ChainSummary {
+            SubtensorModule::get_chain_summary()
+        }
+
+        fn get_consensus_artifacts(netuid: NetUid) -> Option<ConsensusArtifacts> {
+            SubtensorModule::get_consensus_artifacts(netuid)
+        }
+
+        fn get_commit_reveal_status(hotkey_account: Option<AccountId32>) -> Vec<CommitRevealStatus> {
+            SubtensorModule::get_commit_reveal_statuses(hotkey_account)
+        }
+
+        fn get_weights_sparse(netuid: NetUid) -> Option<SubnetWeightsSparse> {
+            SubtensorModule::get_weights_sparse(netuid)
+        }
+
+        fn get_pool_state(netuid: NetUid) -> Option<SubnetPoolState> {
+            SubtensorModule::get_pool_state(netuid)
+        }
+
+        fn get_all_pool_states() -> Vec<SubnetPoolState> {
+            SubtensorModule::get_all_pool_states()
+        }
+
+        fn get_pruning_info(netuid: NetUid) -> Option<PruningInfo> {
+            SubtensorModule::get_pruning_info(netuid)
+        }
+
+        fn get_subnet_supply_info(netuid: NetUid) -> Option<SubnetSupplyInfo> {
+            SubtensorModule::get_subnet_supply_info(netuid)
+        }
+
+        fn get_all_subnet_supply_info() -> Vec<SubnetSupplyInfo> {
+            SubtensorModule::get_all_subnet_supply_info()
+        }
+
+        fn get_subnet_identity_info(netuid: NetUid) -> Option<SubnetIdentityInfo> {
+            SubtensorModule::get_subnet_identity_info(netuid)
+        }
+
+        fn get_all_subnet_identity_info() -> Vec<SubnetIdentityInfo> {
+            SubtensorModule::get_all_subnet_identity_info()
+        }
+
+        fn get_stale_weights_info(netuid: NetUid) -> Option<StaleWeightsInfo> {
+            SubtensorModule::get_stale_weights_info(netuid)
+        }
+
+        fn get_weights_version_info(netuid: NetUid) -> Option<WeightsVersionInfo> {
+            SubtensorModule::get_weights_version_info(netuid)
+        }
+
     }
 
+    #[api_version(3)]
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
         fn get_stake_info_for_coldkey( coldkey_account: AccountId32 ) -> Vec<StakeInfo<AccountId32>> {
             SubtensorModule::get_stake_info_for_coldkey( coldkey_account )
@@ -2354,12 +2552,68 @@ impl_runtime_apis! {
         fn get_stake_fee( origin: Option<(AccountId32, NetUid)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, NetUid)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64 {
             SubtensorModule::get_stake_fee( origin, origin_coldkey_account, destination, destination_coldkey_account, amount )
         }
+
+        fn get_total_stake_for_hotkey( hotkey_account: AccountId32 ) -> TaoCurrency {
+            SubtensorModule::get_total_stake_for_hotkey( &hotkey_account )
+        }
+
+        fn get_stake_for_coldkey_lite( coldkey_account: AccountId32 ) -> Vec<(AccountId32, Compact<NetUid>, Compact<AlphaCurrency>)> {
+            SubtensorModule::get_stake_for_coldkey_lite( coldkey_account )
+        }
+
+        fn get_stake_proof_keys( coldkey_account: AccountId32 ) -> Vec<Vec<u8>> {
+            SubtensorModule::get_stake_proof_keys( coldkey_account )
+        }
     }
 
+    #[api_version(2)]
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
         fn get_network_registration_cost() -> TaoCurrency {
             SubtensorModule::get_network_lock_cost()
         }
+
+        fn get_network_registration_cost_projection(blocks: u64) -> Vec<TaoCurrency> {
+            SubtensorModule::get_network_lock_cost_projection(blocks)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::AccountProfileRuntimeApi<Block> for Runtime {
+        fn get_account_profile(account: AccountId32) -> AccountProfile<AccountId32> {
+            let registry_identity = Registry::identity_of(&account).map(|identity| identity.encode());
+            let mut commitments = Vec::new();
+            let mut revealed_commitments = Vec::new();
+            for netuid in SubtensorModule::get_all_subnet_netuids() {
+                if let Some(commitment) = Commitments::commitment_of(netuid, &account) {
+                    commitments.push((netuid, commitment.encode()));
+                }
+                if let Some(reveals) = Commitments::revealed_commitments(netuid, &account) {
+                    revealed_commitments.push((netuid, reveals));
+                }
+            }
+            SubtensorModule::get_account_profile(account, registry_identity, commitments, revealed_commitments)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::DryRunRuntimeApi<Block> for Runtime {
+        fn dry_run_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> DryRunInfo {
+            let len = extrinsic.encoded_size() as u32;
+            let fee = TransactionPayment::query_info(extrinsic.clone(), len).partial_fee;
+            DryRunInfo::from_apply_result(Executive::apply_extrinsic(extrinsic), fee)
+        }
+
+        fn estimate_fee(extrinsic: <Block as BlockT>::Extrinsic) -> FeeEstimate {
+            let len = extrinsic.encoded_size() as u32;
+            let fee = TransactionPayment::query_info(extrinsic.clone(), len).partial_fee;
+            let call = match &extrinsic.0.function {
+                RuntimeCall::SubtensorModule(call) => Some(call),
+                _ => None,
+            };
+            SubtensorModule::get_fee_estimate(call, fee)
+        }
+
+        fn epoch_dry_run(netuid: NetUid) -> Option<EpochDryRun<AccountId32>> {
+            SubtensorModule::epoch_dry_run(netuid)
+        }
     }
 
 
@@ -1 +1,188 @@
 //! Export migrations from here.
+//!
+//! Runtime migrations implement [`CheckedMigration`] and are registered in `Migrations` wrapped in
+//! [`Checked`], which runs each of them once, records what it did in a [`MigrationReport`] and
+//! logs the report. Under try-runtime, the migration's pre/post-check pair runs around it, the
+//! report is logged again as a dry-run report, and migrations declaring that they preserve stake
+//! are checked to leave the `StakingHotkeys`, `TotalHotkeyAlpha` and `TotalStake` maps unchanged,
+//! so an upgrade can be validated against a snapshot of mainnet before it is enacted.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, storage::unhashed};
+use frame_support::{pallet_prelude::Get, traits::OnRuntimeUpgrade, weights::Weight};
+use pallet_subtensor::HasMigrationRun;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use subtensor_runtime_common::Currency;
+
+use crate::Runtime;
+
+/// What a migration did: the items it migrated and the storage it accessed.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug)]
+pub struct MigrationReport {
+    /// The items migrated.
+    pub items: u64,
+    /// The storage reads.
+    pub reads: u64,
+    /// The storage writes.
+    pub writes: u64,
+    /// The encoded bytes read.
+    pub bytes_read: u64,
+    /// The encoded bytes written.
+    pub bytes_written: u64,
+}
+
+impl MigrationReport {
+    /// Records the read of `value`.
+    pub fn read<V: Encode>(&mut self, value: &V) {
+        self.reads = self.reads.saturating_add(1);
+        self.bytes_read = self.bytes_read.saturating_add(value.encoded_size() as u64);
+    }
+
+    /// Records the write of `value`.
+    pub fn write<V: Encode>(&mut self, value: &V) {
+        self.writes = self.writes.saturating_add(1);
+        self.bytes_written = self
+            .bytes_written
+            .saturating_add(value.encoded_size() as u64);
+    }
+
+    /// Records the removal of an item.
+    pub fn remove(&mut self) {
+        self.writes = self.writes.saturating_add(1);
+    }
+
+    /// Records the migration of an item.
+    pub fn migrated(&mut self) {
+        self.items = self.items.saturating_add(1);
+    }
+
+    /// The weight consumed by the recorded storage accesses.
+    pub fn weight(&self) -> Weight {
+        <Runtime as frame_system::Config>::DbWeight::get().reads_writes(self.reads, self.writes)
+    }
+}
+
+/// A runtime migration, run once by [`Checked`].
+pub trait CheckedMigration {
+    /// The name the migration is recorded under in `HasMigrationRun`.
+    const NAME: &'static str;
+
+    /// Whether the migration leaves the stake maps unchanged.
+    const PRESERVES_STAKE: bool = true;
+
+    /// Migrates storage, recording every item migrated and storage access in `report`.
+    fn migrate(report: &mut MigrationReport);
+
+    /// Returns the state `post_check` compares the migrated storage with.
+    #[cfg(feature = "try-runtime")]
+    fn pre_check() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok(Vec::new())
+    }
+
+    /// Checks the migrated storage against the state returned by `pre_check`.
+    #[cfg(feature = "try-runtime")]
+    fn post_check(_state: Vec<u8>, _report: &MigrationReport) -> Result<(), TryRuntimeError> {
+        Ok(())
+    }
+}
+
+/// Runs the migration `M` once, reporting what it did.
+pub struct Checked<M>(PhantomData<M>);
+
+impl<M: CheckedMigration> Checked<M> {
+    /// The storage key the report of `M` is kept under between the upgrade and its post-check.
+    #[cfg(feature = "try-runtime")]
+    fn report_key() -> Vec<u8> {
+        (b"subtensor/migration_report", M::NAME).encode()
+    }
+}
+
+impl<M: CheckedMigration> OnRuntimeUpgrade for Checked<M> {
+    fn on_runtime_upgrade() -> Weight {
+        let name = M::NAME.as_bytes().to_vec();
+        let mut report = MigrationReport::default();
+
+        report.reads = report.reads.saturating_add(1);
+        if HasMigrationRun::<Runtime>::get(&name) {
+            log::info!("Migration '{}' has already run. Skipping.", M::NAME);
+            return report.weight();
+        }
+
+        log::info!("Running migration '{}'", M::NAME);
+        M::migrate(&mut report);
+        HasMigrationRun::<Runtime>::insert(&name, true);
+        report.write(&true);
+
+        log::info!("Migration '{}' completed: {:?}", M::NAME, report);
+        #[cfg(feature = "try-runtime")]
+        unhashed::put(&Self::report_key(), &report);
+
+        report.weight()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let stake = M::PRESERVES_STAKE.then(StakeSnapshot::take);
+        Ok((M::pre_check()?, stake).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        // A migration that already ran has nothing to check.
+        let Some(report) = unhashed::take::<MigrationReport>(&Self::report_key()) else {
+            return Ok(());
+        };
+        log::info!(
+            "Migration '{}' dry-run report: items migrated: {}, bytes read: {}, bytes written: {}, weight consumed: {:?}",
+            M::NAME,
+            report.items,
+            report.bytes_read,
+            report.bytes_written,
+            report.weight()
+        );
+
+        let (state, stake): (Vec<u8>, Option<StakeSnapshot>) =
+            Decode::decode(&mut state.as_slice())
+                .map_err(|_| "Failed to decode the pre-upgrade state")?;
+        if let Some(stake) = stake {
+            ensure!(
+                StakeSnapshot::take() == stake,
+                "Migration changed the stake maps"
+            );
+        }
+        M::post_check(state, &report)
+    }
+}
+
+/// A summary of the stake maps, compared before and after migrations preserving stake.
+#[cfg(feature = "try-runtime")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct StakeSnapshot {
+    /// The (coldkey, hotkey) pairs in `StakingHotkeys`.
+    pub staking_hotkeys: u64,
+    /// The sum of `TotalHotkeyAlpha` over every hotkey and subnet.
+    pub total_hotkey_alpha: u128,
+    /// The `TotalStake`.
+    pub total_stake: u64,
+}
+
+#[cfg(feature = "try-runtime")]
+impl StakeSnapshot {
+    /// Summarizes the current stake maps.
+    pub fn take() -> Self {
+        Self {
+            staking_hotkeys: pallet_subtensor::StakingHotkeys::<Runtime>::iter_values()
+                .map(|hotkeys| hotkeys.len() as u64)
+                .fold(0, u64::saturating_add),
+            total_hotkey_alpha: pallet_subtensor::TotalHotkeyAlpha::<Runtime>::iter_values()
+                .map(|alpha| u128::from(alpha.to_u64()))
+                .fold(0, u128::saturating_add),
+            total_stake: pallet_subtensor::TotalStake::<Runtime>::get().to_u64(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+#![allow(clippy::unwrap_used)]
+
+use node_subtensor_runtime::{BuildStorage, Runtime, RuntimeEvent, RuntimeGenesisConfig, System};
+use pallet_subtensor::EventBridge;
+use sp_core::{H160, H256, hashing::keccak_256};
+use subtensor_precompiles::{SUBTENSOR_EVENTS_INDEX, SubtensorEventBridge};
+use subtensor_runtime_common::{AccountId, NetUid};
+
+const HOTKEY: [u8; 32] = [7_u8; 32];
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig::default()
+        .build_storage()
+        .unwrap()
+        .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn evm_logs() -> Vec<pallet_evm::Log> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => Some(log),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_neuron_registered_is_mirrored_as_evm_log() {
+    new_test_ext().execute_with(|| {
+        let event = pallet_subtensor::Event::<Runtime>::NeuronRegistered(
+            NetUid::from(3),
+            5,
+            AccountId::from(HOTKEY),
+        );
+        SubtensorEventBridge::<Runtime>::on_event(&event);
+
+        let logs = evm_logs();
+        assert_eq!(logs.len(), 1);
+        let log = logs.first().unwrap();
+        assert_eq!(log.address, H160::from_low_u64_be(SUBTENSOR_EVENTS_INDEX));
+        assert_eq!(
+            log.topics,
+            vec![
+                H256(keccak_256(b"NeuronRegistered(uint16,bytes32,uint16)")),
+                H256::from_low_u64_be(3),
+                H256(HOTKEY),
+            ]
+        );
+        assert_eq!(log.data, H256::from_low_u64_be(5).0.to_vec());
+    });
+}
+
+#[test]
+fn test_unmirrored_event_is_not_bridged() {
+    new_test_ext().execute_with(|| {
+        let event = pallet_subtensor::Event::<Runtime>::NetworkRemoved(NetUid::from(3));
+        SubtensorEventBridge::<Runtime>::on_event(&event);
+
+        assert!(evm_logs().is_empty());
+    });
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used)]
+
+use frame_support::{pallet_prelude::Get, traits::OnRuntimeUpgrade};
+use node_subtensor_runtime::{
+    BuildStorage, Runtime, RuntimeGenesisConfig, System,
+    migrations::{Checked, CheckedMigration, MigrationReport},
+};
+use subtensor_runtime_common::NetUid;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig::default()
+        .build_storage()
+        .unwrap()
+        .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+struct SetTempos;
+
+impl CheckedMigration for SetTempos {
+    const NAME: &'static str = "test_set_tempos";
+
+    fn migrate(report: &mut MigrationReport) {
+        for netuid in [NetUid::from(1), NetUid::from(2)] {
+            let tempo = pallet_subtensor::Tempo::<Runtime>::get(netuid);
+            report.read(&tempo);
+            pallet_subtensor::Tempo::<Runtime>::insert(netuid, 99);
+            report.write(&99u16);
+            report.migrated();
+        }
+    }
+}
+
+#[test]
+fn test_checked_migration_runs_once_and_reports() {
+    new_test_ext().execute_with(|| {
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+        // The migration reports its items and storage accesses, plus the `HasMigrationRun` check
+        // and write.
+        let weight = Checked::<SetTempos>::on_runtime_upgrade();
+        assert_eq!(weight, db_weight.reads_writes(3, 3));
+        assert_eq!(pallet_subtensor::Tempo::<Runtime>::get(NetUid::from(2)), 99);
+        assert!(pallet_subtensor::HasMigrationRun::<Runtime>::get(
+            SetTempos::NAME.as_bytes().to_vec()
+        ));
+
+        // The migration doesn't run again.
+        pallet_subtensor::Tempo::<Runtime>::insert(NetUid::from(2), 10);
+        let weight = Checked::<SetTempos>::on_runtime_upgrade();
+        assert_eq!(weight, db_weight.reads(1));
+        assert_eq!(pallet_subtensor::Tempo::<Runtime>::get(NetUid::from(2)), 10);
+    });
+}
@@ -172,6 +172,35 @@ fn call_register() -> RuntimeCall {
     })
 }
 
+// split stake call
+fn call_add_stake_multiple() -> RuntimeCall {
+    let netuid = NetUid::from(1);
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake_multiple {
+        netuid,
+        amount_staked: 100.into(),
+        splits: vec![(1, AccountId::from(DELEGATE))],
+    })
+}
+
+// registration auction call
+fn call_bid_registration() -> RuntimeCall {
+    let netuid = NetUid::from(1);
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::bid_registration {
+        netuid,
+        hotkey: AccountId::from(DELEGATE),
+        bid: 100.into(),
+    })
+}
+
+// subnet maintenance call
+fn call_set_subnet_maintenance() -> RuntimeCall {
+    let netuid = NetUid::from(1);
+    RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_subnet_maintenance {
+        netuid,
+        enabled: true,
+    })
+}
+
 fn verify_call_with_proxy_type(proxy_type: &ProxyType, call: &RuntimeCall) {
     assert_ok!(Proxy::proxy(
         RuntimeOrigin::signed(AccountId::from(DELEGATE)),
@@ -307,3 +336,42 @@ fn test_owner_type_can_set_subnet_identity_and_update_symbol() {
         verify_call_with_proxy_type(&ProxyType::Owner, &call_update_symbol());
     });
 }
+
+#[test]
+fn test_operational_proxy_types_are_scoped() {
+    let calls = [
+        call_transfer,
+        call_add_stake_multiple,
+        call_bid_registration,
+        call_set_subnet_maintenance,
+    ];
+    let proxy_types = [
+        ProxyType::Staking,
+        ProxyType::Registration,
+        ProxyType::Owner,
+    ];
+
+    for call in calls.iter() {
+        for proxy_type in proxy_types.iter() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Proxy::add_proxy(
+                    RuntimeOrigin::signed(AccountId::from(ACCOUNT)),
+                    AccountId::from(DELEGATE).into(),
+                    *proxy_type,
+                    0
+                ));
+
+                verify_call_with_proxy_type(proxy_type, &call());
+            });
+        }
+    }
+
+    assert!(ProxyType::Staking.filter(&call_add_stake_multiple()));
+    assert!(ProxyType::Registration.filter(&call_bid_registration()));
+    assert!(ProxyType::Owner.filter(&call_set_subnet_maintenance()));
+    for proxy_type in proxy_types.iter() {
+        assert!(!proxy_type.filter(&call_transfer()));
+    }
+    assert!(!ProxyType::NonFungibile.filter(&call_add_stake_multiple()));
+    assert!(!ProxyType::NonCritical.filter(&call_bid_registration()));
+}
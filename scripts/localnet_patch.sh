@@ -26,7 +26,7 @@ fi
 
 # replace
 perl -0777 -i -pe 's|7 \* 24 \* 60 \* 60 / 12 // 7 days|5 // Only 5 blocks for tests|' "$DurationOfStartCall"
-perl -0777 -i -pe 's|pub fn DefaultPendingCooldown<T: Config>\(\) -> u64 \{\s*if cfg!\(feature = "fast-blocks"\) \{\s*return 15;\s*\}\s*7_200\s*\}|pub fn DefaultPendingCooldown<T: Config>() -> u64 {\n        15\n    }|g' "$DefaultPendingCooldown"
+perl -0777 -i -pe 's|pub fn DefaultPendingCooldown<T: Config>\(\) -> u64 \{\s*if cfg!\(feature = "fast-blocks"\) \{\s*return 15;\s*\}\s*subtensor_runtime_common::time::scale_blocks\(7_200\)\s*\}|pub fn DefaultPendingCooldown<T: Config>() -> u64 {\n        15\n    }|g' "$DefaultPendingCooldown"
 perl -0777 -i -pe 's|TransactionType::SetChildren => 150, // 30 minutes|TransactionType::SetChildren => 15, // 3 min|' "$SetChildren"
 
 echo "Patch applied successfully."
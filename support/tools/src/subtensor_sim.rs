@@ -0,0 +1,293 @@
+use anyhow::{Context, anyhow, ensure};
+use clap::{Args, Parser, Subcommand};
+use node_subtensor_runtime::{Runtime, pallet_subtensor::Pallet as SubtensorModule};
+use serde::Serialize;
+use sp_io::TestExternalities;
+use std::{fs, path::PathBuf};
+
+const RAO_PER_TAO: f64 = 1_000_000_000.0;
+
+#[derive(Parser)]
+struct CliArgs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Project total issuance forward over the next N halvings.
+    IssuanceReport(IssuanceReportArgs),
+}
+
+#[derive(Args)]
+struct IssuanceReportArgs {
+    /// Number of halvings to simulate.
+    #[arg(long, default_value_t = 4)]
+    halvings: u32,
+    /// Total issuance (RAO) at the starting block.
+    #[arg(long, default_value_t = 0)]
+    issuance: u64,
+    /// Block number the simulation starts from.
+    #[arg(long, default_value_t = 0)]
+    start_block: u64,
+    /// Subnet emission shares as `netuid=weight`, e.g. `1=0.4,2=0.6`.
+    #[arg(long, value_delimiter = ',', value_parser = parse_subnet_share)]
+    subnets: Vec<(u16, f64)>,
+    /// Optional path to write the report to as JSON.
+    #[arg(long)]
+    json: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct HalvingEntry {
+    index: u32,
+    block: u64,
+    total_issuance: u64,
+    block_emission: u64,
+}
+
+#[derive(Serialize)]
+struct SubnetEmission {
+    netuid: u16,
+    share: f64,
+    cumulative_emission: u64,
+}
+
+#[derive(Serialize)]
+struct IssuanceReport {
+    start_block: u64,
+    start_issuance: u64,
+    halvings: Vec<HalvingEntry>,
+    subnets: Vec<SubnetEmission>,
+}
+
+fn parse_subnet_share(s: &str) -> anyhow::Result<(u16, f64)> {
+    let (netuid, share) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected `netuid=weight`, got `{s}`"))?;
+    let netuid = netuid.trim().parse::<u16>().context("invalid netuid")?;
+    let share = share.trim().parse::<f64>().context("invalid weight")?;
+    ensure!(share >= 0.0, "subnet weight must be non-negative");
+    Ok((netuid, share))
+}
+
+/// Block emission of the runtime at `issuance`.
+fn block_emission(issuance: u64) -> u64 {
+    TestExternalities::default().execute_with(|| {
+        SubtensorModule::<Runtime>::get_block_emission_for_issuance(issuance).unwrap_or_default()
+    })
+}
+
+/// Smallest issuance above `issuance` at which the block emission drops below `emission`, the
+/// emission being non-increasing in the issuance.
+fn next_halving_issuance(issuance: u64, emission: u64) -> u64 {
+    let (mut low, mut high) = (issuance, u64::MAX);
+    while high.saturating_sub(low) > 1 {
+        let mid = low.saturating_add(high.saturating_sub(low) / 2);
+        if block_emission(mid) < emission {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// Number of halvings applied to the initial block emission to get `emission`.
+fn halving_index(initial: u64, emission: u64) -> u32 {
+    initial
+        .checked_div(emission)
+        .and_then(u64::checked_ilog2)
+        .unwrap_or_default()
+}
+
+fn issuance_report(args: &IssuanceReportArgs) -> anyhow::Result<IssuanceReport> {
+    let initial = block_emission(0);
+    let mut emission = block_emission(args.issuance);
+    ensure!(emission > 0, "issuance must be below the total supply");
+
+    let mut block = args.start_block;
+    let mut issuance = args.issuance;
+    let mut halvings = Vec::new();
+
+    for _ in 0..args.halvings {
+        if emission == 0 {
+            break;
+        }
+        let remaining = next_halving_issuance(issuance, emission).saturating_sub(issuance);
+        let blocks = remaining.div_ceil(emission);
+
+        block = block.saturating_add(blocks);
+        issuance = issuance.saturating_add(blocks.saturating_mul(emission));
+        emission = block_emission(issuance);
+
+        halvings.push(HalvingEntry {
+            index: halving_index(initial, emission),
+            block,
+            total_issuance: issuance,
+            block_emission: emission,
+        });
+    }
+
+    let emitted = issuance.saturating_sub(args.issuance);
+    let total_share: f64 = args.subnets.iter().map(|(_, share)| share).sum();
+    let subnets = args
+        .subnets
+        .iter()
+        .map(|&(netuid, share)| {
+            let share = if total_share > 0.0 {
+                share / total_share
+            } else {
+                0.0
+            };
+            SubnetEmission {
+                netuid,
+                share,
+                cumulative_emission: (emitted as f64 * share) as u64,
+            }
+        })
+        .collect();
+
+    Ok(IssuanceReport {
+        start_block: args.start_block,
+        start_issuance: args.issuance,
+        halvings,
+        subnets,
+    })
+}
+
+fn print_report(report: &IssuanceReport) {
+    println!(
+        "Start: block {} / issuance {:.4} TAO",
+        report.start_block,
+        report.start_issuance as f64 / RAO_PER_TAO
+    );
+    println!();
+    println!(
+        "{:>8} {:>14} {:>24} {:>20}",
+        "halving", "block", "total issuance (TAO)", "emission/block (TAO)"
+    );
+    for entry in &report.halvings {
+        println!(
+            "{:>8} {:>14} {:>24.4} {:>20.9}",
+            entry.index,
+            entry.block,
+            entry.total_issuance as f64 / RAO_PER_TAO,
+            entry.block_emission as f64 / RAO_PER_TAO
+        );
+    }
+
+    if !report.subnets.is_empty() {
+        println!();
+        println!(
+            "{:>8} {:>10} {:>28}",
+            "netuid", "share", "cumulative emission (TAO)"
+        );
+        for subnet in &report.subnets {
+            println!(
+                "{:>8} {:>10.4} {:>28.4}",
+                subnet.netuid,
+                subnet.share,
+                subnet.cumulative_emission as f64 / RAO_PER_TAO
+            );
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = CliArgs::parse();
+
+    match args.command {
+        Command::IssuanceReport(args) => {
+            let report = issuance_report(&args)?;
+            print_report(&report);
+            if let Some(path) = &args.json {
+                fs::write(path, serde_json::to_string_pretty(&report)?)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(issuance: u64, halvings: u32, subnets: Vec<(u16, f64)>) -> IssuanceReportArgs {
+        IssuanceReportArgs {
+            halvings,
+            issuance,
+            start_block: 0,
+            subnets,
+            json: None,
+        }
+    }
+
+    #[test]
+    fn test_halvings_match_runtime_emission() {
+        let report = issuance_report(&args(0, 3, Vec::new())).unwrap();
+        assert_eq!(report.halvings.len(), 3);
+
+        let mut emission = block_emission(0);
+        for (i, entry) in report.halvings.iter().enumerate() {
+            // The emission halves at each entry, as computed by the runtime.
+            assert_eq!(entry.block_emission, emission / 2);
+            assert_eq!(entry.block_emission, block_emission(entry.total_issuance));
+            assert_eq!(entry.index as usize, i.saturating_add(1));
+            // The previous emission still applies one block before the halving.
+            let before = entry.total_issuance.saturating_sub(emission);
+            assert_eq!(block_emission(before), emission);
+            emission = entry.block_emission;
+        }
+    }
+
+    #[test]
+    fn test_first_halving_at_half_supply() {
+        let report = issuance_report(&args(0, 1, Vec::new())).unwrap();
+        let first = &report.halvings[0];
+        // 10.5M TAO at 1 TAO per block, up to the precision of the runtime's logarithm.
+        assert!(first.block.abs_diff(10_500_000) <= 10);
+        assert_eq!(
+            first.total_issuance,
+            first.block.saturating_mul(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_starts_from_current_issuance() {
+        let from_zero = issuance_report(&args(0, 2, Vec::new())).unwrap();
+        let first = &from_zero.halvings[0];
+        let resumed = issuance_report(&args(first.total_issuance, 1, Vec::new())).unwrap();
+        assert_eq!(resumed.halvings[0].index, 2);
+        assert_eq!(
+            resumed.halvings[0].block_emission,
+            from_zero.halvings[1].block_emission
+        );
+    }
+
+    #[test]
+    fn test_rejects_issuance_at_total_supply() {
+        assert!(issuance_report(&args(21_000_000_000_000_000, 1, Vec::new())).is_err());
+    }
+
+    #[test]
+    fn test_subnet_shares_are_normalized() {
+        let report = issuance_report(&args(0, 1, vec![(1, 1.0), (2, 3.0)])).unwrap();
+        let emitted = report.halvings[0].total_issuance;
+        assert_eq!(report.subnets[0].share, 0.25);
+        assert_eq!(report.subnets[1].share, 0.75);
+        assert_eq!(
+            report.subnets[1].cumulative_emission,
+            (emitted as f64 * 0.75) as u64
+        );
+    }
+
+    #[test]
+    fn test_parse_subnet_share() {
+        assert_eq!(parse_subnet_share("3=0.5").unwrap(), (3, 0.5));
+        assert!(parse_subnet_share("3").is_err());
+        assert!(parse_subnet_share("3=-1").is_err());
+    }
+}